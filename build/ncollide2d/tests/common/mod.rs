@@ -0,0 +1,37 @@
+//! Fixtures shared by several tests.
+
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::shape::Polyline;
+
+/// A square AABB with the given half-extent, centered at `(x, 0)`.
+pub fn aabb_at(x: f32, half_extent: f32) -> AABB<f32> {
+    AABB::from_half_extents(Point2::new(x, 0.0), Vector2::repeat(half_extent))
+}
+
+/// The signed area of a closed polygon, positive if its vertices are counterclockwise.
+pub fn signed_area(points: &[Point2<f64>]) -> f64 {
+    (0..points.len())
+        .map(|i| {
+            points[i]
+                .coords
+                .perp(&points[(i + 1) % points.len()].coords)
+        })
+        .sum::<f64>()
+        * 0.5
+}
+
+/// A polyline made of the given closed loops.
+pub fn closed_polyline(loops: &[&[Point2<f64>]]) -> Polyline<f64> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    for contour in loops {
+        let first = points.len();
+        let n = contour.len();
+        points.extend_from_slice(contour);
+        indices.extend((0..n).map(|i| Point2::new(first + i, first + (i + 1) % n)));
+    }
+
+    Polyline::new(points, Some(indices))
+}
@@ -48,3 +48,28 @@ fn test_ball_cuboid_query_contact_fp32() {
 fn test_ball_cuboid_query_contact_fixedi40f24() {
     test_collide::<FixedI40F24>(fi40f24_from_f64);
 }
+
+#[test]
+fn test_ball_cuboid_closed_form_matches_polyhedron_projection() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let cuboid_pos = Isometry2::new(Vector2::new(0.5, -0.2), 0.3);
+    let ball = Ball::new(0.4);
+
+    for i in 0..20 {
+        for j in 0..20 {
+            let center = Point2::new(i as f64 * 0.2 - 2.0, j as f64 * 0.2 - 2.0);
+            let expected =
+                query::contact_ball_convex_polyhedron(&center, &ball, &cuboid_pos, &cuboid, 0.1);
+            let actual = query::contact_ball_cuboid(&center, &ball, &cuboid_pos, &cuboid, 0.1);
+
+            assert_eq!(expected.is_some(), actual.is_some());
+
+            if let (Some(expected), Some(actual)) = (expected, actual) {
+                assert_relative_eq!(expected.depth, actual.depth, epsilon = 1.0e-7);
+                assert_relative_eq!(*expected.normal, *actual.normal, epsilon = 1.0e-7);
+                assert_relative_eq!(expected.world1, actual.world1, epsilon = 1.0e-7);
+                assert_relative_eq!(expected.world2, actual.world2, epsilon = 1.0e-7);
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use na::{Point2, Vector2};
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid, Polyline};
+use std::f64::consts::PI;
+
+#[test]
+fn ball_boundary() {
+    let ball = Ball::new(2.0f64);
+    assert_relative_eq!(ball.area(), 4.0 * PI);
+    assert_relative_eq!(ball.perimeter(), 4.0 * PI);
+    assert_relative_eq!(ball.curvature(), 0.5);
+
+    let (pt, normal) = ball.boundary_point_at(PI);
+    assert_relative_eq!(pt, Point2::new(0.0, 2.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector2::y(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn cuboid_boundary() {
+    let cuboid = Cuboid::new(Vector2::new(2.0f64, 1.0));
+    assert_relative_eq!(cuboid.area(), 8.0);
+    assert_relative_eq!(cuboid.perimeter(), 12.0);
+
+    let (pt, normal) = cuboid.boundary_point_at(1.0);
+    assert_relative_eq!(pt, Point2::new(2.0, 0.0));
+    assert_relative_eq!(*normal, Vector2::x());
+
+    let (pt, normal) = cuboid.boundary_point_at(3.0);
+    assert_relative_eq!(pt, Point2::new(1.0, 1.0));
+    assert_relative_eq!(*normal, Vector2::y());
+
+    // The abscissa wraps around the perimeter.
+    let (pt, normal) = cuboid.boundary_point_at(-1.0);
+    assert_relative_eq!(pt, Point2::new(1.0, -1.0));
+    assert_relative_eq!(*normal, -Vector2::y());
+}
+
+#[test]
+fn convex_polygon_boundary() {
+    let points = vec![
+        Point2::new(0.0f64, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(0.0, 4.0),
+    ];
+    let polygon = ConvexPolygon::try_new(points).unwrap();
+    assert_relative_eq!(polygon.area(), 6.0);
+    assert_relative_eq!(polygon.perimeter(), 12.0);
+
+    let (pt, normal) = polygon.boundary_point_at(5.5);
+    assert_relative_eq!(pt, Point2::new(1.5, 2.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*normal, Vector2::new(0.8, 0.6), epsilon = 1.0e-7);
+
+    let total: f64 = (0..3).map(|i| polygon.vertex_turning_angle(i)).sum();
+    assert_relative_eq!(total, 2.0 * PI, epsilon = 1.0e-7);
+    assert_relative_eq!(polygon.vertex_turning_angle(0), PI / 2.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn polyline_boundary() {
+    let points = vec![
+        Point2::new(0.0f64, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ];
+    let indices = vec![
+        Point2::new(0, 1),
+        Point2::new(1, 2),
+        Point2::new(2, 3),
+        Point2::new(3, 0),
+    ];
+    let polyline = Polyline::new(points, Some(indices));
+    assert_relative_eq!(polyline.area(), 4.0);
+    assert_relative_eq!(polyline.perimeter(), 8.0);
+
+    let (pt, _) = polyline.boundary_point_at(3.0).unwrap();
+    assert_relative_eq!(pt, Point2::new(2.0, 1.0));
+}
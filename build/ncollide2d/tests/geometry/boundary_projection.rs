@@ -0,0 +1,119 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::query::{PointQuery, PointQueryWithLocation};
+use ncollide2d::shape::{
+    Ball, Capsule, Compound, Cuboid, ShapeHandle, Triangle, TrianglePointLocation,
+};
+
+#[test]
+fn boundary_projection_of_inside_points() {
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), 0.3);
+
+    let ball = Ball::new(2.0f64);
+    let proj = ball.project_point_on_boundary(&m, &(m * Point2::origin()));
+    assert!(proj.is_inside);
+    assert_relative_eq!(na::distance(&proj.point, &(m * Point2::origin())), 2.0);
+
+    let cuboid = Cuboid::new(Vector2::new(2.0, 1.0));
+    let proj = cuboid.project_point_on_boundary(&m, &(m * Point2::new(0.5, 0.25)));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(0.5, 1.0), epsilon = 1.0e-7);
+
+    let capsule = Capsule::new(1.0, 0.5);
+    let proj = capsule.project_point_on_boundary(&m, &(m * Point2::new(0.1, 0.2)));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(0.5, 0.2), epsilon = 1.0e-7);
+
+    // The closest edge is bc.
+    let triangle = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 4.0),
+    );
+    let proj = triangle.project_point_on_boundary(&m, &(m * Point2::new(1.8, 1.8)));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(2.0, 2.0), epsilon = 1.0e-7);
+
+    // The closest edge is ab.
+    let triangle = Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 1.0),
+    );
+    let proj = triangle.project_point_on_boundary(&m, &(m * Point2::new(0.5, 0.05)));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(0.5, 0.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn inside_triangle_projection_on_its_longest_edge() {
+    // Inside points closest to bc used to be projected on another edge because the edge
+    // parameters along ac and bc were swapped when comparing the edge distances.
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), 0.3);
+    let cases = [
+        (
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(3.0, 0.2),
+            4.2 / 17.0,
+        ),
+        (
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(0.3, 2.0),
+            8.7 / 17.0,
+        ),
+    ];
+
+    for (b, c, pt, u) in cases.iter() {
+        let triangle = Triangle::new(Point2::origin(), *b, *c);
+        let (proj, loc) = triangle.project_point_with_location(&m, &(m * pt), false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, m * (b + (c - b) * *u), epsilon = 1.0e-7);
+
+        match loc {
+            TrianglePointLocation::OnEdge(1, bcoords) => {
+                assert_relative_eq!(bcoords[1], *u, epsilon = 1.0e-7)
+            }
+            _ => panic!("The projection should lie on the edge bc."),
+        }
+    }
+}
+
+#[test]
+fn boundary_projection_of_outside_points() {
+    let cuboid = Cuboid::new(Vector2::new(2.0, 1.0));
+    let pt = Point2::new(3.0, 0.5);
+    let proj = cuboid.project_point_on_boundary(&Isometry2::identity(), &pt);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(2.0, 0.5));
+}
+
+#[test]
+fn boundary_projection_on_compound() {
+    // Two overlapping rectangles forming a 6x2 rectangle.
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::new(2.0f64, 1.0)));
+    let compound = Compound::new(vec![
+        (Isometry2::translation(-1.0, 0.0), cuboid.clone()),
+        (Isometry2::translation(1.0, 0.0), cuboid),
+    ]);
+
+    // The closest point on the boundary of each part lies inside of the other part.
+    let pt = Point2::new(0.9, 0.0);
+    let proj = compound.project_point(&Isometry2::identity(), &pt, false);
+    assert!(compound.contains_point(&Isometry2::identity(), &proj.point));
+    assert_relative_eq!(proj.point.x.abs(), 1.0);
+
+    let proj = compound.project_point_on_boundary(&Isometry2::identity(), &pt);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point.x, 0.9, epsilon = 1.0e-7);
+    assert_relative_eq!(proj.point.y.abs(), 1.0, epsilon = 1.0e-7);
+
+    let m = Isometry2::new(Vector2::new(5.0, -1.0), 1.0);
+    let proj = compound.project_point_on_boundary(&m, &(m * Point2::new(-2.5, 0.2)));
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(-3.0, 0.2), epsilon = 1.0e-7);
+
+    let proj = compound.project_point_on_boundary(&m, &(m * Point2::new(5.0, 0.2)));
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, m * Point2::new(3.0, 0.2), epsilon = 1.0e-7);
+}
@@ -0,0 +1,58 @@
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::{BoundingSphere, BoundingVolume, BoundingVolumePair, AABB};
+use ncollide2d::partitioning::{BVH, BVT};
+use ncollide2d::query::visitors::BoundingVolumePairInterferencesCollector;
+
+#[test]
+fn aabb_sphere_conversions() {
+    let sphere = BoundingSphere::new(Point2::new(1.0, 2.0), 0.5);
+    let aabb = sphere.aabb();
+    assert_eq!(aabb.mins, Point2::new(0.5, 1.5));
+    assert_eq!(aabb.maxs, Point2::new(1.5, 2.5));
+    assert!(aabb.contains_sphere(&sphere));
+
+    let aabb = AABB::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0));
+    let sphere = aabb.bounding_sphere();
+    assert_relative_eq!(sphere.radius(), 2.0f64.sqrt());
+    assert!(sphere.contains_aabb(&aabb));
+    assert!(!sphere.tightened(0.01).contains_aabb(&aabb));
+}
+
+#[test]
+fn aabb_sphere_intersections() {
+    let aabb = AABB::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0));
+
+    // Near a corner, the sphere AABB intersects but not the sphere itself.
+    let sphere = BoundingSphere::new(Point2::new(1.6, 1.6), 0.8);
+    assert!(aabb.intersects(&sphere.aabb()));
+    assert!(!aabb.intersects_sphere(&sphere));
+    assert!(!sphere.intersects_bv(&aabb));
+
+    let sphere = BoundingSphere::new(Point2::new(1.5, 1.5), 0.8);
+    assert!(aabb.intersects_bv(&sphere));
+    assert!(sphere.intersects_aabb(&aabb));
+
+    let sphere = BoundingSphere::new(Point2::origin(), 0.2);
+    assert!(aabb.contains_bv(&sphere));
+    assert!(!sphere.contains_bv(&aabb));
+}
+
+#[test]
+fn sphere_query_on_aabb_tree() {
+    let leaves: Vec<_> = (0..10)
+        .map(|i| {
+            let center = Point2::new(i as f64 * 2.0, 0.0);
+            (i, AABB::from_half_extents(center, Vector2::repeat(0.5)))
+        })
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    let sphere = BoundingSphere::new(Point2::new(4.0, 1.0), 0.6);
+    let mut hits = Vec::new();
+    {
+        let mut visitor = BoundingVolumePairInterferencesCollector::new(&sphere, &mut hits);
+        bvt.visit(&mut visitor);
+    }
+
+    assert_eq!(hits, vec![2]);
+}
@@ -0,0 +1,60 @@
+use crate::common::aabb_at;
+use ncollide2d::partitioning::{BVH, BVT};
+use ncollide2d::query::visitors::BoundingVolumeInterferencesCollector;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Entity(u32);
+
+#[test]
+fn bvt_map_leaves_preserves_structure() {
+    let leaves = (0..10).map(|i| (i, aabb_at(i as f32, 0.4))).collect();
+    let bvt = BVT::new_balanced(leaves);
+    let ids: Vec<usize> = bvt.leaves().iter().map(|l| *l.data()).collect();
+
+    let mut bvt = bvt.map_leaves(|i| Entity(i as u32 * 10));
+
+    for (i, leaf) in bvt.leaves().iter().enumerate() {
+        assert_eq!(*leaf.data(), Entity(ids[i] as u32 * 10));
+        assert_eq!(*leaf.bounding_volume(), aabb_at(ids[i] as f32, 0.4));
+    }
+
+    let leaf_id = bvt
+        .leaves()
+        .iter()
+        .position(|l| *l.data() == Entity(30))
+        .unwrap();
+    *bvt.leaf_mut(leaf_id).data_mut() = Entity(42);
+
+    let mut hits = Vec::new();
+    bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+        &aabb_at(3.0, 0.4),
+        &mut hits,
+    ));
+    assert_eq!(hits, vec![Entity(42)]);
+}
+
+#[test]
+fn bvt_refit_leaves_moves_all_leaves() {
+    let leaves = (0..10).map(|i| (i, aabb_at(i as f32, 0.4))).collect();
+    let mut bvt = BVT::new_balanced(leaves);
+
+    // Move every leaf 100 units along x without rebuilding the tree.
+    bvt.refit_leaves(|_, i| aabb_at(*i as f32 + 100.0, 0.4));
+
+    let root = bvt.root_bounding_volume().unwrap();
+    assert_eq!(root.mins.x, 99.6);
+    assert_eq!(root.maxs.x, 109.4);
+
+    let mut hits = Vec::new();
+    bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+        &aabb_at(3.0, 0.4),
+        &mut hits,
+    ));
+    assert!(hits.is_empty());
+
+    bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+        &aabb_at(104.0, 0.4),
+        &mut hits,
+    ));
+    assert_eq!(hits, vec![4]);
+}
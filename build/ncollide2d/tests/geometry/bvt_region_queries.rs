@@ -0,0 +1,46 @@
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::partitioning::BVT;
+
+fn grid_bvt() -> BVT<usize, AABB<f64>> {
+    let leaves = (0..100)
+        .map(|i| {
+            let center = Point2::new((i % 10) as f64, (i / 10) as f64);
+            (i, AABB::from_half_extents(center, Vector2::repeat(0.4)))
+        })
+        .collect();
+    BVT::new_balanced(leaves)
+}
+
+#[test]
+fn bvt_leaves_intersecting_aabb() {
+    let bvt = grid_bvt();
+    let region = AABB::new(Point2::new(1.5, 1.5), Point2::new(3.5, 3.5));
+    let mut found: Vec<usize> = bvt.leaves_intersecting_aabb(&region).cloned().collect();
+    found.sort();
+
+    assert_eq!(found, vec![22, 23, 32, 33]);
+
+    let outside = AABB::new(Point2::new(20.0, 20.0), Point2::new(21.0, 21.0));
+    assert_eq!(bvt.leaves_intersecting_aabb(&outside).count(), 0);
+}
+
+#[test]
+fn bvt_leaves_containing_point() {
+    let bvt = grid_bvt();
+
+    let found: Vec<usize> = bvt
+        .leaves_containing_point(&Point2::new(4.1, 7.2))
+        .cloned()
+        .collect();
+    assert_eq!(found, vec![74]);
+
+    // Points between the leaves are not contained by any of them.
+    assert_eq!(
+        bvt.leaves_containing_point(&Point2::new(4.5, 7.0)).count(),
+        0
+    );
+
+    let empty = BVT::<usize, AABB<f64>>::new_balanced(Vec::new());
+    assert_eq!(empty.leaves_containing_point(&Point2::origin()).count(), 0);
+}
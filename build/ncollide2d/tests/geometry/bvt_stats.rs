@@ -0,0 +1,62 @@
+use crate::common::aabb_at;
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::partitioning::BVT;
+
+#[test]
+fn bvt_stats_of_balanced_tree() {
+    // Leaves along a diagonal are separated along both axes, whichever axis the median
+    // partitioning splits along.
+    let leaves = (0..8)
+        .map(|i| {
+            let center = Point2::new(i as f32, i as f32);
+            (i, AABB::from_half_extents(center, Vector2::repeat(0.4)))
+        })
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+    let stats = bvt.stats();
+
+    assert_eq!(stats.num_leaves, 8);
+    assert_eq!(stats.num_internals, 7);
+    assert_eq!(stats.depth, 4);
+    // Disjoint leaves never overlap.
+    assert_eq!(stats.max_overlap, 0.0);
+    assert_eq!(stats.average_overlap, 0.0);
+    assert!(stats.sah_cost > 1.0 && stats.sah_cost < 15.0);
+    bvt.assert_invariants::<f32>();
+}
+
+#[test]
+fn bvt_stats_detect_overlaps() {
+    // All the leaves are identical: every internal node overlaps completely.
+    let leaves = (0..4).map(|i| (i, aabb_at(0.0, 0.4))).collect();
+    let bvt = BVT::new_balanced(leaves);
+    let stats = bvt.stats();
+
+    assert_relative_eq!(stats.max_overlap, 1.0);
+    assert_relative_eq!(stats.average_overlap, 1.0);
+    assert_relative_eq!(stats.sah_cost, 7.0);
+}
+
+#[test]
+fn bvt_stats_of_empty_and_single_leaf_trees() {
+    let empty = BVT::<usize, AABB<f32>>::new_balanced(Vec::new());
+    assert_eq!(empty.stats().depth, 0);
+    assert_eq!(empty.stats().num_leaves, 0);
+    empty.assert_invariants::<f32>();
+
+    let single = BVT::new_balanced(vec![(0, aabb_at(0.0, 0.4))]);
+    assert_eq!(single.stats().depth, 1);
+    assert_eq!(single.stats().num_internals, 0);
+    assert_relative_eq!(single.stats().sah_cost, 1.0);
+    single.assert_invariants::<f32>();
+}
+
+#[test]
+#[should_panic]
+fn bvt_invariants_detect_unrefitted_leaves() {
+    let leaves = (0..8).map(|i| (i, aabb_at(i as f32, 0.4))).collect();
+    let mut bvt = BVT::new_balanced(leaves);
+    bvt.set_leaf_bounding_volume(3, aabb_at(20.0, 0.4), false);
+    bvt.assert_invariants::<f32>();
+}
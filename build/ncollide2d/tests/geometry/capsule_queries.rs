@@ -0,0 +1,148 @@
+use nalgebra::{Isometry2, Point2, Vector2};
+use ncollide2d::query::algorithms::VoronoiSimplex;
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Capsule, Cuboid, FeatureId, Polyline, RoundedCuboid, Segment, Shape};
+use std::f64;
+
+#[test]
+fn capsule_ray_cast_matches_gjk() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry2::new(Vector2::new(0.1, -0.2), 0.3);
+
+    for i in 0..64 {
+        let angle = i as f64 * f64::consts::PI / 32.0;
+        let origin = Point2::new(angle.cos(), angle.sin()) * 4.0;
+        let ray = Ray::new(origin, Point2::new(0.2, 0.7 - i as f64 * 0.02) - origin);
+        let ls_ray = ray.inverse_transform_by(&m);
+
+        let exact = capsule.toi_and_normal_with_ray(&m, &ray, 10.0, true);
+        let gjk = query::ray_intersection_with_support_map_with_params(
+            &Isometry2::identity(),
+            &capsule,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            10.0,
+            true,
+        );
+
+        match (exact, gjk) {
+            (Some(exact), Some(gjk)) => {
+                assert_relative_eq!(exact.toi, gjk.toi, epsilon = 1.0e-5);
+                assert_relative_eq!(exact.normal, m * gjk.normal, epsilon = 1.0e-3);
+            }
+            (None, None) => {}
+            (exact, gjk) => panic!("Mismatch: {:?} vs. {:?}", exact, gjk),
+        }
+    }
+}
+
+#[test]
+fn capsule_ray_cast_features() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m = Isometry2::identity();
+
+    let down = Ray::new(Point2::new(0.0, 3.0), -Vector2::y());
+    let inter = capsule.toi_and_normal_with_ray(&m, &down, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 1.5);
+    assert_relative_eq!(inter.normal, Vector2::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let right = Ray::new(Point2::new(-3.0, 0.2), Vector2::x());
+    let inter = capsule.toi_and_normal_with_ray(&m, &right, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.5);
+    assert_relative_eq!(inter.normal, -Vector2::x());
+    assert_eq!(inter.feature, FeatureId::Face(3));
+
+    let inner = Ray::new(Point2::new(0.0, 0.0), Vector2::x());
+    let inter = capsule.toi_and_normal_with_ray(&m, &inner, 10.0, false).unwrap();
+    assert_relative_eq!(inter.toi, 0.5);
+    assert_relative_eq!(inter.normal, -Vector2::x());
+    assert_eq!(inter.feature, FeatureId::Face(2));
+    assert_eq!(capsule.toi_with_ray(&m, &inner, 10.0, true), Some(0.0));
+
+    let missed = Ray::new(Point2::new(-3.0, 1.6), Vector2::x());
+    assert!(capsule.toi_with_ray(&m, &missed, 10.0, true).is_none());
+}
+
+#[test]
+fn capsule_nearly_tangent_to_tile_edge() {
+    // A horizontal capsule resting on a floor tile.
+    let capsule = Capsule::new(1.0, 0.5);
+    let m1 = Isometry2::new(Vector2::new(0.3, 0.5 - 1.0e-7), f64::consts::FRAC_PI_2);
+    let tile = Segment::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0));
+    let m2 = Isometry2::identity();
+
+    let c = query::contact_capsule_segment(&m1, &capsule, &m2, &tile, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 1.0e-7, epsilon = 1.0e-12);
+    assert_relative_eq!(*c.normal, -Vector2::y(), epsilon = 1.0e-12);
+
+    let c = query::contact(&m2, &tile, &m1, &capsule, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 1.0e-7, epsilon = 1.0e-12);
+    assert_relative_eq!(*c.normal, Vector2::y(), epsilon = 1.0e-12);
+
+    // The same capsule on a floor made of several tiles.
+    let floor = Polyline::new(
+        vec![
+            Point2::new(-2.0, 0.0),
+            Point2::new(0.0, 0.0),
+            Point2::new(0.3, 0.0),
+            Point2::new(2.0, 0.0),
+        ],
+        None,
+    );
+    let c = query::contact(&m1, &capsule, &m2, &floor, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 1.0e-7, epsilon = 1.0e-12);
+    assert_relative_eq!(*c.normal, -Vector2::y(), epsilon = 1.0e-12);
+
+    let separated = Isometry2::new(Vector2::new(0.3, 0.6), f64::consts::FRAC_PI_2);
+    assert!(query::contact(&separated, &capsule, &m2, &floor, 0.05).is_none());
+    let c = query::contact(&separated, &capsule, &m2, &floor, 0.2).unwrap();
+    assert_relative_eq!(c.depth, -0.1, epsilon = 1.0e-12);
+}
+
+#[test]
+fn capsule_capsule_contact() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(0.9, 0.5), 0.0);
+
+    let c = query::contact(&m1, &capsule, &m2, &capsule, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-12);
+    assert_relative_eq!(*c.normal, Vector2::x(), epsilon = 1.0e-12);
+    assert_relative_eq!(c.world1.x, 0.5, epsilon = 1.0e-12);
+    assert_relative_eq!(c.world2.x, 0.4, epsilon = 1.0e-12);
+}
+
+#[test]
+fn rounded_cuboid_queries() {
+    let shape = RoundedCuboid::new(Vector2::new(1.0, 0.5), 0.25);
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+
+    let aabb = shape.aabb(&m);
+    assert_relative_eq!(*aabb.mins(), Point2::new(-0.25, 1.25));
+    assert_relative_eq!(*aabb.maxs(), Point2::new(2.25, 2.75));
+
+    // Projection on a rounded corner.
+    let proj = shape.project_point(&m, &Point2::new(3.0, 3.5), true);
+    assert!(!proj.is_inside);
+    let corner = Point2::new(2.0, 2.5);
+    let expected = corner + (Point2::new(3.0, 3.5) - corner).normalize() * 0.25;
+    assert_relative_eq!(proj.point, expected, epsilon = 1.0e-12);
+
+    // Projection from the inside.
+    let proj = shape.project_point(&m, &Point2::new(1.5, 2.4), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.5, 2.75), epsilon = 1.0e-12);
+
+    // Resting on a floor tile.
+    let tile = Segment::new(Point2::new(-3.0, 0.0), Point2::new(3.0, 0.0));
+    let m = Isometry2::new(Vector2::new(1.0, 0.7), 0.0);
+    let c = query::contact(&m, &shape, &Isometry2::identity(), &tile, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.05, epsilon = 1.0e-9);
+    assert_relative_eq!(*c.normal, -Vector2::y(), epsilon = 1.0e-9);
+
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let m2 = Isometry2::new(Vector2::new(1.0, -1.0), 0.0);
+    let c = query::contact(&m, &shape, &m2, &cuboid, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.05, epsilon = 1.0e-9);
+}
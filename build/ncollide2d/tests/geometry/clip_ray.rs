@@ -0,0 +1,47 @@
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::{BoundingSphere, AABB};
+use ncollide2d::query::Ray;
+
+#[test]
+fn aabb_clip_ray_parameters() {
+    let aabb = AABB::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0));
+
+    let ray = Ray::new(Point2::new(-3.0, 0.0), Vector2::x());
+    assert_eq!(aabb.clip_ray_parameters(&ray), Some((2.0, 4.0)));
+
+    // Starting inside.
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    assert_eq!(aabb.clip_ray_parameters(&ray), Some((0.0, 1.0)));
+
+    // Pointing away.
+    let ray = Ray::new(Point2::new(3.0, 0.0), Vector2::x());
+    assert_eq!(aabb.clip_ray_parameters(&ray), None);
+}
+
+#[test]
+fn bounding_sphere_clip_ray_parameters() {
+    let sphere = BoundingSphere::new(Point2::new(1.0, 0.0), 2.0);
+
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x() * 2.0);
+    let (t0, t1) = sphere.clip_ray_parameters(&ray).unwrap();
+    assert_relative_eq!(t0, 2.0);
+    assert_relative_eq!(t1, 4.0);
+
+    let (t0, t1) = sphere
+        .clip_line_parameters(&Point2::new(5.0, 0.0), &Vector2::x())
+        .unwrap();
+    assert_relative_eq!(t0, -6.0);
+    assert_relative_eq!(t1, -2.0);
+
+    // Starting inside.
+    let ray = Ray::new(Point2::new(1.0, 0.0), Vector2::y());
+    let seg = sphere.clip_ray(&ray).unwrap();
+    assert_relative_eq!(*seg.a(), Point2::new(1.0, 0.0));
+    assert_relative_eq!(*seg.b(), Point2::new(1.0, 2.0));
+
+    // Pointing away or missing.
+    let ray = Ray::new(Point2::new(5.0, 0.0), Vector2::x());
+    assert_eq!(sphere.clip_ray_parameters(&ray), None);
+    let ray = Ray::new(Point2::new(-5.0, 3.0), Vector2::x());
+    assert_eq!(sphere.clip_ray_parameters(&ray), None);
+}
@@ -0,0 +1,77 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, PointQuery};
+use ncollide2d::shape::{Cuboid, Polyline, Shape};
+
+fn particles() -> Vec<Point2<f64>> {
+    (0..400)
+        .map(|i| {
+            let (x, y) = ((i % 20) as f64, (i / 20) as f64);
+            Point2::new(x * 0.3 - 3.0, y * 0.3 - 3.0)
+        })
+        .collect()
+}
+
+fn shapes() -> Vec<Box<dyn Shape<f64>>> {
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(-2.0, -1.0),
+            Point2::new(0.0, 1.5),
+            Point2::new(2.0, -1.0),
+            Point2::new(2.5, 2.0),
+        ],
+        None,
+    );
+
+    vec![
+        Box::new(Cuboid::new(Vector2::new(1.0, 0.5))),
+        Box::new(polyline),
+    ]
+}
+
+#[test]
+fn batch_projection_matches_single_projections() {
+    let m = Isometry2::new(Vector2::new(0.2, -0.3), 0.4);
+    let points = particles();
+
+    for shape in shapes() {
+        let shape = shape.as_ref();
+        let mut projs = Vec::new();
+        shape.project_points(&m, &points, false, &mut projs);
+        assert_eq!(projs.len(), points.len());
+
+        for (pt, proj) in points.iter().zip(&projs) {
+            let expected = shape.project_point(&m, pt, false);
+            assert_eq!(proj.is_inside, expected.is_inside);
+            assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-9);
+        }
+    }
+}
+
+#[test]
+fn closest_points_shape_pointcloud_filters_by_distance() {
+    let m = Isometry2::new(Vector2::new(0.2, -0.3), 0.4);
+    let points = particles();
+    let max_dist = 0.5;
+
+    for shape in shapes() {
+        let shape = shape.as_ref();
+        let res = query::closest_points_shape_pointcloud(&m, shape, &points, max_dist);
+        assert_eq!(res.len(), points.len());
+        assert!(res.iter().any(|proj| proj.is_some()));
+        assert!(res.iter().any(|proj| proj.is_none()));
+
+        for (pt, proj) in points.iter().zip(&res) {
+            let expected = shape.project_point(&m, pt, false);
+            let dist = na::distance(pt, &expected.point);
+
+            match proj {
+                Some(proj) => {
+                    assert!(expected.is_inside || dist <= max_dist + 1.0e-9);
+                    assert_eq!(proj.is_inside, expected.is_inside);
+                    assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-9);
+                }
+                None => assert!(!expected.is_inside && dist >= max_dist - 1.0e-9),
+            }
+        }
+    }
+}
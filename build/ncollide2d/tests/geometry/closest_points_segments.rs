@@ -0,0 +1,73 @@
+use na::{Point2, Vector2};
+use ncollide2d::query::{self, Ray};
+
+#[test]
+fn segment_segment_closest_parameters() {
+    let (a1, b1) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0));
+    let (a2, b2) = (Point2::new(1.0, 1.0), Point2::new(3.0, 3.0));
+
+    let (s, t) = query::closest_points_segment_segment_parameters((&a1, &b1), (&a2, &b2));
+    assert_relative_eq!(s, 0.25, epsilon = 1.0e-7);
+    assert_relative_eq!(t, 0.0, epsilon = 1.0e-7);
+
+    // Parallel overlapping segments: the closest points lie on the overlap.
+    let (a2, b2) = (Point2::new(3.0, 1.0), Point2::new(6.0, 1.0));
+    let (s, t, parallel) =
+        query::closest_points_segment_segment_parameters_eps((&a1, &b1), (&a2, &b2), 1.0e-7);
+    assert!(parallel);
+    let (p1, p2) = (a1 + (b1 - a1) * s, a2 + (b2 - a2) * t);
+    assert_relative_eq!(p2 - p1, Vector2::y(), epsilon = 1.0e-7);
+    assert!(p1.x >= 3.0 && p1.x <= 4.0);
+
+    // Parallel disjoint segments, with opposite directions.
+    let (a2, b2) = (Point2::new(9.0, 1.0), Point2::new(6.0, 1.0));
+    let (s, t, parallel) =
+        query::closest_points_segment_segment_parameters_eps((&a1, &b1), (&a2, &b2), 1.0e-7);
+    assert!(parallel);
+    assert_relative_eq!(s, 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(t, 1.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn ray_ray_closest_points() {
+    let ray1 = Ray::new(Point2::origin(), Vector2::x());
+
+    let ray2 = Ray::new(Point2::new(2.0, -2.0), Vector2::y());
+    let (s, t) = query::closest_points_ray_ray_parameters(&ray1, &ray2);
+    assert_relative_eq!(s, 2.0, epsilon = 1.0e-7);
+    assert_relative_eq!(t, 2.0, epsilon = 1.0e-7);
+
+    // The intersection of the supporting lines is behind the second ray.
+    let ray2 = Ray::new(Point2::new(2.0, 1.0), Vector2::y());
+    let (p1, p2) = query::closest_points_ray_ray(&ray1, &ray2);
+    assert_relative_eq!(p1, Point2::new(2.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(p2, Point2::new(2.0, 1.0), epsilon = 1.0e-7);
+
+    // Parallel rays facing each other.
+    let ray2 = Ray::new(Point2::new(5.0, 1.0), -Vector2::x());
+    let (s, t, parallel) = query::closest_points_ray_ray_parameters_eps(&ray1, &ray2, 1.0e-7);
+    assert!(parallel);
+    assert_relative_eq!(
+        ray2.point_at(t) - ray1.point_at(s),
+        Vector2::y(),
+        epsilon = 1.0e-7
+    );
+
+    // Parallel rays pointing away from each other.
+    let ray2 = Ray::new(Point2::new(-1.0, 1.0), -Vector2::x());
+    let (p1, p2) = query::closest_points_ray_ray(&ray1, &ray2);
+    assert_relative_eq!(p1, Point2::origin(), epsilon = 1.0e-7);
+    assert_relative_eq!(p2, Point2::new(-1.0, 1.0), epsilon = 1.0e-7);
+
+    // Lines give the same result as rays when the closest points lie in front of the origins.
+    let ray2 = Ray::new(Point2::new(2.0, -2.0), Vector2::new(1.0, 1.0));
+    let (s, t) = query::closest_points_ray_ray_parameters(&ray1, &ray2);
+    let (ls, lt) = query::closest_points_line_line_parameters(
+        &ray1.origin,
+        &ray1.dir,
+        &ray2.origin,
+        &ray2.dir,
+    );
+    assert_relative_eq!(s, ls, epsilon = 1.0e-7);
+    assert_relative_eq!(t, lt, epsilon = 1.0e-7);
+}
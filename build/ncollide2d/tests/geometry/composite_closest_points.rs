@@ -0,0 +1,158 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, ClosestPoints, ClosestPointsMargin};
+use ncollide2d::shape::{Ball, Compound, Cuboid, Polyline, ShapeHandle};
+
+fn compound_grid(n: usize) -> Compound<f32> {
+    let mut shapes = Vec::new();
+
+    for i in 0..n {
+        for j in 0..n {
+            let pos = Isometry2::translation(i as f32 * 2.0, j as f32 * 2.0);
+            let shape = if (i + j) % 2 == 0 {
+                ShapeHandle::new(Ball::new(0.5f32))
+            } else {
+                ShapeHandle::new(Cuboid::new(Vector2::new(0.4, 0.3)))
+            };
+            shapes.push((pos, shape));
+        }
+    }
+
+    Compound::new(shapes)
+}
+
+fn brute_force_distance(
+    c1: &Compound<f32>,
+    m1: &Isometry2<f32>,
+    c2: &Compound<f32>,
+    m2: &Isometry2<f32>,
+) -> f32 {
+    let mut best = f32::MAX;
+
+    for (pos1, s1) in c1.shapes() {
+        for (pos2, s2) in c2.shapes() {
+            let d = query::distance(&(m1 * pos1), &**s1, &(m2 * pos2), &**s2);
+            best = best.min(d);
+        }
+    }
+
+    best
+}
+
+#[test]
+fn composite_composite_closest_points() {
+    let c1 = compound_grid(5);
+    let c2 = compound_grid(4);
+    let m1 = Isometry2::new(Vector2::new(-1.0, 0.5), 0.3);
+    let m2 = Isometry2::new(Vector2::new(12.0, 3.0), -0.7);
+
+    let expected = brute_force_distance(&c1, &m1, &c2, &m2);
+
+    match query::closest_points(&m1, &c1, &m2, &c2, 100.0) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert!((na::distance(&p1, &p2) - expected).abs() < 1.0e-4)
+        }
+        _ => panic!("Closest points not found."),
+    }
+
+    assert!(
+        match query::closest_points(&m1, &c1, &m2, &c2, expected * 0.5) {
+            ClosestPoints::Disjoint => true,
+            _ => false,
+        }
+    );
+
+    let m2 = Isometry2::translation(1.0, 1.0);
+    assert!(match query::closest_points(&m1, &c1, &m2, &c2, 1.0) {
+        ClosestPoints::Intersecting => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn polyline_polyline_closest_points() {
+    let points1 = (0..50)
+        .map(|i| Point2::new(i as f32 * 0.2, (i as f32 * 0.3).sin()))
+        .collect();
+    let points2 = (0..50)
+        .map(|i| Point2::new(i as f32 * 0.2, 3.0 + (i as f32 * 0.5).cos()))
+        .collect();
+    let polyline1 = Polyline::new(points1, None);
+    let polyline2 = Polyline::new(points2, None);
+    let m = Isometry2::identity();
+
+    let mut expected = f32::MAX;
+    for i in 0..polyline1.edges().len() {
+        for j in 0..polyline2.edges().len() {
+            let d = query::distance(&m, &polyline1.segment_at(i), &m, &polyline2.segment_at(j));
+            expected = expected.min(d);
+        }
+    }
+
+    match query::closest_points(&m, &polyline1, &m, &polyline2, 10.0) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert!((na::distance(&p1, &p2) - expected).abs() < 1.0e-4)
+        }
+        _ => panic!("Closest points not found."),
+    }
+}
+
+#[test]
+fn per_axis_margin_closest_points() {
+    let grid = compound_grid(5);
+    let ball = Ball::new(0.5f32);
+    let m = Isometry2::identity();
+    let margin = ClosestPointsMargin::PerAxis(Vector2::new(2.5, 0.2));
+
+    // Two units above the top row, and two units right of the last column.
+    let above = Isometry2::translation(4.0, 11.0);
+    let right = Isometry2::translation(11.0, 4.0);
+
+    let isotropic = ClosestPointsMargin::Distance(2.5);
+    assert!(
+        match query::closest_points_with_margin(&m, &grid, &above, &ball, &isotropic) {
+            ClosestPoints::WithinMargin(..) => true,
+            _ => false,
+        }
+    );
+    assert_eq!(
+        query::closest_points_with_margin(&m, &grid, &above, &ball, &margin),
+        ClosestPoints::Disjoint
+    );
+
+    match query::closest_points_with_margin(&m, &grid, &right, &ball, &margin) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_relative_eq!(p1, Point2::new(8.5, 4.0), epsilon = 1.0e-5);
+            assert_relative_eq!(p2, Point2::new(10.5, 4.0), epsilon = 1.0e-5);
+        }
+        _ => panic!("Closest points not found."),
+    }
+
+    // The margin is expressed in world-space, whatever the orientation of the shapes.
+    let rotation = Isometry2::rotation(0.5);
+    let right = rotation * right;
+    assert_eq!(
+        query::closest_points_with_margin(&rotation, &grid, &right, &ball, &margin),
+        ClosestPoints::Disjoint
+    );
+    let loose = ClosestPointsMargin::PerAxis(Vector2::new(2.5, 1.0));
+    assert!(
+        match query::closest_points_with_margin(&rotation, &grid, &right, &ball, &loose) {
+            ClosestPoints::WithinMargin(..) => true,
+            _ => false,
+        }
+    );
+
+    // Shapes that are not composite are supported too.
+    let other = Isometry2::translation(0.0, 3.0);
+    assert_eq!(
+        query::closest_points_with_margin(&m, &ball, &other, &ball, &margin),
+        ClosestPoints::Disjoint
+    );
+    let margin = ClosestPointsMargin::PerAxis(Vector2::new(0.2, 2.5));
+    assert!(
+        match query::closest_points_with_margin(&m, &ball, &other, &ball, &margin) {
+            ClosestPoints::WithinMargin(..) => true,
+            _ => false,
+        }
+    );
+}
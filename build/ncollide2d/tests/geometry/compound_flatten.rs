@@ -0,0 +1,65 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, PointQuery};
+use ncollide2d::shape::{Ball, Compound, Cuboid, ShapeHandle};
+
+fn nested_compound() -> Compound<f64> {
+    let inner = Compound::new(vec![
+        (
+            Isometry2::new(Vector2::new(1.0, 0.0), na::zero()),
+            ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5))),
+        ),
+        (
+            Isometry2::new(Vector2::new(0.0, 2.0), 0.3),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ]);
+
+    Compound::new(vec![
+        (
+            Isometry2::new(Vector2::new(-3.0, 0.0), na::zero()),
+            ShapeHandle::new(Ball::new(1.0)),
+        ),
+        (
+            Isometry2::new(Vector2::new(2.0, 1.0), std::f64::consts::FRAC_PI_2),
+            ShapeHandle::new(inner),
+        ),
+    ])
+}
+
+#[test]
+fn flatten_composes_isometries() {
+    let nested = nested_compound();
+    let flat = nested.flatten();
+    assert_eq!(flat.shapes().len(), 3);
+
+    for (pos, shape) in flat.shapes() {
+        assert!(shape.as_shape::<Compound<f64>>().is_none());
+        assert!(shape.contains_point(pos, &(pos * Point2::origin())));
+    }
+
+    let expected = Isometry2::new(Vector2::new(2.0, 1.0), std::f64::consts::FRAC_PI_2)
+        * Isometry2::new(Vector2::new(1.0, 0.0), na::zero());
+    assert_relative_eq!(flat.shapes()[1].0, expected, epsilon = 1.0e-10);
+}
+
+#[test]
+fn nested_compound_queries_match_flattened_compound() {
+    let nested = nested_compound();
+    let flat = nested.flatten();
+    let ball = Ball::new(0.25);
+    let m1 = Isometry2::identity();
+
+    for x in [-4.0, -1.0, 1.5, 2.2, 5.0].iter() {
+        let m2 = Isometry2::new(Vector2::new(*x, 1.9), na::zero());
+
+        let expected = query::distance(&m1, &flat, &m2, &ball);
+        let actual = query::distance(&m1, &nested, &m2, &ball);
+        assert_relative_eq!(expected, actual, epsilon = 1.0e-10);
+
+        let pt = Point2::new(*x, 1.9);
+        assert_eq!(
+            flat.contains_point(&m1, &pt),
+            nested.contains_point(&m1, &pt)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::{PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Compound, Cuboid, ShapeHandle};
+
+const CHASSIS: u32 = 0b01;
+const WHEELS: u32 = 0b10;
+
+// A chassis centered at the origin, with a wheel below it.
+fn vehicle() -> Compound<f64> {
+    Compound::new(vec![
+        (
+            Isometry2::identity(),
+            ShapeHandle::new(Cuboid::new(Vector2::new(2.0, 0.5))),
+        ),
+        (
+            Isometry2::new(Vector2::new(0.0, -1.0), na::zero()),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ])
+    .with_child_masks(vec![CHASSIS, WHEELS])
+}
+
+#[test]
+fn compound_filtered_ray_casts() {
+    let vehicle = vehicle();
+    let m = Isometry2::identity();
+    let from_below = Ray::new(Point2::new(0.0, -5.0), Vector2::y());
+
+    let toi = vehicle.toi_with_ray(&m, &from_below, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-7);
+
+    // The wheel no longer blocks the ray.
+    let inter = vehicle
+        .toi_and_normal_with_ray_filtered(&m, &from_below, 100.0, true, CHASSIS)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 4.5, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, -Vector2::y(), epsilon = 1.0e-7);
+    let toi = vehicle
+        .toi_with_ray_filtered(&m, &from_below, 100.0, true, WHEELS)
+        .unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-7);
+    assert!(vehicle
+        .toi_with_ray_filtered(&m, &from_below, 100.0, true, 0)
+        .is_none());
+}
+
+#[test]
+fn compound_filtered_point_projections() {
+    let mut vehicle = vehicle();
+    let m = Isometry2::identity();
+    let below = Point2::new(0.0, -3.0);
+
+    let proj = vehicle.project_point(&m, &below, true);
+    assert_relative_eq!(proj.point, Point2::new(0.0, -1.5), epsilon = 1.0e-7);
+
+    let proj = vehicle
+        .project_point_filtered(&m, &below, true, CHASSIS)
+        .unwrap();
+    assert_relative_eq!(proj.point, Point2::new(0.0, -0.5), epsilon = 1.0e-7);
+
+    vehicle.set_child_mask(0, WHEELS);
+    assert!(vehicle
+        .project_point_filtered(&m, &below, true, CHASSIS)
+        .is_none());
+}
+
+#[test]
+fn compound_unfiltered_queries_ignore_masks() {
+    let vehicle = vehicle().with_child_masks(vec![0, 0]);
+    let m = Isometry2::identity();
+    let below = Point2::new(0.0, -3.0);
+
+    let proj = vehicle.project_point(&m, &below, true);
+    assert_relative_eq!(proj.point, Point2::new(0.0, -1.5), epsilon = 1.0e-7);
+    assert!(vehicle.contains_point(&m, &Point2::origin()));
+
+    let from_below = Ray::new(Point2::new(0.0, -5.0), Vector2::y());
+    let toi = vehicle.toi_with_ray(&m, &from_below, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-7);
+    assert!(vehicle
+        .project_point_filtered(&m, &below, true, u32::MAX)
+        .is_none());
+    assert!(vehicle
+        .toi_and_normal_with_ray_filtered(&m, &from_below, 100.0, true, u32::MAX)
+        .is_none());
+}
+
+#[test]
+fn compound_contact_filter() {
+    let num_contacts = |contact_filter| {
+        let mut vehicle = vehicle();
+        vehicle.set_contact_filter(contact_filter);
+
+        let mut world = CollisionWorld::new(0.02);
+        let groups = CollisionGroups::new();
+        let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+        let vehicle = world
+            .add(
+                Isometry2::identity(),
+                ShapeHandle::new(vehicle),
+                groups,
+                contacts,
+                (),
+            )
+            .0;
+        // A ball touching the wheel only.
+        let _ = world.add(
+            Isometry2::translation(0.0, -1.9),
+            ShapeHandle::new(Ball::new(0.5)),
+            groups,
+            contacts,
+            (),
+        );
+        world.update();
+
+        world
+            .contacts_with(vehicle, true)
+            .unwrap()
+            .map(|(_, _, _, manifold)| manifold.len())
+            .sum::<usize>()
+    };
+
+    assert!(num_contacts(u32::MAX) > 0);
+    assert_eq!(num_contacts(CHASSIS), 0);
+}
+
+#[test]
+fn flattened_compound_masks() {
+    let inner = vehicle();
+    let outer = Compound::new(vec![
+        (Isometry2::identity(), ShapeHandle::new(inner)),
+        (
+            Isometry2::translation(5.0, 0.0),
+            ShapeHandle::new(Ball::new(1.0)),
+        ),
+    ])
+    .with_child_masks(vec![CHASSIS | 0b100, 0b111]);
+
+    let flat = outer.flatten();
+    assert_eq!(flat.child_masks(), &[CHASSIS, 0, 0b111][..]);
+    assert!(flat.child_matches_filter(2, WHEELS));
+    assert!(!flat.child_matches_filter(1, u32::MAX));
+}
@@ -0,0 +1,53 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query;
+use ncollide2d::shape::{Ball, Cuboid, Plane, Polyline, Scaled, Shape, ShapeHandle};
+
+#[test]
+fn shapes_report_their_convexity() {
+    let convex = [
+        ShapeHandle::new(Ball::new(1.0)),
+        ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 2.0))),
+        ShapeHandle::new(Scaled::new(Ball::new(1.0), Vector2::new(2.0, 1.0))),
+    ];
+    let concave = [
+        ShapeHandle::new(Plane::new(Vector2::y_axis())),
+        ShapeHandle::new(Polyline::new(
+            vec![
+                Point2::origin(),
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 1.0),
+            ],
+            None,
+        )),
+        ShapeHandle::new(Scaled::new(
+            Plane::new(Vector2::y_axis()),
+            Vector2::new(2.0, 1.0),
+        )),
+    ];
+
+    for shape in &convex {
+        assert!(shape.is_convex());
+        assert!(shape.as_convex().is_some());
+    }
+
+    for shape in &concave {
+        assert!(!shape.is_convex());
+        assert!(shape.as_convex().is_none());
+    }
+}
+
+#[test]
+fn convex_shapes_can_be_used_with_gjk() {
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 2.0)));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(5.0, 0.0), 0.0);
+
+    let convex1 = ball.as_convex().unwrap();
+    let convex2 = cuboid.as_convex().unwrap();
+    let dist = query::distance_support_map_support_map(&m1, convex1, &m2, convex2);
+    assert_relative_eq!(dist, 3.0, epsilon = 1.0e-7);
+
+    let shape: &dyn Shape<f64> = convex1;
+    assert!(shape.is_shape::<Ball<f64>>());
+}
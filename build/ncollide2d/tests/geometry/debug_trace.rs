@@ -0,0 +1,61 @@
+use nalgebra::{Isometry2, Vector2};
+use ncollide2d::query;
+use ncollide2d::query::algorithms::DebugTrace;
+use ncollide2d::shape::{Ball, Cuboid};
+
+#[test]
+fn trace_penetrating_shapes() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::new(Vector2::new(0.0, 0.0), 0.3);
+    let m2 = Isometry2::new(Vector2::new(0.5, 0.6), 0.0);
+
+    let mut trace = DebugTrace::new();
+    let traced = query::contact_support_map_support_map_with_trace(
+        &m1, &cuboid, &m2, &ball, 0.0, &mut trace,
+    )
+    .unwrap();
+    let untraced = query::contact_support_map_support_map(&m1, &cuboid, &m2, &ball, 0.0).unwrap();
+
+    assert_eq!(traced.depth, untraced.depth);
+    assert_eq!(traced.normal, untraced.normal);
+    assert!(!trace.gjk.is_empty());
+    assert!(!trace.epa.is_empty());
+
+    for step in &trace.gjk {
+        assert!(!step.simplex.is_empty() && step.simplex.len() <= 3);
+    }
+
+    for step in &trace.epa {
+        // The polytope boundary is a closed polygon: each of its vertices belongs to two faces.
+        let mut counts = vec![0; step.vertices.len()];
+
+        for face in &step.faces {
+            counts[face[0]] += 1;
+            counts[face[1]] += 1;
+        }
+
+        assert!(counts.iter().all(|c| *c == 0 || *c == 2));
+        assert!(step.faces.len() >= 3);
+    }
+}
+
+#[test]
+fn trace_disjoint_shapes() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.2), 0.1);
+
+    let mut trace = DebugTrace::new();
+    let contact = query::contact_support_map_support_map_with_trace(
+        &m1, &cuboid, &m2, &cuboid, 2.0, &mut trace,
+    )
+    .unwrap();
+
+    assert!(contact.depth < 0.0);
+    assert!(!trace.gjk.is_empty());
+    assert!(trace.epa.is_empty());
+
+    trace.clear();
+    assert!(trace.gjk.is_empty());
+}
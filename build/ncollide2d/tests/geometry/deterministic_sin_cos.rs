@@ -0,0 +1,40 @@
+use ncollide2d::utils;
+use std::f64;
+
+#[test]
+fn deterministic_sin_cos_matches_std() {
+    let mut angle = -10.0f64;
+
+    while angle <= 10.0 {
+        let (s, c) = utils::sin_cos(angle);
+        assert_relative_eq!(s, angle.sin(), epsilon = 1.0e-14);
+        assert_relative_eq!(c, angle.cos(), epsilon = 1.0e-14);
+        angle += 0.01;
+    }
+
+    let one_degree = f64::consts::PI / 180.0;
+    assert_relative_eq!(utils::cos(one_degree), one_degree.cos(), epsilon = 1.0e-16);
+    assert_relative_eq!(utils::sin(one_degree), one_degree.sin(), epsilon = 1.0e-16);
+}
+
+#[test]
+fn deterministic_inverse_trigonometry_matches_std() {
+    let mut x = -1.0f64;
+
+    while x <= 1.0 {
+        assert_relative_eq!(utils::acos(x), x.acos(), epsilon = 1.0e-14);
+        assert_relative_eq!(utils::asin(x), x.asin(), epsilon = 1.0e-14);
+        x += 0.01;
+    }
+
+    let mut angle = -3.0f64;
+
+    while angle <= 3.0 {
+        let (y, x) = (angle.sin() * 2.0, angle.cos() * 2.0);
+        assert_relative_eq!(utils::atan2(y, x), y.atan2(x), epsilon = 1.0e-14);
+        angle += 0.01;
+    }
+
+    assert_eq!(utils::atan2(0.0f64, 0.0), 0.0);
+    assert_relative_eq!(utils::atan2(0.0f64, -1.0), f64::consts::PI);
+}
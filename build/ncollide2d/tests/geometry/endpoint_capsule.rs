@@ -0,0 +1,111 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, BoundingSphere, AABB};
+use ncollide2d::pipeline::snapshot;
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Capsule, SupportMap};
+use ncollide2d::transformation::ToPolyline;
+use ncollide2d::volumetric::Volumetric;
+
+#[test]
+fn endpoint_capsule_matches_transformed_capsule() {
+    let a = Point2::new(1.0, 2.0);
+    let b = Point2::new(3.0, -1.0);
+    let capsule = Capsule::new_with_endpoints(a, b, 0.5);
+    let segment = capsule.segment();
+    assert_relative_eq!(segment.a, a, epsilon = 1.0e-7);
+    assert_relative_eq!(segment.b, b, epsilon = 1.0e-7);
+    assert_relative_eq!(capsule.half_height, na::distance(&a, &b) / 2.0);
+
+    // The same capsule, aligned with the `y` axis then moved into place.
+    let frame = *capsule.local_frame().unwrap();
+    let aligned = capsule.aligned();
+    let m = Isometry2::new(Vector2::new(-0.3, 0.7), 0.4);
+    let aligned_m = m * frame;
+
+    for i in 0..20 {
+        let t = i as f64;
+        let dir = Vector2::new((t * 0.37).sin(), (t * 0.71).cos() + 0.1);
+        assert_relative_eq!(
+            capsule.support_point(&m, &dir),
+            aligned.support_point(&aligned_m, &dir),
+            epsilon = 1.0e-7
+        );
+
+        let pt = m * Point2::new(2.0 + dir.x * 3.0, 0.5 + dir.y * 3.0);
+        let proj = capsule.project_point(&m, &pt, false);
+        let expected = aligned.project_point(&aligned_m, &pt, false);
+        assert_eq!(proj.is_inside, expected.is_inside);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-7);
+
+        let ray = Ray::new(pt, m * Point2::new(2.0, 0.5) - pt);
+        let hit = capsule
+            .toi_and_normal_with_ray(&m, &ray, 10.0, true)
+            .unwrap();
+        let expected = aligned
+            .toi_and_normal_with_ray(&aligned_m, &ray, 10.0, true)
+            .unwrap();
+        assert_relative_eq!(hit.toi, expected.toi, epsilon = 1.0e-7);
+        assert_relative_eq!(hit.normal, expected.normal, epsilon = 1.0e-7);
+        assert_eq!(hit.feature, expected.feature);
+    }
+
+    let aabb: AABB<f64> = bounding_volume::aabb(&capsule, &m);
+    let expected: AABB<f64> = bounding_volume::aabb(&aligned, &aligned_m);
+    assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-7);
+    assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-7);
+
+    let sphere: BoundingSphere<f64> = bounding_volume::bounding_sphere(&capsule, &m);
+    assert_relative_eq!(*sphere.center(), m * na::center(&a, &b), epsilon = 1.0e-7);
+    assert_relative_eq!(sphere.radius(), capsule.half_height + 0.5);
+
+    assert_relative_eq!(capsule.volume(), aligned.volume());
+    assert_relative_eq!(
+        capsule.center_of_mass(),
+        na::center(&a, &b),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        capsule.unit_angular_inertia(),
+        aligned.unit_angular_inertia()
+    );
+
+    let polyline = capsule.to_polyline(10);
+    let expected = aligned.to_polyline(10).transformed(&frame);
+    for (p1, p2) in polyline.coords().iter().zip(expected.coords().iter()) {
+        assert_relative_eq!(p1, p2, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn endpoint_capsule_contacts() {
+    let capsule = Capsule::new_with_endpoints(Point2::new(-1.0, 1.0), Point2::new(1.0, 1.0), 0.5);
+    let ball = Ball::new(0.5);
+    let m = Isometry2::identity();
+
+    // The capsule is horizontal, so the ball right below its middle touches it.
+    let contact =
+        query::contact(&m, &capsule, &Isometry2::translation(0.2, 0.1), &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-7);
+    assert_relative_eq!(*contact.normal, -Vector2::y(), epsilon = 1.0e-7);
+
+    assert!(capsule.contains_point(&m, &Point2::new(1.4, 1.0)));
+    assert!(!capsule.contains_point(&m, &Point2::new(0.0, 0.0)));
+
+    // Endpoints symmetric wrt. the origin along `y` give a regular capsule.
+    let regular = Capsule::new_with_endpoints(Point2::new(0.0, -1.0), Point2::new(0.0, 1.0), 0.5);
+    assert!(regular.local_frame().is_none());
+    assert_eq!(regular, Capsule::new(1.0, 0.5));
+}
+
+#[test]
+fn endpoint_capsule_snapshot() {
+    let capsule = Capsule::new_with_endpoints(Point2::new(1.0, 2.0), Point2::new(3.0, -1.0), 0.5);
+    let mut bytes = Vec::new();
+    snapshot::write_shape::<f64>(&capsule, &mut bytes).unwrap();
+    let shape = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    let read = shape.as_shape::<Capsule<f64>>().unwrap();
+
+    assert_relative_eq!(read.segment().a, capsule.segment().a, epsilon = 1.0e-7);
+    assert_relative_eq!(read.segment().b, capsule.segment().b, epsilon = 1.0e-7);
+    assert_eq!(read.radius, capsule.radius);
+}
@@ -0,0 +1,57 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::algorithms::gjk::{self, RaycastOptions, RaycastStatus};
+use ncollide2d::query::algorithms::VoronoiSimplex;
+use ncollide2d::query::Ray;
+use ncollide2d::shape::Ball;
+
+#[test]
+fn gjk_raycast_reports_convergence() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::new(-5.0, 0.5), Vector2::x());
+    let options = RaycastOptions::default();
+
+    let (res, status) =
+        gjk::cast_ray_with_options(&m, &ball, &mut VoronoiSimplex::new(), &ray, 100.0, &options);
+    let (toi, _) = res.unwrap();
+    assert_eq!(status, RaycastStatus::Converged);
+    assert_relative_eq!(toi, 5.0 - 0.75f64.sqrt(), epsilon = 1.0e-5);
+
+    let miss = Ray::new(Point2::new(-5.0, 2.0), Vector2::x());
+    let (res, status) = gjk::cast_ray_with_options(
+        &m,
+        &ball,
+        &mut VoronoiSimplex::new(),
+        &miss,
+        100.0,
+        &options,
+    );
+    assert!(res.is_none());
+    assert_eq!(status, RaycastStatus::Converged);
+}
+
+#[test]
+fn gjk_raycast_reports_failures() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::new(-5.0, 0.5), Vector2::x());
+
+    let options = RaycastOptions::new(gjk::eps_tol(), 1);
+    let (res, status) =
+        gjk::cast_ray_with_options(&m, &ball, &mut VoronoiSimplex::new(), &ray, 100.0, &options);
+    assert!(res.is_none());
+    assert_eq!(status, RaycastStatus::MaxIterations);
+
+    let zero = Ray::new(Point2::new(-5.0, 0.5), Vector2::zeros());
+    let options = RaycastOptions::default();
+    let (res, status) = gjk::cast_ray_with_options(
+        &m,
+        &ball,
+        &mut VoronoiSimplex::new(),
+        &zero,
+        100.0,
+        &options,
+    );
+    assert!(res.is_none());
+    assert_eq!(status, RaycastStatus::Degenerate);
+}
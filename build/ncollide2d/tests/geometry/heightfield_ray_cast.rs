@@ -0,0 +1,61 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use ncollide2d::query::{Ray, RayCast};
+use ncollide2d::shape::HeightField;
+
+// A zigzag of 100 cells over `[-50, 50]`, with peaks at odd vertices.
+fn zigzag() -> HeightField<f64> {
+    let heights = DVector::from_fn(101, |i, _| if i % 2 == 1 { 1.0 } else { 0.0 });
+    HeightField::new(heights, Vector2::new(100.0, 1.0))
+}
+
+#[test]
+fn heightfield_ray_cast_cell_walk() {
+    let heightfield = zigzag();
+    let m = Isometry2::identity();
+
+    // Grazing ray just above the valleys, hitting the first peak it reaches.
+    let ray = Ray::new(Point2::new(-60.0, 0.5), Vector2::x());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 10.5, epsilon = 1.0e-7);
+
+    let ray = Ray::new(Point2::new(60.0, 0.5), -Vector2::x());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 10.5, epsilon = 1.0e-7);
+
+    assert!(heightfield
+        .toi_and_normal_with_ray(&m, &ray, 10.0, true)
+        .is_none());
+
+    // Starting from the middle of a cell.
+    let ray = Ray::new(Point2::new(0.2, 0.5), Vector2::x());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 0.3, epsilon = 1.0e-7);
+}
+
+#[test]
+fn heightfield_ray_cast_transformed() {
+    let heightfield = zigzag();
+    let m = Isometry2::new(Vector2::new(5.0, 20.0), std::f64::consts::FRAC_PI_2);
+
+    // Same as the grazing ray above, expressed in the heightfield frame.
+    let ray = Ray::new(m * Point2::new(-60.0, 0.5), m * Vector2::x());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 10.5, epsilon = 1.0e-7);
+
+    let ray = Ray::new(m * Point2::new(0.2, 0.5), m * Vector2::x());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 0.3, epsilon = 1.0e-7);
+    assert!(heightfield
+        .toi_and_normal_with_ray(&m, &ray, 0.2, true)
+        .is_none());
+}
@@ -0,0 +1,94 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query;
+use ncollide2d::shape::{
+    Ball, Compound, ConvexPolygon, Cuboid, Plane, Polyline, Shape, ShapeHandle,
+};
+
+fn check_against_distance(g1: &dyn Shape<f64>, g2: &dyn Shape<f64>) {
+    let m1 = Isometry2::new(Vector2::new(0.2, -0.1), 0.3);
+
+    for i in 0..40 {
+        for j in 0..40 {
+            let pos = Vector2::new(i as f64, j as f64) * 0.15 - Vector2::new(3.0, 3.0);
+            let m2 = Isometry2::new(pos, (i * j) as f64 * 0.1);
+            let dist = query::distance(&m1, g1, &m2, g2);
+
+            // Skip configurations too close to contact for the result to be well-defined.
+            if dist.abs() < 1.0e-3 {
+                continue;
+            }
+
+            assert_eq!(
+                query::intersection_test(&m1, g1, &m2, g2),
+                dist <= 0.0,
+                "distance: {}",
+                dist
+            );
+        }
+    }
+}
+
+#[test]
+fn intersection_test_support_maps() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let ball = Ball::new(0.7);
+    let triangle = ConvexPolygon::try_from_points(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(1.5, 0.0),
+        Point2::new(0.0, 2.0),
+    ])
+    .unwrap();
+
+    check_against_distance(&ball, &ball);
+    check_against_distance(&cuboid, &ball);
+    check_against_distance(&cuboid, &triangle);
+    check_against_distance(&triangle, &triangle);
+}
+
+#[test]
+fn intersection_test_plane() {
+    let plane = Plane::new(Vector2::y_axis());
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+
+    check_against_distance(&plane, &cuboid);
+    check_against_distance(&cuboid, &plane);
+}
+
+#[test]
+fn intersection_test_composites() {
+    let shapes = vec![
+        (
+            Isometry2::new(Vector2::new(-1.5, 0.0), 0.0),
+            ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5))),
+        ),
+        (
+            Isometry2::new(Vector2::new(1.5, 0.0), 0.0),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ];
+    let compound = Compound::new(shapes);
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(-2.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(2.0, 0.0),
+        ],
+        None,
+    );
+    let ball = Ball::new(0.3);
+
+    check_against_distance(&compound, &ball);
+    check_against_distance(&ball, &compound);
+    check_against_distance(&polyline, &compound);
+
+    // The gap between the two parts of the compound is not part of it.
+    let identity = Isometry2::identity();
+    assert!(!query::intersection_test(
+        &identity, &compound, &identity, &ball
+    ));
+    assert!(query::intersection_test_local(
+        &Isometry2::new(Vector2::new(1.5, 0.7), 0.0),
+        &compound,
+        &ball
+    ));
+}
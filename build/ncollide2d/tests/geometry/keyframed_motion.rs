@@ -0,0 +1,64 @@
+use na::{self, Isometry2, Vector2};
+use ncollide2d::interpolation::{KeyframedRigidMotion, RigidMotion};
+use ncollide2d::math;
+use ncollide2d::query::{self, DefaultTOIDispatcher};
+use ncollide2d::shape::{Ball, Cuboid};
+
+#[test]
+fn interpolate_isometry_lerps_and_slerps() {
+    let m0 = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let m1 = Isometry2::new(Vector2::new(3.0, 2.0), 1.0);
+    let mid = math::interpolate_isometry(&m0, &m1, 0.5);
+
+    assert_relative_eq!(mid.translation.vector, Vector2::new(2.0, 1.0));
+    assert_relative_eq!(mid.rotation.angle(), 0.5);
+    assert_relative_eq!(math::interpolate_isometry(&m0, &m1, 0.0), m0);
+    assert_relative_eq!(math::interpolate_isometry(&m0, &m1, 1.0), m1);
+}
+
+#[test]
+fn keyframed_motion_clamps_and_interpolates() {
+    let motion = KeyframedRigidMotion::new(vec![
+        (0.0, Isometry2::translation(0.0, 0.0)),
+        (1.0, Isometry2::translation(0.0, 0.0)),
+        (2.0, Isometry2::translation(4.0, 0.0)),
+    ]);
+
+    assert_relative_eq!(motion.position_at_time(-1.0), Isometry2::identity());
+    assert_relative_eq!(motion.position_at_time(0.5), Isometry2::identity());
+    assert_relative_eq!(
+        motion.position_at_time(1.25),
+        Isometry2::translation(1.0, 0.0)
+    );
+    assert_relative_eq!(
+        motion.position_at_time(10.0),
+        Isometry2::translation(4.0, 0.0)
+    );
+}
+
+#[test]
+fn keyframed_motion_nonlinear_toi() {
+    // The ball waits for one time unit, then moves to the right at speed 4.
+    let motion = KeyframedRigidMotion::new(vec![
+        (0.0, Isometry2::translation(0.0, 0.0)),
+        (1.0, Isometry2::translation(0.0, 0.0)),
+        (2.0, Isometry2::translation(4.0, 0.0)),
+    ]);
+    let ball = Ball::new(0.5);
+    let cuboid = Cuboid::new(Vector2::repeat(0.5));
+    let wall = Isometry2::translation(3.0, 0.0);
+
+    let toi = query::nonlinear_time_of_impact(
+        &DefaultTOIDispatcher,
+        &motion,
+        &ball,
+        &wall,
+        &cuboid,
+        2.0,
+        na::zero(),
+    )
+    .unwrap()
+    .expect("The ball should hit the wall.");
+
+    assert_relative_eq!(toi.toi, 1.5, epsilon = 1.0e-3);
+}
@@ -0,0 +1,84 @@
+use na::{Point2, Vector2};
+use ncollide2d::bounding_volume::{BoundingVolume, AABB};
+use ncollide2d::partitioning::{LinearBVT, BVH, BVT};
+use ncollide2d::query::visitors::BoundingVolumeInterferencesCollector;
+
+fn grid_aabb(i: usize) -> AABB<f32> {
+    let center = Point2::new((i % 7) as f32 * 1.5, (i / 7) as f32 * 1.5);
+    AABB::from_half_extents(center, Vector2::repeat(0.4))
+}
+
+fn interferences(bvh: &impl BVH<usize, AABB<f32>>, aabb: &AABB<f32>) -> Vec<usize> {
+    let mut res = Vec::new();
+    bvh.visit(&mut BoundingVolumeInterferencesCollector::new(
+        aabb, &mut res,
+    ));
+    res.sort();
+    res
+}
+
+#[test]
+fn linear_bvt_matches_bvt() {
+    let leaves = (0..30).map(|i| (i, grid_aabb(i))).collect();
+    let bvt = BVT::new_balanced(leaves);
+    let linear = LinearBVT::from_bvt(&bvt);
+
+    assert_eq!(linear.nodes().len(), 2 * 30 - 1);
+    assert_eq!(linear.root_bounding_volume(), bvt.root_bounding_volume());
+    assert_eq!(linear.nodes()[0].escape as usize, linear.nodes().len());
+
+    let queries = [
+        AABB::new(Point2::new(-1.0, -1.0), Point2::new(2.0, 2.0)),
+        AABB::new(Point2::new(3.0, 0.5), Point2::new(6.0, 5.0)),
+        AABB::new(Point2::new(20.0, 20.0), Point2::new(21.0, 21.0)),
+    ];
+
+    for aabb in queries.iter() {
+        let expected = interferences(&bvt, aabb);
+        assert_eq!(interferences(&linear, aabb), expected);
+
+        // The default stack-based traversal must agree with the stack-less one.
+        let mut stack = vec![linear.root().unwrap()];
+        let mut res = Vec::new();
+        while let Some(node) = stack.pop() {
+            let (bv, data) = linear.content(node);
+            if bv.intersects(aabb) {
+                res.extend(data.cloned());
+                for i in 0..linear.num_children(node) {
+                    stack.push(linear.child(i, node));
+                }
+            }
+        }
+        res.sort();
+        assert_eq!(res, expected);
+    }
+
+    // Round-trip.
+    let bvt2 = linear.clone().into_bvt();
+    for (l1, l2) in bvt.leaves().iter().zip(bvt2.leaves().iter()) {
+        assert_eq!(l1.data(), l2.data());
+        assert_eq!(l1.bounding_volume(), l2.bounding_volume());
+    }
+
+    for aabb in queries.iter() {
+        assert_eq!(interferences(&bvt2, aabb), interferences(&bvt, aabb));
+    }
+
+    let linear2 = LinearBVT::from_raw_parts(linear.nodes().to_vec(), linear.data().to_vec());
+    assert_eq!(linear2.nodes(), linear.nodes());
+}
+
+#[test]
+fn linear_bvt_single_leaf_and_empty() {
+    let bvt = BVT::new_balanced(vec![(0usize, grid_aabb(0))]);
+    let linear = LinearBVT::from_bvt(&bvt);
+    assert_eq!(linear.nodes().len(), 1);
+    assert!(linear.nodes()[0].is_leaf());
+    assert_eq!(interferences(&linear, &grid_aabb(0)), vec![0]);
+    assert!(!linear.into_bvt().leaves().is_empty());
+
+    let bvt: BVT<usize, AABB<f32>> = BVT::new_balanced(Vec::new());
+    let linear = LinearBVT::from_bvt(&bvt);
+    assert!(linear.root().is_none());
+    assert!(interferences(&linear, &grid_aabb(0)).is_empty());
+}
@@ -0,0 +1,160 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::query::{self, ClosestPoints, DefaultTOIDispatcher, Proximity};
+use ncollide2d::shape::{Ball, Compound, Cuboid, ShapeHandle};
+
+#[test]
+fn local_queries_match_world_space_queries() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 0.5));
+    let ball = Ball::new(0.5f64);
+
+    let m1 = Isometry2::new(Vector2::new(3.0, -2.0), 0.7);
+    let m2 = Isometry2::new(Vector2::new(4.0, -0.5), -0.3);
+    let pos12 = m1.inverse() * m2;
+
+    let dist = query::distance(&m1, &cuboid, &m2, &ball);
+    let local_dist = query::distance_local(&pos12, &cuboid, &ball);
+    assert_relative_eq!(dist, local_dist, epsilon = 1.0e-10);
+
+    let contact = query::contact(&m1, &cuboid, &m2, &ball, 1.0).unwrap();
+    let local_contact = query::contact_local(&pos12, &cuboid, &ball, 1.0).unwrap();
+    assert_relative_eq!(contact.depth, local_contact.depth, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world1, m1 * local_contact.world1, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        *contact.normal,
+        m1 * *local_contact.normal,
+        epsilon = 1.0e-10
+    );
+
+    assert_eq!(
+        query::proximity_local(&pos12, &cuboid, &ball, 0.0),
+        Proximity::Disjoint
+    );
+    assert_eq!(
+        query::proximity_local(&pos12, &cuboid, &ball, 1.0),
+        query::proximity(&m1, &cuboid, &m2, &ball, 1.0)
+    );
+
+    let vel1 = Vector2::new(1.0, 0.0);
+    let vel2 = Vector2::new(0.0, -1.0);
+    let vel12 = m1.inverse_transform_vector(&(vel2 - vel1));
+    let dispatcher = DefaultTOIDispatcher;
+    let toi = query::time_of_impact(
+        &dispatcher,
+        &m1,
+        &vel1,
+        &cuboid,
+        &m2,
+        &vel2,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    let local_toi =
+        query::time_of_impact_local(&dispatcher, &pos12, &vel12, &cuboid, &ball, 10.0, 0.0)
+            .unwrap()
+            .unwrap();
+    assert_relative_eq!(toi.toi, local_toi.toi, epsilon = 1.0e-6);
+}
+
+#[test]
+fn local_composite_queries_match_world_space_queries() {
+    let compound = Compound::new(vec![
+        (
+            Isometry2::translation(-1.5, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector2::new(1.0f64, 0.5))),
+        ),
+        (
+            Isometry2::new(Vector2::new(1.5, 0.5), 0.4),
+            ShapeHandle::new(Ball::new(0.75f64)),
+        ),
+    ]);
+    let ball = Ball::new(0.5f64);
+
+    let m1 = Isometry2::new(Vector2::new(-2.0, 1.0), 1.2);
+    let m2 = m1 * Isometry2::new(Vector2::new(2.5, 1.5), 0.3);
+    let pos12 = m1.inverse() * m2;
+    let pos21 = pos12.inverse();
+
+    // Composite shape first.
+    let dist = query::distance(&m1, &compound, &m2, &ball);
+    assert!(dist > 0.0);
+    assert_relative_eq!(
+        dist,
+        query::distance_local(&pos12, &compound, &ball),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        dist,
+        query::distance_local(&pos21, &ball, &compound),
+        epsilon = 1.0e-10
+    );
+
+    match (
+        query::closest_points(&m1, &compound, &m2, &ball, 1.0),
+        query::closest_points_local(&pos12, &compound, &ball, 1.0),
+    ) {
+        (ClosestPoints::WithinMargin(p1, p2), ClosestPoints::WithinMargin(l1, l2)) => {
+            assert_relative_eq!(p1, m1 * l1, epsilon = 1.0e-10);
+            assert_relative_eq!(p2, m1 * l2, epsilon = 1.0e-10);
+        }
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+
+    let contact = query::contact(&m1, &compound, &m2, &ball, 1.0).unwrap();
+    let local_contact = query::contact_local(&pos12, &compound, &ball, 1.0).unwrap();
+    assert_relative_eq!(contact.depth, local_contact.depth, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world1, m1 * local_contact.world1, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world2, m1 * local_contact.world2, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        *contact.normal,
+        m1 * *local_contact.normal,
+        epsilon = 1.0e-10
+    );
+
+    // Composite shape second: the results are in the local space of the ball.
+    let flipped = query::contact_local(&pos21, &ball, &compound, 1.0).unwrap();
+    assert_relative_eq!(contact.depth, flipped.depth, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world1, m2 * flipped.world2, epsilon = 1.0e-10);
+    assert_relative_eq!(*contact.normal, -(m2 * *flipped.normal), epsilon = 1.0e-10);
+
+    assert_eq!(
+        query::proximity_local(&pos12, &compound, &ball, 1.0),
+        query::proximity(&m1, &compound, &m2, &ball, 1.0)
+    );
+    assert_eq!(
+        query::proximity_local(&pos21, &ball, &compound, 0.0),
+        Proximity::Disjoint
+    );
+
+    let vel1 = Vector2::new(0.5, 0.0);
+    let vel2 = Vector2::new(-1.0, -2.0);
+    let vel12 = m1.inverse_transform_vector(&(vel2 - vel1));
+    let vel21 = m2.inverse_transform_vector(&(vel1 - vel2));
+    let dispatcher = DefaultTOIDispatcher;
+    let toi = query::time_of_impact(
+        &dispatcher,
+        &m1,
+        &vel1,
+        &compound,
+        &m2,
+        &vel2,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    let local_toi =
+        query::time_of_impact_local(&dispatcher, &pos12, &vel12, &compound, &ball, 10.0, 0.0)
+            .unwrap()
+            .unwrap();
+    let flipped_toi =
+        query::time_of_impact_local(&dispatcher, &pos21, &vel21, &ball, &compound, 10.0, 0.0)
+            .unwrap()
+            .unwrap();
+    assert_relative_eq!(toi.toi, local_toi.toi, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.toi, flipped_toi.toi, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness2, flipped_toi.witness1, epsilon = 1.0e-6);
+}
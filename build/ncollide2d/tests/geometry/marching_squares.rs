@@ -0,0 +1,60 @@
+use crate::common::signed_area;
+use na::Point2;
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::procedural;
+use std::f64::consts::PI;
+
+#[test]
+fn marching_squares_circle() {
+    let bounds = AABB::new(Point2::new(-2.0, -2.0), Point2::new(2.0, 2.0));
+    let contours =
+        procedural::marching_squares(|p: &Point2<f64>| p.coords.norm() - 1.0, &bounds, [40, 40]);
+
+    assert_eq!(contours.len(), 1);
+    let coords = contours[0].coords();
+    assert!(coords.iter().all(|p| (p.coords.norm() - 1.0).abs() < 0.01));
+    assert_relative_eq!(signed_area(coords), PI, epsilon = 0.02);
+}
+
+#[test]
+fn marching_squares_ring() {
+    // The hole of the ring is bounded by a clockwise loop.
+    let bounds = AABB::new(Point2::new(-3.0, -3.0), Point2::new(3.0, 3.0));
+    let contours = procedural::marching_squares(
+        |p: &Point2<f64>| (p.coords.norm() - 1.5).abs() - 0.5,
+        &bounds,
+        [60, 60],
+    );
+
+    assert_eq!(contours.len(), 2);
+    let mut areas: Vec<_> = contours.iter().map(|c| signed_area(c.coords())).collect();
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(areas[0], -PI, epsilon = 0.02);
+    assert_relative_eq!(areas[1], 4.0 * PI, epsilon = 0.05);
+}
+
+#[test]
+fn marching_squares_closed_at_bounds() {
+    let bounds = AABB::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0));
+    let contours = procedural::marching_squares(|p: &Point2<f64>| p.y, &bounds, [5, 5]);
+
+    assert_eq!(contours.len(), 1);
+    assert_relative_eq!(signed_area(contours[0].coords()), 2.0, epsilon = 1.0e-9);
+}
+
+#[test]
+fn marching_squares_ambiguous_grid() {
+    // Two negative samples on opposite corners of the central cell.
+    let mut values = vec![1.0; 16];
+    values[5] = -1.0;
+    values[10] = -1.0;
+    let bounds = AABB::new(Point2::origin(), Point2::new(3.0, 3.0));
+
+    let separated = procedural::marching_squares_grid(&values, [4, 4], &bounds);
+    assert_eq!(separated.len(), 2);
+
+    values[6] = 0.1;
+    values[9] = 0.1;
+    let linked = procedural::marching_squares_grid(&values, [4, 4], &bounds);
+    assert_eq!(linked.len(), 1);
+}
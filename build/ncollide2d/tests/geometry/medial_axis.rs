@@ -0,0 +1,85 @@
+use crate::common::closed_polyline;
+use na::Point2;
+use ncollide2d::shape::Polyline;
+use ncollide2d::transformation::medial_axis;
+
+fn rectangle(hx: f64, hy: f64) -> [Point2<f64>; 4] {
+    [
+        Point2::new(-hx, -hy),
+        Point2::new(hx, -hy),
+        Point2::new(hx, hy),
+        Point2::new(-hx, hy),
+    ]
+}
+
+// The distances from `pt` to the sides of axis-aligned rectangles, sorted.
+fn side_distances(pt: &Point2<f64>, rectangles: &[(f64, f64)]) -> Vec<f64> {
+    let mut res: Vec<f64> = rectangles
+        .iter()
+        .flat_map(|(hx, hy)| {
+            let dx = (pt.x.abs() - hx).max(0.0);
+            let dy = (pt.y.abs() - hy).max(0.0);
+            vec![
+                (pt.x - hx).abs().hypot(dy),
+                (pt.x + hx).abs().hypot(dy),
+                (pt.y - hy).abs().hypot(dx),
+                (pt.y + hy).abs().hypot(dx),
+            ]
+        })
+        .collect();
+    res.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    res
+}
+
+#[test]
+fn medial_axis_of_rectangle() {
+    let spacing = 0.05;
+    let polyline = closed_polyline(&[&rectangle(2.0, 0.5)]);
+    let axis = medial_axis(&polyline, spacing).unwrap();
+
+    for pt in axis.points() {
+        assert!(pt.x.abs() < 2.0 && pt.y.abs() < 0.5);
+
+        // Each point of the medial axis is equidistant to two sides.
+        let dists = side_distances(pt, &[(2.0, 0.5)]);
+        assert!(dists[1] - dists[0] <= spacing, "{:?}", pt);
+    }
+
+    // The spine spans the middle of the rectangle.
+    let spine: Vec<_> = axis
+        .points()
+        .iter()
+        .filter(|pt| pt.y.abs() < spacing)
+        .collect();
+    assert!(spine.iter().any(|pt| pt.x < -1.4));
+    assert!(spine.iter().any(|pt| pt.x > 1.4));
+    assert!(axis.perimeter() > 3.0);
+}
+
+#[test]
+fn medial_axis_with_hole() {
+    let spacing = 0.05;
+    let mut hole = rectangle(1.0, 1.0);
+    hole.reverse();
+    let polyline = closed_polyline(&[&rectangle(2.0, 2.0), &hole]);
+    let axis = medial_axis(&polyline, spacing).unwrap();
+
+    for pt in axis.points() {
+        // The medial axis goes around the hole, halfway between both boundaries.
+        assert!(pt.x.abs().max(pt.y.abs()) > 1.0);
+
+        let dists = side_distances(pt, &[(2.0, 2.0), (1.0, 1.0)]);
+        assert!(dists[1] - dists[0] <= spacing, "{:?}", pt);
+    }
+
+    assert!(axis
+        .points()
+        .iter()
+        .any(|pt| (pt.x - 1.5).abs() < spacing && pt.y.abs() < spacing));
+}
+
+#[test]
+fn medial_axis_of_open_polyline() {
+    let polyline = Polyline::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)], None);
+    assert!(medial_axis(&polyline, 0.1).is_none());
+}
@@ -1,6 +1,53 @@
 mod ball_ball_toi;
 mod ball_cuboid_contact;
+mod boundary_measures;
+mod boundary_projection;
+mod bounding_volume_pair;
+mod bvt_leaves;
+mod bvt_region_queries;
+mod bvt_stats;
+mod capsule_queries;
+mod clip_ray;
+mod closest_points_pointcloud;
+mod closest_points_segments;
+mod compound_flatten;
+mod compound_masks;
+mod composite_closest_points;
 mod compound_penetration;
+mod convex_shapes;
+mod debug_trace;
+#[cfg(feature = "deterministic")]
+mod deterministic_sin_cos;
+mod endpoint_capsule;
 mod epa2;
+mod gjk_raycast_options;
+mod heightfield_ray_cast;
+mod intersection_test;
+mod keyframed_motion;
+mod linear_bvt;
+mod local_queries;
+mod marching_squares;
+mod medial_axis;
+mod offset_polyline;
+mod overlap_volume;
+mod procedural_polylines;
+mod project_point_with_part;
+mod query_precision;
+#[cfg(feature = "query_statistics")]
+mod query_statistics;
 mod ray_cast;
+mod ray_packet;
+#[cfg(feature = "rand")]
+mod sample;
+mod segment_query;
+mod shape_handle_queries;
+mod simplify_polyline;
+mod swept;
+mod swept_bounding_volumes;
+mod thick_polyline;
+mod thick_ray;
+mod tight_aabb;
+mod to_polyline;
+mod tile_grid;
 mod time_of_impact2;
+mod volumetric;
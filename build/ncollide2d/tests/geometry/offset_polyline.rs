@@ -0,0 +1,66 @@
+use crate::common::closed_polyline;
+use na::Point2;
+use ncollide2d::shape::Polyline;
+use ncollide2d::transformation::{offset_polyline, OffsetCorner};
+use std::f64::consts::PI;
+
+#[test]
+fn offset_square() {
+    let square = Polyline::quad(1, 1);
+
+    let mitered = offset_polyline(&square, 0.5, OffsetCorner::Miter(2.0)).unwrap();
+    assert_relative_eq!(mitered.area(), 4.0, epsilon = 1.0e-7);
+    assert_relative_eq!(mitered.perimeter(), 8.0, epsilon = 1.0e-7);
+
+    let rounded = offset_polyline(&square, 0.5, OffsetCorner::Round(1.0e-4)).unwrap();
+    assert_relative_eq!(rounded.area(), 3.0 + PI * 0.25, epsilon = 1.0e-3);
+    assert!(rounded.area() < 3.0 + PI * 0.25);
+
+    // Beyond the miter limit, corners are beveled.
+    let beveled = offset_polyline(&square, 0.5, OffsetCorner::Miter(1.0)).unwrap();
+    assert_relative_eq!(beveled.area(), 3.5, epsilon = 1.0e-7);
+
+    let deflated = offset_polyline(&square, -0.25, OffsetCorner::Round(1.0e-4)).unwrap();
+    assert_relative_eq!(deflated.area(), 0.25, epsilon = 1.0e-7);
+    assert_relative_eq!(deflated.perimeter(), 2.0, epsilon = 1.0e-7);
+
+    assert!(offset_polyline(&square, -0.6, OffsetCorner::Round(1.0e-4)).is_none());
+}
+
+#[test]
+fn offset_fills_narrow_slots() {
+    // A unit square with a slot of width 0.2 cut into its top edge.
+    let slotted = closed_polyline(&[&[
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.6, 1.0),
+        Point2::new(0.6, 0.5),
+        Point2::new(0.4, 0.5),
+        Point2::new(0.4, 1.0),
+        Point2::new(0.0, 1.0),
+    ]]);
+    assert_relative_eq!(slotted.area(), 0.9, epsilon = 1.0e-7);
+
+    let inflated = offset_polyline(&slotted, 0.2, OffsetCorner::Miter(2.0)).unwrap();
+    assert_relative_eq!(inflated.area(), 1.96, epsilon = 1.0e-7);
+    assert_relative_eq!(inflated.perimeter(), 5.6, epsilon = 1.0e-7);
+
+    // Deflating rounds the concave corners at the bottom of the slot.
+    let deflated = offset_polyline(&slotted, -0.1, OffsetCorner::Round(1.0e-4)).unwrap();
+    let expected = 0.8 * 0.8 - 0.4 * 0.5 + 2.0 * (0.01 - PI * 0.01 / 4.0);
+    assert_relative_eq!(deflated.area(), expected, epsilon = 1.0e-3);
+}
+
+#[test]
+fn offset_open_polyline() {
+    let segment = Polyline::new(vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)], None);
+
+    let capsule = offset_polyline(&segment, 0.5, OffsetCorner::Round(1.0e-4)).unwrap();
+    assert_relative_eq!(capsule.area(), 1.0 + PI * 0.25, epsilon = 1.0e-3);
+
+    let rectangle = offset_polyline(&segment, 0.5, OffsetCorner::Miter(2.0)).unwrap();
+    assert_relative_eq!(rectangle.area(), 1.0, epsilon = 1.0e-7);
+
+    assert!(offset_polyline(&segment, -0.5, OffsetCorner::Round(1.0e-4)).is_none());
+}
@@ -0,0 +1,59 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query;
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid};
+use std::f64::consts::{FRAC_PI_4, PI};
+
+#[test]
+fn overlap_volume_polygons() {
+    let square = Cuboid::new(Vector2::new(1.0, 1.0));
+    let identity = Isometry2::identity();
+
+    let shifted = Isometry2::new(Vector2::new(1.0, 0.5), 0.0);
+    let area = query::overlap_volume(&identity, &square, &shifted, &square, 1);
+    assert_relative_eq!(area, 1.5, epsilon = 1.0e-7);
+
+    // The intersection of a square with itself rotated by 45 degrees is an octagon.
+    let rotated = Isometry2::new(Vector2::zeros(), FRAC_PI_4);
+    let area = query::overlap_volume(&identity, &square, &rotated, &square, 1);
+    assert_relative_eq!(area, 8.0 * 2.0f64.sqrt() - 8.0, epsilon = 1.0e-7);
+
+    let triangle = ConvexPolygon::try_from_points(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 4.0),
+    ])
+    .unwrap();
+    let area = query::overlap_volume(&identity, &square, &identity, &triangle, 1);
+    assert_relative_eq!(area, 1.0, epsilon = 1.0e-7);
+
+    let inside = Isometry2::new(Vector2::new(1.2, 1.2), 0.0);
+    let area = query::overlap_volume_local(&inside, &triangle, &square, 1);
+    assert_relative_eq!(area, 4.0 - 0.08, epsilon = 1.0e-7);
+
+    let far = Isometry2::new(Vector2::new(5.0, 0.0), 0.3);
+    assert_eq!(
+        query::overlap_volume(&identity, &square, &far, &triangle, 1),
+        0.0
+    );
+}
+
+#[test]
+fn overlap_volume_sampled_shapes() {
+    let ball = Ball::new(1.0);
+    let identity = Isometry2::identity();
+
+    // Area of the lens formed by two unit disks at a distance of 1.
+    let shifted = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+    let area = query::overlap_volume(&identity, &ball, &shifted, &ball, 200);
+    assert_relative_eq!(area, 2.0 * PI / 3.0 - 3.0f64.sqrt() / 2.0, epsilon = 1.0e-2);
+
+    let square = Cuboid::new(Vector2::new(2.0, 2.0));
+    let area = query::overlap_volume(&identity, &square, &identity, &ball, 200);
+    assert_relative_eq!(area, PI, epsilon = 1.0e-2);
+
+    // The sampled estimate converges toward the exact result for polygons too.
+    let square = Cuboid::new(Vector2::new(1.0, 1.0));
+    let exact = query::overlap_volume(&identity, &square, &shifted, &square, 1);
+    let sampled = query::overlap_volume_sampled(&identity, &square, &shifted, &square, 100);
+    assert_relative_eq!(exact, sampled, epsilon = 1.0e-2);
+}
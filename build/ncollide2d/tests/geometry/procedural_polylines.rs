@@ -0,0 +1,72 @@
+use crate::common::signed_area;
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::procedural;
+use ncollide2d::query::PointQuery;
+use ncollide2d::shape::Polyline;
+use std::f64::consts::PI;
+
+#[test]
+fn arc_endpoints() {
+    let arc = procedural::arc(2.0, 0.0, PI / 2.0, 8);
+    let coords = arc.coords();
+
+    assert_eq!(coords.len(), 9);
+    assert_relative_eq!(coords[0], Point2::new(2.0, 0.0));
+    assert_relative_eq!(coords[8], Point2::new(0.0, 2.0), epsilon = 1.0e-12);
+    assert!(coords
+        .iter()
+        .all(|p| (p.coords.norm() - 2.0).abs() < 1.0e-12));
+}
+
+#[test]
+fn regular_polygon_and_star() {
+    let hexagon = procedural::regular_polygon(1.0, 6);
+    assert_eq!(hexagon.coords().len(), 6);
+    assert_relative_eq!(
+        signed_area(hexagon.coords()),
+        3.0 * 3.0f64.sqrt() / 2.0,
+        epsilon = 1.0e-12
+    );
+
+    let star = procedural::star(2.0, 1.0, 5);
+    assert_eq!(star.coords().len(), 10);
+    assert!(signed_area(star.coords()) > 0.0);
+
+    for (i, pt) in star.coords().iter().enumerate() {
+        let expected = if i % 2 == 0 { 2.0 } else { 1.0 };
+        assert_relative_eq!(pt.coords.norm(), expected, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn rounded_rectangle_area() {
+    let extents = Vector2::new(4.0, 2.0);
+    let rect = procedural::rounded_rectangle(&extents, 0.5, 64);
+
+    // The corners of the rectangle are replaced by quarters of a disk.
+    let expected = 8.0 - (4.0 - PI) * 0.25;
+    assert_relative_eq!(signed_area(rect.coords()), expected, epsilon = 1.0e-3);
+
+    // A zero radius gives the sharp rectangle.
+    let sharp = procedural::rounded_rectangle(&extents, 0.0, 64);
+    assert_eq!(sharp.coords().len(), 4);
+    assert_relative_eq!(signed_area(sharp.coords()), 8.0);
+}
+
+#[test]
+fn capsule_outline_as_collider() {
+    let capsule = procedural::capsule(&1.0, &2.0, 32);
+    assert!(signed_area(capsule.coords()) > 0.0);
+
+    let polyline = Polyline::from(capsule);
+    let expected_perimeter = PI + 4.0;
+
+    // The contour is closed.
+    assert_eq!(polyline.edges().len(), polyline.points().len());
+    assert_relative_eq!(polyline.perimeter(), expected_perimeter, epsilon = 1.0e-2);
+    assert_relative_eq!(polyline.area(), PI * 0.25 + 2.0, epsilon = 1.0e-2);
+
+    // The flat sides are vertical.
+    let proj = polyline.project_point(&Isometry2::identity(), &Point2::new(1.0, 0.0), false);
+    assert_relative_eq!(proj.point, Point2::new(0.5, 0.0), epsilon = 1.0e-12);
+}
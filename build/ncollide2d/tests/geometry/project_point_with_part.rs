@@ -0,0 +1,59 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::PointQuery;
+use ncollide2d::shape::{Ball, Compound, Cuboid, FeatureId, Polyline, ShapeHandle};
+
+#[test]
+fn polyline_project_point_with_part_and_feature() {
+    let points = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(3.0, 1.0),
+    ];
+    let polyline = Polyline::new(points, None);
+    let m = Isometry2::translation(1.0, 0.0);
+
+    let (proj, (segment, feature)) =
+        polyline.project_point_with_part_and_feature(&m, &Point2::new(3.5, 2.0));
+    assert_eq!(segment, 2);
+    assert!((proj.point - Point2::new(3.5, 1.0)).norm() < 1.0e-6);
+    assert_eq!(
+        polyline
+            .project_point_with_feature(&m, &Point2::new(3.5, 2.0))
+            .1,
+        feature
+    );
+
+    let (_, (segment, _)) =
+        polyline.project_point_with_part_and_feature(&m, &Point2::new(1.5, -1.0));
+    assert_eq!(segment, 0);
+}
+
+#[test]
+fn compound_project_point_with_part_and_feature() {
+    let shapes = vec![
+        (
+            Isometry2::translation(-3.0, 0.0),
+            ShapeHandle::new(Ball::new(1.0f32)),
+        ),
+        (
+            Isometry2::translation(3.0, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 1.0))),
+        ),
+    ];
+    let compound = Compound::new(shapes);
+    let m = Isometry2::identity();
+
+    let (proj, (part, feature)) =
+        compound.project_point_with_part_and_feature(&m, &Point2::new(3.0, 5.0));
+    assert_eq!(part, 1);
+    assert!((proj.point - Point2::new(3.0, 1.0)).norm() < 1.0e-6);
+    assert!(match feature {
+        FeatureId::Face(_) => true,
+        _ => false,
+    });
+
+    let (proj, feature) = compound.project_point_with_feature(&m, &Point2::new(-6.0, 0.0));
+    assert!((proj.point - Point2::new(-4.0, 0.0)).norm() < 1.0e-6);
+    assert_eq!(feature, FeatureId::Face(0));
+}
@@ -0,0 +1,170 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::algorithms::gjk::{self, GJKOptions, GJKResult, RaycastStatus};
+use ncollide2d::query::algorithms::{CSOPoint, DebugTrace, EPAOptions, VoronoiSimplex, EPA};
+use ncollide2d::query::{QueryPrecision, Ray};
+use ncollide2d::shape::{Ball, Cuboid};
+#[cfg(feature = "improved_fixed_point_support")]
+use simba::scalar::FixedI40F24;
+
+fn run_gjk(precision: &QueryPrecision<f64>) -> (GJKResult<f64>, usize) {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::new(Vector2::zeros(), 0.3);
+    let m2 = Isometry2::new(Vector2::new(3.0, 1.5), 0.0);
+
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(
+        &m1,
+        &cuboid,
+        &m2,
+        &ball,
+        &Vector2::x_axis(),
+    ));
+    let mut trace = DebugTrace::new();
+    let options = GJKOptions::new(f64::MAX, true, &mut trace).with_precision(*precision);
+    let res = gjk::closest_points_with_options(&m1, &cuboid, &m2, &ball, &mut simplex, options);
+
+    (res, trace.gjk.len())
+}
+
+#[test]
+fn gjk_default_precision_matches_closest_points() {
+    let (res, niter) = run_gjk(&QueryPrecision::default());
+    assert!(niter > 1);
+
+    match res {
+        GJKResult::ClosestPoints(p1, p2, _) => {
+            let dist = na::distance(&p1, &p2);
+            assert!(dist > 1.0 && dist < 3.0);
+        }
+        _ => panic!("The shapes are disjoint."),
+    }
+}
+
+#[test]
+fn gjk_precision_limits_iterations() {
+    let (res, niter) = run_gjk(&QueryPrecision::new(gjk::eps_tol(), 1));
+    assert_eq!(niter, 1);
+    assert!(matches!(res, GJKResult::NoIntersection(_)));
+
+    let (_, fine_niter) = run_gjk(&QueryPrecision::default());
+    let coarse = QueryPrecision::default().with_relative_epsilon(0.1);
+    let (res, coarse_niter) = run_gjk(&coarse);
+    assert!(coarse_niter <= fine_niter);
+    assert!(matches!(res, GJKResult::ClosestPoints(..)));
+}
+
+#[test]
+fn epa_default_precision_matches_closest_points() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::new(Vector2::zeros(), 0.3);
+    let m2 = Isometry2::new(Vector2::new(0.5, 0.6), 0.0);
+
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(
+        &m1,
+        &cuboid,
+        &m2,
+        &ball,
+        &Vector2::x_axis(),
+    ));
+    let res = gjk::closest_points(&m1, &cuboid, &m2, &ball, f64::MAX, true, &mut simplex);
+    assert_eq!(res, GJKResult::Intersection);
+
+    let mut epa = EPA::new();
+    let expected = epa
+        .closest_points(&m1, &cuboid, &m2, &ball, &simplex)
+        .unwrap();
+    let actual = epa
+        .closest_points_with_options(
+            &m1,
+            &cuboid,
+            &m2,
+            &ball,
+            &simplex,
+            EPAOptions::new(&mut ()).with_precision(QueryPrecision::epa()),
+        )
+        .unwrap();
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn ray_cast_with_precision() {
+    let ball = Ball::new(1.0f64);
+    let ray = Ray::new(Point2::new(-5.0, 0.5), Vector2::x());
+    let (res, status) = gjk::cast_ray_with_options(
+        &Isometry2::identity(),
+        &ball,
+        &mut VoronoiSimplex::new(),
+        &ray,
+        100.0,
+        &QueryPrecision::new(1.0e-6, 100),
+    );
+
+    assert_eq!(status, RaycastStatus::Converged);
+    assert_relative_eq!(res.unwrap().0, 5.0 - 0.75f64.sqrt(), epsilon = 1.0e-3);
+}
+
+#[test]
+#[cfg(feature = "improved_fixed_point_support")]
+fn ray_cast_fixed_point_with_precision() {
+    let ball = Ball::<FixedI40F24>::new(na::convert(1.0));
+    let ray = Ray::new(
+        Point2::new(na::convert(-5.0), na::convert(0.5)),
+        Vector2::x(),
+    );
+    let precision =
+        QueryPrecision::new(na::convert(1.0e-4), 100).with_relative_epsilon(na::convert(1.0e-2));
+    let (res, _) = gjk::cast_ray_with_options(
+        &Isometry2::identity(),
+        &ball,
+        &mut VoronoiSimplex::new(),
+        &ray,
+        na::convert(100.0),
+        &precision,
+    );
+
+    let expected: FixedI40F24 = na::convert(5.0 - 0.75f64.sqrt());
+    let tolerance: FixedI40F24 = na::convert(1.0e-2);
+    let error = res.unwrap().0 - expected;
+    assert!(error < tolerance && error > -tolerance);
+}
+
+#[test]
+#[cfg(feature = "improved_fixed_point_support")]
+fn gjk_fixed_point_with_precision() {
+    let cuboid = Cuboid::<FixedI40F24>::new(Vector2::new(na::convert(1.0), na::convert(0.5)));
+    let ball = Ball::<FixedI40F24>::new(na::convert(0.5));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::translation(na::convert(3.0), na::convert(0.2));
+
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(
+        &m1,
+        &cuboid,
+        &m2,
+        &ball,
+        &Vector2::x_axis(),
+    ));
+    let precision =
+        QueryPrecision::new(na::convert(1.0e-4), 100).with_relative_epsilon(na::convert(1.0e-2));
+    let res = gjk::closest_points_with_options(
+        &m1,
+        &cuboid,
+        &m2,
+        &ball,
+        &mut simplex,
+        GJKOptions::new(na::convert(10.0), true, &mut ()).with_precision(precision),
+    );
+
+    match res {
+        GJKResult::ClosestPoints(p1, p2, _) => {
+            let expected: FixedI40F24 = na::convert(1.5);
+            let tolerance: FixedI40F24 = na::convert(1.0e-2);
+            let error = na::distance(&p1, &p2) - expected;
+            assert!(error < tolerance && error > -tolerance);
+        }
+        _ => panic!("The shapes are disjoint."),
+    }
+}
@@ -0,0 +1,41 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, QueryStatistics, Ray, RayCast};
+use ncollide2d::shape::{Ball, Cuboid, Polyline};
+
+#[test]
+fn query_statistics_count_bv_and_primitive_tests() {
+    let points = (0..100)
+        .map(|i| Point2::new(i as f32, (i % 2) as f32))
+        .collect();
+    let polyline = Polyline::new(points, None);
+    let ray = Ray::new(Point2::new(50.5, 10.0), -Vector2::y());
+
+    let (hit, stats) = QueryStatistics::measure(|| {
+        polyline.toi_with_ray(&Isometry2::identity(), &ray, 100.0, true)
+    });
+
+    assert!(hit.is_some());
+    assert!(stats.bv_tests > 0);
+    // The bounding volume hierarchy must discard most segments.
+    assert!(stats.primitive_tests >= 1 && stats.primitive_tests < 10);
+    assert_eq!(stats.gjk_iterations, 0);
+}
+
+#[test]
+fn query_statistics_count_gjk_iterations_and_nest() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry2::new(Vector2::zeros(), 0.3);
+    let m2 = Isometry2::new(Vector2::new(3.0, 1.0), 0.0);
+
+    QueryStatistics::reset();
+    let (_, outer) = QueryStatistics::measure(|| {
+        let _ = query::distance(&m1, &cuboid, &m2, &cuboid);
+        let (_, inner) = QueryStatistics::measure(|| query::distance(&m1, &cuboid, &m2, &ball));
+        assert_eq!(inner.bv_tests, 0);
+        inner
+    });
+
+    assert!(outer.gjk_iterations > 0);
+    assert_eq!(QueryStatistics::current(), outer);
+}
@@ -1,6 +1,6 @@
 use na::{self, Isometry2, Point2, Vector2};
 use ncollide2d::query::{Ray, RayCast};
-use ncollide2d::shape::{ConvexPolygon, Segment, Shape};
+use ncollide2d::shape::{Ball, Compound, ConvexPolygon, Cuboid, Segment, Shape, ShapeHandle};
 
 #[test]
 fn issue_178_parallel_raycast() {
@@ -137,3 +137,79 @@ fn convexpoly_raycast_fuzz() {
         }
     }
 }
+
+#[test]
+fn ray_toi_with_moving_ball() {
+    use ncollide2d::query;
+    use ncollide2d::shape::Ball;
+
+    // A ball crossing the ray path: it would be missed without taking its motion into account.
+    let ball = Ball::new(0.5f64);
+    let m = Isometry2::new(Vector2::new(10.0, -5.0), na::zero());
+    let ray = Ray::new(Point2::new(0.0, 0.0), Vector2::new(10.0, 0.0));
+    let linvel = Vector2::new(0.0, 5.0);
+
+    assert!(ball.toi_with_ray(&m, &ray, std::f64::MAX, true).is_none());
+
+    let hit = query::ray_toi_with_moving_shape(&ray, &ball, &m, &linvel, std::f64::MAX).unwrap();
+    let hit_point = ray.point_at(hit.toi);
+    let ball_center = Point2::from(m.translation.vector + linvel * hit.toi);
+    assert_relative_eq!(na::distance(&hit_point, &ball_center), 0.5, epsilon = 1.0e-6);
+    assert!(hit.toi < 1.0);
+
+    let shape: &dyn Shape<f64> = &ball;
+    assert!(query::ray_toi_with_moving_shape(&ray, shape, &m, &-linvel, std::f64::MAX).is_none());
+}
+
+#[test]
+fn compound_per_child_ray_casts() {
+    // A chassis with a wheel below it.
+    let vehicle = Compound::new(vec![
+        (
+            Isometry2::identity(),
+            ShapeHandle::new(Cuboid::new(Vector2::new(2.0, 0.5))),
+        ),
+        (
+            Isometry2::new(Vector2::new(0.0, -1.0), na::zero()),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ])
+    .with_child_masks(vec![0b01, 0b10]);
+    let m = Isometry2::new(Vector2::new(1.0, 0.0), na::zero());
+    let from_below = Ray::new(Point2::new(1.0, -5.0), Vector2::y());
+
+    // Every part along the ray is reported, from the nearest to the farthest.
+    let hits = vehicle.toi_with_ray_per_child(&m, &from_below, 100.0, true);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].0, 1);
+    assert_relative_eq!(hits[0].1, 3.5, epsilon = 1.0e-7);
+    assert_eq!(hits[1].0, 0);
+    assert_relative_eq!(hits[1].1, 4.5, epsilon = 1.0e-7);
+
+    let hits = vehicle.toi_with_ray_per_child(&m, &from_below, 4.0, true);
+    assert_eq!(hits.len(), 1);
+
+    // This ray misses the wheel.
+    let side = Ray::new(Point2::new(2.5, -5.0), Vector2::y());
+    let hits = vehicle.toi_with_ray_per_child(&m, &side, 100.0, true);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].0, 0);
+
+    // Only the parts matching the filter are reported.
+    let hits = vehicle.toi_with_ray_per_child_filtered(&m, &from_below, 100.0, true, 0b01);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].0, 0);
+    assert_relative_eq!(hits[0].1, 4.5, epsilon = 1.0e-7);
+    assert!(vehicle
+        .toi_with_ray_per_child_filtered(&m, &from_below, 100.0, true, 0)
+        .is_empty());
+
+    // The unfiltered query ignores the masks.
+    let vehicle = vehicle.with_child_masks(vec![0, 0]);
+    assert_eq!(
+        vehicle
+            .toi_with_ray_per_child(&m, &from_below, 100.0, true)
+            .len(),
+        2
+    );
+}
@@ -0,0 +1,62 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, AABB};
+use ncollide2d::partitioning::BVT;
+use ncollide2d::query::{Ray, RayCast, RayPacket};
+use ncollide2d::shape::Ball;
+
+#[test]
+fn ray_packet_matches_individual_ray_casts() {
+    let ball = Ball::new(0.4f32);
+    let positions: Vec<_> = (0..20)
+        .map(|i| {
+            let i = i as f32;
+            Isometry2::translation(i * 0.7, (i * 1.3) % 3.0)
+        })
+        .collect();
+    let leaves: Vec<(usize, AABB<f32>)> = positions
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| (i, bounding_volume::aabb(&ball, pos)))
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    let rays: Vec<_> = (0..16)
+        .map(|i| {
+            let y = i as f32 * 0.2 - 0.13;
+            Ray::new(
+                Point2::new(-1.0, y),
+                Vector2::new(1.0, 0.05 * (i % 3) as f32),
+            )
+        })
+        .collect();
+    let packet = RayPacket::new(rays.clone());
+
+    let hits = packet.cast_on_bvh(&bvt, 100.0, |i, ray, max_toi| {
+        ball.toi_and_normal_with_ray(&positions[*i], ray, max_toi, true)
+    });
+    assert_eq!(hits.len(), rays.len());
+
+    for (ray, hit) in rays.iter().zip(hits.iter()) {
+        let expected = positions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pos)| ball.toi_with_ray(pos, ray, 100.0, true).map(|toi| (i, toi)))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        match (hit, expected) {
+            (Some((i, inter)), Some((j, toi))) => {
+                assert_eq!(**i, j);
+                assert!((inter.toi - toi).abs() < 1.0e-5);
+            }
+            (None, None) => {}
+            _ => panic!("Ray packet and individual ray cast mismatch."),
+        }
+    }
+
+    // Rays missing everything.
+    let packet = RayPacket::new(vec![Ray::new(Point2::new(0.0, 10.0), Vector2::x())]);
+    let hits = packet.cast_on_bvh(&bvt, 100.0, |i, ray, max_toi| {
+        ball.toi_and_normal_with_ray(&positions[*i], ray, max_toi, true)
+    });
+    assert!(hits[0].is_none());
+}
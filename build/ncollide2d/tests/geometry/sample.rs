@@ -0,0 +1,52 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::PointQuery;
+use ncollide2d::sample;
+use ncollide2d::shape::{ConvexPolygon, Cuboid, Polyline};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn sample_convex_polygon() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let points = vec![
+        Point2::new(0.0f64, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(0.0, 4.0),
+    ];
+    let polygon = ConvexPolygon::try_new(points).unwrap();
+    let id = Isometry2::identity();
+
+    for pt in sample::sample_interior(&polygon, &mut rng, 500) {
+        assert!(polygon.distance_to_point(&id, &pt, true) < 1.0e-7);
+    }
+
+    for pt in sample::sample_boundary(&polygon, &mut rng, 500) {
+        assert!(polygon.distance_to_point(&id, &pt, false) < 1.0e-7);
+    }
+}
+
+#[test]
+fn sample_cuboid_and_polyline() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let cuboid = Cuboid::new(Vector2::new(3.0f64, 1.0));
+    let pts = sample::sample_boundary(&cuboid, &mut rng, 4000);
+    // The vertical sides are three times shorter than the horizontal ones.
+    let nvertical = pts
+        .iter()
+        .filter(|pt| (pt.x.abs() - 3.0).abs() < 1.0e-7)
+        .count();
+    assert!((nvertical as f64 - 1000.0).abs() < 150.0);
+
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(0.0f64, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+        ],
+        None,
+    );
+    for pt in sample::sample_boundary(&polyline, &mut rng, 100) {
+        assert!(pt.y.abs() < 1.0e-7 || (pt.x - 1.0).abs() < 1.0e-7);
+    }
+}
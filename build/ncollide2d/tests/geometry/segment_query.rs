@@ -0,0 +1,59 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query;
+use ncollide2d::shape::{Ball, Cuboid, Polyline, Segment};
+
+#[test]
+fn clip_segment_with_convex_shapes() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let m = Isometry2::new(Vector2::new(2.0, 0.0), 0.0);
+
+    let seg = Segment::new(Point2::new(-2.0, 0.0), Point2::new(6.0, 0.0));
+    let clipped = query::clip_segment_with_shape(&m, &cuboid, &seg).unwrap();
+    assert_relative_eq!(*clipped.a(), Point2::new(1.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*clipped.b(), Point2::new(3.0, 0.0), epsilon = 1.0e-7);
+
+    // Segment ending inside of the shape.
+    let seg = Segment::new(Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0));
+    let clipped = query::clip_segment_with_shape(&m, &cuboid, &seg).unwrap();
+    assert_relative_eq!(*clipped.a(), Point2::new(1.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*clipped.b(), Point2::new(2.0, 0.0), epsilon = 1.0e-7);
+
+    // Segment entirely inside of the shape.
+    let ball = Ball::new(2.0);
+    let seg = Segment::new(Point2::new(-0.5, 0.5), Point2::new(0.5, 0.5));
+    let clipped = query::clip_segment_with_shape(&Isometry2::identity(), &ball, &seg).unwrap();
+    assert_eq!(clipped, seg);
+    assert!(query::segment_intersects_shape(
+        &Isometry2::identity(),
+        &ball,
+        &seg
+    ));
+
+    // Segment stopping before the shape.
+    let seg = Segment::new(Point2::new(-2.0, 0.0), Point2::new(0.5, 0.0));
+    assert!(query::clip_segment_with_shape(&m, &cuboid, &seg).is_none());
+    assert!(!query::segment_intersects_shape(&m, &cuboid, &seg));
+}
+
+#[test]
+fn segment_intersects_polyline() {
+    let polyline = Polyline::new(
+        vec![
+            Point2::new(0.0, -1.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(2.0, 1.0),
+        ],
+        None,
+    );
+    let m = Isometry2::identity();
+
+    let blocked = Segment::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0));
+    assert!(query::segment_intersects_shape(&m, &polyline, &blocked));
+
+    let clipped = query::clip_segment_with_shape(&m, &polyline, &blocked).unwrap();
+    assert_relative_eq!(*clipped.a(), Point2::new(0.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(*clipped.b(), Point2::new(0.0, 0.0), epsilon = 1.0e-7);
+
+    let free = Segment::new(Point2::new(0.5, 0.0), Point2::new(1.5, 0.5));
+    assert!(!query::segment_intersects_shape(&m, &polyline, &free));
+}
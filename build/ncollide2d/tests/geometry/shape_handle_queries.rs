@@ -0,0 +1,70 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, AABB};
+use ncollide2d::query::{PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Compound, Cuboid, Polyline, ShapeHandle};
+
+// Generic over the shape type, so it accepts shape handles directly.
+fn first_hit<G: RayCast<f64>>(shapes: &[(Isometry2<f64>, G)], ray: &Ray<f64>) -> Option<usize> {
+    let mut best = None;
+
+    for (i, (m, shape)) in shapes.iter().enumerate() {
+        if let Some(toi) = shape.toi_with_ray(m, ray, f64::MAX, true) {
+            if best.map(|(_, t)| toi < t).unwrap_or(true) {
+                best = Some((i, toi));
+            }
+        }
+    }
+
+    best.map(|(i, _)| i)
+}
+
+fn shapes() -> Vec<(Isometry2<f64>, ShapeHandle<f64>)> {
+    let polyline = Polyline::new(vec![Point2::new(0.0, -2.0), Point2::new(0.0, 2.0)], None);
+    let compound = Compound::new(vec![(
+        Isometry2::identity(),
+        ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5))),
+    )]);
+
+    vec![
+        (
+            Isometry2::translation(10.0, 0.0),
+            ShapeHandle::new(Ball::new(1.0)),
+        ),
+        (Isometry2::translation(5.0, 0.0), ShapeHandle::new(polyline)),
+        (Isometry2::translation(7.0, 0.0), ShapeHandle::new(compound)),
+    ]
+}
+
+#[test]
+fn shape_handle_ray_cast() {
+    let shapes = shapes();
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    assert_eq!(first_hit(&shapes, &ray), Some(1));
+
+    let ray = Ray::new(Point2::new(20.0, 0.0), -Vector2::x());
+    assert_eq!(first_hit(&shapes, &ray), Some(0));
+
+    let (m, compound) = &shapes[2];
+    assert_relative_eq!(compound.toi_with_ray(m, &ray, 100.0, true).unwrap(), 12.5);
+    assert!(!compound.intersects_ray(m, &ray, 10.0));
+}
+
+#[test]
+fn shape_handle_point_query_and_bounding_volumes() {
+    let shapes = shapes();
+    let (m, ball) = &shapes[0];
+
+    assert!(ball.contains_point(m, &Point2::new(10.5, 0.0)));
+    assert_relative_eq!(
+        ball.distance_to_point(m, &Point2::new(13.0, 0.0), true),
+        2.0
+    );
+
+    let aabb: AABB<f64> = bounding_volume::aabb(ball, m);
+    assert_eq!(
+        aabb,
+        AABB::new(Point2::new(9.0, -1.0), Point2::new(11.0, 1.0))
+    );
+    let sphere = bounding_volume::bounding_sphere(ball, m);
+    assert_eq!(*sphere.center(), Point2::new(10.0, 0.0));
+}
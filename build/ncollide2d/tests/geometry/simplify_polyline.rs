@@ -0,0 +1,117 @@
+use na::{Isometry2, Point2};
+use ncollide2d::query::PointQuery;
+use ncollide2d::shape::Polyline;
+use ncollide2d::transformation::simplify_polyline;
+use std::f64::consts::PI;
+
+fn assert_within(original: &Polyline<f64>, simplified: &Polyline<f64>, epsilon: f64) {
+    let m = Isometry2::identity();
+
+    for pt in original.points() {
+        assert!(simplified.distance_to_point(&m, pt, true) <= epsilon + 1.0e-9);
+    }
+
+    for pt in simplified.points() {
+        assert!(original.points().contains(pt));
+    }
+}
+
+#[test]
+fn simplify_straight_line() {
+    let points = (0..=100)
+        .map(|i| Point2::new(i as f64 * 0.1, 0.0))
+        .collect();
+    let line = Polyline::new(points, None);
+    let simplified = simplify_polyline(&line, 1.0e-6);
+
+    assert_eq!(simplified.edges().len(), 1);
+    assert_eq!(simplified.points()[0], Point2::new(0.0, 0.0));
+    assert_eq!(simplified.points()[1], Point2::new(10.0, 0.0));
+}
+
+#[test]
+fn simplify_traced_outline() {
+    // A square whose sides are sampled every 0.01, with some jitter.
+    let mut points = Vec::new();
+
+    for side in 0..4 {
+        for k in 0..100 {
+            let t = k as f64 * 0.01;
+            let jitter = if k % 2 == 0 { 0.001 } else { -0.001 };
+            let pt = match side {
+                0 => Point2::new(t, jitter),
+                1 => Point2::new(1.0 + jitter, t),
+                2 => Point2::new(1.0 - t, 1.0 + jitter),
+                _ => Point2::new(jitter, 1.0 - t),
+            };
+            points.push(pt);
+        }
+    }
+
+    let n = points.len();
+    let indices = (0..n).map(|i| Point2::new(i, (i + 1) % n)).collect();
+    let square = Polyline::new(points, Some(indices));
+    let simplified = simplify_polyline(&square, 0.005);
+
+    assert_eq!(simplified.points().len(), 4);
+    assert_eq!(simplified.edges().len(), 4);
+    assert_relative_eq!(simplified.area(), 1.0, epsilon = 0.01);
+    assert_within(&square, &simplified, 0.005);
+
+    // The tolerance is tight enough to keep the jitter.
+    let detailed = simplify_polyline(&square, 0.0005);
+    assert_eq!(detailed.points().len(), n);
+}
+
+#[test]
+fn simplify_circle() {
+    let n = 1000;
+    let points = (0..n)
+        .map(|i| {
+            let angle = i as f64 * 2.0 * PI / n as f64;
+            Point2::new(angle.cos(), angle.sin())
+        })
+        .collect();
+    let indices = (0..n).map(|i| Point2::new(i, (i + 1) % n)).collect();
+    let circle = Polyline::new(points, Some(indices));
+
+    let simplified = simplify_polyline(&circle, 0.01);
+    assert!(simplified.points().len() < 40);
+    assert_eq!(simplified.edges().len(), simplified.points().len());
+    assert!(simplified.area() > 0.0);
+    assert_within(&circle, &simplified, 0.01);
+
+    // Loops never collapse into a segment.
+    let tiny = simplify_polyline(&circle, 10.0);
+    assert_eq!(tiny.points().len(), 3);
+    assert_eq!(tiny.edges().len(), 3);
+}
+
+#[test]
+fn simplify_keeps_junctions() {
+    // A "T" made of three sampled branches meeting at the origin.
+    let mut points = vec![Point2::origin()];
+    let mut indices = Vec::new();
+
+    for dir in &[
+        Point2::new(-1.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, -1.0),
+    ] {
+        let mut prev = 0;
+
+        for k in 1..=10 {
+            points.push(Point2::from(dir.coords * (k as f64 * 0.1)));
+            indices.push(Point2::new(prev, points.len() - 1));
+            prev = points.len() - 1;
+        }
+    }
+
+    let t = Polyline::new(points, Some(indices));
+    let simplified = simplify_polyline(&t, 0.01);
+
+    assert_eq!(simplified.edges().len(), 3);
+    assert_eq!(simplified.points().len(), 4);
+    assert!(simplified.points().contains(&Point2::origin()));
+    assert_within(&t, &simplified, 0.01);
+}
@@ -0,0 +1,38 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume;
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Cuboid, Swept};
+
+#[test]
+fn swept_ball_aabb_covers_both_endpoints() {
+    let swept = Swept::new(Ball::new(1.0f64), Vector2::new(4.0, 0.0));
+    let pos = Isometry2::new(Vector2::new(0.0, 1.0), na::zero());
+    let aabb = bounding_volume::aabb(&swept, &pos);
+
+    assert_relative_eq!(*aabb.mins(), Point2::new(-1.0, 0.0));
+    assert_relative_eq!(*aabb.maxs(), Point2::new(5.0, 2.0));
+}
+
+#[test]
+fn swept_ball_interferes_along_the_motion() {
+    let swept = Swept::new(Ball::new(0.5f64), Vector2::new(10.0, 0.0));
+    let cuboid = Cuboid::new(Vector2::new(0.5, 0.5));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(5.0, 0.0), na::zero());
+
+    // Neither endpoint of the motion touches the cuboid, but the swept volume does.
+    assert!(query::contact(&m1, &swept.shape, &m2, &cuboid, 0.0).is_none());
+    assert!(query::contact(&m1, &swept, &m2, &cuboid, 0.0).is_some());
+    assert!(swept.contains_point(&m1, &Point2::new(5.0, 0.4)));
+}
+
+#[test]
+fn swept_ball_ray_cast() {
+    let swept = Swept::new(Ball::new(1.0f64), Vector2::new(0.0, 4.0));
+    let ray = Ray::new(Point2::new(-10.0, 2.0), Vector2::x());
+    let toi = swept
+        .toi_with_ray(&Isometry2::identity(), &ray, std::f64::MAX, true)
+        .unwrap();
+
+    assert_relative_eq!(toi, 9.0, epsilon = 1.0e-6);
+}
@@ -0,0 +1,46 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, BoundingVolume};
+use ncollide2d::shape::{Cuboid, Segment};
+
+#[test]
+fn aabb_swept_bounds_rotating_segment() {
+    let segment = Segment::new(Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0));
+    let m_start = Isometry2::new(Vector2::zeros(), 0.0);
+    let m_end = Isometry2::new(Vector2::new(0.5, 0.0), std::f64::consts::PI * 0.9);
+    let swept = bounding_volume::aabb_swept(&segment, &m_start, &m_end);
+
+    // The union of the start and end AABBs misses the middle of the motion.
+    let union = bounding_volume::aabb(&segment, &m_start)
+        .merged(&bounding_volume::aabb(&segment, &m_end));
+    let mid = bounding_volume::aabb(&segment, &m_start.lerp_slerp(&m_end, 0.5));
+    assert!(!union.contains(&mid));
+
+    for i in 0..=100 {
+        let m = m_start.lerp_slerp(&m_end, i as f64 / 100.0);
+        assert!(swept.contains(&bounding_volume::aabb(&segment, &m)));
+    }
+}
+
+#[test]
+fn bounding_sphere_swept_bounds_rotating_cuboid() {
+    let cuboid = Cuboid::new(Vector2::new(2.0f64, 0.5));
+    let m_start = Isometry2::new(Vector2::new(3.0, 0.0), 0.0);
+    let m_end = Isometry2::new(Vector2::new(-3.0, 1.0), std::f64::consts::PI * 0.7);
+    let swept = bounding_volume::bounding_sphere_swept(&cuboid, &m_start, &m_end);
+
+    for i in 0..=100 {
+        let m = m_start.lerp_slerp(&m_end, i as f64 / 100.0);
+        assert!(swept.contains(&bounding_volume::bounding_sphere(&cuboid, &m)));
+    }
+}
+
+#[test]
+fn aabb_swept_of_a_translation() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m_start = Isometry2::new(Vector2::zeros(), na::zero());
+    let m_end = Isometry2::new(Vector2::new(3.0, 0.0), na::zero());
+    let swept = bounding_volume::aabb_swept(&cuboid, &m_start, &m_end);
+
+    assert_relative_eq!(*swept.mins(), Point2::new(-1.0, -1.0));
+    assert_relative_eq!(*swept.maxs(), Point2::new(4.0, 1.0));
+}
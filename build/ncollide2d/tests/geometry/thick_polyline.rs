@@ -0,0 +1,92 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume;
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::{self, Ray, RayCast};
+use ncollide2d::shape::{Ball, FeatureId, Polyline, ShapeHandle};
+
+fn wall(thickness: f64) -> Polyline<f64> {
+    Polyline::new(vec![Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0)], None)
+        .with_thickness(thickness)
+}
+
+#[test]
+fn thick_polyline_ray_cast() {
+    let identity = Isometry2::identity();
+    let thin = wall(0.0);
+    let thick = wall(0.1);
+    let down = Ray::new(Point2::new(0.5, 1.0), -Vector2::y());
+    let up = Ray::new(Point2::new(0.5, -1.0), Vector2::y());
+
+    assert_relative_eq!(
+        thin.toi_with_ray(&identity, &down, 10.0, true).unwrap(),
+        1.0
+    );
+    assert_relative_eq!(
+        thick.toi_with_ray(&identity, &down, 10.0, true).unwrap(),
+        0.9,
+        epsilon = 1.0e-6
+    );
+
+    let hit = thick
+        .toi_and_normal_with_ray(&identity, &up, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.9, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, -Vector2::y(), epsilon = 1.0e-6);
+    assert_ne!(
+        hit.feature,
+        thick
+            .toi_and_normal_with_ray(&identity, &down, 10.0, true)
+            .unwrap()
+            .feature
+    );
+
+    // The ends of the segments are rounded.
+    let side = Ray::new(Point2::new(3.0, 0.0), -Vector2::x());
+    assert_relative_eq!(
+        thick.toi_with_ray(&identity, &side, 10.0, true).unwrap(),
+        0.9,
+        epsilon = 1.0e-6
+    );
+
+    let aabb = bounding_volume::aabb(&thick, &identity);
+    assert_relative_eq!(aabb.mins, Point2::new(-2.1, -0.1));
+    assert_relative_eq!(aabb.maxs, Point2::new(2.1, 0.1));
+}
+
+#[test]
+fn thick_polyline_contact() {
+    let identity = Isometry2::identity();
+    let ball = Ball::new(0.2);
+    let pos = Isometry2::new(Vector2::new(0.5, 0.25), 0.0);
+
+    assert!(query::contact(&identity, &wall(0.0), &pos, &ball, 0.0).is_none());
+
+    let contact = query::contact(&identity, &wall(0.1), &pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.world1, Point2::new(0.5, 0.1), epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector2::y(), epsilon = 1.0e-6);
+
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        identity,
+        ShapeHandle::new(wall(0.1)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        pos,
+        ShapeHandle::new(ball),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    let deepest = manifold.deepest_contact().unwrap();
+    assert_relative_eq!(deepest.contact.depth, 0.05, epsilon = 1.0e-6);
+    assert!(deepest.kinematic.dilation1() == 0.1 || deepest.kinematic.dilation2() == 0.1);
+    assert_ne!(deepest.kinematic.feature1(), FeatureId::Unknown);
+}
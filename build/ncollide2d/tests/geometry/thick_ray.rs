@@ -0,0 +1,58 @@
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, AABB};
+use ncollide2d::partitioning::{BVH, BVT};
+use ncollide2d::query::{self, visitors::ThickRayInterferencesCollector, Ray};
+use ncollide2d::shape::{Ball, Cuboid};
+
+#[test]
+fn thick_ray_cast_cuboid() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let m = Isometry2::identity();
+    let ray = Ray::new(Point2::new(-5.0, 1.3), Vector2::x());
+
+    // The thin ray passes above the cuboid.
+    assert!(query::thick_ray_cast(&m, &cuboid, &ray, 0.0, 10.0).is_none());
+    assert!(query::thick_ray_cast(&m, &cuboid, &ray, 0.2, 10.0).is_none());
+
+    // The thick ray grazes the top-left corner of the cuboid.
+    let hit = query::thick_ray_cast(&m, &cuboid, &ray, 0.5, 10.0).unwrap();
+    assert_relative_eq!(hit.toi, 3.6, epsilon = 1.0e-6);
+
+    let hit = query::thick_ray_cast_with_support_map(&m, &cuboid, &ray, 0.5, 10.0).unwrap();
+    assert_relative_eq!(hit.toi, 3.6, epsilon = 1.0e-6);
+
+    // Head-on hit.
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    let hit = query::thick_ray_cast(&m, &cuboid, &ray, 0.5, 10.0).unwrap();
+    assert_relative_eq!(hit.toi, 3.5, epsilon = 1.0e-6);
+    assert_relative_eq!(hit.normal, -Vector2::x(), epsilon = 1.0e-6);
+    assert!(query::thick_ray_cast(&m, &cuboid, &ray, 0.5, 3.0).is_none());
+}
+
+#[test]
+fn thick_ray_bvt_interferences() {
+    let ball = Ball::new(0.1);
+    let centers = [
+        Point2::new(1.0, 0.55),
+        Point2::new(2.0, 1.0),
+        Point2::new(3.0, -0.3),
+        Point2::new(-3.0, 0.0),
+    ];
+    let leaves: Vec<(usize, AABB<f64>)> = centers
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let pos = Isometry2::new(c.coords, na::zero());
+            (i, bounding_volume::aabb(&ball, &pos))
+        })
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    let ray = Ray::new(Point2::origin(), Vector2::x());
+    let mut hits = Vec::new();
+    bvt.visit(&mut ThickRayInterferencesCollector::new(
+        &ray, 0.5, 10.0, &mut hits,
+    ));
+    hits.sort();
+    assert_eq!(hits, vec![0, 2]);
+}
@@ -0,0 +1,51 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::{self, AABB};
+use ncollide2d::shape::{Capsule, ConvexPolygon, Cuboid, Segment, Shape, SupportMap};
+
+fn assert_tight<G: Shape<f64> + SupportMap<f64>>(shape: &G, m: &Isometry2<f64>) {
+    let aabb: AABB<f64> = shape.aabb(m);
+
+    for i in 0..2 {
+        let mut dir = Vector2::zeros();
+        dir[i] = 1.0;
+        assert_relative_eq!(
+            aabb.maxs[i],
+            shape.support_point(m, &dir)[i],
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            aabb.mins[i],
+            shape.support_point(m, &-dir)[i],
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn rotated_shapes_have_tight_aabbs() {
+    let cuboid = Cuboid::new(Vector2::new(2.0, 0.5));
+    let polygon = ConvexPolygon::try_from_points(&[
+        Point2::new(-1.0, -1.0),
+        Point2::new(2.0, -0.5),
+        Point2::new(1.0, 1.5),
+        Point2::new(-0.5, 1.0),
+    ])
+    .unwrap();
+    let capsule = Capsule::new(1.0, 0.3);
+    let segment = Segment::new(Point2::new(-1.0, 0.2), Point2::new(3.0, -1.0));
+
+    for i in 0..16 {
+        let angle = i as f64 * 0.4;
+        let m = Isometry2::new(Vector2::new(1.0, -2.0), angle);
+
+        assert_tight(&cuboid, &m);
+        assert_tight(&polygon, &m);
+        assert_tight(&capsule, &m);
+        assert_tight(&segment, &m);
+
+        let aabb = polygon.aabb(&m);
+        let expected = bounding_volume::point_cloud_aabb(&m, polygon.points());
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-10);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-10);
+    }
+}
@@ -0,0 +1,196 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::snapshot;
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Cuboid, FeatureId, ShapeHandle, TileGrid};
+
+// A floor on the first row, a step on the last three columns of the second row, and a
+// floating tile.
+fn level() -> TileGrid<f64> {
+    TileGrid::from_fn(4, 8, Vector2::new(1.0, 1.0), |i, j| {
+        i == 0 || (i == 1 && j >= 5) || (i == 3 && j == 1)
+    })
+}
+
+#[test]
+fn tile_grid_edges() {
+    let mut grid = level();
+    assert_eq!(grid.edges().len(), 10);
+
+    let floor = grid.horizontal_face_edge(1, 0).unwrap();
+    assert_eq!(grid.horizontal_face_edge(1, 4), Some(floor));
+    assert_eq!(grid.horizontal_face_edge(1, 5), None);
+    assert_eq!(grid.edges()[floor].normal, Vector2::y_axis());
+
+    let segment = grid.edge_segment(floor);
+    assert_eq!(segment.a, Point2::new(5.0, 1.0));
+    assert_eq!(segment.b, Point2::new(0.0, 1.0));
+    assert_eq!(segment.normal(), Some(Vector2::y_axis()));
+
+    let side = grid.vertical_face_edge(0, 8).unwrap();
+    assert_eq!(grid.vertical_face_edge(1, 8), Some(side));
+
+    assert_eq!(grid.aabb().mins, Point2::new(0.0, 0.0));
+    assert_eq!(grid.aabb().maxs, Point2::new(8.0, 4.0));
+
+    grid.set_solid(3, 1, false);
+    assert_eq!(grid.edges().len(), 6);
+    assert_eq!(grid.aabb().maxs, Point2::new(8.0, 2.0));
+}
+
+#[test]
+fn tile_grid_ray_cast() {
+    let grid = level();
+    let m = Isometry2::identity();
+    let floor = grid.horizontal_face_edge(1, 2).unwrap();
+
+    let down = Ray::new(Point2::new(2.5, 3.0), -Vector2::y());
+    let hit = grid.toi_and_normal_with_ray(&m, &down, 10.0, true).unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector2::y());
+    assert_eq!(hit.feature, FeatureId::Face(floor));
+    assert!(grid.toi_with_ray(&m, &down, 1.5, true).is_none());
+
+    let diagonal = Ray::new(Point2::new(3.0, 2.5), Vector2::new(1.0, -0.5));
+    let hit = grid
+        .toi_and_normal_with_ray(&m, &diagonal, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    let diagonal = Ray::new(Point2::new(1.0, 2.5), Vector2::new(1.0, -0.5));
+    let hit = grid
+        .toi_and_normal_with_ray(&m, &diagonal, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 3.0);
+    assert_relative_eq!(hit.normal, Vector2::y());
+
+    let towards_step = Ray::new(Point2::new(3.0, 1.5), Vector2::x());
+    let hit = grid
+        .toi_and_normal_with_ray(&m, &towards_step, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    let from_outside = Ray::new(Point2::new(-1.0, 0.5), Vector2::x());
+    let hit = grid
+        .toi_and_normal_with_ray(&m, &from_outside, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 1.0);
+    assert_relative_eq!(hit.normal, -Vector2::x());
+
+    let from_inside = Ray::new(Point2::new(2.5, 0.5), Vector2::y());
+    assert_eq!(grid.toi_with_ray(&m, &from_inside, 10.0, true), Some(0.0));
+    let hit = grid
+        .toi_and_normal_with_ray(&m, &from_inside, 10.0, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 0.5);
+    assert_relative_eq!(hit.normal, Vector2::y());
+    assert_eq!(hit.feature, FeatureId::Face(floor + grid.edges().len()));
+
+    let miss = Ray::new(Point2::new(0.5, 2.5), Vector2::x());
+    assert!(grid.toi_with_ray(&m, &miss, 100.0, true).is_none());
+
+    // The grid can be moved around.
+    let shifted = Isometry2::new(Vector2::new(0.0, -1.0), 0.0);
+    assert_relative_eq!(grid.toi_with_ray(&shifted, &down, 10.0, true).unwrap(), 3.0);
+}
+
+#[test]
+fn tile_grid_point_query() {
+    let grid = level();
+    let m = Isometry2::identity();
+
+    assert!(grid.contains_point(&m, &Point2::new(2.5, 0.5)));
+    assert!(grid.contains_point(&m, &Point2::new(6.5, 1.5)));
+    assert!(!grid.contains_point(&m, &Point2::new(2.5, 1.5)));
+    assert!(!grid.contains_point(&m, &Point2::new(-0.5, 0.5)));
+
+    let proj = grid.project_point(&m, &Point2::new(2.5, 1.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(2.5, 1.0));
+
+    let proj = grid.project_point(&m, &Point2::new(2.5, 0.9), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(2.5, 1.0));
+    assert_relative_eq!(
+        grid.distance_to_point(&m, &Point2::new(2.5, 0.9), true),
+        0.0
+    );
+
+    // Without any solid cell, points are projected on the boundary of the grid.
+    let empty = TileGrid::new(2, 3, Vector2::new(1.0, 1.0));
+    let proj = empty.project_point(&m, &Point2::new(1.0, 1.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 2.0));
+}
+
+#[test]
+fn tile_grid_contact() {
+    let grid = level();
+    let m = Isometry2::identity();
+    let ball = Ball::new(0.5);
+    let ball_pos = Isometry2::new(Vector2::new(2.0, 1.4), 0.0);
+
+    let contact = query::contact(&m, &grid, &ball_pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal.into_inner(), Vector2::y(), epsilon = 1.0e-6);
+
+    // A box resting across several tiles only touches the top of the floor.
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        m,
+        ShapeHandle::new(grid),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::new(Vector2::new(2.0, 1.49), 0.0),
+        ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 0.5))),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert!(manifold.len() > 0);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.normal.x, 0.0, epsilon = 1.0e-6);
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn tile_grid_snapshot() {
+    let grid = level();
+    let mut bytes = Vec::new();
+    snapshot::write_shape(&grid, &mut bytes).unwrap();
+
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    let loaded = loaded.as_shape::<TileGrid<f64>>().unwrap();
+    assert_eq!(loaded.nrows(), grid.nrows());
+    assert_eq!(loaded.ncols(), grid.ncols());
+    assert_eq!(loaded.cell_size(), grid.cell_size());
+    assert_eq!(loaded.edges().len(), grid.edges().len());
+
+    for i in 0..grid.nrows() {
+        for j in 0..grid.ncols() {
+            assert_eq!(loaded.is_solid(i, j), grid.is_solid(i, j));
+        }
+    }
+
+    // Writing the loaded grid gives the same bytes.
+    let mut bytes2 = Vec::new();
+    snapshot::write_shape(loaded, &mut bytes2).unwrap();
+    assert_eq!(bytes, bytes2);
+
+    // A truncated grid is rejected.
+    match snapshot::read_shape::<f64>(&mut &bytes[..bytes.len() - 1]) {
+        Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof),
+        Ok(_) => panic!("The truncated grid should not be read."),
+    }
+}
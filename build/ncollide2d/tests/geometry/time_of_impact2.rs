@@ -1,4 +1,5 @@
-use na::{self, Isometry2, Vector2};
+use na::{self, Isometry2, Point2, Vector2};
+use ncollide2d::interpolation::ConstantVelocityRigidMotion;
 use ncollide2d::query;
 use ncollide2d::shape::{Ball, Cuboid};
 
@@ -88,3 +89,108 @@ fn cuboid_cuboid_toi_issue_214() {
     .unwrap();
     assert!(toi.is_some());
 }
+
+#[test]
+fn spinning_blade_ball_rotational_toi() {
+    let blade = Cuboid::new(Vector2::new(2.0, 0.1));
+    let ball = Ball::new(0.2);
+
+    let blade_pos = Isometry2::identity();
+    let ball_pos = Isometry2::new(Vector2::new(0.0, 1.5), na::zero());
+    let zero = Vector2::zeros();
+
+    // Without rotation, the blade never reaches the ball.
+    let toi = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &blade_pos,
+        &zero,
+        &blade,
+        &ball_pos,
+        &zero,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+
+    // Even a blade doing a full turn every time unit does not tunnel through the ball.
+    let angvel = std::f64::consts::PI * 2.0;
+    let ball_motion = ConstantVelocityRigidMotion::new(0.0, ball_pos, Point2::origin(), zero, 0.0);
+    let blade_motion =
+        ConstantVelocityRigidMotion::new(0.0, blade_pos, Point2::origin(), zero, angvel);
+    let dispatcher = query::DefaultTOIDispatcher;
+    let toi = query::time_of_impact_with_angular_velocity(
+        &dispatcher,
+        &blade_motion,
+        &blade,
+        &ball_motion,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .expect("The blade should hit the ball.");
+    assert_eq!(toi.status, query::TOIStatus::Converged);
+    assert!(toi.toi > 0.0 && toi.toi < 0.25);
+    let toi_at_start = toi.toi;
+
+    let blade_pos_at_toi = Isometry2::new(Vector2::zeros(), angvel * toi.toi);
+    let dist = query::distance(&blade_pos_at_toi, &blade, &ball_pos, &ball);
+    assert!(dist < 1.0e-5);
+
+    // A blade spinning too slowly does not reach the ball in time.
+    let blade_motion =
+        ConstantVelocityRigidMotion::new(0.0, blade_pos, Point2::origin(), zero, 0.01);
+    let toi = query::time_of_impact_with_angular_velocity(
+        &dispatcher,
+        &blade_motion,
+        &blade,
+        &ball_motion,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+
+    // The search starts at the starting time of the motions.
+    let ball_motion = ConstantVelocityRigidMotion::new(1.0, ball_pos, Point2::origin(), zero, 0.0);
+    let blade_motion =
+        ConstantVelocityRigidMotion::new(1.0, blade_pos, Point2::origin(), zero, angvel);
+    let delayed = query::time_of_impact_with_angular_velocity(
+        &dispatcher,
+        &blade_motion,
+        &blade,
+        &ball_motion,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .expect("The blade should hit the ball.");
+    assert_relative_eq!(delayed.toi, toi_at_start + 1.0, epsilon = 1.0e-6);
+
+    // Without rotation, the translational time of impact is used.
+    let ball_motion = ConstantVelocityRigidMotion::new(
+        1.0,
+        ball_pos,
+        Point2::origin(),
+        Vector2::new(0.0, -1.0),
+        0.0,
+    );
+    let blade_motion =
+        ConstantVelocityRigidMotion::new(1.0, blade_pos, Point2::origin(), zero, 0.0);
+    let sliding = query::time_of_impact_with_angular_velocity(
+        &dispatcher,
+        &blade_motion,
+        &blade,
+        &ball_motion,
+        &ball,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .expect("The ball should hit the blade.");
+    assert_relative_eq!(sliding.toi, 2.2, epsilon = 1.0e-6);
+}
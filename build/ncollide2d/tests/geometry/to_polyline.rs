@@ -0,0 +1,103 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use ncollide2d::procedural;
+use ncollide2d::shape::{
+    Ball, Compound, ConvexPolygon, Cuboid, HeightField, Plane, Polyline, Segment, Shape,
+    ShapeHandle,
+};
+use ncollide2d::transformation::ToPolyline;
+
+#[test]
+fn dyn_shape_to_polyline() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    let polygon = ConvexPolygon::try_from_points(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ])
+    .unwrap();
+
+    let shapes: Vec<(Box<dyn Shape<f64>>, usize)> = vec![
+        (Box::new(Ball::new(1.0)), 16),
+        (Box::new(cuboid), 4),
+        (Box::new(polygon), 3),
+        (Box::new(Plane::new(Vector2::y_axis())), 0),
+    ];
+
+    for (shape, npoints) in shapes {
+        assert_eq!(shape.to_polyline(16).coords().len(), npoints);
+    }
+}
+
+#[test]
+fn heightfield_and_plane_to_polyline() {
+    let heightfield = HeightField::new(
+        DVector::from_vec(vec![0.0, 1.0, 0.0]),
+        Vector2::new(2.0, 1.0),
+    );
+    let polyline = heightfield.to_polyline(());
+    assert_eq!(
+        polyline.coords(),
+        &[
+            Point2::new(-1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, 0.0)
+        ][..]
+    );
+    assert!(!polyline.is_closed());
+    assert_eq!(polyline.edges(), vec![Point2::new(0, 1), Point2::new(1, 2)]);
+
+    let polyline = Plane::new(Vector2::y_axis()).to_polyline(3.0);
+    assert_eq!(
+        polyline.coords(),
+        &[Point2::new(3.0, 0.0), Point2::new(-3.0, 0.0)][..]
+    );
+    assert_eq!(polyline.edges(), vec![Point2::new(0, 1)]);
+}
+
+#[test]
+fn procedural_polyline_closure() {
+    let segment = Segment::new(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+    let polyline: Polyline<f64> = segment.to_polyline(()).into();
+    assert_eq!(polyline.edges().len(), 1);
+
+    let arc: Polyline<f64> = procedural::arc(1.0, 0.0, 1.0, 4).into();
+    assert_eq!(arc.edges().len(), 4);
+
+    let circle = procedural::circle(&2.0, 16);
+    assert!(circle.is_closed());
+    let circle: Polyline<f64> = circle.into();
+    assert_eq!(circle.edges().len(), 16);
+    assert_eq!(circle.edges()[15].indices, Point2::new(15, 0));
+
+    // The last point of a loop may duplicate its first point instead of being flagged as closed.
+    let points = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(0.0, 0.0),
+    ];
+    let edges = procedural::Polyline::new(points, None).edges();
+    assert_eq!(
+        edges,
+        vec![Point2::new(0, 1), Point2::new(1, 2), Point2::new(2, 0)]
+    );
+}
+
+#[test]
+fn compound_to_polyline() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::repeat(1.0)));
+    let compound = Compound::new(vec![
+        (Isometry2::translation(-3.0, 0.0), cuboid.clone()),
+        (Isometry2::translation(3.0, 0.0), cuboid),
+    ]);
+    let polyline = compound.to_polyline(16);
+
+    assert_eq!(polyline.coords().len(), 8);
+    assert!(polyline.coords()[..4].iter().all(|pt| pt.x < 0.0));
+    assert!(polyline.coords()[4..].iter().all(|pt| pt.x > 0.0));
+
+    // Each part is a separate loop, without any edge bridging both cuboids.
+    let edges = polyline.edges();
+    assert_eq!(edges.len(), 8);
+    assert!(edges.iter().all(|e| (e.x < 4) == (e.y < 4)));
+}
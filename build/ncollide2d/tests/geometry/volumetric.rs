@@ -0,0 +1,83 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::shape::{Ball, Capsule, Compound, ConvexPolygon, Cuboid, ShapeHandle, Volumetric};
+
+#[test]
+fn convex_polygon_matches_cuboid() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 2.0));
+    let points = [
+        Point2::new(4.0, 3.0),
+        Point2::new(4.0, 7.0),
+        Point2::new(2.0, 7.0),
+        Point2::new(2.0, 3.0),
+    ];
+    let polygon = ConvexPolygon::try_from_points(&points).unwrap();
+
+    let (mass, com, inertia) = polygon.mass_properties(2.0);
+    assert_relative_eq!(mass, cuboid.mass(2.0), epsilon = 1.0e-7);
+    assert_relative_eq!(com, Point2::new(3.0, 5.0), epsilon = 1.0e-7);
+    assert_relative_eq!(inertia, cuboid.angular_inertia(mass), epsilon = 1.0e-7);
+    // Moment of a rectangle: m(w² + h²)/12.
+    assert_relative_eq!(inertia, 16.0 * (4.0 + 16.0) / 12.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn compound_mass_properties() {
+    // Two unit squares side by side form a 2x1 rectangle.
+    let square = ShapeHandle::new(Cuboid::new(Vector2::new(0.5f64, 0.5)));
+    let compound = Compound::new(vec![
+        (Isometry2::new(Vector2::new(1.5, 0.0), 0.3), square.clone()),
+        (Isometry2::new(Vector2::new(2.5, 0.0), -1.2), square),
+    ]);
+    let rect = Cuboid::new(Vector2::new(1.0, 0.5));
+
+    assert_relative_eq!(compound.volume(), 2.0, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        compound.center_of_mass(),
+        Point2::new(2.0, 0.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        compound.unit_angular_inertia(),
+        rect.unit_angular_inertia(),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn ball_and_capsule_mass_properties() {
+    let ball = Ball::new(2.0f64);
+    assert_relative_eq!(ball.volume(), 4.0 * std::f64::consts::PI, epsilon = 1.0e-7);
+    assert_relative_eq!(ball.unit_angular_inertia(), 2.0, epsilon = 1.0e-7);
+
+    // A capsule with no cylindrical part is a ball.
+    let capsule = Capsule::new(0.0, 2.0f64);
+    assert_relative_eq!(capsule.volume(), ball.volume(), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        capsule.unit_angular_inertia(),
+        ball.unit_angular_inertia(),
+        epsilon = 1.0e-7
+    );
+
+    // Compare with a polygonal approximation of a longer capsule.
+    let capsule = Capsule::new(1.5, 0.5f64);
+    let mut points = Vec::new();
+    let nsubdivs = 500;
+
+    for i in 0..=nsubdivs {
+        let angle = std::f64::consts::PI * (i as f64) / (nsubdivs as f64);
+        points.push(Point2::new(0.5 * angle.cos(), 1.5 + 0.5 * angle.sin()));
+    }
+
+    for i in 0..=nsubdivs {
+        let angle = std::f64::consts::PI * (i as f64) / (nsubdivs as f64);
+        points.push(Point2::new(-0.5 * angle.cos(), -1.5 - 0.5 * angle.sin()));
+    }
+
+    let polygon = ConvexPolygon::try_new(points).unwrap();
+    assert_relative_eq!(capsule.volume(), polygon.volume(), epsilon = 1.0e-4);
+    assert_relative_eq!(
+        capsule.unit_angular_inertia(),
+        polygon.unit_angular_inertia(),
+        epsilon = 1.0e-4
+    );
+}
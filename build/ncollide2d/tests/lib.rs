@@ -3,5 +3,6 @@ extern crate approx;
 extern crate nalgebra as na;
 extern crate ncollide2d;
 
+mod common;
 mod geometry;
 mod pipeline;
@@ -0,0 +1,366 @@
+use crate::common::aabb_at;
+use na::Point2;
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::pipeline::broad_phase::{
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhasePairEvictionPolicy,
+    BroadPhaseUpdateBudget, DBVTBroadPhase,
+};
+use std::time::Duration;
+
+struct CountingHandler {
+    started: usize,
+    stopped: usize,
+}
+
+impl BroadPhaseInterferenceHandler<usize> for CountingHandler {
+    fn is_interference_allowed(&mut self, a: &usize, b: &usize) -> bool {
+        a != b
+    }
+
+    fn interference_started(&mut self, _: &usize, _: &usize) {
+        self.started += 1;
+    }
+
+    fn interference_stopped(&mut self, _: &usize, _: &usize) {
+        self.stopped += 1;
+    }
+}
+
+#[test]
+fn dbvt_pair_manager_persistent_ids() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let h0 = bf.create_proxy(aabb_at(0.0, 1.0), 0);
+    let h1 = bf.create_proxy(aabb_at(1.5, 1.0), 1);
+    let h2 = bf.create_proxy(aabb_at(10.0, 1.0), 2);
+    bf.update(&mut handler);
+
+    assert_eq!(handler.started, 1);
+    assert_eq!(bf.pair_manager().len(), 1);
+    assert!(bf.contains_pair(h0, h1));
+    assert!(bf.contains_pair(h1, h0));
+    assert!(!bf.contains_pair(h0, h2));
+
+    let id01 = bf.pair_manager().pair_id(h1, h0).unwrap();
+    assert_eq!(bf.pair_manager().pair(id01), Some((h0, h1)));
+
+    // Move the third proxy so it interferes with the second one.
+    bf.deferred_set_bounding_volume(h2, aabb_at(3.0, 1.0));
+    bf.update(&mut handler);
+
+    assert_eq!(handler.started, 2);
+    assert_eq!(bf.pair_manager().pair_id(h0, h1), Some(id01));
+    let id12 = bf.pair_manager().pair_id(h1, h2).unwrap();
+    assert_ne!(id01, id12);
+
+    let pairs: Vec<_> = bf.pair_manager().iter().collect();
+    assert_eq!(pairs, vec![(id01, h0, h1), (id12, h1, h2)]);
+
+    // Move the first proxy away.
+    bf.deferred_set_bounding_volume(h0, aabb_at(-10.0, 1.0));
+    bf.update(&mut handler);
+
+    assert_eq!(handler.stopped, 1);
+    assert!(!bf.contains_pair(h0, h1));
+    assert_eq!(bf.pair_manager().pair(id01), None);
+    assert_eq!(bf.pair_manager().pair_id(h1, h2), Some(id12));
+}
+
+#[test]
+fn dbvt_filtered_interference_handles() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let handles: Vec<_> = (0..6)
+        .map(|i| bf.create_proxy(aabb_at(i as f32 * 1.5, 1.0), i))
+        .collect();
+    bf.update(&mut handler);
+
+    let query = AABB::new(Point2::new(-0.5, -0.5), Point2::new(4.0, 0.5));
+    let mut res = Vec::new();
+    bf.interference_handles_with_bounding_volume(&query, None, &mut res);
+    res.sort();
+    assert_eq!(res, handles[..4].to_vec());
+
+    // Only keep the odd proxies.
+    res.clear();
+    bf.interference_handles_with_bounding_volume(&query, Some(&|i: &usize| i % 2 == 1), &mut res);
+    res.sort();
+    assert_eq!(res, vec![handles[1], handles[3]]);
+
+    res.clear();
+    bf.interference_handles_with_point(&Point2::new(7.5, 0.0), None, &mut res);
+    res.sort();
+    assert_eq!(res, vec![handles[5]]);
+
+    // The handles can be used for removal directly.
+    bf.remove(&res, &mut |_, _| {});
+    res.clear();
+    bf.interference_handles_with_point(&Point2::new(7.5, 0.0), None, &mut res);
+    assert!(res.is_empty());
+}
+
+// Allows every interference with the proxy `0` only while `allow_zero` is true.
+struct ToggleHandler {
+    allow_zero: bool,
+    stopped: usize,
+}
+
+impl BroadPhaseInterferenceHandler<usize> for ToggleHandler {
+    fn is_interference_allowed(&mut self, a: &usize, b: &usize) -> bool {
+        a != b && (self.allow_zero || (*a != 0 && *b != 0))
+    }
+
+    fn interference_started(&mut self, _: &usize, _: &usize) {}
+
+    fn interference_stopped(&mut self, _: &usize, _: &usize) {
+        self.stopped += 1;
+    }
+}
+
+#[test]
+fn dbvt_periodic_pair_eviction() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = ToggleHandler {
+        allow_zero: true,
+        stopped: 0,
+    };
+
+    assert_eq!(
+        bf.pair_eviction_policy(),
+        BroadPhasePairEvictionPolicy::Lazy
+    );
+    let _ = bf.create_proxy(aabb_at(0.0, 1.0), 0);
+    let _ = bf.create_proxy(aabb_at(1.5, 1.0), 1);
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 1);
+
+    // Nothing moves so the lazy policy keeps the pair.
+    handler.allow_zero = false;
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 1);
+
+    bf.set_pair_eviction_policy(BroadPhasePairEvictionPolicy::Periodic(3));
+    bf.update(&mut handler);
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 1);
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 0);
+    assert_eq!(handler.stopped, 1);
+}
+
+#[test]
+fn dbvt_explicit_purge() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = ToggleHandler {
+        allow_zero: true,
+        stopped: 0,
+    };
+
+    let _ = bf.create_proxy(aabb_at(0.0, 1.0), 0);
+    let _ = bf.create_proxy(aabb_at(1.5, 1.0), 1);
+    let _ = bf.create_proxy(aabb_at(3.0, 1.0), 2);
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 2);
+
+    handler.allow_zero = false;
+    bf.purge(&mut handler);
+    assert_eq!(bf.num_interferences(), 1);
+    assert_eq!(handler.stopped, 1);
+}
+
+#[test]
+fn dbvt_shrink_to_fit_after_removals() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let handles: Vec<_> = (0..200)
+        .map(|i| bf.create_proxy(aabb_at(i as f32 * 0.5, 1.0), i))
+        .collect();
+    bf.update(&mut handler);
+    assert_eq!(bf.num_proxies(), 200);
+    let capacity = bf.pair_manager().capacity();
+    assert!(capacity >= bf.num_interferences());
+
+    // Only keep the first proxies so the trailing memory can be released.
+    bf.remove(&handles[10..], &mut |_, _| {});
+    bf.update(&mut handler);
+    bf.shrink_to_fit();
+
+    assert_eq!(bf.num_proxies(), 10);
+    assert_eq!(bf.num_interferences(), 9 + 8 + 7 + 6);
+    assert!(bf.pair_manager().capacity() < capacity);
+
+    // The remaining proxies are still usable.
+    bf.deferred_set_bounding_volume(handles[0], aabb_at(-10.0, 1.0));
+    bf.update(&mut handler);
+    assert!(!bf.contains_pair(handles[0], handles[1]));
+}
+
+#[test]
+fn dbvt_proxy_handle_lifecycle() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    // Handles are returned immediately, but the proxies are only inserted by `update`.
+    let h0 = bf.create_proxy(aabb_at(0.0, 1.0), 0);
+    let h1 = bf.create_proxy(aabb_at(1.5, 1.0), 1);
+    assert!(!h0.is_invalid() && h0 != h1);
+    assert!(bf.proxy(h0).is_none());
+
+    bf.update(&mut handler);
+    assert_eq!(bf.proxy(h0).map(|p| *p.1), Some(0));
+    assert_eq!(handler.started, 1);
+
+    // Removal is immediate and reports the interferences of the removed proxies.
+    let mut removed = Vec::new();
+    bf.remove(&[h1], &mut |a, b| removed.push((*a, *b)));
+    assert_eq!(removed.len(), 1);
+    assert!(bf.proxy(h1).is_none());
+    assert_eq!(bf.num_interferences(), 0);
+}
+
+#[test]
+fn dbvt_budgeted_update() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let handles: Vec<_> = (0..100)
+        .map(|i| bf.create_proxy(aabb_at(i as f32 * 1.5, 1.0), i))
+        .collect();
+
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(30)));
+    let inserted = handles.iter().filter(|h| bf.proxy(**h).is_some()).count();
+    assert_eq!(inserted, 30);
+    assert!(handler.started < 30);
+
+    // Proxies waiting for their insertion can be removed.
+    assert!(bf.proxy(handles[50]).is_none());
+    bf.remove(&[handles[50]], &mut |_, _| {});
+
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(30)));
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(30)));
+    assert!(bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(30)));
+    assert_eq!(bf.num_proxies(), 99);
+    assert_eq!(bf.num_interferences(), 97);
+    assert_eq!(handler.started, 97);
+
+    // Nothing left to do.
+    assert!(bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(0)));
+
+    // A full update completes the remaining work.
+    bf.deferred_set_bounding_volume(handles[0], aabb_at(-10.0, 1.0));
+    bf.deferred_set_bounding_volume(handles[99], aabb_at(200.0, 1.0));
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(1)));
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 95);
+    assert_eq!(handler.stopped, 2);
+
+    let budget = BroadPhaseUpdateBudget::Time(Duration::from_secs(60));
+    bf.deferred_set_bounding_volume(handles[0], aabb_at(0.0, 1.0));
+    assert!(bf.update_with_budget(&mut handler, budget));
+    assert_eq!(bf.num_interferences(), 96);
+}
+
+#[test]
+fn dbvt_budgeted_update_is_fifo() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let streamed: Vec<_> = (0..10)
+        .map(|i| bf.create_proxy(aabb_at(i as f32 * 10.0, 1.0), i))
+        .collect();
+    let movers: Vec<_> = (0..5)
+        .map(|i| bf.create_proxy(aabb_at(-100.0 - i as f32 * 10.0, 1.0), 100 + i))
+        .collect();
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(5)));
+
+    // More proxies move at each frame than the budget allows to re-insert: the older ones
+    // are still inserted first.
+    for frame in 0..3 {
+        for (i, h) in movers.iter().enumerate() {
+            let x = -100.0 - i as f32 * 10.0 - frame as f32;
+            bf.deferred_set_bounding_volume(*h, aabb_at(x - 5.0, 1.0));
+        }
+
+        let _ = bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(5));
+    }
+
+    assert!(streamed.iter().all(|h| bf.proxy(*h).is_some()));
+}
+
+#[test]
+fn dbvt_budgeted_update_with_zero_budget() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = CountingHandler {
+        started: 0,
+        stopped: 0,
+    };
+
+    let handles: Vec<_> = (0..3)
+        .map(|i| bf.create_proxy(aabb_at(i as f32 * 1.5, 1.0), i))
+        .collect();
+
+    // Each call still inserts one proxy.
+    for budget in &[
+        BroadPhaseUpdateBudget::Proxies(0),
+        BroadPhaseUpdateBudget::Time(Duration::from_secs(0)),
+    ] {
+        assert!(!bf.update_with_budget(&mut handler, *budget));
+    }
+    assert_eq!(
+        handles.iter().filter(|h| bf.proxy(**h).is_some()).count(),
+        2
+    );
+
+    assert!(bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(0)));
+    assert_eq!(bf.num_interferences(), 2);
+}
+
+#[test]
+fn dbvt_purge_after_partial_update() {
+    let mut bf = DBVTBroadPhase::new(0.0);
+    let mut handler = ToggleHandler {
+        allow_zero: true,
+        stopped: 0,
+    };
+
+    let h0 = bf.create_proxy(aabb_at(0.0, 1.0), 0);
+    let _ = bf.create_proxy(aabb_at(1.5, 1.0), 1);
+    let _ = bf.create_proxy(aabb_at(3.0, 1.0), 2);
+    bf.update(&mut handler);
+    assert_eq!(bf.num_interferences(), 2);
+
+    // The proxy `0` is detached but not re-inserted yet.
+    let _ = bf.create_proxy(aabb_at(4.5, 1.0), 3);
+    bf.deferred_set_bounding_volume(h0, aabb_at(-10.0, 1.0));
+    assert!(!bf.update_with_budget(&mut handler, BroadPhaseUpdateBudget::Proxies(1)));
+    assert!(bf.proxy(h0).is_none());
+
+    handler.allow_zero = false;
+    bf.purge(&mut handler);
+    assert_eq!(handler.stopped, 0);
+
+    bf.update(&mut handler);
+    assert_eq!(handler.stopped, 1);
+    assert_eq!(bf.num_interferences(), 2);
+}
@@ -0,0 +1,117 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{
+    CollisionGroups, CollisionWorld, ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
+    DefaultContactDispatcher, GeometricQueryType,
+};
+use ncollide2d::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
+use ncollide2d::shape::{Ball, Cuboid, Polyline, Shape, ShapeHandle};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+// Wraps the default mesh-vs-convex generator, counting how many times it runs.
+struct CountingGenerator {
+    inner: ContactAlgorithm<f64>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl ContactManifoldGenerator<f64> for CountingGenerator {
+    fn generate_contacts(
+        &mut self,
+        dispatcher: &dyn ContactDispatcher<f64>,
+        ma: &Isometry2<f64>,
+        a: &dyn Shape<f64>,
+        proc1: Option<&dyn ContactPreprocessor<f64>>,
+        mb: &Isometry2<f64>,
+        b: &dyn Shape<f64>,
+        proc2: Option<&dyn ContactPreprocessor<f64>>,
+        prediction: &ContactPrediction<f64>,
+        manifold: &mut ContactManifold<f64>,
+    ) -> bool {
+        let _ = self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner
+            .generate_contacts(dispatcher, ma, a, proc1, mb, b, proc2, prediction, manifold)
+    }
+
+    fn init_manifold(&self) -> ContactManifold<f64> {
+        self.inner.init_manifold()
+    }
+}
+
+// Overrides the composite-vs-convex pairs only, and delegates everything else.
+struct MeshConvexDispatcher {
+    default: DefaultContactDispatcher,
+    calls: Arc<AtomicUsize>,
+}
+
+impl ContactDispatcher<f64> for MeshConvexDispatcher {
+    fn get_contact_algorithm(
+        &self,
+        a: &dyn Shape<f64>,
+        b: &dyn Shape<f64>,
+    ) -> Option<ContactAlgorithm<f64>> {
+        let algorithm = self.default.get_contact_algorithm(a, b)?;
+
+        if (a.is_composite_shape() && b.is_convex()) || (a.is_convex() && b.is_composite_shape()) {
+            Some(Box::new(CountingGenerator {
+                inner: algorithm,
+                calls: self.calls.clone(),
+            }))
+        } else {
+            Some(algorithm)
+        }
+    }
+}
+
+#[test]
+fn world_with_overridden_mesh_convex_algorithm() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let dispatcher = MeshConvexDispatcher {
+        default: DefaultContactDispatcher::new(),
+        calls: calls.clone(),
+    };
+    let mut world = CollisionWorld::with_contact_dispatcher(0.02, Box::new(dispatcher));
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+
+    let ground = Polyline::new(vec![Point2::new(-5.0, 0.0), Point2::new(5.0, 0.0)], None);
+    let _ = world.add(
+        Isometry2::identity(),
+        ShapeHandle::new(ground),
+        groups,
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::translation(0.0, 0.4),
+        ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5))),
+        groups,
+        query,
+        (),
+    );
+    // A pair of balls handled by the default algorithm.
+    let _ = world.add(
+        Isometry2::translation(10.0, 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::translation(10.8, 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let depths: Vec<f64> = world
+        .contact_pairs(true)
+        .map(|(_, _, _, manifold)| manifold.deepest_contact().unwrap().contact.depth)
+        .collect();
+    assert_eq!(depths.len(), 2);
+    assert!(depths.iter().any(|d| (d - 0.1).abs() < 1.0e-6));
+    assert!(depths.iter().any(|d| (d - 0.2).abs() < 1.0e-6));
+}
@@ -0,0 +1,50 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, Cuboid, FeatureId, ShapeHandle};
+
+#[test]
+fn manifold_contacts_have_kinematics() {
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let pos1 = Isometry2::new(Vector2::new(0.0, 1.8), 0.0);
+    let pos2 = Isometry2::new(Vector2::new(0.3, 0.0), 0.1);
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector2::new(2.0, 1.0));
+    let _ = world.add(
+        pos2,
+        ShapeHandle::new(cuboid),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (ball_handle, _) = world.add(
+        pos1,
+        ShapeHandle::new(ball),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (h1, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(h1, ball_handle);
+    assert_eq!(manifold.len(), 1);
+
+    let tracked = manifold.deepest_contact().unwrap();
+    let contact = tracked.contact;
+    let kinematic = tracked.kinematic;
+    assert_ne!(kinematic.feature2(), FeatureId::Unknown);
+
+    // The local points of the kinematic do not include the dilation of the ball.
+    let world1 = pos1 * kinematic.local1() + *contact.normal * kinematic.dilation1();
+    assert_relative_eq!(world1, contact.world1, epsilon = 1.0e-6);
+    assert_relative_eq!(kinematic.dilation1(), 1.0);
+
+    // Moving the cuboid along the normal changes the penetration depth accordingly.
+    let shifted = Isometry2::new(pos2.translation.vector + *contact.normal * 0.1, 0.1);
+    let updated = tracked
+        .updated_contact(&pos1, &ball, &shifted, &cuboid)
+        .unwrap();
+    assert_relative_eq!(updated.depth, contact.depth - 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(updated.normal, contact.normal, epsilon = 1.0e-6);
+}
@@ -0,0 +1,166 @@
+use na::{Isometry2, Point2, Unit, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use ncollide2d::pipeline::{
+    CollisionGroups, CollisionWorld, GeometricQueryType, RegistryContactDispatcher,
+};
+use ncollide2d::query::{
+    self, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    NeighborhoodGeometry,
+};
+use ncollide2d::shape::{Ball, FeatureId, Shape, ShapeHandle};
+
+// A user-defined shape unknown to ncollide.
+#[derive(Clone)]
+struct Disk {
+    radius: f64,
+}
+
+impl Shape<f64> for Disk {
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB<f64> {
+        AABB::from_half_extents(
+            Point2::from(m.translation.vector),
+            Vector2::repeat(self.radius),
+        )
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry2<f64>,
+        _: Option<&[f64]>,
+        _: &Unit<Vector2<f64>>,
+    ) -> bool {
+        false
+    }
+}
+
+struct DiskBallManifoldGenerator {
+    flip: bool,
+}
+
+impl ContactManifoldGenerator<f64> for DiskBallManifoldGenerator {
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<f64>,
+        ma: &Isometry2<f64>,
+        a: &dyn Shape<f64>,
+        proc1: Option<&dyn ContactPreprocessor<f64>>,
+        mb: &Isometry2<f64>,
+        b: &dyn Shape<f64>,
+        proc2: Option<&dyn ContactPreprocessor<f64>>,
+        prediction: &ContactPrediction<f64>,
+        manifold: &mut ContactManifold<f64>,
+    ) -> bool {
+        let (r1, r2) = if self.flip {
+            (
+                a.as_shape::<Ball<f64>>().unwrap().radius,
+                b.as_shape::<Disk>().unwrap().radius,
+            )
+        } else {
+            (
+                a.as_shape::<Disk>().unwrap().radius,
+                b.as_shape::<Ball<f64>>().unwrap().radius,
+            )
+        };
+
+        let c1 = Point2::from(ma.translation.vector);
+        let c2 = Point2::from(mb.translation.vector);
+
+        if let Some(contact) = query::contact_ball_ball(
+            &c1,
+            &Ball::new(r1),
+            &c2,
+            &Ball::new(r2),
+            prediction.linear(),
+        ) {
+            let mut kinematic = ContactKinematic::new();
+            kinematic.set_approx1(
+                FeatureId::Face(0),
+                Point2::origin(),
+                NeighborhoodGeometry::Point,
+            );
+            kinematic.set_approx2(
+                FeatureId::Face(0),
+                Point2::origin(),
+                NeighborhoodGeometry::Point,
+            );
+            kinematic.set_dilation1(r1);
+            kinematic.set_dilation2(r2);
+
+            let _ = manifold.push(contact, kinematic, Point2::origin(), proc1, proc2);
+        }
+
+        true
+    }
+}
+
+fn registry() -> RegistryContactDispatcher<f64> {
+    let mut registry = RegistryContactDispatcher::new();
+    registry.register_contact_algorithm::<Disk, Ball<f64>>(|flip| {
+        Box::new(DiskBallManifoldGenerator { flip })
+    });
+    registry
+}
+
+#[test]
+fn registry_dispatches_custom_shapes() {
+    let registry = registry();
+    let disk = Disk { radius: 1.0 };
+    let ball = Ball::new(1.0);
+
+    assert!(registry.contains_contact_algorithm::<Ball<f64>, Disk>());
+    assert!(registry.get_contact_algorithm(&disk, &ball).is_some());
+    assert!(registry.get_contact_algorithm(&ball, &disk).is_some());
+    assert!(registry.get_contact_algorithm(&disk, &disk).is_none());
+    // Built-in pairs are still handled by the fallback dispatcher.
+    assert!(registry.get_contact_algorithm(&ball, &ball).is_some());
+}
+
+#[test]
+fn registry_contact_query() {
+    let registry = registry();
+    let disk = Disk { radius: 1.0 };
+    let ball = Ball::new(1.0);
+    let m1 = Isometry2::translation(0.0, 0.0);
+    let m2 = Isometry2::translation(1.5, 0.0);
+
+    let contact = registry.contact(&m1, &disk, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector2::x(), epsilon = 1.0e-6);
+
+    let contact = registry.contact(&m2, &ball, &m1, &disk, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, -Vector2::x(), epsilon = 1.0e-6);
+
+    // Built-in pairs are handled by `query::contact`.
+    let contact = registry.contact(&m1, &ball, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-6);
+    let far = Isometry2::translation(5.0, 0.0);
+    assert!(registry.contact(&m1, &disk, &far, &ball, 0.1).is_none());
+}
+
+#[test]
+fn collision_world_with_custom_shapes() {
+    let mut world = CollisionWorld::with_contact_dispatcher(0.02, Box::new(registry()));
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry2::translation(0.0, 0.0),
+        ShapeHandle::new(Disk { radius: 1.0 }),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::translation(1.5, 0.0),
+        ShapeHandle::new(Ball::new(1.0)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    let contact = manifold.deepest_contact().unwrap().contact;
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-6);
+}
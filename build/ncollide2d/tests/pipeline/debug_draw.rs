@@ -0,0 +1,102 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::debug_draw::{self, DebugDrawOptions, DebugRenderBuffers};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, Cuboid, Polyline, ShapeHandle};
+
+#[test]
+fn debug_draw_shape_outlines() {
+    let mut buffers = DebugRenderBuffers::new();
+    let m = Isometry2::new(Vector2::new(1.0f64, 2.0), 0.5);
+
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.5));
+    buffers.push_shape_outline(&m, &cuboid, 16);
+    assert_eq!(buffers.lines.len(), 8);
+
+    for pt in &buffers.lines {
+        let local = m.inverse_transform_point(pt);
+        assert_relative_eq!(local.x.abs(), 1.0, epsilon = 1.0e-6);
+        assert_relative_eq!(local.y.abs(), 0.5, epsilon = 1.0e-6);
+    }
+
+    buffers.clear();
+    buffers.push_shape_outline(&m, &Ball::new(0.5), 16);
+    assert_eq!(buffers.lines.len(), 32);
+
+    for pt in &buffers.lines {
+        assert_relative_eq!(
+            na::distance(pt, &Point2::new(1.0, 2.0)),
+            0.5,
+            epsilon = 1.0e-6
+        );
+    }
+
+    buffers.clear();
+    buffers.push_shape_triangles(&m, &Ball::new(0.5), 16);
+    assert_eq!(buffers.triangles.len(), 14 * 3);
+
+    let points = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 0.0),
+    ];
+    let polyline = Polyline::new(points, None);
+    buffers.clear();
+    buffers.push_shape_outline(&Isometry2::identity(), &polyline, 16);
+    assert_eq!(buffers.lines.len(), 4);
+    assert!(buffers.triangles.is_empty());
+}
+
+#[test]
+fn debug_draw_world_contacts_and_aabbs() {
+    let mut world = CollisionWorld::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let _ = world.add(Isometry2::identity(), ball.clone(), groups, query, ());
+    let _ = world.add(
+        Isometry2::translation(0.8, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let options = DebugDrawOptions {
+        aabbs: true,
+        ..DebugDrawOptions::default()
+    };
+    let mut buffers = DebugRenderBuffers::new();
+    debug_draw::debug_draw_world(&world, &options, &mut buffers);
+
+    // Two outlines of 16 segments, two AABBs of 4 segments, and one contact made of two
+    // crosses and one normal.
+    assert_eq!(buffers.lines.len(), 2 * (2 * 16 + 2 * 4 + 5));
+
+    let normal = &buffers.lines[buffers.lines.len() - 2..];
+    assert!((normal[0].x - 0.5).abs() < 1.0e-6 || (normal[0].x - 0.3).abs() < 1.0e-6);
+    assert_relative_eq!(
+        na::distance(&normal[0], &normal[1]),
+        options.contact_normal_length,
+        epsilon = 1.0e-6
+    );
+
+    // Drawing the same shapes without the world yields the same outlines and the tight AABBs.
+    let shapes = [
+        (Isometry2::identity(), ball.clone()),
+        (Isometry2::translation(0.8, 0.0), ball),
+    ];
+    let mut shape_buffers = DebugRenderBuffers::new();
+    debug_draw::debug_draw_shapes(
+        shapes.iter().map(|(m, s)| (m, s.as_ref())),
+        &options,
+        &mut shape_buffers,
+    );
+    assert_eq!(shape_buffers.lines.len(), 2 * (16 + 4) * 2);
+    assert_relative_eq!(
+        shape_buffers.lines[32],
+        Point2::new(-0.5, -0.5),
+        epsilon = 1.0e-6
+    );
+}
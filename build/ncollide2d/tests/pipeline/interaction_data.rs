@@ -0,0 +1,88 @@
+use na::Isometry2;
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, ShapeHandle};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, PartialEq)]
+struct Impulses(Vec<f32>);
+
+#[test]
+fn edge_data_persists_while_the_pair_exists() {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+
+    let (h1, _) = world.add(Isometry2::identity(), ball.clone(), groups, query, ());
+    let (h2, _) = world.add(
+        Isometry2::translation(0.9, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    let (h3, _) = world.add(
+        Isometry2::translation(1.8, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let removed = Arc::new(Mutex::new(Vec::new()));
+    let removed2 = removed.clone();
+    world
+        .interactions
+        .set_edge_data_removal_callback(Some(Box::new(move |a, b, data| {
+            let impulses = data.downcast::<Impulses>().unwrap();
+            removed2.lock().unwrap().push((a, b, *impulses));
+        })));
+
+    let id = |world: &CollisionWorld<f32, ()>, h| {
+        world.collision_object(h).unwrap().graph_index().unwrap()
+    };
+    let (id1, id2, id3) = (id(&world, h1), id(&world, h2), id(&world, h3));
+    assert!(world
+        .interactions
+        .set_edge_data(id1, id2, Impulses(vec![1.0])));
+    assert!(world
+        .interactions
+        .set_edge_data(id3, id2, Impulses(vec![2.0])));
+    assert!(!world
+        .interactions
+        .set_edge_data(id1, id3, Impulses(vec![3.0])));
+
+    // The data survives updates and can be modified.
+    world.update();
+    world
+        .interactions
+        .edge_data_mut::<Impulses>(id2, id1)
+        .unwrap()
+        .0
+        .push(4.0);
+    assert_eq!(
+        world.interactions.edge_data::<Impulses>(id1, id2),
+        Some(&Impulses(vec![1.0, 4.0]))
+    );
+    assert!(world.interactions.edge_data::<f32>(id1, id2).is_none());
+
+    // The pair ends when the objects move away from each other.
+    world
+        .get_mut(h1)
+        .unwrap()
+        .set_position(Isometry2::translation(-10.0, 0.0));
+    world.update();
+    {
+        let removed = removed.lock().unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(removed[0].0 == h1 || removed[0].1 == h1);
+        assert_eq!(removed[0].2, Impulses(vec![1.0, 4.0]));
+    }
+
+    // Removing an object ends its pairs.
+    world.remove(&[h3]);
+    let removed = removed.lock().unwrap();
+    assert_eq!(removed.len(), 2);
+    assert_eq!(removed[1].2, Impulses(vec![2.0]));
+}
@@ -0,0 +1,74 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn islands_split_by_static_objects() {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+    let ground = ShapeHandle::new(Cuboid::new(Vector2::new(10.0, 0.5)));
+
+    // The user data is `true` for static objects.
+    let (ground, _) = world.add(Isometry2::identity(), ground, groups, contacts, true);
+    let mut add_ball = |x: f32, y: f32| {
+        world
+            .add(
+                Isometry2::translation(x, y),
+                ball.clone(),
+                groups,
+                contacts,
+                false,
+            )
+            .0
+    };
+
+    let a1 = add_ball(-5.0, 0.95);
+    let a2 = add_ball(-5.0, 1.9);
+    let b1 = add_ball(5.0, 0.95);
+    let b2 = add_ball(5.0, 1.9);
+    let lonely = add_ball(0.0, 10.0);
+    let _ = world.add(
+        Isometry2::translation(-5.5, 1.9),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        GeometricQueryType::Proximity(0.0),
+        false,
+    );
+    world.update();
+
+    let is_static = |h| *world.collision_object(h).unwrap().data();
+    let islands = world.islands(is_static, |h| h != b2);
+    let objects: Vec<_> = islands.iter().map(|i| i.objects.clone()).collect();
+
+    // The sensor only has a proximity so it forms its own island.
+    assert_eq!(objects.len(), 4);
+    assert!(objects.contains(&vec![a1, a2]));
+    assert!(objects.contains(&vec![b1, b2]));
+    assert!(objects.contains(&vec![lonely]));
+    assert!(islands.iter().all(|i| !i.objects.contains(&ground)));
+
+    for island in &islands {
+        if island.objects == vec![a1, a2] || island.objects == vec![b1, b2] {
+            // One contact with the ground and one between the two balls.
+            assert_eq!(island.contacts.len(), 2);
+
+            for contact in &island.contacts {
+                let (h1, h2, _) = world.interactions.index_interaction(*contact).unwrap();
+                assert!(island.objects.contains(&h1) || island.objects.contains(&h2));
+            }
+        } else {
+            assert!(island.contacts.is_empty());
+        }
+
+        assert_eq!(island.can_sleep, !island.objects.contains(&b2));
+    }
+
+    // Making the ground dynamic merges both stacks.
+    let islands = world.islands(|_| false, |_| true);
+    assert_eq!(islands.len(), 3);
+    assert!(islands
+        .iter()
+        .any(|i| i.objects.len() == 5 && i.contacts.len() == 4));
+}
@@ -0,0 +1,136 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::{ContactManifold, ContactPrediction};
+use ncollide2d::shape::{Cuboid, ShapeHandle};
+
+fn stacked_manifold(
+    ground: &Cuboid<f64>,
+    cube: &Cuboid<f64>,
+    pos1: Isometry2<f64>,
+    pos2: Isometry2<f64>,
+) -> ContactManifold<f64> {
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        pos2,
+        ShapeHandle::new(*cube),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (h1, _) = world.add(
+        pos1,
+        ShapeHandle::new(*ground),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (handle1, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(handle1, h1);
+    manifold.clone()
+}
+
+#[test]
+fn manifold_update_keeps_valid_contacts() {
+    let ground = Cuboid::new(Vector2::new(2.0, 0.5));
+    let cube = Cuboid::new(Vector2::new(0.5, 0.5));
+    let pos1 = Isometry2::identity();
+    let pos2 = Isometry2::new(Vector2::new(0.0, 0.99), 0.0);
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+
+    let mut manifold = stacked_manifold(&ground, &cube, pos1, pos2);
+    assert_eq!(manifold.len(), 2);
+
+    // A small motion keeps all the contacts and updates their depth.
+    let moved = Isometry2::new(Vector2::new(0.01, 0.98), 0.02);
+    assert_eq!(
+        manifold.update_contacts(&pos1, &ground, &moved, &cube, &prediction),
+        0
+    );
+    assert_eq!(manifold.len(), 2);
+
+    for tracked in manifold.contacts() {
+        assert!(tracked.contact.depth > 0.0);
+        assert_relative_eq!(moved * tracked.kinematic.local2(), tracked.contact.world2);
+    }
+
+    let deepest = manifold.deepest_contact().unwrap().contact;
+    let expected = 0.5 - 0.98 + 0.5 * (0.02f64.sin() + 0.02f64.cos());
+    assert_relative_eq!(deepest.depth, expected, epsilon = 1.0e-7);
+}
+
+#[test]
+fn manifold_update_drops_stale_contacts() {
+    let ground = Cuboid::new(Vector2::new(2.0, 0.5));
+    let cube = Cuboid::new(Vector2::new(0.5, 0.5));
+    let pos1 = Isometry2::identity();
+    let pos2 = Isometry2::new(Vector2::new(0.0, 0.99), 0.0);
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+
+    // Once the cube rotated, one of its bottom corners no longer faces the ground.
+    let mut manifold = stacked_manifold(&ground, &cube, pos1, pos2);
+    let rotated = Isometry2::new(Vector2::new(0.0, 0.99), 1.0);
+    assert_eq!(
+        manifold.update_contacts(&pos1, &ground, &rotated, &cube, &prediction),
+        1
+    );
+    assert_eq!(manifold.len(), 1);
+    assert_eq!(manifold.contacts().count(), 1);
+    assert!(manifold.deepest_contact().is_some());
+
+    // Separated shapes do not have any contact left.
+    let mut manifold = stacked_manifold(&ground, &cube, pos1, pos2);
+    let separated = Isometry2::new(Vector2::new(0.0, 1.5), 0.0);
+    assert_eq!(
+        manifold.update_contacts(&pos1, &ground, &separated, &cube, &prediction),
+        2
+    );
+    assert_eq!(manifold.len(), 0);
+    assert!(manifold.deepest_contact().is_none());
+}
+
+#[test]
+fn world_update_refreshes_moved_contacts() {
+    let ground = Cuboid::new(Vector2::new(2.0, 0.5));
+    let cube = Cuboid::new(Vector2::new(0.5, 0.5));
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let (h2, _) = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.99), 0.0),
+        ShapeHandle::new(cube),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (h1, _) = world.add(
+        Isometry2::identity(),
+        ShapeHandle::new(ground),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (handle1, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+    assert_eq!(handle1, h1);
+    let old: Vec<_> = manifold.contacts().map(|c| c.id).collect();
+    assert_eq!(old.len(), 2);
+
+    // After a quarter turn, the corner that was on the right now lies at the top of the cube
+    // while another corner touches the ground where it used to be. The contact of the corner
+    // that moved away must not be matched with the new one.
+    let moved = Isometry2::new(Vector2::new(1.0, 0.99), std::f64::consts::FRAC_PI_2);
+    world.set_position(h2, moved);
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pair(h1, h2, true).unwrap();
+    assert_eq!(manifold.len(), 2);
+
+    for tracked in manifold.contacts() {
+        assert_relative_eq!(tracked.contact.depth, 0.01, epsilon = 1.0e-7);
+        assert_relative_eq!(moved * tracked.kinematic.local2(), tracked.contact.world2);
+        assert!(!old.contains(&tracked.id));
+    }
+}
@@ -1,2 +1,19 @@
+mod broad_phase_pairs;
+mod contact_dispatcher_override;
+mod contact_kinematics;
+mod custom_shapes;
+mod debug_draw;
+mod interaction_data;
 mod is_send_sync;
+mod islands;
+mod manifold_update;
+mod per_object_interactions;
+mod per_object_prediction;
+mod query_flags;
+mod query_pipeline;
+mod sorted_ray_hits;
+mod speculative_contacts;
+mod thin_obstacles;
+mod toi_events;
 mod world_remove;
+mod world_snapshot;
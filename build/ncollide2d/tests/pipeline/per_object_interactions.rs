@@ -0,0 +1,74 @@
+use na::Isometry2;
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::Proximity;
+use ncollide2d::shape::{Ball, ShapeHandle};
+
+#[test]
+fn contacts_and_proximities_with_one_object() {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+
+    let (center, _) = world.add(Isometry2::identity(), ball.clone(), groups, contacts, 0);
+    let (left, _) = world.add(
+        Isometry2::translation(-0.9, 0.0),
+        ball.clone(),
+        groups,
+        contacts,
+        1,
+    );
+    let (right, _) = world.add(
+        Isometry2::translation(0.9, 0.0),
+        ball.clone(),
+        groups,
+        contacts,
+        2,
+    );
+    let (far, _) = world.add(
+        Isometry2::translation(10.0, 0.0),
+        ball.clone(),
+        groups,
+        contacts,
+        3,
+    );
+    let (sensor, _) = world.add(
+        Isometry2::translation(0.0, 0.9),
+        ball.clone(),
+        groups,
+        GeometricQueryType::Proximity(0.0),
+        4,
+    );
+    world.update();
+
+    let mut touching: Vec<_> = world
+        .contacts_with(center, true)
+        .unwrap()
+        .map(|(h1, h2, _, manifold)| {
+            assert!(manifold.len() > 0);
+            if h1 == center {
+                h2
+            } else {
+                h1
+            }
+        })
+        .collect();
+    touching.sort();
+    assert_eq!(touching, vec![left, right]);
+
+    assert_eq!(world.contacts_with(far, false).unwrap().count(), 0);
+    assert_eq!(world.contacts_with(left, true).unwrap().count(), 1);
+
+    let proximities: Vec<_> = world
+        .proximities_with(center, true)
+        .unwrap()
+        .map(|(h1, h2, _, prox)| (h1.max(h2), prox))
+        .collect();
+    assert_eq!(proximities, vec![(sensor, Proximity::Intersecting)]);
+    assert_eq!(world.proximities_with(far, false).unwrap().count(), 0);
+
+    // Removed objects do not have any interaction.
+    world.remove(&[center]);
+    assert!(world.contacts_with(center, false).is_none());
+    assert_eq!(world.contacts_with(left, false).unwrap().count(), 0);
+}
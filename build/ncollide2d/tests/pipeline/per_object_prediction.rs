@@ -0,0 +1,83 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{
+    CollisionGroups, CollisionObjectSlabHandle, CollisionWorld, GeometricQueryType,
+};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+fn ncontacts(
+    world: &CollisionWorld<f64, ()>,
+    h1: CollisionObjectSlabHandle,
+    h2: CollisionObjectSlabHandle,
+) -> usize {
+    world
+        .contact_pair(h1, h2, false)
+        .map(|pair| pair.3.len())
+        .unwrap_or(0)
+}
+
+#[test]
+fn prediction_margins_are_per_object() {
+    let mut world = CollisionWorld::new(0.0);
+    let groups = CollisionGroups::new();
+    let terrain_query = GeometricQueryType::Contacts(0.5, 0.0);
+    let bullet_query = GeometricQueryType::Contacts(0.01, 0.0);
+    let bullet = ShapeHandle::new(Ball::new(0.05));
+
+    // The top of the terrain is at y = 0.
+    let (terrain, _) = world.add(
+        Isometry2::new(Vector2::new(0.0, -50.0), 0.0),
+        ShapeHandle::new(Cuboid::new(Vector2::new(100.0, 50.0))),
+        groups,
+        terrain_query,
+        (),
+    );
+    // Within the terrain margin.
+    let (near, _) = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.35), 0.0),
+        bullet.clone(),
+        groups,
+        bullet_query,
+        (),
+    );
+    // Beyond the sum of both margins.
+    let (far, _) = world.add(
+        Isometry2::new(Vector2::new(5.0, 0.6), 0.0),
+        bullet.clone(),
+        groups,
+        bullet_query,
+        (),
+    );
+    // Two bullets 0.1 apart: farther than the sum of their own margins.
+    let (b1, _) = world.add(
+        Isometry2::new(Vector2::new(-5.0, 2.0), 0.0),
+        bullet.clone(),
+        groups,
+        bullet_query,
+        (),
+    );
+    let (b2, _) = world.add(
+        Isometry2::new(Vector2::new(-4.8, 2.0), 0.0),
+        bullet,
+        groups,
+        bullet_query,
+        (),
+    );
+
+    world.update();
+
+    // Predicted contacts have a negative depth, so they are not effective contacts.
+    let (_, _, _, manifold) = world.contact_pair(terrain, near, false).unwrap();
+    let contact = manifold.deepest_contact().unwrap().contact;
+    assert_relative_eq!(contact.depth, -0.3, epsilon = 1.0e-6);
+
+    assert_eq!(ncontacts(&world, terrain, far), 0);
+    assert_eq!(ncontacts(&world, b1, b2), 0);
+
+    // Widening the margin of a single bullet is enough to predict its contacts.
+    world
+        .get_mut(b2)
+        .unwrap()
+        .set_query_type(GeometricQueryType::Contacts(0.2, 0.0));
+    world.update();
+    assert_eq!(ncontacts(&world, b1, b2), 1);
+}
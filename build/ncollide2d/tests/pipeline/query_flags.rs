@@ -0,0 +1,151 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::pipeline::{
+    CollisionGroups, CollisionObjectQueryFlags, CollisionWorld, GeometricQueryType,
+};
+use ncollide2d::query::{Proximity, Ray};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn query_flags_filter_pairwise_interactions() {
+    let mut world = CollisionWorld::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let (solid, _) = world.add(Isometry2::identity(), ball.clone(), groups, query, "solid");
+    let (sensor, co) = world.add(
+        Isometry2::translation(0.8, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        "sensor",
+    );
+    co.set_query_flags(CollisionObjectQueryFlags::PROXIMITY);
+    let (ghost, co) = world.add(
+        Isometry2::translation(-0.8, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        "ghost",
+    );
+    co.set_query_flags(CollisionObjectQueryFlags::RAY_CAST);
+    world.update();
+
+    // The sensor only reports proximities, and the ghost does not interact at all.
+    assert_eq!(world.contact_pairs(false).count(), 0);
+    let proximities: Vec<_> = world.proximity_pairs(false).collect();
+    assert_eq!(proximities.len(), 1);
+    let (h1, h2, _, prox) = proximities[0];
+    assert!((h1, h2) == (solid, sensor) || (h1, h2) == (sensor, solid));
+    assert_eq!(prox, Proximity::Intersecting);
+    assert_eq!(world.collision_object(ghost).unwrap().data(), &"ghost");
+
+    // Restoring the contact flag on the sensor turns its proximity into a contact.
+    world
+        .get_mut(sensor)
+        .unwrap()
+        .set_query_flags(CollisionObjectQueryFlags::all());
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 1);
+    assert_eq!(world.proximity_pairs(false).count(), 0);
+
+    // Restoring the flags of the ghost makes it collide with the solid ball.
+    world
+        .get_mut(ghost)
+        .unwrap()
+        .set_query_flags(CollisionObjectQueryFlags::all());
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 2);
+}
+
+#[test]
+fn query_flags_filter_ray_casts_and_point_queries() {
+    let mut world = CollisionWorld::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let (front, co) = world.add(Isometry2::identity(), ball.clone(), groups, query, ());
+    co.set_query_flags(CollisionObjectQueryFlags::CONTACTS | CollisionObjectQueryFlags::PROXIMITY);
+    let (back, _) = world.add(Isometry2::translation(3.0, 0.0), ball, groups, query, ());
+    world.update();
+
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    let hit = world
+        .first_interference_with_ray(&ray, 100.0, &groups)
+        .unwrap();
+    assert_eq!(hit.handle, back);
+    assert_eq!(
+        world
+            .interferences_with_ray(&ray, 100.0, &groups)
+            .map(|hit| hit.0)
+            .collect::<Vec<_>>(),
+        vec![back]
+    );
+
+    let pipeline = world.query_pipeline();
+    assert_eq!(
+        pipeline.cast_ray(&ray, 100.0, true, |_, _| true).unwrap().0,
+        back
+    );
+    assert!(pipeline
+        .project_point(&Point2::origin(), 0.1, true, |_, _| true)
+        .is_none());
+    assert_eq!(
+        world
+            .interferences_with_point(&Point2::origin(), &groups)
+            .count(),
+        0
+    );
+    assert_eq!(
+        world
+            .interferences_with_point(&Point2::new(3.0, 0.0), &groups)
+            .count(),
+        1
+    );
+    let _ = front;
+}
+
+#[test]
+fn query_flags_filter_intersection_queries() {
+    let mut world = CollisionWorld::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(0.5f64));
+
+    let (_, co) = world.add(Isometry2::identity(), ball.clone(), groups, query, ());
+    co.set_query_flags(
+        CollisionObjectQueryFlags::all() - CollisionObjectQueryFlags::INTERSECTION_QUERY,
+    );
+    let (visible, _) = world.add(
+        Isometry2::translation(3.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let aabb = AABB::new(Point2::new(-1.0, -1.0), Point2::new(4.0, 1.0));
+    let hits: Vec<_> = world
+        .interferences_with_aabb(&aabb, &groups)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits, vec![visible]);
+
+    let pipeline = world.query_pipeline();
+    let hits: Vec<_> = pipeline
+        .intersections_with_aabb(&aabb, |_, _| true)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits, vec![visible]);
+
+    let cuboid = Cuboid::new(Vector2::new(2.5, 0.1));
+    let pos = Isometry2::translation(1.5, 0.0);
+    let hits: Vec<_> = pipeline
+        .intersections_with_shape(&pos, &cuboid, |_, _| true)
+        .map(|hit| hit.0)
+        .collect();
+    assert_eq!(hits, vec![visible]);
+}
@@ -0,0 +1,79 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::Ray;
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn query_pipeline_with_filters() {
+    let mut world = CollisionWorld::new(0.02);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+
+    let (h0, _) = world.add(
+        Isometry2::translation(0.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        0,
+    );
+    let (h1, _) = world.add(
+        Isometry2::translation(3.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        1,
+    );
+    let (h2, _) = world.add(
+        Isometry2::translation(6.0, 0.0),
+        ball.clone(),
+        groups,
+        query,
+        2,
+    );
+    world.update();
+
+    let pipeline = world.query_pipeline();
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+
+    let (hit, _, inter) = pipeline.cast_ray(&ray, 100.0, true, |_, _| true).unwrap();
+    assert_eq!(hit, h0);
+    assert!((inter.toi - 4.5).abs() < 1.0e-5);
+
+    let (hit, co, inter) = pipeline
+        .cast_ray(&ray, 100.0, true, |_, co| *co.data() != 0)
+        .unwrap();
+    assert_eq!(hit, h1);
+    assert_eq!(*co.data(), 1);
+    assert!((inter.toi - 7.5).abs() < 1.0e-5);
+
+    assert!(pipeline.cast_ray(&ray, 4.0, true, |_, _| true).is_none());
+
+    let (hit, _, proj) = pipeline
+        .project_point(&Point2::new(5.0, 0.0), 10.0, true, |_, _| true)
+        .unwrap();
+    assert_eq!(hit, h2);
+    assert!((proj.point - Point2::new(5.5, 0.0)).norm() < 1.0e-5);
+
+    let (hit, _, _) = pipeline
+        .project_point(&Point2::new(5.0, 0.0), 10.0, true, |h, _| h != h2)
+        .unwrap();
+    assert_eq!(hit, h1);
+
+    let cuboid = Cuboid::new(Vector2::new(2.0, 0.1));
+    let pos = Isometry2::translation(1.5, 0.0);
+    let mut hits: Vec<_> = pipeline
+        .intersections_with_shape(&pos, &cuboid, |_, _| true)
+        .map(|(h, _)| h)
+        .collect();
+    hits.sort();
+    assert_eq!(hits, vec![h0, h1]);
+
+    let aabb = AABB::new(Point2::new(2.0, -1.0), Point2::new(7.0, 1.0));
+    let hits: Vec<_> = pipeline
+        .intersections_with_aabb(&aabb, |_, co| *co.data() == 2)
+        .map(|(h, _)| h)
+        .collect();
+    assert_eq!(hits, vec![h2]);
+}
@@ -0,0 +1,103 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query::Ray;
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+use std::f32::consts::FRAC_PI_4;
+
+fn world() -> CollisionWorld<f32, usize> {
+    let mut world = CollisionWorld::new(0.1);
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let groups = CollisionGroups::new();
+    let objects = [
+        // Hit last even though its AABB is hit first.
+        (
+            Isometry2::translation(4.0, 1.9),
+            ShapeHandle::new(Ball::new(2.0f32)),
+        ),
+        (
+            Isometry2::translation(10.0, 0.0),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+        (
+            Isometry2::translation(3.0, 0.0),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+        // The AABB intersects the ray but not the shape.
+        (
+            Isometry2::new(Vector2::new(6.0, 0.75), FRAC_PI_4),
+            ShapeHandle::new(Cuboid::new(Vector2::repeat(0.5))),
+        ),
+        (
+            Isometry2::translation(0.0, 0.0),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ];
+
+    for (i, (pos, shape)) in objects.iter().enumerate() {
+        let _ = world.add(*pos, shape.clone(), groups, query, i);
+    }
+
+    world.update();
+    world
+}
+
+#[test]
+fn broad_phase_ray_candidates_are_sorted() {
+    let world = world();
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+    let mut candidates = Vec::new();
+    world
+        .broad_phase
+        .interferences_with_ray_sorted(&ray, 100.0, &mut candidates);
+
+    assert_eq!(candidates.len(), 5);
+    assert!(candidates.windows(2).all(|w| w[0].0 <= w[1].0));
+
+    let first = world.collision_object(*candidates[1].1).unwrap();
+    assert_eq!(*first.data(), 0);
+}
+
+#[test]
+fn world_ray_hits_are_sorted_by_toi() {
+    let world = world();
+    let groups = CollisionGroups::new();
+    let ray = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+
+    let hits: Vec<_> = world
+        .interferences_with_ray_sorted(&ray, 100.0, &groups)
+        .map(|(_, co, inter)| (*co.data(), inter.toi))
+        .collect();
+    let ids: Vec<_> = hits.iter().map(|h| h.0).collect();
+    assert_eq!(ids, vec![4, 2, 0, 1]);
+    assert!(hits.windows(2).all(|w| w[0].1 <= w[1].1));
+
+    // Same hits as the unsorted query.
+    let mut unsorted: Vec<_> = world
+        .interferences_with_ray(&ray, 100.0, &groups)
+        .map(|(_, co, _)| *co.data())
+        .collect();
+    unsorted.sort();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(sorted, unsorted);
+
+    let first = world
+        .first_interference_with_ray(&ray, 100.0, &groups)
+        .unwrap();
+    assert_eq!(*first.co.data(), 4);
+
+    // Starting after the first ball.
+    let ray = Ray::new(Point2::new(1.0, 0.0), Vector2::x());
+    let (_, co, inter) = world
+        .interferences_with_ray_sorted(&ray, 100.0, &groups)
+        .next()
+        .unwrap();
+    assert_eq!(*co.data(), 2);
+    assert_relative_eq!(inter.toi, 1.5, epsilon = 1.0e-5);
+
+    let (_, co, _) = world
+        .query_pipeline()
+        .cast_ray(&ray, 100.0, true, |_, _| true)
+        .unwrap();
+    assert_eq!(*co.data(), 2);
+}
@@ -0,0 +1,66 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn speculative_contact_between_separated_shapes() {
+    let mut world = CollisionWorld::new_speculative(1.0, 1.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.0), 0.0),
+        ShapeHandle::new(Ball::new(1.0)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (cuboid, _) = world.add(
+        Isometry2::new(Vector2::new(10.0, 0.0), 0.0),
+        ShapeHandle::new(Cuboid::new(Vector2::new(1.0, 1.0))),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+    assert!(world.contact_pairs(false).next().is_none());
+
+    // Moving the cuboid makes the broad-phase loosen its AABB by its margin.
+    world.set_position(cuboid, Isometry2::new(Vector2::new(2.5, 0.0), 0.0));
+    world.update();
+
+    // Speculative contacts are not effective contacts.
+    assert!(world.contact_pairs(true).next().is_none());
+    let (_, _, _, manifold) = world.contact_pairs(false).next().unwrap();
+    let contact = manifold.deepest_contact().unwrap().contact;
+
+    assert_eq!(manifold.len(), 1);
+    assert!(contact.is_speculative());
+    assert_relative_eq!(contact.separation(), 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn no_speculative_contact_beyond_margin() {
+    let mut world = CollisionWorld::new_speculative(2.0, 1.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let _ = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.0), 0.0),
+        shape.clone(),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (ball, _) = world.add(
+        Isometry2::new(Vector2::new(10.0, 0.0), 0.0),
+        shape,
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+    world.set_position(ball, Isometry2::new(Vector2::new(3.5, 0.0), 0.0));
+    world.update();
+
+    for (_, _, _, manifold) in world.contact_pairs(false) {
+        assert_eq!(manifold.len(), 0);
+    }
+}
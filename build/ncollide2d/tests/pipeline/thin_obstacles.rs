@@ -0,0 +1,72 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::query;
+use ncollide2d::shape::{Ball, Segment, ShapeHandle, Triangle};
+
+fn add_shape<S: ncollide2d::shape::Shape<f64>>(
+    world: &mut CollisionWorld<f64, usize>,
+    pos: Isometry2<f64>,
+    shape: S,
+    data: usize,
+) {
+    let _ = world.add(
+        pos,
+        ShapeHandle::new(shape),
+        CollisionGroups::new(),
+        GeometricQueryType::Contacts(0.0, 0.0),
+        data,
+    );
+}
+
+fn num_contacts(world: &CollisionWorld<f64, usize>, data1: usize, data2: usize) -> usize {
+    world
+        .contact_pairs(true)
+        .filter(|(h1, h2, _, _)| {
+            let d1 = *world.collision_object(*h1).unwrap().data();
+            let d2 = *world.collision_object(*h2).unwrap().data();
+            (d1, d2) == (data1, data2) || (d1, d2) == (data2, data1)
+        })
+        .map(|(_, _, _, manifold)| manifold.len())
+        .sum()
+}
+
+#[test]
+fn segments_and_triangles_as_collision_objects() {
+    let mut world = CollisionWorld::new(0.0);
+    let wire = Segment::new(Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0));
+    let blade = Triangle::new(
+        Point2::new(10.0, 0.0),
+        Point2::new(12.0, 0.0),
+        Point2::new(11.0, 2.0),
+    );
+
+    add_shape(&mut world, Isometry2::identity(), wire, 0);
+    add_shape(&mut world, Isometry2::translation(0.0, 0.9), Ball::new(1.0), 1);
+    add_shape(&mut world, Isometry2::identity(), blade, 2);
+    add_shape(&mut world, Isometry2::translation(11.0, 2.5), Ball::new(1.0), 3);
+    // A wire lying on top of the first one.
+    add_shape(&mut world, Isometry2::translation(1.0, 0.0), wire, 4);
+
+    world.update();
+
+    assert_eq!(num_contacts(&world, 0, 1), 1);
+    assert_eq!(num_contacts(&world, 2, 3), 1);
+    assert_eq!(num_contacts(&world, 0, 4), 2);
+}
+
+#[test]
+fn segment_segment_contact() {
+    let seg = Segment::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(0.0, 0.5), 1.0);
+
+    let contact = query::contact(&m1, &seg, &m2, &seg, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.0);
+    assert_relative_eq!(*contact.normal, Vector2::y(), epsilon = 1.0e-7);
+
+    let m2 = Isometry2::new(Vector2::new(3.0, 0.5), 1.0);
+    let contact = query::contact(&m1, &seg, &m2, &seg, 10.0).unwrap();
+    assert_relative_eq!(contact.world1, Point2::new(1.0, 0.0));
+    assert!(contact.depth < 0.0);
+    assert!(query::contact(&m1, &seg, &m2, &seg, 0.1).is_none());
+}
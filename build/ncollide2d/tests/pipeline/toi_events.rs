@@ -0,0 +1,49 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+
+#[test]
+fn fast_ball_through_thin_wall() {
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let (wall, _) = world.add(
+        Isometry2::new(Vector2::new(0.0, 0.0), 0.0),
+        ShapeHandle::new(Cuboid::new(Vector2::new(0.1, 10.0))),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (slow_ball, _) = world.add(
+        Isometry2::new(Vector2::new(0.0, 15.0), 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let (fast_ball, fast_ball_object) = world.add(
+        Isometry2::new(Vector2::new(-10.0, 0.0), 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    fast_ball_object.set_linear_velocity(Some(Vector2::new(20.0, 0.0)));
+    world
+        .objects
+        .get_mut(slow_ball)
+        .unwrap()
+        .set_linear_velocity(Some(Vector2::new(0.0, -10.0)));
+    world.update();
+
+    let events: Vec<_> = world.toi_events().iter().collect();
+    assert_eq!(events.len(), 2);
+
+    // The slow ball is closer to the wall so it hits it first.
+    let (h1, h2) = (events[0].collider1, events[0].collider2);
+    assert!((h1, h2) == (wall, slow_ball) || (h1, h2) == (slow_ball, wall));
+    assert_relative_eq!(events[0].toi.toi, 4.5 / 10.0, epsilon = 1.0e-5);
+
+    let (h1, h2) = (events[1].collider1, events[1].collider2);
+    assert!((h1, h2) == (wall, fast_ball) || (h1, h2) == (fast_ball, wall));
+    assert_relative_eq!(events[1].toi.toi, 9.4 / 20.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,258 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use ncollide2d::bounding_volume::AABB;
+use ncollide2d::pipeline::snapshot::{self, SnapshotData};
+use ncollide2d::pipeline::{
+    CollisionGroups, CollisionObjectQueryFlags, CollisionWorld, GeometricQueryType,
+};
+use ncollide2d::shape::{
+    Ball, Compound, ConvexPolygon, Cuboid, HeightField, Polyline, Scaled, ShapeHandle,
+};
+use std::io::ErrorKind;
+
+fn world() -> CollisionWorld<f64, u32> {
+    let mut world = CollisionWorld::new(0.02);
+    let contacts = GeometricQueryType::Contacts(0.1, 0.0);
+    let ball = ShapeHandle::new(Ball::new(0.5));
+    let polygon = ConvexPolygon::try_from_points(&[
+        Point2::new(-1.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ])
+    .unwrap();
+    let compound = Compound::new(vec![
+        (Isometry2::identity(), ShapeHandle::new(polygon)),
+        (
+            Isometry2::new(Vector2::new(2.0, 0.0), 0.3),
+            ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.25))),
+        ),
+    ]);
+    let mut heightfield = HeightField::new(
+        DVector::from_vec(vec![0.0, 0.5, 0.2, 0.1]),
+        Vector2::new(10.0, 1.0),
+    );
+    heightfield.set_segment_removed(1, true);
+
+    let groups = CollisionGroups::new().with_membership(&[1, 4]);
+    let (_, co) = world.add(
+        Isometry2::new(Vector2::new(-3.0, 0.3), 0.4),
+        ball.clone(),
+        groups,
+        contacts,
+        1,
+    );
+    co.set_linear_velocity(Some(Vector2::new(1.0, -2.0)));
+    let (removed, _) = world.add(Isometry2::identity(), ball.clone(), groups, contacts, 2);
+    let (_, co) = world.add(
+        Isometry2::translation(-2.8, 0.6),
+        ball,
+        CollisionGroups::new().with_blacklist(&[4]),
+        GeometricQueryType::Proximity(0.05),
+        3,
+    );
+    co.set_query_flags(CollisionObjectQueryFlags::PROXIMITY);
+    let _ = world.add(
+        Isometry2::translation(0.0, -1.0),
+        ShapeHandle::new(compound),
+        groups,
+        contacts,
+        4,
+    );
+    let _ = world.add(
+        Isometry2::identity(),
+        ShapeHandle::new(heightfield),
+        groups,
+        contacts,
+        5,
+    );
+    let polyline = Polyline::new(
+        vec![
+            Point2::origin(),
+            Point2::new(1.0, 1.0),
+            Point2::new(2.0, 0.0),
+        ],
+        None,
+    )
+    .with_thickness(0.05);
+    let _ = world.add(
+        Isometry2::translation(5.0, 0.0),
+        ShapeHandle::new(polyline),
+        groups,
+        contacts,
+        6,
+    );
+
+    // Leave a hole in the handles.
+    world.remove(&[removed]);
+    world
+}
+
+#[test]
+fn world_snapshot_round_trip() {
+    let mut world = world();
+    world.update();
+
+    let mut bytes = Vec::new();
+    world.save(&mut bytes).unwrap();
+
+    let mut loaded = CollisionWorld::new(0.02);
+    loaded.load(&bytes[..]).unwrap();
+    loaded.update();
+
+    // The handles, positions and data are restored.
+    let original: Vec<_> = world
+        .collision_objects()
+        .map(|(h, co)| (h, *co.position(), *co.data(), co.query_type()))
+        .collect();
+    let restored: Vec<_> = loaded
+        .collision_objects()
+        .map(|(h, co)| (h, *co.position(), *co.data(), co.query_type()))
+        .collect();
+    assert_eq!(original, restored);
+
+    for (handle, co) in world.collision_objects() {
+        let other = loaded.collision_object(handle).unwrap();
+        assert_eq!(co.linear_velocity(), other.linear_velocity());
+        assert_eq!(co.query_flags(), other.query_flags());
+        assert_eq!(
+            co.shape().aabb(co.position()),
+            other.shape().aabb(other.position())
+        );
+        assert_eq!(
+            co.collision_groups().is_member_of(4),
+            other.collision_groups().is_member_of(4)
+        );
+    }
+
+    // Saving the loaded world gives the same bytes.
+    let mut bytes2 = Vec::new();
+    loaded.save(&mut bytes2).unwrap();
+    assert_eq!(bytes, bytes2);
+
+    // The interactions are recomputed identically.
+    let pairs = |w: &CollisionWorld<f64, u32>| {
+        let mut pairs: Vec<_> = w
+            .contact_pairs(true)
+            .map(|(h1, h2, _, m)| (h1.0.min(h2.0), h1.0.max(h2.0), m.len()))
+            .collect();
+        pairs.sort();
+        pairs
+    };
+    assert_eq!(pairs(&world), pairs(&loaded));
+    assert!(!pairs(&world).is_empty());
+}
+
+#[test]
+fn world_snapshot_shares_shapes() {
+    let world = world();
+    let mut bytes = Vec::new();
+    world.save(&mut bytes).unwrap();
+
+    let mut loaded: CollisionWorld<f64, u32> = CollisionWorld::new(0.02);
+    loaded.load(&bytes[..]).unwrap();
+
+    let shapes: Vec<_> = loaded
+        .collision_objects()
+        .filter(|(_, co)| co.shape().is_shape::<Ball<f64>>())
+        .map(|(_, co)| co.shape().clone())
+        .collect();
+    assert_eq!(shapes.len(), 2);
+    assert!(std::sync::Arc::ptr_eq(
+        shapes[0].as_arc(),
+        shapes[1].as_arc()
+    ));
+}
+
+#[test]
+fn world_snapshot_errors() {
+    let world = world();
+    let mut bytes = Vec::new();
+    world.save(&mut bytes).unwrap();
+
+    // A truncated snapshot leaves the world unchanged.
+    let mut loaded = self::world();
+    let err = loaded.load(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(loaded.collision_objects().count(), 5);
+
+    // Unknown versions are rejected.
+    let mut future = bytes.clone();
+    future[4] = 2;
+    let err = loaded.load(&future[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // Huge handles are rejected before allocating anything.
+    let mut huge = Vec::new();
+    snapshot::write_header(&mut huge).unwrap();
+    1u64.write_snapshot(&mut huge).unwrap();
+    snapshot::write_shape::<f64>(&Ball::new(1.0), &mut huge).unwrap();
+    1u64.write_snapshot(&mut huge).unwrap();
+    (1u64 << 40).write_snapshot(&mut huge).unwrap();
+    let err = loaded.load(&huge[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(loaded.collision_objects().count(), 5);
+
+    // Unsupported shapes cannot be saved.
+    let scaled = Scaled::new(Ball::new(1.0), Vector2::new(1.0, 2.0));
+    let err = snapshot::write_shape::<f64>(&scaled, &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn world_region_streaming() {
+    let mut world = world();
+    world.update();
+
+    // Only the two balls on the left intersect the region.
+    let region = AABB::new(Point2::new(-4.0, 0.6), Point2::new(-2.0, 2.0));
+    let mut chunk = Vec::new();
+    let extracted = world.extract_region(&region, &mut chunk).unwrap();
+    assert_eq!(extracted.len(), 2);
+    assert!(extracted
+        .iter()
+        .all(|h| world.collision_object(*h).is_none()));
+
+    let mut remaining: Vec<_> = world
+        .collision_objects()
+        .map(|(_, co)| *co.data())
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, vec![4, 5, 6]);
+
+    // Merge the chunk into a world that already contains objects.
+    let mut hibernated = CollisionWorld::new(0.02);
+    let (existing, _) = hibernated.add(
+        Isometry2::translation(100.0, 0.0),
+        ShapeHandle::new(Ball::new(1.0)),
+        CollisionGroups::new(),
+        GeometricQueryType::Contacts(0.0, 0.0),
+        7,
+    );
+    let mapping = hibernated.merge(&chunk[..]).unwrap();
+    assert_eq!(mapping.len(), 2);
+    assert_eq!(hibernated.collision_objects().count(), 3);
+    assert_eq!(*hibernated.collision_object(existing).unwrap().data(), 7);
+
+    let merged: Vec<_> = extracted.iter().map(|h| mapping[h]).collect();
+    let ball1 = hibernated.collision_object(merged[0]).unwrap();
+    let ball3 = hibernated.collision_object(merged[1]).unwrap();
+    assert_eq!(*ball1.data(), 1);
+    assert_eq!(ball1.linear_velocity(), Some(&Vector2::new(1.0, -2.0)));
+    assert_eq!(*ball3.data(), 3);
+    assert_eq!(ball3.query_flags(), CollisionObjectQueryFlags::PROXIMITY);
+    assert!(std::sync::Arc::ptr_eq(
+        ball1.shape().as_arc(),
+        ball3.shape().as_arc()
+    ));
+    hibernated.update();
+
+    // Bring the region back into the active world.
+    let mut chunk = Vec::new();
+    let moved = hibernated.extract_region(&region, &mut chunk).unwrap();
+    assert_eq!(moved.len(), 2);
+    let mapping = world.merge(&chunk[..]).unwrap();
+    assert_eq!(world.collision_objects().count(), 5);
+    assert!(moved
+        .iter()
+        .all(|h| world.collision_object(mapping[h]).is_some()));
+    world.update();
+}
@@ -0,0 +1,16 @@
+//! Fixtures shared by several tests.
+
+use ncollide3d::procedural::TriMesh;
+
+/// The signed volume enclosed by a triangle mesh, positive if its faces are oriented outward.
+pub fn signed_volume(mesh: &TriMesh<f64>) -> f64 {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize].coords;
+            let b = mesh.coords[t[1] as usize].coords;
+            let c = mesh.coords[t[2] as usize].coords;
+            a.dot(&b.cross(&c)) / 6.0
+        })
+        .sum()
+}
@@ -0,0 +1,106 @@
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingSphere, HasBoundingVolume};
+use ncollide3d::procedural;
+use ncollide3d::shape::{ConvexHull, TriMesh};
+
+fn cloud() -> Vec<Point3<f64>> {
+    (0..500)
+        .map(|i| {
+            let i = i as f64;
+            Point3::new(
+                (i * 0.37).sin() * 3.0,
+                (i * 0.71).cos() * 1.0,
+                (i * 0.13).sin() * 0.5,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn exact_bounding_sphere_contains_all_points() {
+    let pts = cloud();
+    let exact = bounding_volume::bounding_sphere_exact(&pts);
+    let (_, approx_radius) = bounding_volume::point_cloud_bounding_sphere(&pts);
+
+    for pt in &pts {
+        assert!(na::distance(exact.center(), pt) <= exact.radius() + 1.0e-7);
+    }
+
+    assert!(exact.radius() <= approx_radius + 1.0e-7);
+}
+
+#[test]
+fn exact_bounding_sphere_of_known_configurations() {
+    // Two antipodal points with some points inside.
+    let mut pts = vec![Point3::new(-2.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0)];
+    pts.extend(
+        cloud()
+            .iter()
+            .map(|p| Point3::from(p.coords * 0.3 + Vector3::y())),
+    );
+    let sphere = bounding_volume::bounding_sphere_exact(&pts);
+    assert_relative_eq!(
+        *sphere.center(),
+        Point3::new(0.0, 1.0, 0.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(sphere.radius(), 2.0, epsilon = 1.0e-7);
+
+    // The corners of a box, with many duplicates.
+    let mut corners = Vec::new();
+    for _ in 0..3 {
+        for i in 0..8 {
+            corners.push(Point3::new(
+                if i & 1 == 0 { -1.0 } else { 1.0 },
+                if i & 2 == 0 { -2.0 } else { 2.0 },
+                if i & 4 == 0 { -3.0 } else { 3.0 },
+            ));
+        }
+    }
+    let sphere = bounding_volume::bounding_sphere_exact(&corners);
+    assert_relative_eq!(*sphere.center(), Point3::origin(), epsilon = 1.0e-7);
+    assert_relative_eq!(sphere.radius(), 14.0f64.sqrt(), epsilon = 1.0e-7);
+
+    // A single point.
+    let sphere = bounding_volume::bounding_sphere_exact(&[Point3::new(1.0, 2.0, 3.0)]);
+    assert_eq!(*sphere.center(), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(sphere.radius(), 0.0);
+}
+
+#[test]
+fn convex_hull_bounding_sphere_is_minimal() {
+    let hull = ConvexHull::try_from_points(&cloud()).unwrap();
+    let sphere: BoundingSphere<f64> = hull.local_bounding_volume();
+    let expected = bounding_volume::bounding_sphere_exact(hull.points());
+
+    assert_relative_eq!(*sphere.center(), *expected.center());
+    assert_relative_eq!(sphere.radius(), expected.radius());
+}
+
+#[test]
+fn exact_bounding_sphere_ignores_the_input_order() {
+    let pts = cloud();
+    let mut reversed = pts.clone();
+    reversed.reverse();
+    let sphere1 = bounding_volume::bounding_sphere_exact(&pts);
+    let sphere2 = bounding_volume::bounding_sphere_exact(&reversed);
+
+    assert_relative_eq!(*sphere1.center(), *sphere2.center(), epsilon = 1.0e-7);
+    assert_relative_eq!(sphere1.radius(), sphere2.radius(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn trimesh_bounding_sphere_follows_transformations() {
+    let mut mesh: TriMesh<f64> = procedural::sphere(2.0, 10, 10, false).into();
+    mesh.transform_by(&Isometry3::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(0.3, 0.2, 0.1),
+    ));
+    mesh.scale_by(&Vector3::new(1.0, 2.0, 3.0));
+
+    let sphere: BoundingSphere<f64> = mesh.local_bounding_volume();
+    let expected = bounding_volume::bounding_sphere_exact(mesh.points());
+
+    assert_relative_eq!(*sphere.center(), *expected.center(), epsilon = 1.0e-7);
+    assert_relative_eq!(sphere.radius(), expected.radius(), epsilon = 1.0e-7);
+}
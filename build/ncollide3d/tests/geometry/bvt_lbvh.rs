@@ -0,0 +1,100 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::{BoundingVolume, AABB};
+use ncollide3d::partitioning::{BVH, BVT};
+use ncollide3d::query::visitors::BoundingVolumeInterferencesCollector;
+
+fn leaves(n: usize) -> Vec<(usize, AABB<f64>)> {
+    (0..n)
+        .map(|i| {
+            let x = (i as f64 * 0.37).sin() * 10.0;
+            let y = (i as f64 * 0.71).cos() * 10.0;
+            let z = (i as f64 * 0.13).sin() * 5.0;
+            let half_extents = Vector3::repeat(0.2 + (i % 5) as f64 * 0.1);
+            (
+                i,
+                AABB::from_half_extents(Point3::new(x, y, z), half_extents),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn lbvh_interferences_match_brute_force() {
+    let leaves = leaves(200);
+    let bvt = BVT::new_lbvh(leaves.clone());
+    assert_eq!(bvt.leaves().len(), leaves.len());
+
+    let root = bvt.root_bounding_volume().unwrap();
+    for (_, aabb) in &leaves {
+        assert!(root.contains(aabb));
+    }
+
+    for i in 0..20 {
+        let center = Point3::new(i as f64 - 10.0, (i as f64).sin() * 8.0, 0.0);
+        let query = AABB::from_half_extents(center, Vector3::repeat(2.0));
+
+        let mut res = Vec::new();
+        bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query, &mut res,
+        ));
+        res.sort();
+
+        let expected: Vec<usize> = leaves
+            .iter()
+            .filter(|(_, aabb)| aabb.intersects(&query))
+            .map(|(i, _)| *i)
+            .collect();
+
+        assert_eq!(res, expected);
+    }
+}
+
+#[test]
+fn lbvh_degenerate_leaves() {
+    // All the leaves share the same center.
+    let aabb = AABB::from_half_extents(Point3::origin(), Vector3::repeat(1.0));
+    let bvt = BVT::new_lbvh((0..10).map(|i| (i, aabb)).collect());
+    assert_eq!(bvt.leaves().len(), 10);
+
+    let mut res = Vec::new();
+    bvt.visit(&mut BoundingVolumeInterferencesCollector::new(
+        &aabb, &mut res,
+    ));
+    assert_eq!(res.len(), 10);
+
+    let bvt: BVT<usize, AABB<f64>> = BVT::new_lbvh(Vec::new());
+    assert!(bvt.root_bounding_volume().is_none());
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn lbvh_parallel_matches_sequential() {
+    let leaves = leaves(5000);
+    let sequential = BVT::new_lbvh(leaves.clone());
+    let parallel = BVT::new_lbvh_parallel(leaves.clone());
+    assert_eq!(parallel.leaves().len(), leaves.len());
+    assert_eq!(
+        parallel.root_bounding_volume(),
+        sequential.root_bounding_volume()
+    );
+
+    for i in 0..20 {
+        let center = Point3::new(i as f64 - 10.0, (i as f64).sin() * 8.0, 0.0);
+        let query = AABB::from_half_extents(center, Vector3::repeat(2.0));
+
+        let mut expected = Vec::new();
+        sequential.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query,
+            &mut expected,
+        ));
+        expected.sort();
+
+        let mut res = Vec::new();
+        parallel.visit(&mut BoundingVolumeInterferencesCollector::new(
+            &query, &mut res,
+        ));
+        res.sort();
+
+        assert_eq!(res, expected);
+    }
+}
@@ -0,0 +1,91 @@
+use na::{Isometry3, Point2, Point3, Unit, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::query::{Ray, RayCast, RayIntersection};
+use ncollide3d::shape::{Ball, Compound, Cuboid, FeatureId, Shape, ShapeHandle};
+
+// A cuboid with texture coordinates given by the local `x` and `y` coordinates of the hit point.
+#[derive(Clone)]
+struct TexturedCuboid(Cuboid<f32>);
+
+impl RayCast<f32> for TexturedCuboid {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry3<f32>,
+        ray: &Ray<f32>,
+        max_toi: f32,
+        solid: bool,
+    ) -> Option<RayIntersection<f32>> {
+        self.0.toi_and_normal_with_ray(m, ray, max_toi, solid)
+    }
+
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry3<f32>,
+        ray: &Ray<f32>,
+        max_toi: f32,
+        solid: bool,
+    ) -> Option<RayIntersection<f32>> {
+        self.0
+            .toi_and_normal_with_ray(m, ray, max_toi, solid)
+            .map(|mut inter| {
+                let pt = m.inverse_transform_point(&ray.point_at(inter.toi));
+                inter.uvs = Some(Point2::new(pt.x, pt.y));
+                inter
+            })
+    }
+}
+
+impl Shape<f32> for TexturedCuboid {
+    fn aabb(&self, m: &Isometry3<f32>) -> AABB<f32> {
+        self.0.aabb(m)
+    }
+
+    fn as_ray_cast(&self) -> Option<&dyn RayCast<f32>> {
+        Some(self)
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry3<f32>,
+        _: Option<&[f32]>,
+        _: &Unit<Vector3<f32>>,
+    ) -> bool {
+        false
+    }
+}
+
+#[test]
+fn compound_ray_cast_with_uvs() {
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 2.0),
+            ShapeHandle::new(TexturedCuboid(Cuboid::new(Vector3::repeat(1.0)))),
+        ),
+        (
+            Isometry3::translation(10.0, 0.0, 0.0),
+            ShapeHandle::new(Ball::new(0.5f32)),
+        ),
+    ]);
+
+    let m = Isometry3::translation(0.0, 0.0, -1.0);
+    let ray = Ray::new(Point3::new(0.25, 0.5, -5.0), Vector3::z());
+    let inter = compound
+        .toi_and_normal_and_uv_with_ray(&m, &ray, 100.0, true)
+        .unwrap();
+
+    assert_relative_eq!(inter.toi, 5.0, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, -Vector3::z(), epsilon = 1.0e-5);
+    assert_relative_eq!(inter.uvs.unwrap(), Point2::new(0.25, 0.5), epsilon = 1.0e-5);
+
+    // The texture coordinates computed by the ball itself are preserved.
+    let ray = Ray::new(Point3::new(10.0, 0.0, -5.0), Vector3::z());
+    let inter = compound
+        .toi_and_normal_and_uv_with_ray(&m, &ray, 100.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 3.5, epsilon = 1.0e-5);
+    let expected = Ball::new(0.5f32)
+        .toi_and_normal_and_uv_with_ray(&Isometry3::translation(10.0, 0.0, -1.0), &ray, 100.0, true)
+        .unwrap();
+    assert_eq!(inter.uvs, expected.uvs);
+}
@@ -0,0 +1,43 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Cone, Cuboid, Cylinder, SupportMap};
+use ncollide3d::transformation;
+
+#[test]
+fn convex_approximation_of_cuboid_is_exact() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let hull = transformation::convex_approximation(&cuboid, 200).unwrap();
+
+    assert_eq!(hull.points().len(), 8);
+    for pt in hull.points() {
+        assert_relative_eq!(pt.coords.abs(), cuboid.half_extents);
+    }
+}
+
+#[test]
+fn convex_approximation_of_curved_shapes_is_inscribed() {
+    let cone = Cone::new(1.0, 0.5);
+    let cylinder = Cylinder::new(1.0, 0.5);
+
+    for shape in [&cone as &dyn SupportMap<f64>, &cylinder] {
+        let hull = transformation::convex_approximation(shape, 500).unwrap();
+        assert!(hull.points().len() > 20);
+
+        // Every vertex is a support point, so it lies on the boundary of the original shape.
+        for pt in hull.points() {
+            let support = shape.local_support_point(&pt.coords);
+            assert!(pt.coords.dot(&pt.coords) <= support.coords.dot(&pt.coords) + 1.0e-7);
+        }
+
+        // The approximation is close to the original shape along the main axes.
+        for dir in [Vector3::x(), Vector3::y(), -Vector3::y(), Vector3::z()] {
+            let exact = shape.local_support_point(&dir).coords.dot(&dir);
+            let approx = hull.local_support_point(&dir).coords.dot(&dir);
+            assert!(approx <= exact + 1.0e-7);
+            assert!(approx >= exact - 0.05);
+        }
+    }
+
+    let hull = transformation::convex_approximation(&cylinder, 500).unwrap();
+    assert!(hull.contains_point(&Isometry3::identity(), &Point3::origin()));
+}
@@ -0,0 +1,94 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::{Ball, Cuboid, Plane, PointCloud, Shape, TriMesh};
+use ncollide3d::transformation::convex_hull_of_shape;
+
+// A unit cube which top face is subdivided around a slightly raised center.
+fn bumpy_cube(bump: f32) -> TriMesh<f32> {
+    let mut points = vec![
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, 1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(-1.0, 1.0, 1.0),
+    ];
+    points.push(Point3::new(0.0, 1.0 + bump, 0.0));
+
+    let indices = vec![
+        // Bottom and sides.
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+        Point3::new(0, 4, 5),
+        Point3::new(0, 5, 1),
+        Point3::new(1, 5, 6),
+        Point3::new(1, 6, 2),
+        Point3::new(2, 6, 7),
+        Point3::new(2, 7, 3),
+        Point3::new(3, 7, 4),
+        Point3::new(3, 4, 0),
+        // Top.
+        Point3::new(4, 8, 5),
+        Point3::new(5, 8, 6),
+        Point3::new(6, 8, 7),
+        Point3::new(7, 8, 4),
+    ];
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn convex_hull_of_polyhedral_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 2.0, 3.0));
+    let hull = convex_hull_of_shape(&cuboid, None).unwrap();
+    assert_eq!(hull.points().len(), 8);
+
+    let mesh = bumpy_cube(0.001);
+    assert_eq!(convex_hull_of_shape(&mesh, None).unwrap().points().len(), 9);
+
+    // The bump is collapsed, but not a larger one.
+    let hull = convex_hull_of_shape(&mesh, Some(0.01)).unwrap();
+    assert_eq!(hull.points().len(), 8);
+    let mesh = bumpy_cube(0.1);
+    assert_eq!(
+        convex_hull_of_shape(&mesh, Some(0.01))
+            .unwrap()
+            .points()
+            .len(),
+        9
+    );
+
+    let cloud = PointCloud::new(vec![
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.1, 0.1, 0.1),
+    ]);
+    assert_eq!(
+        convex_hull_of_shape(&cloud, None).unwrap().points().len(),
+        4
+    );
+}
+
+#[test]
+fn convex_hull_of_curved_shapes() {
+    let ball = Ball::new(1.0f32);
+    let fine = convex_hull_of_shape(&ball, Some(0.05)).unwrap();
+    let coarse = convex_hull_of_shape(&ball, Some(0.2)).unwrap();
+    assert!(coarse.points().len() < fine.points().len());
+
+    // The hull is inscribed into the ball and close to its boundary.
+    let m = Isometry3::identity();
+    for pt in fine.points() {
+        assert!(na::distance(pt, &Point3::origin()) <= 1.0 + 1.0e-5);
+    }
+
+    let dist = fine.distance_to_point(&m, &Point3::new(0.0, 0.0, 2.0), true);
+    assert!(dist >= 1.0 && dist <= 1.1);
+
+    let plane = Plane::new(Vector3::y_axis());
+    assert!(convex_hull_of_shape(&plane as &dyn Shape<f32>, None).is_none());
+}
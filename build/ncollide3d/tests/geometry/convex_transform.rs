@@ -0,0 +1,109 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, PointQuery};
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, SupportMap, TriMesh, Triangle};
+
+fn cube_points() -> Vec<Point3<f64>> {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        points.push(Point3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        ));
+    }
+
+    points
+}
+
+#[test]
+fn convex_hull_transform_by() {
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.5));
+    let hull = ConvexHull::try_from_points(&cube_points()).unwrap();
+    let transformed = hull.clone().transformed(&pos);
+    transformed.check_geometry();
+
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ball_pos = Isometry3::new(Vector3::new(1.0, 2.0, 6.0), Vector3::zeros());
+    let ball = Ball::new(0.5);
+
+    let expected = query::contact(&pos, &cuboid, &ball_pos, &ball, 10.0).unwrap();
+    let actual =
+        query::contact(&Isometry3::identity(), &transformed, &ball_pos, &ball, 10.0).unwrap();
+    assert_relative_eq!(expected.depth, actual.depth, epsilon = 1.0e-6);
+    assert_relative_eq!(*expected.normal, *actual.normal, epsilon = 1.0e-6);
+}
+
+#[test]
+fn convex_hull_scale_by() {
+    let scale = Vector3::new(2.0, 1.0, 0.5);
+    let hull = ConvexHull::try_from_points(&cube_points())
+        .unwrap()
+        .scaled(&scale);
+    hull.check_geometry();
+
+    let cuboid = Cuboid::new(scale);
+    let m = Isometry3::new(Vector3::zeros(), Vector3::new(0.1, 0.2, 0.3));
+
+    for dir in [
+        Vector3::new(1.0, 0.3, -0.2),
+        Vector3::new(-0.5, 1.0, 0.7),
+        Vector3::new(0.1, -0.4, -1.0),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(
+            hull.support_point(&m, dir),
+            cuboid.support_point(&m, dir),
+            epsilon = 1.0e-10
+        );
+    }
+
+    let ball_pos = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), Vector3::zeros());
+    let ball = Ball::new(0.75);
+    let contact = query::contact(&Isometry3::identity(), &hull, &ball_pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.25, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector3::z(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn trimesh_transform_and_scale_update_normals() {
+    use ncollide3d::procedural;
+    use ncollide3d::query::PointQuery;
+    use ncollide3d::shape::{TriMesh, Triangle};
+
+    let mut mesh: TriMesh<f64> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+    mesh.set_oriented(true);
+    mesh.set_closed(true);
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.5));
+    mesh.transform_by(&pos);
+    mesh.scale_by(&Vector3::new(2.0, 0.5, 3.0));
+
+    for face in mesh.faces() {
+        let pts = mesh.points();
+        let tri = Triangle::new(
+            pts[face.indices.x],
+            pts[face.indices.y],
+            pts[face.indices.z],
+        );
+        let expected = tri.normal().unwrap();
+        assert_relative_eq!(face.normal.unwrap(), expected, epsilon = 1.0e-7);
+
+        let sides = face.side_normals.unwrap();
+        assert!(sides[0].dot(&(tri.b - tri.a)).abs() < 1.0e-7);
+        assert!(sides[1].dot(&(tri.c - tri.b)).abs() < 1.0e-7);
+        assert!(sides[2].dot(&(tri.a - tri.c)).abs() < 1.0e-7);
+        assert!(sides[0].dot(&(tri.c - tri.a)) < 0.0);
+    }
+
+    // Point queries use the refreshed normals and bounding volumes.
+    let id = Isometry3::identity();
+    let inside = Point3::from(
+        (pos * Point3::origin())
+            .coords
+            .component_mul(&Vector3::new(2.0, 0.5, 3.0)),
+    );
+    assert!(mesh.contains_point(&id, &inside));
+    assert!(mesh.distance_to_point(&id, &inside, false) < 0.0);
+}
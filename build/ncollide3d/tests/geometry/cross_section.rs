@@ -0,0 +1,50 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::shape::{Plane, TriMesh};
+use ncollide3d::transformation;
+
+#[test]
+fn cube_cross_section() {
+    let mesh: TriMesh<f32> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+    let plane = Plane::new(Vector3::z_axis());
+    let m = Isometry3::translation(0.0, 0.0, 0.25);
+    let sections = transformation::cross_section(&mesh, &m, &plane);
+
+    assert_eq!(sections.len(), 1);
+    let section = &sections[0];
+    // The curve is closed.
+    assert_eq!(section.edges().len(), section.points().len());
+
+    let mut perimeter = 0.0;
+    for i in 0..section.edges().len() {
+        let seg = section.edge_segment(i);
+        perimeter += (seg.b() - seg.a()).norm();
+        assert!((seg.a().z - 0.25).abs() < 1.0e-5);
+        assert!((seg.a().x.abs() - 1.0).abs() < 1.0e-5 || (seg.a().y.abs() - 1.0).abs() < 1.0e-5);
+    }
+    assert!((perimeter - 8.0).abs() < 1.0e-4);
+}
+
+#[test]
+fn sphere_cross_section() {
+    let mesh: TriMesh<f32> = procedural::sphere(2.0, 32, 16, false).into();
+    let plane = Plane::new(Vector3::x_axis());
+    let sections = transformation::cross_section(&mesh, &Isometry3::identity(), &plane);
+
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].edges().len(), sections[0].points().len());
+
+    for pt in sections[0].points() {
+        assert!(pt.x.abs() < 1.0e-5);
+        assert!(pt.coords.norm() <= 1.0 + 1.0e-5);
+        assert!(pt.coords.norm() >= 0.9);
+    }
+}
+
+#[test]
+fn missed_cross_section() {
+    let mesh: TriMesh<f32> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+    let plane = Plane::new(Vector3::y_axis());
+    let m = Isometry3::translation(0.0, 3.0, 0.0);
+    assert!(transformation::cross_section(&mesh, &m, &plane).is_empty());
+}
@@ -0,0 +1,113 @@
+use na::{Isometry3, Point3, Translation3, Unit, UnitQuaternion, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB};
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{Cone, Cylinder, SupportMap};
+use ncollide3d::transformation::ToTriMesh;
+use ncollide3d::volumetric::Volumetric;
+
+// Checks that `shape` behaves like `aligned` rotated by `rot`.
+fn assert_rotated<G1, G2>(shape: &G1, aligned: &G2, rot: UnitQuaternion<f64>)
+where
+    G1: HasBoundingVolume<f64, AABB<f64>>
+        + RayCast<f64>
+        + SupportMap<f64>
+        + Volumetric<f64>
+        + ToTriMesh<f64, DiscretizationParameter = u32>,
+    G2: HasBoundingVolume<f64, AABB<f64>>
+        + RayCast<f64>
+        + SupportMap<f64>
+        + Volumetric<f64>
+        + ToTriMesh<f64, DiscretizationParameter = u32>,
+{
+    let m = Isometry3::new(Vector3::new(0.1, 0.2, 0.3), Vector3::new(0.4, 0.5, 0.6));
+    let aligned_m = m * Isometry3::from_parts(Translation3::identity(), rot);
+
+    for i in 0..20 {
+        let t = i as f64;
+        let dir = Vector3::new((t * 0.37).sin(), (t * 0.71).cos(), (t * 0.13).sin() + 0.1);
+        assert_relative_eq!(
+            shape.support_point(&m, &dir),
+            aligned.support_point(&aligned_m, &dir),
+            epsilon = 1.0e-7
+        );
+
+        let origin = m * Point3::from(dir * 3.0);
+        let ray = Ray::new(origin, m.translation.vector - origin.coords + dir * 0.2);
+        let res = shape.toi_and_normal_with_ray(&m, &ray, 10.0, true).unwrap();
+        let expected = aligned
+            .toi_and_normal_with_ray(&aligned_m, &ray, 10.0, true)
+            .unwrap();
+        assert_relative_eq!(res.toi, expected.toi, epsilon = 1.0e-7);
+        assert_relative_eq!(res.normal, expected.normal, epsilon = 1.0e-7);
+    }
+
+    let aabb = bounding_volume::aabb(shape, &m);
+    let expected = bounding_volume::aabb(aligned, &aligned_m);
+    assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-7);
+    assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-7);
+
+    let mesh = shape.to_trimesh(16);
+    let expected = aligned
+        .to_trimesh(16)
+        .transformed(&Isometry3::from_parts(Translation3::identity(), rot));
+    for (a, b) in mesh.coords.iter().zip(expected.coords.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1.0e-7);
+    }
+    let local_aabb = bounding_volume::local_aabb(shape);
+    assert!(mesh
+        .coords
+        .iter()
+        .all(|pt| local_aabb.loosened(1.0e-7).contains_local_point(pt)));
+
+    assert_relative_eq!(shape.volume(), aligned.volume());
+    assert_relative_eq!(
+        shape.center_of_mass(),
+        rot * aligned.center_of_mass(),
+        epsilon = 1.0e-7
+    );
+    let inertia = rot.to_rotation_matrix()
+        * aligned.unit_angular_inertia()
+        * rot.inverse().to_rotation_matrix();
+    assert_relative_eq!(shape.unit_angular_inertia(), inertia, epsilon = 1.0e-7);
+}
+
+#[test]
+fn cylinder_with_axis() {
+    let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, -0.5));
+    let cylinder = Cylinder::new(1.5, 0.5).with_axis(axis);
+    let rot = UnitQuaternion::rotation_between_axis(&Vector3::y_axis(), &axis).unwrap();
+
+    assert_relative_eq!(cylinder.axis(), axis, epsilon = 1.0e-7);
+    assert_rotated(&cylinder, &Cylinder::new(1.5, 0.5), rot);
+
+    // Aligning with `y` is a no-op, and the cylinder stays constructible from its fields.
+    let aligned = Cylinder {
+        half_height: 1.5,
+        radius: 0.5,
+    };
+    let cylinder = aligned.with_axis(Vector3::y_axis());
+    assert_eq!(cylinder.shape, aligned);
+    assert_eq!(cylinder.rotation, UnitQuaternion::identity());
+}
+
+#[test]
+fn cone_with_axis() {
+    let cone = Cone::new(1.5, 0.5).with_axis(Vector3::x_axis());
+    let rot =
+        UnitQuaternion::rotation_between_axis(&Vector3::y_axis(), &Vector3::x_axis()).unwrap();
+
+    assert_relative_eq!(
+        cone.local_support_point(&Vector3::x()),
+        Point3::new(1.5, 0.0, 0.0)
+    );
+    assert_rotated(&cone, &Cone::new(1.5, 0.5), rot);
+
+    // The apex is at the other end along `-y`.
+    let flipped = Cone::new(1.5, 0.5).with_axis(-Vector3::y_axis());
+    assert_relative_eq!(flipped.axis(), -Vector3::y_axis(), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        flipped.local_support_point(&-Vector3::y()),
+        Point3::new(0.0, -1.5, 0.0),
+        epsilon = 1.0e-7
+    );
+}
@@ -0,0 +1,125 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::algorithms::VoronoiSimplex;
+use ncollide3d::query::{self, Ray, RayCast};
+use ncollide3d::shape::{Cone, Cylinder, FeatureId, SupportMap};
+
+fn rays() -> Vec<Ray<f64>> {
+    let mut rays = Vec::new();
+
+    for i in -2..=2 {
+        for j in -2..=2 {
+            for k in -2..=2 {
+                let origin = Point3::new(i as f64 * 1.7, j as f64 * 1.3 + 0.1, k as f64 * 1.9);
+                let dir = Point3::new(0.3, -0.2, 0.1) - origin;
+                rays.push(Ray::new(origin + Vector3::new(0.0, 0.0, 0.01), dir));
+            }
+        }
+    }
+
+    rays
+}
+
+fn assert_matches_gjk<G: RayCast<f64> + SupportMap<f64>>(shape: &G) {
+    let m = Isometry3::new(Vector3::new(0.1, 0.2, 0.3), Vector3::new(0.4, 0.5, 0.6));
+
+    for ray in rays() {
+        let ls_ray = ray.inverse_transform_by(&m);
+        let exact = shape.toi_and_normal_with_ray(&m, &ray, 10.0, true);
+        let gjk = query::ray_intersection_with_support_map_with_params(
+            &Isometry3::identity(),
+            shape,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            10.0,
+            true,
+        );
+
+        match (exact, gjk) {
+            (Some(exact), Some(gjk)) => {
+                assert_relative_eq!(exact.toi, gjk.toi, epsilon = 1.0e-5);
+
+                if exact.toi > 0.0 {
+                    assert_relative_eq!(exact.normal.norm(), 1.0, epsilon = 1.0e-9);
+                    assert_relative_eq!(exact.normal, m * gjk.normal, epsilon = 1.0e-3);
+                }
+            }
+            (None, None) => {}
+            (exact, gjk) => panic!("Mismatch: {:?} vs. {:?}", exact, gjk),
+        }
+    }
+}
+
+#[test]
+fn cylinder_ray_cast_matches_gjk() {
+    assert_matches_gjk(&Cylinder::new(1.0, 0.5));
+}
+
+#[test]
+fn cone_ray_cast_matches_gjk() {
+    assert_matches_gjk(&Cone::new(1.0, 0.5));
+}
+
+#[test]
+fn cylinder_ray_cast_features() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let m = Isometry3::identity();
+
+    let down = Ray::new(Point3::new(0.1, 3.0, 0.2), -Vector3::y());
+    let inter = cylinder.toi_and_normal_with_ray(&m, &down, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_relative_eq!(inter.normal, Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let up = Ray::new(Point3::new(0.1, -3.0, 0.2), Vector3::y());
+    let inter = cylinder.toi_and_normal_with_ray(&m, &up, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_relative_eq!(inter.normal, -Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(2));
+
+    let side = Ray::new(Point3::new(3.0, 0.5, 0.0), -Vector3::x());
+    let inter = cylinder.toi_and_normal_with_ray(&m, &side, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.5);
+    assert_relative_eq!(inter.normal, Vector3::x());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    // From the inside.
+    let inner = Ray::new(Point3::new(0.0, 0.5, 0.0), Vector3::x());
+    let inter = cylinder.toi_and_normal_with_ray(&m, &inner, 10.0, false).unwrap();
+    assert_relative_eq!(inter.toi, 0.5);
+    assert_relative_eq!(inter.normal, -Vector3::x());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+    assert_eq!(cylinder.toi_with_ray(&m, &inner, 10.0, true), Some(0.0));
+
+    let missed = Ray::new(Point3::new(3.0, 1.5, 0.0), -Vector3::x());
+    assert!(cylinder.toi_with_ray(&m, &missed, 10.0, true).is_none());
+}
+
+#[test]
+fn cone_ray_cast_features() {
+    let cone = Cone::new(1.0, 0.5);
+    let m = Isometry3::identity();
+
+    let down = Ray::new(Point3::new(0.0, 3.0, 0.0), -Vector3::y());
+    let inter = cone.toi_and_normal_with_ray(&m, &down, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_eq!(inter.feature, FeatureId::Vertex(0));
+
+    let up = Ray::new(Point3::new(0.1, -3.0, 0.2), Vector3::y());
+    let inter = cone.toi_and_normal_with_ray(&m, &up, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_relative_eq!(inter.normal, -Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    // The lateral surface at `y = 0` has a radius of 0.25.
+    let side = Ray::new(Point3::new(3.0, 0.0, 0.0), -Vector3::x());
+    let inter = cone.toi_and_normal_with_ray(&m, &side, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.75);
+    assert_relative_eq!(inter.normal, Vector3::new(4.0, 1.0, 0.0).normalize());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    // A ray along the axis crossing both nappes of the cone's quadric.
+    let through = Ray::new(Point3::new(0.05, 5.0, 0.0), -Vector3::y());
+    let inter = cone.toi_and_normal_with_ray(&m, &through, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.2, epsilon = 1.0e-9);
+    assert_eq!(inter.feature, FeatureId::Face(0));
+}
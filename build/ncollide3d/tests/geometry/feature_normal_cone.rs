@@ -0,0 +1,86 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::shape::{ConvexHull, ConvexPolyhedron, Cuboid, FeatureId, Segment, Triangle};
+
+fn check_support_features(shape: &dyn ConvexPolyhedron<f64>, m: &Isometry3<f64>, solid: bool) {
+    for i in 0..50 {
+        let t = i as f64;
+        let dir = Unit::new_normalize(Vector3::new(
+            (t * 0.37).sin(),
+            (t * 0.71).cos(),
+            (t * 0.13).sin() + 0.1,
+        ));
+        let local_dir = m.inverse_transform_unit_vector(&dir);
+        let feature = shape.support_feature_id_toward(&local_dir);
+        let normal = m * shape.feature_normal(feature);
+
+        assert!(shape.normal_cone_contains_dir(feature, m, &normal));
+
+        // The normal cone of the edge of a flat shape contains opposite directions.
+        if solid {
+            assert!(!shape.normal_cone_contains_dir(feature, m, &-normal));
+        }
+    }
+}
+
+#[test]
+fn cuboid_feature_normal_cones() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, 0.2, -0.4));
+    check_support_features(&cuboid, &m, true);
+
+    let id = Isometry3::identity();
+    let face = cuboid.support_feature_id_toward(&Vector3::x_axis());
+    assert!(cuboid.normal_cone_contains_dir(face, &id, &Vector3::x_axis()));
+
+    let tilted = Unit::new_normalize(Vector3::new(1.0, 0.1, 0.0));
+    assert!(!cuboid.normal_cone_contains_dir(face, &id, &tilted));
+
+    let vertex = cuboid.support_feature_id_toward(&Unit::new_normalize(Vector3::repeat(1.0)));
+    assert!(cuboid.normal_cone_contains_dir(vertex, &id, &tilted));
+}
+
+#[test]
+fn triangle_feature_normal_cones() {
+    let triangle = Triangle::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    let id = Isometry3::identity();
+    let normal = Unit::new_normalize(triangle.normal().unwrap().into_inner());
+    let face = triangle.support_feature_id_toward(&normal);
+
+    assert!(triangle.normal_cone_contains_dir(face, &id, &normal));
+    assert!(!triangle.normal_cone_contains_dir(face, &id, &-normal));
+
+    // A direction pointing away from the edge along the `x` axis is only on the normal cone of
+    // this edge and its vertices, not of the face.
+    let out_of_edge = Unit::new_normalize(Vector3::new(0.0, -0.1, -1.0));
+    let edge = triangle.support_feature_id_toward(&out_of_edge);
+    match edge {
+        FeatureId::Edge(_) => {}
+        _ => panic!("Expected an edge, found {:?}", edge),
+    }
+    assert!(triangle.normal_cone_contains_dir(edge, &id, &out_of_edge));
+    assert!(!triangle.normal_cone_contains_dir(face, &id, &out_of_edge));
+}
+
+#[test]
+fn convex_hull_and_segment_feature_normal_cones() {
+    let points: Vec<_> = (0..30)
+        .map(|i| {
+            let t = i as f64;
+            Point3::new(
+                (t * 0.37).sin() * 2.0,
+                (t * 0.71).cos(),
+                (t * 0.13).sin() * 0.5,
+            )
+        })
+        .collect();
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let m = Isometry3::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(0.1, -0.2, 0.3));
+    check_support_features(&hull, &m, true);
+
+    let segment = Segment::new(Point3::origin(), Point3::new(1.0, 1.0, 0.0));
+    check_support_features(&segment, &m, false);
+}
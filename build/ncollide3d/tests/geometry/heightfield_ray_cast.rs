@@ -0,0 +1,53 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::HeightField;
+
+// A flat heightfield of 512x512 cells over `[-256, 256]^2`, with a single bump.
+fn terrain() -> HeightField<f64> {
+    let heights = DMatrix::from_fn(
+        513,
+        513,
+        |i, j| {
+            if i == 400 && j == 300 {
+                10.0
+            } else {
+                0.0
+            }
+        },
+    );
+    HeightField::new(heights, Vector3::new(512.0, 1.0, 512.0))
+}
+
+#[test]
+fn heightfield_ray_cast_diagonal_walk() {
+    let heightfield = terrain();
+    let m = Isometry3::identity();
+
+    // The bump vertex is at `(44, 10, 144)`.
+    let target = Point3::new(44.0, 5.0, 144.0);
+    let origin = Point3::new(-250.0, 5.0, -250.0);
+    let ray = Ray::new(origin, (target - origin).normalize());
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    let hit = ray.point_at(inter.toi);
+    assert!(na::distance(&hit, &target) < 1.0);
+    assert!(hit.y > 4.9 && hit.y < 5.1);
+
+    // The same ray stops before reaching the bump.
+    assert!(heightfield
+        .toi_and_normal_with_ray(&m, &ray, 400.0, true)
+        .is_none());
+
+    // Rays going down always hit the ground, even after a transformation.
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * 0.3);
+    let ray = Ray::new(
+        m * Point3::new(-100.0, 50.0, 20.0),
+        m * Vector3::new(1.0, -1.0, 0.5),
+    );
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, 1000.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 50.0, epsilon = 1.0e-6);
+    assert_relative_eq!(inter.normal, m * Vector3::y(), epsilon = 1.0e-6);
+}
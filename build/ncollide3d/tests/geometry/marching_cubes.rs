@@ -0,0 +1,78 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::procedural::{self, IndexBuffer, TriMesh};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+// Checks that each edge is shared by exactly two triangles traversing it in opposite directions,
+// and returns the volume enclosed by the mesh.
+fn closed_volume(mesh: &TriMesh<f64>) -> f64 {
+    let triangles = match &mesh.indices {
+        IndexBuffer::Unified(idx) => idx.clone(),
+        IndexBuffer::Split(_) => panic!("Marching cubes generates unified index buffers."),
+    };
+    assert!(!triangles.is_empty());
+
+    let mut edges = HashMap::new();
+    let mut volume = 0.0;
+
+    for t in &triangles {
+        for (a, b) in &[(t.x, t.y), (t.y, t.z), (t.z, t.x)] {
+            *edges.entry((*a, *b)).or_insert(0) += 1;
+        }
+
+        let (a, b, c) = (
+            mesh.coords[t.x as usize].coords,
+            mesh.coords[t.y as usize].coords,
+            mesh.coords[t.z as usize].coords,
+        );
+        volume += a.dot(&b.cross(&c)) / 6.0;
+    }
+
+    for ((a, b), count) in &edges {
+        assert_eq!(*count, 1);
+        assert_eq!(edges.get(&(*b, *a)), Some(&1));
+    }
+
+    volume
+}
+
+#[test]
+fn marching_cubes_sphere() {
+    let bounds = AABB::new(Point3::new(-1.5, -1.5, -1.5), Point3::new(1.5, 1.5, 1.5));
+    let mesh = procedural::marching_cubes(
+        |p: &Point3<f64>| p.coords.norm() - 1.0,
+        &bounds,
+        [30, 30, 30],
+    );
+
+    assert!(mesh
+        .coords
+        .iter()
+        .all(|p| (p.coords.norm() - 1.0).abs() < 0.01));
+    assert_relative_eq!(closed_volume(&mesh), 4.0 / 3.0 * PI, epsilon = 0.05);
+}
+
+#[test]
+fn marching_cubes_closed_at_bounds() {
+    // Half of the cube is inside of the half-space.
+    let bounds = AABB::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    let mesh = procedural::marching_cubes(|p| p.z - 0.01, &bounds, [4, 4, 4]);
+
+    assert_relative_eq!(closed_volume(&mesh), 4.04, epsilon = 1.0e-9);
+    assert!(mesh.coords.iter().all(|p| bounds.contains_local_point(p)));
+}
+
+#[test]
+fn marching_cubes_ambiguous_grid() {
+    // Two negative samples on opposite corners of a face, and of the cell.
+    for other in &[Vector3::new(1, 1, 0), Vector3::new(1, 1, 1)] {
+        let mut values = vec![1.0; 27];
+        values[13] = -1.0;
+        values[13 + other.x + 3 * (other.y + 3 * other.z)] = -1.0;
+
+        let bounds = AABB::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0));
+        let mesh = procedural::marching_cubes_grid(&values, [3, 3, 3], &bounds);
+        assert!(closed_volume(&mesh) > 0.0);
+    }
+}
@@ -1,11 +1,47 @@
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod bounding_sphere_exact;
+mod bvt_lbvh;
+mod compound_ray_uvs;
 mod contact;
+mod convex_approximation;
+mod convex_hull_of_shape;
+mod convex_transform;
+mod cross_section;
 mod cuboid_ray_cast;
+mod cylinder_cone_axis;
+mod cylinder_cone_ray_cast;
 mod cylinder_cuboid_contact;
 mod epa3;
+mod feature_normal_cone;
 mod first_interference_with_ray;
+mod heightfield_ray_cast;
 mod interferences_with_ray;
+mod marching_cubes;
+mod overlap_volume;
+mod point_cloud;
+mod procedural_trimesh;
+mod quad;
+mod query_buffers;
+mod ray_packet;
+mod ray_primitives;
+#[cfg(feature = "rand")]
+mod sample;
+mod scaled;
+mod shared_shapes;
 mod still_objects_toi;
+mod superellipsoid;
+mod sweep;
+mod thick_trimesh;
 mod time_of_impact3;
+mod to_trimesh;
+mod triangle_triangle_intersection;
+mod trimesh_components;
+mod trimesh_face_data;
+mod trimesh_internal_edges;
+mod trimesh_lod;
+mod trimesh_normal_cones;
+mod trimesh_signed_distance;
+mod trimesh_trimesh_contact;
 mod trimesh_trimesh_toi;
+mod volumetric;
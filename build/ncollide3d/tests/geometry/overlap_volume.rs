@@ -0,0 +1,41 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid};
+use std::f64::consts::{FRAC_PI_4, PI};
+
+#[test]
+fn overlap_volume_polyhedra() {
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let identity = Isometry3::identity();
+
+    let shifted = Isometry3::new(Vector3::new(1.0, 0.5, -1.5), Vector3::zeros());
+    let volume = query::overlap_volume(&identity, &cube, &shifted, &cube, 1);
+    assert_relative_eq!(volume, 1.0 * 1.5 * 0.5, epsilon = 1.0e-7);
+
+    // The intersection of a cube with itself rotated by 45 degrees about an axis is an
+    // octagonal prism.
+    let corners: Vec<_> = (0..8)
+        .map(|k| {
+            Point3::new(
+                if k & 1 == 0 { -1.0 } else { 1.0 },
+                if k & 2 == 0 { -1.0 } else { 1.0 },
+                if k & 4 == 0 { -1.0 } else { 1.0 },
+            )
+        })
+        .collect();
+    let hull = ConvexHull::try_from_points(&corners).unwrap();
+    let rotated = Isometry3::new(Vector3::zeros(), Vector3::z() * FRAC_PI_4);
+    let volume = query::overlap_volume(&identity, &cube, &rotated, &hull, 1);
+    assert_relative_eq!(volume, (8.0 * 2.0f64.sqrt() - 8.0) * 2.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn overlap_volume_sampled_balls() {
+    let ball = Ball::new(1.0);
+    let identity = Isometry3::identity();
+    let shifted = Isometry3::new(Vector3::x(), Vector3::zeros());
+
+    // Volume of the lens formed by two unit balls at a distance of 1.
+    let volume = query::overlap_volume(&identity, &ball, &shifted, &ball, 60);
+    assert_relative_eq!(volume, PI * 5.0 / 12.0, epsilon = 2.0e-2);
+}
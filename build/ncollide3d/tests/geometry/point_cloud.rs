@@ -0,0 +1,132 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::{self, HasBoundingVolume, AABB};
+use ncollide3d::query::{PointQuery, PointQueryWithLocation};
+use ncollide3d::shape::{Ball, Cuboid, FeatureId, PointCloud, Shape};
+
+// A deterministic scan-like set of points, with duplicates.
+fn points() -> Vec<Point3<f64>> {
+    let mut seed = 12345u64;
+    let mut next = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 4.0 - 2.0
+    };
+
+    let mut points: Vec<_> = (0..500)
+        .map(|_| Point3::new(next(), next(), next()))
+        .collect();
+    points.extend_from_slice(&points[..10].to_vec());
+    points
+}
+
+fn sorted(mut ids: Vec<usize>) -> Vec<usize> {
+    ids.sort();
+    ids
+}
+
+#[test]
+fn point_cloud_nearest_point() {
+    let points = points();
+    let cloud = PointCloud::new(points.clone());
+    assert_eq!(cloud.points(), &points[..]);
+
+    for query in &[
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.5, -0.3, 0.7),
+        Point3::new(10.0, 10.0, -10.0),
+        points[42],
+    ] {
+        let (id, dist) = cloud.nearest_point(query);
+        let expected = points
+            .iter()
+            .map(|p| na::distance(p, query))
+            .fold(f64::MAX, f64::min);
+        assert_eq!(dist, expected);
+        assert_eq!(na::distance(&points[id], query), expected);
+    }
+}
+
+#[test]
+fn point_cloud_range_queries() {
+    let points = points();
+    let cloud = PointCloud::new(points.clone());
+
+    let center = Point3::new(0.3, -0.2, 0.5);
+    let mut ids = Vec::new();
+    cloud.points_within_radius(&center, 0.8, &mut ids);
+    let expected: Vec<_> = (0..points.len())
+        .filter(|i| na::distance(&points[*i], &center) <= 0.8)
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(sorted(ids), expected);
+
+    let aabb = AABB::new(Point3::new(-1.0, 0.0, -0.5), Point3::new(0.5, 1.0, 2.0));
+    let mut ids = Vec::new();
+    cloud.points_in_aabb(&aabb, &mut ids);
+    let expected: Vec<_> = (0..points.len())
+        .filter(|i| aabb.contains_local_point(&points[*i]))
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(sorted(ids), expected);
+}
+
+#[test]
+fn point_cloud_points_inside_shape() {
+    let points = points();
+    let cloud = PointCloud::new(points.clone());
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.3, 0.0));
+    let m_shape = Isometry3::new(Vector3::new(1.5, 0.5, 0.0), Vector3::new(0.2, 0.0, 0.4));
+
+    let shapes: [&dyn Shape<f64>; 2] = [&Ball::new(1.0), &Cuboid::new(Vector3::new(1.0, 0.5, 0.8))];
+
+    for shape in shapes.iter() {
+        let mut ids = Vec::new();
+        cloud.points_inside_shape(&m, &m_shape, *shape, &mut ids);
+        let query = shape.as_point_query().unwrap();
+        let expected: Vec<_> = (0..points.len())
+            .filter(|i| query.contains_point(&m_shape, &(m * points[*i])))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(ids), expected);
+    }
+}
+
+#[test]
+fn point_cloud_point_query() {
+    let points = points();
+    let cloud = PointCloud::new(points.clone());
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let query = m * Point3::new(0.5, 0.5, 0.5);
+
+    let (proj, id) = cloud.project_point_with_location(&m, &query, true);
+    let (local_id, dist) = cloud.nearest_point(&Point3::new(0.5, 0.5, 0.5));
+    assert_eq!(id, local_id);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, m * points[id], epsilon = 1.0e-12);
+    assert_relative_eq!(cloud.distance_to_point(&m, &query, true), dist);
+
+    let shape: &dyn Shape<f64> = &cloud;
+    let (proj, feature) = shape
+        .as_point_query()
+        .unwrap()
+        .project_point_with_feature(&Isometry3::identity(), &points[7]);
+    assert!(proj.is_inside);
+    assert!(matches!(feature, FeatureId::Vertex(_)));
+    assert!(shape.aabb(&m).contains_local_point(&(m * points[7])));
+}
+
+#[test]
+fn point_cloud_bounding_sphere_is_cached() {
+    let points = points();
+    let cloud = PointCloud::new(points.clone());
+    let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&points);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    assert_eq!(*cloud.bounding_sphere().center(), center);
+    assert_eq!(cloud.bounding_sphere().radius(), radius);
+
+    let sphere: bounding_volume::BoundingSphere<f64> = cloud.bounding_volume(&m);
+    assert_relative_eq!(*sphere.center(), m * center, epsilon = 1.0e-12);
+    assert_eq!(sphere.radius(), radius);
+}
@@ -0,0 +1,81 @@
+use na::{Point3, Vector3};
+use ncollide3d::procedural::{self, IndexBuffer, TriMesh};
+
+fn triangle() -> TriMesh<f64> {
+    TriMesh::new(
+        vec![
+            Point3::origin(),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ],
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn trimesh_flip_normals_and_triangles() {
+    let mut mesh = triangle();
+    mesh.recompute_normals();
+    assert_eq!(mesh.normals.as_ref().unwrap()[0], Vector3::z());
+
+    mesh.flip_normals();
+    mesh.flip_triangles();
+    assert_eq!(mesh.normals.as_ref().unwrap()[0], -Vector3::z());
+
+    // The recomputed normals agree with the flipped winding.
+    mesh.recompute_normals();
+    assert_eq!(mesh.normals.as_ref().unwrap()[0], -Vector3::z());
+}
+
+#[test]
+fn trimesh_flat_normals() {
+    let mut cuboid = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0));
+    cuboid.unify_index_buffer();
+    cuboid.recompute_normals();
+    cuboid.recompute_flat_normals();
+
+    let normals = cuboid.normals.clone().unwrap();
+    assert_eq!(normals.len(), 12);
+
+    match cuboid.indices {
+        IndexBuffer::Split(ref idx) => {
+            for (i, t) in idx.iter().enumerate() {
+                let a = cuboid.coords[t.x.x as usize];
+                let n = normals[t.x.y as usize];
+                assert_eq!(t.x.y as usize, i);
+                // Every flat normal of a cuboid centered at the origin points outward.
+                assert!(n.dot(&a.coords) > 0.0);
+                assert_relative_eq!(n.abs().max(), 1.0);
+            }
+        }
+        IndexBuffer::Unified(_) => panic!("Flat normals require a split index buffer."),
+    }
+}
+
+#[test]
+fn trimesh_append() {
+    // Two unified meshes remain unified.
+    let mut mesh = triangle();
+    mesh.append(triangle().transformed(&na::Isometry3::translation(0.0, 0.0, 1.0)));
+    assert_eq!(mesh.coords.len(), 6);
+    assert_eq!(mesh.flat_indices(), vec![0, 1, 2, 3, 4, 5]);
+    assert!(mesh.normals.is_none());
+
+    // Mixing with a split index buffer splits the result.
+    let cuboid = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0));
+    let num_cuboid_coords = cuboid.coords.len() as u32;
+    let mesh = cuboid.merged(triangle());
+    assert_eq!(mesh.num_triangles(), 13);
+    assert!(mesh.normals.is_none());
+
+    match mesh.indices {
+        IndexBuffer::Split(ref idx) => {
+            let last = idx.last().unwrap();
+            assert_eq!(last.x.x, num_cuboid_coords);
+            assert_eq!(last.z.x, num_cuboid_coords + 2);
+        }
+        IndexBuffer::Unified(_) => panic!("The merged index buffer should be split."),
+    }
+}
@@ -0,0 +1,83 @@
+use na::{Isometry3, Point3, Vector2, Vector3};
+use ncollide3d::bounding_volume;
+use ncollide3d::query::{self, PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, FeatureId, Quad};
+
+#[test]
+fn ray_cast_on_quad() {
+    let quad = Quad::new(Vector2::new(1.0f64, 2.0));
+    let m = Isometry3::translation(0.0, 1.0, 0.0);
+
+    let down = Ray::new(Point3::new(0.5, 3.0, -1.5), -Vector3::y());
+    let inter = quad
+        .toi_and_normal_and_uv_with_ray(&m, &down, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_relative_eq!(inter.normal, Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(0));
+    assert_relative_eq!(inter.uvs.unwrap(), na::Point2::new(0.75, 0.125));
+
+    let up = Ray::new(Point3::new(0.5, -1.0, 0.0), Vector3::y());
+    let inter = quad.toi_and_normal_with_ray(&m, &up, 10.0, true).unwrap();
+    assert_relative_eq!(inter.toi, 2.0);
+    assert_relative_eq!(inter.normal, -Vector3::y());
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    // Beyond the extents of the quad.
+    let outside = Ray::new(Point3::new(1.5, 3.0, 0.0), -Vector3::y());
+    assert!(!quad.intersects_ray(&m, &outside, 10.0));
+
+    let parallel = Ray::new(Point3::new(0.0, 1.0, -5.0), Vector3::z());
+    assert!(!quad.intersects_ray(&m, &parallel, 10.0));
+}
+
+#[test]
+fn project_point_on_quad() {
+    let quad = Quad::new(Vector2::new(1.0f64, 2.0));
+    let m = Isometry3::identity();
+
+    let (proj, feature) = quad.project_point_with_feature(&m, &Point3::new(0.5, -3.0, 1.0));
+    assert_relative_eq!(proj.point, Point3::new(0.5, 0.0, 1.0));
+    assert!(!proj.is_inside);
+    assert_eq!(feature, FeatureId::Face(1));
+
+    let (proj, feature) = quad.project_point_with_feature(&m, &Point3::new(3.0, 1.0, 0.5));
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.5));
+    assert_eq!(feature, FeatureId::Edge(2));
+
+    let (proj, feature) = quad.project_point_with_feature(&m, &Point3::new(-3.0, 1.0, 5.0));
+    assert_relative_eq!(proj.point, Point3::new(-1.0, 0.0, 2.0));
+    assert_eq!(feature, FeatureId::Vertex(1));
+
+    assert_relative_eq!(
+        quad.distance_to_point(&m, &Point3::new(2.0, 0.0, 0.0), true),
+        1.0
+    );
+    assert!(quad.contains_point(&m, &Point3::new(0.2, 0.0, -1.0)));
+}
+
+#[test]
+fn quad_bounding_volumes() {
+    let quad = Quad::new(Vector2::new(1.0f64, 2.0));
+    let m = Isometry3::translation(1.0, 2.0, 3.0);
+    let aabb = bounding_volume::aabb(&quad, &m);
+
+    assert_relative_eq!(aabb.mins, Point3::new(0.0, 2.0, 1.0));
+    assert_relative_eq!(aabb.maxs, Point3::new(2.0, 2.0, 5.0));
+}
+
+#[test]
+fn ball_quad_contact() {
+    let quad = Quad::new(Vector2::new(1.0f64, 1.0));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::identity();
+
+    let m2 = Isometry3::translation(0.2, 0.4, -0.3);
+    let contact = query::contact(&m1, &quad, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector3::y(), epsilon = 1.0e-6);
+
+    // The ball is past the edge of the quad.
+    let m2 = Isometry3::translation(2.0, 0.4, 0.0);
+    assert!(query::contact(&m1, &quad, &m2, &ball, 0.0).is_none());
+}
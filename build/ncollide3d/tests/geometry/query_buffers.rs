@@ -0,0 +1,67 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, visitors::CompositeClosestPointVisitor, QueryBuffers};
+use ncollide3d::shape::{Ball, CompositeShape, Cuboid, TriMesh};
+use ncollide3d::transformation;
+
+#[test]
+fn contact_with_buffers_matches_contact() {
+    let mut buffers = QueryBuffers::new();
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball = Ball::new(0.5f64);
+    let mesh: TriMesh<f64> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+
+    let m1 = Isometry3::identity();
+    let positions = [
+        Isometry3::new(Vector3::new(1.5, 0.2, 0.0), Vector3::new(0.0, 0.3, 0.2)),
+        Isometry3::new(Vector3::new(2.1, 0.0, 0.1), Vector3::zeros()),
+        Isometry3::new(Vector3::new(10.0, 0.0, 0.0), Vector3::zeros()),
+    ];
+
+    // The same buffers are reused for every query.
+    for m2 in positions.iter() {
+        let expected = query::contact(&m1, &cuboid, m2, &cuboid, 0.5);
+        let actual = query::contact_with_buffers(&m1, &cuboid, m2, &cuboid, 0.5, &mut buffers);
+        assert_eq!(expected, actual);
+
+        let expected = query::contact(&m1, &mesh, m2, &ball, 0.5);
+        let actual = query::contact_with_buffers(&m1, &mesh, m2, &ball, 0.5, &mut buffers);
+        assert_eq!(expected, actual);
+
+        let expected = query::contact(&m1, &cuboid, m2, &mesh, 0.5);
+        let actual = query::contact_with_buffers(&m1, &cuboid, m2, &mesh, 0.5, &mut buffers);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn best_first_search_with_buffers_matches_best_first_search() {
+    let mut buffers = QueryBuffers::new();
+    let mesh: TriMesh<f64> = procedural::sphere(2.0, 10, 10, true).into();
+
+    for point in [Point3::new(3.0, 0.0, 0.0), Point3::new(0.1, 0.2, -0.3)].iter() {
+        let mut visitor = CompositeClosestPointVisitor::new(&mesh, point, true);
+        let expected = mesh.bvh().best_first_search(&mut visitor).unwrap();
+        let mut visitor = CompositeClosestPointVisitor::new(&mesh, point, true);
+        let actual = mesh
+            .bvh()
+            .best_first_search_with_buffers(&mut visitor, &mut buffers)
+            .unwrap();
+
+        assert_eq!(expected.1.point, actual.1.point);
+    }
+}
+
+#[test]
+fn convex_hull_with_buffers_matches_convex_hull() {
+    let mut buffers = QueryBuffers::new();
+
+    for subdiv in 4..7 {
+        let points = procedural::sphere(1.0f64, subdiv, subdiv, true).coords;
+        let expected = transformation::convex_hull(&points[..]);
+        let actual = transformation::convex_hull_with_buffers(&points[..], &mut buffers);
+
+        assert_eq!(expected.coords, actual.coords);
+        assert_eq!(expected.flat_indices(), actual.flat_indices());
+    }
+}
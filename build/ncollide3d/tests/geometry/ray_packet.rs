@@ -0,0 +1,37 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::{Ray, RayCast, RayPacket};
+use ncollide3d::shape::TriMesh;
+
+#[test]
+fn ray_packet_on_trimesh() {
+    let mesh: TriMesh<f32> = procedural::sphere(2.0, 16, 16, false).into();
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * 0.3);
+
+    let mut rays = Vec::new();
+    for i in 0..8 {
+        for j in 0..8 {
+            let origin = Point3::new(i as f32 * 0.31 - 1.2, j as f32 * 0.29 + 0.8, -5.0);
+            rays.push(Ray::new(origin, Vector3::z()));
+        }
+    }
+
+    let packet = RayPacket::new(rays.clone());
+    let hits = packet.toi_and_normal_with_trimesh(&m, &mesh, 100.0);
+
+    for (ray, hit) in rays.iter().zip(hits.iter()) {
+        let expected = mesh.toi_and_normal_with_ray(&m, ray, 100.0, true);
+
+        match (hit, expected) {
+            (Some(hit), Some(expected)) => {
+                assert!((hit.toi - expected.toi).abs() < 1.0e-5);
+                assert!((hit.normal - expected.normal).norm() < 1.0e-5);
+                assert_eq!(hit.feature, expected.feature);
+            }
+            (None, None) => {}
+            _ => panic!("Ray packet and individual ray cast mismatch."),
+        }
+    }
+
+    assert!(hits.iter().any(|hit| hit.is_some()));
+}
@@ -0,0 +1,60 @@
+use na::{Point3, Vector3};
+use ncollide3d::query::ray::primitives;
+use ncollide3d::query::Ray;
+use ncollide3d::shape::FeatureId;
+
+#[test]
+fn ray_triangle_barycentric_coordinates() {
+    let a = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(2.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 2.0, 0.0);
+
+    let ray = Ray::new(Point3::new(0.5, 1.0, 3.0), Vector3::new(0.0, 0.0, -2.0));
+    let (inter, bcoords) = primitives::ray_intersection_with_triangle(&a, &b, &c, &ray).unwrap();
+    assert_relative_eq!(inter.toi, 1.5, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, Vector3::z(), epsilon = 1.0e-7);
+    assert_eq!(inter.feature, FeatureId::Face(0));
+
+    let pt = a * bcoords.x + b.coords * bcoords.y + c.coords * bcoords.z;
+    assert_relative_eq!(pt, ray.point_at(inter.toi), epsilon = 1.0e-7);
+
+    // Hitting the back side.
+    let ray = Ray::new(Point3::new(0.5, 1.0, -3.0), Vector3::z());
+    let (inter, _) = primitives::ray_intersection_with_triangle(&a, &b, &c, &ray).unwrap();
+    assert_relative_eq!(inter.normal, -Vector3::z(), epsilon = 1.0e-7);
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let ray = Ray::new(Point3::new(0.5, 1.0, 3.0), Vector3::x());
+    assert!(primitives::ray_intersection_with_triangle(&a, &b, &c, &ray).is_none());
+}
+
+#[test]
+fn ray_aabb_and_ball_primitives() {
+    let mins = Point3::new(-1.0, -1.0, -1.0);
+    let maxs = Point3::new(1.0, 1.0, 1.0);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0));
+    let toi = primitives::ray_toi_with_aabb(&mins, &maxs, &ray, 10.0, true);
+    assert_relative_eq!(toi.unwrap(), 2.0, epsilon = 1.0e-7);
+    assert!(primitives::ray_toi_with_aabb(&mins, &maxs, &ray, 1.0, true).is_none());
+
+    // Starting inside of the box.
+    let ray = Ray::new(Point3::origin(), Vector3::y());
+    let toi = primitives::ray_toi_with_aabb(&mins, &maxs, &ray, 10.0, true);
+    assert_eq!(toi, Some(0.0));
+    let toi = primitives::ray_toi_with_aabb(&mins, &maxs, &ray, 10.0, false);
+    assert_relative_eq!(toi.unwrap(), 1.0, epsilon = 1.0e-7);
+
+    let center = Point3::new(0.0, 0.0, 4.0);
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    let (inside, toi) = primitives::ray_toi_with_ball(&center, 1.0, &ray, true);
+    assert!(!inside);
+    assert_relative_eq!(toi.unwrap(), 3.0, epsilon = 1.0e-7);
+
+    let ray = Ray::new(center, Vector3::x());
+    let (inside, inter) = primitives::ray_toi_and_normal_with_ball(&center, 1.0, &ray, false);
+    let inter = inter.unwrap();
+    assert!(inside);
+    assert_relative_eq!(inter.toi, 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-7);
+}
@@ -0,0 +1,97 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::PointQuery;
+use ncollide3d::sample;
+use ncollide3d::shape::{Ball, Compound, ConvexHull, Cuboid, ShapeHandle, TriMesh};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn sample_ball_and_cuboid() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let ball = Ball::new(2.0f64);
+
+    let pts = sample::sample_interior(&ball, &mut rng, 1000);
+    assert_eq!(pts.len(), 1000);
+    assert!(pts.iter().all(|pt| pt.coords.norm() <= 2.0));
+
+    let pts = sample::sample_boundary(&ball, &mut rng, 1000);
+    assert!(pts.iter().all(|pt| (pt.coords.norm() - 2.0).abs() < 1.0e-7));
+    let mean = pts.iter().fold(Vector3::zeros(), |acc, pt| acc + pt.coords) / 1000.0;
+    assert!(mean.norm() < 0.2);
+
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let pts = sample::sample_boundary(&cuboid, &mut rng, 1000);
+    assert!(pts.iter().all(|pt| {
+        (0..3).any(|i| (pt[i].abs() - cuboid.half_extents[i]).abs() < 1.0e-7)
+            && (0..3).all(|i| pt[i].abs() <= cuboid.half_extents[i] + 1.0e-7)
+    }));
+}
+
+#[test]
+fn sample_polyhedra() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mesh = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0));
+    let hull = ConvexHull::try_from_points(&mesh.coords).unwrap();
+    let trimesh: TriMesh<f64> = mesh.into();
+    let id = Isometry3::identity();
+
+    for pt in sample::sample_interior(&hull, &mut rng, 500) {
+        assert!(hull.contains_point(&id, &pt));
+    }
+
+    for pt in sample::sample_interior(&trimesh, &mut rng, 500) {
+        assert!(pt.coords.amax() <= 1.0);
+    }
+
+    for pt in sample::sample_boundary(&trimesh, &mut rng, 500) {
+        assert!((pt.coords.amax() - 1.0).abs() < 1.0e-7);
+    }
+}
+
+#[test]
+fn sample_compound_proportionally() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(-10.0, 0.0, 0.0),
+            ShapeHandle::new(Ball::new(1.0f64)),
+        ),
+        (
+            Isometry3::translation(10.0, 0.0, 0.0),
+            ShapeHandle::new(Ball::new(2.0f64)),
+        ),
+    ]);
+
+    let pts = sample::sample_interior(&compound, &mut rng, 9000);
+    let nsmall = pts.iter().filter(|pt| pt.x < 0.0).count();
+    // The volume of the big ball is 8 times the volume of the small one.
+    assert!((nsmall as f64 - 1000.0).abs() < 150.0);
+    assert!(pts.iter().all(|pt| {
+        (pt - Point3::new(-10.0, 0.0, 0.0)).norm() <= 1.0
+            || (pt - Point3::new(10.0, 0.0, 0.0)).norm() <= 2.0
+    }));
+
+    let pts = sample::sample_boundary(&compound, &mut rng, 5000);
+    let nsmall = pts.iter().filter(|pt| pt.x < 0.0).count();
+    // The area of the big sphere is 4 times the area of the small one.
+    assert!((nsmall as f64 - 1000.0).abs() < 150.0);
+}
+
+#[test]
+fn sample_open_trimesh_interior_terminates() {
+    let mut rng = StdRng::seed_from_u64(3);
+    // A single triangle encloses nothing, but its signed volume is not zero.
+    let mesh = TriMesh::new(
+        vec![
+            Point3::new(1.0f64, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        ],
+        vec![Point3::new(0, 1, 2)],
+        None,
+    );
+
+    let pts = sample::sample_interior(&mesh, &mut rng, 10);
+    assert!(pts.len() < 10);
+}
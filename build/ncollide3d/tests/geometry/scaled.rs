@@ -0,0 +1,66 @@
+use na::{Isometry3, Point3, UnitQuaternion, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, Scaled, Shape, TriMesh};
+
+#[test]
+fn scaled_trimesh_matches_cuboid() {
+    let mesh: TriMesh<f64> = procedural::cuboid(&Vector3::repeat(2.0)).into();
+    let scaled = Scaled::new(mesh, Vector3::new(2.0, 1.0, -0.5));
+    let cuboid = Cuboid::new(Vector3::new(2.0, 1.0, 0.5));
+    let m = Isometry3::from_parts(
+        Vector3::new(1.0, 2.0, 3.0).into(),
+        UnitQuaternion::from_euler_angles(0.3, 0.2, 0.1),
+    );
+
+    let aabb = scaled.local_aabb();
+    assert_relative_eq!(aabb.mins, Point3::new(-2.0, -1.0, -0.5));
+    assert_relative_eq!(aabb.maxs, Point3::new(2.0, 1.0, 0.5));
+
+    let dirs = [
+        Vector3::new(1.0, 0.1, 0.2),
+        Vector3::new(-0.3, 1.0, 0.4),
+        Vector3::new(0.1, -0.2, -1.0),
+    ];
+
+    for dir in dirs.iter() {
+        let ray = Ray::new(m * Point3::origin() - dir * 10.0, *dir);
+        let expected = cuboid
+            .toi_and_normal_with_ray(&m, &ray, 100.0, true)
+            .unwrap();
+        let inter = scaled
+            .toi_and_normal_with_ray(&m, &ray, 100.0, true)
+            .unwrap();
+
+        assert_relative_eq!(inter.toi, expected.toi, epsilon = 1.0e-7);
+        assert_relative_eq!(inter.normal, expected.normal, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn scaled_ball_is_an_ellipsoid() {
+    let ellipsoid = Scaled::new(Ball::new(1.0f64), Vector3::new(3.0, 2.0, 1.0));
+    let m = Isometry3::identity();
+
+    // Support points and AABB.
+    let aabb = ellipsoid.aabb(&m);
+    assert_relative_eq!(aabb.maxs, Point3::new(3.0, 2.0, 1.0), epsilon = 1.0e-7);
+
+    // Ray cast with a normal given by the gradient of the implicit equation.
+    let dir = Vector3::new(-1.0, -1.0, -1.0);
+    let ray = Ray::new(Point3::new(10.0, 10.0, 10.0), dir);
+    let inter = ellipsoid
+        .toi_and_normal_with_ray(&m, &ray, 100.0, true)
+        .unwrap();
+    let pt = ray.point_at(inter.toi);
+    let implicit = (pt.x / 3.0).powi(2) + (pt.y / 2.0).powi(2) + pt.z.powi(2);
+    let gradient = Vector3::new(pt.x / 9.0, pt.y / 4.0, pt.z).normalize();
+    assert_relative_eq!(implicit, 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, gradient, epsilon = 1.0e-7);
+
+    // The scaled shape can be used with the generic geometric queries.
+    let ball = Ball::new(0.5);
+    let pos = Isometry3::translation(3.4, 0.0, 0.0);
+    let contact = query::contact(&m, &ellipsoid, &pos, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-5);
+}
@@ -0,0 +1,122 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::partitioning::{BVT, DBVT};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::procedural;
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{
+    Ball, Compound, ConvexHull, Cuboid, HeightField, Polyline, ShapeHandle, TriMesh,
+};
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn shapes_are_send_sync() {
+    assert_send_sync::<TriMesh<f32>>();
+    assert_send_sync::<Compound<f32>>();
+    assert_send_sync::<HeightField<f32>>();
+    assert_send_sync::<Polyline<f32>>();
+    assert_send_sync::<ConvexHull<f32>>();
+    assert_send_sync::<ShapeHandle<f32>>();
+    assert_send_sync::<BVT<usize, AABB<f32>>>();
+    assert_send_sync::<DBVT<f32, usize, AABB<f32>>>();
+    assert_send_sync::<CollisionWorld<f32, ()>>();
+}
+
+fn rays() -> Vec<Ray<f32>> {
+    (0..64)
+        .map(|i| {
+            let y = i as f32 / 64.0 - 0.5;
+            Ray::new(Point3::new(-5.0, y, 0.1), Vector3::x())
+        })
+        .collect()
+}
+
+#[test]
+fn parallel_ray_casts_on_shared_shapes() {
+    let trimesh: TriMesh<f32> = procedural::sphere(2.0, 32, 16, false).into();
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(0.0, 0.0, 0.0),
+            ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5))),
+        ),
+        (
+            Isometry3::translation(3.0, 0.0, 0.0),
+            ShapeHandle::new(Ball::new(0.5)),
+        ),
+    ]);
+    let m = Isometry3::identity();
+    let rays = rays();
+
+    let sequential: Vec<_> = rays
+        .iter()
+        .map(|ray| {
+            (
+                trimesh.toi_with_ray(&m, ray, f32::MAX, true),
+                compound.toi_with_ray(&m, ray, f32::MAX, true),
+            )
+        })
+        .collect();
+
+    let parallel: Vec<_> = thread::scope(|s| {
+        let workers: Vec<_> = rays
+            .chunks(16)
+            .map(|chunk| {
+                let (trimesh, compound) = (&trimesh, &compound);
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|ray| {
+                            (
+                                trimesh.toi_with_ray(&m, ray, f32::MAX, true),
+                                compound.toi_with_ray(&m, ray, f32::MAX, true),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(sequential, parallel);
+    assert!(sequential.iter().all(|(t, c)| t.is_some() && c.is_some()));
+}
+
+#[test]
+fn parallel_world_queries() {
+    let mut world = CollisionWorld::new(0.01);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    for i in 0..8 {
+        let pos = Isometry3::translation(0.0, i as f32 * 0.125 - 0.5, 0.0);
+        let shape = ShapeHandle::new(Ball::new(0.2f32));
+        let _ = world.add(pos, shape, groups, query, ());
+    }
+    world.update();
+
+    let rays = rays();
+    let world = &world;
+    let counts: Vec<usize> = thread::scope(|s| {
+        let workers: Vec<_> = rays
+            .iter()
+            .map(|ray| {
+                s.spawn(move || world.interferences_with_ray(ray, f32::MAX, &groups).count())
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    for (ray, count) in rays.iter().zip(&counts) {
+        let expected = world.interferences_with_ray(ray, f32::MAX, &groups).count();
+        assert_eq!(*count, expected);
+    }
+
+    assert!(counts.iter().any(|count| *count > 0));
+}
@@ -0,0 +1,75 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{Ball, Shape, Superellipsoid, SupportMap};
+use ncollide3d::transformation::ToTriMesh;
+
+fn directions() -> Vec<Vector3<f64>> {
+    let mut dirs = Vec::new();
+
+    for i in -3..=3 {
+        for j in -3..=3 {
+            for k in -3..=3 {
+                if i != 0 || j != 0 || k != 0 {
+                    dirs.push(Vector3::new(i as f64, j as f64, k as f64).normalize());
+                }
+            }
+        }
+    }
+
+    dirs
+}
+
+#[test]
+fn superellipsoid_support_point_lies_on_surface() {
+    for &(e1, e2) in &[(0.3, 0.3), (1.0, 1.0), (0.5, 1.7), (1.8, 0.2)] {
+        let shape = Superellipsoid::new(Vector3::new(1.0, 2.0, 0.5), e1, e2);
+
+        for dir in directions() {
+            let pt = shape.local_support_point(&dir);
+            assert_relative_eq!(shape.inside_outside(&pt), 1.0, epsilon = 1.0e-6);
+        }
+    }
+}
+
+#[test]
+fn superellipsoid_support_point_is_extremal() {
+    let shape = Superellipsoid::new(Vector3::new(1.0, 2.0, 0.5), 0.4, 1.5);
+    let mesh = shape.to_trimesh((64, 64));
+
+    for dir in directions() {
+        let supp = shape.local_support_point(&dir).coords.dot(&dir);
+
+        for pt in &mesh.coords {
+            assert_relative_eq!(shape.inside_outside(pt), 1.0, epsilon = 1.0e-6);
+            assert!(pt.coords.dot(&dir) <= supp + 1.0e-9);
+        }
+    }
+}
+
+#[test]
+fn unit_exponents_superellipsoid_is_ellipsoid() {
+    let shape = Superellipsoid::new(Vector3::new(2.0, 2.0, 2.0), 1.0, 1.0);
+    let ball = Ball::new(2.0);
+
+    for dir in directions() {
+        assert_relative_eq!(
+            shape.local_support_point(&dir),
+            ball.local_support_point(&dir),
+            epsilon = 1.0e-9
+        );
+    }
+}
+
+#[test]
+fn superellipsoid_aabb_and_ray_cast() {
+    let shape = Superellipsoid::new(Vector3::new(1.0, 2.0, 3.0), 0.2, 0.2);
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    let aabb = shape.local_aabb();
+    assert_relative_eq!(*aabb.maxs(), Point3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(*aabb.mins(), Point3::new(-1.0, -2.0, -3.0));
+
+    let ray = Ray::new(Point3::new(10.0, 0.0, 0.0), -Vector3::x());
+    let toi = shape.toi_with_ray(&m, &ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 8.0, epsilon = 1.0e-6);
+}
@@ -0,0 +1,82 @@
+use crate::common::signed_volume;
+use na::{Point2, Point3, Vector3};
+use ncollide3d::procedural::{self, SweepJoin, SweepOptions};
+
+fn square_profile() -> Vec<Point2<f64>> {
+    vec![
+        Point2::new(-0.5, -0.5),
+        Point2::new(0.5, -0.5),
+        Point2::new(0.5, 0.5),
+        Point2::new(-0.5, 0.5),
+    ]
+}
+
+fn l_path() -> Vec<Point3<f64>> {
+    vec![
+        Point3::origin(),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 3.0),
+    ]
+}
+
+#[test]
+fn sweep_straight_pipe_volume() {
+    let path = [Point3::origin(), Point3::new(0.0, 0.0, 4.0)];
+    let mesh = procedural::sweep(&square_profile(), &path, &SweepOptions::default());
+
+    // Two sections of four points, plus the two cap centers.
+    assert_eq!(mesh.coords.len(), 10);
+    assert_relative_eq!(signed_volume(&mesh), 4.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sweep_miter_join_lies_on_bisector_plane() {
+    let mesh = procedural::sweep(&square_profile(), &l_path(), &SweepOptions::default());
+
+    // One section per path point.
+    assert_eq!(mesh.coords.len(), 3 * 4 + 2);
+
+    let bisector = Vector3::new(1.0, 0.0, 1.0);
+    for pt in &mesh.coords[4..8] {
+        assert_relative_eq!((pt - Point3::new(2.0, 0.0, 0.0)).dot(&bisector), 0.0);
+    }
+
+    // The mitered pipe has the volume of the profile swept along the path length.
+    assert_relative_eq!(signed_volume(&mesh), 5.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sweep_round_join_subdivisions() {
+    let options = SweepOptions {
+        join: SweepJoin::Round(4),
+        ..SweepOptions::default()
+    };
+    let mesh = procedural::sweep(&square_profile(), &l_path(), &options);
+
+    // Start and end sections, plus five sections around the joint.
+    assert_eq!(mesh.coords.len(), 7 * 4 + 2);
+
+    // All the sections of the joint are centered on the joint.
+    for section in mesh.coords[4..24].chunks(4) {
+        let center = section
+            .iter()
+            .fold(Vector3::zeros(), |acc, p| acc + p.coords)
+            / 4.0;
+        assert_relative_eq!(center, Vector3::new(2.0, 0.0, 0.0), epsilon = 1.0e-10);
+    }
+
+    assert!(signed_volume(&mesh) > 0.0);
+}
+
+#[test]
+fn sweep_open_profile_has_no_caps() {
+    let profile = [Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0)];
+    let options = SweepOptions {
+        closed_profile: false,
+        ..SweepOptions::default()
+    };
+    let mesh = procedural::sweep(&profile, &l_path(), &options);
+
+    assert_eq!(mesh.coords.len(), 6);
+    assert_eq!(mesh.num_triangles(), 4);
+}
@@ -0,0 +1,117 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::{self, Ray, RayCast};
+use ncollide3d::shape::{Ball, FeatureId, ShapeHandle, TriMesh};
+
+fn floor(thickness: f64) -> TriMesh<f64> {
+    let points = vec![
+        Point3::new(-2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, 2.0),
+        Point3::new(-2.0, 0.0, 2.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    TriMesh::new(points, indices, None).with_thickness(thickness)
+}
+
+#[test]
+fn thick_trimesh_ray_cast() {
+    let identity = Isometry3::identity();
+    let mesh = floor(0.1);
+    let down = Ray::new(Point3::new(0.5, 1.0, 0.3), -Vector3::y());
+    let up = Ray::new(Point3::new(0.5, -1.0, 0.3), Vector3::y());
+
+    let top = mesh
+        .toi_and_normal_with_ray(&identity, &down, 10.0, true)
+        .unwrap();
+    let bottom = mesh
+        .toi_and_normal_with_ray(&identity, &up, 10.0, true)
+        .unwrap();
+
+    assert_relative_eq!(top.toi, 0.9, epsilon = 1.0e-6);
+    assert_relative_eq!(top.normal, Vector3::y(), epsilon = 1.0e-6);
+    assert_relative_eq!(bottom.toi, 0.9, epsilon = 1.0e-6);
+    assert_relative_eq!(bottom.normal, -Vector3::y(), epsilon = 1.0e-6);
+    assert_ne!(top.feature, bottom.feature);
+    assert_relative_eq!(
+        floor(0.0)
+            .toi_with_ray(&identity, &down, 10.0, true)
+            .unwrap(),
+        1.0
+    );
+}
+
+#[test]
+fn thick_trimesh_contact() {
+    let identity = Isometry3::identity();
+    let ball = Ball::new(0.2);
+    let pos = Isometry3::new(Vector3::new(0.5, 0.25, 0.3), Vector3::zeros());
+
+    assert!(query::contact(&identity, &floor(0.0), &pos, &ball, 0.0).is_none());
+
+    let contact = query::contact(&pos, &ball, &identity, &floor(0.1), 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.world2, Point3::new(0.5, 0.1, 0.3), epsilon = 1.0e-6);
+
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        identity,
+        ShapeHandle::new(floor(0.1)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        pos,
+        ShapeHandle::new(ball),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    let deepest = manifold.deepest_contact().unwrap();
+    assert_relative_eq!(deepest.contact.depth, 0.05, epsilon = 1.0e-6);
+    assert!(deepest.kinematic.dilation1() == 0.1 || deepest.kinematic.dilation2() == 0.1);
+    assert_ne!(deepest.kinematic.feature1(), FeatureId::Unknown);
+}
+
+#[test]
+fn thick_trimesh_trimesh_contact() {
+    let identity = Isometry3::identity();
+    let pos = Isometry3::new(Vector3::new(0.3, 0.1, 0.2), Vector3::zeros());
+
+    // Both meshes are dilated, by the query and by the contact manifold generator.
+    assert!(query::contact(&identity, &floor(0.0), &pos, &floor(0.0), 0.0).is_none());
+    let contact = query::contact(&identity, &floor(0.1), &pos, &floor(0.05), 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.05, epsilon = 1.0e-6);
+
+    let mut world = CollisionWorld::new(0.0);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        identity,
+        ShapeHandle::new(floor(0.1)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        pos,
+        ShapeHandle::new(floor(0.05)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert!(manifold.len() > 0);
+
+    for tracked in manifold.contacts() {
+        assert_relative_eq!(tracked.contact.depth, 0.05, epsilon = 1.0e-6);
+        let dilations = tracked.kinematic.dilation1() + tracked.kinematic.dilation2();
+        assert_relative_eq!(dilations, 0.15, epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::common::signed_volume;
+use na::{Isometry3, Unit, Vector3};
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, Plane, Shape, ShapeHandle,
+};
+use ncollide3d::transformation::ToTriMesh;
+
+#[test]
+fn convex_hull_to_trimesh_is_closed_and_outward() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let hull = ConvexHull::try_from_points(&cuboid.to_trimesh(()).coords).unwrap();
+    let mesh = hull.to_trimesh(());
+
+    assert_eq!(mesh.num_triangles(), 12);
+    assert_relative_eq!(signed_volume(&mesh), 48.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn capsule_to_trimesh_with_low_subdivisions() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let exact = std::f64::consts::PI * 0.25 * 2.0 + 4.0 / 3.0 * std::f64::consts::PI * 0.125;
+
+    // The mesh is inscribed into the capsule.
+    for nsubdivs in [(1, 1), (2, 2), (3, 2), (16, 8)] {
+        let volume = signed_volume(&capsule.to_trimesh(nsubdivs));
+        assert!(volume > 0.0 && volume < exact);
+    }
+
+    // With enough subdivisions, the volume converges to the exact one.
+    assert_relative_eq!(
+        signed_volume(&capsule.to_trimesh((64, 32))),
+        exact,
+        epsilon = 1.0e-2
+    );
+}
+
+#[test]
+fn plane_to_trimesh_is_a_bounded_quad() {
+    let normal = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    let mesh = Plane::new(normal).to_trimesh(2.0);
+
+    assert_eq!(mesh.num_triangles(), 2);
+    for pt in &mesh.coords {
+        assert_relative_eq!(pt.coords.dot(&normal), 0.0, epsilon = 1.0e-7);
+        assert_relative_eq!(pt.coords.norm(), 8.0f64.sqrt(), epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn compound_and_dyn_shape_to_trimesh() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::repeat(1.0)));
+    let ball = ShapeHandle::new(Ball::new(1.0));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(-3.0, 0.0, 0.0), cuboid.clone()),
+        (Isometry3::translation(3.0, 0.0, 0.0), cuboid),
+        (Isometry3::translation(0.0, 5.0, 0.0), ball),
+    ]);
+
+    let mesh = compound.to_trimesh((16, 16));
+    assert!(signed_volume(&mesh) > 16.0);
+    assert!(signed_volume(&mesh) < 16.0 + 4.0 / 3.0 * std::f64::consts::PI);
+    assert!(mesh.coords.iter().all(|pt| pt.x.abs() <= 4.0 + 1.0e-7));
+
+    let shape: &dyn Shape<f64> = &compound;
+    assert_eq!(
+        shape.to_trimesh((16, 16)).num_triangles(),
+        mesh.num_triangles()
+    );
+
+    // Cones and cylinders have their own discretization.
+    let cone: &dyn Shape<f64> = &Cone::new(1.0, 0.5);
+    assert_eq!(
+        cone.to_trimesh((16, 16)).num_triangles(),
+        Cone::new(1.0, 0.5).to_trimesh(16).num_triangles()
+    );
+    let cylinder: &dyn Shape<f64> = &Cylinder::new(1.0, 0.5);
+    assert_eq!(
+        cylinder.to_trimesh((16, 16)).num_triangles(),
+        Cylinder::new(1.0, 0.5).to_trimesh(16).num_triangles()
+    );
+    let oriented: &dyn Shape<f64> = &Cone::new(1.0, 0.5).with_axis(Vector3::x_axis());
+    let mesh = oriented.to_trimesh((16, 16));
+    assert_eq!(
+        mesh.num_triangles(),
+        Cone::new(1.0, 0.5).to_trimesh(16).num_triangles()
+    );
+    assert!(mesh.coords.iter().all(|pt| pt.x <= 1.0 + 1.0e-7));
+    assert!(mesh.coords.iter().any(|pt| pt.x >= 1.0 - 1.0e-7));
+
+    // Planes are unbounded.
+    let plane: &dyn Shape<f64> = &Plane::new(Vector3::y_axis());
+    assert_eq!(plane.to_trimesh((16, 16)).num_triangles(), 0);
+}
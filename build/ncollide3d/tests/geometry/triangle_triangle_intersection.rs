@@ -0,0 +1,132 @@
+use na::Point3;
+use ncollide3d::query::{self, TriangleTriangleIntersection};
+use ncollide3d::shape::Triangle;
+
+fn assert_segment_eq(
+    res: TriangleTriangleIntersection<f32>,
+    expected_a: Point3<f32>,
+    expected_b: Point3<f32>,
+) {
+    match res {
+        TriangleTriangleIntersection::Segment(a, b) => {
+            let same = (a - expected_a).norm() < 1.0e-5 && (b - expected_b).norm() < 1.0e-5;
+            let flipped = (a - expected_b).norm() < 1.0e-5 && (b - expected_a).norm() < 1.0e-5;
+            assert!(same || flipped, "Unexpected segment: {:?} {:?}", a, b);
+        }
+        _ => panic!("Expected a segment, found {:?}", res),
+    }
+}
+
+#[test]
+fn triangle_triangle_crossing() {
+    let t1 = Triangle::new(
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    let t2 = Triangle::new(
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, -3.0),
+    );
+
+    // The second triangle pierces the first one along the z axis.
+    assert_segment_eq(
+        query::triangle_triangle_intersection(&t1, &t2),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 0.0),
+    );
+    assert_segment_eq(
+        query::triangle_triangle_intersection(&t2, &t1),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn triangle_triangle_disjoint_and_touching() {
+    let t1 = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let t2 = Triangle::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 2.0),
+    );
+    assert_eq!(
+        query::triangle_triangle_intersection(&t1, &t2),
+        TriangleTriangleIntersection::Disjoint
+    );
+
+    // A vertex of the second triangle lies on the first one.
+    let t2 = Triangle::new(
+        Point3::new(0.25, 0.25, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    match query::triangle_triangle_intersection(&t1, &t2) {
+        TriangleTriangleIntersection::Touching(pt) => {
+            assert!((pt - Point3::new(0.25, 0.25, 0.0)).norm() < 1.0e-5)
+        }
+        res => panic!("Expected a touching point, found {:?}", res),
+    }
+
+    // The planes intersect but the triangles do not.
+    let t2 = Triangle::new(
+        Point3::new(2.0, 2.0, -1.0),
+        Point3::new(3.0, 2.0, 1.0),
+        Point3::new(2.0, 3.0, 1.0),
+    );
+    assert_eq!(
+        query::triangle_triangle_intersection(&t1, &t2),
+        TriangleTriangleIntersection::Disjoint
+    );
+}
+
+#[test]
+fn triangle_triangle_coplanar() {
+    let t1 = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    );
+    let t2 = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+
+    match query::triangle_triangle_intersection(&t1, &t2) {
+        TriangleTriangleIntersection::Coplanar(poly) => {
+            assert_eq!(poly.len(), 3);
+            for pt in &poly {
+                assert!(pt.x >= -1.0e-5 && pt.y >= -1.0e-5 && pt.x + pt.y <= 1.0 + 1.0e-5);
+            }
+        }
+        res => panic!("Expected a coplanar polygon, found {:?}", res),
+    }
+
+    // Coplanar triangles sharing an edge.
+    let t2 = Triangle::new(
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+    );
+    assert_segment_eq(
+        query::triangle_triangle_intersection(&t1, &t2),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    );
+
+    let t2 = Triangle::new(
+        Point3::new(3.0, 3.0, 0.0),
+        Point3::new(4.0, 3.0, 0.0),
+        Point3::new(3.0, 4.0, 0.0),
+    );
+    assert_eq!(
+        query::triangle_triangle_intersection(&t1, &t2),
+        TriangleTriangleIntersection::Disjoint
+    );
+}
@@ -0,0 +1,83 @@
+use na::{Point2, Point3, Vector3};
+use ncollide3d::shape::TriMesh;
+
+fn tetrahedron(
+    offset: Vector3<f32>,
+    points: &mut Vec<Point3<f32>>,
+    indices: &mut Vec<Point3<usize>>,
+) {
+    let base = points.len();
+    points.push(Point3::origin() + offset);
+    points.push(Point3::new(1.0, 0.0, 0.0) + offset);
+    points.push(Point3::new(0.0, 1.0, 0.0) + offset);
+    points.push(Point3::new(0.0, 0.0, 1.0) + offset);
+    indices.push(Point3::new(base, base + 2, base + 1));
+    indices.push(Point3::new(base, base + 1, base + 3));
+    indices.push(Point3::new(base, base + 3, base + 2));
+    indices.push(Point3::new(base + 1, base + 2, base + 3));
+}
+
+// Two tetrahedra far away from each other, and a triangle sharing a single vertex with the
+// first one. An unused point is inserted between both tetrahedra.
+fn mesh() -> TriMesh<f32> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    tetrahedron(Vector3::zeros(), &mut points, &mut indices);
+    points.push(Point3::new(100.0, 100.0, 100.0));
+    tetrahedron(Vector3::new(10.0, 0.0, 0.0), &mut points, &mut indices);
+    points.push(Point3::new(-1.0, 0.0, 0.0));
+    points.push(Point3::new(-1.0, -1.0, 0.0));
+    indices.push(Point3::new(0, 9, 10));
+
+    let uvs = (0..points.len())
+        .map(|i| Point2::new(i as f32, 0.0))
+        .collect();
+    let mut mesh = TriMesh::new(points, indices, Some(uvs));
+    mesh.set_oriented(true);
+    mesh.set_closed(true);
+    mesh
+}
+
+#[test]
+fn trimesh_connected_components() {
+    let mesh = mesh();
+    assert_eq!(mesh.connected_components(), vec![0, 0, 0, 0, 1, 1, 1, 1, 0]);
+}
+
+#[test]
+fn trimesh_split_components() {
+    let mesh = mesh();
+    let parts = mesh.split_components();
+    assert_eq!(parts.len(), 2);
+
+    assert_eq!(parts[0].faces().len(), 5);
+    assert_eq!(parts[0].points().len(), 6);
+    assert_eq!(parts[1].faces().len(), 4);
+    assert_eq!(parts[1].points().len(), 4);
+    assert!(parts.iter().all(|p| p.oriented() && p.closed()));
+    assert!(parts[0].normal_cone_bvt().is_none());
+
+    // The points are kept in their original relative order.
+    assert_eq!(parts[0].points()[4], Point3::new(-1.0, 0.0, 0.0));
+    assert_eq!(parts[1].points()[0], Point3::new(10.0, 0.0, 0.0));
+    assert_eq!(parts[1].uvs().unwrap()[0], Point2::new(5.0, 0.0));
+
+    // Each face of the original mesh is found, with the same vertices, in one of the parts.
+    let mut labels = mesh.connected_components().into_iter();
+    let mut seen = vec![0; 2];
+
+    for i in 0..mesh.faces().len() {
+        let label = labels.next().unwrap();
+        let original = mesh.triangle_at(i);
+        let part = parts[label].triangle_at(seen[label]);
+        assert_eq!(
+            (original.a, original.b, original.c),
+            (part.a, part.b, part.c)
+        );
+        seen[label] += 1;
+    }
+
+    let with_cones = mesh.with_normal_cones().split_components();
+    assert!(with_cones.iter().all(|p| p.normal_cone_bvt().is_some()));
+}
@@ -0,0 +1,81 @@
+use na::{Isometry3, Point2, Point3, Vector3};
+use ncollide3d::query::{PointQuery, Ray, RayCast};
+use ncollide3d::shape::{FeatureId, TriMesh};
+
+// A unit square in the `xy` plane made of two triangles, with the materials 7 and 9.
+fn square() -> TriMesh<f32> {
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)];
+    let uvs = points.iter().map(|pt| Point2::new(pt.x, pt.y)).collect();
+
+    TriMesh::new(points, indices, Some(uvs)).with_face_data(vec![7, 9])
+}
+
+#[test]
+fn trimesh_face_data_in_ray_casts() {
+    let mesh = square();
+    let m = Isometry3::translation(0.0, 0.0, 1.0);
+    let dir = -Vector3::z();
+
+    let hit1 = Ray::new(Point3::new(0.75, 0.25, 2.0), dir);
+    let hit2 = Ray::new(Point3::new(0.25, 0.75, 2.0), dir);
+
+    let inter = mesh.toi_and_normal_with_ray(&m, &hit1, 10.0, true).unwrap();
+    assert_eq!(inter.face_data(), Some(7));
+    let inter = mesh
+        .toi_and_normal_and_uv_with_ray(&m, &hit2, 10.0, true)
+        .unwrap();
+    assert_eq!(inter.face_data(), Some(9));
+    assert!(inter.uvs.is_some());
+
+    let mut mesh = square();
+    mesh.set_face_data(None);
+    let inter = mesh.toi_and_normal_with_ray(&m, &hit1, 10.0, true).unwrap();
+    assert_eq!(inter.face_data(), None);
+}
+
+#[test]
+fn trimesh_face_data_in_point_projections() {
+    let mesh = square();
+    let m = Isometry3::identity();
+
+    let proj = mesh.project_point(&m, &Point3::new(0.9, 0.1, 1.0), true);
+    assert_eq!(proj.face_data(), Some(7));
+    let (proj, feature) = mesh.project_point_with_feature(&m, &Point3::new(-1.0, 2.0, 0.0));
+    assert_eq!(proj.face_data(), Some(9));
+    assert_eq!(feature, FeatureId::Vertex(3));
+    assert_eq!(mesh.face_data_at_feature(feature), Some(9));
+
+    assert_eq!(mesh.face_data_at_feature(FeatureId::Face(0)), Some(7));
+    // Backfaces are attached to the same data as their front face.
+    assert_eq!(mesh.face_data_at_feature(FeatureId::Face(3)), Some(9));
+    assert_eq!(mesh.face_data_at_feature(FeatureId::Unknown), None);
+}
+
+#[test]
+fn trimesh_face_data_of_components() {
+    let mut points = square().points().to_vec();
+    points.extend(square().points().iter().map(|pt| pt + Vector3::z() * 5.0));
+    let indices = vec![
+        Point3::new(0, 1, 2),
+        Point3::new(4, 5, 6),
+        Point3::new(0, 2, 3),
+    ];
+    let mesh = TriMesh::new(points, indices, None).with_face_data(vec![1, 2, 3]);
+    let parts = mesh.split_components();
+
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].face_data(), Some(&[1, 3][..]));
+    assert_eq!(parts[1].face_data(), Some(&[2][..]));
+}
+
+#[test]
+#[should_panic]
+fn trimesh_face_data_length_mismatch() {
+    let _ = square().with_face_data(vec![1, 2, 3]);
+}
@@ -0,0 +1,96 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::query::ContactPrediction;
+use ncollide3d::shape::{Cuboid, TriMesh};
+
+// A flat square made of four triangles, with internal edges along `x = 0` and the diagonals.
+fn flat_ground() -> TriMesh<f64> {
+    let points = vec![
+        Point3::new(-2.0, 0.0, -2.0),
+        Point3::new(0.0, 0.0, -2.0),
+        Point3::new(2.0, 0.0, -2.0),
+        Point3::new(-2.0, 0.0, 2.0),
+        Point3::new(0.0, 0.0, 2.0),
+        Point3::new(2.0, 0.0, 2.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 3, 4),
+        Point3::new(0, 4, 1),
+        Point3::new(1, 4, 5),
+        Point3::new(1, 5, 2),
+    ];
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn sliding_cuboid_does_not_catch_internal_edges() {
+    let mesh = flat_ground();
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let mut algorithm = dispatcher.get_contact_algorithm(&mesh, &cuboid).unwrap();
+
+    for i in 0..100 {
+        // The cuboid penetrates the ground and slides across the edge at `x = 0`.
+        let x = -0.6 + i as f64 * 0.012;
+        let pos_cuboid = Isometry3::translation(x, 0.45, 0.3);
+        let mut manifold = algorithm.init_manifold();
+
+        let _ = algorithm.generate_contacts(
+            &dispatcher,
+            &Isometry3::identity(),
+            &mesh,
+            None,
+            &pos_cuboid,
+            &cuboid,
+            None,
+            &prediction,
+            &mut manifold,
+        );
+
+        assert!(manifold.len() > 0);
+
+        for c in manifold.contacts() {
+            assert_relative_eq!(*c.contact.normal, Vector3::y(), epsilon = 1.0e-6);
+            assert!(c.contact.depth <= 0.05 + 1.0e-6);
+        }
+
+        let deepest = manifold.deepest_contact().unwrap();
+        assert_relative_eq!(deepest.contact.depth, 0.05, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn sliding_cuboid_does_not_catch_internal_edges_flipped() {
+    let mesh = flat_ground();
+    let cuboid = Cuboid::new(Vector3::repeat(0.5));
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let mut algorithm = dispatcher.get_contact_algorithm(&cuboid, &mesh).unwrap();
+
+    for i in 0..100 {
+        let z = -0.6 + i as f64 * 0.012;
+        let pos_cuboid = Isometry3::translation(0.7, 0.45, z);
+        let mut manifold = algorithm.init_manifold();
+
+        let _ = algorithm.generate_contacts(
+            &dispatcher,
+            &pos_cuboid,
+            &cuboid,
+            None,
+            &Isometry3::identity(),
+            &mesh,
+            None,
+            &prediction,
+            &mut manifold,
+        );
+
+        for c in manifold.contacts() {
+            assert_relative_eq!(*c.contact.normal, -Vector3::y(), epsilon = 1.0e-6);
+        }
+
+        let deepest = manifold.deepest_contact().unwrap();
+        assert_relative_eq!(deepest.contact.depth, 0.05, epsilon = 1.0e-6);
+    }
+}
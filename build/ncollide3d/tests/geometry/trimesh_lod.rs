@@ -0,0 +1,100 @@
+use na::Point3;
+use ncollide3d::procedural::{IndexBuffer, TriMesh};
+use ncollide3d::shape::{Ball, Capsule, Compound, ShapeHandle};
+use ncollide3d::transformation::{ToTriMesh, ToTriMeshWithTolerance, TriMeshLOD};
+
+// The largest distance between the ball of radius `radius` centered at the origin and the
+// planes of the triangles of `mesh`.
+fn max_chordal_error(mesh: &TriMesh<f64>, radius: f64) -> f64 {
+    let indices = match &mesh.indices {
+        IndexBuffer::Unified(indices) => indices.clone(),
+        IndexBuffer::Split(indices) => indices
+            .iter()
+            .map(|t| Point3::new(t.x.x, t.y.x, t.z.x))
+            .collect(),
+    };
+
+    indices
+        .iter()
+        .filter_map(|t| {
+            let a = mesh.coords[t.x as usize];
+            let b = mesh.coords[t.y as usize];
+            let c = mesh.coords[t.z as usize];
+            let n = (b - a).cross(&(c - a)).try_normalize(1.0e-12)?;
+            Some(radius - a.coords.dot(&n).abs())
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn ball_tolerance_scales_with_radius() {
+    let small = Ball::new(0.1);
+    let large = Ball::new(10.0);
+
+    let (ntheta_small, _) = small.discretization_parameter(0.01);
+    let (ntheta_large, _) = large.discretization_parameter(0.01);
+    assert!(ntheta_large > ntheta_small);
+
+    for &radius in &[0.1, 1.0, 10.0] {
+        let mesh = Ball::new(radius).to_trimesh_with_tolerance(0.01);
+        assert!(max_chordal_error(&mesh, radius) <= 0.01);
+    }
+}
+
+#[test]
+fn capsule_tolerance() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let (ntheta, nphi) = capsule.discretization_parameter(0.001);
+    assert!(ntheta > 3 && nphi > 2);
+
+    // A tolerance larger than the radius gives the coarsest capsule.
+    assert_eq!(capsule.discretization_parameter(1.0), (3, 2));
+}
+
+#[test]
+fn compound_tolerance_per_part() {
+    let parts = vec![
+        (na::Isometry3::identity(), ShapeHandle::new(Ball::new(0.1))),
+        (
+            na::Isometry3::translation(5.0, 0.0, 0.0),
+            ShapeHandle::new(Ball::new(2.0)),
+        ),
+    ];
+    let compound = Compound::new(parts);
+
+    let small = Ball::new(0.1).to_trimesh_with_tolerance(0.01);
+    let large = Ball::new(2.0).to_trimesh_with_tolerance(0.01);
+    let mesh = compound.to_trimesh_with_tolerance(0.01);
+
+    assert_eq!(mesh.coords.len(), small.coords.len() + large.coords.len());
+    assert_eq!(
+        compound.discretization_parameter(0.01),
+        Ball::new(2.0).discretization_parameter(0.01)
+    );
+}
+
+#[test]
+fn lod_levels() {
+    let ball = Ball::new(1.0);
+    let lod = TriMeshLOD::with_doubling_tolerances(&ball, 0.001, 4);
+
+    let tolerances: Vec<f64> = lod.levels().iter().map(|l| l.0).collect();
+    assert_eq!(tolerances, vec![0.001, 0.002, 0.004, 0.008]);
+
+    // The coarser levels have less vertices.
+    assert!(lod
+        .levels()
+        .windows(2)
+        .all(|w| w[0].1.coords.len() > w[1].1.coords.len()));
+
+    assert_eq!(
+        lod.level(0.005).coords.len(),
+        lod.levels()[2].1.coords.len()
+    );
+    assert_eq!(lod.level(1.0).coords.len(), lod.levels()[3].1.coords.len());
+    // Too coarse: the finest level is returned.
+    assert_eq!(
+        lod.level(0.0001).coords.len(),
+        lod.levels()[0].1.coords.len()
+    );
+}
@@ -0,0 +1,94 @@
+use na::{Isometry3, Point3, Unit, UnitQuaternion, Vector3};
+use ncollide3d::partitioning::BVH;
+use ncollide3d::query::visitors::{
+    FrontFacingFacesCollector, FrontFacingRayInterferencesCollector,
+};
+use ncollide3d::query::Ray;
+use ncollide3d::shape::TriMesh;
+
+// A grid of quads where the quads on the left face +z and the quads on the right face -z.
+fn two_sided_grid() -> TriMesh<f32> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+
+    for i in 0..8 {
+        let x = i as f32;
+        let base = points.len();
+        points.push(Point3::new(x, 0.0, 0.0));
+        points.push(Point3::new(x + 1.0, 0.0, 0.0));
+        points.push(Point3::new(x + 1.0, 1.0, 0.0));
+        points.push(Point3::new(x, 1.0, 0.0));
+
+        if i < 4 {
+            indices.push(Point3::new(base, base + 1, base + 2));
+            indices.push(Point3::new(base, base + 2, base + 3));
+        } else {
+            indices.push(Point3::new(base, base + 2, base + 1));
+            indices.push(Point3::new(base, base + 3, base + 2));
+        }
+    }
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn front_facing_faces() {
+    let mesh = two_sided_grid().with_normal_cones();
+    let bvt = mesh.normal_cone_bvt().unwrap();
+
+    let mut faces = Vec::new();
+    let view_dir = Unit::new_normalize(-Vector3::z());
+    bvt.visit(&mut FrontFacingFacesCollector::new(view_dir, &mut faces));
+    faces.sort();
+    assert_eq!(faces, (0..8).collect::<Vec<_>>());
+
+    let mut faces = Vec::new();
+    bvt.visit(&mut FrontFacingFacesCollector::new(-view_dir, &mut faces));
+    faces.sort();
+    assert_eq!(faces, (8..16).collect::<Vec<_>>());
+}
+
+#[test]
+fn front_facing_ray_interferences() {
+    let mesh = two_sided_grid().with_normal_cones();
+    let bvt = mesh.normal_cone_bvt().unwrap();
+
+    // This ray crosses a face pointing toward it.
+    let ray = Ray::new(Point3::new(1.5, 0.5, 1.0), -Vector3::z());
+    let mut faces = Vec::new();
+    bvt.visit(&mut FrontFacingRayInterferencesCollector::new(
+        &ray,
+        f32::MAX,
+        &mut faces,
+    ));
+    assert!(!faces.is_empty());
+    assert!(faces.iter().all(|f| *f < 8));
+
+    // This ray crosses a face pointing away from it.
+    let ray = Ray::new(Point3::new(5.5, 0.5, 1.0), -Vector3::z());
+    let mut faces = Vec::new();
+    bvt.visit(&mut FrontFacingRayInterferencesCollector::new(
+        &ray,
+        f32::MAX,
+        &mut faces,
+    ));
+    assert!(faces.is_empty());
+}
+
+#[test]
+fn normal_cones_follow_transformations() {
+    let mut mesh = two_sided_grid().with_normal_cones();
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f32::consts::PI);
+    mesh.transform_by(&Isometry3::from_parts(na::one(), rot));
+
+    let mut faces = Vec::new();
+    let view_dir = Unit::new_normalize(-Vector3::z());
+    mesh.normal_cone_bvt()
+        .unwrap()
+        .visit(&mut FrontFacingFacesCollector::new(view_dir, &mut faces));
+    faces.sort();
+    assert_eq!(faces, (8..16).collect::<Vec<_>>());
+
+    mesh.clear_normal_cones();
+    assert!(mesh.normal_cone_bvt().is_none());
+}
@@ -0,0 +1,61 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::TriMesh;
+
+fn closed_cube() -> TriMesh<f32> {
+    let mut mesh: TriMesh<f32> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+    mesh.set_closed(true);
+    mesh
+}
+
+#[test]
+fn trimesh_winding_number() {
+    let mesh = closed_cube();
+    assert!((mesh.winding_number(&Point3::origin()) - 1.0).abs() < 1.0e-4);
+    assert!(mesh.winding_number(&Point3::new(0.3, -0.5, 0.9)) > 0.99);
+    assert!(mesh.winding_number(&Point3::new(3.0, 0.0, 0.0)).abs() < 1.0e-4);
+}
+
+#[test]
+fn trimesh_signed_distance() {
+    let mesh = closed_cube();
+    let m = Isometry3::translation(10.0, 0.0, 0.0);
+
+    let inside = Point3::new(10.5, 0.0, 0.0);
+    assert!(mesh.contains_point(&m, &inside));
+    assert!((mesh.distance_to_point(&m, &inside, false) + 0.5).abs() < 1.0e-5);
+    assert_eq!(mesh.distance_to_point(&m, &inside, true), 0.0);
+
+    let proj = mesh.project_point(&m, &inside, false);
+    assert!(proj.is_inside);
+    assert!((proj.point - Point3::new(11.0, 0.0, 0.0)).norm() < 1.0e-5);
+
+    let proj = mesh.project_point(&m, &inside, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, inside);
+
+    let outside = Point3::new(12.0, 0.0, 0.0);
+    assert!(!mesh.contains_point(&m, &outside));
+    assert!((mesh.distance_to_point(&m, &outside, false) - 1.0).abs() < 1.0e-5);
+
+    // Open meshes are hollow.
+    let mut mesh = mesh;
+    mesh.set_closed(false);
+    assert!(!mesh.contains_point(&m, &inside));
+    assert!((mesh.distance_to_point(&m, &inside, false) - 0.5).abs() < 1.0e-5);
+}
+
+#[test]
+fn trimesh_boundary_projection() {
+    let mesh = closed_cube();
+    let m = Isometry3::translation(10.0, 0.0, 0.0);
+
+    let proj = mesh.project_point_on_boundary(&m, &Point3::new(10.2, 0.7, 0.1));
+    assert!(proj.is_inside);
+    assert!((proj.point - Point3::new(10.2, 1.0, 0.1)).norm() < 1.0e-5);
+
+    let proj = mesh.project_point_on_boundary(&m, &Point3::new(10.2, 1.5, 0.1));
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(10.2, 1.0, 0.1)).norm() < 1.0e-5);
+}
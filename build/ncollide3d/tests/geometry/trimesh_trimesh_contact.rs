@@ -0,0 +1,60 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, ContactPrediction};
+use ncollide3d::shape::TriMesh;
+
+#[test]
+fn trimesh_trimesh_contact() {
+    let mesh: TriMesh<f32> = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).into();
+
+    let m1 = Isometry3::identity();
+    // The triangles of both meshes cross each other.
+    let m2 = Isometry3::new(Vector3::new(1.9, 0.0, 0.0), Vector3::new(0.0, 0.3, 0.2));
+    let contact = query::contact(&m1, &mesh, &m2, &mesh, 0.0).expect("Contact not found.");
+    assert!(contact.depth > 0.0);
+    assert!(contact.depth < 0.5);
+
+    // Separated meshes within the prediction distance.
+    let m2 = Isometry3::translation(2.1, 0.0, 0.0);
+    let contact = query::contact(&m1, &mesh, &m2, &mesh, 0.5).expect("Contact not found.");
+    assert!((contact.depth + 0.1).abs() < 1.0e-4);
+    assert!((contact.normal.into_inner() - Vector3::x()).norm() < 1.0e-4);
+
+    assert!(query::contact(&m1, &mesh, &m2, &mesh, 0.05).is_none());
+}
+
+#[test]
+fn trimesh_trimesh_manifold() {
+    // A kinematic platform slightly sinking into a flat terrain.
+    let platform: TriMesh<f64> = procedural::cuboid(&Vector3::new(1.0, 1.0, 1.0)).into();
+    let terrain: TriMesh<f64> = procedural::quad(4.0, 4.0, 4, 4).into();
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let mut algorithm = dispatcher
+        .get_contact_algorithm(&terrain, &platform)
+        .expect("No mesh-mesh contact algorithm.");
+
+    let m1 = Isometry3::rotation(Vector3::x() * -std::f64::consts::FRAC_PI_2);
+    let m2 = Isometry3::translation(0.3, 0.45, 0.2);
+    let mut manifold = algorithm.init_manifold();
+    let _ = algorithm.generate_contacts(
+        &dispatcher,
+        &m1,
+        &terrain,
+        None,
+        &m2,
+        &platform,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    // The contacts are clustered on the face of the platform touching the terrain.
+    assert!(manifold.len() > 0);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(*c.contact.normal, Vector3::y(), epsilon = 1.0e-6);
+        assert_relative_eq!(c.contact.depth, 0.05, epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,140 @@
+use na::{Isometry3, Matrix3, Point3, Unit, UnitQuaternion, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, ShapeHandle, SupportMap, TriMesh,
+    Volumetric,
+};
+
+fn hull_of(shape: &dyn SupportMap<f64>) -> ConvexHull<f64> {
+    let mut points = Vec::new();
+    let nsubdivs = 60;
+
+    for i in 0..=nsubdivs {
+        let phi = std::f64::consts::PI * (i as f64) / (nsubdivs as f64);
+        for j in 0..nsubdivs {
+            let theta = 2.0 * std::f64::consts::PI * (j as f64) / (nsubdivs as f64);
+            let dir = Vector3::new(phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin());
+            points.push(shape.local_support_point(&dir));
+        }
+    }
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+fn assert_approx_shape(shape: &dyn Volumetric<f64>, approx: &dyn Volumetric<f64>, eps: f64) {
+    assert_relative_eq!(shape.volume(), approx.volume(), max_relative = eps);
+    assert_relative_eq!(
+        shape.center_of_mass(),
+        approx.center_of_mass(),
+        epsilon = eps
+    );
+    assert_relative_eq!(
+        shape.unit_angular_inertia(),
+        approx.unit_angular_inertia(),
+        epsilon = eps
+    );
+}
+
+#[test]
+fn trimesh_and_convex_hull_match_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let shift = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), na::zero());
+    let mut mesh = procedural::cuboid(&Vector3::new(2.0, 4.0, 6.0));
+    mesh.transform_by(&shift);
+    let trimesh: TriMesh<f64> = mesh.clone().into();
+    let hull = ConvexHull::try_from_points(&mesh.coords).unwrap();
+
+    for shape in [&trimesh as &dyn Volumetric<f64>, &hull] {
+        assert_relative_eq!(shape.volume(), 48.0, epsilon = 1.0e-7);
+        assert_relative_eq!(
+            shape.center_of_mass(),
+            Point3::new(1.0, -2.0, 3.0),
+            epsilon = 1.0e-7
+        );
+        assert_relative_eq!(
+            shape.unit_angular_inertia(),
+            cuboid.unit_angular_inertia(),
+            epsilon = 1.0e-7
+        );
+    }
+
+    // Flipping the faces orientation does not change the result.
+    let mut flipped = mesh;
+    flipped.unify_index_buffer();
+    if let procedural::IndexBuffer::Unified(ref mut idx) = flipped.indices {
+        for i in idx.iter_mut() {
+            i.coords.swap_rows(1, 2);
+        }
+    }
+    let flipped: TriMesh<f64> = flipped.into();
+    assert_relative_eq!(flipped.volume(), 48.0, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        flipped.unit_angular_inertia(),
+        cuboid.unit_angular_inertia(),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn support_map_shapes_match_their_polyhedral_approximation() {
+    let ball = Ball::new(1.5f64);
+    assert_approx_shape(&ball, &hull_of(&ball), 1.0e-2);
+
+    let cylinder = Cylinder::new(1.0f64, 0.5);
+    assert_approx_shape(&cylinder, &hull_of(&cylinder), 1.0e-2);
+
+    let cone = Cone::new(1.0f64, 0.5);
+    assert_approx_shape(&cone, &hull_of(&cone), 1.0e-2);
+
+    let capsule = Capsule::new(1.0f64, 0.5);
+    assert_approx_shape(&capsule, &hull_of(&capsule), 1.0e-2);
+
+    // Shapes that are not aligned with the `y` axis.
+    let axis = Unit::new_normalize(Vector3::new(1.0, 0.5, -0.3));
+    let cylinder = Cylinder::new(1.0f64, 0.5).with_axis(axis);
+    assert_approx_shape(&cylinder, &hull_of(&cylinder), 1.0e-2);
+
+    let cone = Cone::new(1.0f64, 0.5).with_axis(axis);
+    assert_approx_shape(&cone, &hull_of(&cone), 1.0e-2);
+
+    let capsule =
+        Capsule::new_with_endpoints(Point3::new(1.0, 0.0, 0.5), Point3::new(-0.5, 1.0, 0.0), 0.5);
+    assert_approx_shape(&capsule, &hull_of(&capsule), 1.0e-2);
+}
+
+#[test]
+fn compound_mass_properties() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 1.0, 2.0));
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.7);
+    let pos = Isometry3::from_parts(Vector3::new(1.0, 2.0, 3.0).into(), rot);
+    let compound = Compound::new(vec![(pos, ShapeHandle::new(cuboid))]);
+
+    let (mass, com, inertia) = compound.mass_properties(3.0);
+    let rot = rot.to_rotation_matrix();
+    let expected = rot * cuboid.unit_angular_inertia() * rot.inverse() * mass;
+    assert_relative_eq!(mass, cuboid.mass(3.0), epsilon = 1.0e-7);
+    assert_relative_eq!(com, Point3::new(1.0, 2.0, 3.0), epsilon = 1.0e-7);
+    assert_relative_eq!(inertia, expected, epsilon = 1.0e-7);
+
+    // Two balls away from the center of mass (parallel axis theorem).
+    let ball = ShapeHandle::new(Ball::new(1.0f64));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(-2.0, 0.0, 0.0), ball.clone()),
+        (Isometry3::translation(2.0, 0.0, 0.0), ball),
+    ]);
+    let ball_inertia = 2.0 / 5.0;
+    assert_relative_eq!(
+        compound.center_of_mass(),
+        Point3::origin(),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        compound.unit_angular_inertia(),
+        Matrix3::from_diagonal(&Vector3::new(
+            ball_inertia,
+            ball_inertia + 4.0,
+            ball_inertia + 4.0
+        )),
+        epsilon = 1.0e-7
+    );
+}
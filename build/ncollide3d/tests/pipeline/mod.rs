@@ -1,3 +1,5 @@
 mod contact_pairs;
 mod duplicate_trimesh_on_world;
 mod is_send_sync;
+mod plane_contacts;
+mod shape_snapshot;
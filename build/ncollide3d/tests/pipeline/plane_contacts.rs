@@ -0,0 +1,84 @@
+use na::{Isometry3, Vector3};
+use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::query::ContactManifold;
+use ncollide3d::shape::{Cuboid, FeatureId, HalfSpace, Plane, ShapeHandle, Superellipsoid};
+
+fn manifold_against_ground(
+    shape: ShapeHandle<f64>,
+    pos: Isometry3<f64>,
+    ground_first: bool,
+) -> (ContactManifold<f64>, bool) {
+    let mut world = CollisionWorld::new(0.01);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ground = ShapeHandle::new(HalfSpace::new(Vector3::y_axis()));
+
+    let (ground, shape) = if ground_first {
+        let ground = world
+            .add(Isometry3::identity(), ground, groups, query, ())
+            .0;
+        (ground, world.add(pos, shape, groups, query, ()).0)
+    } else {
+        let shape = world.add(pos, shape, groups, query, ()).0;
+        (
+            world
+                .add(Isometry3::identity(), ground, groups, query, ())
+                .0,
+            shape,
+        )
+    };
+
+    world.update();
+    let (h1, _, _, manifold) = world.contact_pair(ground, shape, true).unwrap();
+
+    // The manifold is expressed with the objects in the order of the returned handles.
+    (manifold.clone(), h1 == ground)
+}
+
+#[test]
+fn resting_cuboid_projects_its_supporting_face() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 0.5, 2.0)));
+    let pos = Isometry3::translation(0.0, 0.49, 0.0);
+
+    for ground_first in &[true, false] {
+        let (manifold, ground_is_first) =
+            manifold_against_ground(cuboid.clone(), pos, *ground_first);
+        assert_eq!(manifold.len(), 4);
+
+        let mut vertices = Vec::new();
+
+        for c in manifold.contacts() {
+            let (plane_feature, cuboid_feature) = if ground_is_first {
+                (c.kinematic.feature1(), c.kinematic.feature2())
+            } else {
+                (c.kinematic.feature2(), c.kinematic.feature1())
+            };
+
+            assert_eq!(plane_feature, Plane::<f64>::FACE_ID);
+            assert!(matches!(cuboid_feature, FeatureId::Vertex(_)));
+            assert!(!vertices.contains(&cuboid_feature));
+            vertices.push(cuboid_feature);
+            assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-9);
+        }
+    }
+}
+
+#[test]
+fn curved_support_map_touches_the_ground() {
+    let shape = ShapeHandle::new(Superellipsoid::new(Vector3::new(1.0, 0.5, 1.0), 0.5, 0.5));
+    let pos = Isometry3::translation(0.0, 0.4, 0.0);
+    let (manifold, ground_is_first) = manifold_against_ground(shape, pos, true);
+
+    assert_eq!(manifold.len(), 1);
+    let c = manifold.deepest_contact().unwrap();
+    let (plane_feature, shape_feature, normal) = if ground_is_first {
+        (c.kinematic.feature1(), c.kinematic.feature2(), Vector3::y())
+    } else {
+        (c.kinematic.feature2(), c.kinematic.feature1(), -Vector3::y())
+    };
+    assert_eq!(plane_feature, Plane::<f64>::FACE_ID);
+    // The deepest point of a curved shape is not one of its faces.
+    assert_eq!(shape_feature, FeatureId::Unknown);
+    assert_relative_eq!(c.contact.depth, 0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(c.contact.normal.into_inner(), normal, epsilon = 1.0e-9);
+}
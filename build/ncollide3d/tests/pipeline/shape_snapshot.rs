@@ -0,0 +1,118 @@
+use na::{DMatrix, Isometry3, Unit, Vector3};
+use ncollide3d::pipeline::snapshot;
+use ncollide3d::procedural;
+use ncollide3d::shape::{
+    Ball, Cone, ConvexHull, Cylinder, HeightField, HeightFieldCellStatus, Oriented, PointCloud,
+    Shape, Superellipsoid, TriMesh,
+};
+
+fn round_trip(shape: &dyn Shape<f64>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    snapshot::write_shape(shape, &mut bytes).unwrap();
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    assert_eq!(shape.aabb(&m), loaded.aabb(&m));
+
+    // Writing the loaded shape gives the same bytes.
+    let mut bytes2 = Vec::new();
+    snapshot::write_shape(loaded.as_ref(), &mut bytes2).unwrap();
+    assert_eq!(bytes, bytes2);
+    bytes
+}
+
+#[test]
+fn convex_hull_snapshot() {
+    let points = procedural::sphere(2.0, 8, 6, false).coords;
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let _ = round_trip(&hull);
+}
+
+#[test]
+fn point_cloud_snapshot() {
+    let points = procedural::sphere(2.0, 8, 6, false).coords;
+    let _ = round_trip(&PointCloud::new(points));
+}
+
+#[test]
+fn trimesh_snapshot() {
+    let mesh: TriMesh<f64> = procedural::cuboid(&Vector3::new(1.0, 2.0, 3.0)).into();
+    let _ = round_trip(&mesh.clone().with_normal_cones());
+
+    let thick = mesh.clone().with_thickness(0.1);
+    let bytes = round_trip(&thick);
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    assert_eq!(loaded.as_shape::<TriMesh<f64>>().unwrap().thickness(), 0.1);
+
+    let mut closed = mesh.clone();
+    closed.set_closed(true);
+    let bytes = round_trip(&closed);
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    assert!(loaded.as_shape::<TriMesh<f64>>().unwrap().closed());
+
+    let nfaces = mesh.faces().len() as u32;
+    let with_data = mesh.with_face_data((0..nfaces).collect());
+    let bytes = round_trip(&with_data);
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    let loaded = loaded.as_shape::<TriMesh<f64>>().unwrap();
+    assert_eq!(loaded.face_data(), with_data.face_data());
+}
+
+#[test]
+fn heightfield_snapshot() {
+    let heights = DMatrix::from_fn(3, 4, |i, j| (i * j) as f64 * 0.1);
+    let mut heightfield = HeightField::new(heights, Vector3::new(4.0, 1.0, 2.0));
+    heightfield.set_cell_status(1, 2, HeightFieldCellStatus::CELL_REMOVED);
+    heightfield.set_cell_status(0, 0, HeightFieldCellStatus::ZIGZAG_SUBDIVISION);
+
+    let bytes = round_trip(&heightfield);
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    let loaded = loaded.as_shape::<HeightField<f64>>().unwrap();
+    assert_eq!(loaded.cells_statuses(), heightfield.cells_statuses());
+}
+
+#[test]
+fn superellipsoid_snapshot() {
+    let shape = Superellipsoid::new(Vector3::new(1.0, 2.0, 0.5), 0.3, 0.7);
+    let _ = round_trip(&shape);
+
+    // The tag of a truncated shape is not enough.
+    match snapshot::read_shape::<f64>(&mut &[13u8][..]) {
+        Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof),
+        Ok(_) => panic!("The truncated shape should not be read."),
+    }
+}
+
+#[test]
+fn cylinder_and_cone_snapshot() {
+    let _ = round_trip(&Cylinder::new(1.5, 0.5));
+    let _ = round_trip(&Cone::new(0.5, 2.0));
+
+    // A cylinder with a negative radius is rejected instead of panicking.
+    let mut bytes = Vec::new();
+    snapshot::write_shape(&Cylinder::new(1.5, 0.5), &mut bytes).unwrap();
+    bytes[9..].copy_from_slice(&(-0.5f64).to_le_bytes());
+
+    match snapshot::read_shape::<f64>(&mut &bytes[..]) {
+        Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::InvalidData),
+        Ok(_) => panic!("The invalid cylinder should not be read."),
+    }
+}
+
+#[test]
+fn oriented_snapshot() {
+    let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, -0.5));
+    let cylinder = Cylinder::new(1.5, 0.5).with_axis(axis);
+    let bytes = round_trip(&cylinder);
+
+    let loaded = snapshot::read_shape::<f64>(&mut &bytes[..]).unwrap();
+    let loaded = loaded.as_shape::<Oriented<f64, Cylinder<f64>>>().unwrap();
+    assert_eq!(*loaded, cylinder);
+
+    let _ = round_trip(&Cone::new(0.5, 2.0).with_axis(axis));
+
+    // Only cylinders and cones can be oriented.
+    let oriented_ball = Oriented::new(Ball::new(1.0), cylinder.rotation);
+    let err = snapshot::write_shape::<f64>(&oriented_ball, &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -129,11 +129,26 @@ impl<N: RealField + Copy> AABB<N> {
     #[inline]
     pub fn bounding_sphere(&self) -> BoundingSphere<N> {
         let center = self.center();
-        let rad = na::distance(&self.mins, &self.maxs);
+        let rad = na::distance(&self.mins, &self.maxs) * na::convert(0.5);
 
         BoundingSphere::new(center, rad)
     }
 
+    /// Checks if this AABB intersects the given bounding sphere.
+    #[inline]
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere<N>) -> bool {
+        let center = sphere.center();
+        let closest = center.coords.sup(&self.mins.coords).inf(&self.maxs.coords);
+
+        (center.coords - closest).norm_squared() <= sphere.radius() * sphere.radius()
+    }
+
+    /// Checks if this AABB contains the given bounding sphere.
+    #[inline]
+    pub fn contains_sphere(&self, sphere: &BoundingSphere<N>) -> bool {
+        self.contains(&sphere.aabb())
+    }
+
     #[inline]
     pub fn contains_local_point(&self, point: &Point<N>) -> bool {
         for i in 0..DIM {
@@ -5,6 +5,10 @@ use crate::utils::IsometryOps;
 use na::RealField;
 
 impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Cuboid<N> {
+    /// The tightest AABB of the cuboid transformed by `m`.
+    ///
+    /// The half-extents of the result are the sum of the absolute values of the rotated
+    /// half-extents, so the AABB touches the cuboid even when it is rotated.
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
         let center = Point::from(m.translation.vector);
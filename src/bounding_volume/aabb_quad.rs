@@ -0,0 +1,23 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::Quad;
+use crate::utils::IsometryOps;
+use na::RealField;
+
+impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Quad<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        let center = Point::from(m.translation.vector);
+        let half_extents = Vector::new(self.half_extents.x, N::zero(), self.half_extents.y);
+        let ws_half_extents = m.absolute_transform_vector(&half_extents);
+
+        AABB::from_half_extents(center, ws_half_extents)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let half_extents = Point::new(self.half_extents.x, N::zero(), self.half_extents.y);
+
+        AABB::new(-half_extents, half_extents)
+    }
+}
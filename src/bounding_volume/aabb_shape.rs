@@ -1,6 +1,6 @@
 use crate::bounding_volume::{HasBoundingVolume, AABB};
 use crate::math::Isometry;
-use crate::shape::Shape;
+use crate::shape::{Shape, ShapeHandle};
 use na::RealField;
 
 impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for dyn Shape<N> {
@@ -14,3 +14,15 @@ impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for dyn Shape<N> {
         self.local_aabb()
     }
 }
+
+impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for ShapeHandle<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        self.aabb(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        self.local_aabb()
+    }
+}
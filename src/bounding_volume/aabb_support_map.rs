@@ -1,9 +1,10 @@
 use crate::bounding_volume;
 use crate::bounding_volume::{HasBoundingVolume, AABB};
-use crate::math::Isometry;
-use crate::shape::{Capsule, Segment};
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::{Capsule, RoundedCuboid, Segment, SupportMap, Swept};
+use crate::utils::IsometryOps;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder};
+use crate::shape::{Cone, Cylinder, Oriented, Superellipsoid};
 use na::RealField;
 
 #[cfg(feature = "dim3")]
@@ -32,6 +33,32 @@ impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Cylinder<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy, G: SupportMap<N>> HasBoundingVolume<N, AABB<N>> for Oriented<N, G> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_support_map_aabb(self)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Superellipsoid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        AABB::from_half_extents(Point::origin(), self.radii)
+    }
+}
+
 impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Capsule<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
@@ -44,10 +71,38 @@ impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Capsule<N> {
     }
 }
 
+impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for RoundedCuboid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        let center = Point::from(m.translation.vector);
+        let ws_half_extents =
+            m.absolute_transform_vector(&self.half_extents) + Vector::repeat(self.border_radius);
+
+        AABB::from_half_extents(center, ws_half_extents)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        let half_extents = self.half_extents + Vector::repeat(self.border_radius);
+        AABB::from_half_extents(Point::origin(), half_extents)
+    }
+}
+
 impl<N: RealField + Copy> HasBoundingVolume<N, AABB<N>> for Segment<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
-        // SPEED: optimize this
+        bounding_volume::point_cloud_aabb(m, &[self.a, self.b])
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::local_point_cloud_aabb(&[self.a, self.b])
+    }
+}
+
+impl<N: RealField + Copy, G: SupportMap<N>> HasBoundingVolume<N, AABB<N>> for Swept<N, G> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
         bounding_volume::support_map_aabb(m, self)
     }
 
@@ -1,11 +1,14 @@
 use std::iter::IntoIterator;
 
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::shape::SupportMap;
 use na::{self, RealField};
 
 /// Computes the AABB of an support mapped shape.
+///
+/// The result is the tightest AABB enclosing the shape transformed by `m`: each of its faces
+/// touches the shape at the support point in the direction of the face normal.
 pub fn support_map_aabb<N, G>(m: &Isometry<N>, i: &G) -> AABB<N>
 where
     N: RealField + Copy,
@@ -13,18 +16,14 @@ where
 {
     let mut min = na::zero::<Vector<N>>();
     let mut max = na::zero::<Vector<N>>();
-    let mut basis = na::zero::<Vector<N>>();
+    let rot = m.rotation.to_rotation_matrix();
 
     for d in 0..DIM {
-        // FIXME: this could be further improved iterating on `m`'s columns, and passing
-        // Id as the transformation matrix.
-        basis[d] = na::one();
-        max[d] = i.support_point(m, &basis)[d];
-
-        basis[d] = -na::one::<N>();
-        min[d] = i.support_point(m, &basis)[d];
+        // The world-space axis `d` expressed in the local-space of the shape.
+        let axis: Vector<N> = rot.matrix().row(d).transpose();
 
-        basis[d] = na::zero();
+        max[d] = m.transform_point(&i.local_support_point(&axis))[d];
+        min[d] = m.transform_point(&i.local_support_point(&-axis))[d];
     }
 
     AABB::new(Point::from(min), Point::from(max))
@@ -41,8 +40,6 @@ where
     let mut basis = na::zero::<Vector<N>>();
 
     for d in 0..DIM {
-        // FIXME: this could be further improved iterating on `m`'s columns, and passing
-        // Id as the transformation matrix.
         basis[d] = na::one();
         max[d] = i.local_support_point(&basis)[d];
 
@@ -98,3 +95,62 @@ where
 
     AABB::new(min, max)
 }
+
+/// Computes an AABB bounding the shape `g` during its motion from `m_start` to `m_end`.
+///
+/// The motion is the interpolation between `m_start` and `m_end` using LERP for the translation
+/// part and SLERP for the rotation part, i.e., the motion described by
+/// `interpolation::InterpolatedRigidMotion`. The result is conservative: unlike the union of
+/// the AABBs at `m_start` and `m_end`, it accounts for the parts of the shape that rotate outside
+/// of those two AABBs mid-motion.
+pub fn aabb_swept<N, G: ?Sized>(g: &G, m_start: &Isometry<N>, m_end: &Isometry<N>) -> AABB<N>
+where
+    N: RealField + Copy,
+    G: HasBoundingVolume<N, AABB<N>>,
+{
+    let local_aabb = g.local_bounding_volume();
+    let radius = local_aabb
+        .mins
+        .coords
+        .abs()
+        .sup(&local_aabb.maxs.coords.abs())
+        .norm();
+    let (nsteps, deviation) = swept_subdivision(m_start, m_end);
+    let margin = radius * deviation;
+
+    let mut res = g.bounding_volume(m_start);
+    let mut prev = res;
+
+    for i in 1..=nsteps {
+        let t = na::convert::<_, N>(i as f64) / na::convert(nsteps as f64);
+        let curr = g.bounding_volume(&m_start.lerp_slerp(m_end, t));
+        res.merge(&prev.merged(&curr).loosened(margin));
+        prev = curr;
+    }
+
+    res
+}
+
+/// Subdivides the lerp-slerp interpolation between two isometries into steps of small rotations.
+///
+/// Returns the number of steps and a factor such that, on each step, any point at a distance
+/// `d` from the local origin deviates by at most `factor * d` from the linear interpolation of its
+/// positions at the beginning and the end of the step.
+pub(crate) fn swept_subdivision<N: RealField + Copy>(
+    m_start: &Isometry<N>,
+    m_end: &Isometry<N>,
+) -> (usize, N) {
+    let angle = (m_start.rotation.inverse() * m_end.rotation).angle().abs();
+    let max_step_angle = N::frac_pi_4();
+    let nsteps = (angle / max_step_angle)
+        .ceil()
+        .to_subset()
+        .unwrap_or(1.0)
+        .max(1.0) as usize;
+    let step_angle = angle / na::convert(nsteps as f64);
+
+    // The deviation of a curve from its linear interpolation is bounded by
+    // 1/8 of the maximum norm of its second derivative. For a point rotating
+    // around the origin at a constant angular speed, this is `d * step_angle²`.
+    (nsteps, step_angle * step_angle / na::convert(8.0))
+}
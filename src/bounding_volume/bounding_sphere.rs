@@ -1,7 +1,7 @@
 //! Bounding sphere.
 
-use crate::bounding_volume::{BoundingVolume, HasBoundingVolume};
-use crate::math::{Isometry, Point};
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector};
 use na::{self, RealField};
 
 // Seems useful to help type inference. See issue #84.
@@ -59,6 +59,25 @@ impl<N: RealField + Copy> BoundingSphere<N> {
     pub fn transform_by(&self, m: &Isometry<N>) -> BoundingSphere<N> {
         BoundingSphere::new(m * self.center, self.radius)
     }
+
+    /// The smallest AABB containing this bounding sphere.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        AABB::from_half_extents(self.center, Vector::repeat(self.radius))
+    }
+
+    /// Checks if this bounding sphere intersects the given AABB.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &AABB<N>) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    /// Checks if this bounding sphere contains the given AABB.
+    #[inline]
+    pub fn contains_aabb(&self, aabb: &AABB<N>) -> bool {
+        let farthest = (self.center - aabb.center()).abs() + aabb.half_extents();
+        farthest.norm_squared() <= self.radius * self.radius
+    }
 }
 
 impl<N: RealField + Copy> BoundingVolume<N> for BoundingSphere<N> {
@@ -13,7 +13,10 @@ impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for Capsule<N>
     #[inline]
     fn local_bounding_volume(&self) -> BoundingSphere<N> {
         let radius = self.radius + self.half_height;
+        let center = self
+            .local_frame()
+            .map_or_else(Point::origin, |frame| Point::from(frame.translation.vector));
 
-        BoundingSphere::new(Point::origin(), radius)
+        BoundingSphere::new(center, radius)
     }
 }
@@ -13,8 +13,6 @@ impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for ConvexHull
 
     #[inline]
     fn local_bounding_volume(&self) -> BoundingSphere<N> {
-        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(self.points());
-
-        BoundingSphere::new(center, radius)
+        bounding_volume::bounding_sphere_exact(self.points())
     }
 }
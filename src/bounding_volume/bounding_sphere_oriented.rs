@@ -0,0 +1,21 @@
+use simba::scalar::RealField;
+
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::Oriented;
+
+impl<N, G> HasBoundingVolume<N, BoundingSphere<N>> for Oriented<N, G>
+where
+    N: RealField + Copy,
+    G: HasBoundingVolume<N, BoundingSphere<N>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.shape.bounding_volume(&(m * self.local_isometry()))
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.shape.bounding_volume(&self.local_isometry())
+    }
+}
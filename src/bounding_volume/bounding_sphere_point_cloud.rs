@@ -0,0 +1,17 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::PointCloud;
+use na::RealField;
+
+impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for PointCloud<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        *self.bounding_sphere()
+    }
+}
@@ -1,17 +1,18 @@
 use na::RealField;
 
-use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
 use crate::math::Isometry;
 use crate::shape::Polyline;
 
 impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for Polyline<N> {
     #[inline]
     fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
-        self.aabb().bounding_sphere().transform_by(m)
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
     }
 
     #[inline]
     fn local_bounding_volume(&self) -> BoundingSphere<N> {
-        self.aabb().bounding_sphere()
+        self.cached_bounding_sphere().loosened(self.thickness())
     }
 }
@@ -1,6 +1,6 @@
 use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
 use crate::math::Isometry;
-use crate::shape::Shape;
+use crate::shape::{Shape, ShapeHandle};
 use na::RealField;
 
 impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for dyn Shape<N> {
@@ -14,3 +14,15 @@ impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for dyn Shape<
         self.local_bounding_sphere()
     }
 }
+
+impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for ShapeHandle<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.bounding_sphere(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.local_bounding_sphere()
+    }
+}
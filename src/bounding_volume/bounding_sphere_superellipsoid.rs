@@ -0,0 +1,19 @@
+use simba::scalar::RealField;
+
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::{Isometry, Point};
+use crate::shape::Superellipsoid;
+
+impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for Superellipsoid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        // The corners of the local AABB are approached as the exponents go to zero.
+        BoundingSphere::new(Point::origin(), self.radii.norm())
+    }
+}
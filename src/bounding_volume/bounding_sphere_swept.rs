@@ -0,0 +1,25 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::Swept;
+use na::{self, RealField};
+
+impl<N: RealField + Copy, G: HasBoundingVolume<N, BoundingSphere<N>>>
+    HasBoundingVolume<N, BoundingSphere<N>> for Swept<N, G>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.shape.local_bounding_volume();
+        let half_displacement = self.displacement * na::convert::<_, N>(0.5);
+
+        BoundingSphere::new(
+            bv.center() + half_displacement,
+            bv.radius() + half_displacement.norm(),
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::TileGrid;
+use na::RealField;
+
+impl<N: RealField + Copy> HasBoundingVolume<N, BoundingSphere<N>> for TileGrid<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.aabb().bounding_sphere().transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.aabb().bounding_sphere()
+    }
+}
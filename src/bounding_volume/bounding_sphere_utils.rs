@@ -1,4 +1,6 @@
-use crate::math::Point;
+use crate::bounding_volume::aabb_utils;
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::{Isometry, Point, Vector, DIM};
 use crate::utils;
 use na::{self, RealField};
 
@@ -28,3 +30,215 @@ pub fn point_cloud_bounding_sphere_with_center<N: RealField + Copy>(
 pub fn point_cloud_bounding_sphere<N: RealField + Copy>(pts: &[Point<N>]) -> (Point<N>, N) {
     point_cloud_bounding_sphere_with_center(pts, utils::center(pts))
 }
+
+/// Computes the smallest sphere enclosing the specified set of points.
+///
+/// This uses the move-to-front variant of Welzl's algorithm. The points are first shuffled
+/// with a fixed seed so the result does not depend on any randomness, and the running time is
+/// expected linear whatever the order of the input points. Unlike `point_cloud_bounding_sphere`, which centers the sphere at the barycenter of the
+/// points, the result is the exact minimal enclosing sphere (up to rounding errors). The input
+/// must not be empty.
+pub fn bounding_sphere_exact<N: RealField + Copy>(pts: &[Point<N>]) -> BoundingSphere<N> {
+    assert!(
+        !pts.is_empty(),
+        "Cannot compute the bounding sphere of an empty point cloud."
+    );
+
+    let mut pts = pts.to_vec();
+    shuffle(&mut pts);
+    let mut support = Vec::with_capacity(DIM + 1);
+    let len = pts.len();
+    let (center, radius) = welzl_move_to_front(&mut pts, len, &mut support);
+
+    BoundingSphere::new(center, radius)
+}
+
+/// The smallest sphere enclosing `pts`, or an empty sphere at the origin if there is no point.
+pub(crate) fn bounding_sphere_exact_or_empty<N: RealField + Copy>(
+    pts: &[Point<N>],
+) -> BoundingSphere<N> {
+    if pts.is_empty() {
+        BoundingSphere::new(Point::origin(), N::zero())
+    } else {
+        bounding_sphere_exact(pts)
+    }
+}
+
+// Fisher-Yates shuffle driven by a xorshift generator with a fixed seed.
+fn shuffle<T>(elts: &mut [T]) {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+
+    for i in (1..elts.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        elts.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+fn welzl_move_to_front<N: RealField + Copy>(
+    pts: &mut [Point<N>],
+    end: usize,
+    support: &mut Vec<Point<N>>,
+) -> (Point<N>, N) {
+    let mut sphere = sphere_through_points(support);
+
+    if support.len() == DIM + 1 {
+        return sphere;
+    }
+
+    for i in 0..end {
+        if !sphere_contains(&sphere, &pts[i]) {
+            support.push(pts[i]);
+            sphere = welzl_move_to_front(pts, i, support);
+            let _ = support.pop();
+            pts[..=i].rotate_right(1);
+        }
+    }
+
+    sphere
+}
+
+fn sphere_contains<N: RealField + Copy>(sphere: &(Point<N>, N), pt: &Point<N>) -> bool {
+    let eps = N::default_epsilon().sqrt();
+    na::distance(&sphere.0, pt) <= sphere.1 + eps * (N::one() + sphere.1)
+}
+
+/// The smallest sphere having all the given points (at most `DIM + 1`) on its boundary.
+fn sphere_through_points<N: RealField + Copy>(pts: &[Point<N>]) -> (Point<N>, N) {
+    if pts.is_empty() {
+        return (Point::origin(), -N::one());
+    }
+
+    // The center is `pts[0] + sum(lambda_i * v_i)` with `v_i = pts[i] - pts[0]`. Being
+    // equidistant to all points yields the linear system `2 * (v_i . v_j) lambda_j = |v_i|²`.
+    let n = pts.len() - 1;
+    let mut mat = [[N::zero(); DIM + 1]; DIM];
+    let mut v = [Vector::zeros(); DIM];
+
+    for i in 0..n {
+        v[i] = pts[i + 1] - pts[0];
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            mat[i][j] = v[i].dot(&v[j]) * na::convert(2.0);
+        }
+        mat[i][n] = v[i].norm_squared();
+    }
+
+    match solve_in_place(&mut mat, n) {
+        Some(lambda) => {
+            let mut center = pts[0];
+
+            for i in 0..n {
+                center += v[i] * lambda[i];
+            }
+
+            (center, na::distance(&center, &pts[0]))
+        }
+        None => {
+            // Degenerate configuration: fall back to the sphere spanned by the farthest pair.
+            let mut best = (pts[0], N::zero());
+
+            for i in 0..pts.len() {
+                for j in i + 1..pts.len() {
+                    let radius = na::distance(&pts[i], &pts[j]) * na::convert(0.5);
+
+                    if radius > best.1 {
+                        best = (na::center(&pts[i], &pts[j]), radius);
+                    }
+                }
+            }
+
+            best
+        }
+    }
+}
+
+/// Solves the `n x n` system stored in the first `n` rows of `mat`, with the right-hand side on
+/// the column `n`, using Gaussian elimination with partial pivoting.
+fn solve_in_place<N: RealField + Copy>(
+    mat: &mut [[N; DIM + 1]; DIM],
+    n: usize,
+) -> Option<[N; DIM]> {
+    let mut scale = N::zero();
+
+    for i in 0..n {
+        scale = scale.max(mat[i][i].abs());
+    }
+
+    let eps = N::default_epsilon().sqrt() * scale;
+
+    for k in 0..n {
+        let mut pivot = k;
+
+        for i in k + 1..n {
+            if mat[i][k].abs() > mat[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+
+        if mat[pivot][k].abs() <= eps {
+            return None;
+        }
+
+        mat.swap(k, pivot);
+
+        for i in k + 1..n {
+            let factor = mat[i][k] / mat[k][k];
+
+            for j in k..=n {
+                let sub = mat[k][j] * factor;
+                mat[i][j] -= sub;
+            }
+        }
+    }
+
+    let mut res = [N::zero(); DIM];
+
+    for k in (0..n).rev() {
+        let mut acc = mat[k][n];
+
+        for j in k + 1..n {
+            acc -= mat[k][j] * res[j];
+        }
+
+        res[k] = acc / mat[k][k];
+    }
+
+    Some(res)
+}
+
+/// Computes a bounding sphere bounding the shape `g` during its motion from `m_start` to `m_end`.
+///
+/// The motion is the interpolation between `m_start` and `m_end` using LERP for the translation
+/// part and SLERP for the rotation part, i.e., the motion described by
+/// `interpolation::InterpolatedRigidMotion`. The result is conservative even if the rotation
+/// moves the center of the bounding sphere of `g` away from the segment joining its initial and
+/// final positions.
+pub fn bounding_sphere_swept<N, G: ?Sized>(
+    g: &G,
+    m_start: &Isometry<N>,
+    m_end: &Isometry<N>,
+) -> BoundingSphere<N>
+where
+    N: RealField + Copy,
+    G: HasBoundingVolume<N, BoundingSphere<N>>,
+{
+    let local_sphere = g.local_bounding_volume();
+    let (nsteps, deviation) = aabb_utils::swept_subdivision(m_start, m_end);
+    let margin = local_sphere.center().coords.norm() * deviation;
+
+    let mut res = g.bounding_volume(m_start);
+    let mut prev = res;
+
+    for i in 1..=nsteps {
+        let t = na::convert::<_, N>(i as f64) / na::convert(nsteps as f64);
+        let curr = g.bounding_volume(&m_start.lerp_slerp(m_end, t));
+        res.merge(&prev.merged(&curr).loosened(margin));
+        prev = curr;
+    }
+
+    res
+}
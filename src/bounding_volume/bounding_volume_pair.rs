@@ -0,0 +1,63 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, AABB};
+use na::RealField;
+
+/// Intersection and inclusion tests between two bounding volumes of possibly different types.
+///
+/// This lets spatial partitioning structures built with one kind of bounding volume be queried
+/// with another one, e.g., finding the leaves of an AABB tree intersecting a bounding sphere,
+/// without converting the query to a shape first.
+pub trait BoundingVolumePair<N: RealField + Copy, BV> {
+    /// Checks if `self` intersects `other`.
+    fn intersects_bv(&self, other: &BV) -> bool;
+
+    /// Checks if `self` contains `other`.
+    fn contains_bv(&self, other: &BV) -> bool;
+}
+
+impl<N: RealField + Copy> BoundingVolumePair<N, AABB<N>> for AABB<N> {
+    #[inline]
+    fn intersects_bv(&self, other: &AABB<N>) -> bool {
+        self.intersects(other)
+    }
+
+    #[inline]
+    fn contains_bv(&self, other: &AABB<N>) -> bool {
+        self.contains(other)
+    }
+}
+
+impl<N: RealField + Copy> BoundingVolumePair<N, BoundingSphere<N>> for AABB<N> {
+    #[inline]
+    fn intersects_bv(&self, other: &BoundingSphere<N>) -> bool {
+        self.intersects_sphere(other)
+    }
+
+    #[inline]
+    fn contains_bv(&self, other: &BoundingSphere<N>) -> bool {
+        self.contains_sphere(other)
+    }
+}
+
+impl<N: RealField + Copy> BoundingVolumePair<N, AABB<N>> for BoundingSphere<N> {
+    #[inline]
+    fn intersects_bv(&self, other: &AABB<N>) -> bool {
+        self.intersects_aabb(other)
+    }
+
+    #[inline]
+    fn contains_bv(&self, other: &AABB<N>) -> bool {
+        self.contains_aabb(other)
+    }
+}
+
+impl<N: RealField + Copy> BoundingVolumePair<N, BoundingSphere<N>> for BoundingSphere<N> {
+    #[inline]
+    fn intersects_bv(&self, other: &BoundingSphere<N>) -> bool {
+        self.intersects(other)
+    }
+
+    #[inline]
+    fn contains_bv(&self, other: &BoundingSphere<N>) -> bool {
+        self.contains(other)
+    }
+}
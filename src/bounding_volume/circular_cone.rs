@@ -1,4 +1,5 @@
 use crate::math::Vector;
+use crate::utils;
 use na::{self, RealField, Unit};
 
 /// A cone with a circular basis and its apex at the origin.
@@ -22,9 +23,6 @@ pub enum CircularCone<N: RealField + Copy> {
     },
 }
 
-// FIXME: rewrite all those without calls to acos()
-// (by performing tests on the cos themselves instead of the actual angles).
-
 impl<N: RealField + Copy> CircularCone<N> {
     /// Creates a circular cone from a set of vectors.
     pub fn from_vectors(dirs: &[Unit<Vector<N>>]) -> Self {
@@ -57,7 +55,7 @@ impl<N: RealField + Copy> CircularCone<N> {
                 ref mut angle,
             } => {
                 let dot = axis.dot(&dir);
-                let delta_ang = dot.acos();
+                let delta_ang = utils::acos(dot.max(-N::one()).min(N::one()));
 
                 if delta_ang <= *angle {
                     // The current cone already contains dir.
@@ -65,9 +63,13 @@ impl<N: RealField + Copy> CircularCone<N> {
                     let ortho = *dir - **axis * dot;
                     if let Some(basis2) = Unit::try_new(ortho, N::zero()) {
                         let hang = delta_ang * na::convert(0.5);
-                        let (s, c) = hang.sin_cos();
+                        let (s, c) = utils::sin_cos(hang);
                         *axis = Unit::new_unchecked(**axis * c + *basis2 * s);
                         *angle = hang + *angle * na::convert(0.5);
+                    } else if dot < N::zero() {
+                        // dir and axis point to opposite directions. Fall back to the
+                        // full space which is the only cone containing both.
+                        *self = CircularCone::Full;
                     }
                     // Otherwise, dir and axis are collinear so there is nothing more to do.
                 }
@@ -92,8 +94,9 @@ impl<N: RealField + Copy> CircularCone<N> {
                     angle: angle2,
                 },
             ) => {
-                let ang = axis1.dot(&axis2).acos();
-                ang <= *angle1 + *angle2
+                // Compare the cosines to avoid computing the angle between both axes.
+                let spread = *angle1 + *angle2;
+                spread >= N::pi() || axis1.dot(&axis2) >= utils::cos(spread)
             }
         }
     }
@@ -115,8 +118,8 @@ impl<N: RealField + Copy> CircularCone<N> {
                     angle: angle2,
                 },
             ) => {
-                let ang = axis1.dot(&axis2).acos();
-                ang <= *angle1 + *angle2 || (N::pi() - ang) <= *angle1 + *angle2
+                let spread = *angle1 + *angle2;
+                spread >= N::pi() || axis1.dot(&axis2).abs() >= utils::cos(spread)
             }
         }
     }
@@ -138,8 +141,22 @@ impl<N: RealField + Copy> CircularCone<N> {
                     angle: angle2,
                 },
             ) => {
-                let ang = axis1.dot(&axis2).acos();
-                ang + *angle2 <= *angle1
+                let margin = *angle1 - *angle2;
+                margin >= N::zero() && axis1.dot(&axis2) >= utils::cos(margin)
+            }
+        }
+    }
+
+    /// Returns `true` if all the vectors of this cone form an angle of at most `π / 2` with `dir`.
+    ///
+    /// In other words, this tests if this cone is fully contained by the closed half-space
+    /// with an outward normal equal to `-dir`. An empty cone is contained by any half-space.
+    pub fn is_within_half_space(&self, dir: &Unit<Vector<N>>) -> bool {
+        match self {
+            CircularCone::Empty => true,
+            CircularCone::Full => false,
+            CircularCone::Spread { axis, angle } => {
+                *angle <= N::frac_pi_2() && axis.dot(dir) >= utils::sin(*angle)
             }
         }
     }
@@ -167,7 +184,7 @@ impl<N: RealField + Copy> CircularCone<N> {
                 },
             ) => {
                 let dot = axis1.dot(&axis2);
-                let ang = dot.acos();
+                let ang = utils::acos(dot.max(-N::one()).min(N::one()));
                 if ang + *angle1 <= *angle2 {
                     // self is contained in other
                     // so there is nothing to do for the merge.
@@ -180,7 +197,7 @@ impl<N: RealField + Copy> CircularCone<N> {
 
                     if let Some(basis2) = Unit::try_new(ortho, N::zero()) {
                         let partial_sum = (ang + *angle2) * na::convert(0.5);
-                        let (s, c) = partial_sum.sin_cos();
+                        let (s, c) = utils::sin_cos(partial_sum);
                         let new_axis = **axis1 * c + *basis2 * s;
                         CircularCone::Spread {
                             axis: Unit::new_unchecked(new_axis),
@@ -189,9 +206,12 @@ impl<N: RealField + Copy> CircularCone<N> {
                     } else {
                         // This should be unreachable because that means both axii are superimposed so one
                         // of the first `if` statements above should have kicked in already.
-                        // But this might happen due to rounding errors. Just return the
-                        // cone with the largest angle.
-                        if *angle2 > *angle1 {
+                        // But this might happen due to rounding errors, or if both axii point to
+                        // opposite directions. In the latter case, conservatively return the full
+                        // space. Otherwise, just return the cone with the largest angle.
+                        if dot < N::zero() {
+                            CircularCone::Full
+                        } else if *angle2 > *angle1 {
                             *other
                         } else {
                             *self
@@ -6,17 +6,20 @@ pub use self::spatialized_normal_cone::SpatializedNormalCone;
 pub use crate::bounding_volume::aabb::{aabb, local_aabb, AABB};
 pub use crate::bounding_volume::aabb_ball::ball_aabb;
 pub use crate::bounding_volume::aabb_utils::{
-    local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb, support_map_aabb,
+    aabb_swept, local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb, support_map_aabb,
 };
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_sphere::{
     bounding_sphere, local_bounding_sphere, BoundingSphere,
 };
 pub use crate::bounding_volume::bounding_sphere_utils::{
-    point_cloud_bounding_sphere, point_cloud_bounding_sphere_with_center,
+    bounding_sphere_exact, bounding_sphere_swept, point_cloud_bounding_sphere,
+    point_cloud_bounding_sphere_with_center,
 };
+pub(crate) use crate::bounding_volume::bounding_sphere_utils::bounding_sphere_exact_or_empty;
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_volume::{BoundingVolume, HasBoundingVolume};
+pub use crate::bounding_volume::bounding_volume_pair::BoundingVolumePair;
 
 #[doc(hidden)]
 pub mod bounding_volume;
@@ -32,9 +35,14 @@ mod aabb_convex_polygon;
 mod aabb_cuboid;
 mod aabb_heightfield;
 mod aabb_plane;
+mod aabb_point_cloud;
 mod aabb_polyline;
+#[cfg(feature = "dim3")]
+mod aabb_quad;
 mod aabb_shape;
 mod aabb_support_map;
+#[cfg(feature = "dim2")]
+mod aabb_tile_grid;
 mod aabb_triangle;
 #[cfg(feature = "dim3")]
 mod aabb_trimesh;
@@ -55,14 +63,26 @@ mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
 mod bounding_sphere_heightfield;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_oriented;
 mod bounding_sphere_plane;
+mod bounding_sphere_point_cloud;
 mod bounding_sphere_polyline;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_quad;
+mod bounding_sphere_rounded_cuboid;
 mod bounding_sphere_segment;
 mod bounding_sphere_shape;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_superellipsoid;
+mod bounding_sphere_swept;
+#[cfg(feature = "dim2")]
+mod bounding_sphere_tile_grid;
 mod bounding_sphere_triangle;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
+mod bounding_volume_pair;
 
 pub(crate) mod circular_cone;
 mod spatialized_normal_cone;
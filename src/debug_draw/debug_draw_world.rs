@@ -0,0 +1,116 @@
+use crate::debug_draw::DebugRenderBuffers;
+use crate::math::Isometry;
+use crate::partitioning::BVHImpl;
+use crate::pipeline::CollisionWorld;
+use crate::shape::Shape;
+use na::{self, RealField};
+
+/// Selects what the debug-draw functions generate, and how.
+#[derive(Clone, Debug)]
+pub struct DebugDrawOptions<N: RealField + Copy> {
+    /// Whether the outline of each shape is added to the line list.
+    pub shape_outlines: bool,
+    /// Whether a triangulation of each shape is added to the triangle list.
+    pub shape_triangles: bool,
+    /// Whether the AABB of each shape is added to the line list.
+    ///
+    /// For a collision world, those are the AABBs stored by the broad phase, i.e., enlarged by
+    /// the world margin.
+    pub aabbs: bool,
+    /// Whether the bounding volumes of the nodes of the BVT of composite shapes are added to
+    /// the line list.
+    pub bvts: bool,
+    /// Whether the contact points and normals are added to the line list.
+    ///
+    /// This is only used when drawing a collision world.
+    pub contacts: bool,
+    /// The number of subdivisions used to approximate curved shapes.
+    pub nsubdivs: usize,
+    /// The half-size of the crosses representing contact points.
+    pub contact_point_size: N,
+    /// The length of the segments representing contact normals.
+    pub contact_normal_length: N,
+}
+
+impl<N: RealField + Copy> Default for DebugDrawOptions<N> {
+    fn default() -> Self {
+        DebugDrawOptions {
+            shape_outlines: true,
+            shape_triangles: false,
+            aabbs: false,
+            bvts: false,
+            contacts: true,
+            nsubdivs: 16,
+            contact_point_size: na::convert(0.05),
+            contact_normal_length: na::convert(0.2),
+        }
+    }
+}
+
+/// Generates the debug-render buffers of a set of positioned shapes.
+pub fn debug_draw_shapes<'a, N: RealField + Copy>(
+    shapes: impl IntoIterator<Item = (&'a Isometry<N>, &'a dyn Shape<N>)>,
+    options: &DebugDrawOptions<N>,
+    out: &mut DebugRenderBuffers<N>,
+) {
+    for (m, shape) in shapes {
+        push_shape(m, shape, options, out);
+
+        if options.aabbs {
+            out.push_aabb(&Isometry::identity(), &shape.aabb(m));
+        }
+    }
+}
+
+/// Generates the debug-render buffers of all the collision objects and contacts of a world.
+///
+/// Only the contacts of the contact pairs that actually have at least one contact are drawn.
+pub fn debug_draw_world<N: RealField + Copy, T>(
+    world: &CollisionWorld<N, T>,
+    options: &DebugDrawOptions<N>,
+    out: &mut DebugRenderBuffers<N>,
+) {
+    for (handle, co) in world.collision_objects() {
+        push_shape(co.position(), co.shape().as_ref(), options, out);
+
+        if options.aabbs {
+            if let Some(aabb) = world.broad_phase_aabb(handle) {
+                out.push_aabb(&Isometry::identity(), aabb);
+            }
+        }
+    }
+
+    if options.contacts {
+        for (_, _, _, manifold) in world.contact_pairs(true) {
+            out.push_contact_manifold(
+                manifold,
+                options.contact_point_size,
+                options.contact_normal_length,
+            );
+        }
+    }
+}
+
+fn push_shape<N: RealField + Copy>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    options: &DebugDrawOptions<N>,
+    out: &mut DebugRenderBuffers<N>,
+) {
+    if options.shape_outlines {
+        out.push_shape_outline(m, shape, options.nsubdivs);
+    }
+
+    if options.shape_triangles {
+        out.push_shape_triangles(m, shape, options.nsubdivs);
+    }
+
+    if options.bvts {
+        if let Some(composite) = shape.as_composite_shape() {
+            match composite.bvh() {
+                BVHImpl::BVT(bvt) => out.push_bvh(m, bvt),
+                BVHImpl::DBVT(dbvt) => out.push_bvh(m, dbvt),
+            }
+        }
+    }
+}
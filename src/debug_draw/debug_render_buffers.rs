@@ -0,0 +1,353 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::partitioning::BVH;
+use crate::query::{Contact, ContactManifold};
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{Cuboid, HeightField, Plane, Segment, Shape, SupportMap, Triangle};
+use na::{self, RealField, Unit};
+
+/// Line and triangle lists describing the geometry of shapes, bounding volumes, and contacts.
+///
+/// Each pair of consecutive points of `lines` is a line segment, and each triplet of consecutive
+/// points of `triangles` is a triangle. All the points are expressed in world-space.
+#[derive(Clone, Debug)]
+pub struct DebugRenderBuffers<N: RealField + Copy> {
+    /// The extremities of the line segments, two points per segment.
+    pub lines: Vec<Point<N>>,
+    /// The vertices of the triangles, three points per triangle.
+    pub triangles: Vec<Point<N>>,
+}
+
+impl<N: RealField + Copy> DebugRenderBuffers<N> {
+    /// Creates empty buffers.
+    pub fn new() -> Self {
+        DebugRenderBuffers {
+            lines: Vec::new(),
+            triangles: Vec::new(),
+        }
+    }
+
+    /// Removes all the lines and triangles from these buffers.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.triangles.clear();
+    }
+
+    /// Adds the line segment `[a, b]`.
+    #[inline]
+    pub fn push_line(&mut self, a: Point<N>, b: Point<N>) {
+        self.lines.push(a);
+        self.lines.push(b);
+    }
+
+    /// Adds the triangle `(a, b, c)`.
+    #[inline]
+    pub fn push_triangle(&mut self, a: Point<N>, b: Point<N>, c: Point<N>) {
+        self.triangles.push(a);
+        self.triangles.push(b);
+        self.triangles.push(c);
+    }
+
+    /// Adds the edges of `aabb` transformed by `m`.
+    ///
+    /// Because the corners are transformed independently, the result is the oriented box `m` maps
+    /// the AABB to, not the AABB of this box.
+    pub fn push_aabb(&mut self, m: &Isometry<N>, aabb: &AABB<N>) {
+        let ncorners = 1 << DIM;
+        let corner = |i: usize| {
+            let mut pt = aabb.mins;
+
+            for k in 0..DIM {
+                if i & (1 << k) != 0 {
+                    pt[k] = aabb.maxs[k];
+                }
+            }
+
+            m * pt
+        };
+
+        // Two corners are linked by an edge if their indices differ by exactly one bit.
+        for i in 0..ncorners {
+            for k in 0..DIM {
+                if i & (1 << k) == 0 {
+                    self.push_line(corner(i), corner(i | (1 << k)));
+                }
+            }
+        }
+    }
+
+    /// Adds the edges of every bounding volume of the nodes of `bvh`, transformed by `m`.
+    pub fn push_bvh<T>(&mut self, m: &Isometry<N>, bvh: &impl BVH<T, AABB<N>>) {
+        let mut stack = Vec::new();
+        stack.extend(bvh.root());
+
+        while let Some(node) = stack.pop() {
+            self.push_aabb(m, bvh.content(node).0);
+
+            for i in 0..bvh.num_children(node) {
+                stack.push(bvh.child(i, node));
+            }
+        }
+    }
+
+    /// Adds a contact.
+    ///
+    /// The contact points are drawn as crosses with half-size `point_size`, and the normal as a
+    /// segment of length `normal_length` starting at the contact point on the first object.
+    pub fn push_contact(&mut self, contact: &Contact<N>, point_size: N, normal_length: N) {
+        for pt in &[contact.world1, contact.world2] {
+            for k in 0..DIM {
+                let mut shift = Vector::zeros();
+                shift[k] = point_size;
+                self.push_line(pt - shift, pt + shift);
+            }
+        }
+
+        self.push_line(
+            contact.world1,
+            contact.world1 + *contact.normal * normal_length,
+        );
+    }
+
+    /// Adds all the contacts of a contact manifold.
+    pub fn push_contact_manifold(
+        &mut self,
+        manifold: &ContactManifold<N>,
+        point_size: N,
+        normal_length: N,
+    ) {
+        for c in manifold.contacts() {
+            self.push_contact(&c.contact, point_size, normal_length);
+        }
+    }
+
+    /// Adds the outline of `shape` transformed by `m`.
+    ///
+    /// Polygonal shapes and composite shapes are drawn exactly. Other support-mapped shapes are
+    /// approximated by sampling `nsubdivs` support points along a circle of directions (in 3D,
+    /// along the three circles of directions in the local coordinate planes of the shape). Planes
+    /// are drawn as a unit-sized patch together with their normal. Shapes that fit in none of
+    /// those categories are drawn as their local AABB.
+    pub fn push_shape_outline(&mut self, m: &Isometry<N>, shape: &dyn Shape<N>, nsubdivs: usize) {
+        if let Some(s) = shape.as_shape::<Segment<N>>() {
+            self.push_line(m * s.a, m * s.b);
+        } else if let Some(t) = shape.as_shape::<Triangle<N>>() {
+            for e in t.edges().iter() {
+                self.push_line(m * e.a, m * e.b);
+            }
+        } else if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+            let he = c.half_extents;
+            self.push_aabb(m, &AABB::from_half_extents(Point::origin(), he));
+        } else if let Some(p) = shape.as_shape::<Plane<N>>() {
+            self.push_plane(m, p);
+        } else if let Some(h) = shape.as_shape::<HeightField<N>>() {
+            self.push_heightfield_outline(m, h);
+        } else if let Some(c) = shape.as_composite_shape() {
+            for i in 0..c.nparts() {
+                c.map_part_at(i, m, &mut |m, part| {
+                    self.push_shape_outline(m, part, nsubdivs)
+                });
+            }
+        } else if let Some(pts) = polygon_vertices(shape) {
+            for i in 0..pts.len() {
+                self.push_line(m * pts[i], m * pts[(i + 1) % pts.len()]);
+            }
+        } else if let Some(s) = shape.as_support_map() {
+            self.push_support_map_outline(m, s, nsubdivs);
+        } else {
+            self.push_aabb(m, &shape.local_aabb());
+        }
+    }
+
+    /// Adds a triangulation of `shape` transformed by `m`.
+    ///
+    /// Support-mapped shapes are triangulated by sampling their support points along
+    /// `nsubdivs` directions (per coordinate circle in 3D). Segments, planes, and shapes that are
+    /// neither support-mapped, composite, nor heightfields add no triangle.
+    pub fn push_shape_triangles(&mut self, m: &Isometry<N>, shape: &dyn Shape<N>, nsubdivs: usize) {
+        if shape.is_shape::<Segment<N>>() || shape.is_shape::<Plane<N>>() {
+            // Nothing to fill.
+        } else if let Some(t) = shape.as_shape::<Triangle<N>>() {
+            self.push_triangle(m * t.a, m * t.b, m * t.c);
+        } else if let Some(h) = shape.as_shape::<HeightField<N>>() {
+            #[cfg(feature = "dim3")]
+            {
+                for t in h.triangles() {
+                    self.push_triangle(m * t.a, m * t.b, m * t.c);
+                }
+            }
+            #[cfg(feature = "dim2")]
+            {
+                let _ = h;
+            }
+        } else if let Some(c) = shape.as_composite_shape() {
+            for i in 0..c.nparts() {
+                c.map_part_at(i, m, &mut |m, part| {
+                    self.push_shape_triangles(m, part, nsubdivs)
+                });
+            }
+        } else if let Some(pts) = polygon_vertices(shape) {
+            for i in 1..pts.len().saturating_sub(1) {
+                self.push_triangle(m * pts[0], m * pts[i], m * pts[i + 1]);
+            }
+        } else if let Some(s) = shape.as_support_map() {
+            self.push_support_map_triangles(m, s, nsubdivs);
+        }
+    }
+
+    fn push_heightfield_outline(&mut self, m: &Isometry<N>, heightfield: &HeightField<N>) {
+        #[cfg(feature = "dim2")]
+        {
+            for s in heightfield.segments() {
+                self.push_line(m * s.a, m * s.b);
+            }
+        }
+        #[cfg(feature = "dim3")]
+        {
+            for t in heightfield.triangles() {
+                for e in t.edges().iter() {
+                    self.push_line(m * e.a, m * e.b);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn push_plane(&mut self, m: &Isometry<N>, plane: &Plane<N>) {
+        let n = plane.normal;
+        let tangent = Vector::new(-n.y, n.x);
+        let half: N = na::convert(0.5);
+
+        self.push_line(
+            m * Point::from(-tangent * half),
+            m * Point::from(tangent * half),
+        );
+        self.push_line(m * Point::origin(), m * Point::from(*n));
+    }
+
+    #[cfg(feature = "dim3")]
+    fn push_plane(&mut self, m: &Isometry<N>, plane: &Plane<N>) {
+        let n = plane.normal;
+        let mut basis = [Vector::zeros(); 2];
+        let mut k = 0;
+        Vector::orthonormal_subspace_basis(&[*n], |v| {
+            basis[k] = *v;
+            k += 1;
+            true
+        });
+
+        let half: N = na::convert(0.5);
+        let corners = [
+            (basis[0] + basis[1]) * half,
+            (basis[1] - basis[0]) * half,
+            -(basis[0] + basis[1]) * half,
+            (basis[0] - basis[1]) * half,
+        ];
+
+        for i in 0..4 {
+            self.push_line(
+                m * Point::from(corners[i]),
+                m * Point::from(corners[(i + 1) % 4]),
+            );
+        }
+
+        self.push_line(m * Point::origin(), m * Point::from(*n));
+    }
+
+    fn push_support_map_outline(
+        &mut self,
+        m: &Isometry<N>,
+        shape: &dyn SupportMap<N>,
+        nsubdivs: usize,
+    ) {
+        let nsubdivs = nsubdivs.max(3);
+
+        #[cfg(feature = "dim2")]
+        let planes = [(0, 1)];
+        #[cfg(feature = "dim3")]
+        let planes = [(0, 1), (1, 2), (2, 0)];
+
+        for &(a, b) in planes.iter() {
+            let pts: Vec<_> = (0..nsubdivs)
+                .map(|i| shape.support_point_toward(m, &circle_dir(a, b, i, nsubdivs)))
+                .collect();
+
+            for i in 0..nsubdivs {
+                self.push_line(pts[i], pts[(i + 1) % nsubdivs]);
+            }
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn push_support_map_triangles(
+        &mut self,
+        m: &Isometry<N>,
+        shape: &dyn SupportMap<N>,
+        nsubdivs: usize,
+    ) {
+        let nsubdivs = nsubdivs.max(3);
+        let pts: Vec<_> = (0..nsubdivs)
+            .map(|i| shape.support_point_toward(m, &circle_dir(0, 1, i, nsubdivs)))
+            .collect();
+
+        for i in 1..nsubdivs - 1 {
+            self.push_triangle(pts[0], pts[i], pts[i + 1]);
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    fn push_support_map_triangles(
+        &mut self,
+        m: &Isometry<N>,
+        shape: &dyn SupportMap<N>,
+        nsubdivs: usize,
+    ) {
+        let nsubdivs = nsubdivs.max(3) as u32;
+        let sphere = crate::procedural::unit_sphere::<N>(nsubdivs, (nsubdivs / 2).max(2), false);
+        let pts: Vec<_> = sphere
+            .coords
+            .iter()
+            .map(|pt| match Unit::try_new(pt.coords, N::default_epsilon()) {
+                Some(dir) => shape.support_point_toward(m, &dir),
+                None => m * Point::origin(),
+            })
+            .collect();
+
+        for idx in sphere.flat_indices().chunks(3) {
+            self.push_triangle(
+                pts[idx[0] as usize],
+                pts[idx[1] as usize],
+                pts[idx[2] as usize],
+            );
+        }
+    }
+}
+
+impl<N: RealField + Copy> Default for DebugRenderBuffers<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The local-space vertices of `shape` if it is a polygon with explicit vertices.
+#[cfg(feature = "dim2")]
+fn polygon_vertices<N: RealField + Copy>(shape: &dyn Shape<N>) -> Option<&[Point<N>]> {
+    shape.as_shape::<ConvexPolygon<N>>().map(|p| p.points())
+}
+
+/// The local-space vertices of `shape` if it is a polygon with explicit vertices.
+#[cfg(feature = "dim3")]
+fn polygon_vertices<N: RealField + Copy>(_: &dyn Shape<N>) -> Option<&[Point<N>]> {
+    None
+}
+
+/// The `i`-th of `n` unit directions evenly distributed on the circle spanned by the axes `a`
+/// and `b`.
+fn circle_dir<N: RealField + Copy>(a: usize, b: usize, i: usize, n: usize) -> Unit<Vector<N>> {
+    let angle = N::two_pi() * na::convert(i as f64) / na::convert(n as f64);
+    let mut dir = Vector::zeros();
+    dir[a] = angle.cos();
+    dir[b] = angle.sin();
+    Unit::new_unchecked(dir)
+}
@@ -0,0 +1,10 @@
+//! Generation of line and triangle lists for the debug rendering of shapes and collision worlds.
+//!
+//! Nothing here depends on a renderer: the output is a set of plain point buffers that can be
+//! uploaded as-is to any graphics API.
+
+pub use self::debug_draw_world::{debug_draw_shapes, debug_draw_world, DebugDrawOptions};
+pub use self::debug_render_buffers::DebugRenderBuffers;
+
+mod debug_draw_world;
+mod debug_render_buffers;
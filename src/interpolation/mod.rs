@@ -1,8 +1,8 @@
 //! Interpolation of the motion of an object.
 
 pub use self::rigid_motion::{
-    ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion, InterpolatedRigidMotion,
-    RigidMotion, RigidMotionComposition,
+    interpolate_isometry, ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion,
+    InterpolatedRigidMotion, KeyframedRigidMotion, RigidMotion, RigidMotionComposition,
 };
 
 mod rigid_motion;
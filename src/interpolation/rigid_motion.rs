@@ -18,6 +18,23 @@ impl<N: RealField + Copy> RigidMotion<N> for Isometry<N> {
     }
 }
 
+/// Interpolates between two isometries using LERP for the translation part and SLERP for the rotation.
+///
+/// Returns `m0` if `t = 0.0` and `m1` if `t = 1.0`.
+///
+/// # Panics
+///
+/// In 3D, panics if the rotational parts of `m0` and `m1` differ by an angle of exactly `π`
+/// because the rotation path is then ambiguous.
+#[inline]
+pub fn interpolate_isometry<N: RealField + Copy>(
+    m0: &Isometry<N>,
+    m1: &Isometry<N>,
+    t: N,
+) -> Isometry<N> {
+    m0.lerp_slerp(m1, t)
+}
+
 /// Interpolation between two isometries using LERP for the translation part and SLERP for the rotation.
 pub struct InterpolatedRigidMotion<N: RealField + Copy> {
     /// The transformation at `t = 0.0`.
@@ -38,7 +55,61 @@ impl<N: RealField + Copy> InterpolatedRigidMotion<N> {
 
 impl<N: RealField + Copy> RigidMotion<N> for InterpolatedRigidMotion<N> {
     fn position_at_time(&self, t: N) -> Isometry<N> {
-        self.start.lerp_slerp(&self.end, t)
+        interpolate_isometry(&self.start, &self.end, t)
+    }
+}
+
+/// A motion interpolating a sequence of keyframes.
+///
+/// Between two consecutive keyframes, the position is interpolated with `interpolate_isometry`.
+/// Before the first keyframe and after the last one, the position is the one of the closest
+/// keyframe. This is useful to describe the scripted motion of kinematic objects like moving
+/// platforms.
+#[derive(Clone, Debug)]
+pub struct KeyframedRigidMotion<N: RealField + Copy> {
+    keyframes: Vec<(N, Isometry<N>)>,
+}
+
+impl<N: RealField + Copy> KeyframedRigidMotion<N> {
+    /// Initialize a motion from keyframes given as `(time, position)` pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keyframes` is empty or if the times are not sorted in increasing order.
+    pub fn new(keyframes: Vec<(N, Isometry<N>)>) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "A keyframed motion must have at least one keyframe."
+        );
+        assert!(
+            keyframes.windows(2).all(|w| w[0].0 <= w[1].0),
+            "The keyframes must be sorted by increasing time."
+        );
+
+        KeyframedRigidMotion { keyframes }
+    }
+
+    /// The `(time, position)` keyframes of this motion, sorted by increasing time.
+    #[inline]
+    pub fn keyframes(&self) -> &[(N, Isometry<N>)] {
+        &self.keyframes[..]
+    }
+}
+
+impl<N: RealField + Copy> RigidMotion<N> for KeyframedRigidMotion<N> {
+    fn position_at_time(&self, t: N) -> Isometry<N> {
+        // Index of the first keyframe strictly after `t`.
+        let i = self.keyframes.partition_point(|k| k.0 <= t);
+
+        if i == 0 {
+            self.keyframes[0].1
+        } else if i == self.keyframes.len() {
+            self.keyframes[i - 1].1
+        } else {
+            let (t0, m0) = &self.keyframes[i - 1];
+            let (t1, m1) = &self.keyframes[i];
+            interpolate_isometry(m0, m1, (t - *t0) / (*t1 - *t0))
+        }
     }
 }
 
@@ -81,20 +81,26 @@ const NOT_REGISTERED_ERROR: &'static str =
 pub use crate::pipeline::{broad_phase, narrow_phase, world};
 
 pub mod bounding_volume;
+pub mod debug_draw;
 pub mod interpolation;
 pub mod partitioning;
 pub mod pipeline;
 pub mod procedural;
 pub mod query;
+#[cfg(feature = "rand")]
+pub mod sample;
 pub mod shape;
 pub mod transformation;
 pub mod utils;
+pub mod volumetric;
 
 /// Compilation flags dependent aliases for mathematical types.
 #[cfg(feature = "dim3")]
 pub mod math {
     use na::{Isometry3, Matrix3, Point3, Translation3, UnitQuaternion, Vector3, Vector6, U3, U6};
 
+    pub use crate::interpolation::interpolate_isometry;
+
     /// The dimension of the space.
     pub const DIM: usize = 3;
 
@@ -113,6 +119,9 @@ pub mod math {
     /// The angular vector type.
     pub type AngularVector<N> = Vector3<N>;
 
+    /// The angular inertia type.
+    pub type AngularInertia<N> = Matrix3<N>;
+
     /// The vector type.
     pub type Vector<N> = Vector3<N>;
 
@@ -140,6 +149,8 @@ pub mod math {
 pub mod math {
     use na::{Isometry2, Matrix2, Point2, Translation2, UnitComplex, Vector1, Vector2, U2};
 
+    pub use crate::interpolation::interpolate_isometry;
+
     /// The dimension of the space.
     pub const DIM: usize = 2;
 
@@ -149,6 +160,9 @@ pub mod math {
     /// The point type.
     pub type Point<N> = Point2<N>;
 
+    /// The angular inertia type.
+    pub type AngularInertia<N> = N;
+
     /// The vector type.
     pub type Vector<N> = Vector2<N>;
 
@@ -2,6 +2,7 @@ use crate::partitioning::{
     BVTNodeId, BestFirstVisitStatus, BestFirstVisitor, DBVTNodeId, SimultaneousVisitor,
     VisitStatus, Visitor, BVT, DBVT,
 };
+use crate::query::{statistics, QueryBuffers};
 use na::RealField;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -31,6 +32,7 @@ pub trait BVH<T, BV> {
             while let Some(node) = stack.pop() {
                 let content = self.content(node);
 
+                statistics::count_bv_test();
                 match visitor.visit(content.0, content.1) {
                     VisitStatus::Continue => {
                         for i in 0..self.num_children(node) {
@@ -56,6 +58,7 @@ pub trait BVH<T, BV> {
                 let content1 = self.content(node1);
                 let content2 = other.content(node2);
 
+                statistics::count_bv_test();
                 match visitor.visit(content1.0, content1.1, content2.0, content2.1) {
                     VisitStatus::Continue => {
                         let nchild1 = self.num_children(node1);
@@ -103,7 +106,24 @@ pub trait BVH<T, BV> {
         N: RealField + Copy,
         BFS: BestFirstVisitor<N, T, BV>,
     {
-        let mut queue: BinaryHeap<WeightedValue<N, Self::Node>> = BinaryHeap::new();
+        self.best_first_search_with_queue(visitor, &mut BestFirstSearchQueue::new())
+    }
+
+    /// Performs a best-first-search on the BVH, reusing the memory of the given priority queue.
+    ///
+    /// Returns the content of the leaf with the smallest associated cost, and a result of
+    /// user-defined type.
+    fn best_first_search_with_queue<N, BFS>(
+        &self,
+        visitor: &mut BFS,
+        queue: &mut BestFirstSearchQueue<N, Self::Node>,
+    ) -> Option<(Self::Node, BFS::Result)>
+    where
+        N: RealField + Copy,
+        BFS: BestFirstVisitor<N, T, BV>,
+    {
+        let queue = &mut queue.heap;
+        queue.clear();
         // The lowest cost collision with actual scene geometry.
         let mut best_cost = N::max_value().unwrap();
         let mut best_result = None;
@@ -111,6 +131,7 @@ pub trait BVH<T, BV> {
         if let Some(root) = self.root() {
             let (root_bv, root_data) = self.content(root);
 
+            statistics::count_bv_test();
             match visitor.visit(best_cost, root_bv, root_data) {
                 BestFirstVisitStatus::Continue { cost, result } => {
                     // Root may be a leaf node
@@ -135,6 +156,7 @@ pub trait BVH<T, BV> {
                     let child = self.child(i, entry.value);
                     let (child_bv, child_data) = self.content(child);
 
+                    statistics::count_bv_test();
                     match visitor.visit(best_cost, child_bv, child_data) {
                         BestFirstVisitStatus::Continue { cost, result } => {
                             if cost < best_cost {
@@ -246,6 +268,48 @@ impl<'a, N: RealField + Copy, T, BV> BVHImpl<'a, N, T, BV> {
                 .map(|res| (BVHNodeId::DBVTNodeId(res.0), res.1)),
         }
     }
+
+    /// Performs a best-first-search on the tree, reusing the memory of `buffers`.
+    ///
+    /// Returns the content of the leaf with the smallest associated cost, and a result of
+    /// user-defined type.
+    pub fn best_first_search_with_buffers<BFS>(
+        self,
+        visitor: &mut BFS,
+        buffers: &mut QueryBuffers<N>,
+    ) -> Option<(BVHNodeId, BFS::Result)>
+    where
+        BFS: BestFirstVisitor<N, T, BV>,
+    {
+        match self {
+            BVHImpl::BVT(bvt) => bvt
+                .best_first_search_with_queue(visitor, &mut buffers.bvt_queue)
+                .map(|res| (BVHNodeId::BVTNodeId(res.0), res.1)),
+            BVHImpl::DBVT(dbvt) => dbvt
+                .best_first_search_with_queue(visitor, &mut buffers.dbvt_queue)
+                .map(|res| (BVHNodeId::DBVTNodeId(res.0), res.1)),
+        }
+    }
+}
+
+/// A priority queue that can be reused by successive best-first searches on a BVH.
+pub struct BestFirstSearchQueue<N, Node> {
+    heap: BinaryHeap<WeightedValue<N, Node>>,
+}
+
+impl<N: RealField + Copy, Node> BestFirstSearchQueue<N, Node> {
+    /// Creates a new empty priority queue.
+    pub fn new() -> Self {
+        BestFirstSearchQueue {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<N: RealField + Copy, Node> Default for BestFirstSearchQueue<N, Node> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 struct WeightedValue<N, T> {
@@ -1,8 +1,9 @@
 //! A read-only Bounding Volume Tree.
 
-use crate::bounding_volume::BoundingVolume;
-use crate::math::{Point, DIM};
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::{Point, Vector, DIM};
 use crate::partitioning::BVH;
+use crate::query::statistics;
 use crate::utils;
 use simba::scalar::RealField;
 use std::collections::VecDeque;
@@ -63,6 +64,12 @@ impl<T, BV> BVTLeaf<T, BV> {
     pub fn data(&self) -> &T {
         &self.data
     }
+
+    /// Mutable reference to the user-data stored on this leaf.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -124,6 +131,41 @@ impl<T, BV> BVT<T, BV> {
         }
     }
 
+    /// Builds a bounding volume tree from its raw parts.
+    ///
+    /// Each internal node is given as its bounding volume and the identifiers of its left and
+    /// right children. The leaves must not be empty.
+    pub(crate) fn from_raw_parts(
+        root: BVTNodeId,
+        internals: Vec<(BV, BVTNodeId, BVTNodeId)>,
+        leaves: Vec<(T, BV)>,
+    ) -> BVT<T, BV> {
+        let internals = internals
+            .into_iter()
+            .map(|(bounding_volume, left, right)| BVTInternal {
+                bounding_volume,
+                left,
+                right,
+            })
+            .collect();
+        let leaves = leaves
+            .into_iter()
+            .map(|(data, bounding_volume)| BVTLeaf {
+                bounding_volume,
+                data,
+            })
+            .collect();
+
+        BVT {
+            root,
+            internals,
+            leaves,
+            deformation_timestamp: 1,
+            deformation_infos: Vec::new(),
+            parents_to_update: VecDeque::new(),
+        }
+    }
+
     /// The set of leaves on this BVT.
     #[inline]
     pub fn leaves(&self) -> &[BVTLeaf<T, BV>] {
@@ -136,6 +178,39 @@ impl<T, BV> BVT<T, BV> {
         &self.leaves[i]
     }
 
+    /// Mutable reference to the i-th leaf of this BVT.
+    ///
+    /// Only the user-data of the leaf can be modified this way. Use
+    /// `.set_leaf_bounding_volume(...)` to modify its bounding volume.
+    #[inline]
+    pub fn leaf_mut(&mut self, i: usize) -> &mut BVTLeaf<T, BV> {
+        &mut self.leaves[i]
+    }
+
+    /// Transforms the user-data of every leaf of this BVT with `f`.
+    ///
+    /// The structure of the tree and all its bounding volumes are left unchanged, so the
+    /// i-th leaf of the resulting BVT holds `f` applied to the data of the i-th leaf of `self`.
+    pub fn map_leaves<U>(self, mut f: impl FnMut(T) -> U) -> BVT<U, BV> {
+        let leaves = self
+            .leaves
+            .into_iter()
+            .map(|leaf| BVTLeaf {
+                bounding_volume: leaf.bounding_volume,
+                data: f(leaf.data),
+            })
+            .collect();
+
+        BVT {
+            root: self.root,
+            internals: self.internals,
+            leaves,
+            deformation_timestamp: self.deformation_timestamp,
+            deformation_infos: self.deformation_infos,
+            parents_to_update: self.parents_to_update,
+        }
+    }
+
     /// Reference to the bounding volume of the tree root.
     pub fn root_bounding_volume(&self) -> Option<&BV> {
         if self.leaves.is_empty() {
@@ -248,6 +323,56 @@ impl<T, BV> BVT<T, BV> {
         }
     }
 
+    /// Replaces the bounding volume of every leaf and refits the whole tree bottom-up.
+    ///
+    /// The closure is given the index of each leaf and its data, and must return its new
+    /// bounding volume. The structure of the tree is left unchanged, so this is much cheaper
+    /// than rebuilding the tree but the tree quality may degrade if the leaves move a lot.
+    pub fn refit_leaves<N: RealField + Copy>(&mut self, mut f: impl FnMut(usize, &T) -> BV)
+    where
+        BV: BoundingVolume<N>,
+    {
+        for (i, leaf) in self.leaves.iter_mut().enumerate() {
+            leaf.bounding_volume = f(i, &leaf.data)
+        }
+
+        if let BVTNodeId::Internal(root) = self.root {
+            if !self.leaves.is_empty() {
+                self.refit_internal(root);
+            }
+        }
+
+        // All the pending updates have been performed.
+        self.parents_to_update.clear();
+    }
+
+    fn refit_internal<N: RealField + Copy>(&mut self, i: usize)
+    where
+        BV: BoundingVolume<N>,
+    {
+        let (left, right) = (self.internals[i].left, self.internals[i].right);
+
+        if let BVTNodeId::Internal(j) = left {
+            self.refit_internal(j)
+        }
+
+        if let BVTNodeId::Internal(j) = right {
+            self.refit_internal(j)
+        }
+
+        let bv = self
+            .node_bounding_volume(left)
+            .merged(self.node_bounding_volume(right));
+        self.internals[i].bounding_volume = bv;
+    }
+
+    fn node_bounding_volume(&self, node: BVTNodeId) -> &BV {
+        match node {
+            BVTNodeId::Internal(i) => &self.internals[i].bounding_volume,
+            BVTNodeId::Leaf(i) => &self.leaves[i].bounding_volume,
+        }
+    }
+
     fn init_deformation_infos(&mut self) {
         if self.deformation_infos.is_empty() {
             self.deformation_infos = iter::repeat(BVTDeformationInfo {
@@ -276,6 +401,268 @@ impl<T, BV> BVT<T, BV> {
     }
 }
 
+/// Statistics describing the structure and the quality of a BVT.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BVTStats<N: RealField + Copy> {
+    /// The number of nodes on the longest path from the root to a leaf.
+    ///
+    /// This is zero for an empty tree and one for a tree made of a single leaf.
+    pub depth: usize,
+    /// The number of leaves of the tree.
+    pub num_leaves: usize,
+    /// The number of internal nodes of the tree.
+    pub num_internals: usize,
+    /// The surface area heuristic (SAH) cost of the tree.
+    ///
+    /// This is the sum of the surface areas of all the nodes (the perimeters in 2D), divided by
+    /// the surface area of the root. It estimates the number of nodes visited by a random ray
+    /// hitting the root bounding volume: lower is better.
+    pub sah_cost: N,
+    /// The average, over all the internal nodes, of the volume of the intersection of the
+    /// bounding volumes of its two children divided by its own volume (areas in 2D).
+    pub average_overlap: N,
+    /// The maximum, over all the internal nodes, of the volume of the intersection of the
+    /// bounding volumes of its two children divided by its own volume (areas in 2D).
+    pub max_overlap: N,
+}
+
+impl<N: RealField + Copy, T> BVT<T, AABB<N>> {
+    /// Computes statistics describing the structure and the quality of this tree.
+    ///
+    /// Pathological trees, e.g., built from degenerate procedurally generated meshes, can be
+    /// detected by a depth much larger than the logarithm of the number of leaves, or by large
+    /// overlaps.
+    pub fn stats(&self) -> BVTStats<N> {
+        let mut stats = BVTStats {
+            depth: 0,
+            num_leaves: self.leaves.len(),
+            num_internals: 0,
+            sah_cost: N::zero(),
+            average_overlap: N::zero(),
+            max_overlap: N::zero(),
+        };
+
+        let root_bv = match self.root_bounding_volume() {
+            Some(bv) => bv,
+            None => return stats,
+        };
+        let root_area = aabb_surface_area(root_bv);
+        let mut sum_areas = N::zero();
+        let mut stack = vec![(self.root, 1)];
+
+        while let Some((node, depth)) = stack.pop() {
+            stats.depth = stats.depth.max(depth);
+            sum_areas += aabb_surface_area(self.node_bounding_volume(node));
+
+            if let BVTNodeId::Internal(i) = node {
+                let internal = &self.internals[i];
+                let overlap = aabb_overlap_ratio(
+                    &internal.bounding_volume,
+                    self.node_bounding_volume(internal.left),
+                    self.node_bounding_volume(internal.right),
+                );
+
+                stats.num_internals += 1;
+                stats.average_overlap += overlap;
+                stats.max_overlap = stats.max_overlap.max(overlap);
+                stack.push((internal.left, depth + 1));
+                stack.push((internal.right, depth + 1));
+            }
+        }
+
+        if stats.num_internals != 0 {
+            stats.average_overlap /= na::convert(stats.num_internals as f64);
+        }
+
+        stats.sah_cost = if root_area.is_zero() {
+            na::convert((stats.num_internals + stats.num_leaves) as f64)
+        } else {
+            sum_areas / root_area
+        };
+
+        stats
+    }
+}
+
+impl<N: RealField + Copy, T> BVT<T, AABB<N>> {
+    /// Iterates through the data of all the leaves with an AABB intersecting `aabb`.
+    ///
+    /// This is a shortcut for traversing this tree with a `BoundingVolumeInterferencesCollector`,
+    /// except that the leaves are yielded lazily instead of being collected into a vector.
+    pub fn leaves_intersecting_aabb<'a>(
+        &'a self,
+        aabb: &'a AABB<N>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        BVTRegionIter::new(self, move |bv: &AABB<N>| bv.intersects(aabb))
+    }
+
+    /// Iterates through the data of all the leaves with an AABB containing `pt`.
+    ///
+    /// This is a shortcut for traversing this tree with a `PointInterferencesCollector`, except
+    /// that the leaves are yielded lazily instead of being collected into a vector.
+    pub fn leaves_containing_point<'a>(
+        &'a self,
+        pt: &'a Point<N>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        BVTRegionIter::new(self, move |bv: &AABB<N>| bv.contains_local_point(pt))
+    }
+}
+
+/// A depth-first traversal of the leaves of a BVT, pruning the subtrees with a bounding volume
+/// rejected by a predicate.
+struct BVTRegionIter<'a, T, BV, F> {
+    tree: &'a BVT<T, BV>,
+    stack: Vec<BVTNodeId>,
+    predicate: F,
+}
+
+impl<'a, T, BV, F: FnMut(&BV) -> bool> BVTRegionIter<'a, T, BV, F> {
+    fn new(tree: &'a BVT<T, BV>, predicate: F) -> Self {
+        let stack = if tree.leaves.is_empty() {
+            Vec::new()
+        } else {
+            vec![tree.root]
+        };
+
+        BVTRegionIter {
+            tree,
+            stack,
+            predicate,
+        }
+    }
+}
+
+impl<'a, T, BV, F: FnMut(&BV) -> bool> Iterator for BVTRegionIter<'a, T, BV, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        while let Some(node) = self.stack.pop() {
+            statistics::count_bv_test();
+
+            match node {
+                BVTNodeId::Internal(i) => {
+                    let internal = &self.tree.internals[i];
+
+                    if (self.predicate)(&internal.bounding_volume) {
+                        self.stack.push(internal.right);
+                        self.stack.push(internal.left);
+                    }
+                }
+                BVTNodeId::Leaf(i) => {
+                    let leaf = &self.tree.leaves[i];
+
+                    if (self.predicate)(&leaf.bounding_volume) {
+                        return Some(&leaf.data);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<T, BV> BVT<T, BV> {
+    /// Panics if this tree is malformed.
+    ///
+    /// This checks that every leaf is reachable exactly once from the root, and that the
+    /// bounding volume of each internal node contains the bounding volumes of its children.
+    /// The latter does not hold after `.set_leaf_bounding_volume(_, _, false)` until `.refit(_)`
+    /// is called.
+    pub fn assert_invariants<N: RealField + Copy>(&self)
+    where
+        BV: BoundingVolume<N>,
+    {
+        if self.leaves.is_empty() {
+            return;
+        }
+
+        let mut leaf_visited = vec![false; self.leaves.len()];
+        let mut internal_visited = vec![false; self.internals.len()];
+        let mut stack = vec![self.root];
+
+        while let Some(node) = stack.pop() {
+            match node {
+                BVTNodeId::Leaf(i) => {
+                    assert!(
+                        !leaf_visited[i],
+                        "BVT invariant violated: the leaf {} is reachable twice.",
+                        i
+                    );
+                    leaf_visited[i] = true;
+                }
+                BVTNodeId::Internal(i) => {
+                    assert!(
+                        !internal_visited[i],
+                        "BVT invariant violated: the internal node {} is reachable twice.",
+                        i
+                    );
+                    internal_visited[i] = true;
+
+                    let internal = &self.internals[i];
+
+                    for child in &[internal.left, internal.right] {
+                        assert!(
+                            internal
+                                .bounding_volume
+                                .contains(self.node_bounding_volume(*child)),
+                            "BVT invariant violated: the bounding volume of the internal node {} does not contain the bounding volume of its child {:?}.",
+                            i,
+                            child
+                        );
+                        stack.push(*child);
+                    }
+                }
+            }
+        }
+
+        if let Some(i) = leaf_visited.iter().position(|visited| !visited) {
+            panic!(
+                "BVT invariant violated: the leaf {} is not reachable from the root.",
+                i
+            );
+        }
+    }
+}
+
+/// The surface area of an AABB in 3D, or its perimeter in 2D.
+fn aabb_surface_area<N: RealField + Copy>(aabb: &AABB<N>) -> N {
+    let extents = aabb.extents();
+    let mut area = N::zero();
+
+    // Sum of the measures of the faces orthogonal to each axis, which are counted twice.
+    for i in 0..DIM {
+        let mut face = N::one();
+
+        for j in 0..DIM {
+            if j != i {
+                face *= extents[j];
+            }
+        }
+
+        area += face;
+    }
+
+    area * na::convert(2.0)
+}
+
+/// The volume of intersection of `a` and `b`, divided by the volume of `parent`.
+fn aabb_overlap_ratio<N: RealField + Copy>(parent: &AABB<N>, a: &AABB<N>, b: &AABB<N>) -> N {
+    let parent_volume = parent.extents().iter().fold(N::one(), |acc, e| acc * *e);
+
+    if parent_volume.is_zero() {
+        return N::zero();
+    }
+
+    let mins = a.mins.coords.sup(&b.mins.coords);
+    let maxs = a.maxs.coords.inf(&b.maxs.coords);
+    let overlap = (maxs - mins)
+        .iter()
+        .fold(N::one(), |acc, e| acc * e.max(N::zero()));
+
+    overlap / parent_volume
+}
+
 impl<T, BV> BVT<T, BV> {
     /// Creates a balanced `BVT`.
     pub fn new_balanced<N>(leaves: Vec<(T, BV)>) -> BVT<T, BV>
@@ -361,6 +748,65 @@ impl<T, BV> BVT<T, BV> {
         Self::median_partitioning_with_centers(depth, leaves, &mut |_, bv| bv.center())
     }
 
+    /// Creates a `BVT` by sorting the leaves along a Morton curve (linear BVH).
+    ///
+    /// The leaves are sorted by the Morton code of the center of their bounding volume, and the
+    /// tree is then built by splitting each set of leaves where the highest bit of their codes
+    /// changes. This is much faster than `new_balanced` for large sets of leaves, at the cost of
+    /// a slightly lower tree quality, making it suitable to trees rebuilt at each frame.
+    pub fn new_lbvh<N>(leaves: Vec<(T, BV)>) -> BVT<T, BV>
+    where
+        N: RealField + Copy,
+        BV: BoundingVolume<N> + Clone,
+    {
+        if leaves.is_empty() {
+            return BVT::from_partitioning(leaves, &mut Self::median_partitioning);
+        }
+
+        let (mins, extents) = lbvh_bounds(&leaves);
+        let mut coded: Vec<_> = leaves
+            .into_iter()
+            .map(|(data, bv)| {
+                let code = morton_code(&bv.center(), &mins, &extents);
+                ((code, data), bv)
+            })
+            .collect();
+        coded.sort_unstable_by_key(|leaf| (leaf.0).0);
+
+        BVT::from_partitioning(coded, &mut lbvh_partitioning).map_leaves(|(_, data)| data)
+    }
+
+    /// Creates a `BVT` by sorting the leaves along a Morton curve, using all the threads of the
+    /// rayon thread pool.
+    ///
+    /// The resulting tree is the same as the one built by `new_lbvh`, up to the order of the
+    /// leaves sharing the same Morton code.
+    #[cfg(feature = "parallel")]
+    pub fn new_lbvh_parallel<N>(leaves: Vec<(T, BV)>) -> BVT<T, BV>
+    where
+        N: RealField + Copy,
+        T: Send,
+        BV: BoundingVolume<N> + Clone + Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        if leaves.is_empty() {
+            return BVT::from_partitioning(leaves, &mut Self::median_partitioning);
+        }
+
+        let (mins, extents) = lbvh_bounds(&leaves);
+        let mut coded: Vec<_> = leaves
+            .into_par_iter()
+            .map(|(data, bv)| {
+                let code = morton_code(&bv.center(), &mins, &extents);
+                ((code, data), bv)
+            })
+            .collect();
+        coded.par_sort_unstable_by_key(|leaf| (leaf.0).0);
+
+        lbvh_build_parallel(coded).map_leaves(|(_, data)| data)
+    }
+
     fn _from_partitioning<F: FnMut(usize, Vec<(T, BV)>) -> (BV, BinaryPartition<T, BV>)>(
         depth: usize,
         leaves: Vec<(T, BV)>,
@@ -404,6 +850,136 @@ impl<T, BV> BVT<T, BV> {
     }
 }
 
+/// The smallest corner and the extents of the AABB of the centers of the given leaves.
+fn lbvh_bounds<N, T, BV>(leaves: &[(T, BV)]) -> (Point<N>, Vector<N>)
+where
+    N: RealField + Copy,
+    BV: BoundingVolume<N>,
+{
+    let mut mins = leaves[0].1.center();
+    let mut maxs = mins;
+
+    for (_, bv) in &leaves[1..] {
+        let center = bv.center();
+        mins = mins.inf(&center);
+        maxs = maxs.sup(&center);
+    }
+
+    (mins, maxs - mins)
+}
+
+/// Builds a tree from leaves sorted by Morton codes, the subtrees being built in parallel until
+/// they contain too few leaves to be worth it.
+///
+/// The nodes are laid out exactly as `BVT::from_partitioning` would.
+#[cfg(feature = "parallel")]
+fn lbvh_build_parallel<N, T, BV>(leaves: Vec<((u64, T), BV)>) -> BVT<(u64, T), BV>
+where
+    N: RealField + Copy,
+    T: Send,
+    BV: BoundingVolume<N> + Clone + Send,
+{
+    const SEQUENTIAL_THRESHOLD: usize = 1024;
+
+    if leaves.len() <= SEQUENTIAL_THRESHOLD {
+        return BVT::from_partitioning(leaves, &mut lbvh_partitioning);
+    }
+
+    let (bv, left, right) = match lbvh_partitioning(0, leaves) {
+        (bv, BinaryPartition::Parts(left, right)) => (bv, left, right),
+        (_, BinaryPartition::Part(_)) => unreachable!(),
+    };
+    let (mut res, right) = rayon::join(|| lbvh_build_parallel(left), || lbvh_build_parallel(right));
+
+    // The nodes of the right subtree are moved after those of the left subtree.
+    let (ninternals, nleaves) = (res.internals.len(), res.leaves.len());
+    let shift = |id| match id {
+        BVTNodeId::Internal(i) => BVTNodeId::Internal(i + ninternals),
+        BVTNodeId::Leaf(i) => BVTNodeId::Leaf(i + nleaves),
+    };
+
+    res.internals
+        .extend(right.internals.into_iter().map(|node| BVTInternal {
+            bounding_volume: node.bounding_volume,
+            left: shift(node.left),
+            right: shift(node.right),
+        }));
+    res.leaves.extend(right.leaves);
+    res.internals.push(BVTInternal {
+        bounding_volume: bv,
+        left: res.root,
+        right: shift(right.root),
+    });
+    res.root = BVTNodeId::Internal(res.internals.len() - 1);
+
+    res
+}
+
+/// Partitions leaves sorted by Morton codes at the highest bit that differs among their codes.
+fn lbvh_partitioning<N, T, BV>(
+    _depth: usize,
+    mut leaves: Vec<((u64, T), BV)>,
+) -> (BV, BinaryPartition<(u64, T), BV>)
+where
+    N: RealField + Copy,
+    BV: BoundingVolume<N> + Clone,
+{
+    if leaves.len() == 1 {
+        let (data, bv) = leaves.pop().unwrap();
+        return (bv, BinaryPartition::Part(data));
+    }
+
+    let mut bv = leaves[0].1.clone();
+
+    for leaf in &leaves[1..] {
+        bv.merge(&leaf.1)
+    }
+
+    let first = (leaves[0].0).0;
+    let last = (leaves[leaves.len() - 1].0).0;
+
+    let split = if first == last {
+        leaves.len() / 2
+    } else {
+        let bit = 1 << (63 - (first ^ last).leading_zeros());
+        leaves
+            .iter()
+            .position(|leaf| (leaf.0).0 & bit != 0)
+            .unwrap()
+    };
+
+    let right = leaves.split_off(split);
+    (bv, BinaryPartition::Parts(leaves, right))
+}
+
+/// The Morton code of `pt`, after normalization of its coordinates with the given bounds.
+fn morton_code<N: RealField + Copy>(pt: &Point<N>, mins: &Point<N>, extents: &Vector<N>) -> u64 {
+    #[cfg(feature = "dim2")]
+    const BITS: u32 = 32;
+    #[cfg(feature = "dim3")]
+    const BITS: u32 = 21;
+
+    let max_coord = ((1u64 << BITS) - 1) as f64;
+    let mut code = 0;
+
+    for i in 0..DIM {
+        let normalized = if extents[i] > N::zero() {
+            (pt[i] - mins[i]) / extents[i]
+        } else {
+            N::zero()
+        };
+        let normalized: f64 = na::try_convert(normalized).unwrap_or(0.0);
+        let quantized = (normalized.max(0.0).min(1.0) * max_coord) as u64;
+
+        // Interleave the bits of all the coordinates.
+        for b in 0..BITS {
+            code |= ((quantized >> b) & 1) << (b as usize * DIM + i);
+        }
+    }
+
+    code
+}
+
 impl<'a, T, BV> BVH<T, BV> for BVT<T, BV> {
     type Node = BVTNodeId;
 
@@ -154,6 +154,15 @@ impl<N: RealField + Copy, T, BV: BoundingVolume<N>> DBVT<N, T, BV> {
         self.leaves.is_empty()
     }
 
+    /// Releases the unused memory of this DBVT.
+    ///
+    /// The identifiers of the leaves remain valid. The memory of removed nodes located after
+    /// the last live node can be released.
+    pub fn shrink_to_fit(&mut self) {
+        self.leaves.shrink_to_fit();
+        self.internals.shrink_to_fit();
+    }
+
     /// Inserts a leaf into this DBVT.
     pub fn insert(&mut self, leaf: DBVTLeaf<N, T, BV>) -> DBVTLeafId {
         if self.is_empty() {
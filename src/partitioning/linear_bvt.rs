@@ -0,0 +1,289 @@
+//! A read-only Bounding Volume Tree stored as a flat array of nodes.
+
+use crate::partitioning::{BVTNodeId, VisitStatus, Visitor, BVH, BVT};
+use crate::query::statistics;
+
+/// A node of a `LinearBVT`.
+///
+/// This has a C-compatible layout so that an array of nodes can be uploaded to the GPU or
+/// memory-mapped as-is, provided the bounding volume type also has a C-compatible layout.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct LinearBVTNode<BV> {
+    /// The bounding volume of this node.
+    pub bounding_volume: BV,
+    /// The index of the right child of an internal node, or the index of the data of a leaf.
+    ///
+    /// The left child of an internal node is always the node following it on the array.
+    pub right_or_data: u32,
+    /// The index of the first node following the subtree rooted at this node.
+    ///
+    /// This allows stack-less traversals: skipping this node children amounts to jumping to
+    /// this index.
+    pub escape: u32,
+    /// Non-zero if this node is a leaf.
+    pub is_leaf: u32,
+}
+
+impl<BV> LinearBVTNode<BV> {
+    /// Whether this node is a leaf.
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        self.is_leaf != 0
+    }
+}
+
+/// A Bounding Volume Tree stored as a flat array of nodes in depth-first order.
+///
+/// Unlike the `BVT`, the nodes do not contain the leaves data that are stored on a separate
+/// array instead. This layout is cache-friendly and can be traversed without any allocation.
+/// A `LinearBVT` can be converted from and to a `BVT`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct LinearBVT<T, BV> {
+    nodes: Vec<LinearBVTNode<BV>>,
+    data: Vec<T>,
+}
+
+impl<T, BV> LinearBVT<T, BV> {
+    /// Creates a linear BVT from its nodes and the data referenced by its leaves.
+    ///
+    /// Panics if the nodes are not ordered in depth-first order, or if they reference
+    /// out-of-bounds nodes or data.
+    pub fn from_raw_parts(nodes: Vec<LinearBVTNode<BV>>, data: Vec<T>) -> Self {
+        for (i, node) in nodes.iter().enumerate() {
+            assert!(
+                node.escape as usize > i && node.escape as usize <= nodes.len(),
+                "Invalid escape index on a linear BVT node."
+            );
+
+            if node.is_leaf() {
+                assert!(
+                    (node.right_or_data as usize) < data.len(),
+                    "Invalid data index on a linear BVT leaf."
+                );
+            } else {
+                assert!(
+                    node.right_or_data as usize > i + 1
+                        && (node.right_or_data as usize) < node.escape as usize,
+                    "Invalid right child index on a linear BVT node."
+                );
+            }
+        }
+
+        LinearBVT { nodes, data }
+    }
+
+    /// Creates a linear BVT from the given BVT.
+    ///
+    /// The i-th element of the data of the resulting linear BVT is the data of the i-th leaf of `bvt`.
+    pub fn from_bvt(bvt: &BVT<T, BV>) -> Self
+    where
+        T: Clone,
+        BV: Clone,
+    {
+        let mut nodes = Vec::with_capacity(bvt.leaves().len() * 2);
+        let data = bvt.leaves().iter().map(|l| l.data().clone()).collect();
+
+        if let Some(root) = bvt.root() {
+            Self::push_subtree(bvt, root, &mut nodes);
+        }
+
+        LinearBVT { nodes, data }
+    }
+
+    fn push_subtree(bvt: &BVT<T, BV>, node: BVTNodeId, out: &mut Vec<LinearBVTNode<BV>>)
+    where
+        BV: Clone,
+    {
+        let id = out.len();
+        let bounding_volume = bvt.content(node).0.clone();
+
+        match node {
+            BVTNodeId::Leaf(i) => out.push(LinearBVTNode {
+                bounding_volume,
+                right_or_data: i as u32,
+                escape: id as u32 + 1,
+                is_leaf: 1,
+            }),
+            BVTNodeId::Internal(_) => {
+                out.push(LinearBVTNode {
+                    bounding_volume,
+                    right_or_data: 0,
+                    escape: 0,
+                    is_leaf: 0,
+                });
+                Self::push_subtree(bvt, bvt.child(0, node), out);
+                out[id].right_or_data = out.len() as u32;
+                Self::push_subtree(bvt, bvt.child(1, node), out);
+                out[id].escape = out.len() as u32;
+            }
+        }
+    }
+
+    /// Converts this linear BVT into a `BVT`.
+    ///
+    /// Panics if some data are not referenced by exactly one leaf.
+    pub fn into_bvt(self) -> BVT<T, BV>
+    where
+        BV: Clone,
+    {
+        let mut internal_ids = Vec::with_capacity(self.nodes.len());
+        let mut num_internals = 0;
+
+        for node in &self.nodes {
+            internal_ids.push(num_internals);
+
+            if !node.is_leaf() {
+                num_internals += 1;
+            }
+        }
+
+        let node_id = |i: usize| {
+            let node = &self.nodes[i];
+            if node.is_leaf() {
+                BVTNodeId::Leaf(node.right_or_data as usize)
+            } else {
+                BVTNodeId::Internal(internal_ids[i])
+            }
+        };
+
+        let mut internals = Vec::with_capacity(num_internals);
+        let mut leaf_bvs = vec![None; self.data.len()];
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.is_leaf() {
+                let bv = &mut leaf_bvs[node.right_or_data as usize];
+                assert!(
+                    bv.is_none(),
+                    "Linear BVT data referenced by several leaves."
+                );
+                *bv = Some(node.bounding_volume.clone());
+            } else {
+                internals.push((
+                    node.bounding_volume.clone(),
+                    node_id(i + 1),
+                    node_id(node.right_or_data as usize),
+                ));
+            }
+        }
+
+        let root = if self.nodes.is_empty() {
+            BVTNodeId::Leaf(0)
+        } else {
+            node_id(0)
+        };
+        let leaves = self
+            .data
+            .into_iter()
+            .zip(leaf_bvs)
+            .map(|(data, bv)| {
+                (
+                    data,
+                    bv.expect("Linear BVT data not referenced by any leaf."),
+                )
+            })
+            .collect();
+
+        BVT::from_raw_parts(root, internals, leaves)
+    }
+
+    /// The nodes of this linear BVT, in depth-first order.
+    #[inline]
+    pub fn nodes(&self) -> &[LinearBVTNode<BV>] {
+        &self.nodes
+    }
+
+    /// The data referenced by the leaves of this linear BVT.
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutable reference to the data referenced by the leaves of this linear BVT.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Reference to the bounding volume of the tree root.
+    #[inline]
+    pub fn root_bounding_volume(&self) -> Option<&BV> {
+        self.nodes.first().map(|n| &n.bounding_volume)
+    }
+}
+
+impl<'a, T: Clone, BV: Clone> From<&'a BVT<T, BV>> for LinearBVT<T, BV> {
+    fn from(bvt: &'a BVT<T, BV>) -> Self {
+        Self::from_bvt(bvt)
+    }
+}
+
+impl<T, BV: Clone> From<LinearBVT<T, BV>> for BVT<T, BV> {
+    fn from(bvt: LinearBVT<T, BV>) -> Self {
+        bvt.into_bvt()
+    }
+}
+
+impl<T, BV> BVH<T, BV> for LinearBVT<T, BV> {
+    type Node = usize;
+
+    fn root(&self) -> Option<Self::Node> {
+        if self.nodes.is_empty() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+
+    fn num_children(&self, node: Self::Node) -> usize {
+        if self.nodes[node].is_leaf() {
+            0
+        } else {
+            2
+        }
+    }
+
+    fn child(&self, i: usize, node: Self::Node) -> Self::Node {
+        let node_ref = &self.nodes[node];
+        assert!(!node_ref.is_leaf(), "Linear BVT child index out of bounds.");
+
+        if i == 0 {
+            node + 1
+        } else {
+            node_ref.right_or_data as usize
+        }
+    }
+
+    fn content(&self, node: Self::Node) -> (&BV, Option<&T>) {
+        let node = &self.nodes[node];
+
+        if node.is_leaf() {
+            (
+                &node.bounding_volume,
+                Some(&self.data[node.right_or_data as usize]),
+            )
+        } else {
+            (&node.bounding_volume, None)
+        }
+    }
+
+    fn visit(&self, visitor: &mut impl Visitor<T, BV>) {
+        // Stack-less traversal relying on the escape indices.
+        let mut curr = 0;
+
+        while curr < self.nodes.len() {
+            let (bv, data) = self.content(curr);
+
+            statistics::count_bv_test();
+            match visitor.visit(bv, data) {
+                VisitStatus::Continue if !self.nodes[curr].is_leaf() => curr += 1,
+                VisitStatus::Continue | VisitStatus::Stop => {
+                    curr = self.nodes[curr].escape as usize
+                }
+                VisitStatus::ExitEarly => return,
+            }
+        }
+    }
+}
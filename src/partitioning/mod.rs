@@ -1,8 +1,9 @@
 //! Spatial partitioning tools.
 
-pub use self::bvh::{BVHImpl, BVH};
-pub use self::bvt::{BVTNodeId, BinaryPartition, BVT};
+pub use self::bvh::{BVHImpl, BestFirstSearchQueue, BVH};
+pub use self::bvt::{BVTNodeId, BVTStats, BinaryPartition, BVT};
 pub use self::dbvt::{DBVTLeaf, DBVTLeafId, DBVTNodeId, DBVT};
+pub use self::linear_bvt::{LinearBVT, LinearBVTNode};
 pub use self::visitor::{
     BestFirstVisitStatus, BestFirstVisitor, SimultaneousVisitor, VisitStatus, Visitor,
 };
@@ -10,4 +11,5 @@ pub use self::visitor::{
 mod bvh;
 mod bvt;
 mod dbvt;
+mod linear_bvt;
 mod visitor;
@@ -1,29 +1,53 @@
 use na::RealField;
 use std::any::Any;
+use std::time::Duration;
 
 use crate::math::Point;
-use crate::query::{Ray, RayIntersection};
+use crate::query::{Ray, RayCast, RayIntersection};
 
+/// The handle of a proxy, i.e., a bounding volume with its associated data, on a broad phase.
+///
+/// Handles are allocated by the broad phase itself when calling `BroadPhase::create_proxy`, so
+/// the user does not have to manage identifiers. The wrapped integer is only meant to be read
+/// or written by broad-phase implementations.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BroadPhaseProxyHandle(pub usize);
 
 impl BroadPhaseProxyHandle {
+    /// A handle that does not identify any proxy.
     #[inline]
     pub fn invalid() -> Self {
         BroadPhaseProxyHandle(usize::max_value())
     }
 
+    /// Returns `true` if this handle is equal to `BroadPhaseProxyHandle::invalid()`.
     #[inline]
     pub fn is_invalid(&self) -> bool {
         self.0 == usize::max_value()
     }
 
+    /// The integer identifying this proxy on its broad phase.
     #[inline]
     pub fn uid(&self) -> usize {
         self.0
     }
 }
 
+/// The amount of work a broad phase may perform during a call to `BroadPhase::update_with_budget`.
+///
+/// At least one pending proxy is processed by each call, even if the budget is zero, so that
+/// repeated calls always end up completing the update.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BroadPhaseUpdateBudget {
+    /// At most the given number of proxies with a new or modified bounding volume are processed.
+    Proxies(usize),
+    /// Proxies are processed until the given duration has elapsed.
+    ///
+    /// The elapsed time is measured with `std::time::Instant`, so this is not supported on
+    /// platforms without a clock, e.g., `wasm32-unknown-unknown`.
+    Time(Duration),
+}
+
 /// Proximity handling for BroadPhase updates.
 pub trait BroadPhaseInterferenceHandler<T> {
     /// A pre-filter that may cheaply discard objects before checking for bounding volume
@@ -38,14 +62,24 @@ pub trait BroadPhaseInterferenceHandler<T> {
 }
 
 /// Trait all broad phase must implement.
+///
+/// Proxy creation and bounding volume modifications are deferred: the returned handles are valid
+/// immediately but the corresponding interferences are only detected by the next call to
+/// `update`. Removals take effect immediately.
 pub trait BroadPhase<N: RealField + Copy, BV, T>: Any + Sync + Send {
     /// Tells the broad phase to add a bounding-volume at the next update.
+    ///
+    /// The returned handle identifies the new proxy until it is removed, after which it may be
+    /// reused for another proxy.
     fn create_proxy(&mut self, bv: BV, data: T) -> BroadPhaseProxyHandle;
 
     /// Retrieves the bounding volume and data associated to the given proxy.
     fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&BV, &T)>;
 
     /// Tells the broad phase to remove the given set of handles.
+    ///
+    /// The `removal_handler` is called with the data of both proxies of each interference
+    /// involving a removed proxy.
     fn remove(
         &mut self,
         handles: &[BroadPhaseProxyHandle],
@@ -64,6 +98,30 @@ pub trait BroadPhase<N: RealField + Copy, BV, T>: Any + Sync + Send {
     /// Updates the object additions, removals, and interferences detection.
     fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>);
 
+    /// Performs part of an update, stopping once the given budget is exhausted.
+    ///
+    /// This spreads the insertion of a large number of proxies over several frames. The proxies
+    /// not processed yet are not reported by the broad phase queries, and their interferences
+    /// are detected by subsequent calls to `update_with_budget` or `update`. Returns `true` if
+    /// all the deferred operations have been processed, i.e., if the broad phase is up to date.
+    ///
+    /// The default implementation performs a complete update.
+    fn update_with_budget(
+        &mut self,
+        handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+        budget: BroadPhaseUpdateBudget,
+    ) -> bool {
+        let _ = budget;
+        self.update(handler);
+        true
+    }
+
+    /// Releases the memory kept by this broad phase for proxies and pairs that were removed.
+    ///
+    /// Broad phases usually keep their buffers allocated after objects are removed so they can
+    /// be reused. The default implementation does nothing.
+    fn shrink_to_fit(&mut self) {}
+
     /*
      * FIXME: the following are not flexible enough.
      */
@@ -74,9 +132,54 @@ pub trait BroadPhase<N: RealField + Copy, BV, T>: Any + Sync + Send {
     /// Collects every object which might intersect a given ray.
     fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>);
 
+    /// Collects every object which bounding volume intersects a given ray, together with the
+    /// time of impact of the ray with this bounding volume.
+    ///
+    /// The objects are sorted by increasing time of impact. Because the bounding volume of an
+    /// object contains it, this time of impact is a lower bound of the time of impact with the
+    /// object itself.
+    fn interferences_with_ray_sorted<'a>(
+        &'a self,
+        ray: &Ray<N>,
+        max_toi: N,
+        out: &mut Vec<(N, &'a T)>,
+    ) where
+        BV: RayCast<N> + 'a;
+
     /// Collects every object which might contain a given point.
     fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>);
 
+    /// Collects the handles of every proxy which might intersect a given bounding volume.
+    ///
+    /// If `filter` is given, only the proxies with data satisfying this predicate are collected.
+    fn interference_handles_with_bounding_volume(
+        &self,
+        bv: &BV,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    );
+
+    /// Collects the handles of every proxy which might intersect a given ray.
+    ///
+    /// If `filter` is given, only the proxies with data satisfying this predicate are collected.
+    fn interference_handles_with_ray(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    );
+
+    /// Collects the handles of every proxy which might contain a given point.
+    ///
+    /// If `filter` is given, only the proxies with data satisfying this predicate are collected.
+    fn interference_handles_with_point(
+        &self,
+        point: &Point<N>,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    );
+
     fn first_interference_with_ray<'a, 'b>(
         &'a self,
         ray: &'b Ray<N>,
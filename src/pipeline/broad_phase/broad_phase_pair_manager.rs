@@ -0,0 +1,189 @@
+use crate::pipeline::broad_phase::BroadPhaseProxyHandle;
+use crate::utils::{DeterministicState, SortedPair};
+use slab::{self, Slab};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// The persistent identifier of a pair of proxies detected by a broad phase.
+///
+/// This identifier remains valid as long as the pair keeps being reported by the broad phase,
+/// so it can be used to index user-defined per-pair caches. It may be reused for another pair
+/// once this pair stops interfering.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BroadPhasePairId(pub usize);
+
+/// When the pairs of a broad phase are re-checked for interference and evicted.
+///
+/// A pair stops being reported once the bounding volumes of its proxies no longer intersect,
+/// or once the pair is no longer allowed by the interference handler. With the `Lazy` policy,
+/// only the pairs for which a proxy moved since the last update are re-checked. This is
+/// enough to keep the set of pairs exact with the default pair filters, but pairs rejected by
+/// an interference handler whose result changed over time are kept until one of their proxies
+/// moves.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum BroadPhasePairEvictionPolicy {
+    /// Re-check only the pairs involving a proxy updated since the last update.
+    #[default]
+    Lazy,
+    /// Re-check every pair every `n` updates, in addition to the `Lazy` checks.
+    ///
+    /// A period of zero is handled as a period of one.
+    Periodic(usize),
+}
+
+struct PairEntry {
+    id: usize,
+    up_to_date: bool,
+}
+
+/// The set of pairs of proxies which bounding volumes currently intersect.
+pub struct BroadPhasePairManager {
+    ids: HashMap<SortedPair<BroadPhaseProxyHandle>, PairEntry, DeterministicState>,
+    pairs: Slab<SortedPair<BroadPhaseProxyHandle>>,
+}
+
+impl BroadPhasePairManager {
+    /// Creates a new empty pair manager.
+    pub fn new() -> Self {
+        BroadPhasePairManager {
+            ids: HashMap::with_hasher(DeterministicState::new()),
+            pairs: Slab::new(),
+        }
+    }
+
+    /// The number of pairs on this pair manager.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// The number of pairs this pair manager can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.pairs.capacity()
+    }
+
+    /// Returns `true` if this pair manager does not contain any pair.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns `true` if the pair formed by the proxies `a` and `b` is on this pair manager.
+    ///
+    /// The order of `a` and `b` does not matter.
+    #[inline]
+    pub fn contains_pair(&self, a: BroadPhaseProxyHandle, b: BroadPhaseProxyHandle) -> bool {
+        self.ids.contains_key(&SortedPair::new(a, b))
+    }
+
+    /// The persistent identifier of the pair formed by the proxies `a` and `b`, if it exists.
+    ///
+    /// The order of `a` and `b` does not matter.
+    #[inline]
+    pub fn pair_id(
+        &self,
+        a: BroadPhaseProxyHandle,
+        b: BroadPhaseProxyHandle,
+    ) -> Option<BroadPhasePairId> {
+        self.ids
+            .get(&SortedPair::new(a, b))
+            .map(|e| BroadPhasePairId(e.id))
+    }
+
+    /// The proxies of the pair identified by `id`, if it exists.
+    ///
+    /// The proxies are sorted in increasing order of their handles.
+    #[inline]
+    pub fn pair(
+        &self,
+        id: BroadPhasePairId,
+    ) -> Option<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)> {
+        self.pairs.get(id.0).map(|pair| **pair)
+    }
+
+    /// An iterator through all the pairs on this pair manager, together with their identifiers.
+    ///
+    /// Pairs are yielded in increasing order of their identifiers, so the iteration order
+    /// only changes when pairs are added or removed.
+    #[inline]
+    pub fn iter(&self) -> BroadPhasePairs<'_> {
+        BroadPhasePairs {
+            iter: self.pairs.iter(),
+        }
+    }
+
+    // Returns `true` if the pair did not exist before.
+    pub(crate) fn insert(&mut self, pair: SortedPair<BroadPhaseProxyHandle>) -> bool {
+        match self.ids.entry(pair) {
+            Entry::Occupied(entry) => {
+                entry.into_mut().up_to_date = true;
+                false
+            }
+            Entry::Vacant(entry) => {
+                let id = self.pairs.insert(pair);
+                let _ = entry.insert(PairEntry {
+                    id,
+                    up_to_date: true,
+                });
+                true
+            }
+        }
+    }
+
+    // Releases the memory of the removed pairs.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.ids.shrink_to_fit();
+        self.pairs.shrink_to_fit();
+    }
+
+    // Keeps only the pairs for which `f` returns `true`. The boolean given to `f`
+    // is the pair's up-to-date flag.
+    pub(crate) fn retain(
+        &mut self,
+        mut f: impl FnMut(&SortedPair<BroadPhaseProxyHandle>, &mut bool) -> bool,
+    ) {
+        let pairs = &mut self.pairs;
+        self.ids.retain(|pair, entry| {
+            let retain = f(pair, &mut entry.up_to_date);
+
+            if !retain {
+                let _ = pairs.remove(entry.id);
+            }
+
+            retain
+        })
+    }
+}
+
+impl Default for BroadPhasePairManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator through the pairs of a `BroadPhasePairManager`.
+pub struct BroadPhasePairs<'a> {
+    iter: slab::Iter<'a, SortedPair<BroadPhaseProxyHandle>>,
+}
+
+impl<'a> Iterator for BroadPhasePairs<'a> {
+    type Item = (
+        BroadPhasePairId,
+        BroadPhaseProxyHandle,
+        BroadPhaseProxyHandle,
+    );
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(id, pair)| (BroadPhasePairId(id), pair.0, pair.1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -1,26 +1,30 @@
 use crate::bounding_volume::BoundingVolume;
-use crate::math::Point;
+use crate::math::{Isometry, Point};
 use crate::partitioning::{DBVTLeaf, DBVTLeafId, BVH, DBVT};
 use crate::pipeline::broad_phase::{
-    BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhasePairEvictionPolicy, BroadPhasePairManager,
+    BroadPhaseProxyHandle, BroadPhaseUpdateBudget,
 };
 use crate::query::visitors::{
     BoundingVolumeInterferencesCollector, PointInterferencesCollector, RayInterferencesCollector,
     RayIntersectionCostFnVisitor,
 };
 use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
-use crate::utils::{DeterministicState, SortedPair};
+use crate::utils::SortedPair;
 use na::RealField;
 use slab::Slab;
 use std::any::Any;
-use std::collections::hash_map::Entry;
-use std::collections::{HashMap, VecDeque};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::mem;
+use std::time::Instant;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum ProxyStatus {
     OnStaticTree(DBVTLeafId),
     OnDynamicTree(DBVTLeafId, usize),
-    // The usize is the location of the corresponding on proxies_to_update
+    // The usize is the location of the corresponding leaf on leaves_to_update, offset
+    // by the number of leaves already popped from its front.
     Detached(Option<usize>),
     Deleted,
 }
@@ -61,14 +65,19 @@ pub struct DBVTBroadPhase<N: RealField + Copy, BV, T> {
     // DBVT for static objects.
     stree: DBVT<N, BroadPhaseProxyHandle, BV>,
     // Pairs detected.
-    pairs: HashMap<SortedPair<BroadPhaseProxyHandle>, bool, DeterministicState>,
+    pairs: BroadPhasePairManager,
     // The margin added to each bounding volume.
     margin: N,
     purge_all: bool,
+    eviction_policy: BroadPhasePairEvictionPolicy,
+    // Number of updates since the last periodic purge.
+    updates_since_purge: usize,
 
     // Just to avoid dynamic allocations.
     collector: Vec<BroadPhaseProxyHandle>,
-    leaves_to_update: Vec<DBVTLeaf<N, BroadPhaseProxyHandle, BV>>,
+    leaves_to_update: VecDeque<DBVTLeaf<N, BroadPhaseProxyHandle, BV>>,
+    // Number of leaves popped from the front of `leaves_to_update` since it was last empty.
+    leaves_offset: usize,
     proxies_to_update: VecDeque<(BroadPhaseProxyHandle, BV)>,
 }
 
@@ -83,10 +92,13 @@ where
             proxies: Slab::new(),
             tree: DBVT::new(),
             stree: DBVT::new(),
-            pairs: HashMap::with_hasher(DeterministicState::new()),
+            pairs: BroadPhasePairManager::new(),
             purge_all: false,
+            eviction_policy: BroadPhasePairEvictionPolicy::Lazy,
+            updates_since_purge: 0,
             collector: Vec::new(),
-            leaves_to_update: Vec::new(),
+            leaves_to_update: VecDeque::new(),
+            leaves_offset: 0,
             proxies_to_update: VecDeque::new(),
             margin,
         }
@@ -98,6 +110,51 @@ where
         self.pairs.len()
     }
 
+    /// The set of pairs of proxies which bounding volumes currently intersect.
+    ///
+    /// Each pair has a persistent identifier that remains valid until the pair stops
+    /// interfering, so it can be used to index per-pair caches.
+    #[inline]
+    pub fn pair_manager(&self) -> &BroadPhasePairManager {
+        &self.pairs
+    }
+
+    /// Number of proxies on this broad phase, including those added since the last update.
+    #[inline]
+    pub fn num_proxies(&self) -> usize {
+        self.proxies.len()
+    }
+
+    /// The policy used to decide when the detected pairs are re-checked and evicted.
+    #[inline]
+    pub fn pair_eviction_policy(&self) -> BroadPhasePairEvictionPolicy {
+        self.eviction_policy
+    }
+
+    /// Sets the policy used to decide when the detected pairs are re-checked and evicted.
+    #[inline]
+    pub fn set_pair_eviction_policy(&mut self, policy: BroadPhasePairEvictionPolicy) {
+        self.eviction_policy = policy;
+        self.updates_since_purge = 0;
+    }
+
+    /// Re-checks every detected pair now, and evicts those that stopped interfering.
+    ///
+    /// The `handler` is notified of each evicted pair. Proxies and bounding volumes modified since
+    /// the last update are not taken into account before the next call to `update`, and the pairs
+    /// involving proxies still waiting for their re-insertion by a budgeted update are kept as-is.
+    pub fn purge(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        self.purge_all = true;
+        self.purge_some_contact_pairs(handler);
+    }
+
+    /// Returns `true` if the bounding volumes of the proxies `a` and `b` were found to be
+    /// interfering during the last update.
+    #[inline]
+    pub fn contains_pair(&self, a: BroadPhaseProxyHandle, b: BroadPhaseProxyHandle) -> bool {
+        self.pairs.contains_pair(a, b)
+    }
+
     fn purge_some_contact_pairs(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
         let purge_all = self.purge_all;
         let proxies = &self.proxies;
@@ -116,6 +173,12 @@ where
                     .get(pair.1.uid())
                     .expect("DBVT broad phase: internal error.");
 
+                // The proxies waiting for their re-insertion are not on any tree yet.
+                if proxy1.is_detached() || proxy2.is_detached() {
+                    *up_to_date = false;
+                    return true;
+                }
+
                 if purge_all || proxy1.updated || proxy2.updated {
                     if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
                         let l1 = match proxy1.status {
@@ -144,6 +207,9 @@ where
             *up_to_date = false;
             retain
         });
+
+        self.purge_all = false;
+        self.updates_since_purge = 0;
     }
 
     fn update_activation_states(&mut self) {
@@ -163,15 +229,10 @@ where
             }
         }
     }
-}
 
-impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
-where
-    N: RealField + Copy,
-    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
-    T: Any + Send + Sync + Clone,
-{
-    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+    // Removes from the trees all the proxies that have been modified, and queues their new
+    // leaves on `leaves_to_update`.
+    fn detach_proxies_to_update(&mut self) {
         /*
          * Remove from the trees all nodes that have been deleted or modified.
          */
@@ -182,20 +243,20 @@ where
                     ProxyStatus::OnStaticTree(leaf) => {
                         let mut leaf = self.stree.remove(leaf);
                         leaf.bounding_volume = bv;
-                        self.leaves_to_update.push(leaf);
+                        self.leaves_to_update.push_back(leaf);
                     }
                     ProxyStatus::OnDynamicTree(leaf, _) => {
                         let mut leaf = self.tree.remove(leaf);
                         leaf.bounding_volume = bv;
-                        self.leaves_to_update.push(leaf);
+                        self.leaves_to_update.push_back(leaf);
                     }
                     ProxyStatus::Detached(None) => {
                         let leaf = DBVTLeaf::new(bv, handle);
-                        self.leaves_to_update.push(leaf);
+                        self.leaves_to_update.push_back(leaf);
                     }
                     ProxyStatus::Detached(Some(id)) => {
                         let leaf = DBVTLeaf::new(bv, handle);
-                        self.leaves_to_update[id] = leaf;
+                        self.leaves_to_update[id - self.leaves_offset] = leaf;
                         set_status = false;
                     }
                     ProxyStatus::Deleted => {
@@ -206,58 +267,165 @@ where
                 proxy.updated = true;
 
                 if set_status {
-                    proxy.status = ProxyStatus::Detached(Some(self.leaves_to_update.len() - 1));
+                    proxy.status = ProxyStatus::Detached(Some(
+                        self.leaves_offset + self.leaves_to_update.len() - 1,
+                    ));
                 }
             }
         }
+    }
 
-        /*
-         * Re-insert outdated nodes one by one and collect interferences at the same time.
-         */
-        let some_leaves_updated = self.leaves_to_update.len() != 0;
-        for leaf in self.leaves_to_update.drain(..) {
+    // Inserts a leaf removed from the trees back into the dynamic tree, and reports its new
+    // interferences.
+    fn reinsert_leaf(
+        &mut self,
+        leaf: DBVTLeaf<N, BroadPhaseProxyHandle, BV>,
+        handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+    ) {
+        // The proxy was removed before being re-inserted.
+        if leaf.data.is_invalid() {
+            return;
+        }
+
+        {
+            let proxy1 = &self.proxies[leaf.data.uid()];
             {
-                let proxy1 = &self.proxies[leaf.data.uid()];
-                {
-                    let mut visitor = BoundingVolumeInterferencesCollector::new(
-                        &leaf.bounding_volume,
-                        &mut self.collector,
-                    );
-
-                    self.tree.visit(&mut visitor);
-                    self.stree.visit(&mut visitor);
-                }
+                let mut visitor = BoundingVolumeInterferencesCollector::new(
+                    &leaf.bounding_volume,
+                    &mut self.collector,
+                );
 
-                // Event generation.
-                for proxy_key2 in self.collector.iter() {
-                    let proxy2 = &self.proxies[proxy_key2.uid()];
+                self.tree.visit(&mut visitor);
+                self.stree.visit(&mut visitor);
+            }
 
-                    if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
-                        match self.pairs.entry(SortedPair::new(leaf.data, *proxy_key2)) {
-                            Entry::Occupied(entry) => *entry.into_mut() = true,
-                            Entry::Vacant(entry) => {
-                                handler.interference_started(&proxy1.data, &proxy2.data);
-                                let _ = entry.insert(true);
-                            }
-                        }
+            // Event generation.
+            for proxy_key2 in self.collector.iter() {
+                let proxy2 = &self.proxies[proxy_key2.uid()];
+
+                if handler.is_interference_allowed(&proxy1.data, &proxy2.data) {
+                    if self.pairs.insert(SortedPair::new(leaf.data, *proxy_key2)) {
+                        handler.interference_started(&proxy1.data, &proxy2.data);
                     }
                 }
-
-                self.collector.clear();
             }
 
-            let proxy1 = &mut self.proxies[leaf.data.uid()];
-            assert!(proxy1.is_detached());
-            let leaf = self.tree.insert(leaf);
-            proxy1.status = ProxyStatus::OnDynamicTree(leaf, DEACTIVATION_THRESHOLD);
+            self.collector.clear();
+        }
+
+        let proxy1 = &mut self.proxies[leaf.data.uid()];
+        assert!(proxy1.is_detached());
+        let leaf = self.tree.insert(leaf);
+        proxy1.status = ProxyStatus::OnDynamicTree(leaf, DEACTIVATION_THRESHOLD);
+    }
+
+    // Purges the pairs and updates the activation states once all the leaves are re-inserted.
+    fn finish_update(
+        &mut self,
+        some_leaves_updated: bool,
+        handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+    ) {
+        if let BroadPhasePairEvictionPolicy::Periodic(period) = self.eviction_policy {
+            self.updates_since_purge += 1;
+
+            if self.updates_since_purge >= period {
+                self.purge_all = true;
+            }
         }
 
-        if some_leaves_updated {
+        if some_leaves_updated || self.purge_all {
             self.purge_some_contact_pairs(handler);
         }
         self.update_activation_states();
     }
 
+    /// Removes from `handles[first..]` the handles of the proxies not satisfying `filter`.
+    fn filter_handles(
+        &self,
+        first: usize,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        handles: &mut Vec<BroadPhaseProxyHandle>,
+    ) {
+        if let Some(filter) = filter {
+            let mut i = first;
+
+            while i < handles.len() {
+                if filter(&self.proxies[handles[i].uid()].data) {
+                    i += 1;
+                } else {
+                    let _ = handles.swap_remove(i);
+                }
+            }
+        }
+    }
+}
+
+impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
+where
+    N: RealField + Copy,
+    BV: BoundingVolume<N> + RayCast<N> + PointQuery<N> + Any + Send + Sync + Clone,
+    T: Any + Send + Sync + Clone,
+{
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<T>) {
+        self.detach_proxies_to_update();
+
+        /*
+         * Re-insert outdated nodes one by one and collect interferences at the same time.
+         */
+        let some_leaves_updated = self.leaves_to_update.len() != 0;
+        let mut leaves = mem::take(&mut self.leaves_to_update);
+
+        for leaf in leaves.drain(..) {
+            self.reinsert_leaf(leaf, handler);
+        }
+
+        self.leaves_to_update = leaves;
+        self.leaves_offset = 0;
+
+        self.finish_update(some_leaves_updated, handler);
+    }
+
+    fn update_with_budget(
+        &mut self,
+        handler: &mut dyn BroadPhaseInterferenceHandler<T>,
+        budget: BroadPhaseUpdateBudget,
+    ) -> bool {
+        self.detach_proxies_to_update();
+
+        let some_leaves_updated = !self.leaves_to_update.is_empty();
+        let start = match budget {
+            BroadPhaseUpdateBudget::Time(_) => Some(Instant::now()),
+            BroadPhaseUpdateBudget::Proxies(_) => None,
+        };
+        let mut num_processed = 0;
+
+        // The leaves are re-inserted in the order they were queued, so that the proxies modified
+        // at each update do not delay the insertion of the older ones indefinitely. At least one
+        // leaf is processed so that repeated calls always make progress, even with a zero budget.
+        while !self.leaves_to_update.is_empty() {
+            let exhausted = match budget {
+                BroadPhaseUpdateBudget::Proxies(max) => num_processed >= max,
+                BroadPhaseUpdateBudget::Time(max) => {
+                    start.map(|t| t.elapsed() >= max).unwrap_or(false)
+                }
+            };
+
+            if exhausted && num_processed > 0 {
+                return false;
+            }
+
+            let leaf = self.leaves_to_update.pop_front().unwrap();
+            self.leaves_offset += 1;
+            self.reinsert_leaf(leaf, handler);
+            num_processed += 1;
+        }
+
+        self.leaves_offset = 0;
+
+        self.finish_update(some_leaves_updated, handler);
+        true
+    }
+
     /// Retrieves the bounding volume and data associated to the given proxy.
     fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&BV, &T)> {
         let proxy = self.proxies.get(handle.uid())?;
@@ -289,6 +457,10 @@ where
                     ProxyStatus::OnDynamicTree(leaf, _) => {
                         let _ = self.tree.remove(leaf);
                     }
+                    ProxyStatus::Detached(Some(id)) => {
+                        self.leaves_to_update[id - self.leaves_offset].data =
+                            BroadPhaseProxyHandle::invalid();
+                    }
                     _ => {}
                 }
 
@@ -384,6 +556,16 @@ where
         self.purge_all = true;
     }
 
+    fn shrink_to_fit(&mut self) {
+        self.proxies.shrink_to_fit();
+        self.tree.shrink_to_fit();
+        self.stree.shrink_to_fit();
+        self.pairs.shrink_to_fit();
+        self.collector.shrink_to_fit();
+        self.leaves_to_update.shrink_to_fit();
+        self.proxies_to_update.shrink_to_fit();
+    }
+
     fn interferences_with_bounding_volume<'a>(&'a self, bv: &BV, out: &mut Vec<&'a T>) {
         let mut collector = Vec::new();
 
@@ -414,6 +596,31 @@ where
         }
     }
 
+    fn interferences_with_ray_sorted<'a>(
+        &'a self,
+        ray: &Ray<N>,
+        max_toi: N,
+        out: &mut Vec<(N, &'a T)>,
+    ) where
+        BV: 'a,
+    {
+        let mut handles = Vec::new();
+        self.interference_handles_with_ray(ray, max_toi, None, &mut handles);
+
+        let first = out.len();
+        let identity = Isometry::identity();
+
+        for handle in handles {
+            if let Some((bv, data)) = self.proxy(handle) {
+                if let Some(toi) = bv.toi_with_ray(&identity, ray, max_toi, true) {
+                    out.push((toi, data))
+                }
+            }
+        }
+
+        out[first..].sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    }
+
     fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>) {
         let mut collector = Vec::new();
 
@@ -429,6 +636,61 @@ where
         }
     }
 
+    fn interference_handles_with_bounding_volume(
+        &self,
+        bv: &BV,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    ) {
+        let first = out.len();
+
+        {
+            let mut visitor = BoundingVolumeInterferencesCollector::new(bv, out);
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.filter_handles(first, filter, out)
+    }
+
+    fn interference_handles_with_ray(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    ) {
+        let first = out.len();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(ray, max_toi, out);
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.filter_handles(first, filter, out)
+    }
+
+    fn interference_handles_with_point(
+        &self,
+        point: &Point<N>,
+        filter: Option<&dyn Fn(&T) -> bool>,
+        out: &mut Vec<BroadPhaseProxyHandle>,
+    ) {
+        let first = out.len();
+
+        {
+            let mut visitor = PointInterferencesCollector::new(point, out);
+
+            self.tree.visit(&mut visitor);
+            self.stree.visit(&mut visitor);
+        }
+
+        self.filter_handles(first, filter, out)
+    }
+
     /// Returns the first object that interferes with a ray.
     fn first_interference_with_ray<'a, 'b>(
         &'a self,
@@ -2,15 +2,21 @@
 
 pub use self::query::{
     first_interference_with_ray, interferences_with_aabb, interferences_with_point,
-    interferences_with_ray, FirstInterferenceWithRay, InterferencesWithAABB,
-    InterferencesWithPoint, InterferencesWithRay,
+    interferences_with_ray, interferences_with_ray_sorted, FirstInterferenceWithRay,
+    InterferencesWithAABB, InterferencesWithPoint, InterferencesWithRay,
+    SortedInterferencesWithRay,
 };
+pub use self::query_pipeline::QueryPipeline;
 pub use setup::{
     create_proxies, default_broad_phase, default_interaction_graph, default_narrow_phase,
     remove_proxies,
 };
-pub use update::{perform_all_pipeline, perform_broad_phase, perform_narrow_phase};
+pub use update::{
+    perform_all_pipeline, perform_broad_phase, perform_broad_phase_with_budget,
+    perform_narrow_phase,
+};
 
 mod query;
+mod query_pipeline;
 mod setup;
 mod update;
@@ -1,15 +1,19 @@
 use na::RealField;
+use std::iter::Peekable;
 use std::vec::IntoIter;
 
 use crate::bounding_volume::AABB;
 use crate::math::Point;
 use crate::pipeline::broad_phase::BroadPhase;
-use crate::pipeline::object::{CollisionGroups, CollisionObjectRef, CollisionObjectSet};
+use crate::pipeline::object::{
+    CollisionGroups, CollisionObjectQueryFlags, CollisionObjectRef, CollisionObjectSet,
+};
 use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
 
 /// Returns an iterator yielding all the collision objects intersecting with the given ray.
 ///
-/// The result will only include collision objects in a group that can interact with the given `groups`.
+/// The result will only include collision objects in a group that can interact with the given `groups`,
+/// and with the `CollisionObjectQueryFlags::RAY_CAST` flag.
 pub fn interferences_with_ray<'a, 'b, N, Objects>(
     objects: &'a Objects,
     broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
@@ -57,7 +61,11 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(handle) = self.handles.next() {
             if let Some(co) = self.objects.collision_object(*handle) {
-                if co.collision_groups().can_interact_with_groups(self.groups) {
+                if co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::RAY_CAST)
+                    && co.collision_groups().can_interact_with_groups(self.groups)
+                {
                     let inter = co.shape().toi_and_normal_with_ray(
                         &co.position(),
                         self.ray,
@@ -76,9 +84,125 @@ where
     }
 }
 
+/// Returns an iterator yielding all the collision objects intersecting with the given ray, by
+/// increasing time of impact.
+///
+/// The candidates of the broad phase are ordered by the time of impact of the ray with their
+/// bounding volumes, and the exact ray cast with a candidate is only performed once every
+/// closer hit has been yielded. Stopping the iteration early thus avoids testing the candidates
+/// located further along the ray.
+///
+/// The result will only include collision objects in a group that can interact with the given `groups`,
+/// and with the `CollisionObjectQueryFlags::RAY_CAST` flag.
+pub fn interferences_with_ray_sorted<'a, 'b, N, Objects>(
+    objects: &'a Objects,
+    broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    ray: &'b Ray<N>,
+    max_toi: N,
+    groups: &'b CollisionGroups,
+) -> SortedInterferencesWithRay<'a, 'b, N, Objects>
+where
+    N: RealField + Copy,
+    Objects: CollisionObjectSet<N>,
+{
+    let mut candidates = Vec::new();
+    broad_phase.interferences_with_ray_sorted(ray, max_toi, &mut candidates);
+
+    SortedInterferencesWithRay {
+        ray,
+        max_toi,
+        groups,
+        objects,
+        candidates: candidates.into_iter().peekable(),
+        hits: Vec::new(),
+    }
+}
+
+/// Iterator through all the objects on the world that intersect a specific ray, by increasing
+/// time of impact.
+pub struct SortedInterferencesWithRay<'a, 'b, N: RealField + Copy, Objects: CollisionObjectSet<N>> {
+    ray: &'b Ray<N>,
+    max_toi: N,
+    objects: &'a Objects,
+    groups: &'b CollisionGroups,
+    candidates: Peekable<IntoIter<(N, &'a Objects::CollisionObjectHandle)>>,
+    // Hits found but not yielded yet because a closer one may still exist.
+    hits: Vec<(
+        Objects::CollisionObjectHandle,
+        &'a Objects::CollisionObject,
+        RayIntersection<N>,
+    )>,
+}
+
+impl<'a, 'b, N, Objects> SortedInterferencesWithRay<'a, 'b, N, Objects>
+where
+    N: RealField + Copy,
+    Objects: CollisionObjectSet<N>,
+{
+    fn closest_hit(&self) -> Option<(usize, N)> {
+        let mut best = None;
+
+        for (i, hit) in self.hits.iter().enumerate() {
+            match best {
+                Some((_, toi)) if toi <= hit.2.toi => {}
+                _ => best = Some((i, hit.2.toi)),
+            }
+        }
+
+        best
+    }
+}
+
+impl<'a, 'b, N, Objects> Iterator for SortedInterferencesWithRay<'a, 'b, N, Objects>
+where
+    N: RealField + Copy,
+    Objects: CollisionObjectSet<N>,
+{
+    type Item = (
+        Objects::CollisionObjectHandle,
+        &'a Objects::CollisionObject,
+        RayIntersection<N>,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let closest = self.closest_hit();
+            let next_bv_toi = self.candidates.peek().map(|c| c.0);
+
+            if let Some((i, toi)) = closest {
+                if next_bv_toi.map(|bv_toi| toi <= bv_toi).unwrap_or(true) {
+                    return Some(self.hits.swap_remove(i));
+                }
+            }
+
+            let (_, handle) = self.candidates.next()?;
+
+            if let Some(co) = self.objects.collision_object(*handle) {
+                if co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::RAY_CAST)
+                    && co.collision_groups().can_interact_with_groups(self.groups)
+                {
+                    let inter = co.shape().toi_and_normal_with_ray(
+                        &co.position(),
+                        self.ray,
+                        self.max_toi,
+                        true,
+                    );
+
+                    if let Some(inter) = inter {
+                        self.hits.push((*handle, co, inter));
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Returns an iterator yielding all the collision objects containing the given point.
 ///
-/// The result will only include collision objects in a group that can interact with the given `groups`.
+/// The result will only include collision objects in a group that can interact with the given `groups`,
+/// and with the `CollisionObjectQueryFlags::POINT_QUERY` flag.
 pub fn interferences_with_point<'a, 'b, N, Objects>(
     objects: &'a Objects,
     broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
@@ -119,7 +243,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(handle) = self.handles.next() {
             if let Some(co) = self.objects.collision_object(*handle) {
-                if co.collision_groups().can_interact_with_groups(self.groups)
+                if co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::POINT_QUERY)
+                    && co.collision_groups().can_interact_with_groups(self.groups)
                     && co.shape().contains_point(&co.position(), self.point)
                 {
                     return Some((*handle, co));
@@ -133,7 +260,8 @@ where
 
 /// Returns an iterator yielding all the collision objects with an AABB intersecting with the given AABB.
 ///
-/// The result will only include collision objects in a group that can interact with the given `groups`.
+/// The result will only include collision objects in a group that can interact with the given `groups`,
+/// and with the `CollisionObjectQueryFlags::INTERSECTION_QUERY` flag.
 pub fn interferences_with_aabb<'a, 'b, N, Objects>(
     objects: &'a Objects,
     broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
@@ -170,7 +298,11 @@ impl<'a, 'b, N: RealField + Copy, Objects: CollisionObjectSet<N>> Iterator
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(handle) = self.handles.next() {
             if let Some(co) = self.objects.collision_object(*handle) {
-                if co.collision_groups().can_interact_with_groups(self.groups) {
+                if co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::INTERSECTION_QUERY)
+                    && co.collision_groups().can_interact_with_groups(self.groups)
+                {
                     return Some((*handle, co));
                 }
             }
@@ -196,7 +328,8 @@ pub struct FirstInterferenceWithRay<'a, N: RealField + Copy, Objects: CollisionO
 
 /// Returns an the closest collision object intersecting with the given ray.
 ///
-/// The result will only include collision objects in a group that can interact with the given `groups`.
+/// The result will only include collision objects in a group that can interact with the given `groups`,
+/// and with the `CollisionObjectQueryFlags::RAY_CAST` flag.
 pub fn first_interference_with_ray<'a, 'b, N: RealField + Copy, Objects: CollisionObjectSet<N>>(
     objects: &'a Objects,
     broad_phase: &'a (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
@@ -207,7 +340,11 @@ pub fn first_interference_with_ray<'a, 'b, N: RealField + Copy, Objects: Collisi
     // Narrow phase
     let narrow_phase = move |handle: Objects::CollisionObjectHandle, ray: &Ray<N>, max_toi: N| {
         let co = objects.collision_object(handle)?;
-        if co.collision_groups().can_interact_with_groups(groups) {
+        if co
+            .query_flags()
+            .contains(CollisionObjectQueryFlags::RAY_CAST)
+            && co.collision_groups().can_interact_with_groups(groups)
+        {
             let inter = co
                 .shape()
                 .toi_and_normal_with_ray(&co.position(), ray, max_toi, true);
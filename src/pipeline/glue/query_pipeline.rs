@@ -0,0 +1,209 @@
+use na::RealField;
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
+use crate::pipeline::broad_phase::BroadPhase;
+use crate::pipeline::object::{CollisionObjectQueryFlags, CollisionObjectRef, CollisionObjectSet};
+use crate::query::{self, PointProjection, PointQuery, Proximity, Ray, RayCast, RayIntersection};
+use crate::shape::Shape;
+
+/// An immutable view of a set of collision objects and of the broad phase containing them.
+///
+/// This allows geometric queries to be performed on the objects without requiring mutable
+/// access to the structure owning them, e.g., the `CollisionWorld`. Every query accepts a
+/// `filter` closure called with the handle and the collision object before any exact
+/// geometric test is performed: objects for which it returns `false` are ignored.
+///
+/// The candidate objects are selected using the bounding volumes stored by the broad phase at
+/// the time of its last update, while exact tests use the current positions of the objects.
+pub struct QueryPipeline<'a, N: RealField + Copy, Objects: CollisionObjectSet<N>> {
+    objects: &'a Objects,
+    broad_phase: &'a dyn BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle>,
+}
+
+impl<'a, N: RealField + Copy, Objects: CollisionObjectSet<N>> QueryPipeline<'a, N, Objects> {
+    /// Creates a new query pipeline from a set of collision objects and the broad phase
+    /// containing their proxies.
+    pub fn new(
+        objects: &'a Objects,
+        broad_phase: &'a dyn BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle>,
+    ) -> Self {
+        QueryPipeline {
+            objects,
+            broad_phase,
+        }
+    }
+
+    /// Finds the collision object with the smallest time of impact with `ray`.
+    ///
+    /// Only objects hit before `max_toi` and with the `CollisionObjectQueryFlags::RAY_CAST`
+    /// flag are considered. If `solid` is `true`, a ray starting inside of an object will hit it
+    /// with a time of impact equal to zero.
+    pub fn cast_ray(
+        &self,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        mut filter: impl FnMut(Objects::CollisionObjectHandle, &Objects::CollisionObject) -> bool,
+    ) -> Option<(
+        Objects::CollisionObjectHandle,
+        &'a Objects::CollisionObject,
+        RayIntersection<N>,
+    )> {
+        let mut candidates = Vec::new();
+        self.broad_phase
+            .interferences_with_ray_sorted(ray, max_toi, &mut candidates);
+
+        let mut best_toi = max_toi;
+        let mut result = None;
+
+        for (bv_toi, handle) in candidates {
+            // The candidates are sorted so the remaining ones cannot be hit earlier.
+            if result.is_some() && bv_toi > best_toi {
+                break;
+            }
+
+            if let Some(co) = self.objects.collision_object(*handle) {
+                if !co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::RAY_CAST)
+                    || !filter(*handle, co)
+                {
+                    continue;
+                }
+
+                let inter = co
+                    .shape()
+                    .toi_and_normal_with_ray(co.position(), ray, best_toi, solid);
+
+                if let Some(inter) = inter {
+                    if result.is_none() || inter.toi < best_toi {
+                        best_toi = inter.toi;
+                        result = Some((*handle, co, inter));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Finds the collision object closest to `point`, and the projection of `point` on it.
+    ///
+    /// Only objects at a distance smaller than `max_dist` from `point` and with the
+    /// `CollisionObjectQueryFlags::POINT_QUERY` flag are considered. If `solid` is `true`, a point
+    /// located inside of an object is its own projection.
+    pub fn project_point(
+        &self,
+        point: &Point<N>,
+        max_dist: N,
+        solid: bool,
+        mut filter: impl FnMut(Objects::CollisionObjectHandle, &Objects::CollisionObject) -> bool,
+    ) -> Option<(
+        Objects::CollisionObjectHandle,
+        &'a Objects::CollisionObject,
+        PointProjection<N>,
+    )> {
+        let aabb = AABB::from_half_extents(*point, Vector::repeat(max_dist));
+        let mut handles = Vec::new();
+        self.broad_phase
+            .interferences_with_bounding_volume(&aabb, &mut handles);
+
+        let mut best_dist = max_dist;
+        let mut result = None;
+
+        for handle in handles {
+            if let Some(co) = self.objects.collision_object(*handle) {
+                if !co
+                    .query_flags()
+                    .contains(CollisionObjectQueryFlags::POINT_QUERY)
+                    || !filter(*handle, co)
+                {
+                    continue;
+                }
+
+                let proj = co.shape().project_point(co.position(), point, solid);
+                let dist = if proj.is_inside {
+                    N::zero()
+                } else {
+                    na::distance(point, &proj.point)
+                };
+
+                if dist <= best_dist && (result.is_none() || dist < best_dist) {
+                    best_dist = dist;
+                    result = Some((*handle, co, proj));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Iterates through all the collision objects intersecting the given shape located at `m`.
+    ///
+    /// Only objects with the `CollisionObjectQueryFlags::INTERSECTION_QUERY` flag are considered.
+    ///
+    /// # Panics
+    /// This will panic if no algorithm is known to test the intersection between `shape` and
+    /// the shape of one of the candidate collision objects (see `query::proximity`).
+    pub fn intersections_with_shape<'b>(
+        &self,
+        m: &'b Isometry<N>,
+        shape: &'b dyn Shape<N>,
+        mut filter: impl FnMut(Objects::CollisionObjectHandle, &Objects::CollisionObject) -> bool + 'b,
+    ) -> impl Iterator<Item = (Objects::CollisionObjectHandle, &'a Objects::CollisionObject)> + 'b
+    where
+        'a: 'b,
+    {
+        let aabb = shape.aabb(m);
+
+        self.intersections_with_aabb(&aabb, move |handle, co| {
+            filter(handle, co)
+                && query::proximity(m, shape, co.position(), co.shape(), N::zero())
+                    == Proximity::Intersecting
+        })
+    }
+
+    /// Iterates through all the collision objects which broad phase bounding volumes
+    /// intersect `aabb`.
+    ///
+    /// Only objects with the `CollisionObjectQueryFlags::INTERSECTION_QUERY` flag are considered.
+    pub fn intersections_with_aabb<'b>(
+        &self,
+        aabb: &AABB<N>,
+        mut filter: impl FnMut(Objects::CollisionObjectHandle, &Objects::CollisionObject) -> bool + 'b,
+    ) -> impl Iterator<Item = (Objects::CollisionObjectHandle, &'a Objects::CollisionObject)> + 'b
+    where
+        'a: 'b,
+    {
+        let mut handles = Vec::new();
+        self.broad_phase
+            .interferences_with_bounding_volume(aabb, &mut handles);
+
+        let objects = self.objects;
+        handles.into_iter().filter_map(move |handle| {
+            let co = objects.collision_object(*handle)?;
+
+            if co
+                .query_flags()
+                .contains(CollisionObjectQueryFlags::INTERSECTION_QUERY)
+                && filter(*handle, co)
+            {
+                Some((*handle, co))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, N: RealField + Copy, Objects: CollisionObjectSet<N>> Clone
+    for QueryPipeline<'a, N, Objects>
+{
+    fn clone(&self) -> Self {
+        QueryPipeline {
+            objects: self.objects,
+            broad_phase: self.broad_phase,
+        }
+    }
+}
@@ -2,10 +2,12 @@ use na::RealField;
 
 use crate::bounding_volume::AABB;
 use crate::pipeline::broad_phase::{
-    BroadPhase, BroadPhaseInterferenceHandler, BroadPhasePairFilter,
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhasePairFilter, BroadPhaseUpdateBudget,
 };
 use crate::pipeline::narrow_phase::{InteractionGraph, NarrowPhase};
-use crate::pipeline::object::{CollisionGroupsPairFilter, CollisionObjectRef, CollisionObjectSet};
+use crate::pipeline::object::{
+    CollisionGroupsPairFilter, CollisionObjectQueryFlags, CollisionObjectRef, CollisionObjectSet,
+};
 
 struct CollisionWorldInterferenceHandler<'a, 'b, N, Objects, Filter>
 where
@@ -32,8 +34,13 @@ where
         b2: &Objects::CollisionObjectHandle,
     ) -> bool {
         let filter_by_groups = CollisionGroupsPairFilter;
+        let co1 = self.objects.collision_object(*b1).unwrap();
+        let co2 = self.objects.collision_object(*b2).unwrap();
+        let pairwise_flags =
+            CollisionObjectQueryFlags::CONTACTS | CollisionObjectQueryFlags::PROXIMITY;
 
-        filter_by_groups.is_pair_valid(*b1, *b2, self.objects)
+        (co1.query_flags() & co2.query_flags()).intersects(pairwise_flags)
+            && filter_by_groups.is_pair_valid(*b1, *b2, self.objects)
             && self
                 .pair_filters
                 .map(|f| f.is_pair_valid(*b1, *b2, self.objects))
@@ -71,9 +78,58 @@ pub fn perform_broad_phase<N: RealField + Copy, Objects>(
     pair_filters: Option<&(impl BroadPhasePairFilter<N, Objects> + ?Sized)>,
 ) where
     Objects: CollisionObjectSet<N>,
+{
+    register_object_changes(objects, broad_phase, narrow_phase, interactions);
+
+    // Update the broad-phase.
+    broad_phase.update(&mut CollisionWorldInterferenceHandler {
+        interactions,
+        narrow_phase,
+        pair_filters,
+        objects,
+    });
+}
+
+/// Performs part of the broad-phase, stopping once the given budget is exhausted.
+///
+/// This is the same as `perform_broad_phase` except that the broad-phase is updated with
+/// `BroadPhase::update_with_budget`. Returns `true` if the broad-phase is up to date.
+pub fn perform_broad_phase_with_budget<N: RealField + Copy, Objects>(
+    objects: &Objects,
+    broad_phase: &mut (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle>,
+    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+    pair_filters: Option<&(impl BroadPhasePairFilter<N, Objects> + ?Sized)>,
+    budget: BroadPhaseUpdateBudget,
+) -> bool
+where
+    Objects: CollisionObjectSet<N>,
+{
+    register_object_changes(objects, broad_phase, narrow_phase, interactions);
+
+    broad_phase.update_with_budget(
+        &mut CollisionWorldInterferenceHandler {
+            interactions,
+            narrow_phase,
+            pair_filters,
+            objects,
+        },
+        budget,
+    )
+}
+
+// Notifies the broad-phase and the narrow-phase of the collision objects modified since the
+// last update.
+fn register_object_changes<N: RealField + Copy, Objects>(
+    objects: &Objects,
+    broad_phase: &mut (impl BroadPhase<N, AABB<N>, Objects::CollisionObjectHandle> + ?Sized),
+    narrow_phase: &mut NarrowPhase<N, Objects::CollisionObjectHandle>,
+    interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+) where
+    Objects: CollisionObjectSet<N>,
 {
     // Take changes into account.
-    objects.foreach(|_, co| {
+    objects.foreach(|handle, co| {
         let flags = co.update_flags();
         let proxy_handle = co.proxy_handle().expect(crate::NOT_REGISTERED_ERROR);
 
@@ -84,14 +140,10 @@ pub fn perform_broad_phase<N: RealField + Copy, Objects>(
         if flags.needs_broad_phase_redispatch() {
             broad_phase.deferred_recompute_all_proximities_with(proxy_handle);
         }
-    });
 
-    // Update the broad-phase.
-    broad_phase.update(&mut CollisionWorldInterferenceHandler {
-        interactions,
-        narrow_phase,
-        pair_filters,
-        objects,
+        if flags.needs_narrow_phase_redispatch() {
+            narrow_phase.redispatch_interactions_with(interactions, objects, handle);
+        }
     });
 }
 
@@ -4,10 +4,12 @@ pub use self::broad_phase::*;
 pub use self::glue::*;
 pub use self::narrow_phase::*;
 pub use self::object::*;
+pub use self::snapshot::SnapshotData;
 pub use self::world::*;
 
 pub mod broad_phase;
 pub mod glue;
 pub mod narrow_phase;
 pub mod object;
+pub mod snapshot;
 pub mod world;
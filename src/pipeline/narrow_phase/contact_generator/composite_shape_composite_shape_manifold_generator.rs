@@ -44,6 +44,11 @@ impl<N: RealField + Copy> CompositeShapeCompositeShapeManifoldGenerator<N> {
     ) {
         self.timestamp += 1;
 
+        // The parts are dilated by the contact preprocessors of the composite shapes.
+        let mut prediction = prediction.clone();
+        prediction.set_linear(prediction.linear() + g1.thickness() + g2.thickness());
+        let prediction = &prediction;
+
         // Find new collisions
         let ls_m2 = m1.inverse() * m2;
         // For transforming AABBs from g2 in the local space of g1.
@@ -46,6 +46,11 @@ impl<N: RealField + Copy> CompositeShapeShapeManifoldGenerator<N> {
     ) {
         self.timestamp += 1;
 
+        // The parts are dilated by the contact preprocessors of the composite shape.
+        let mut prediction = prediction.clone();
+        prediction.set_linear(prediction.linear() + g1.thickness());
+        let prediction = &prediction;
+
         // Find new collisions
         let ls_m2 = m1.inverse() * m2.clone();
         let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(prediction.linear());
@@ -35,8 +35,17 @@ pub trait ContactManifoldGenerator<N: RealField + Copy>: Any + Send + Sync {
     }
 }
 
+/// A boxed contact manifold generator.
 pub type ContactAlgorithm<N> = Box<dyn ContactManifoldGenerator<N>>;
 
+/// Trait implemented by the selectors of the contact manifold generator used for each pair of
+/// shapes.
+///
+/// The dispatcher of a `CollisionWorld` is given to `CollisionWorld::with_contact_dispatcher`.
+/// It is also given to every contact manifold generator, so that the generators of composite
+/// shapes select the algorithms of their sub-shapes through it too. To replace the algorithm of
+/// some pairs of shapes only, implement this trait for a type that handles those pairs and
+/// delegates all the others to a `DefaultContactDispatcher`.
 pub trait ContactDispatcher<N>: Any + Send + Sync {
     /// Allocate a collision algorithm corresponding to a pair of objects with the given shapes.
     fn get_contact_algorithm(
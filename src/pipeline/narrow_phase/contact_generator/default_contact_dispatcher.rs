@@ -6,14 +6,17 @@ use crate::pipeline::{
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
     HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    PlaneConvexPolyhedronManifoldGenerator, SegmentSegmentManifoldGenerator,
 };
 #[cfg(feature = "dim3")]
 use crate::shape::TriMesh;
-use crate::shape::{Ball, Capsule, HeightField, Plane, Shape};
+use crate::shape::{Ball, Capsule, HeightField, Plane, Segment, Shape};
 use na::RealField;
 
-/// Collision dispatcher for shapes defined by `ncollide_entities`.
+/// Collision dispatcher for the shapes defined by this crate.
+///
+/// Custom dispatchers can delegate to this one for the pairs of shapes they do not want to
+/// handle themselves.
 pub struct DefaultContactDispatcher {}
 
 impl DefaultContactDispatcher {
@@ -23,6 +26,12 @@ impl DefaultContactDispatcher {
     }
 }
 
+impl Default for DefaultContactDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<N: RealField + Copy> ContactDispatcher<N> for DefaultContactDispatcher {
     fn get_contact_algorithm(
         &self,
@@ -70,6 +79,8 @@ impl<N: RealField + Copy> ContactDispatcher<N> for DefaultContactDispatcher {
         } else if b_is_plane && a.is_support_map() {
             let gen = PlaneConvexPolyhedronManifoldGenerator::<N>::new(true);
             Some(Box::new(gen))
+        } else if a.is_shape::<Segment<N>>() && b.is_shape::<Segment<N>>() {
+            Some(Box::new(SegmentSegmentManifoldGenerator::<N>::new()))
         } else if a_is_ball && b.is_convex_polyhedron() {
             let gen = BallConvexPolyhedronManifoldGenerator::<N>::new(false);
             Some(Box::new(gen))
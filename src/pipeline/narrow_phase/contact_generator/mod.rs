@@ -14,6 +14,10 @@ pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::heightfield_shape_manifold_generator::HeightFieldShapeManifoldGenerator;
 pub use self::plane_ball_manifold_generator::PlaneBallManifoldGenerator;
 pub use self::plane_convex_polyhedron_manifold_generator::PlaneConvexPolyhedronManifoldGenerator;
+pub use self::registry_contact_dispatcher::{ContactAlgorithmFactory, RegistryContactDispatcher};
+pub use self::segment_segment_manifold_generator::SegmentSegmentManifoldGenerator;
+pub use self::speculative_contact_dispatcher::SpeculativeContactDispatcher;
+pub use self::speculative_manifold_generator::SpeculativeManifoldGenerator;
 #[cfg(feature = "dim3")]
 pub use self::trimesh_trimesh_manifold_generator::TriMeshTriMeshManifoldGenerator;
 
@@ -31,5 +35,9 @@ mod default_contact_dispatcher;
 mod heightfield_shape_manifold_generator;
 mod plane_ball_manifold_generator;
 mod plane_convex_polyhedron_manifold_generator;
+mod registry_contact_dispatcher;
+mod segment_segment_manifold_generator;
+mod speculative_contact_dispatcher;
+mod speculative_manifold_generator;
 #[cfg(feature = "dim3")]
 mod trimesh_trimesh_manifold_generator;
@@ -53,7 +53,7 @@ impl<N: RealField + Copy> PlaneBallManifoldGenerator<N> {
                 let local1 = m1.inverse_transform_point(&world1);
                 let local2 = Point::origin();
 
-                let f1 = FeatureId::Face(0);
+                let f1 = Plane::<N>::FACE_ID;
                 let f2 = FeatureId::Face(0);
                 let mut kinematic = ContactKinematic::new();
                 let contact;
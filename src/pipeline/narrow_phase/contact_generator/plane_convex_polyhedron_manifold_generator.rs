@@ -7,7 +7,12 @@ use crate::query::{
 use crate::shape::{ConvexPolygonalFeature, FeatureId, Plane, Shape};
 use na::{self, RealField};
 
-/// Collision detector between g1 plane and g1 shape implementing the `SupportMap` trait.
+/// Collision detector between a plane and a shape implementing the `SupportMap` trait.
+///
+/// If the shape is a convex polyhedron, one contact is generated for each vertex of its face
+/// supporting the plane, identified by the `FeatureId` of that vertex. Other support maps are
+/// given a single contact at their deepest point, identified by the feature their point query
+/// reports for it. On the plane side, every contact is identified by `Plane::FACE_ID`.
 #[derive(Clone)]
 pub struct PlaneConvexPolyhedronManifoldGenerator<N: RealField + Copy> {
     flip: bool,
@@ -38,40 +43,39 @@ impl<N: RealField + Copy> PlaneConvexPolyhedronManifoldGenerator<N> {
         manifold: &mut ContactManifold<N>,
         flip: bool,
     ) -> bool {
-        if let (Some(plane), Some(cp)) = (g1.as_shape::<Plane<N>>(), g2.as_convex_polyhedron()) {
+        if let Some(plane) = g1.as_shape::<Plane<N>>() {
             let plane_normal = m1 * plane.normal;
-            let plane_center = Point::from(m1.translation.vector);
-
-            cp.support_face_toward(m2, &-plane_normal, poly_feature);
-
-            for (i, world2) in poly_feature.vertices.iter().enumerate() {
-                let dpt = *world2 - plane_center;
-                let dist = dpt.dot(plane_normal.as_ref());
-
-                if dist <= prediction.linear() {
-                    let world1 = *world2 + (-*plane_normal * dist);
-                    let local1 = m1.inverse_transform_point(&world1);
-                    let local2 = m2.inverse_transform_point(&world2);
-                    let f1 = FeatureId::Face(0);
-                    let f2 = poly_feature.vertices_id[i];
-                    let mut kinematic = ContactKinematic::new();
-                    let contact;
+            let pair = PlaneShapePair {
+                m1,
+                plane,
+                proc1,
+                m2,
+                proc2,
+                prediction,
+                flip,
+            };
 
-                    let approx_plane = NeighborhoodGeometry::Plane(plane.normal);
-                    let approx2 = NeighborhoodGeometry::Point;
+            if let Some(cp) = g2.as_convex_polyhedron() {
+                // The whole supporting face is projected on the plane.
+                cp.support_face_toward(m2, &-plane_normal, poly_feature);
 
-                    if !flip {
-                        contact = Contact::new(world1, *world2, plane_normal, -dist);
-                        kinematic.set_approx1(f1, local1, approx_plane);
-                        kinematic.set_approx2(f2, local2, approx2);
-                        let _ = manifold.push(contact, kinematic, local2, proc1, proc2);
-                    } else {
-                        contact = Contact::new(*world2, world1, -plane_normal, -dist);
-                        kinematic.set_approx1(f2, local2, approx2);
-                        kinematic.set_approx2(f1, local1, approx_plane);
-                        let _ = manifold.push(contact, kinematic, local2, proc2, proc1);
-                    }
+                for (world2, f2) in poly_feature
+                    .vertices
+                    .iter()
+                    .zip(poly_feature.vertices_id.iter())
+                {
+                    pair.push_contact(world2, *f2, manifold);
                 }
+            } else if let Some(sm) = g2.as_support_map() {
+                // The only contact of a curved shape is its deepest point.
+                let world2 = sm.support_point(m2, &-plane_normal);
+                let f2 = g2
+                    .as_point_query()
+                    .map(|q| q.project_point_with_feature(m2, &world2).1)
+                    .unwrap_or(FeatureId::Unknown);
+                pair.push_contact(&world2, f2, manifold);
+            } else {
+                return false;
             }
 
             true
@@ -81,6 +85,52 @@ impl<N: RealField + Copy> PlaneConvexPolyhedronManifoldGenerator<N> {
     }
 }
 
+/// A plane and the shape it collides with, in the order expected by the contact manifold.
+struct PlaneShapePair<'a, N: RealField + Copy> {
+    m1: &'a Isometry<N>,
+    plane: &'a Plane<N>,
+    proc1: Option<&'a dyn ContactPreprocessor<N>>,
+    m2: &'a Isometry<N>,
+    proc2: Option<&'a dyn ContactPreprocessor<N>>,
+    prediction: &'a ContactPrediction<N>,
+    flip: bool,
+}
+
+impl<'a, N: RealField + Copy> PlaneShapePair<'a, N> {
+    /// Adds to `manifold` the contact between the plane and the point `world2` of the feature
+    /// `f2` of the other shape, if they are close enough.
+    #[inline]
+    fn push_contact(&self, world2: &Point<N>, f2: FeatureId, manifold: &mut ContactManifold<N>) {
+        let plane_normal = self.m1 * self.plane.normal;
+        let plane_center = Point::from(self.m1.translation.vector);
+        let dist = (*world2 - plane_center).dot(plane_normal.as_ref());
+
+        if dist <= self.prediction.linear() {
+            let world1 = *world2 + (-*plane_normal * dist);
+            let local1 = self.m1.inverse_transform_point(&world1);
+            let local2 = self.m2.inverse_transform_point(world2);
+            let f1 = Plane::<N>::FACE_ID;
+            let mut kinematic = ContactKinematic::new();
+            let contact;
+
+            let approx_plane = NeighborhoodGeometry::Plane(self.plane.normal);
+            let approx2 = NeighborhoodGeometry::Point;
+
+            if !self.flip {
+                contact = Contact::new(world1, *world2, plane_normal, -dist);
+                kinematic.set_approx1(f1, local1, approx_plane);
+                kinematic.set_approx2(f2, local2, approx2);
+                let _ = manifold.push(contact, kinematic, local2, self.proc1, self.proc2);
+            } else {
+                contact = Contact::new(*world2, world1, -plane_normal, -dist);
+                kinematic.set_approx1(f2, local2, approx2);
+                kinematic.set_approx2(f1, local1, approx_plane);
+                let _ = manifold.push(contact, kinematic, local2, self.proc2, self.proc1);
+            }
+        }
+    }
+}
+
 impl<N: RealField + Copy> ContactManifoldGenerator<N>
     for PlaneConvexPolyhedronManifoldGenerator<N>
 {
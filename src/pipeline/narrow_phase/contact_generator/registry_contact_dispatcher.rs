@@ -0,0 +1,142 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{
+    ContactAlgorithm, ContactDispatcher, DefaultContactDispatcher,
+};
+use crate::query::{self, Contact, ContactManifold, ContactPrediction};
+use crate::shape::Shape;
+use crate::utils::DeterministicState;
+use na::RealField;
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// A function creating a new contact manifold generator.
+///
+/// Its argument is `true` if the generator will be given the shapes in the reverse order of
+/// their registration.
+pub type ContactAlgorithmFactory<N> = Box<dyn Fn(bool) -> ContactAlgorithm<N> + Send + Sync>;
+
+/// Collision dispatcher with contact manifold generators registered at runtime.
+///
+/// This lets downstream crates add their own shape types to the narrow phase: the registered
+/// generators are used for each pair of shapes of the registered types, while all the other
+/// pairs are handled by a fallback dispatcher. The contact algorithms for the sub-shapes
+/// of composite shapes are obtained through this dispatcher too, so custom shapes can be
+/// part of a `Compound`. One-shot contact queries involving custom shapes are performed with
+/// `self.contact` since `query::contact` only knows the built-in shapes.
+pub struct RegistryContactDispatcher<N: RealField + Copy> {
+    algorithms: HashMap<(TypeId, TypeId), ContactAlgorithmFactory<N>, DeterministicState>,
+    fallback: Box<dyn ContactDispatcher<N>>,
+}
+
+impl<N: RealField + Copy> RegistryContactDispatcher<N> {
+    /// Creates a registry falling back to the `DefaultContactDispatcher`.
+    pub fn new() -> RegistryContactDispatcher<N> {
+        Self::from_dispatcher(Box::new(DefaultContactDispatcher::new()))
+    }
+
+    /// Creates a registry falling back to an arbitrary contact dispatcher.
+    pub fn from_dispatcher(
+        fallback: Box<dyn ContactDispatcher<N>>,
+    ) -> RegistryContactDispatcher<N> {
+        RegistryContactDispatcher {
+            algorithms: HashMap::with_hasher(DeterministicState),
+            fallback,
+        }
+    }
+
+    /// Registers the contact manifold generator factory for shapes of types `G1` and `G2`.
+    ///
+    /// The factory is called with `false` for pairs of shapes of types `(G1, G2)`, and with
+    /// `true` for pairs of shapes of types `(G2, G1)`, in which case the generator must expect the
+    /// shape of type `G2` first. If a factory was already registered for this pair of types, it
+    /// is replaced.
+    pub fn register_contact_algorithm<G1, G2>(
+        &mut self,
+        factory: impl Fn(bool) -> ContactAlgorithm<N> + Send + Sync + 'static,
+    ) where
+        G1: Shape<N>,
+        G2: Shape<N>,
+    {
+        let _ = self
+            .algorithms
+            .insert((TypeId::of::<G1>(), TypeId::of::<G2>()), Box::new(factory));
+    }
+
+    /// Checks if a contact manifold generator factory is registered for the shapes of types
+    /// `G1` and `G2`, in any order.
+    pub fn contains_contact_algorithm<G1, G2>(&self) -> bool
+    where
+        G1: Shape<N>,
+        G2: Shape<N>,
+    {
+        let (t1, t2) = (TypeId::of::<G1>(), TypeId::of::<G2>());
+        self.algorithms.contains_key(&(t1, t2)) || self.algorithms.contains_key(&(t2, t1))
+    }
+
+    /// Computes one contact point between two shapes.
+    ///
+    /// This is the equivalent of `query::contact` taking the registered generators into
+    /// account: for a pair of registered shape types, the deepest contact of a manifold computed
+    /// by the registered generator is returned. All the other pairs are handled by
+    /// `query::contact`.
+    pub fn contact(
+        &self,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        prediction: N,
+    ) -> Option<Contact<N>> {
+        match self.registered_contact_algorithm(g1, g2) {
+            Some(mut algorithm) => {
+                let prediction = ContactPrediction::new(prediction, N::zero(), N::zero());
+                let mut manifold = ContactManifold::new();
+                let _ = algorithm.generate_contacts(
+                    self,
+                    m1,
+                    g1,
+                    None,
+                    m2,
+                    g2,
+                    None,
+                    &prediction,
+                    &mut manifold,
+                );
+                manifold.deepest_contact().map(|c| c.contact)
+            }
+            None => query::contact(m1, g1, m2, g2, prediction),
+        }
+    }
+
+    fn registered_contact_algorithm(
+        &self,
+        a: &dyn Shape<N>,
+        b: &dyn Shape<N>,
+    ) -> Option<ContactAlgorithm<N>> {
+        let ta = a.as_any().type_id();
+        let tb = b.as_any().type_id();
+
+        if let Some(factory) = self.algorithms.get(&(ta, tb)) {
+            Some(factory(false))
+        } else {
+            self.algorithms.get(&(tb, ta)).map(|factory| factory(true))
+        }
+    }
+}
+
+impl<N: RealField + Copy> Default for RegistryContactDispatcher<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: RealField + Copy> ContactDispatcher<N> for RegistryContactDispatcher<N> {
+    fn get_contact_algorithm(
+        &self,
+        a: &dyn Shape<N>,
+        b: &dyn Shape<N>,
+    ) -> Option<ContactAlgorithm<N>> {
+        self.registered_contact_algorithm(a, b)
+            .or_else(|| self.fallback.get_contact_algorithm(a, b))
+    }
+}
@@ -0,0 +1,126 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{self, Contact, ContactManifold, ContactPrediction, ContactPreprocessor};
+#[cfg(feature = "dim3")]
+use crate::shape::ClippingCache;
+use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, Segment, Shape};
+use na::RealField;
+
+/// Collision detector between two segments.
+///
+/// Unlike the generic convex polyhedron collision detector, this relies on the closed-form
+/// segment-segment closest points computation instead of GJK.
+pub struct SegmentSegmentManifoldGenerator<N: RealField + Copy> {
+    #[cfg(feature = "dim3")]
+    clip_cache: ClippingCache<N>,
+    new_contacts: Vec<(Contact<N>, FeatureId, FeatureId)>,
+    manifold1: ConvexPolygonalFeature<N>,
+    manifold2: ConvexPolygonalFeature<N>,
+}
+
+impl<N: RealField + Copy> SegmentSegmentManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between two segments.
+    pub fn new() -> Self {
+        SegmentSegmentManifoldGenerator {
+            #[cfg(feature = "dim3")]
+            clip_cache: ClippingCache::new(),
+            new_contacts: Vec::new(),
+            manifold1: ConvexPolygonalFeature::new(),
+            manifold2: ConvexPolygonalFeature::new(),
+        }
+    }
+}
+
+impl<N: RealField + Copy> Default for SegmentSegmentManifoldGenerator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: RealField + Copy> ContactManifoldGenerator<N> for SegmentSegmentManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        let (seg1, seg2) = match (a.as_shape::<Segment<N>>(), b.as_shape::<Segment<N>>()) {
+            (Some(seg1), Some(seg2)) => (seg1, seg2),
+            _ => return false,
+        };
+
+        self.new_contacts.clear();
+        self.manifold1.clear();
+        self.manifold2.clear();
+
+        let contact = match query::contact_segment_segment(m1, seg1, m2, seg2, prediction.linear())
+        {
+            Some(contact) => contact,
+            None => return true,
+        };
+
+        seg1.support_feature_toward(
+            m1,
+            &contact.normal,
+            prediction.angular1(),
+            &mut self.manifold1,
+        );
+        seg2.support_feature_toward(
+            m2,
+            &-contact.normal,
+            prediction.angular2(),
+            &mut self.manifold2,
+        );
+
+        // Parallel segments may yield two contacts.
+        #[cfg(feature = "dim2")]
+        {
+            self.manifold1.clip(
+                &self.manifold2,
+                &contact.normal,
+                prediction,
+                &mut self.new_contacts,
+            )
+        }
+        #[cfg(feature = "dim3")]
+        {
+            self.manifold1.clip(
+                &self.manifold2,
+                &contact.normal,
+                prediction,
+                &mut self.clip_cache,
+                &mut self.new_contacts,
+            )
+        }
+
+        if self.new_contacts.is_empty() {
+            self.new_contacts.push((
+                contact,
+                self.manifold1.feature_id,
+                self.manifold2.feature_id,
+            ));
+        }
+
+        for (c, f1, f2) in self.new_contacts.drain(..) {
+            self.manifold1.add_contact_to_manifold(
+                &self.manifold2,
+                c,
+                m1,
+                f1,
+                proc1,
+                m2,
+                f2,
+                proc2,
+                manifold,
+            )
+        }
+
+        true
+    }
+}
@@ -0,0 +1,58 @@
+use crate::pipeline::narrow_phase::{
+    ContactAlgorithm, ContactDispatcher, DefaultContactDispatcher, SpeculativeManifoldGenerator,
+};
+use crate::shape::{Plane, Shape};
+use na::RealField;
+
+/// Collision dispatcher generating speculative contacts between convex shapes.
+///
+/// The contact manifold generators of the wrapped dispatcher are combined with a
+/// `SpeculativeManifoldGenerator` so that separated shapes closer than `margin` still
+/// get a single contact with a negative depth. Composite shapes are supported as well since
+/// their sub-shapes contact manifold generators are obtained through this dispatcher too.
+pub struct SpeculativeContactDispatcher<N: RealField + Copy> {
+    dispatcher: Box<dyn ContactDispatcher<N>>,
+    margin: N,
+}
+
+impl<N: RealField + Copy> SpeculativeContactDispatcher<N> {
+    /// Creates a speculative contact dispatcher wrapping the `DefaultContactDispatcher`.
+    pub fn new(margin: N) -> SpeculativeContactDispatcher<N> {
+        Self::from_dispatcher(Box::new(DefaultContactDispatcher::new()), margin)
+    }
+
+    /// Creates a speculative contact dispatcher wrapping an arbitrary contact dispatcher.
+    pub fn from_dispatcher(
+        dispatcher: Box<dyn ContactDispatcher<N>>,
+        margin: N,
+    ) -> SpeculativeContactDispatcher<N> {
+        SpeculativeContactDispatcher { dispatcher, margin }
+    }
+
+    /// The distance below which speculative contacts are generated.
+    #[inline]
+    pub fn margin(&self) -> N {
+        self.margin
+    }
+}
+
+impl<N: RealField + Copy> ContactDispatcher<N> for SpeculativeContactDispatcher<N> {
+    fn get_contact_algorithm(
+        &self,
+        a: &dyn Shape<N>,
+        b: &dyn Shape<N>,
+    ) -> Option<ContactAlgorithm<N>> {
+        let algorithm = self.dispatcher.get_contact_algorithm(a, b)?;
+        let a_is_convex = a.is_support_map() || a.is_shape::<Plane<N>>();
+        let b_is_convex = b.is_support_map() || b.is_shape::<Plane<N>>();
+
+        if a_is_convex && b_is_convex {
+            Some(Box::new(SpeculativeManifoldGenerator::new(
+                algorithm,
+                self.margin,
+            )))
+        } else {
+            Some(algorithm)
+        }
+    }
+}
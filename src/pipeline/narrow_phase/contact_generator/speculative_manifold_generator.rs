@@ -0,0 +1,84 @@
+use crate::math::Isometry;
+use crate::pipeline::narrow_phase::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    self, ClosestPoints, Contact, ContactKinematic, ContactManifold, ContactPrediction,
+    ContactPreprocessor, NeighborhoodGeometry,
+};
+use crate::shape::{FeatureId, Shape};
+use na::{RealField, Unit};
+
+/// Contact manifold generator that adds a speculative contact to separated shapes.
+///
+/// The contacts are first computed by the wrapped contact manifold generator. If this does
+/// not yield any contact, the closest points between the two shapes are computed with a margin
+/// equal to the speculative margin. If they are closer than this margin, a single speculative
+/// contact is added to the manifold. The depth of a speculative contact is negative and its
+/// opposite is the current separation distance between the two shapes, i.e., `contact.separation()`.
+pub struct SpeculativeManifoldGenerator<N: RealField + Copy> {
+    generator: ContactAlgorithm<N>,
+    margin: N,
+}
+
+impl<N: RealField + Copy> SpeculativeManifoldGenerator<N> {
+    /// Creates a new speculative contact manifold generator wrapping `generator`.
+    ///
+    /// Speculative contacts will be generated for shapes separated by a distance smaller than `margin`.
+    pub fn new(generator: ContactAlgorithm<N>, margin: N) -> SpeculativeManifoldGenerator<N> {
+        SpeculativeManifoldGenerator { generator, margin }
+    }
+
+    /// The distance below which speculative contacts are generated.
+    #[inline]
+    pub fn margin(&self) -> N {
+        self.margin
+    }
+}
+
+impl<N: RealField + Copy> ContactManifoldGenerator<N> for SpeculativeManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        d: &dyn ContactDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        let ncontacts = manifold.len();
+
+        if !self
+            .generator
+            .generate_contacts(d, ma, a, proc1, mb, b, proc2, prediction, manifold)
+        {
+            return false;
+        }
+
+        if manifold.len() == ncontacts {
+            let margin = self.margin.max(prediction.linear());
+
+            if let ClosestPoints::WithinMargin(world1, world2) =
+                query::closest_points(ma, a, mb, b, margin)
+            {
+                if let Some(normal) = Unit::try_new(world2 - world1, N::default_epsilon()) {
+                    let contact = Contact::new_wo_depth(world1, world2, normal);
+                    let local1 = ma.inverse_transform_point(&world1);
+                    let local2 = mb.inverse_transform_point(&world2);
+                    let mut kinematic = ContactKinematic::new();
+                    kinematic.set_approx1(FeatureId::Unknown, local1, NeighborhoodGeometry::Point);
+                    kinematic.set_approx2(FeatureId::Unknown, local2, NeighborhoodGeometry::Point);
+
+                    let _ = manifold.push(contact, kinematic, local1, proc1, proc2);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn init_manifold(&self) -> ContactManifold<N> {
+        self.generator.init_manifold()
+    }
+}
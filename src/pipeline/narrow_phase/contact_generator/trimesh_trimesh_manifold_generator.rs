@@ -515,6 +515,32 @@ impl<N: RealField + Copy> TriMeshTriMeshManifoldGenerator<N> {
     }
 }
 
+// Dilates the contacts by the thickness of a mesh.
+struct MeshDilation<N: RealField + Copy>(N);
+
+impl<N: RealField + Copy> ContactPreprocessor<N> for MeshDilation<N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        if self.0 > N::zero() {
+            if is_first {
+                kinematic.set_dilation1(self.0);
+                c.world1 += *c.normal * self.0;
+            } else {
+                kinematic.set_dilation2(self.0);
+                c.world2 -= *c.normal * self.0;
+            }
+
+            c.depth += self.0;
+        }
+
+        true
+    }
+}
+
 impl<N: RealField + Copy> ContactManifoldGenerator<N> for TriMeshTriMeshManifoldGenerator<N> {
     fn generate_contacts(
         &mut self,
@@ -531,6 +557,16 @@ impl<N: RealField + Copy> ContactManifoldGenerator<N> for TriMeshTriMeshManifold
         if let (Some(mesh1), Some(mesh2)) =
             (g1.as_shape::<TriMesh<N>>(), g2.as_shape::<TriMesh<N>>())
         {
+            // The triangles are dilated by the thickness of their mesh.
+            let (thickness1, thickness2) = (mesh1.thickness(), mesh2.thickness());
+            let dilation1 = MeshDilation(thickness1);
+            let dilation2 = MeshDilation(thickness2);
+            let proc1: &dyn ContactPreprocessor<N> = &(proc1, &dilation1);
+            let proc2: &dyn ContactPreprocessor<N> = &(proc2, &dilation2);
+            let mut prediction = prediction.clone();
+            prediction.set_linear(prediction.linear() + thickness1 + thickness2);
+            let prediction = &prediction;
+
             // Find new collisions
             let m12 = m1.inverse() * m2;
             let m21 = m12.inverse();
@@ -551,7 +587,17 @@ impl<N: RealField + Copy> ContactManifoldGenerator<N> for TriMeshTriMeshManifold
             let mut interferences = mem::replace(&mut self.interferences, Vec::new());
             for id in interferences.drain(..) {
                 self.compute_faces_closest_points(
-                    &m12, &m21, m1, mesh1, id.0, proc1, m2, mesh2, id.1, proc2, prediction,
+                    &m12,
+                    &m21,
+                    m1,
+                    mesh1,
+                    id.0,
+                    Some(proc1),
+                    m2,
+                    mesh2,
+                    id.1,
+                    Some(proc2),
+                    prediction,
                     manifold,
                 );
             }
@@ -1,6 +1,7 @@
 //! Structures for describing and storing collision-related events.
 
-use crate::query::Proximity;
+use crate::query::{Proximity, TOI};
+use na::RealField;
 use std::iter::IntoIterator;
 use std::slice::Iter;
 
@@ -15,6 +16,8 @@ pub struct EventPool<E> {
 pub type ContactEvents<Handle> = EventPool<ContactEvent<Handle>>;
 /// A set of proximity events.
 pub type ProximityEvents<Handle> = EventPool<ProximityEvent<Handle>>;
+/// A set of time of impact events.
+pub type TOIEvents<N, Handle> = EventPool<TOIEvent<N, Handle>>;
 
 impl<E> EventPool<E> {
     /// Creates a new empty set of events.
@@ -109,3 +112,27 @@ impl<Handle> ProximityEvent<Handle> {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+/// Event occuring when two moving collision objects are expected to touch during the next update.
+pub struct TOIEvent<N: RealField + Copy, Handle> {
+    /// The first collider to which the time of impact event applies.
+    pub collider1: Handle,
+    /// The second collider to which the time of impact event applies.
+    pub collider2: Handle,
+    /// The time of impact between the two collision objects.
+    ///
+    /// The time is expressed in units of the collision world update, i.e., it lies in `[0, 1]`.
+    pub toi: TOI<N>,
+}
+
+impl<N: RealField + Copy, Handle> TOIEvent<N, Handle> {
+    /// Instantiates a new time of impact event.
+    pub fn new(collider1: Handle, collider2: Handle, toi: TOI<N>) -> Self {
+        Self {
+            collider1,
+            collider2,
+            toi,
+        }
+    }
+}
@@ -5,8 +5,11 @@ use petgraph::visit::EdgeRef;
 use crate::pipeline::narrow_phase::{ContactAlgorithm, ProximityAlgorithm, ProximityDetector};
 use crate::pipeline::object::CollisionObjectHandle;
 use crate::query::{ContactManifold, Proximity};
+use crate::utils::DeterministicState;
 use petgraph::prelude::EdgeIndex;
 use petgraph::Direction;
+use std::any::Any;
+use std::collections::HashMap;
 
 /// Index of a node of the interaction graph.
 pub type CollisionObjectGraphIndex = NodeIndex<usize>;
@@ -45,15 +48,56 @@ impl<N: RealField + Copy> Interaction<N> {
     }
 }
 
+/// A set of collision objects connected to each other by effective contacts.
+///
+/// Static objects are never part of an island, and do not connect the objects touching them.
+/// Thus, two stacks of boxes lying on the same static ground form two separate islands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Island<Handle> {
+    /// The non-static collision objects of this island.
+    pub objects: Vec<Handle>,
+    /// The effective contacts between objects of this island, or between an object of this
+    /// island and a static object.
+    pub contacts: Vec<TemporaryInteractionIndex>,
+    /// Whether every object of this island is allowed to sleep.
+    ///
+    /// An island must be put to sleep, or woken up, as a whole.
+    pub can_sleep: bool,
+}
+
+/// User-defined data attached to an edge of the interaction graph.
+pub type EdgeData = Box<dyn Any + Send + Sync>;
+
+/// A closure called with the handles of both collision objects of an interaction that ended, and
+/// the user-defined data that was attached to it.
+pub type EdgeDataRemovalCallback<Handle> = Box<dyn FnMut(Handle, Handle, EdgeData) + Send + Sync>;
+
+// The user-defined data of the edges, identified by the handles of their endpoints because the
+// graph indices are not stable wrt. removals.
+struct EdgesData<Handle: CollisionObjectHandle> {
+    data: HashMap<(Handle, Handle), EdgeData, DeterministicState>,
+    removal_callback: Option<EdgeDataRemovalCallback<Handle>>,
+}
+
 /// A graph where nodes are collision objects and edges are contact or proximity algorithms.
+///
+/// Each edge may also carry some user-defined data, e.g., the impulses accumulated by a
+/// constraints solver for warm-starting. This data is kept as long as the interaction exists.
 pub struct InteractionGraph<N: RealField + Copy, Handle: CollisionObjectHandle>(
     pub(crate) UnGraph<Handle, Interaction<N>, usize>,
+    EdgesData<Handle>,
 );
 
 impl<N: RealField + Copy, Handle: CollisionObjectHandle> InteractionGraph<N, Handle> {
     /// Creates a new empty collection of collision objects.
     pub fn new() -> Self {
-        InteractionGraph(UnGraph::with_capacity(10, 10))
+        InteractionGraph(
+            UnGraph::with_capacity(10, 10),
+            EdgesData {
+                data: HashMap::with_hasher(DeterministicState::new()),
+                removal_callback: None,
+            },
+        )
     }
 
     /// Adds a handle to this graph.
@@ -77,10 +121,117 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> InteractionGraph<N, Han
     /// ```
     #[must_use = "The graph index of the collision object returned by this method has been changed to `id`."]
     pub fn remove_node(&mut self, id: CollisionObjectGraphIndex) -> Option<Handle> {
+        if !self.1.data.is_empty() {
+            let edges: Vec<_> = self.0.edges(id).map(|e| e.id()).collect();
+
+            for edge in edges {
+                self.remove_edge_data(edge);
+            }
+        }
+
         let _ = self.0.remove_node(id);
         self.0.node_weight(id).cloned()
     }
 
+    // Removes an edge, and its user-defined data.
+    pub(crate) fn remove_edge(
+        &mut self,
+        edge: TemporaryInteractionIndex,
+    ) -> Option<Interaction<N>> {
+        self.remove_edge_data(edge);
+        self.0.remove_edge(edge)
+    }
+
+    /// Attaches some user-defined data to the interaction between the collision objects
+    /// identified by their graph index, replacing any data previously attached to it.
+    ///
+    /// Returns `false`, and drops `data`, if these objects do not interact. The data is dropped
+    /// when the interaction is removed, after being given to the callback set by
+    /// `self.set_edge_data_removal_callback`.
+    pub fn set_edge_data<D: Any + Send + Sync>(
+        &mut self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+        data: D,
+    ) -> bool {
+        if let Some(key) = self.edge_data_key(id1, id2) {
+            let _ = self.1.data.insert(key, Box::new(data));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The user-defined data attached to the interaction between the collision objects
+    /// identified by their graph index.
+    ///
+    /// Returns `None` if there is no such interaction, if no data is attached to it, or if its
+    /// data is not of type `D`.
+    pub fn edge_data<D: Any>(
+        &self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+    ) -> Option<&D> {
+        let key = self.edge_data_key(id1, id2)?;
+        self.1.data.get(&key)?.downcast_ref()
+    }
+
+    /// A mutable reference to the user-defined data attached to the interaction between the
+    /// collision objects identified by their graph index.
+    ///
+    /// Returns `None` if there is no such interaction, if no data is attached to it, or if its
+    /// data is not of type `D`.
+    pub fn edge_data_mut<D: Any>(
+        &mut self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+    ) -> Option<&mut D> {
+        let key = self.edge_data_key(id1, id2)?;
+        self.1.data.get_mut(&key)?.downcast_mut()
+    }
+
+    /// Removes the user-defined data attached to the interaction between the collision objects
+    /// identified by their graph index, without calling the removal callback.
+    pub fn take_edge_data(
+        &mut self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+    ) -> Option<EdgeData> {
+        let key = self.edge_data_key(id1, id2)?;
+        self.1.data.remove(&key)
+    }
+
+    /// Sets the closure called with the user-defined data of each interaction removed from
+    /// this graph.
+    pub fn set_edge_data_removal_callback(
+        &mut self,
+        callback: Option<EdgeDataRemovalCallback<Handle>>,
+    ) {
+        self.1.removal_callback = callback;
+    }
+
+    fn edge_data_key(
+        &self,
+        id1: CollisionObjectGraphIndex,
+        id2: CollisionObjectGraphIndex,
+    ) -> Option<(Handle, Handle)> {
+        let edge = self.0.find_edge(id1, id2)?;
+        let (a, b) = self.0.edge_endpoints(edge)?;
+        Some((self.0[a], self.0[b]))
+    }
+
+    fn remove_edge_data(&mut self, edge: TemporaryInteractionIndex) {
+        if let Some((a, b)) = self.0.edge_endpoints(edge) {
+            let (h1, h2) = (self.0[a], self.0[b]);
+
+            if let Some(data) = self.1.data.remove(&(h1, h2)) {
+                if let Some(callback) = &mut self.1.removal_callback {
+                    callback(h1, h2, data)
+                }
+            }
+        }
+    }
+
     /// All the interactions pairs on this graph.
     ///
     /// Refer to the official [user guide](https://ncollide.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -384,6 +535,81 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> InteractionGraph<N, Han
         })
     }
 
+    /// Computes the connected components of the graph formed by the effective contacts.
+    ///
+    /// Objects for which `is_static` returns `true` are ignored, except for the contacts
+    /// they are involved in. Each non-static object belongs to exactly one island, possibly
+    /// containing only itself. The `can_sleep` closure tells whether a non-static object would
+    /// be allowed to sleep; it is used to compute `Island::can_sleep`.
+    ///
+    /// The islands are sorted by the smallest graph index of their objects, and the objects of
+    /// an island are sorted by increasing graph index.
+    pub fn islands(
+        &self,
+        mut is_static: impl FnMut(Handle) -> bool,
+        mut can_sleep: impl FnMut(Handle) -> bool,
+    ) -> Vec<Island<Handle>> {
+        let statics: Vec<bool> = self.0.node_weights().map(|h| is_static(*h)).collect();
+        let mut visited = vec![false; self.0.node_count()];
+        let mut stack = Vec::new();
+        let mut ids = Vec::new();
+        let mut islands = Vec::new();
+
+        for root in self.0.node_indices() {
+            if statics[root.index()] || visited[root.index()] {
+                continue;
+            }
+
+            let mut contacts = Vec::new();
+            let mut island_can_sleep = true;
+
+            visited[root.index()] = true;
+            stack.push(root);
+
+            while let Some(id) = stack.pop() {
+                ids.push(id);
+                island_can_sleep = can_sleep(self.0[id]) && island_can_sleep;
+
+                for e in self.0.edges(id) {
+                    let inter = e.weight();
+
+                    if !inter.is_contact() || !Self::is_interaction_effective(inter) {
+                        continue;
+                    }
+
+                    let other = if e.source() == id {
+                        e.target()
+                    } else {
+                        e.source()
+                    };
+
+                    if statics[other.index()] {
+                        contacts.push(e.id());
+                    } else {
+                        // Each contact between two non-static objects is reached twice.
+                        if other.index() > id.index() {
+                            contacts.push(e.id());
+                        }
+
+                        if !visited[other.index()] {
+                            visited[other.index()] = true;
+                            stack.push(other);
+                        }
+                    }
+                }
+            }
+
+            ids.sort();
+            islands.push(Island {
+                objects: ids.drain(..).map(|id| self.0[id]).collect(),
+                contacts,
+                can_sleep: island_can_sleep,
+            });
+        }
+
+        islands
+    }
+
     // NOTE: we don't make this method public because different
     // applications will have a different interpretation of when a
     // contact is considered effective (for example in nphysics).
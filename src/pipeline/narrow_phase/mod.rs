@@ -7,14 +7,18 @@ pub use self::contact_generator::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
     CapsuleCapsuleManifoldGenerator, CapsuleShapeManifoldGenerator,
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
-    ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
+    ContactAlgorithm, ContactAlgorithmFactory, ContactDispatcher, ContactManifoldGenerator,
     ConvexPolyhedronConvexPolyhedronManifoldGenerator, DefaultContactDispatcher,
     HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    PlaneConvexPolyhedronManifoldGenerator, RegistryContactDispatcher,
+    SegmentSegmentManifoldGenerator, SpeculativeContactDispatcher, SpeculativeManifoldGenerator,
+};
+pub use self::events::{
+    ContactEvent, ContactEvents, EventPool, ProximityEvent, ProximityEvents, TOIEvent, TOIEvents,
 };
-pub use self::events::{ContactEvent, ContactEvents, EventPool, ProximityEvent, ProximityEvents};
 pub use self::interaction_graph::{
-    CollisionObjectGraphIndex, Interaction, InteractionGraph, TemporaryInteractionIndex,
+    CollisionObjectGraphIndex, EdgeData, EdgeDataRemovalCallback, Interaction, InteractionGraph,
+    Island, TemporaryInteractionIndex,
 };
 pub use self::narrow_phase::NarrowPhase;
 #[doc(inline)]
@@ -1,14 +1,18 @@
 use na::RealField;
 use slotmap::{Key, SlotMap};
+use std::cmp::Ordering;
 
+use crate::math::Vector;
 use crate::pipeline::narrow_phase::{
     ContactDispatcher, ContactEvent, ContactEvents, ContactManifoldGenerator, Interaction,
     InteractionGraph, ProximityDetector, ProximityDispatcher, ProximityEvent, ProximityEvents,
+    TOIEvent, TOIEvents,
 };
 use crate::pipeline::object::{
-    CollisionObjectHandle, CollisionObjectRef, CollisionObjectSet, GeometricQueryType,
+    CollisionObjectHandle, CollisionObjectQueryFlags, CollisionObjectRef, CollisionObjectSet,
+    GeometricQueryType,
 };
-use crate::query::{ContactId, ContactManifold, Proximity};
+use crate::query::{ContactId, ContactManifold, Proximity, TOIDispatcher};
 
 /// Collision detector dispatcher for collision objects.
 pub struct NarrowPhase<N: RealField + Copy, Handle: CollisionObjectHandle> {
@@ -16,6 +20,7 @@ pub struct NarrowPhase<N: RealField + Copy, Handle: CollisionObjectHandle> {
     proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
     contact_events: ContactEvents<Handle>,
     proximity_events: ProximityEvents<Handle>,
+    toi_events: TOIEvents<N, Handle>,
     id_allocator: SlotMap<ContactId, bool>,
 }
 
@@ -30,6 +35,7 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
             proximity_dispatcher,
             contact_events: ContactEvents::new(),
             proximity_events: ProximityEvents::new(),
+            toi_events: TOIEvents::new(),
             id_allocator: SlotMap::with_key(),
         }
     }
@@ -68,6 +74,20 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
             .query_type()
             .contact_queries_to_prediction(co2.query_type())
         {
+            // Refresh the contacts found at the last update first, so that the contacts between
+            // features that rotated apart are not kept in the cache and matched again by the
+            // generator. Their kinematic information is only relative to the whole shapes if
+            // those are convex polyhedra.
+            if co1.shape().is_convex_polyhedron() && co2.shape().is_convex_polyhedron() {
+                let _ = manifold.update_contacts(
+                    co1.position(),
+                    co1.shape(),
+                    co2.position(),
+                    co2.shape(),
+                    &prediction,
+                );
+            }
+
             manifold.save_cache_and_clear();
             let _ = detector.generate_contacts(
                 &*self.contact_dispatcher,
@@ -196,6 +216,129 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
         self.garbage_collect_ids(interactions)
     }
 
+    /// Computes the time of impact of every interaction pair involving at least one collision
+    /// object with a linear velocity.
+    ///
+    /// The resulting events replace the previous content of `self.toi_events()` and are sorted by
+    /// increasing time of impact. Only pairs reported by the broad-phase are considered so the
+    /// collision objects bounding volumes must take their velocities into account, which is the case of
+    /// `CollisionObjectRef::compute_swept_aabb`.
+    pub fn update_toi_events<Objects>(
+        &mut self,
+        interactions: &InteractionGraph<N, Objects::CollisionObjectHandle>,
+        objects: &Objects,
+        toi_dispatcher: &dyn TOIDispatcher<N>,
+    ) where
+        Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
+    {
+        let mut events = Vec::new();
+
+        for eid in interactions.0.edge_indices() {
+            let (id1, id2) = interactions.0.edge_endpoints(eid).unwrap();
+            let handle1 = interactions.0[id1];
+            let handle2 = interactions.0[id2];
+            let co1 = objects.collision_object(handle1).unwrap();
+            let co2 = objects.collision_object(handle2).unwrap();
+
+            if co1.linear_velocity().is_none() && co2.linear_velocity().is_none() {
+                continue;
+            }
+
+            let vel1 = co1.linear_velocity().cloned().unwrap_or(Vector::zeros());
+            let vel2 = co2.linear_velocity().cloned().unwrap_or(Vector::zeros());
+
+            if let Ok(Some(toi)) = toi_dispatcher.time_of_impact(
+                toi_dispatcher,
+                co1.position(),
+                &vel1,
+                co1.shape(),
+                co2.position(),
+                &vel2,
+                co2.shape(),
+                N::one(),
+                N::zero(),
+            ) {
+                events.push(TOIEvent::new(handle1, handle2, toi));
+            }
+        }
+
+        events.sort_by(|a, b| {
+            a.toi
+                .toi
+                .partial_cmp(&b.toi.toi)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        self.toi_events.clear();
+
+        for event in events {
+            self.toi_events.push(event);
+        }
+    }
+
+    /// The kind of interaction two collision objects should have given their query types and
+    /// query flags.
+    fn interaction_kind(
+        co1: &impl CollisionObjectRef<N>,
+        co2: &impl CollisionObjectRef<N>,
+    ) -> Option<InteractionKind> {
+        let flags = co1.query_flags() & co2.query_flags();
+
+        match (co1.query_type(), co2.query_type()) {
+            (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..))
+                if flags.contains(CollisionObjectQueryFlags::CONTACTS) =>
+            {
+                Some(InteractionKind::Contact)
+            }
+            _ if flags.contains(CollisionObjectQueryFlags::PROXIMITY) => {
+                Some(InteractionKind::Proximity)
+            }
+            _ => None,
+        }
+    }
+
+    /// Recreates the interactions of a collision object that no longer match its query type or
+    /// query flags.
+    ///
+    /// The broad phase does not report again the pairs it already knows about, so this must be
+    /// called whenever the query type or the query flags of an object change. An interaction that
+    /// is replaced generates the same events as if the objects stopped interacting.
+    pub fn redispatch_interactions_with<Objects>(
+        &mut self,
+        interactions: &mut InteractionGraph<N, Objects::CollisionObjectHandle>,
+        objects: &Objects,
+        handle: Objects::CollisionObjectHandle,
+    ) where
+        Objects: CollisionObjectSet<N, CollisionObjectHandle = Handle>,
+    {
+        let co = objects.collision_object(handle).unwrap();
+        let id = co.graph_index().expect(crate::NOT_REGISTERED_ERROR);
+        let outdated: Vec<_> = interactions
+            .interactions_with(id, false)
+            .filter_map(|(h1, h2, interaction)| {
+                let co1 = objects.collision_object(h1)?;
+                let co2 = objects.collision_object(h2)?;
+
+                let up_to_date = matches!(
+                    (Self::interaction_kind(co1, co2), interaction),
+                    (Some(InteractionKind::Contact), Interaction::Contact(..))
+                        | (Some(InteractionKind::Proximity), Interaction::Proximity(..))
+                );
+
+                if up_to_date {
+                    None
+                } else {
+                    Some((h1, h2))
+                }
+            })
+            .collect();
+
+        for (h1, h2) in outdated {
+            self.handle_interaction(interactions, objects, h1, h2, false);
+            self.handle_interaction(interactions, objects, h1, h2, true);
+        }
+    }
+
     /// Handles a pair of collision objects detected as either started or stopped interacting.
     pub fn handle_interaction<Objects>(
         &mut self,
@@ -214,8 +357,8 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
 
         if started {
             if !interactions.0.contains_edge(id1, id2) {
-                match (co1.query_type(), co2.query_type()) {
-                    (GeometricQueryType::Contacts(..), GeometricQueryType::Contacts(..)) => {
+                match Self::interaction_kind(co1, co2) {
+                    Some(InteractionKind::Contact) => {
                         let dispatcher = &self.contact_dispatcher;
 
                         if let Some(detector) =
@@ -229,8 +372,7 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
                             );
                         }
                     }
-                    (_, GeometricQueryType::Proximity(_))
-                    | (GeometricQueryType::Proximity(_), _) => {
+                    Some(InteractionKind::Proximity) => {
                         let dispatcher = &self.proximity_dispatcher;
 
                         if let Some(detector) =
@@ -243,6 +385,8 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
                             );
                         }
                     }
+                    // The objects do not take part in any common pairwise query.
+                    None => {}
                 }
             }
         } else {
@@ -251,7 +395,7 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
                 let handle1 = *interactions.0.node_weight(endpoints.0).unwrap();
                 let handle2 = *interactions.0.node_weight(endpoints.1).unwrap();
 
-                if let Some(detector) = interactions.0.remove_edge(eid) {
+                if let Some(detector) = interactions.remove_edge(eid) {
                     match detector {
                         Interaction::Contact(_, mut manifold) => {
                             // Register a collision lost event if there was a contact.
@@ -287,9 +431,20 @@ impl<N: RealField + Copy, Handle: CollisionObjectHandle> NarrowPhase<N, Handle>
         &self.proximity_events
     }
 
+    /// The set of time of impact events generated by this narrow-phase, sorted by increasing time of impact.
+    pub fn toi_events(&self) -> &TOIEvents<N, Handle> {
+        &self.toi_events
+    }
+
     /// Clear the events generated by this narrow-phase.
     pub fn clear_events(&mut self) {
         self.contact_events.clear();
         self.proximity_events.clear();
+        self.toi_events.clear();
     }
 }
+
+enum InteractionKind {
+    Contact,
+    Proximity,
+}
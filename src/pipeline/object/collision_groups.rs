@@ -42,9 +42,9 @@ const NO_GROUP: u32 = 0;
 ///    (which B is part of), B does not whitelists the groups 6 nor 9 (which B is part of).
 #[derive(Clone, Debug, Copy)]
 pub struct CollisionGroups {
-    membership: u32,
-    whitelist: u32,
-    blacklist: u32,
+    pub(crate) membership: u32,
+    pub(crate) whitelist: u32,
+    pub(crate) blacklist: u32,
 }
 
 impl CollisionGroups {
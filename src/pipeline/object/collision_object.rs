@@ -1,5 +1,5 @@
 use crate::bounding_volume::{self, BoundingVolume, AABB};
-use crate::math::Isometry;
+use crate::math::{Isometry, Translation, Vector};
 use crate::pipeline::broad_phase::BroadPhaseProxyHandle;
 use crate::pipeline::narrow_phase::CollisionObjectGraphIndex;
 use crate::pipeline::object::CollisionGroups;
@@ -19,8 +19,38 @@ bitflags! {
         const SHAPE_CHANGED = 0b000100;
         /// Indicate that the collision object's collision group changed.
         const COLLISION_GROUPS_CHANGED = 0b001000;
-        /// Indicate that the collision object's geometric query type changed.
+        /// Indicate that the collision object's geometric query type or query flags changed.
         const QUERY_TYPE_CHANGED = 0b0010000;
+        /// Indicate that the collision object's linear velocity changed.
+        const LINEAR_VELOCITY_CHANGED = 0b0100000;
+    }
+}
+
+bitflags! {
+    /// Flags indicating which geometric queries a collision object takes part in.
+    ///
+    /// All the flags are set by default. A pair of collision objects is checked for contacts
+    /// only if both have the `CONTACTS` flag, and for proximity only if both have the `PROXIMITY`
+    /// flag. If the `GeometricQueryType` of both objects require contacts but one of them lacks
+    /// the `CONTACTS` flag, the pair is checked for proximity instead.
+    pub struct CollisionObjectQueryFlags: u8 {
+        /// The object can generate contacts with other objects.
+        const CONTACTS = 0b0001;
+        /// The object can be tested for proximity with other objects.
+        const PROXIMITY = 0b0010;
+        /// The object can be hit by the ray casts performed on the collision world.
+        const RAY_CAST = 0b0100;
+        /// The object can be found by the point queries performed on the collision world.
+        const POINT_QUERY = 0b1000;
+        /// The object can be found by the AABB and shape intersection queries performed on the
+        /// collision world.
+        const INTERSECTION_QUERY = 0b10000;
+    }
+}
+
+impl Default for CollisionObjectQueryFlags {
+    fn default() -> Self {
+        Self::all()
     }
 }
 
@@ -32,8 +62,8 @@ impl CollisionObjectUpdateFlags {
 
     /// Checks if the collision object has been changed in a way that justify a narrow-phase update.
     pub fn needs_narrow_phase_update(&self) -> bool {
-        // The only change that does not trigger an update
-        // is a change on predicted position.
+        // The only changes that do not trigger an update
+        // are changes on predicted position and velocity.
         self.intersects(
             Self::POSITION_CHANGED
                 | Self::SHAPE_CHANGED
@@ -46,7 +76,12 @@ impl CollisionObjectUpdateFlags {
     pub fn needs_bounding_volume_update(&self) -> bool {
         // NOTE: the QUERY_TYPE_CHANGED is included here because the
         // prediction margin may have changed.
-        self.intersects(Self::POSITION_CHANGED | Self::SHAPE_CHANGED | Self::QUERY_TYPE_CHANGED)
+        self.intersects(
+            Self::POSITION_CHANGED
+                | Self::SHAPE_CHANGED
+                | Self::QUERY_TYPE_CHANGED
+                | Self::LINEAR_VELOCITY_CHANGED,
+        )
     }
 
     /// Checks if the collision object has been changed in a way that justify that the broad-phase
@@ -56,6 +91,12 @@ impl CollisionObjectUpdateFlags {
             Self::SHAPE_CHANGED | Self::COLLISION_GROUPS_CHANGED | Self::QUERY_TYPE_CHANGED,
         )
     }
+
+    /// Checks if the collision object has been changed in a way that justify that the narrow-phase
+    /// recreates the existing interactions involving this collision objects.
+    pub fn needs_narrow_phase_redispatch(&self) -> bool {
+        self.contains(Self::QUERY_TYPE_CHANGED)
+    }
 }
 
 /// Trait implemented by collision objects.
@@ -76,12 +117,24 @@ pub trait CollisionObjectRef<N: RealField + Copy> {
     /// This is typically needed for CCD (continuous collision detection) to be sure the broad-phase does not miss pential
     /// interactions in-between two discontinuous positions of the collision object.
     fn predicted_position(&self) -> Option<&Isometry<N>>;
+    /// The linear velocity of this collision object used for continuous collision detection.
+    ///
+    /// It is expressed in units of the collision world update: the collision object is expected to
+    /// be translated by exactly this vector during the next update. Thus, the times of impact
+    /// computed from this velocity are in `[0, 1]`.
+    fn linear_velocity(&self) -> Option<&Vector<N>> {
+        None
+    }
     /// The shape of this collision object.
     fn shape(&self) -> &dyn Shape<N>;
     /// The collision groups of this collision object.
     fn collision_groups(&self) -> &CollisionGroups;
     /// The type of geometric queries this collision object is subjected to.
     fn query_type(&self) -> GeometricQueryType<N>;
+    /// The geometric queries this collision object takes part in.
+    fn query_flags(&self) -> CollisionObjectQueryFlags {
+        CollisionObjectQueryFlags::all()
+    }
     /// Flags indicating what changed in this collision object.
     fn update_flags(&self) -> CollisionObjectUpdateFlags;
 
@@ -92,13 +145,15 @@ pub trait CollisionObjectRef<N: RealField + Copy> {
         aabb
     }
 
-    /// Computes the swept AABB of this collision object, taking `self.predict_position()` into account.
+    /// Computes the swept AABB of this collision object, taking `self.predict_position()` and
+    /// `self.linear_velocity()` into account.
     ///
     /// Given the AABB of this collision object at the position `self.position()’, and the AABB of
     /// this collision object at the position `self.predicted_position()`, this returns an AABB that
-    /// bounds both.
+    /// bounds both. If this collision object has a linear velocity, the returned AABB also
+    /// bounds the AABB of this collision object translated by this velocity.
     fn compute_swept_aabb(&self) -> AABB<N> {
-        if let Some(predicted_pos) = self.predicted_position() {
+        let mut aabb = if let Some(predicted_pos) = self.predicted_position() {
             let shape = self.shape();
             let mut aabb1 = bounding_volume::aabb(shape, self.position());
             let mut aabb2 = bounding_volume::aabb(shape, predicted_pos);
@@ -109,7 +164,18 @@ pub trait CollisionObjectRef<N: RealField + Copy> {
             aabb1
         } else {
             self.compute_aabb()
+        };
+
+        if let Some(vel) = self.linear_velocity() {
+            let mut moved = bounding_volume::aabb(
+                self.shape(),
+                &(Translation::from(*vel) * self.position()),
+            );
+            moved.loosen(self.query_type().query_limit());
+            aabb.merge(&moved);
         }
+
+        aabb
     }
 }
 
@@ -133,9 +199,11 @@ pub struct CollisionObject<N: RealField + Copy, T> {
     graph_index: Option<CollisionObjectGraphIndex>,
     position: Isometry<N>,
     predicted_position: Option<Isometry<N>>,
+    linear_velocity: Option<Vector<N>>,
     shape: ShapeHandle<N>,
     collision_groups: CollisionGroups,
     query_type: GeometricQueryType<N>,
+    query_flags: CollisionObjectQueryFlags,
     update_flags: CollisionObjectUpdateFlags,
     data: T,
 }
@@ -156,10 +224,12 @@ impl<N: RealField + Copy, T> CollisionObject<N, T> {
             graph_index,
             position,
             predicted_position: None,
+            linear_velocity: None,
             shape,
             collision_groups: groups,
             data,
             query_type,
+            query_flags: CollisionObjectQueryFlags::all(),
             update_flags: CollisionObjectUpdateFlags::all(),
         }
     }
@@ -237,6 +307,25 @@ impl<N: RealField + Copy, T> CollisionObject<N, T> {
         self.predicted_position = pos;
     }
 
+    /// The linear velocity of this collision object used for continuous collision detection.
+    ///
+    /// See `CollisionObjectRef::linear_velocity` for details.
+    #[inline]
+    pub fn linear_velocity(&self) -> Option<&Vector<N>> {
+        self.linear_velocity.as_ref()
+    }
+
+    /// Sets the linear velocity of this collision object used for continuous collision detection.
+    ///
+    /// This velocity is expressed in units of the collision world update, i.e., this collision
+    /// object is expected to be translated by `vel` during the next update. Set it to `None` to
+    /// disable continuous collision detection for this collision object.
+    #[inline]
+    pub fn set_linear_velocity(&mut self, vel: Option<Vector<N>>) {
+        self.update_flags |= CollisionObjectUpdateFlags::LINEAR_VELOCITY_CHANGED;
+        self.linear_velocity = vel;
+    }
+
     /// Deforms the underlying shape if possible.
     ///
     /// Panics if the shape is not deformable.
@@ -276,7 +365,7 @@ impl<N: RealField + Copy, T> CollisionObject<N, T> {
         self.collision_groups = groups
     }
 
-    /// The kind of queries this collision object is expected to .
+    /// The kind of queries this collision object is expected to take part in.
     #[inline]
     pub fn query_type(&self) -> GeometricQueryType<N> {
         self.query_type
@@ -290,6 +379,22 @@ impl<N: RealField + Copy, T> CollisionObject<N, T> {
         self.query_type = query_type;
     }
 
+    /// The geometric queries this collision object takes part in.
+    #[inline]
+    pub fn query_flags(&self) -> CollisionObjectQueryFlags {
+        self.query_flags
+    }
+
+    /// Sets the geometric queries this collision object takes part in.
+    ///
+    /// The interactions of this object with the others are recomputed at the next update of the
+    /// collision world.
+    #[inline]
+    pub fn set_query_flags(&mut self, flags: CollisionObjectQueryFlags) {
+        self.update_flags |= CollisionObjectUpdateFlags::QUERY_TYPE_CHANGED;
+        self.query_flags = flags;
+    }
+
     /// Reference to the user-defined data associated to this object.
     #[inline]
     pub fn data(&self) -> &T {
@@ -320,6 +425,10 @@ impl<N: RealField + Copy, T> CollisionObjectRef<N> for CollisionObject<N, T> {
         self.predicted_position()
     }
 
+    fn linear_velocity(&self) -> Option<&Vector<N>> {
+        self.linear_velocity()
+    }
+
     fn shape(&self) -> &dyn Shape<N> {
         self.shape().as_ref()
     }
@@ -332,6 +441,10 @@ impl<N: RealField + Copy, T> CollisionObjectRef<N> for CollisionObject<N, T> {
         self.query_type()
     }
 
+    fn query_flags(&self) -> CollisionObjectQueryFlags {
+        self.query_flags()
+    }
+
     fn update_flags(&self) -> CollisionObjectUpdateFlags {
         self.update_flags
     }
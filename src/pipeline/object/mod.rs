@@ -2,7 +2,8 @@
 
 pub use self::collision_groups::{CollisionGroups, CollisionGroupsPairFilter};
 pub use self::collision_object::{
-    CollisionObject, CollisionObjectRef, CollisionObjectSlabHandle, CollisionObjectUpdateFlags,
+    CollisionObject, CollisionObjectQueryFlags, CollisionObjectRef, CollisionObjectSlabHandle,
+    CollisionObjectUpdateFlags,
 };
 pub use self::collision_object_set::{
     CollisionObjectHandle, CollisionObjectSet, CollisionObjectSlab, CollisionObjects,
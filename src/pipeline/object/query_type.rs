@@ -6,14 +6,25 @@ use na::RealField;
 /// The following queries are executed for a given pair of `GeometricQueryType` associated with two
 /// collision objects:
 ///
-/// * Contacts + Contacts = exact contact point coputation.
+/// * Contacts + Contacts = exact contact point computation.
 /// * Contacts + Proximity = proximity test only.
 /// * Proximity + Proximity = proximity test only.
+///
+/// The margins are set per collision object and added for each pair, so a world can mix, e.g.,
+/// terrain chunks with a wide prediction margin and small fast projectiles with a tight one.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum GeometricQueryType<N: RealField + Copy> {
     /// This objects can respond to both contact point computation and proximity queries.
+    ///
+    /// The first value is the linear prediction margin: contacts are generated as soon as the
+    /// distance between two objects is smaller than the sum of their linear margins. The second
+    /// value is the angular prediction margin used to generate contacts between features with
+    /// almost-aligned normals.
     Contacts(N, N),
     /// This object can respond to proximity tests only.
+    ///
+    /// The value is the margin within which a pair of objects is reported as
+    /// `Proximity::WithinMargin`.
     Proximity(N),
     // FIXME: not yet implemented: Distance
 }
@@ -0,0 +1,826 @@
+//! Versioned binary snapshots of shapes and collision worlds.
+//!
+//! All the integers are stored in little-endian byte order, and all the scalars as 64-bits
+//! floats. Saving then loading a world using `f32` or `f64` scalars thus restores exactly the
+//! same shapes and positions on any platform.
+
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::shape::{
+    Ball, Capsule, Compound, Cuboid, HeightField, Plane, PointCloud, Polyline, RoundedCuboid,
+    Segment, Shape, ShapeHandle, Triangle,
+};
+#[cfg(feature = "dim3")]
+use crate::shape::{
+    Cone, ConvexHull, Cylinder, HeightFieldCellStatus, Oriented, Quad, Superellipsoid, TriMesh,
+};
+#[cfg(feature = "dim2")]
+use crate::shape::{ConvexPolygon, TileGrid};
+use na::{self, Point2, RealField, Unit};
+#[cfg(feature = "dim2")]
+use na::{Complex, DVector, UnitComplex};
+#[cfg(feature = "dim3")]
+use na::{DMatrix, Point3, Quaternion, UnitQuaternion, Vector2, Vector4};
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+
+/// The first bytes of every snapshot.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"NCSN";
+
+/// The version of the snapshot format written by this version of the library.
+///
+/// Snapshots with a different version are rejected when loaded.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// The largest collision object handle that can be stored in a snapshot.
+///
+/// This bounds the memory allocated when a snapshot is loaded, whatever its content.
+pub const MAX_SNAPSHOT_HANDLE: usize = (1 << 24) - 1;
+
+const TAG_BALL: u8 = 0;
+const TAG_CUBOID: u8 = 1;
+const TAG_CAPSULE: u8 = 2;
+const TAG_SEGMENT: u8 = 3;
+const TAG_TRIANGLE: u8 = 4;
+const TAG_PLANE: u8 = 5;
+const TAG_ROUNDED_CUBOID: u8 = 6;
+const TAG_COMPOUND: u8 = 7;
+const TAG_POLYLINE: u8 = 8;
+const TAG_HEIGHTFIELD: u8 = 9;
+const TAG_CONVEX: u8 = 10;
+#[cfg(feature = "dim3")]
+const TAG_TRIMESH: u8 = 11;
+#[cfg(feature = "dim3")]
+const TAG_QUAD: u8 = 12;
+#[cfg(feature = "dim3")]
+const TAG_SUPERELLIPSOID: u8 = 13;
+const TAG_POINT_CLOUD: u8 = 14;
+const TAG_ENDPOINT_CAPSULE: u8 = 15;
+#[cfg(feature = "dim3")]
+const TAG_CYLINDER: u8 = 16;
+#[cfg(feature = "dim3")]
+const TAG_CONE: u8 = 17;
+#[cfg(feature = "dim2")]
+const TAG_TILE_GRID: u8 = 18;
+#[cfg(feature = "dim3")]
+const TAG_ORIENTED: u8 = 19;
+
+/// User data that can be written to and read from a snapshot.
+pub trait SnapshotData: Sized {
+    /// Writes this data to `writer`.
+    fn write_snapshot(&self, writer: &mut dyn Write) -> io::Result<()>;
+    /// Reads a data written by `write_snapshot` from `reader`.
+    fn read_snapshot(reader: &mut dyn Read) -> io::Result<Self>;
+}
+
+impl SnapshotData for () {
+    fn write_snapshot(&self, _: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn read_snapshot(_: &mut dyn Read) -> io::Result<Self> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_snapshot_data_for_primitive(
+    ($($t: ty),*) => {$(
+        impl SnapshotData for $t {
+            fn write_snapshot(&self, writer: &mut dyn Write) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn read_snapshot(reader: &mut dyn Read) -> io::Result<Self> {
+                let mut bytes = [0; std::mem::size_of::<$t>()];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$t>::from_le_bytes(bytes))
+            }
+        }
+    )*}
+);
+
+impl_snapshot_data_for_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl SnapshotData for usize {
+    fn write_snapshot(&self, writer: &mut dyn Write) -> io::Result<()> {
+        (*self as u64).write_snapshot(writer)
+    }
+
+    fn read_snapshot(reader: &mut dyn Read) -> io::Result<Self> {
+        let value = u64::read_snapshot(reader)?;
+        usize::try_from(value).map_err(|_| invalid_data("integer too large for this platform"))
+    }
+}
+
+impl SnapshotData for bool {
+    fn write_snapshot(&self, writer: &mut dyn Write) -> io::Result<()> {
+        (*self as u8).write_snapshot(writer)
+    }
+
+    fn read_snapshot(reader: &mut dyn Read) -> io::Result<Self> {
+        match u8::read_snapshot(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid boolean")),
+        }
+    }
+}
+
+/// Writes the snapshot header: the magic bytes, the format version, and the dimension.
+pub fn write_header(writer: &mut dyn Write) -> io::Result<()> {
+    writer.write_all(&SNAPSHOT_MAGIC)?;
+    SNAPSHOT_VERSION.write_snapshot(writer)?;
+    (DIM as u8).write_snapshot(writer)
+}
+
+/// Reads and checks a snapshot header written by `write_header`.
+pub fn read_header(reader: &mut dyn Read) -> io::Result<()> {
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+
+    if magic != SNAPSHOT_MAGIC {
+        return Err(invalid_data("not a collision snapshot"));
+    }
+
+    if u32::read_snapshot(reader)? != SNAPSHOT_VERSION {
+        return Err(invalid_data("unsupported snapshot version"));
+    }
+
+    if u8::read_snapshot(reader)? as usize != DIM {
+        return Err(invalid_data(
+            "snapshot of a world with a different dimension",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Writes a shape, without any header.
+///
+/// Fails with `io::ErrorKind::InvalidInput` if the shape, or one of the parts of a compound
+/// shape, is not supported by the snapshot format. All the shapes of this crate are supported,
+/// except `Scaled` and `Swept` shapes, and `Oriented` shapes wrapping anything else than a
+/// `Cylinder` or a `Cone`.
+pub fn write_shape<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    if let Some(s) = shape.as_shape::<Ball<N>>() {
+        TAG_BALL.write_snapshot(writer)?;
+        write_scalar(s.radius, writer)
+    } else if let Some(s) = shape.as_shape::<Cuboid<N>>() {
+        TAG_CUBOID.write_snapshot(writer)?;
+        write_vector(&s.half_extents, writer)
+    } else if let Some(s) = shape.as_shape::<Capsule<N>>() {
+        if s.local_frame().is_some() {
+            let segment = s.segment();
+            TAG_ENDPOINT_CAPSULE.write_snapshot(writer)?;
+            write_point(&segment.a, writer)?;
+            write_point(&segment.b, writer)?;
+        } else {
+            TAG_CAPSULE.write_snapshot(writer)?;
+            write_scalar(s.half_height, writer)?;
+        }
+
+        write_scalar(s.radius, writer)
+    } else if let Some(s) = shape.as_shape::<Segment<N>>() {
+        TAG_SEGMENT.write_snapshot(writer)?;
+        write_point(&s.a, writer)?;
+        write_point(&s.b, writer)
+    } else if let Some(s) = shape.as_shape::<Triangle<N>>() {
+        TAG_TRIANGLE.write_snapshot(writer)?;
+        write_point(&s.a, writer)?;
+        write_point(&s.b, writer)?;
+        write_point(&s.c, writer)
+    } else if let Some(s) = shape.as_shape::<Plane<N>>() {
+        TAG_PLANE.write_snapshot(writer)?;
+        write_vector(&s.normal, writer)
+    } else if let Some(s) = shape.as_shape::<RoundedCuboid<N>>() {
+        TAG_ROUNDED_CUBOID.write_snapshot(writer)?;
+        write_vector(&s.half_extents, writer)?;
+        write_scalar(s.border_radius, writer)
+    } else if let Some(s) = shape.as_shape::<Compound<N>>() {
+        TAG_COMPOUND.write_snapshot(writer)?;
+        s.shapes().len().write_snapshot(writer)?;
+
+        for (m, part) in s.shapes() {
+            write_isometry(m, writer)?;
+            write_shape(part.as_ref(), writer)?;
+        }
+
+        Ok(())
+    } else if let Some(s) = shape.as_shape::<Polyline<N>>() {
+        TAG_POLYLINE.write_snapshot(writer)?;
+        write_points(s.points(), writer)?;
+        s.edges().len().write_snapshot(writer)?;
+
+        for edge in s.edges() {
+            edge.indices.x.write_snapshot(writer)?;
+            edge.indices.y.write_snapshot(writer)?;
+        }
+
+        s.oriented().write_snapshot(writer)?;
+        write_scalar(s.thickness(), writer)
+    } else if let Some(s) = shape.as_shape::<HeightField<N>>() {
+        TAG_HEIGHTFIELD.write_snapshot(writer)?;
+        write_heightfield(s, writer)
+    } else if let Some(s) = shape.as_shape::<PointCloud<N>>() {
+        TAG_POINT_CLOUD.write_snapshot(writer)?;
+        write_points(s.points(), writer)
+    } else {
+        write_dim_specific_shape(shape, writer)
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn write_dim_specific_shape<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    if let Some(s) = shape.as_shape::<ConvexPolygon<N>>() {
+        TAG_CONVEX.write_snapshot(writer)?;
+        write_points(s.points(), writer)
+    } else if let Some(s) = shape.as_shape::<TileGrid<N>>() {
+        TAG_TILE_GRID.write_snapshot(writer)?;
+        s.nrows().write_snapshot(writer)?;
+        s.ncols().write_snapshot(writer)?;
+        write_vector(s.cell_size(), writer)?;
+
+        // Row-major, like the cell identifiers.
+        for i in 0..s.nrows() {
+            for j in 0..s.ncols() {
+                s.is_solid(i, j).write_snapshot(writer)?;
+            }
+        }
+
+        Ok(())
+    } else {
+        Err(unsupported_shape())
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn write_dim_specific_shape<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    if let Some(s) = shape.as_shape::<ConvexHull<N>>() {
+        TAG_CONVEX.write_snapshot(writer)?;
+        write_points(s.points(), writer)?;
+
+        // Each face is a convex polygon, stored as a triangle fan.
+        let mut indices = Vec::new();
+        for i in 0..s.num_faces() {
+            let face = s.face_vertices(i);
+            for k in 1..face.len() - 1 {
+                indices.extend_from_slice(&[face[0], face[k], face[k + 1]]);
+            }
+        }
+
+        indices.len().write_snapshot(writer)?;
+        for i in indices {
+            i.write_snapshot(writer)?;
+        }
+
+        Ok(())
+    } else if let Some(s) = shape.as_shape::<TriMesh<N>>() {
+        TAG_TRIMESH.write_snapshot(writer)?;
+        write_points(s.points(), writer)?;
+        s.faces().len().write_snapshot(writer)?;
+
+        for face in s.faces() {
+            face.indices.x.write_snapshot(writer)?;
+            face.indices.y.write_snapshot(writer)?;
+            face.indices.z.write_snapshot(writer)?;
+        }
+
+        match s.uvs() {
+            Some(uvs) => {
+                true.write_snapshot(writer)?;
+                uvs.len().write_snapshot(writer)?;
+                for uv in uvs {
+                    write_scalar(uv.x, writer)?;
+                    write_scalar(uv.y, writer)?;
+                }
+            }
+            None => false.write_snapshot(writer)?,
+        }
+
+        match s.face_data() {
+            Some(data) => {
+                true.write_snapshot(writer)?;
+                for d in data {
+                    d.write_snapshot(writer)?;
+                }
+            }
+            None => false.write_snapshot(writer)?,
+        }
+
+        s.oriented().write_snapshot(writer)?;
+        s.closed().write_snapshot(writer)?;
+        write_scalar(s.thickness(), writer)?;
+        s.normal_cone_bvt().is_some().write_snapshot(writer)
+    } else if let Some(s) = shape.as_shape::<Quad<N>>() {
+        TAG_QUAD.write_snapshot(writer)?;
+        write_scalar(s.half_extents.x, writer)?;
+        write_scalar(s.half_extents.y, writer)
+    } else if let Some(s) = shape.as_shape::<Superellipsoid<N>>() {
+        TAG_SUPERELLIPSOID.write_snapshot(writer)?;
+        write_vector(&s.radii, writer)?;
+        write_scalar(s.e1, writer)?;
+        write_scalar(s.e2, writer)
+    } else if let Some(s) = shape.as_shape::<Cylinder<N>>() {
+        TAG_CYLINDER.write_snapshot(writer)?;
+        write_scalar(s.half_height, writer)?;
+        write_scalar(s.radius, writer)
+    } else if let Some(s) = shape.as_shape::<Cone<N>>() {
+        TAG_CONE.write_snapshot(writer)?;
+        write_scalar(s.half_height, writer)?;
+        write_scalar(s.radius, writer)
+    } else if let Some(s) = shape.as_shape::<Oriented<N, Cylinder<N>>>() {
+        TAG_ORIENTED.write_snapshot(writer)?;
+        write_rotation(&s.rotation, writer)?;
+        write_shape(&s.shape, writer)
+    } else if let Some(s) = shape.as_shape::<Oriented<N, Cone<N>>>() {
+        TAG_ORIENTED.write_snapshot(writer)?;
+        write_rotation(&s.rotation, writer)?;
+        write_shape(&s.shape, writer)
+    } else {
+        Err(unsupported_shape())
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn write_heightfield<N: RealField + Copy>(
+    heightfield: &HeightField<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    let heights = heightfield.heights();
+    heights.len().write_snapshot(writer)?;
+
+    for h in heights.iter() {
+        write_scalar(*h, writer)?;
+    }
+
+    write_vector(heightfield.scale(), writer)?;
+
+    for i in 0..heightfield.num_cells() {
+        heightfield.is_segment_removed(i).write_snapshot(writer)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "dim3")]
+fn write_heightfield<N: RealField + Copy>(
+    heightfield: &HeightField<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    let heights = heightfield.heights();
+    heights.nrows().write_snapshot(writer)?;
+    heights.ncols().write_snapshot(writer)?;
+
+    // Column-major, like the matrix storage.
+    for h in heights.iter() {
+        write_scalar(*h, writer)?;
+    }
+
+    write_vector(heightfield.scale(), writer)?;
+
+    for status in heightfield.cells_statuses().iter() {
+        status.bits().write_snapshot(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Reads a shape written by `write_shape`.
+pub fn read_shape<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<ShapeHandle<N>> {
+    let shape = match u8::read_snapshot(reader)? {
+        TAG_BALL => ShapeHandle::new(Ball::new(read_scalar(reader)?)),
+        TAG_CUBOID => ShapeHandle::new(Cuboid::new(read_vector(reader)?)),
+        TAG_CAPSULE => {
+            let half_height = read_scalar(reader)?;
+            ShapeHandle::new(Capsule::new(half_height, read_scalar(reader)?))
+        }
+        TAG_ENDPOINT_CAPSULE => {
+            let a = read_point(reader)?;
+            let b = read_point(reader)?;
+            ShapeHandle::new(Capsule::new_with_endpoints(a, b, read_scalar(reader)?))
+        }
+        TAG_SEGMENT => {
+            let a = read_point(reader)?;
+            ShapeHandle::new(Segment::new(a, read_point(reader)?))
+        }
+        TAG_TRIANGLE => {
+            let a = read_point(reader)?;
+            let b = read_point(reader)?;
+            ShapeHandle::new(Triangle::new(a, b, read_point(reader)?))
+        }
+        TAG_PLANE => ShapeHandle::new(Plane::new(Unit::new_unchecked(read_vector(reader)?))),
+        TAG_ROUNDED_CUBOID => {
+            let half_extents = read_vector(reader)?;
+            ShapeHandle::new(RoundedCuboid::new(half_extents, read_scalar(reader)?))
+        }
+        TAG_COMPOUND => {
+            let nparts = usize::read_snapshot(reader)?;
+            let mut parts = Vec::new();
+
+            for _ in 0..nparts {
+                let m = read_isometry(reader)?;
+                parts.push((m, read_shape(reader)?));
+            }
+
+            if parts.is_empty() {
+                return Err(invalid_data("empty compound shape"));
+            }
+
+            ShapeHandle::new(Compound::new(parts))
+        }
+        TAG_POLYLINE => {
+            let points = read_points(reader)?;
+            let nedges = usize::read_snapshot(reader)?;
+            let mut indices = Vec::new();
+
+            for _ in 0..nedges {
+                let a = read_index(reader, points.len())?;
+                indices.push(Point2::new(a, read_index(reader, points.len())?));
+            }
+
+            let mut polyline = Polyline::new(points, Some(indices));
+            polyline.set_oriented(bool::read_snapshot(reader)?);
+            polyline.set_thickness(read_thickness(reader)?);
+            ShapeHandle::new(polyline)
+        }
+        TAG_HEIGHTFIELD => ShapeHandle::new(read_heightfield(reader)?),
+        TAG_POINT_CLOUD => {
+            let points = read_points(reader)?;
+
+            if points.is_empty() {
+                return Err(invalid_data("empty point cloud"));
+            }
+
+            ShapeHandle::new(PointCloud::new(points))
+        }
+        tag => read_dim_specific_shape(tag, reader)?,
+    };
+
+    Ok(shape)
+}
+
+#[cfg(feature = "dim2")]
+fn read_dim_specific_shape<N: RealField + Copy>(
+    tag: u8,
+    reader: &mut dyn Read,
+) -> io::Result<ShapeHandle<N>> {
+    match tag {
+        TAG_CONVEX => ConvexPolygon::try_new(read_points(reader)?)
+            .map(ShapeHandle::new)
+            .ok_or_else(|| invalid_data("degenerate convex polygon")),
+        TAG_TILE_GRID => {
+            let nrows = usize::read_snapshot(reader)?;
+            let ncols = usize::read_snapshot(reader)?;
+            let cell_size: Vector<N> = read_vector(reader)?;
+
+            if cell_size.x <= N::zero() || cell_size.y <= N::zero() {
+                return Err(invalid_data("non-positive tile grid cell size"));
+            }
+
+            let ncells = nrows
+                .checked_mul(ncols)
+                .ok_or_else(|| invalid_data("too many tile grid cells"))?;
+            let mut solid = Vec::new();
+
+            for _ in 0..ncells {
+                solid.push(bool::read_snapshot(reader)?);
+            }
+
+            Ok(ShapeHandle::new(TileGrid::from_fn(
+                nrows,
+                ncols,
+                cell_size,
+                |i, j| solid[i * ncols + j],
+            )))
+        }
+        _ => Err(invalid_data("unknown shape type")),
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn read_dim_specific_shape<N: RealField + Copy>(
+    tag: u8,
+    reader: &mut dyn Read,
+) -> io::Result<ShapeHandle<N>> {
+    match tag {
+        TAG_CONVEX => {
+            let points = read_points(reader)?;
+            let nindices = usize::read_snapshot(reader)?;
+            let mut indices = Vec::new();
+
+            for _ in 0..nindices {
+                indices.push(read_index(reader, points.len())?);
+            }
+
+            ConvexHull::try_new(points, &indices)
+                .map(ShapeHandle::new)
+                .ok_or_else(|| invalid_data("degenerate convex hull"))
+        }
+        TAG_TRIMESH => {
+            let points = read_points(reader)?;
+            let nfaces = usize::read_snapshot(reader)?;
+            let mut indices = Vec::new();
+
+            for _ in 0..nfaces {
+                let a = read_index(reader, points.len())?;
+                let b = read_index(reader, points.len())?;
+                indices.push(Point3::new(a, b, read_index(reader, points.len())?));
+            }
+
+            let uvs = if bool::read_snapshot(reader)? {
+                let nuvs = usize::read_snapshot(reader)?;
+                let mut uvs = Vec::new();
+                for _ in 0..nuvs {
+                    let u = read_scalar(reader)?;
+                    uvs.push(Point2::new(u, read_scalar(reader)?));
+                }
+                Some(uvs)
+            } else {
+                None
+            };
+
+            let mut mesh = TriMesh::new(points, indices, uvs);
+
+            if bool::read_snapshot(reader)? {
+                let mut data = Vec::new();
+                for _ in 0..nfaces {
+                    data.push(u32::read_snapshot(reader)?);
+                }
+
+                if data.len() != mesh.faces().len() {
+                    return Err(invalid_data("per-face data length mismatch"));
+                }
+
+                mesh.set_face_data(Some(data));
+            }
+
+            mesh.set_oriented(bool::read_snapshot(reader)?);
+            mesh.set_closed(bool::read_snapshot(reader)?);
+            mesh.set_thickness(read_thickness(reader)?);
+
+            if bool::read_snapshot(reader)? {
+                mesh.compute_normal_cones();
+            }
+
+            Ok(ShapeHandle::new(mesh))
+        }
+        TAG_QUAD => {
+            let x = read_scalar(reader)?;
+            let y = read_scalar(reader)?;
+            Ok(ShapeHandle::new(Quad::new(Vector2::new(x, y))))
+        }
+        TAG_SUPERELLIPSOID => {
+            let radii = read_vector(reader)?;
+            let e1 = read_scalar(reader)?;
+            let e2 = read_scalar(reader)?;
+            Ok(ShapeHandle::new(Superellipsoid::new(radii, e1, e2)))
+        }
+        TAG_CYLINDER => {
+            let (half_height, radius) = read_half_height_and_radius(reader)?;
+            Ok(ShapeHandle::new(Cylinder::new(half_height, radius)))
+        }
+        TAG_CONE => {
+            let (half_height, radius) = read_half_height_and_radius(reader)?;
+            Ok(ShapeHandle::new(Cone::new(half_height, radius)))
+        }
+        TAG_ORIENTED => {
+            let rotation = read_rotation(reader)?;
+            let shape = read_shape(reader)?;
+
+            if let Some(s) = shape.as_shape::<Cylinder<N>>() {
+                Ok(ShapeHandle::new(Oriented::new(*s, rotation)))
+            } else if let Some(s) = shape.as_shape::<Cone<N>>() {
+                Ok(ShapeHandle::new(Oriented::new(*s, rotation)))
+            } else {
+                Err(invalid_data("unsupported oriented shape"))
+            }
+        }
+        _ => Err(invalid_data("unknown shape type")),
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn read_heightfield<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<HeightField<N>> {
+    let nheights = usize::read_snapshot(reader)?;
+
+    if nheights < 2 {
+        return Err(invalid_data("heightfield with less than two heights"));
+    }
+
+    let mut heights = Vec::new();
+    for _ in 0..nheights {
+        heights.push(read_scalar(reader)?);
+    }
+
+    let mut heightfield = HeightField::new(DVector::from_vec(heights), read_vector(reader)?);
+
+    for i in 0..heightfield.num_cells() {
+        if bool::read_snapshot(reader)? {
+            heightfield.set_segment_removed(i, true);
+        }
+    }
+
+    Ok(heightfield)
+}
+
+#[cfg(feature = "dim3")]
+fn read_heightfield<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<HeightField<N>> {
+    let nrows = usize::read_snapshot(reader)?;
+    let ncols = usize::read_snapshot(reader)?;
+
+    if nrows < 2 || ncols < 2 {
+        return Err(invalid_data(
+            "heightfield with less than two rows or columns",
+        ));
+    }
+
+    let mut heights = Vec::new();
+    for _ in 0..nrows * ncols {
+        heights.push(read_scalar(reader)?);
+    }
+
+    let heights = DMatrix::from_vec(nrows, ncols, heights);
+    let mut heightfield = HeightField::new(heights, read_vector(reader)?);
+
+    for status in heightfield.cells_statuses_mut().iter_mut() {
+        *status = HeightFieldCellStatus::from_bits(u8::read_snapshot(reader)?)
+            .ok_or_else(|| invalid_data("invalid heightfield cell status"))?;
+    }
+
+    Ok(heightfield)
+}
+
+/// Writes a scalar as a 64-bits float.
+pub fn write_scalar<N: RealField + Copy>(value: N, writer: &mut dyn Write) -> io::Result<()> {
+    let value: f64 = na::try_convert(value)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "unrepresentable scalar"))?;
+    value.write_snapshot(writer)
+}
+
+/// Reads a scalar written by `write_scalar`.
+pub fn read_scalar<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<N> {
+    Ok(na::convert(f64::read_snapshot(reader)?))
+}
+
+fn write_vector<N: RealField + Copy>(v: &Vector<N>, writer: &mut dyn Write) -> io::Result<()> {
+    for i in 0..DIM {
+        write_scalar(v[i], writer)?;
+    }
+
+    Ok(())
+}
+
+fn read_vector<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<Vector<N>> {
+    let mut res = Vector::zeros();
+
+    for i in 0..DIM {
+        res[i] = read_scalar(reader)?;
+    }
+
+    Ok(res)
+}
+
+fn write_point<N: RealField + Copy>(p: &Point<N>, writer: &mut dyn Write) -> io::Result<()> {
+    write_vector(&p.coords, writer)
+}
+
+fn read_point<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<Point<N>> {
+    read_vector(reader).map(Point::from)
+}
+
+fn write_points<N: RealField + Copy>(
+    points: &[Point<N>],
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    points.len().write_snapshot(writer)?;
+
+    for p in points {
+        write_point(p, writer)?;
+    }
+
+    Ok(())
+}
+
+fn read_points<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<Vec<Point<N>>> {
+    let npoints = usize::read_snapshot(reader)?;
+    let mut points = Vec::new();
+
+    for _ in 0..npoints {
+        points.push(read_point(reader)?);
+    }
+
+    Ok(points)
+}
+
+fn read_thickness<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<N> {
+    let thickness = read_scalar(reader)?;
+
+    if thickness >= N::zero() {
+        Ok(thickness)
+    } else {
+        Err(invalid_data("negative thickness"))
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn read_half_height_and_radius<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<(N, N)> {
+    let half_height = read_scalar(reader)?;
+    let radius = read_scalar(reader)?;
+
+    if half_height > N::zero() && radius > N::zero() {
+        Ok((half_height, radius))
+    } else {
+        Err(invalid_data("non-positive half-height or radius"))
+    }
+}
+
+fn read_index(reader: &mut dyn Read, len: usize) -> io::Result<usize> {
+    let i = usize::read_snapshot(reader)?;
+
+    if i < len {
+        Ok(i)
+    } else {
+        Err(invalid_data("vertex index out of bounds"))
+    }
+}
+
+/// Writes an isometry.
+///
+/// The rotation is stored as the raw components of its unit complex number or unit quaternion.
+pub fn write_isometry<N: RealField + Copy>(
+    m: &Isometry<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    write_vector(&m.translation.vector, writer)?;
+
+    #[cfg(feature = "dim2")]
+    {
+        write_scalar(m.rotation.re, writer)?;
+        write_scalar(m.rotation.im, writer)
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        write_rotation(&m.rotation, writer)
+    }
+}
+
+/// Reads an isometry written by `write_isometry`.
+pub fn read_isometry<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<Isometry<N>> {
+    let translation = read_vector(reader)?;
+
+    #[cfg(feature = "dim2")]
+    {
+        let re = read_scalar(reader)?;
+        let im = read_scalar(reader)?;
+        let rotation = UnitComplex::new_unchecked(Complex::new(re, im));
+        Ok(Isometry::from_parts(translation.into(), rotation))
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        let rotation = read_rotation(reader)?;
+        Ok(Isometry::from_parts(translation.into(), rotation))
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn write_rotation<N: RealField + Copy>(
+    rotation: &UnitQuaternion<N>,
+    writer: &mut dyn Write,
+) -> io::Result<()> {
+    for c in rotation.coords.iter() {
+        write_scalar(*c, writer)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "dim3")]
+fn read_rotation<N: RealField + Copy>(reader: &mut dyn Read) -> io::Result<UnitQuaternion<N>> {
+    let mut coords = Vector4::zeros();
+    for i in 0..4 {
+        coords[i] = read_scalar(reader)?;
+    }
+
+    Ok(UnitQuaternion::new_unchecked(Quaternion::from(coords)))
+}
+
+pub(crate) fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn unsupported_shape() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "shape not supported by the snapshot format",
+    )
+}
@@ -4,27 +4,38 @@ use na::{RealField, Unit};
 
 use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Rotation, Translation, Vector};
-use crate::pipeline::broad_phase::{BroadPhase, BroadPhasePairFilter, DBVTBroadPhase};
+use crate::pipeline::broad_phase::{
+    BroadPhase, BroadPhasePairFilter, BroadPhaseUpdateBudget, DBVTBroadPhase,
+};
 use crate::pipeline::glue::{
     self, FirstInterferenceWithRay, InterferencesWithAABB, InterferencesWithPoint,
-    InterferencesWithRay,
+    InterferencesWithRay, QueryPipeline, SortedInterferencesWithRay,
 };
 use crate::pipeline::narrow_phase::{
-    ContactAlgorithm, ContactEvents, DefaultContactDispatcher, DefaultProximityDispatcher,
-    Interaction, InteractionGraph, NarrowPhase, ProximityDetector, ProximityEvents,
+    ContactAlgorithm, ContactDispatcher, ContactEvents, DefaultContactDispatcher,
+    DefaultProximityDispatcher, Interaction, InteractionGraph, Island, NarrowPhase,
+    ProximityDetector, ProximityEvents, SpeculativeContactDispatcher, TOIEvents,
     TemporaryInteractionIndex,
 };
 use crate::pipeline::object::{
-    CollisionGroups, CollisionObject, CollisionObjectSet, CollisionObjectSlab,
-    CollisionObjectSlabHandle, CollisionObjects, GeometricQueryType,
+    CollisionGroups, CollisionObject, CollisionObjectQueryFlags, CollisionObjectSet,
+    CollisionObjectSlab, CollisionObjectSlabHandle, CollisionObjects, GeometricQueryType,
 };
+use crate::pipeline::snapshot::{self, SnapshotData};
 use crate::query::{ContactManifold, DefaultTOIDispatcher, Proximity, Ray, TOIDispatcher, TOI};
 use crate::shape::{Shape, ShapeHandle};
+use slab::Slab;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::sync::Arc;
 
 /// Type of the broad phase trait-object used by the collision world.
 pub type BroadPhaseObject<N> = Box<dyn BroadPhase<N, AABB<N>, CollisionObjectSlabHandle>>;
 
 /// A world that handles collision objects.
+///
+/// The world is `Send + Sync` whenever the user data `T` is, so its read-only queries, e.g.,
+/// `interferences_with_ray`, can be run from several threads at once.
 pub struct CollisionWorld<N: RealField + Copy, T> {
     /// The set of objects on this collision world.
     pub objects: CollisionObjectSlab<N, T>,
@@ -44,8 +55,33 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
     /// Creates a new collision world.
     // FIXME: use default values for `margin` and allow its modification by the user ?
     pub fn new(margin: N) -> CollisionWorld<N, T> {
+        Self::with_contact_dispatcher(margin, Box::new(DefaultContactDispatcher::new()))
+    }
+
+    /// Creates a new collision world generating speculative contacts.
+    ///
+    /// In addition to the regular contacts, pairs of convex shapes separated by a distance
+    /// smaller than `speculative_margin` will be given a single contact with a negative depth.
+    /// Its `separation()` is the current distance between the two shapes. Both objects must be
+    /// registered with a `GeometricQueryType::Contacts` query type. Note that the broad phase must
+    /// report the pair for speculative contacts to be generated, so the objects query limits or
+    /// the broad-phase `margin` should be large enough. Speculative contacts are not effective
+    /// contacts, use `self.contact_pairs(false)` to retrieve them.
+    pub fn new_speculative(margin: N, speculative_margin: N) -> CollisionWorld<N, T> {
+        Self::with_contact_dispatcher(
+            margin,
+            Box::new(SpeculativeContactDispatcher::new(speculative_margin)),
+        )
+    }
+
+    /// Creates a new collision world using the given contact dispatcher.
+    ///
+    /// This is typically used with a `RegistryContactDispatcher` to support user-defined shapes.
+    pub fn with_contact_dispatcher(
+        margin: N,
+        coll_dispatcher: Box<dyn ContactDispatcher<N>>,
+    ) -> CollisionWorld<N, T> {
         let objects = CollisionObjectSlab::new();
-        let coll_dispatcher = Box::new(DefaultContactDispatcher::new());
         let prox_dispatcher = Box::new(DefaultProximityDispatcher::new());
         let toi_dispatcher = Box::new(DefaultTOIDispatcher);
         let broad_phase =
@@ -101,6 +137,7 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
     /// 1. Clears the event pools.
     /// 2. Executes the broad phase first.
     /// 3. Executes the narrow phase.
+    /// 4. Computes the time of impact events of collision objects with a linear velocity.
     pub fn update(&mut self) {
         self.narrow_phase.clear_events();
 
@@ -112,6 +149,12 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
             self.pair_filters.as_ref().map(|f| &**f),
         );
 
+        self.narrow_phase.update_toi_events(
+            &self.interactions,
+            &self.objects,
+            &*self.toi_dispatcher,
+        );
+
         // Clear update flags.
         for (_, co) in self.objects.iter_mut() {
             co.clear_update_flags();
@@ -143,6 +186,11 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
         }
     }
 
+    /// Releases the memory kept by the broad phase after many collision objects were removed.
+    pub fn shrink_to_fit(&mut self) {
+        self.broad_phase.shrink_to_fit()
+    }
+
     /// Sets the position of the collision object attached to the specified object.
     #[deprecated = "Call directly the method `.set_position` on the collision object."]
     pub fn set_position(&mut self, handle: CollisionObjectSlabHandle, pos: Isometry<N>) {
@@ -227,6 +275,22 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
         )
     }
 
+    /// Executes part of the broad phase of the collision detection pipeline.
+    ///
+    /// The broad phase stops once the given budget is exhausted, and resumes at the next call to
+    /// this method or to `perform_broad_phase`. Returns `true` if the broad phase is up to date.
+    /// This avoids long updates when a large number of collision objects are added at once.
+    pub fn perform_broad_phase_with_budget(&mut self, budget: BroadPhaseUpdateBudget) -> bool {
+        glue::perform_broad_phase_with_budget(
+            &self.objects,
+            &mut *self.broad_phase,
+            &mut self.narrow_phase,
+            &mut self.interactions,
+            self.pair_filters.as_deref(),
+            budget,
+        )
+    }
+
     /// Executes the narrow phase of the collision detection pipeline.
     pub fn perform_narrow_phase(&mut self) {
         glue::perform_narrow_phase(
@@ -348,6 +412,21 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
         glue::interferences_with_ray(&self.objects, &*self.broad_phase, ray, max_toi, groups)
     }
 
+    /// Computes the interferences between every rigid bodies on this world and a ray, by
+    /// increasing time of impact.
+    ///
+    /// Unlike `interferences_with_ray`, the exact ray casts are performed lazily, so only the
+    /// objects located before the last yielded hit are tested.
+    #[inline]
+    pub fn interferences_with_ray_sorted<'a, 'b>(
+        &'a self,
+        ray: &'b Ray<N>,
+        max_toi: N,
+        groups: &'b CollisionGroups,
+    ) -> SortedInterferencesWithRay<'a, 'b, N, CollisionObjectSlab<N, T>> {
+        glue::interferences_with_ray_sorted(&self.objects, &*self.broad_phase, ray, max_toi, groups)
+    }
+
     /// Computes the first interference with `ray` and
     #[inline]
     pub fn first_interference_with_ray<'a, 'b>(
@@ -379,6 +458,15 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
         glue::interferences_with_aabb(&self.objects, &*self.broad_phase, aabb, groups)
     }
 
+    /// An immutable view of this world used to perform geometric queries on its collision objects.
+    ///
+    /// The candidate objects of each query are selected using the broad phase bounding volumes
+    /// computed during the last call to `self.update()`.
+    #[inline]
+    pub fn query_pipeline(&self) -> QueryPipeline<'_, N, CollisionObjectSlab<N, T>> {
+        QueryPipeline::new(&self.objects, &*self.broad_phase)
+    }
+
     /// Customize the selection of narrowphase collision detection algorithms
     pub fn set_narrow_phase(&mut self, narrow_phase: NarrowPhase<N, CollisionObjectSlabHandle>) {
         self.narrow_phase = narrow_phase;
@@ -559,6 +647,8 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
 
     /// All the proximity pairs involving the specified collision object.
     ///
+    /// Only the interactions of this object are visited, not every interaction of the world.
+    ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
     /// for details.
     pub fn proximities_with(
@@ -582,6 +672,8 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
 
     /// All the contact pairs involving the specified collision object.
     ///
+    /// The cost of this iteration is proportional to the number of interactions of this object.
+    ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
     /// for details.
     pub fn contacts_with(
@@ -644,6 +736,17 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
         Some(self.interactions.collision_objects_in_proximity_of(id))
     }
 
+    /// The sets of non-static collision objects connected by effective contacts.
+    ///
+    /// See `InteractionGraph::islands` for details.
+    pub fn islands(
+        &self,
+        is_static: impl FnMut(CollisionObjectSlabHandle) -> bool,
+        can_sleep: impl FnMut(CollisionObjectSlabHandle) -> bool,
+    ) -> Vec<Island<CollisionObjectSlabHandle>> {
+        self.interactions.islands(is_static, can_sleep)
+    }
+
     /*
      *
      * Events
@@ -658,4 +761,330 @@ impl<N: RealField + Copy, T> CollisionWorld<N, T> {
     pub fn proximity_events(&self) -> &ProximityEvents<CollisionObjectSlabHandle> {
         self.narrow_phase.proximity_events()
     }
+
+    /// The time of impact events pool, sorted by increasing time of impact.
+    pub fn toi_events(&self) -> &TOIEvents<N, CollisionObjectSlabHandle> {
+        self.narrow_phase.toi_events()
+    }
+}
+
+/// The content of a collision object in a snapshot.
+struct CollisionObjectSnapshot<N: RealField + Copy, T> {
+    handle: CollisionObjectSlabHandle,
+    shape: usize,
+    position: Isometry<N>,
+    predicted_position: Option<Isometry<N>>,
+    linear_velocity: Option<Vector<N>>,
+    collision_groups: CollisionGroups,
+    query_type: GeometricQueryType<N>,
+    query_flags: CollisionObjectQueryFlags,
+    data: T,
+}
+
+/// The shapes and collision objects read from a snapshot.
+type SnapshotContent<N, T> = (Vec<ShapeHandle<N>>, Vec<CollisionObjectSnapshot<N, T>>);
+
+impl<N: RealField + Copy, T: SnapshotData> CollisionWorld<N, T> {
+    /// Writes a snapshot of all the collision objects of this world.
+    ///
+    /// This saves the handle, shape, position, predicted position, linear velocity, collision
+    /// groups, query type, query flags, and user data of each collision object. Shapes shared by
+    /// several collision objects are written only once. See `snapshot::write_shape` for the
+    /// supported shapes.
+    ///
+    /// The broad-phase and narrow-phase states are not saved: `load` rebuilds them from the
+    /// saved collision objects, in a deterministic order.
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let _ = self.write_objects(|_| true, &mut writer)?;
+        Ok(())
+    }
+
+    /// Writes a snapshot of the collision objects which AABB intersects `aabb`.
+    ///
+    /// The snapshot has the same format as the ones written by `self.save`, so it can be merged
+    /// into any world with `merge`. Returns the handles of the saved collision objects, sorted in
+    /// increasing order.
+    pub fn save_region<W: Write>(
+        &self,
+        aabb: &AABB<N>,
+        mut writer: W,
+    ) -> io::Result<Vec<CollisionObjectSlabHandle>> {
+        self.write_objects(
+            |co| co.shape().aabb(co.position()).intersects(aabb),
+            &mut writer,
+        )
+    }
+
+    /// Moves the collision objects which AABB intersects `aabb` out of this world, into a
+    /// snapshot.
+    ///
+    /// This is the same as `self.save_region(aabb, writer)` followed by the removal of the saved
+    /// collision objects, e.g., to hibernate a region of an open world. The collision objects
+    /// are removed only if the snapshot was written successfully. Returns the handles of the
+    /// removed collision objects.
+    pub fn extract_region<W: Write>(
+        &mut self,
+        aabb: &AABB<N>,
+        writer: W,
+    ) -> io::Result<Vec<CollisionObjectSlabHandle>> {
+        let handles = self.save_region(aabb, writer)?;
+        self.remove(&handles);
+        Ok(handles)
+    }
+
+    fn write_objects(
+        &self,
+        mut filter: impl FnMut(&CollisionObject<N, T>) -> bool,
+        writer: &mut dyn Write,
+    ) -> io::Result<Vec<CollisionObjectSlabHandle>> {
+        let objects: Vec<_> = self.objects.iter().filter(|(_, co)| filter(co)).collect();
+
+        if objects
+            .iter()
+            .any(|(handle, _)| handle.0 > snapshot::MAX_SNAPSHOT_HANDLE)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "collision object handle too large for a snapshot",
+            ));
+        }
+        let mut shape_ids = HashMap::new();
+        let mut shapes = Vec::new();
+
+        for (_, co) in &objects {
+            let key = Arc::as_ptr(co.shape().as_arc()) as *const () as usize;
+            let _ = shape_ids.entry(key).or_insert_with(|| {
+                shapes.push(co.shape());
+                shapes.len() - 1
+            });
+        }
+
+        snapshot::write_header(writer)?;
+        shapes.len().write_snapshot(writer)?;
+
+        for shape in shapes {
+            snapshot::write_shape(shape.as_ref(), writer)?;
+        }
+
+        objects.len().write_snapshot(writer)?;
+
+        for (handle, co) in &objects {
+            let key = Arc::as_ptr(co.shape().as_arc()) as *const () as usize;
+            handle.0.write_snapshot(writer)?;
+            shape_ids[&key].write_snapshot(writer)?;
+            snapshot::write_isometry(co.position(), writer)?;
+
+            match co.predicted_position() {
+                Some(pos) => {
+                    true.write_snapshot(writer)?;
+                    snapshot::write_isometry(pos, writer)?;
+                }
+                None => false.write_snapshot(writer)?,
+            }
+
+            match co.linear_velocity() {
+                Some(vel) => {
+                    true.write_snapshot(writer)?;
+                    for v in vel.iter() {
+                        snapshot::write_scalar(*v, writer)?;
+                    }
+                }
+                None => false.write_snapshot(writer)?,
+            }
+
+            let groups = co.collision_groups();
+            groups.membership.write_snapshot(writer)?;
+            groups.whitelist.write_snapshot(writer)?;
+            groups.blacklist.write_snapshot(writer)?;
+
+            match co.query_type() {
+                GeometricQueryType::Contacts(linear, angular) => {
+                    0u8.write_snapshot(writer)?;
+                    snapshot::write_scalar(linear, writer)?;
+                    snapshot::write_scalar(angular, writer)?;
+                }
+                GeometricQueryType::Proximity(margin) => {
+                    1u8.write_snapshot(writer)?;
+                    snapshot::write_scalar(margin, writer)?;
+                }
+            }
+
+            co.query_flags().bits().write_snapshot(writer)?;
+            co.data().write_snapshot(writer)?;
+        }
+
+        Ok(objects.iter().map(|(handle, _)| *handle).collect())
+    }
+
+    /// Replaces all the collision objects of this world by the ones of a snapshot written by
+    /// `self.save`.
+    ///
+    /// Each collision object gets back the handle it had when the snapshot was saved. The
+    /// snapshot is entirely read before this world is modified, so this world is left unchanged
+    /// if an error occurs. The contact and proximity dispatchers, the pair filters, and the
+    /// margin of the broad phase are those of `self`.
+    ///
+    /// Snapshots with handles greater than `snapshot::MAX_SNAPSHOT_HANDLE` are rejected, so
+    /// that a corrupted snapshot cannot allocate an arbitrary amount of memory.
+    ///
+    /// The broad-phase proxies are created in increasing handle order and the contacts are
+    /// recomputed by the next `self.update()`. Thus, loading the same snapshot into two worlds
+    /// created the same way results in identical worlds.
+    pub fn load<R: Read>(&mut self, mut reader: R) -> io::Result<()> {
+        let (shapes, objects) = Self::read_objects(&mut reader)?;
+
+        let handles: Vec<_> = self.objects.iter().map(|(handle, _)| handle).collect();
+        self.remove(&handles);
+        self.narrow_phase.clear_events();
+
+        let mut slab_entries = Vec::with_capacity(objects.len());
+
+        for object in objects {
+            let shape = shapes[object.shape].clone();
+            let (proxy_handle, graph_index) = glue::create_proxies(
+                object.handle,
+                &mut *self.broad_phase,
+                &mut self.interactions,
+                &object.position,
+                shape.as_ref(),
+                object.query_type,
+            );
+
+            let mut co = CollisionObject::new(
+                Some(proxy_handle),
+                Some(graph_index),
+                object.position,
+                shape,
+                object.collision_groups,
+                object.query_type,
+                object.data,
+            );
+            co.set_predicted_position(object.predicted_position);
+            co.set_linear_velocity(object.linear_velocity);
+            co.set_query_flags(object.query_flags);
+
+            slab_entries.push((object.handle.0, co));
+        }
+
+        self.objects.objects = slab_entries.into_iter().collect::<Slab<_>>();
+
+        Ok(())
+    }
+
+    /// Adds to this world the collision objects of a snapshot written by `save`,
+    /// `save_region`, or `extract_region`, possibly by another world.
+    ///
+    /// The collision objects already in this world are left untouched, and the merged ones are
+    /// given new handles. Returns the mapping from the handles saved in the snapshot to the new
+    /// handles. Shapes shared by several collision objects of the snapshot remain shared. The
+    /// snapshot is entirely read before this world is modified, so this world is left unchanged
+    /// if an error occurs.
+    pub fn merge<R: Read>(
+        &mut self,
+        mut reader: R,
+    ) -> io::Result<HashMap<CollisionObjectSlabHandle, CollisionObjectSlabHandle>> {
+        let (shapes, objects) = Self::read_objects(&mut reader)?;
+        let mut handles = HashMap::with_capacity(objects.len());
+
+        for object in objects {
+            let (handle, co) = self.add(
+                object.position,
+                shapes[object.shape].clone(),
+                object.collision_groups,
+                object.query_type,
+                object.data,
+            );
+            co.set_predicted_position(object.predicted_position);
+            co.set_linear_velocity(object.linear_velocity);
+            co.set_query_flags(object.query_flags);
+
+            let _ = handles.insert(object.handle, handle);
+        }
+
+        Ok(handles)
+    }
+
+    fn read_objects(reader: &mut dyn Read) -> io::Result<SnapshotContent<N, T>> {
+        snapshot::read_header(reader)?;
+
+        let nshapes = usize::read_snapshot(reader)?;
+        let mut shapes = Vec::new();
+
+        for _ in 0..nshapes {
+            shapes.push(snapshot::read_shape(reader)?);
+        }
+
+        let nobjects = usize::read_snapshot(reader)?;
+        let mut objects = Vec::new();
+
+        for _ in 0..nobjects {
+            let handle = CollisionObjectSlabHandle(usize::read_snapshot(reader)?);
+
+            if handle.0 > snapshot::MAX_SNAPSHOT_HANDLE {
+                return Err(snapshot::invalid_data("collision object handle too large"));
+            }
+
+            let shape = usize::read_snapshot(reader)?;
+
+            if shape >= shapes.len() {
+                return Err(snapshot::invalid_data("shape index out of bounds"));
+            }
+
+            let last_handle = objects
+                .last()
+                .map(|last: &CollisionObjectSnapshot<N, T>| last.handle);
+            if last_handle >= Some(handle) {
+                return Err(snapshot::invalid_data(
+                    "collision objects not sorted by handle",
+                ));
+            }
+
+            let position = snapshot::read_isometry(reader)?;
+            let predicted_position = if bool::read_snapshot(reader)? {
+                Some(snapshot::read_isometry(reader)?)
+            } else {
+                None
+            };
+            let linear_velocity = if bool::read_snapshot(reader)? {
+                let mut vel = Vector::zeros();
+                for v in vel.iter_mut() {
+                    *v = snapshot::read_scalar(reader)?;
+                }
+                Some(vel)
+            } else {
+                None
+            };
+
+            let mut collision_groups = CollisionGroups::new();
+            collision_groups.membership = u32::read_snapshot(reader)?;
+            collision_groups.whitelist = u32::read_snapshot(reader)?;
+            collision_groups.blacklist = u32::read_snapshot(reader)?;
+
+            let query_type = match u8::read_snapshot(reader)? {
+                0 => {
+                    let linear = snapshot::read_scalar(reader)?;
+                    GeometricQueryType::Contacts(linear, snapshot::read_scalar(reader)?)
+                }
+                1 => GeometricQueryType::Proximity(snapshot::read_scalar(reader)?),
+                _ => return Err(snapshot::invalid_data("invalid query type")),
+            };
+
+            let query_flags = CollisionObjectQueryFlags::from_bits(u8::read_snapshot(reader)?)
+                .ok_or_else(|| snapshot::invalid_data("invalid query flags"))?;
+
+            objects.push(CollisionObjectSnapshot {
+                handle,
+                shape,
+                position,
+                predicted_position,
+                linear_velocity,
+                collision_groups,
+                query_type,
+                query_flags,
+                data: T::read_snapshot(reader)?,
+            });
+        }
+
+        Ok((shapes, objects))
+    }
 }
@@ -165,5 +165,5 @@ pub fn unit_rectangle<N: RealField + Copy>() -> Polyline<N> {
     p_ul[0] = m0_5;
     p_ul[1] = _0_5;
 
-    Polyline::new(vec![p_ur, p_ul, p_dl, p_dr], None)
+    Polyline::new(vec![p_ur, p_ul, p_dl, p_dr], None).with_closed(true)
 }
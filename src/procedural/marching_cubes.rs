@@ -0,0 +1,221 @@
+use super::{IndexBuffer, TriMesh};
+use crate::bounding_volume::AABB;
+use na::{self, Point3, RealField};
+use std::collections::HashMap;
+
+// The corners of each face of a cube, counterclockwise around the outward normal of the face.
+// The corner `c` is located at `(c & 1, (c >> 1) & 1, c >> 2)`.
+const FACES: [[usize; 4]; 6] = [
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+];
+
+/// Extracts the surface separating the negative and positive values of a scalar field.
+///
+/// The field is sampled at the vertices of a regular grid subdividing `bounds` into
+/// `resolution[i]` cells along the `i`-th axis. The region where the field is negative is
+/// considered inside of the surface, e.g., with signed distance functions. See
+/// `marching_cubes_grid` for details.
+pub fn marching_cubes<N: RealField + Copy>(
+    mut field: impl FnMut(&Point3<N>) -> N,
+    bounds: &AABB<N>,
+    resolution: [usize; 3],
+) -> TriMesh<N> {
+    let dims = [resolution[0] + 1, resolution[1] + 1, resolution[2] + 1];
+    let mut values = Vec::with_capacity(dims[0] * dims[1] * dims[2]);
+
+    for k in 0..dims[2] {
+        for j in 0..dims[1] {
+            for i in 0..dims[0] {
+                values.push(field(&grid_point(bounds, dims, [i, j, k])));
+            }
+        }
+    }
+
+    marching_cubes_grid(&values, dims, bounds)
+}
+
+/// Extracts the surface separating the negative and positive values sampled on a regular grid,
+/// e.g., voxel data.
+///
+/// The grid has `dims[i]` samples along the `i`-th axis, and the first and last samples along
+/// each axis lie on the sides of `bounds`. The sample at the grid coordinates `(i, j, k)` is
+/// `values[i + dims[0] * (j + dims[1] * k)]`.
+///
+/// The surface vertices are placed on the grid edges by linear interpolation of the samples.
+/// Inside of each grid cell, the surface is built by linking its intersections with the faces of
+/// the cell, so that the surfaces generated by adjacent cells always match. The ambiguous faces,
+/// with two diagonally opposite negative corners, are resolved with the average of their four
+/// samples. The surface is closed along the sides of `bounds`, so the resulting mesh is
+/// watertight and its triangles are oriented counterclockwise when seen from the positive side.
+pub fn marching_cubes_grid<N: RealField + Copy>(
+    values: &[N],
+    dims: [usize; 3],
+    bounds: &AABB<N>,
+) -> TriMesh<N> {
+    assert!(
+        dims.iter().all(|d| *d >= 2),
+        "The grid must have at least two samples along each axis."
+    );
+    assert_eq!(
+        values.len(),
+        dims[0] * dims[1] * dims[2],
+        "There must be exactly one value per grid sample."
+    );
+
+    // The grid is surrounded by a layer of positive samples at the same locations as the
+    // samples on its sides, which closes the surface without moving it.
+    let value = |p: [isize; 3]| {
+        if (0..3).all(|k| p[k] >= 0 && p[k] < dims[k] as isize) {
+            values[p[0] as usize + dims[0] * (p[1] as usize + dims[1] * p[2] as usize)]
+        } else {
+            N::one()
+        }
+    };
+    let clamp = |p: [isize; 3]| {
+        let mut res = [0; 3];
+
+        for k in 0..3 {
+            res[k] = p[k].max(0).min(dims[k] as isize - 1) as usize;
+        }
+
+        res
+    };
+
+    let mut coords = Vec::new();
+    let mut indices = Vec::new();
+    let mut vertex_ids: HashMap<(usize, usize), u32> = HashMap::new();
+
+    for ck in -1..dims[2] as isize {
+        for cj in -1..dims[1] as isize {
+            for ci in -1..dims[0] as isize {
+                let mut corners = [[0; 3]; 8];
+                let mut samples = [N::zero(); 8];
+
+                for c in 0..8 {
+                    corners[c] = [
+                        ci + (c & 1) as isize,
+                        cj + ((c >> 1) & 1) as isize,
+                        ck + (c >> 2) as isize,
+                    ];
+                    samples[c] = value(corners[c]);
+                }
+
+                let num_inside = samples.iter().filter(|v| **v < N::zero()).count();
+
+                if num_inside == 0 || num_inside == 8 {
+                    continue;
+                }
+
+                // The polygon boundaries in this cell, as links between the cell edges,
+                // identified by `8 * a + b` for the cell corners `a < b`.
+                let mut next = [usize::MAX; 64];
+
+                for face in &FACES {
+                    for (entry, exit) in face_segments(face, &samples) {
+                        next[entry] = exit;
+                    }
+                }
+
+                for start in 0..64 {
+                    if next[start] == usize::MAX {
+                        continue;
+                    }
+
+                    let mut polygon = Vec::new();
+                    let mut curr = start;
+
+                    while next[curr] != usize::MAX {
+                        let (a, b) = (curr / 8, curr % 8);
+                        let cell_edge = next[curr];
+                        next[curr] = usize::MAX;
+
+                        let ra = clamp(corners[a]);
+                        let rb = clamp(corners[b]);
+                        let ia = ra[0] + dims[0] * (ra[1] + dims[1] * ra[2]);
+                        let ib = rb[0] + dims[0] * (rb[1] + dims[1] * rb[2]);
+
+                        // Crossings located on a zero sample all share its vertex.
+                        let key = if samples[a].is_zero() {
+                            (ia, ia)
+                        } else if samples[b].is_zero() {
+                            (ib, ib)
+                        } else {
+                            (ia.min(ib), ia.max(ib))
+                        };
+
+                        let id = *vertex_ids
+                            .entry(key)
+                            .or_insert_with(|| {
+                                let (pa, pb) =
+                                    (grid_point(bounds, dims, ra), grid_point(bounds, dims, rb));
+                                let t = samples[a] / (samples[a] - samples[b]);
+                                coords.push(pa + (pb - pa) * t);
+                                coords.len() as u32 - 1
+                            });
+
+                        polygon.push(id);
+                        curr = cell_edge;
+                    }
+
+                    for i in 1..polygon.len().saturating_sub(1) {
+                        let tri = Point3::new(polygon[0], polygon[i], polygon[i + 1]);
+
+                        if tri.x != tri.y && tri.y != tri.z && tri.z != tri.x {
+                            indices.push(tri);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+}
+
+// The pieces of the surface boundary on a face of a cell, each going from the cell edge where
+// the face boundary enters the negative region, to a cell edge where it leaves it.
+fn face_segments<N: RealField + Copy>(face: &[usize; 4], samples: &[N; 8]) -> Vec<(usize, usize)> {
+    let mut crossings = Vec::with_capacity(4);
+
+    for m in 0..4 {
+        let (a, b) = (face[m], face[(m + 1) % 4]);
+        let (in_a, in_b) = (samples[a] < N::zero(), samples[b] < N::zero());
+
+        if in_a != in_b {
+            crossings.push((8 * a.min(b) + a.max(b), in_b));
+        }
+    }
+
+    let center = face.iter().fold(N::zero(), |acc, c| acc + samples[*c]);
+    let mut segments = Vec::with_capacity(2);
+
+    for (m, crossing) in crossings.iter().enumerate() {
+        if crossing.1 {
+            // With four crossings, the negative corners are either linked or separated.
+            let exit = if crossings.len() == 4 && center < N::zero() {
+                (m + crossings.len() - 1) % crossings.len()
+            } else {
+                (m + 1) % crossings.len()
+            };
+            segments.push((crossing.0, crossings[exit].0));
+        }
+    }
+
+    segments
+}
+
+fn grid_point<N: RealField + Copy>(bounds: &AABB<N>, dims: [usize; 3], p: [usize; 3]) -> Point3<N> {
+    let mut res = bounds.mins;
+
+    for k in 0..3 {
+        let t: N = na::convert(p[k] as f64 / (dims[k] - 1) as f64);
+        res[k] += (bounds.maxs[k] - bounds.mins[k]) * t;
+    }
+
+    res
+}
@@ -0,0 +1,186 @@
+use super::Polyline;
+use crate::bounding_volume::AABB;
+use na::{self, Point2, RealField};
+use std::collections::HashMap;
+
+// The corners of a square, counterclockwise. The corner `c` is located at `(c & 1, c >> 1)`.
+const CORNERS: [usize; 4] = [0, 1, 3, 2];
+
+/// Extracts the contours separating the negative and positive values of a scalar field.
+///
+/// The field is sampled at the vertices of a regular grid subdividing `bounds` into
+/// `resolution[i]` cells along the `i`-th axis. The region where the field is negative is
+/// considered inside of the contours, e.g., with signed distance functions. See
+/// `marching_squares_grid` for details.
+pub fn marching_squares<N: RealField + Copy>(
+    mut field: impl FnMut(&Point2<N>) -> N,
+    bounds: &AABB<N>,
+    resolution: [usize; 2],
+) -> Vec<Polyline<N>> {
+    let dims = [resolution[0] + 1, resolution[1] + 1];
+    let mut values = Vec::with_capacity(dims[0] * dims[1]);
+
+    for j in 0..dims[1] {
+        for i in 0..dims[0] {
+            values.push(field(&grid_point(bounds, dims, [i, j])));
+        }
+    }
+
+    marching_squares_grid(&values, dims, bounds)
+}
+
+/// Extracts the contours separating the negative and positive values sampled on a regular grid,
+/// e.g., the pixels of a height map.
+///
+/// The grid has `dims[i]` samples along the `i`-th axis, and the first and last samples along
+/// each axis lie on the sides of `bounds`. The sample at the grid coordinates `(i, j)` is
+/// `values[i + dims[0] * j]`.
+///
+/// The contour vertices are placed on the grid edges by linear interpolation of the samples.
+/// The ambiguous cells, with two diagonally opposite negative corners, are resolved with the
+/// average of their four samples. Each returned polyline is a closed loop: the outer boundaries
+/// of the negative regions are counterclockwise, and the boundaries of their holes are
+/// clockwise. The contours are closed along the sides of `bounds`.
+pub fn marching_squares_grid<N: RealField + Copy>(
+    values: &[N],
+    dims: [usize; 2],
+    bounds: &AABB<N>,
+) -> Vec<Polyline<N>> {
+    assert!(
+        dims[0] >= 2 && dims[1] >= 2,
+        "The grid must have at least two samples along each axis."
+    );
+    assert_eq!(
+        values.len(),
+        dims[0] * dims[1],
+        "There must be exactly one value per grid sample."
+    );
+
+    // The grid is surrounded by a layer of positive samples at the same locations as the
+    // samples on its sides, so that contours crossing the bounds follow them.
+    let value = |p: [isize; 2]| {
+        if p[0] >= 0 && p[0] < dims[0] as isize && p[1] >= 0 && p[1] < dims[1] as isize {
+            values[p[0] as usize + dims[0] * p[1] as usize]
+        } else {
+            N::one()
+        }
+    };
+    let clamp = |p: [isize; 2]| {
+        [
+            p[0].max(0).min(dims[0] as isize - 1) as usize,
+            p[1].max(0).min(dims[1] as isize - 1) as usize,
+        ]
+    };
+
+    let mut positions = HashMap::new();
+    let mut segments = Vec::new();
+
+    for cj in -1..dims[1] as isize {
+        for ci in -1..dims[0] as isize {
+            let mut corners = [[0; 2]; 4];
+            let mut samples = [N::zero(); 4];
+
+            for c in 0..4 {
+                corners[c] = [ci + (c & 1) as isize, cj + (c >> 1) as isize];
+                samples[c] = value(corners[c]);
+            }
+
+            // The grid edges crossed by the contour, in counterclockwise order, and whether the
+            // cell boundary enters the negative region there.
+            let mut crossings = Vec::with_capacity(4);
+
+            for m in 0..4 {
+                let (a, b) = (CORNERS[m], CORNERS[(m + 1) % 4]);
+                let (in_a, in_b) = (samples[a] < N::zero(), samples[b] < N::zero());
+
+                if in_a != in_b {
+                    let (ra, rb) = (clamp(corners[a]), clamp(corners[b]));
+                    let ia = ra[0] + dims[0] * ra[1];
+                    let ib = rb[0] + dims[0] * rb[1];
+                    // Crossings located on a zero sample all share its vertex.
+                    let key = if samples[a].is_zero() {
+                        (ia, ia)
+                    } else if samples[b].is_zero() {
+                        (ib, ib)
+                    } else {
+                        (ia.min(ib), ia.max(ib))
+                    };
+
+                    let _ = positions.entry(key).or_insert_with(|| {
+                        let (pa, pb) = (grid_point(bounds, dims, ra), grid_point(bounds, dims, rb));
+                        let t = samples[a] / (samples[a] - samples[b]);
+                        pa + (pb - pa) * t
+                    });
+
+                    crossings.push((key, in_b));
+                }
+            }
+
+            let center = samples.iter().fold(N::zero(), |acc, v| acc + *v);
+
+            for (m, crossing) in crossings.iter().enumerate() {
+                if crossing.1 {
+                    // With four crossings, the negative corners are either linked or separated.
+                    let exit = if crossings.len() == 4 && center < N::zero() {
+                        (m + crossings.len() - 1) % crossings.len()
+                    } else {
+                        (m + 1) % crossings.len()
+                    };
+
+                    // Keep the negative region on the left of the contour.
+                    if crossings[exit].0 != crossing.0 {
+                        segments.push((crossings[exit].0, crossing.0));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut next: HashMap<_, Vec<_>> = HashMap::new();
+
+    for (a, b) in &segments {
+        next.entry(*a).or_default().push(*b);
+    }
+
+    let mut contours = Vec::new();
+
+    for (start, _) in &segments {
+        let mut coords: Vec<Point2<N>> = Vec::new();
+        let mut curr = *start;
+
+        while let Some(b) = next.get_mut(&curr).and_then(|nexts| nexts.pop()) {
+            let pt = positions[&curr];
+
+            if coords.last() != Some(&pt) {
+                coords.push(pt);
+            }
+
+            curr = b;
+
+            if curr == *start {
+                break;
+            }
+        }
+
+        if coords.len() > 1 && coords.first() == coords.last() {
+            let _ = coords.pop();
+        }
+
+        if coords.len() >= 3 {
+            contours.push(Polyline::new(coords, None).with_closed(true));
+        }
+    }
+
+    contours
+}
+
+fn grid_point<N: RealField + Copy>(bounds: &AABB<N>, dims: [usize; 2], p: [usize; 2]) -> Point2<N> {
+    let mut res = bounds.mins;
+
+    for k in 0..2 {
+        let t: N = na::convert(p[k] as f64 / (dims[k] - 1) as f64);
+        res[k] += (bounds.maxs[k] - bounds.mins[k]) * t;
+    }
+
+    res
+}
@@ -13,6 +13,14 @@ pub use self::cuboid::{cuboid, unit_cuboid};
 pub use self::cuboid::{rectangle, unit_rectangle};
 #[cfg(feature = "dim3")]
 pub use self::cylinder::{cylinder, unit_cylinder};
+#[cfg(feature = "dim3")]
+pub use self::marching_cubes::{marching_cubes, marching_cubes_grid};
+#[cfg(feature = "dim2")]
+pub use self::marching_squares::{marching_squares, marching_squares_grid};
+#[cfg(feature = "dim3")]
+pub use self::path::{sweep, SweepJoin, SweepOptions};
+#[cfg(feature = "dim2")]
+pub use self::polygon::{arc, capsule, regular_polygon, rounded_rectangle, star};
 #[cfg(feature = "dim2")]
 pub use self::polyline::Polyline;
 #[cfg(feature = "dim3")]
@@ -41,5 +49,11 @@ mod cuboid;
 #[cfg(feature = "dim3")]
 mod cylinder;
 #[cfg(feature = "dim3")]
+mod marching_cubes;
+#[cfg(feature = "dim2")]
+mod marching_squares;
+#[cfg(feature = "dim2")]
+mod polygon;
+#[cfg(feature = "dim3")]
 mod quad;
 mod sphere;
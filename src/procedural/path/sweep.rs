@@ -0,0 +1,227 @@
+use crate::procedural::{IndexBuffer, TriMesh};
+use na::{self, Point2, Point3, RealField, Unit, UnitQuaternion, Vector3};
+
+/// The way consecutive segments of a swept path are joined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SweepJoin {
+    /// The profile is extended along each segment up to the plane bisecting the two segments.
+    ///
+    /// This generates a single cross-section per joint, but sharp turns produce long spikes.
+    Miter,
+    /// The profile is rotated around the joint in the given number of steps.
+    Round(u32),
+}
+
+/// Parameters of the `sweep` mesh generation.
+#[derive(Copy, Clone, Debug)]
+pub struct SweepOptions {
+    /// How the consecutive segments of the path are joined.
+    pub join: SweepJoin,
+    /// Whether the last point of the profile is joined to its first point.
+    ///
+    /// This is `true` for pipes and `false` for, e.g., the cross-section of a road.
+    pub closed_profile: bool,
+    /// Whether both ends of the sweep are closed by a triangle fan.
+    ///
+    /// This is ignored if the profile is not closed. The fan is centered on the barycenter of the
+    /// profile, so the caps are correct only for star-shaped profiles.
+    pub caps: bool,
+}
+
+impl Default for SweepOptions {
+    fn default() -> Self {
+        SweepOptions {
+            join: SweepJoin::Miter,
+            closed_profile: true,
+            caps: true,
+        }
+    }
+}
+
+/// Generates the mesh obtained by sweeping a 2D profile along a 3D polyline path.
+///
+/// The profile point `(x, y)` is placed at `x` along the side axis and `y` along the up axis of
+/// a frame following the path. The up axis of the first frame is the projection of the `y` axis
+/// on the plane orthogonal to the first segment, and the frame is then propagated along the path
+/// with the smallest possible rotation at each joint, so the profile does not twist. If the
+/// profile is given in counterclockwise order, the triangles face outward.
+///
+/// # Panics
+///
+/// Panics if the profile has less than two points, or if the path does not have at least two
+/// distinct points.
+pub fn sweep<N: RealField + Copy>(
+    profile: &[Point2<N>],
+    path: &[Point3<N>],
+    options: &SweepOptions,
+) -> TriMesh<N> {
+    assert!(
+        profile.len() > 1,
+        "The profile must have at least two points."
+    );
+
+    let mut points: Vec<Point3<N>> = Vec::with_capacity(path.len());
+    for pt in path {
+        if points.last() != Some(pt) {
+            points.push(*pt);
+        }
+    }
+
+    assert!(
+        points.len() > 1,
+        "The path must have at least two distinct points."
+    );
+
+    let tangents: Vec<Vector3<N>> = points
+        .windows(2)
+        .map(|w| (w[1] - w[0]).normalize())
+        .collect();
+    let first_tangent = tangents[0];
+    let mut up = Vector3::y() - first_tangent * first_tangent.y;
+    if up.try_normalize_mut(N::default_epsilon()).is_none() {
+        up = Vector3::x() - first_tangent * first_tangent.x;
+        let _ = up.normalize_mut();
+    }
+    let mut side = up.cross(&first_tangent);
+
+    // Generate the cross-sections.
+    let mut coords = Vec::new();
+    push_section(&mut coords, profile, &points[0], &side, &up, None);
+
+    for i in 1..points.len() - 1 {
+        let (t_in, t_out) = (tangents[i - 1], tangents[i]);
+        let rotation = UnitQuaternion::rotation_between(&t_in, &t_out).unwrap_or_else(|| {
+            // U-turn: any rotation axis orthogonal to the path works.
+            UnitQuaternion::from_axis_angle(&Unit::new_unchecked(up), N::pi())
+        });
+
+        match options.join {
+            SweepJoin::Miter => {
+                let miter = t_in + t_out;
+                if miter.norm_squared() > N::default_epsilon() {
+                    push_section(
+                        &mut coords,
+                        profile,
+                        &points[i],
+                        &side,
+                        &up,
+                        Some((t_in, miter)),
+                    );
+                } else {
+                    // U-turn: the miter plane is undefined.
+                    push_section(&mut coords, profile, &points[i], &side, &up, None);
+                }
+            }
+            SweepJoin::Round(nsubdivs) => {
+                push_section(&mut coords, profile, &points[i], &side, &up, None);
+
+                for k in 1..nsubdivs.max(1) {
+                    let t = na::convert::<f64, N>(k as f64) / na::convert(nsubdivs as f64);
+                    let partial = UnitQuaternion::identity().slerp(&rotation, t);
+                    push_section(
+                        &mut coords,
+                        profile,
+                        &points[i],
+                        &(partial * side),
+                        &(partial * up),
+                        None,
+                    );
+                }
+            }
+        }
+
+        side = rotation * side;
+        up = rotation * up;
+
+        if options.join != SweepJoin::Miter {
+            push_section(&mut coords, profile, &points[i], &side, &up, None);
+        }
+    }
+
+    push_section(
+        &mut coords,
+        profile,
+        &points[points.len() - 1],
+        &side,
+        &up,
+        None,
+    );
+
+    // Connect consecutive cross-sections.
+    let n = profile.len() as u32;
+    let nsections = coords.len() as u32 / n;
+    let nedges = if options.closed_profile { n } else { n - 1 };
+    let mut indices = Vec::new();
+
+    for j in 0..nsections - 1 {
+        for k in 0..nedges {
+            let a = j * n + k;
+            let b = j * n + (k + 1) % n;
+            let c = a + n;
+            let d = b + n;
+            indices.push(Point3::new(a, b, d));
+            indices.push(Point3::new(a, d, c));
+        }
+    }
+
+    if options.closed_profile && options.caps {
+        let last_section = (nsections - 1) * n;
+        push_cap(&mut coords, &mut indices, 0, n, true);
+        push_cap(&mut coords, &mut indices, last_section, n, false);
+    }
+
+    TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+}
+
+/// Pushes the profile placed into the given frame.
+///
+/// If `extrusion` is set to `(tangent, miter)`, each point of the profile is moved along `tangent`
+/// up to the plane orthogonal to `miter`.
+fn push_section<N: RealField + Copy>(
+    coords: &mut Vec<Point3<N>>,
+    profile: &[Point2<N>],
+    center: &Point3<N>,
+    side: &Vector3<N>,
+    up: &Vector3<N>,
+    extrusion: Option<(Vector3<N>, Vector3<N>)>,
+) {
+    for p in profile {
+        let offset = side * p.x + up * p.y;
+        let extension = match extrusion {
+            Some((tangent, miter)) => tangent * (-offset.dot(&miter) / tangent.dot(&miter)),
+            None => Vector3::zeros(),
+        };
+        coords.push(center + offset + extension);
+    }
+}
+
+fn push_cap<N: RealField + Copy>(
+    coords: &mut Vec<Point3<N>>,
+    indices: &mut Vec<Point3<u32>>,
+    first: u32,
+    n: u32,
+    start: bool,
+) {
+    let section = &coords[first as usize..(first + n) as usize];
+    let mut center = Point3::origin();
+    for pt in section {
+        center.coords += pt.coords;
+    }
+    center.coords /= na::convert::<f64, N>(n as f64);
+
+    let center_id = coords.len() as u32;
+    coords.push(center);
+
+    for k in 0..n {
+        let a = first + k;
+        let b = first + (k + 1) % n;
+
+        // The side triangles go from one section to the next with the same orientation as the
+        // profile, so the start cap has the opposite orientation.
+        if start {
+            indices.push(Point3::new(center_id, b, a));
+        } else {
+            indices.push(Point3::new(center_id, a, b));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::{utils, Polyline};
+use crate::math::{Point, Vector};
+use na;
+use simba::scalar::RealField;
+
+/// Creates an arc of circle centered at the origin, from the angle `start_angle` to the angle
+/// `end_angle`.
+///
+/// The arc is counterclockwise if `end_angle > start_angle`. Unlike the other generators of
+/// this module, the result is an open polyline of `nsubdivs + 1` points including both ends.
+pub fn arc<N: RealField + Copy>(
+    radius: N,
+    start_angle: N,
+    end_angle: N,
+    nsubdivs: u32,
+) -> Polyline<N> {
+    let nsubdivs = nsubdivs.max(1);
+    let dtheta = (end_angle - start_angle) / na::convert(nsubdivs as f64);
+    let mut pts = Vec::with_capacity(nsubdivs as usize + 1);
+
+    for i in 0..=nsubdivs {
+        let theta = start_angle + dtheta * na::convert(i as f64);
+        pts.push(Point::new(theta.cos() * radius, theta.sin() * radius));
+    }
+
+    Polyline::new(pts, None)
+}
+
+/// Creates the counterclockwise contour of a regular polygon centered at the origin, with a
+/// vertex on the positive `x` axis.
+///
+/// # Arguments:
+/// * `circumradius` - the distance between the center and each vertex.
+/// * `nsides` - the number of sides, at least 3.
+pub fn regular_polygon<N: RealField + Copy>(circumradius: N, nsides: u32) -> Polyline<N> {
+    assert!(nsides >= 3, "A polygon must have at least three sides.");
+    super::circle(&(circumradius * na::convert(2.0)), nsides)
+}
+
+/// Creates the counterclockwise contour of a star centered at the origin, with a branch on the
+/// positive `x` axis.
+///
+/// The `2 * nbranches` vertices alternate between the tips of the branches, at the distance
+/// `outer_radius` from the center, and the hollows between them at the distance `inner_radius`.
+pub fn star<N: RealField + Copy>(outer_radius: N, inner_radius: N, nbranches: u32) -> Polyline<N> {
+    assert!(nbranches >= 2, "A star must have at least two branches.");
+
+    let dtheta = N::pi() / na::convert(nbranches as f64);
+    let mut pts = Vec::with_capacity(2 * nbranches as usize);
+
+    for i in 0..2 * nbranches {
+        let theta = dtheta * na::convert(i as f64);
+        let radius = if i % 2 == 0 {
+            outer_radius
+        } else {
+            inner_radius
+        };
+        pts.push(Point::new(theta.cos() * radius, theta.sin() * radius));
+    }
+
+    Polyline::new(pts, None).with_closed(true)
+}
+
+/// Creates the counterclockwise contour of a rectangle centered at the origin, with rounded
+/// corners.
+///
+/// # Arguments:
+/// * `extents` - the extents of the rectangle, including the rounded corners.
+/// * `corner_radius` - the radius of the corners, clamped to half the smallest extent.
+/// * `nsubdivs` - the number of segments of each corner.
+pub fn rounded_rectangle<N: RealField + Copy>(
+    extents: &Vector<N>,
+    corner_radius: N,
+    nsubdivs: u32,
+) -> Polyline<N> {
+    let half_extents = extents * na::convert::<f64, N>(0.5);
+    let radius = corner_radius.min(half_extents.min());
+
+    if radius <= N::zero() {
+        return super::rectangle(extents);
+    }
+
+    let core = half_extents - Vector::repeat(radius);
+    let nsubdivs = nsubdivs.max(1);
+    let mut pts = Vec::with_capacity(4 * (nsubdivs as usize + 1));
+
+    let corners = [
+        Vector::new(core.x, core.y),
+        Vector::new(-core.x, core.y),
+        Vector::new(-core.x, -core.y),
+        Vector::new(core.x, -core.y),
+    ];
+
+    for (i, corner) in corners.iter().enumerate() {
+        let start = N::frac_pi_2() * na::convert(i as f64);
+        let quarter = arc(radius, start, start + N::frac_pi_2(), nsubdivs);
+        pts.extend(quarter.coords().iter().map(|pt| pt + corner));
+    }
+
+    Polyline::new(pts, None).with_closed(true)
+}
+
+/// Creates the counterclockwise contour of a capsule aligned with the `y` axis.
+///
+/// # Arguments:
+/// * `caps_diameter` - the diameter of the caps.
+/// * `cylinder_height` - the distance between the centers of the caps.
+/// * `nsubdiv` - the number of segments of each cap.
+pub fn capsule<N: RealField + Copy>(
+    caps_diameter: &N,
+    cylinder_height: &N,
+    nsubdiv: u32,
+) -> Polyline<N> {
+    let radius = *caps_diameter * na::convert(0.5);
+    let half_height = *cylinder_height * na::convert(0.5);
+    let nsubdiv = nsubdiv.max(1);
+    let dtheta = N::pi() / na::convert(nsubdiv as f64);
+    let mut pts = Vec::with_capacity(2 * (nsubdiv as usize + 1));
+
+    utils::push_xy_arc(radius, nsubdiv + 1, dtheta, &mut pts);
+    let ncap = pts.len();
+
+    for i in 0..ncap {
+        let pt = pts[i];
+        pts.push(-pt - Vector::y() * half_height);
+        pts[i] = pt + Vector::y() * half_height;
+    }
+
+    Polyline::new(pts, None).with_closed(true)
+}
@@ -1,5 +1,5 @@
 use crate::math::{Isometry, Point, Rotation, Translation, Vector, DIM};
-use na::{self, RealField};
+use na::{self, Point2, RealField, Vector2};
 
 /// Geometric description of a polyline.
 #[derive(Clone)]
@@ -9,6 +9,10 @@ pub struct Polyline<N: RealField + Copy> {
     coords: Vec<Point<N>>,
     /// Coordinates of the polyline normals.
     normals: Option<Vec<Vector<N>>>,
+    /// Explicit edges of the polyline, as pairs of vertex indices.
+    indices: Option<Vec<Point2<usize>>>,
+    /// Whether the last vertex is joined to the first one when there are no explicit edges.
+    closed: bool,
 }
 
 impl<N: RealField + Copy> Polyline<N> {
@@ -21,7 +25,35 @@ impl<N: RealField + Copy> Polyline<N> {
             );
         }
 
-        Polyline { coords, normals }
+        Polyline {
+            coords,
+            normals,
+            indices: None,
+            closed: false,
+        }
+    }
+
+    /// Sets whether the last vertex of this polyline is joined to its first vertex.
+    ///
+    /// This is only used if this polyline has no explicit edges.
+    pub fn with_closed(mut self, closed: bool) -> Polyline<N> {
+        self.closed = closed;
+        self
+    }
+
+    /// Sets the edges of this polyline, as pairs of indices of its vertices.
+    ///
+    /// Without explicit edges, the vertices form a single chain following their order.
+    pub fn with_indices(mut self, indices: Vec<Point2<usize>>) -> Polyline<N> {
+        assert!(
+            indices
+                .iter()
+                .all(|e| e.x < self.coords.len() && e.y < self.coords.len()),
+            "The edge indices must be smaller than the number of vertices."
+        );
+
+        self.indices = Some(indices);
+        self
     }
 }
 
@@ -49,6 +81,44 @@ impl<N: RealField + Copy> Polyline<N> {
         self.normals.as_ref().map(Vec::as_slice)
     }
 
+    /// The explicit edges of this polyline, if any.
+    #[inline]
+    pub fn indices(&self) -> Option<&[Point2<usize>]> {
+        self.indices.as_deref()
+    }
+
+    /// Whether the last vertex of this polyline is joined to its first vertex.
+    ///
+    /// This is only used if this polyline has no explicit edges.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// The edges of this polyline.
+    ///
+    /// These are the explicit edges of this polyline if it has some, or the chain joining its
+    /// consecutive vertices otherwise. This chain is a loop if this polyline is closed. It is
+    /// also a loop if its last vertex coincides with its first vertex, in which case the last
+    /// vertex is not referenced by any edge.
+    pub fn edges(&self) -> Vec<Point2<usize>> {
+        if let Some(ref indices) = self.indices {
+            return indices.clone();
+        }
+
+        let n = self.coords.len();
+
+        if self.closed {
+            (0..n).map(|i| Point2::new(i, (i + 1) % n)).collect()
+        } else if n > 2 && self.coords[0] == self.coords[n - 1] {
+            (0..n - 1)
+                .map(|i| Point2::new(i, (i + 1) % (n - 1)))
+                .collect()
+        } else {
+            (1..n).map(|i| Point2::new(i - 1, i)).collect()
+        }
+    }
+
     /// The mutable normals of this polyline vertices.
     #[inline]
     pub fn normals_mut(&mut self) -> Option<&mut [Vector<N>]> {
@@ -120,4 +190,38 @@ impl<N: RealField + Copy> Polyline<N> {
         self.scale_by(s);
         self
     }
+
+    /// Appends the vertices and edges of `other` to this polyline.
+    ///
+    /// The edges of both polylines are made explicit so that no edge joins them. The result has
+    /// normals only if both polylines have normals.
+    pub fn append(&mut self, mut other: Polyline<N>) {
+        if self.coords.is_empty() {
+            *self = other;
+            return;
+        }
+
+        let base = Vector2::repeat(self.coords.len());
+        let mut indices = self.edges();
+        indices.extend(other.edges().iter().map(|e| e + base));
+
+        self.coords.append(&mut other.coords);
+        self.normals = match (self.normals.take(), other.normals.take()) {
+            (Some(mut ns), Some(mut other_ns)) => {
+                ns.append(&mut other_ns);
+                Some(ns)
+            }
+            _ => None,
+        };
+        self.indices = Some(indices);
+    }
+
+    /// Appends the vertices and edges of `other` to this polyline.
+    ///
+    /// See `append` for details.
+    #[inline]
+    pub fn merged(mut self, other: Polyline<N>) -> Self {
+        self.append(other);
+        self
+    }
 }
@@ -166,12 +166,17 @@ fn unit_sphere_with_uvs<N: RealField + Copy>(ntheta_subdiv: u32, nphi_subdiv: u3
 }
 
 /// Creates an hemisphere with a diameter of 1.
+///
+/// At least 3 subdivisions along `theta` and 2 along `phi` are used, whatever the given values.
 #[cfg(feature = "dim3")]
 pub fn unit_hemisphere<N: RealField + Copy>(ntheta_subdiv: u32, nphi_subdiv: u32) -> TriMesh<N> {
+    let ntheta_subdiv = ntheta_subdiv.max(3);
+    let nphi_subdiv = nphi_subdiv.max(2);
     let two_pi = N::two_pi();
     let pi_two = N::frac_pi_2();
     let dtheta = two_pi / na::convert(ntheta_subdiv as f64);
-    let dphi = pi_two / na::convert(nphi_subdiv as f64);
+    // The `nphi_subdiv - 1` rings and the pole are evenly spaced.
+    let dphi = pi_two / na::convert((nphi_subdiv - 1) as f64);
 
     let mut coords = Vec::new();
     let mut curr_phi = na::zero::<N>();
@@ -231,7 +236,7 @@ pub fn circle<N: RealField + Copy>(diameter: &N, nsubdivs: u32) -> Polyline<N> {
 
     // FIXME: normals
 
-    Polyline::new(pts, None)
+    Polyline::new(pts, None).with_closed(true)
 }
 
 /// Creates a circle lying on the `(x,y)` plane.
@@ -1,7 +1,7 @@
 use super::utils;
 use crate::math::{Isometry, Point, Translation, Vector, DIM};
 use crate::utils::DeterministicState;
-use na::{self, Point2, Point3, RealField};
+use na::{self, Point2, Point3, RealField, Vector3};
 use std::collections::HashMap;
 
 /// Different representations of the index buffer.
@@ -110,6 +110,13 @@ impl<N: RealField + Copy> TriMesh<N> {
         }
     }
 
+    /// Transforms each vertex and rotates each normal of this mesh.
+    #[inline]
+    pub fn transformed(mut self, t: &Isometry<N>) -> Self {
+        self.transform_by(t);
+        self
+    }
+
     /// The number of triangles on this mesh.
     #[inline]
     pub fn num_triangles(&self) -> usize {
@@ -148,6 +155,9 @@ impl<N: RealField + Copy> TriMesh<N> {
 impl<N: RealField + Copy> TriMesh<N> {
     /// Recomputes the mesh normals using its vertex coordinates and adjascency informations
     /// infered from the index buffer.
+    ///
+    /// This computes smooth normals: the normal of each vertex is the average of the normals of
+    /// its adjacent triangles.
     #[inline]
     pub fn recompute_normals(&mut self) {
         let mut new_normals = Vec::new();
@@ -156,7 +166,7 @@ impl<N: RealField + Copy> TriMesh<N> {
             IndexBuffer::Unified(ref idx) => {
                 utils::compute_normals(&self.coords[..], &idx[..], &mut new_normals);
             }
-            IndexBuffer::Split(ref idx) => {
+            IndexBuffer::Split(ref mut idx) => {
                 // XXX: too bad we have to reconstruct the index buffer here.
                 // The utils::recompute_normals function should be generic wrt. the index buffer
                 // type (it could use an iterator instead).
@@ -166,18 +176,51 @@ impl<N: RealField + Copy> TriMesh<N> {
                     .collect();
 
                 utils::compute_normals(&self.coords[..], &coord_idx[..], &mut new_normals);
+
+                // The new normals are indexed like the vertex coordinates.
+                for t in idx.iter_mut() {
+                    t.x.y = t.x.x;
+                    t.y.y = t.y.x;
+                    t.z.y = t.z.x;
+                }
             }
         }
 
         self.normals = Some(new_normals);
     }
 
+    /// Recomputes the mesh normals such that each triangle has its own, uniform, normal.
+    ///
+    /// This converts the index buffer to a split index buffer where the normal index of each
+    /// vertex of the `i`-th triangle is `i`. The normal of degenerate triangles is zero.
+    pub fn recompute_flat_normals(&mut self) {
+        self.split_index_buffer(false);
+
+        if let IndexBuffer::Split(ref mut idx) = self.indices {
+            let mut new_normals = Vec::with_capacity(idx.len());
+
+            for (i, t) in idx.iter_mut().enumerate() {
+                let a = self.coords[t.x.x as usize];
+                let b = self.coords[t.y.x as usize];
+                let c = self.coords[t.z.x as usize];
+                let normal = (b - a).cross(&(c - a)).try_normalize(N::zero());
+                new_normals.push(normal.unwrap_or_else(Vector::zeros));
+
+                t.x.y = i as u32;
+                t.y.y = i as u32;
+                t.z.y = i as u32;
+            }
+
+            self.normals = Some(new_normals);
+        }
+    }
+
     /// Flips all the normals of this mesh.
     #[inline]
     pub fn flip_normals(&mut self) {
         if let Some(ref mut normals) = self.normals {
             for n in normals {
-                *n = *n
+                *n = -*n
             }
         }
     }
@@ -200,6 +243,8 @@ impl<N: RealField + Copy> TriMesh<N> {
     }
 
     /// Scales each vertex of this mesh.
+    ///
+    /// The normals are updated accordingly. All the components of `s` must be non-zero.
     #[inline]
     pub fn scale_by(&mut self, s: &Vector<N>) {
         for c in self.coords.iter_mut() {
@@ -207,7 +252,86 @@ impl<N: RealField + Copy> TriMesh<N> {
                 c[i] = (*c)[i] * s[i];
             }
         }
-        // FIXME: do something for the normals?
+
+        // Normals are transformed by the inverse transpose of the scaling.
+        if let Some(ref mut normals) = self.normals {
+            for n in normals.iter_mut() {
+                if let Some(scaled) = n.component_div(s).try_normalize(N::zero()) {
+                    *n = scaled
+                }
+            }
+        }
+    }
+
+    /// Scales each vertex of this mesh.
+    ///
+    /// The normals are updated accordingly. All the components of `s` must be non-zero.
+    #[inline]
+    pub fn scaled(mut self, s: &Vector<N>) -> Self {
+        self.scale_by(s);
+        self
+    }
+
+    /// Appends the vertices and triangles of `other` to this mesh.
+    ///
+    /// The result has normals (resp. texture coordinates) only if both meshes have normals
+    /// (resp. texture coordinates). The index buffer stays unified if both meshes have an unified
+    /// index buffer, otherwise both index buffers are split before being concatenated.
+    pub fn append(&mut self, mut other: TriMesh<N>) {
+        if self.coords.is_empty() && self.num_triangles() == 0 {
+            *self = other;
+            return;
+        }
+
+        let base_coords = self.coords.len() as u32;
+        let base_normals = self.normals.as_ref().map(|ns| ns.len()).unwrap_or(0) as u32;
+        let base_uvs = self.uvs.as_ref().map(|uvs| uvs.len()).unwrap_or(0) as u32;
+
+        self.coords.append(&mut other.coords);
+        self.normals = match (self.normals.take(), other.normals.take()) {
+            (Some(mut ns), Some(mut other_ns)) => {
+                ns.append(&mut other_ns);
+                Some(ns)
+            }
+            _ => None,
+        };
+        self.uvs = match (self.uvs.take(), other.uvs.take()) {
+            (Some(mut uvs), Some(mut other_uvs)) => {
+                uvs.append(&mut other_uvs);
+                Some(uvs)
+            }
+            _ => None,
+        };
+
+        if let (IndexBuffer::Unified(idx), IndexBuffer::Unified(other_idx)) =
+            (&mut self.indices, &other.indices)
+        {
+            idx.extend(other_idx.iter().map(|t| t + Vector3::repeat(base_coords)));
+            return;
+        }
+
+        self.split_index_buffer(false);
+        other.split_index_buffer(false);
+
+        if let (IndexBuffer::Split(idx), IndexBuffer::Split(other_idx)) =
+            (&mut self.indices, &other.indices)
+        {
+            let offset = Vector3::new(base_coords, base_normals, base_uvs);
+            idx.extend(
+                other_idx
+                    .iter()
+                    .map(|t| Point3::new(t.x + offset, t.y + offset, t.z + offset)),
+            );
+        }
+    }
+
+    /// Appends the vertices and triangles of `other` to this mesh.
+    ///
+    /// See `append` for details.
+    #[inline]
+    pub fn merged(mut self, other: TriMesh<N>) -> Self {
+        self.append(other);
+        self
     }
 }
 
@@ -0,0 +1,121 @@
+//! Recording of the intermediate states of the GJK and EPA algorithms.
+
+use crate::math::{Vector, DIM};
+use crate::query::algorithms::{CSOPoint, EPAPolytope, VoronoiSimplex};
+use na::{RealField, Unit};
+
+/// A sink receiving the intermediate states of the GJK and EPA algorithms.
+///
+/// This is meant for debugging: rendering the successive simplices and polytopes is often the
+/// quickest way to understand why a contact is wrong. Both methods do nothing by default. The
+/// unit type `()` implements this trait and traces nothing.
+pub trait DebugTraceSink<N: RealField + Copy> {
+    /// Called at each GJK iteration, once the support point `support` of the Minkowski difference
+    /// along `dir` has been computed, but before it is added to `simplex`.
+    fn gjk_iteration(
+        &mut self,
+        _simplex: &VoronoiSimplex<N>,
+        _dir: &Unit<Vector<N>>,
+        _support: &CSOPoint<N>,
+    ) {
+    }
+
+    /// Called at each EPA iteration, once the support point `support` of the Minkowski
+    /// difference along the normal `dir` of the face closest to the origin has been computed,
+    /// but before `polytope` is expanded.
+    fn epa_iteration(
+        &mut self,
+        _polytope: &EPAPolytope<N>,
+        _dir: &Unit<Vector<N>>,
+        _support: &CSOPoint<N>,
+    ) {
+    }
+}
+
+impl<N: RealField + Copy> DebugTraceSink<N> for () {}
+
+/// A snapshot of one iteration of the GJK algorithm.
+#[derive(Clone, Debug)]
+pub struct GJKTraceStep<N: RealField + Copy> {
+    /// The vertices of the simplex at the beginning of this iteration.
+    pub simplex: Vec<CSOPoint<N>>,
+    /// The direction along which the support point has been computed.
+    pub dir: Unit<Vector<N>>,
+    /// The support point computed during this iteration.
+    pub support: CSOPoint<N>,
+}
+
+/// A snapshot of one iteration of the EPA algorithm.
+#[derive(Clone, Debug)]
+pub struct EPATraceStep<N: RealField + Copy> {
+    /// The vertices of the polytope at the beginning of this iteration.
+    pub vertices: Vec<CSOPoint<N>>,
+    /// The faces of the polytope at the beginning of this iteration, as indices of `vertices`.
+    pub faces: Vec<[usize; DIM]>,
+    /// The normal of the face being expanded.
+    pub dir: Unit<Vector<N>>,
+    /// The support point computed during this iteration.
+    pub support: CSOPoint<N>,
+}
+
+/// A `DebugTraceSink` keeping a copy of every iteration of the GJK and EPA algorithms.
+#[derive(Clone, Debug)]
+pub struct DebugTrace<N: RealField + Copy> {
+    /// The recorded GJK iterations, in order.
+    pub gjk: Vec<GJKTraceStep<N>>,
+    /// The recorded EPA iterations, in order.
+    pub epa: Vec<EPATraceStep<N>>,
+}
+
+impl<N: RealField + Copy> DebugTrace<N> {
+    /// Creates an empty trace.
+    pub fn new() -> Self {
+        DebugTrace {
+            gjk: Vec::new(),
+            epa: Vec::new(),
+        }
+    }
+
+    /// Removes all the recorded iterations.
+    pub fn clear(&mut self) {
+        self.gjk.clear();
+        self.epa.clear();
+    }
+}
+
+impl<N: RealField + Copy> Default for DebugTrace<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: RealField + Copy> DebugTraceSink<N> for DebugTrace<N> {
+    fn gjk_iteration(
+        &mut self,
+        simplex: &VoronoiSimplex<N>,
+        dir: &Unit<Vector<N>>,
+        support: &CSOPoint<N>,
+    ) {
+        self.gjk.push(GJKTraceStep {
+            simplex: (0..=simplex.dimension())
+                .map(|i| *simplex.point(i))
+                .collect(),
+            dir: *dir,
+            support: *support,
+        })
+    }
+
+    fn epa_iteration(
+        &mut self,
+        polytope: &EPAPolytope<N>,
+        dir: &Unit<Vector<N>>,
+        support: &CSOPoint<N>,
+    ) {
+        self.epa.push(EPATraceStep {
+            vertices: polytope.vertices().to_vec(),
+            faces: polytope.faces().map(|(pts, _)| pts).collect(),
+            dir: *dir,
+            support: *support,
+        })
+    }
+}
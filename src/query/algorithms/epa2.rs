@@ -8,8 +8,9 @@ use simba::scalar::RealField;
 
 use crate::math::{Isometry, Point, Vector};
 use crate::query::algorithms::{
-    gjk, special_support_maps::ConstantOrigin, CSOPoint, VoronoiSimplex,
+    gjk, special_support_maps::ConstantOrigin, CSOPoint, DebugTraceSink, VoronoiSimplex,
 };
+use crate::query::QueryPrecision;
 use crate::shape::SupportMap;
 use crate::utils;
 
@@ -20,8 +21,8 @@ struct FaceId<N: RealField + Copy> {
 }
 
 impl<N: RealField + Copy> FaceId<N> {
-    fn new(id: usize, neg_dist: N) -> Option<Self> {
-        if neg_dist > gjk::eps_tol() {
+    fn new(id: usize, neg_dist: N, eps: N) -> Option<Self> {
+        if neg_dist > eps {
             None
         } else {
             Some(FaceId { id, neg_dist })
@@ -34,7 +35,7 @@ impl<N: RealField + Copy> Eq for FaceId<N> {}
 impl<N: RealField + Copy> PartialOrd for FaceId<N> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.neg_dist.partial_cmp(&other.neg_dist)
+        Some(self.cmp(other))
     }
 }
 
@@ -46,7 +47,9 @@ impl<N: RealField + Copy> Ord for FaceId<N> {
         } else if self.neg_dist > other.neg_dist {
             Ordering::Greater
         } else {
-            Ordering::Equal
+            // Break ties with the face ids so the faces are visited in the same
+            // order whatever the implementation of the heap.
+            other.id.cmp(&self.id)
         }
     }
 }
@@ -111,6 +114,52 @@ impl<N: RealField + Copy> Face<N> {
     }
 }
 
+/// A read-only view of the polytope expanded by the EPA algorithm.
+pub struct EPAPolytope<'a, N: RealField + Copy> {
+    vertices: &'a [CSOPoint<N>],
+    faces: &'a [Face<N>],
+}
+
+impl<'a, N: RealField + Copy> EPAPolytope<'a, N> {
+    /// The vertices of this polytope, including vertices that are no longer part of any face.
+    pub fn vertices(&self) -> &'a [CSOPoint<N>] {
+        self.vertices
+    }
+
+    /// The faces of this polytope, given as indices of `self.vertices()`, with their outward
+    /// normals.
+    pub fn faces(&self) -> impl Iterator<Item = ([usize; 2], Unit<Vector<N>>)> + 'a {
+        self.faces
+            .iter()
+            .filter(|f| !f.deleted)
+            .map(|f| (f.pts, f.normal))
+    }
+}
+
+/// The parameters of `EPA::closest_points_with_options`.
+pub struct EPAOptions<'a, N: RealField + Copy, T: ?Sized> {
+    /// The tolerances of the algorithm.
+    pub precision: QueryPrecision<N>,
+    /// The sink each iteration of the algorithm is reported to.
+    pub trace: &'a mut T,
+}
+
+impl<'a, N: RealField + Copy, T: ?Sized + DebugTraceSink<N>> EPAOptions<'a, N, T> {
+    /// Initializes the EPA parameters with the default tolerances of the EPA algorithm.
+    pub fn new(trace: &'a mut T) -> Self {
+        EPAOptions {
+            precision: QueryPrecision::epa(),
+            trace,
+        }
+    }
+
+    /// Replaces the tolerances of these parameters.
+    pub fn with_precision(mut self, precision: QueryPrecision<N>) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
 /// The Expanding Polytope Algorithm in 2D.
 pub struct EPA<N: RealField + Copy> {
     vertices: Vec<CSOPoint<N>>,
@@ -169,8 +218,30 @@ impl<N: RealField + Copy> EPA<N> {
         G1: SupportMap<N>,
         G2: SupportMap<N>,
     {
-        let _eps = N::default_epsilon();
-        let _eps_tol = _eps * na::convert(100.0f64);
+        self.closest_points_with_options(m1, g1, m2, g2, simplex, EPAOptions::new(&mut ()))
+    }
+
+    /// Projects the origin on a shape using the EPA algorithm with the given parameters,
+    /// reporting each iteration to `options.trace`.
+    ///
+    /// This is identical to `closest_points` otherwise. Returns `None` if
+    /// `options.precision.max_iterations` is exceeded.
+    pub fn closest_points_with_options<G1: ?Sized, G2: ?Sized, T: ?Sized>(
+        &mut self,
+        m1: &Isometry<N>,
+        g1: &G1,
+        m2: &Isometry<N>,
+        g2: &G2,
+        simplex: &VoronoiSimplex<N>,
+        options: EPAOptions<N, T>,
+    ) -> Option<(Point<N>, Point<N>, Unit<Vector<N>>)>
+    where
+        G1: SupportMap<N>,
+        G2: SupportMap<N>,
+        T: DebugTraceSink<N>,
+    {
+        let EPAOptions { precision, trace } = options;
+        let _eps_tol = precision.epsilon;
 
         self.reset();
 
@@ -242,17 +313,17 @@ impl<N: RealField + Copy> EPA<N> {
 
             if proj_is_inside1 {
                 let dist1 = self.faces[0].normal.dot(&self.vertices[0].point.coords);
-                self.heap.push(FaceId::new(0, -dist1)?);
+                self.heap.push(FaceId::new(0, -dist1, _eps_tol)?);
             }
 
             if proj_is_inside2 {
                 let dist2 = self.faces[1].normal.dot(&self.vertices[1].point.coords);
-                self.heap.push(FaceId::new(1, -dist2)?);
+                self.heap.push(FaceId::new(1, -dist2, _eps_tol)?);
             }
 
             if proj_is_inside3 {
                 let dist3 = self.faces[2].normal.dot(&self.vertices[2].point.coords);
-                self.heap.push(FaceId::new(2, -dist3)?);
+                self.heap.push(FaceId::new(2, -dist3, _eps_tol)?);
             }
         } else {
             let pts1 = [0, 1];
@@ -274,8 +345,8 @@ impl<N: RealField + Copy> EPA<N> {
             let dist1 = self.faces[0].normal.dot(&self.vertices[0].point.coords);
             let dist2 = self.faces[1].normal.dot(&self.vertices[1].point.coords);
 
-            self.heap.push(FaceId::new(0, dist1)?);
-            self.heap.push(FaceId::new(1, dist2)?);
+            self.heap.push(FaceId::new(0, dist1, _eps_tol)?);
+            self.heap.push(FaceId::new(1, dist2, _eps_tol)?);
         }
 
         let mut niter = 0;
@@ -294,6 +365,14 @@ impl<N: RealField + Copy> EPA<N> {
             }
 
             let cso_point = CSOPoint::from_shapes(m1, g1, m2, g2, &face.normal);
+            trace.epa_iteration(
+                &EPAPolytope {
+                    vertices: &self.vertices,
+                    faces: &self.faces,
+                },
+                &face.normal,
+                &cso_point,
+            );
             let support_point_id = self.vertices.len();
             self.vertices.push(cso_point);
 
@@ -312,6 +391,8 @@ impl<N: RealField + Copy> EPA<N> {
                 return Some((cpts.0, cpts.1, best_face.normal));
             }
 
+            self.faces[face_id.id].deleted = true;
+
             let pts1 = [face.pts[0], support_point_id];
             let pts2 = [support_point_id, face.pts[1]];
 
@@ -331,7 +412,8 @@ impl<N: RealField + Copy> EPA<N> {
                     }
 
                     if !f.0.deleted {
-                        self.heap.push(FaceId::new(self.faces.len(), -dist)?);
+                        self.heap
+                            .push(FaceId::new(self.faces.len(), -dist, _eps_tol)?);
                     }
                 }
 
@@ -339,7 +421,7 @@ impl<N: RealField + Copy> EPA<N> {
             }
 
             niter += 1;
-            if niter > 10000 {
+            if niter > precision.max_iterations {
                 return None;
             }
         }
@@ -3,11 +3,13 @@
 use na::{self, Unit};
 use simba::scalar::RealField;
 
-use crate::query::algorithms::{special_support_maps::ConstantOrigin, CSOPoint, VoronoiSimplex};
+use crate::query::algorithms::{
+    special_support_maps::ConstantOrigin, CSOPoint, DebugTraceSink, VoronoiSimplex,
+};
 use crate::shape::SupportMap;
 // use query::Proximity;
 use crate::math::{Isometry, Point, Vector, DIM};
-use crate::query::{self, Ray};
+use crate::query::{self, statistics, QueryPrecision, Ray};
 
 /// Results of the GJK algorithm.
 #[derive(Clone, Debug, PartialEq)]
@@ -87,9 +89,73 @@ where
     G1: SupportMap<N>,
     G2: SupportMap<N>,
 {
-    let _eps = N::default_epsilon();
-    let _eps_tol: N = eps_tol();
-    let _eps_rel: N = _eps_tol.sqrt();
+    closest_points_with_options(
+        m1,
+        g1,
+        m2,
+        g2,
+        simplex,
+        GJKOptions::new(max_dist, exact_dist, &mut ()),
+    )
+}
+
+/// The parameters of `closest_points_with_options`.
+pub struct GJKOptions<'a, N: RealField + Copy, T: ?Sized> {
+    /// The distance beyond which the origin is reported to be outside of the shape.
+    pub max_dist: N,
+    /// Whether the exact closest points are needed. See `closest_points` for details.
+    pub exact_dist: bool,
+    /// The tolerances of the algorithm.
+    pub precision: QueryPrecision<N>,
+    /// The sink each iteration of the algorithm is reported to.
+    pub trace: &'a mut T,
+}
+
+impl<'a, N: RealField + Copy, T: ?Sized + DebugTraceSink<N>> GJKOptions<'a, N, T> {
+    /// Initializes the GJK parameters with the default tolerances.
+    pub fn new(max_dist: N, exact_dist: bool, trace: &'a mut T) -> Self {
+        GJKOptions {
+            max_dist,
+            exact_dist,
+            precision: QueryPrecision::default(),
+            trace,
+        }
+    }
+
+    /// Replaces the tolerances of these parameters.
+    pub fn with_precision(mut self, precision: QueryPrecision<N>) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+/// Projects the origin on a shape using the Separating Axis GJK algorithm with the given
+/// parameters, reporting each iteration to `options.trace`.
+///
+/// This is identical to `closest_points` otherwise. If `options.precision.max_iterations` is
+/// reached, `GJKResult::NoIntersection` is returned.
+pub fn closest_points_with_options<N, G1: ?Sized, G2: ?Sized, T: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+    options: GJKOptions<N, T>,
+) -> GJKResult<N>
+where
+    N: RealField + Copy,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+    T: DebugTraceSink<N>,
+{
+    let GJKOptions {
+        max_dist,
+        exact_dist,
+        precision,
+        trace,
+    } = options;
+    let _eps_tol: N = precision.epsilon;
+    let _eps_rel: N = precision.relative_epsilon;
 
     // FIXME: reset the simplex if it is empty?
     let mut proj = simplex.project_origin_and_reduce();
@@ -107,6 +173,7 @@ where
     let mut niter = 0;
 
     loop {
+        statistics::count_gjk_iteration();
         let old_max_bound = max_bound;
 
         if let Some((new_dir, dist)) = Unit::try_new_and_get(-proj.coords, _eps_tol) {
@@ -127,6 +194,7 @@ where
         }
 
         let cso_point = CSOPoint::from_shapes(m1, g1, m2, g2, &dir);
+        trace.gjk_iteration(simplex, &dir, &cso_point);
         let min_bound = -dir.dot(&cso_point.point.coords);
 
         assert!(min_bound == min_bound);
@@ -170,12 +238,83 @@ where
             }
         }
         niter += 1;
-        if niter == 10000 {
+        if niter >= precision.max_iterations {
             return GJKResult::NoIntersection(Vector::x_axis());
         }
     }
 }
 
+/// Tests whether the origin is inside of the Minkowski difference of two shapes.
+///
+/// This is a boolean-only version of the GJK algorithm: it exits as soon as a separating axis is
+/// found or the simplex encloses the origin, without trying to converge toward the closest points.
+/// Shapes separated by a distance smaller than the GJK tolerance are considered intersecting.
+///
+/// The `simplex` must be already initialized with at least one point on the shape boundary.
+pub fn intersection_test<N, G1, G2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+) -> bool
+where
+    N: RealField + Copy,
+    G1: ?Sized + SupportMap<N>,
+    G2: ?Sized + SupportMap<N>,
+{
+    let _eps_tol: N = eps_tol();
+    let max_iterations = QueryPrecision::<N>::default().max_iterations;
+
+    for _ in 0..max_iterations {
+        statistics::count_gjk_iteration();
+        let proj = simplex.project_origin_and_reduce();
+
+        if simplex.dimension() == DIM {
+            return true; // Point inside of the cso.
+        }
+
+        let dir = if let Some(dir) = Unit::try_new(-proj.coords, _eps_tol) {
+            dir
+        } else {
+            // The origin is on the simplex.
+            return true;
+        };
+
+        let cso_point = CSOPoint::from_shapes(m1, g1, m2, g2, &dir);
+
+        if dir.dot(&cso_point.point.coords) < N::zero() {
+            // `dir` is a separating axis.
+            return false;
+        }
+
+        if !simplex.add_point(cso_point) {
+            // No progress can be made: the origin is outside, at the distance of the simplex.
+            return false;
+        }
+    }
+
+    false
+}
+
+/// Parameters of the GJK-based ray-casting algorithm.
+///
+/// These are the same tolerances and iteration limit as those of the other iterative algorithms.
+/// The relative tolerance is used to detect stagnation near grazing contacts.
+pub type RaycastOptions<N> = QueryPrecision<N>;
+
+/// The reason why the GJK-based ray-casting algorithm stopped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RaycastStatus {
+    /// The algorithm converged to a hit or to a definite miss.
+    Converged,
+    /// The maximum number of iterations was reached. This is reported as a miss.
+    MaxIterations,
+    /// The algorithm stopped because of numerical issues, e.g., a zero-length ray or
+    /// stagnating bounds at grazing angles. The returned result may be inaccurate.
+    Degenerate,
+}
+
 /// Casts a ray on a support map using the GJK algorithm.
 pub fn cast_ray<N, G: ?Sized>(
     m: &Isometry<N>,
@@ -184,13 +323,36 @@ pub fn cast_ray<N, G: ?Sized>(
     ray: &Ray<N>,
     max_toi: N,
 ) -> Option<(N, Vector<N>)>
+where
+    N: RealField + Copy,
+    G: SupportMap<N>,
+{
+    cast_ray_with_options(m, shape, simplex, ray, max_toi, &RaycastOptions::default()).0
+}
+
+/// Casts a ray on a support map using the GJK algorithm with the given parameters.
+///
+/// The reason why the algorithm stopped is returned alongside the time of impact and normal.
+pub fn cast_ray_with_options<N, G: ?Sized>(
+    m: &Isometry<N>,
+    shape: &G,
+    simplex: &mut VoronoiSimplex<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    options: &RaycastOptions<N>,
+) -> (Option<(N, Vector<N>)>, RaycastStatus)
 where
     N: RealField + Copy,
     G: SupportMap<N>,
 {
     let m2 = Isometry::identity();
     let g2 = ConstantOrigin;
-    minkowski_ray_cast(m, shape, &m2, &g2, ray, max_toi, simplex)
+    let cast = MinkowskiRayCast {
+        ray,
+        max_toi,
+        precision: options,
+    };
+    minkowski_ray_cast(m, shape, &m2, &g2, simplex, &cast)
 }
 
 /// Compute the normal and the distance that can travel `g1` along the direction
@@ -209,8 +371,14 @@ where
     G2: SupportMap<N>,
 {
     let ray = Ray::new(Point::origin(), *dir);
-    minkowski_ray_cast(m1, g1, m2, g2, &ray, N::max_value().unwrap(), simplex).map(
-        |(toi, normal)| {
+    let cast = MinkowskiRayCast {
+        ray: &ray,
+        max_toi: N::max_value().unwrap(),
+        precision: &QueryPrecision::default(),
+    };
+    minkowski_ray_cast(m1, g1, m2, g2, simplex, &cast)
+        .0
+        .map(|(toi, normal)| {
             let witnesses = if !toi.is_zero() {
                 result(simplex, simplex.dimension() == DIM)
             } else {
@@ -220,8 +388,14 @@ where
             };
 
             (toi, normal, witnesses.0, witnesses.1)
-        },
-    )
+        })
+}
+
+// A ray cast on the Minkowski Difference, with its maximum time of impact and its tolerances.
+struct MinkowskiRayCast<'a, N: RealField + Copy> {
+    ray: &'a Ray<N>,
+    max_toi: N,
+    precision: &'a QueryPrecision<N>,
 }
 
 // Ray-cast on the Minkowski Difference `m1 * g1 - m2 * g2`.
@@ -230,23 +404,26 @@ fn minkowski_ray_cast<N, G1: ?Sized, G2: ?Sized>(
     g1: &G1,
     m2: &Isometry<N>,
     g2: &G2,
-    ray: &Ray<N>,
-    max_toi: N,
     simplex: &mut VoronoiSimplex<N>,
-) -> Option<(N, Vector<N>)>
+    cast: &MinkowskiRayCast<N>,
+) -> (Option<(N, Vector<N>)>, RaycastStatus)
 where
     N: RealField + Copy,
     G1: SupportMap<N>,
     G2: SupportMap<N>,
 {
-    let _eps = N::default_epsilon();
-    let _eps_tol: N = eps_tol();
-    let _eps_rel: N = _eps_tol.sqrt();
+    let MinkowskiRayCast {
+        ray,
+        max_toi,
+        precision,
+    } = *cast;
+    let _eps_tol: N = precision.epsilon;
+    let _eps_rel: N = precision.relative_epsilon;
 
     let ray_length = ray.dir.norm();
 
     if relative_eq!(ray_length, N::zero()) {
-        return None;
+        return (None, RaycastStatus::Degenerate);
     }
 
     let mut ltoi = N::zero();
@@ -266,13 +443,14 @@ where
     let mut last_chance = false;
 
     loop {
+        statistics::count_gjk_iteration();
         let old_max_bound = max_bound;
 
         if let Some((new_dir, dist)) = Unit::try_new_and_get(-proj.coords, _eps_tol) {
             dir = new_dir;
             max_bound = dist;
         } else {
-            return Some((ltoi / ray_length, ldir));
+            return (Some((ltoi / ray_length, ldir)), RaycastStatus::Converged);
         }
 
         let support_point = if max_bound >= old_max_bound {
@@ -285,7 +463,7 @@ where
 
         if last_chance && ltoi > N::zero() {
             // last_chance && ltoi > N::zero() && (support_point.point - curr_ray.origin).dot(&ldir) >= N::zero() {
-            return Some((ltoi / ray_length, ldir));
+            return (Some((ltoi / ray_length, ldir)), RaycastStatus::Degenerate);
         }
 
         // Clip the ray on the support plane (None <=> t < 0)
@@ -307,7 +485,7 @@ where
                     // because the multiplication may cause an overflow if max_toi is set
                     // to N::max_value().unwrap() by users that want to have an infinite ray.
                     if ltoi / ray_length > max_toi {
-                        return None;
+                        return (None, RaycastStatus::Converged);
                     }
 
                     let shift = curr_ray.dir * t;
@@ -320,13 +498,13 @@ where
             None => {
                 if dir.dot(&curr_ray.dir) > _eps_tol {
                     // miss
-                    return None;
+                    return (None, RaycastStatus::Converged);
                 }
             }
         }
 
         if last_chance {
-            return None;
+            return (None, RaycastStatus::Degenerate);
         }
 
         let min_bound = -dir.dot(&(support_point.point.coords - curr_ray.origin.coords));
@@ -340,9 +518,9 @@ where
             // this case, even with floating-point numbers. Though it
             // has not been sufficinetly tested with floats yet to be sure.
             if cfg!(feature = "improved_fixed_point_support") {
-                return Some((ltoi / ray_length, ldir));
+                return (Some((ltoi / ray_length, ldir)), RaycastStatus::Degenerate);
             } else {
-                return None;
+                return (None, RaycastStatus::Degenerate);
             }
         }
 
@@ -351,15 +529,16 @@ where
 
         if simplex.dimension() == DIM {
             if min_bound >= _eps_tol {
-                return None;
+                return (None, RaycastStatus::Converged);
             } else {
-                return Some((ltoi / ray_length, ldir)); // Point inside of the cso.
+                // Point inside of the cso.
+                return (Some((ltoi / ray_length, ldir)), RaycastStatus::Converged);
             }
         }
 
         niter += 1;
-        if niter == 10000 {
-            return None;
+        if niter >= precision.max_iterations {
+            return (None, RaycastStatus::MaxIterations);
         }
     }
 }
@@ -1,9 +1,18 @@
 //! Algorithms needed for distance and penetration depth computation.
 
 pub use self::cso_point::CSOPoint;
+pub use self::debug_trace::{DebugTrace, DebugTraceSink, EPATraceStep, GJKTraceStep};
+#[cfg(feature = "dim2")]
+pub use self::epa2::EPAOptions;
+#[cfg(feature = "dim2")]
+pub use self::epa2::EPAPolytope;
 #[cfg(feature = "dim2")]
 pub use self::epa2::EPA;
 #[cfg(feature = "dim3")]
+pub use self::epa3::EPAOptions;
+#[cfg(feature = "dim3")]
+pub use self::epa3::EPAPolytope;
+#[cfg(feature = "dim3")]
 pub use self::epa3::EPA;
 #[cfg(feature = "dim2")]
 pub use self::voronoi_simplex2::VoronoiSimplex;
@@ -11,6 +20,7 @@ pub use self::voronoi_simplex2::VoronoiSimplex;
 pub use self::voronoi_simplex3::VoronoiSimplex;
 
 mod cso_point;
+mod debug_trace;
 #[cfg(feature = "dim2")]
 pub mod epa2;
 #[cfg(feature = "dim3")]
@@ -1,4 +1,4 @@
-use crate::math::Point;
+use crate::math::{Isometry, Point, Vector};
 use na::RealField;
 use std::mem;
 
@@ -21,4 +21,69 @@ impl<N: RealField + Copy> ClosestPoints<N> {
             mem::swap(p1, p2)
         }
     }
+
+    /// Transforms the two points by `m`.
+    pub(crate) fn transform_by(&mut self, m: &Isometry<N>) {
+        if let ClosestPoints::WithinMargin(ref mut p1, ref mut p2) = *self {
+            *p1 = m * *p1;
+            *p2 = m * *p2;
+        }
+    }
+}
+
+/// The region the closest points of two shapes must lie within of each other to be reported.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClosestPointsMargin<N: RealField + Copy> {
+    /// The distance between the closest points must not exceed the given value.
+    Distance(N),
+    /// The absolute value of each component of the vector joining the closest points must not
+    /// exceed the corresponding component of the given vector.
+    ///
+    /// The components are expressed in world-space, e.g., a margin of `(2.0, 0.2)` allows a
+    /// separation of two units along the `x` axis but only of 0.2 along the `y` axis.
+    PerAxis(Vector<N>),
+}
+
+impl<N: RealField + Copy> ClosestPointsMargin<N> {
+    /// The largest distance between two points separated by a vector within this margin.
+    pub fn max_dist(&self) -> N {
+        match self {
+            ClosestPointsMargin::Distance(dist) => *dist,
+            ClosestPointsMargin::PerAxis(half_extents) => half_extents.norm(),
+        }
+    }
+
+    /// Tests if the given separation vector lies inside of this margin.
+    pub fn contains(&self, separation: &Vector<N>) -> bool {
+        match self {
+            ClosestPointsMargin::Distance(dist) => separation.norm_squared() <= *dist * *dist,
+            ClosestPointsMargin::PerAxis(half_extents) => separation
+                .iter()
+                .zip(half_extents.iter())
+                .all(|(s, h)| s.abs() <= *h),
+        }
+    }
+
+    /// The half-extents of the AABB, in the local-space of `m`, of all the separation vectors
+    /// within this margin.
+    pub fn local_half_extents(&self, m: &Isometry<N>) -> Vector<N> {
+        match self {
+            ClosestPointsMargin::Distance(dist) => Vector::repeat(*dist),
+            ClosestPointsMargin::PerAxis(half_extents) => {
+                m.rotation.to_rotation_matrix().matrix().abs().transpose() * half_extents
+            }
+        }
+    }
+
+    /// Classifies the closest points `pts`, computed with a distance threshold of at least
+    /// `self.max_dist()`, with regard to this margin.
+    pub fn filter(&self, pts: ClosestPoints<N>) -> ClosestPoints<N> {
+        match pts {
+            ClosestPoints::WithinMargin(p1, p2) if !self.contains(&(p2 - p1)) => {
+                ClosestPoints::Disjoint
+            }
+            _ => pts,
+        }
+    }
 }
@@ -0,0 +1,226 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Vector};
+use crate::partitioning::{BVHImpl, BVH};
+use crate::query::{self, ClosestPoints, ClosestPointsMargin};
+use crate::shape::CompositeShape;
+use na::{self, RealField};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Closest points between two composite shapes.
+///
+/// This performs a simultaneous branch-and-bound descent of the bounding volume hierarchies of
+/// both shapes: pairs of nodes are visited by increasing distance between their bounding volumes,
+/// and pairs further than the best distance found so far are never refined. Thus, only a small
+/// fraction of the pairs of parts are actually tested in practice.
+pub fn closest_points_composite_shape_composite_shape<N, G1, G2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    margin: N,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+    G2: ?Sized + CompositeShape<N>,
+{
+    closest_points_composite_shape_composite_shape_with_margin(
+        m1,
+        g1,
+        m2,
+        g2,
+        &ClosestPointsMargin::Distance(margin),
+    )
+}
+
+/// Closest points between two composite shapes, within an arbitrary margin.
+///
+/// The pairs of nodes whose bounding volumes are out of the margin region of each other are
+/// never refined.
+pub fn closest_points_composite_shape_composite_shape_with_margin<N, G1, G2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    margin: &ClosestPointsMargin<N>,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+    G2: ?Sized + CompositeShape<N>,
+{
+    match (g1.bvh(), g2.bvh()) {
+        (BVHImpl::BVT(bvh1), BVHImpl::BVT(bvh2)) => {
+            closest_points_bvh_bvh(m1, g1, bvh1, m2, g2, bvh2, margin)
+        }
+        (BVHImpl::BVT(bvh1), BVHImpl::DBVT(bvh2)) => {
+            closest_points_bvh_bvh(m1, g1, bvh1, m2, g2, bvh2, margin)
+        }
+        (BVHImpl::DBVT(bvh1), BVHImpl::BVT(bvh2)) => {
+            closest_points_bvh_bvh(m1, g1, bvh1, m2, g2, bvh2, margin)
+        }
+        (BVHImpl::DBVT(bvh1), BVHImpl::DBVT(bvh2)) => {
+            closest_points_bvh_bvh(m1, g1, bvh1, m2, g2, bvh2, margin)
+        }
+    }
+}
+
+fn closest_points_bvh_bvh<N, G1, G2, B1, B2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    bvh1: &B1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    bvh2: &B2,
+    margin: &ClosestPointsMargin<N>,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+    G2: ?Sized + CompositeShape<N>,
+    B1: BVH<usize, AABB<N>>,
+    B2: BVH<usize, AABB<N>>,
+{
+    let (root1, root2) = match (bvh1.root(), bvh2.root()) {
+        (Some(root1), Some(root2)) => (root1, root2),
+        _ => return ClosestPoints::Disjoint,
+    };
+
+    // All the bounding volumes of `g2` are transformed to the local-space of `g1`.
+    let ls_m2 = m1.inverse() * m2;
+    let mut queue = BinaryHeap::new();
+    let mut best_dist = margin.max_dist();
+    let mut best_result = ClosestPoints::Disjoint;
+    let local_margin = margin.local_half_extents(m1);
+
+    let cost = aabb_distance(
+        bvh1.content(root1).0,
+        &bvh2.content(root2).0.transform_by(&ls_m2),
+        &local_margin,
+    );
+    if cost <= best_dist {
+        queue.push(NodePair::new(root1, root2, cost));
+    }
+
+    while let Some(pair) = queue.pop() {
+        if pair.cost > best_dist {
+            // No pair left has a chance to be closer than the best result.
+            break;
+        }
+
+        let (bv1, data1) = bvh1.content(pair.node1);
+        let (bv2, data2) = bvh2.content(pair.node2);
+
+        if let (Some(i1), Some(i2)) = (data1, data2) {
+            let mut pts = ClosestPoints::Disjoint;
+
+            g1.map_part_at(*i1, m1, &mut |m1, g1| {
+                g2.map_part_at(*i2, m2, &mut |m2, g2| {
+                    pts = margin.filter(query::closest_points(m1, g1, m2, g2, best_dist));
+                });
+            });
+
+            match pts {
+                ClosestPoints::Intersecting => return ClosestPoints::Intersecting,
+                ClosestPoints::WithinMargin(ref p1, ref p2) => {
+                    let dist = na::distance(p1, p2);
+
+                    if dist <= best_dist {
+                        best_dist = dist;
+                        best_result = pts;
+                    }
+                }
+                ClosestPoints::Disjoint => {}
+            }
+
+            continue;
+        }
+
+        // Refine the largest node that is not a leaf.
+        let split1 = data2.is_some()
+            || (data1.is_none()
+                && bv1.half_extents().norm_squared() >= bv2.half_extents().norm_squared());
+
+        if split1 {
+            let ls_bv2 = bv2.transform_by(&ls_m2);
+
+            for i in 0..bvh1.num_children(pair.node1) {
+                let child1 = bvh1.child(i, pair.node1);
+                let cost = aabb_distance(bvh1.content(child1).0, &ls_bv2, &local_margin);
+
+                if cost <= best_dist {
+                    queue.push(NodePair::new(child1, pair.node2, cost));
+                }
+            }
+        } else {
+            for i in 0..bvh2.num_children(pair.node2) {
+                let child2 = bvh2.child(i, pair.node2);
+                let ls_child_bv2 = bvh2.content(child2).0.transform_by(&ls_m2);
+                let cost = aabb_distance(bv1, &ls_child_bv2, &local_margin);
+
+                if cost <= best_dist {
+                    queue.push(NodePair::new(pair.node1, child2, cost));
+                }
+            }
+        }
+    }
+
+    best_result
+}
+
+// The distance between two AABBs, or infinity if their gap along some axis exceeds the
+// corresponding component of `margin`.
+fn aabb_distance<N: RealField + Copy>(aabb1: &AABB<N>, aabb2: &AABB<N>, margin: &Vector<N>) -> N {
+    let gap = (aabb2.mins - aabb1.maxs)
+        .sup(&(aabb1.mins - aabb2.maxs))
+        .sup(&Vector::zeros());
+
+    if gap.iter().zip(margin.iter()).any(|(g, m)| *g > *m) {
+        N::max_value().unwrap()
+    } else {
+        gap.norm()
+    }
+}
+
+// A pair of nodes ordered by decreasing cost so that the `BinaryHeap` pops the closest pair first.
+struct NodePair<N, Node1, Node2> {
+    node1: Node1,
+    node2: Node2,
+    cost: N,
+}
+
+impl<N, Node1, Node2> NodePair<N, Node1, Node2> {
+    fn new(node1: Node1, node2: Node2, cost: N) -> Self {
+        NodePair { node1, node2, cost }
+    }
+}
+
+impl<N: PartialEq, Node1, Node2> PartialEq for NodePair<N, Node1, Node2> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<N: PartialEq, Node1, Node2> Eq for NodePair<N, Node1, Node2> {}
+
+impl<N: PartialOrd, Node1, Node2> PartialOrd for NodePair<N, Node1, Node2> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: PartialOrd, Node1, Node2> Ord for NodePair<N, Node1, Node2> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Greater
+        } else if self.cost > other.cost {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+}
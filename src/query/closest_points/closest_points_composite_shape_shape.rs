@@ -1,7 +1,7 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point, Vector};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{self, ClosestPoints, PointQuery};
+use crate::query::{self, statistics, ClosestPoints, ClosestPointsMargin, PointQuery};
 use crate::shape::{CompositeShape, Shape};
 use na::{self, RealField};
 
@@ -17,12 +17,13 @@ where
     N: RealField + Copy,
     G1: CompositeShape<N>,
 {
-    let mut visitor = CompositeShapeAgainstShapeClosestPointsVisitor::new(m1, g1, m2, g2, margin);
-
-    g1.bvh()
-        .best_first_search(&mut visitor)
-        .expect("The composite shape must not be empty.")
-        .1
+    closest_points_composite_shape_shape_with_margin(
+        m1,
+        g1,
+        m2,
+        g2,
+        &ClosestPointsMargin::Distance(margin),
+    )
 }
 
 /// Closest points between a shape and a composite shape.
@@ -42,15 +43,108 @@ where
     res
 }
 
+/// Closest points between a composite shape and any other shape, within an arbitrary margin.
+///
+/// The parts of `g1` whose bounding volumes are out of the margin region are skipped.
+pub fn closest_points_composite_shape_shape_with_margin<N, G1>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: &ClosestPointsMargin<N>,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+{
+    let ls_m2 = m1.inverse() * m2;
+    let mut visitor =
+        CompositeShapeAgainstShapeClosestPointsVisitor::new(m1, &ls_m2, g1, g2, margin);
+
+    g1.bvh()
+        .best_first_search(&mut visitor)
+        .map(|res| res.1)
+        .unwrap_or(ClosestPoints::Disjoint)
+}
+
+/// Closest points between a shape and a composite shape, within an arbitrary margin.
+pub fn closest_points_shape_composite_shape_with_margin<N, G2>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &G2,
+    margin: &ClosestPointsMargin<N>,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G2: ?Sized + CompositeShape<N>,
+{
+    let mut res = closest_points_composite_shape_shape_with_margin(m2, g2, m1, g1, margin);
+    res.flip();
+    res
+}
+
+/// Closest points between a composite shape and any other shape, expressed in the local space of
+/// the composite shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`. The parts of `g1` are queried at their
+/// local positions so no inverse transformation is computed.
+pub fn closest_points_composite_shape_shape_local<N, G1>(
+    pos12: &Isometry<N>,
+    g1: &G1,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+{
+    let mut visitor = CompositeShapeAgainstShapeClosestPointsVisitor::new(
+        &Isometry::identity(),
+        pos12,
+        g1,
+        g2,
+        &ClosestPointsMargin::Distance(margin),
+    );
+
+    g1.bvh()
+        .best_first_search(&mut visitor)
+        .map(|res| res.1)
+        .unwrap_or(ClosestPoints::Disjoint)
+}
+
+/// Closest points between a shape and a composite shape, expressed in the local space of the
+/// first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn closest_points_shape_composite_shape_local<N, G2>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &G2,
+    margin: N,
+) -> ClosestPoints<N>
+where
+    N: RealField + Copy,
+    G2: ?Sized + CompositeShape<N>,
+{
+    let mut res = closest_points_composite_shape_shape_local(&pos12.inverse(), g2, g1, margin);
+    res.flip();
+    res.transform_by(pos12);
+    res
+}
+
 struct CompositeShapeAgainstShapeClosestPointsVisitor<'a, N: 'a + RealField + Copy, G1: ?Sized + 'a>
 {
     msum_shift: Vector<N>,
     msum_margin: Vector<N>,
-    margin: N,
+    margin: ClosestPointsMargin<N>,
+    local_margin: Vector<N>,
 
-    m1: &'a Isometry<N>,
+    // The position of `g1`, used to express the closest points in the frame of the margin.
+    m1: Isometry<N>,
     g1: &'a G1,
-    m2: &'a Isometry<N>,
+    // The position of `g2` relative to `g1`.
+    pos12: Isometry<N>,
     g2: &'a dyn Shape<N>,
 }
 
@@ -60,23 +154,23 @@ where
     G1: CompositeShape<N>,
 {
     pub fn new(
-        m1: &'a Isometry<N>,
+        m1: &Isometry<N>,
+        pos12: &Isometry<N>,
         g1: &'a G1,
-        m2: &'a Isometry<N>,
         g2: &'a dyn Shape<N>,
-        margin: N,
+        margin: &ClosestPointsMargin<N>,
     ) -> CompositeShapeAgainstShapeClosestPointsVisitor<'a, N, G1> {
-        let ls_m2 = m1.inverse() * m2.clone();
-        let ls_aabb2 = g2.aabb(&ls_m2);
+        let ls_aabb2 = g2.aabb(pos12);
 
         CompositeShapeAgainstShapeClosestPointsVisitor {
             msum_shift: -ls_aabb2.center().coords,
             msum_margin: ls_aabb2.half_extents(),
-            margin: margin,
-            m1: m1,
-            g1: g1,
-            m2: m2,
-            g2: g2,
+            margin: *margin,
+            local_margin: margin.local_half_extents(m1),
+            m1: *m1,
+            g1,
+            pos12: *pos12,
+            g2,
         }
     }
 }
@@ -101,6 +195,13 @@ where
             bv.maxs + self.msum_shift + self.msum_margin,
         );
 
+        // The origin must lie within the margin region around the minkowski sum.
+        for k in 0..msum.mins.len() {
+            if msum.mins[k] > self.local_margin[k] || msum.maxs[k] < -self.local_margin[k] {
+                return BestFirstVisitStatus::Stop;
+            }
+        }
+
         let dist = msum.distance_to_point(&Isometry::identity(), &Point::origin(), true);
 
         let mut res = BestFirstVisitStatus::Continue {
@@ -110,21 +211,31 @@ where
 
         if let Some(b) = data {
             if dist < best {
-                self.g1.map_part_at(*b, self.m1, &mut |m1, g1| {
-                    let pts = query::closest_points(m1, g1, self.m2, self.g2, self.margin);
-                    match pts {
-                        ClosestPoints::WithinMargin(ref p1, ref p2) => {
-                            res = BestFirstVisitStatus::Continue {
-                                cost: na::distance(p1, p2),
-                                result: Some(pts),
+                statistics::count_primitive_test();
+                self.g1
+                    .map_part_at(*b, &Isometry::identity(), &mut |m1, g1| {
+                        let mut pts = query::closest_points(
+                            m1,
+                            g1,
+                            &self.pos12,
+                            self.g2,
+                            self.margin.max_dist(),
+                        );
+                        pts.transform_by(&self.m1);
+                        let pts = self.margin.filter(pts);
+                        match pts {
+                            ClosestPoints::WithinMargin(ref p1, ref p2) => {
+                                res = BestFirstVisitStatus::Continue {
+                                    cost: na::distance(p1, p2),
+                                    result: Some(pts),
+                                }
+                            }
+                            ClosestPoints::Intersecting => {
+                                res = BestFirstVisitStatus::ExitEarly(Some(pts))
                             }
-                        }
-                        ClosestPoints::Intersecting => {
-                            res = BestFirstVisitStatus::ExitEarly(Some(pts))
-                        }
-                        ClosestPoints::Disjoint => {}
-                    };
-                });
+                            ClosestPoints::Disjoint => {}
+                        };
+                    });
             }
         }
 
@@ -0,0 +1,81 @@
+use crate::math::Point;
+use crate::query::Ray;
+use na::RealField;
+
+/// Closest points between two rays.
+///
+/// The result, say `res`, is such that the closest points between both rays are
+/// `ray1.point_at(res.0)` and `ray2.point_at(res.1)`. Both parameters are non-negative.
+#[inline]
+pub fn closest_points_ray_ray_parameters<N: RealField + Copy>(
+    ray1: &Ray<N>,
+    ray2: &Ray<N>,
+) -> (N, N) {
+    let res = closest_points_ray_ray_parameters_eps(ray1, ray2, N::default_epsilon());
+    (res.0, res.1)
+}
+
+/// Closest points between two rays with a custom tolerance epsilon.
+///
+/// The result, say `res`, is such that the closest points between both rays are
+/// `ray1.point_at(res.0)` and `ray2.point_at(res.1)`. If the rays are parallel then
+/// `res.2` is set to `true` and the returned closest points are taken among the ray
+/// origins projected on the other ray.
+#[inline]
+pub fn closest_points_ray_ray_parameters_eps<N: RealField + Copy>(
+    ray1: &Ray<N>,
+    ray2: &Ray<N>,
+    eps: N,
+) -> (N, N, bool) {
+    // Same as the segment-segment case, without any upper bound on the parameters.
+    let r = ray1.origin - ray2.origin;
+
+    let a = ray1.dir.norm_squared();
+    let e = ray2.dir.norm_squared();
+    let f = ray2.dir.dot(&r);
+
+    if a <= eps && e <= eps {
+        (N::zero(), N::zero(), false)
+    } else if a <= eps {
+        (N::zero(), (f / e).max(N::zero()), false)
+    } else {
+        let c = ray1.dir.dot(&r);
+
+        if e <= eps {
+            ((-c / a).max(N::zero()), N::zero(), false)
+        } else {
+            let b = ray1.dir.dot(&ray2.dir);
+            let ae = a * e;
+            let bb = b * b;
+            let denom = ae - bb;
+
+            // Use absolute and ulps error to test collinearity.
+            let parallel = denom <= eps || ulps_eq!(ae, bb);
+
+            let mut s = if !parallel {
+                ((b * f - c * e) / denom).max(N::zero())
+            } else {
+                N::zero()
+            };
+
+            let mut t = (b * s + f) / e;
+
+            if t < N::zero() {
+                t = N::zero();
+                s = (-c / a).max(N::zero());
+            }
+
+            (s, t, parallel)
+        }
+    }
+}
+
+/// Closest points between two rays.
+#[inline]
+pub fn closest_points_ray_ray<N: RealField + Copy>(
+    ray1: &Ray<N>,
+    ray2: &Ray<N>,
+) -> (Point<N>, Point<N>) {
+    let (s, t) = closest_points_ray_ray_parameters(ray1, ray2);
+    (ray1.point_at(s), ray2.point_at(t))
+}
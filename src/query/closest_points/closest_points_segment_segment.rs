@@ -64,6 +64,43 @@ pub fn closest_points_segment_segment_with_locations_nD_eps<N, const D: usize>(
     seg2: (&Point<N, D>, &Point<N, D>),
     eps: N,
 ) -> (SegmentPointLocation<N>, SegmentPointLocation<N>, bool)
+where
+    N: RealField + Copy,
+{
+    let (s, t, parallel) = closest_points_segment_segment_parameters_eps(seg1, seg2, eps);
+    (location(s), location(t), parallel)
+}
+
+/// Parameters of the closest points between two segments.
+///
+/// The result, say `res`, is such that the closest points between both segments are
+/// `seg1.0 + (seg1.1 - seg1.0) * res.0` and `seg2.0 + (seg2.1 - seg2.0) * res.1`. Both
+/// parameters lie in `[0, 1]`.
+#[inline]
+pub fn closest_points_segment_segment_parameters<N, const D: usize>(
+    seg1: (&Point<N, D>, &Point<N, D>),
+    seg2: (&Point<N, D>, &Point<N, D>),
+) -> (N, N)
+where
+    N: RealField + Copy,
+{
+    let res = closest_points_segment_segment_parameters_eps(seg1, seg2, N::default_epsilon());
+    (res.0, res.1)
+}
+
+/// Parameters of the closest points between two segments with a custom tolerance epsilon.
+///
+/// The result, say `res`, is such that the closest points between both segments are
+/// `seg1.0 + (seg1.1 - seg1.0) * res.0` and `seg2.0 + (seg2.1 - seg2.0) * res.1`. If the
+/// segments are parallel then `res.2` is set to `true` and the returned closest points are
+/// taken among the endpoints projected on the other segment, so they lie on the overlapping
+/// part of both segments if there is one.
+#[inline]
+pub fn closest_points_segment_segment_parameters_eps<N, const D: usize>(
+    seg1: (&Point<N, D>, &Point<N, D>),
+    seg2: (&Point<N, D>, &Point<N, D>),
+    eps: N,
+) -> (N, N, bool)
 where
     N: RealField + Copy,
 {
@@ -121,21 +158,15 @@ where
         }
     }
 
-    let loc1 = if s == _0 {
-        SegmentPointLocation::OnVertex(0)
-    } else if s == _1 {
-        SegmentPointLocation::OnVertex(1)
-    } else {
-        SegmentPointLocation::OnEdge([_1 - s, s])
-    };
+    (s, t, parallel)
+}
 
-    let loc2 = if t == _0 {
+fn location<N: RealField + Copy>(t: N) -> SegmentPointLocation<N> {
+    if t == N::zero() {
         SegmentPointLocation::OnVertex(0)
-    } else if t == _1 {
+    } else if t == N::one() {
         SegmentPointLocation::OnVertex(1)
     } else {
-        SegmentPointLocation::OnEdge([_1 - t, t])
-    };
-
-    (loc1, loc2, parallel)
+        SegmentPointLocation::OnEdge([N::one() - t, t])
+    }
 }
@@ -0,0 +1,48 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::Shape;
+
+/// Projects each point of a point cloud on a shape in a single pass.
+///
+/// Returns, for each point of `points`, its projection on the boundary of `shape` transformed by
+/// `m`, or `None` if the point is outside of the shape and farther than `max_dist` from it. The
+/// points inside of the shape are always projected, with `is_inside` set to `true`.
+///
+/// The points are brought to the local-space of the shape once, and those farther than
+/// `max_dist` from its AABB are rejected without being projected. Composite shapes then use their
+/// BVT to project each of the remaining points.
+pub fn closest_points_shape_pointcloud<N: RealField + Copy>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    points: &[Point<N>],
+    max_dist: N,
+) -> Vec<Option<PointProjection<N>>> {
+    let query = shape
+        .as_point_query()
+        .expect("No PointQuery implementation for the underlying shape.");
+    let identity = Isometry::identity();
+    let inv_m = m.inverse();
+    let aabb = shape.local_aabb();
+
+    points
+        .iter()
+        .map(|pt| {
+            let ls_pt = inv_m * pt;
+
+            if aabb.distance_to_point(&identity, &ls_pt, true) > max_dist {
+                return None;
+            }
+
+            let mut proj = query.project_point(&identity, &ls_pt, false);
+
+            if proj.is_inside || na::distance(&ls_pt, &proj.point) <= max_dist {
+                proj.point = m * proj.point;
+                Some(proj)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -1,7 +1,7 @@
 use na::RealField;
 
 use crate::math::{Isometry, Point};
-use crate::query::{self, ClosestPoints};
+use crate::query::{self, ClosestPoints, ClosestPointsMargin};
 use crate::shape::{Ball, Plane, Segment, Shape};
 
 /// Computes the pair of closest points between two shapes.
@@ -28,6 +28,8 @@ pub fn closest_points<N: RealField + Copy>(
         query::closest_points_support_map_plane(m1, s1, m2, p2, max_dist)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::closest_points_support_map_support_map(m1, s1, m2, s2, max_dist)
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        query::closest_points_composite_shape_composite_shape(m1, c1, m2, c2, max_dist)
     } else if let Some(c1) = g1.as_composite_shape() {
         query::closest_points_composite_shape_shape(m1, c1, m2, g2, max_dist)
     } else if let Some(c2) = g2.as_composite_shape() {
@@ -36,3 +38,48 @@ pub fn closest_points<N: RealField + Copy>(
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes the pair of closest points between two shapes, expressed in the local space of the
+/// first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`, e.g. `m1.inverse() * m2`.
+pub fn closest_points_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+    max_dist: N,
+) -> ClosestPoints<N> {
+    if let (Some(_), Some(_)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        closest_points(&Isometry::identity(), g1, pos12, g2, max_dist)
+    } else if let Some(c1) = g1.as_composite_shape() {
+        query::closest_points_composite_shape_shape_local(pos12, c1, g2, max_dist)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::closest_points_shape_composite_shape_local(pos12, g1, c2, max_dist)
+    } else {
+        closest_points(&Isometry::identity(), g1, pos12, g2, max_dist)
+    }
+}
+
+/// Computes the pair of closest points between two shapes, within an arbitrary margin.
+///
+/// Returns `ClosestPoints::Disjoint` if the closest points are not within `margin` of each
+/// other, e.g., if they are too far apart along an axis with `ClosestPointsMargin::PerAxis`.
+/// The bounding volumes of the parts of composite shapes are tested against the margin region
+/// so that the parts out of it are skipped.
+pub fn closest_points_with_margin<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    margin: &ClosestPointsMargin<N>,
+) -> ClosestPoints<N> {
+    if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        query::closest_points_composite_shape_composite_shape_with_margin(m1, c1, m2, c2, margin)
+    } else if let Some(c1) = g1.as_composite_shape() {
+        query::closest_points_composite_shape_shape_with_margin(m1, c1, m2, g2, margin)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::closest_points_shape_composite_shape_with_margin(m1, g1, m2, c2, margin)
+    } else {
+        margin.filter(closest_points(m1, g1, m2, g2, margin.max_dist()))
+    }
+}
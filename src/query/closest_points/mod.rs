@@ -1,9 +1,15 @@
 //! Implementation details of the `closest_points` function.
 
-pub use self::closest_points::ClosestPoints;
+pub use self::closest_points::{ClosestPoints, ClosestPointsMargin};
 pub use self::closest_points_ball_ball::closest_points_ball_ball;
+pub use self::closest_points_composite_shape_composite_shape::{
+    closest_points_composite_shape_composite_shape,
+    closest_points_composite_shape_composite_shape_with_margin,
+};
 pub use self::closest_points_composite_shape_shape::{
-    closest_points_composite_shape_shape, closest_points_shape_composite_shape,
+    closest_points_composite_shape_shape, closest_points_composite_shape_shape_local,
+    closest_points_composite_shape_shape_with_margin, closest_points_shape_composite_shape,
+    closest_points_shape_composite_shape_local, closest_points_shape_composite_shape_with_margin,
 };
 pub use self::closest_points_line_line::{
     closest_points_line_line, closest_points_line_line_parameters,
@@ -12,20 +18,31 @@ pub use self::closest_points_line_line::{
 pub use self::closest_points_plane_support_map::{
     closest_points_plane_support_map, closest_points_support_map_plane,
 };
+pub use self::closest_points_ray_ray::{
+    closest_points_ray_ray, closest_points_ray_ray_parameters,
+    closest_points_ray_ray_parameters_eps,
+};
 pub use self::closest_points_segment_segment::{
-    closest_points_segment_segment, closest_points_segment_segment_with_locations,
+    closest_points_segment_segment, closest_points_segment_segment_parameters,
+    closest_points_segment_segment_parameters_eps, closest_points_segment_segment_with_locations,
     closest_points_segment_segment_with_locations_nD,
     closest_points_segment_segment_with_locations_nD_eps,
 };
-pub use self::closest_points_shape_shape::closest_points;
+pub use self::closest_points_shape_pointcloud::closest_points_shape_pointcloud;
+pub use self::closest_points_shape_shape::{
+    closest_points, closest_points_local, closest_points_with_margin,
+};
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map;
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map_with_params;
 
 mod closest_points;
 mod closest_points_ball_ball;
+mod closest_points_composite_shape_composite_shape;
 mod closest_points_composite_shape_shape;
 mod closest_points_line_line;
 mod closest_points_plane_support_map;
+mod closest_points_ray_ray;
 mod closest_points_segment_segment;
+mod closest_points_shape_pointcloud;
 mod closest_points_shape_shape;
 mod closest_points_support_map_support_map;
@@ -1,5 +1,7 @@
-use crate::math::{Point, Vector};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::ContactKinematic;
+use crate::shape::Shape;
+use crate::utils;
 use na::{self, RealField, Unit};
 use slotmap::Key;
 use std::mem;
@@ -44,6 +46,21 @@ impl<N: RealField + Copy> Contact<N> {
         let depth = -normal.dot(&(world2 - world1));
         Self::new(world1, world2, normal, depth)
     }
+
+    /// The signed distance separating the two contact points along the contact normal.
+    ///
+    /// This is the opposite of the penetration depth. It is positive for speculative contacts,
+    /// i.e., contacts between shapes that are not penetrating yet.
+    #[inline]
+    pub fn separation(&self) -> N {
+        -self.depth
+    }
+
+    /// Whether this contact is a speculative contact, i.e., the two shapes are not penetrating.
+    #[inline]
+    pub fn is_speculative(&self) -> bool {
+        self.depth < N::zero()
+    }
 }
 
 impl<N: RealField + Copy> Contact<N> {
@@ -53,6 +70,14 @@ impl<N: RealField + Copy> Contact<N> {
         mem::swap(&mut self.world1, &mut self.world2);
         self.normal = -self.normal;
     }
+
+    /// Transforms the contact points and normal by `m`.
+    #[inline]
+    pub(crate) fn transform_by(&mut self, m: &Isometry<N>) {
+        self.world1 = m * self.world1;
+        self.world2 = m * self.world2;
+        self.normal = m * self.normal;
+    }
 }
 
 /// A contact combined with contact kinematic information as well as a persistent identifier.
@@ -80,6 +105,24 @@ impl<N: RealField + Copy> TrackedContact<N> {
             id: ContactId::null(),
         }
     }
+
+    /// Recomputes this contact after the solids moved to `m1` and `m2`, without running the
+    /// collision detection again.
+    ///
+    /// The contact is recomputed from `self.kinematic`, which also gives the features and local
+    /// points of both solids the contact lies on. Returns `None` if the contact can no longer be
+    /// computed from this information, e.g., if the solids rotated too much.
+    pub fn updated_contact(
+        &self,
+        m1: &Isometry<N>,
+        s1: &dyn Shape<N>,
+        m2: &Isometry<N>,
+        s2: &dyn Shape<N>,
+    ) -> Option<Contact<N>> {
+        let default_normal = m1.inverse_transform_unit_vector(&self.contact.normal);
+        self.kinematic
+            .contact(m1, s1, None, m2, s2, None, &default_normal)
+    }
 }
 
 /// The prediction parameters for contact determination.
@@ -101,10 +144,10 @@ impl<N: RealField + Copy> ContactPrediction<N> {
             linear,
             angular1,
             angular2,
-            cos_angular1: angular1.cos(),
-            cos_angular2: angular2.cos(),
-            sin_angular1: angular1.sin(),
-            sin_angular2: angular2.sin(),
+            cos_angular1: utils::cos(angular1),
+            cos_angular2: utils::cos(angular2),
+            sin_angular1: utils::sin(angular1),
+            sin_angular2: utils::sin(angular2),
         }
     }
 
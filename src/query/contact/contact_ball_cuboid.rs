@@ -0,0 +1,86 @@
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::query::Contact;
+use crate::shape::{Ball, Cuboid};
+use na::{RealField, Unit};
+
+/// Contact between a ball and a cuboid.
+///
+/// This is a closed-form computation that does not rely on any iterative algorithm.
+#[inline]
+pub fn contact_ball_cuboid<N: RealField + Copy>(
+    ball_center1: &Point<N>,
+    ball1: &Ball<N>,
+    m2: &Isometry<N>,
+    cuboid2: &Cuboid<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let he = cuboid2.half_extents;
+    let local_center = m2.inverse_transform_point(ball_center1);
+    let proj = Point::from(local_center.coords.sup(&-he).inf(&he));
+    let dpt = proj - local_center;
+
+    let local_normal;
+    let depth;
+    let local_world2;
+
+    if let Some((dir, dist)) = Unit::try_new_and_get(dpt, N::default_epsilon()) {
+        // The ball center is outside of the cuboid.
+        local_normal = dir;
+        depth = ball1.radius - dist;
+        local_world2 = proj;
+    } else {
+        // The ball center is inside of the cuboid: push it through the closest face.
+        let mut best = 0;
+        let mut best_dist = he[0] - local_center[0].abs();
+
+        for i in 1..DIM {
+            let dist = he[i] - local_center[i].abs();
+
+            if dist < best_dist {
+                best = i;
+                best_dist = dist;
+            }
+        }
+
+        let mut normal = Vector::zeros();
+        let mut world2 = local_center;
+
+        if local_center[best] >= N::zero() {
+            normal[best] = -N::one();
+            world2[best] = he[best];
+        } else {
+            normal[best] = N::one();
+            world2[best] = -he[best];
+        }
+
+        local_normal = Unit::new_unchecked(normal);
+        depth = ball1.radius + best_dist;
+        local_world2 = world2;
+    }
+
+    if depth >= -prediction {
+        let normal = m2 * local_normal;
+        let world1 = ball_center1 + normal.into_inner() * ball1.radius;
+        Some(Contact::new(world1, m2 * local_world2, normal, depth))
+    } else {
+        None
+    }
+}
+
+/// Contact between a cuboid and a ball.
+///
+/// This is a closed-form computation that does not rely on any iterative algorithm.
+#[inline]
+pub fn contact_cuboid_ball<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    cuboid1: &Cuboid<N>,
+    ball_center2: &Point<N>,
+    ball2: &Ball<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let mut res = contact_ball_cuboid(ball_center2, ball2, m1, cuboid1, prediction);
+    if let Some(c) = &mut res {
+        c.flip()
+    }
+    res
+}
@@ -0,0 +1,119 @@
+use crate::math::Isometry;
+use crate::query::{self, Contact};
+use crate::shape::{Capsule, Segment, Shape};
+use na::RealField;
+
+/// Dilates the first shape of a contact by `radius`.
+#[inline]
+pub(crate) fn dilate_contact1<N: RealField + Copy>(mut c: Contact<N>, radius: N) -> Contact<N> {
+    c.world1 += *c.normal * radius;
+    c.depth += radius;
+    c
+}
+
+/// Dilates the second shape of a contact by `radius`.
+#[inline]
+pub(crate) fn dilate_contact2<N: RealField + Copy>(mut c: Contact<N>, radius: N) -> Contact<N> {
+    c.world2 -= *c.normal * radius;
+    c.depth += radius;
+    c
+}
+
+/// Contact between a capsule and a segment.
+///
+/// This relies on the closed-form segment-segment closest points computation between the
+/// capsule axis and the segment instead of GJK, so it remains exact when the capsule is nearly
+/// tangent to the segment.
+#[inline]
+pub fn contact_capsule_segment<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    capsule1: &Capsule<N>,
+    m2: &Isometry<N>,
+    segment2: &Segment<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact_segment_segment(
+        m1,
+        &capsule1.segment(),
+        m2,
+        segment2,
+        prediction + capsule1.radius,
+    )
+    .map(|c| dilate_contact1(c, capsule1.radius))
+}
+
+/// Contact between a segment and a capsule.
+#[inline]
+pub fn contact_segment_capsule<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    segment1: &Segment<N>,
+    m2: &Isometry<N>,
+    capsule2: &Capsule<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    contact_capsule_segment(m2, capsule2, m1, segment1, prediction).map(|mut c| {
+        c.flip();
+        c
+    })
+}
+
+/// Contact between two capsules.
+#[inline]
+pub fn contact_capsule_capsule<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    capsule1: &Capsule<N>,
+    m2: &Isometry<N>,
+    capsule2: &Capsule<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact_segment_segment(
+        m1,
+        &capsule1.segment(),
+        m2,
+        &capsule2.segment(),
+        prediction + capsule1.radius + capsule2.radius,
+    )
+    .map(|c| dilate_contact2(dilate_contact1(c, capsule1.radius), capsule2.radius))
+}
+
+/// Contact between a capsule and any other shape.
+///
+/// The contact is computed between the axis of the capsule and the other shape, then dilated by
+/// the capsule radius. In particular, contacts with segments and polylines are computed in
+/// closed form.
+#[inline]
+pub fn contact_capsule_shape<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    capsule1: &Capsule<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact(
+        m1,
+        &capsule1.segment(),
+        m2,
+        g2,
+        prediction + capsule1.radius,
+    )
+    .map(|c| dilate_contact1(c, capsule1.radius))
+}
+
+/// Contact between any shape and a capsule.
+#[inline]
+pub fn contact_shape_capsule<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    capsule2: &Capsule<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact(
+        m1,
+        g1,
+        m2,
+        &capsule2.segment(),
+        prediction + capsule2.radius,
+    )
+    .map(|c| dilate_contact2(c, capsule2.radius))
+}
@@ -1,11 +1,17 @@
 use crate::bounding_volume::BoundingVolume;
 use crate::math::Isometry;
-use crate::query::visitors::BoundingVolumeInterferencesCollector;
-use crate::query::{self, Contact};
+use crate::query::contact::contact_capsule::{dilate_contact1, dilate_contact2};
+use crate::query::visitors::{
+    AABBSetsInterferencesCollector, BoundingVolumeInterferencesCollector,
+};
+use crate::query::{self, statistics, Contact, QueryBuffers};
 use crate::shape::{CompositeShape, Shape};
 use na::{self, RealField};
+use std::mem;
 
 /// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// Each part of the composite shape is dilated by its `thickness()`.
 pub fn contact_composite_shape_shape<N: RealField + Copy, G1: ?Sized>(
     m1: &Isometry<N>,
     g1: &G1,
@@ -15,10 +21,49 @@ pub fn contact_composite_shape_shape<N: RealField + Copy, G1: ?Sized>(
 ) -> Option<Contact<N>>
 where
     G1: CompositeShape<N>,
+{
+    let mut res = contact_composite_shape_shape_local(&(m1.inverse() * m2), g1, g2, prediction);
+    if let Some(c) = &mut res {
+        c.transform_by(m1)
+    }
+    res
+}
+
+/// Best contact between a shape and a composite (`Mesh`, `Compound`) shape.
+pub fn contact_shape_composite_shape<N: RealField + Copy, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+) -> Option<Contact<N>>
+where
+    G2: CompositeShape<N>,
+{
+    let mut res = contact_composite_shape_shape(m2, g2, m1, g1, prediction);
+    if let Some(c) = &mut res {
+        c.flip()
+    }
+    res
+}
+
+/// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape, expressed in
+/// the local space of the composite shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`. The parts of `g1` are queried at their
+/// local positions so no inverse transformation is computed.
+pub fn contact_composite_shape_shape_local<N: RealField + Copy, G1>(
+    pos12: &Isometry<N>,
+    g1: &G1,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>>
+where
+    G1: ?Sized + CompositeShape<N>,
 {
     // Find new collisions
-    let ls_m2 = m1.inverse() * m2.clone();
-    let ls_aabb2 = g2.aabb(&ls_m2).loosened(prediction);
+    let thickness = g1.thickness();
+    let ls_aabb2 = g2.aabb(pos12).loosened(prediction + thickness);
 
     let mut interferences = Vec::new();
 
@@ -30,8 +75,10 @@ where
     let mut res = None::<Contact<N>>;
 
     for i in interferences.into_iter() {
-        g1.map_part_at(i, m1, &mut |m, part| {
-            if let Some(c) = query::contact(m, part, m2, g2, prediction) {
+        statistics::count_primitive_test();
+        g1.map_part_at(i, &Isometry::identity(), &mut |m, part| {
+            if let Some(c) = query::contact(m, part, pos12, g2, prediction + thickness) {
+                let c = dilate_contact1(c, thickness);
                 let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
 
                 if replace {
@@ -44,20 +91,152 @@ where
     res
 }
 
-/// Best contact between a shape and a composite (`Mesh`, `Compound`) shape.
-pub fn contact_shape_composite_shape<N: RealField + Copy, G2: ?Sized>(
+/// Best contact between a shape and a composite (`Mesh`, `Compound`) shape, expressed in the
+/// local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn contact_shape_composite_shape_local<N: RealField + Copy, G2>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &G2,
+    prediction: N,
+) -> Option<Contact<N>>
+where
+    G2: ?Sized + CompositeShape<N>,
+{
+    let mut res = contact_composite_shape_shape_local(&pos12.inverse(), g2, g1, prediction);
+    if let Some(c) = &mut res {
+        c.flip();
+        c.transform_by(pos12)
+    }
+    res
+}
+
+/// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape, reusing the
+/// scratch memory of `buffers`.
+pub fn contact_composite_shape_shape_with_buffers<N: RealField + Copy, G1: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+    buffers: &mut QueryBuffers<N>,
+) -> Option<Contact<N>>
+where
+    G1: CompositeShape<N>,
+{
+    let thickness = g1.thickness();
+    let ls_m2 = m1.inverse() * m2.clone();
+    let ls_aabb2 = g2.aabb(&ls_m2).loosened(prediction + thickness);
+
+    // The interferences are taken out of the buffers so the recursive queries can use them.
+    let mut interferences = mem::take(&mut buffers.interferences);
+    interferences.clear();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
+        g1.bvh().visit(&mut visitor);
+    }
+
+    let mut res = None::<Contact<N>>;
+
+    for i in interferences.iter() {
+        statistics::count_primitive_test();
+        g1.map_part_at(*i, &Isometry::identity(), &mut |m, part| {
+            let part_prediction = prediction + thickness;
+
+            if let Some(c) =
+                query::contact_with_buffers(m, part, &ls_m2, g2, part_prediction, buffers)
+            {
+                let c = dilate_contact1(c, thickness);
+                let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
+
+                if replace {
+                    res = Some(c)
+                }
+            }
+        });
+    }
+
+    buffers.interferences = interferences;
+
+    if let Some(c) = &mut res {
+        c.transform_by(m1)
+    }
+    res
+}
+
+/// Best contact between a shape and a composite (`Mesh`, `Compound`) shape, reusing the scratch
+/// memory of `buffers`.
+pub fn contact_shape_composite_shape_with_buffers<N: RealField + Copy, G2: ?Sized>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
     m2: &Isometry<N>,
     g2: &G2,
     prediction: N,
+    buffers: &mut QueryBuffers<N>,
 ) -> Option<Contact<N>>
 where
     G2: CompositeShape<N>,
 {
-    let mut res = contact_composite_shape_shape(m2, g2, m1, g1, prediction);
+    let mut res = contact_composite_shape_shape_with_buffers(m2, g2, m1, g1, prediction, buffers);
     if let Some(c) = &mut res {
         c.flip()
     }
     res
 }
+
+/// Best contact between two composite shapes (`Mesh`, `Compound`).
+///
+/// The pairs of parts with intersecting bounding volumes are found with a simultaneous
+/// traversal of the bounding volume hierarchies of both shapes. The contact with the largest
+/// depth among those pairs of parts is returned. In 3D, persistent contact manifolds between two
+/// `TriMesh` are generated by the `TriMeshTriMeshManifoldGenerator` of the narrow phase instead.
+pub fn contact_composite_shape_composite_shape<N: RealField + Copy, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+) -> Option<Contact<N>>
+where
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    let (thickness1, thickness2) = (g1.thickness(), g2.thickness());
+    let part_prediction = prediction + thickness1 + thickness2;
+    let ls_m2 = m1.inverse() * m2;
+    // For transforming AABBs from g2 in the local space of g1.
+    let ls_m2_abs_rot = ls_m2.rotation.to_rotation_matrix().matrix().abs();
+    let mut interferences = Vec::new();
+
+    {
+        let mut visitor = AABBSetsInterferencesCollector::new(
+            part_prediction,
+            &ls_m2,
+            &ls_m2_abs_rot,
+            &mut interferences,
+        );
+        g1.bvh().visit_bvtt(g2.bvh(), &mut visitor);
+    }
+
+    let mut res = None::<Contact<N>>;
+
+    for (i1, i2) in interferences.into_iter() {
+        statistics::count_primitive_test();
+        g1.map_part_at(i1, m1, &mut |m1, part1| {
+            g2.map_part_at(i2, m2, &mut |m2, part2| {
+                if let Some(c) = query::contact(m1, part1, m2, part2, part_prediction) {
+                    let c = dilate_contact2(dilate_contact1(c, thickness1), thickness2);
+                    let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
+
+                    if replace {
+                        res = Some(c)
+                    }
+                }
+            });
+        });
+    }
+
+    res
+}
@@ -1,8 +1,8 @@
-use crate::math::Point;
+use crate::math::{Isometry, Point, Vector};
 use crate::query::ContactPreprocessor;
-use crate::query::{Contact, ContactKinematic, TrackedContact};
-use crate::shape::FeatureId;
-use na::{self, RealField};
+use crate::query::{Contact, ContactKinematic, ContactPrediction, TrackedContact};
+use crate::shape::{ConvexPolygonalFeature, FeatureId, Shape};
+use na::{self, RealField, Unit};
 use slab::Slab;
 use std::collections::{hash_map::Entry, HashMap};
 
@@ -155,6 +155,73 @@ impl<N: RealField + Copy> ContactManifold<N> {
         });
     }
 
+    /// Updates the contacts of this manifold after the shapes moved to `m1` and `m2`, without
+    /// running the collision detection again.
+    ///
+    /// Each contact is recomputed from its kinematic information. A contact is removed if it is
+    /// now separated by more than `prediction.linear()`, or if one of the features it lies on is
+    /// no longer a support feature of its polyhedral shape along the contact normal. The angular
+    /// predictions are used as tolerances for this test, the same way the contact manifold
+    /// generators use them to select the support features. This discards the contacts between
+    /// features that rotated apart. Returns the number of removed contacts.
+    pub fn update_contacts(
+        &mut self,
+        m1: &Isometry<N>,
+        s1: &dyn Shape<N>,
+        m2: &Isometry<N>,
+        s2: &dyn Shape<N>,
+        prediction: &ContactPrediction<N>,
+    ) -> usize {
+        let persistence = self.persistence;
+        let mut nremoved = 0;
+        let mut deepest = None;
+
+        for (i, c) in self.contacts.iter_mut() {
+            if c.1 != persistence {
+                continue;
+            }
+
+            let kinematic = &c.0.kinematic;
+            let updated = c.0.updated_contact(m1, s1, m2, s2).filter(|contact| {
+                contact.depth >= -prediction.linear()
+                    && normal_is_valid(
+                        s1,
+                        kinematic.feature1(),
+                        m1,
+                        &contact.normal,
+                        prediction.angular1(),
+                    )
+                    && normal_is_valid(
+                        s2,
+                        kinematic.feature2(),
+                        m2,
+                        &-contact.normal,
+                        prediction.angular2(),
+                    )
+            });
+
+            match updated {
+                Some(contact) => {
+                    match deepest {
+                        Some((_, depth)) if depth >= contact.depth => {}
+                        _ => deepest = Some((i, contact.depth)),
+                    }
+
+                    c.0.contact = contact;
+                }
+                None => {
+                    // The contact is removed from the cache by the next `save_cache_and_clear`.
+                    c.1 = 0;
+                    nremoved += 1;
+                }
+            }
+        }
+
+        self.ncontacts -= nremoved;
+        self.deepest = deepest.map_or(0, |(i, _)| i);
+        nremoved
+    }
+
     // FIXME: the method taking a preprocessor should be different?
     /// Add a new contact to the manifold.
     ///
@@ -275,3 +342,32 @@ impl<N: RealField + Copy> ContactManifold<N> {
         }
     }
 }
+
+// Checks that `feature` of `shape` transformed by `m` is still a support feature toward `normal`,
+// up to the angular tolerance `angle` used by the contact manifold generators.
+fn normal_is_valid<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    feature: FeatureId,
+    m: &Isometry<N>,
+    normal: &Unit<Vector<N>>,
+    angle: N,
+) -> bool {
+    let poly = match shape.as_convex_polyhedron() {
+        Some(poly) if feature != FeatureId::Unknown => poly,
+        _ => return true,
+    };
+
+    if poly.normal_cone_contains_dir(feature, m, normal) {
+        return true;
+    }
+
+    let mut support = ConvexPolygonalFeature::new();
+    poly.support_feature_toward(m, normal, angle, &mut support);
+
+    #[cfg(feature = "dim2")]
+    let on_support = support.vertices_id[..support.nvertices].contains(&feature);
+    #[cfg(feature = "dim3")]
+    let on_support = support.vertices_id.contains(&feature) || support.edges_id.contains(&feature);
+
+    support.feature_id == feature || on_support
+}
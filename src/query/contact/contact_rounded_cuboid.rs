@@ -0,0 +1,46 @@
+use crate::math::Isometry;
+use crate::query::contact::contact_capsule::{dilate_contact1, dilate_contact2};
+use crate::query::{self, Contact};
+use crate::shape::{RoundedCuboid, Shape};
+use na::RealField;
+
+/// Contact between a rounded cuboid and any other shape.
+///
+/// The contact is computed between the inner cuboid of the rounded cuboid and the other shape,
+/// then dilated by the border radius.
+#[inline]
+pub fn contact_rounded_cuboid_shape<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    cuboid1: &RoundedCuboid<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact(
+        m1,
+        &cuboid1.inner_cuboid(),
+        m2,
+        g2,
+        prediction + cuboid1.border_radius,
+    )
+    .map(|c| dilate_contact1(c, cuboid1.border_radius))
+}
+
+/// Contact between any shape and a rounded cuboid.
+#[inline]
+pub fn contact_shape_rounded_cuboid<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    cuboid2: &RoundedCuboid<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    query::contact(
+        m1,
+        g1,
+        m2,
+        &cuboid2.inner_cuboid(),
+        prediction + cuboid2.border_radius,
+    )
+    .map(|c| dilate_contact2(c, cuboid2.border_radius))
+}
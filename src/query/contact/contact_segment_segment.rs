@@ -0,0 +1,78 @@
+use crate::math::{Isometry, Vector};
+use crate::query::{self, Contact};
+use crate::shape::Segment;
+use na::{self, RealField, Unit};
+
+/// Contact between two segments.
+///
+/// This uses the closed-form segment-segment closest points computation instead of GJK. Because
+/// segments have no thickness, the resulting penetration depth is never positive: intersecting
+/// segments yield a contact with a zero depth and a normal orthogonal to the first segment.
+#[inline]
+pub fn contact_segment_segment<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    seg1: &Segment<N>,
+    m2: &Isometry<N>,
+    seg2: &Segment<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    let seg1 = seg1.transformed(m1);
+    let seg2 = seg2.transformed(m2);
+    let (loc1, loc2) = query::closest_points_segment_segment_with_locations_nD(
+        (&seg1.a, &seg1.b),
+        (&seg2.a, &seg2.b),
+    );
+    let world1 = seg1.point_at(&loc1);
+    let world2 = seg2.point_at(&loc2);
+
+    let (normal, dist) = match Unit::try_new_and_get(world2 - world1, N::default_epsilon()) {
+        Some(res) => res,
+        None => (intersecting_segments_normal(&seg1, &seg2), N::zero()),
+    };
+
+    if dist <= prediction {
+        Some(Contact::new(world1, world2, normal, -dist))
+    } else {
+        None
+    }
+}
+
+/// A normal for two segments touching each other, pointing from `seg1` toward the center of `seg2`.
+fn intersecting_segments_normal<N: RealField + Copy>(
+    seg1: &Segment<N>,
+    seg2: &Segment<N>,
+) -> Unit<Vector<N>> {
+    let dir1 = match seg1.direction().or_else(|| seg2.direction()) {
+        Some(dir) => dir,
+        None => return Vector::y_axis(),
+    };
+
+    #[cfg(feature = "dim2")]
+    let normal = Vector::new(-dir1.y, dir1.x);
+
+    #[cfg(feature = "dim3")]
+    let normal = {
+        let cross = seg2
+            .direction()
+            .and_then(|dir2| Unit::try_new(dir1.cross(&dir2), N::default_epsilon()));
+
+        match cross {
+            Some(cross) => cross.into_inner(),
+            None => {
+                // The segments are parallel: choose any direction orthogonal to them.
+                let iamin = dir1.iamin();
+                let mut normal = Vector::zeros();
+                normal[iamin] = N::one();
+                normal - *dir1 * dir1[iamin]
+            }
+        }
+    };
+
+    let dcenters = na::center(&seg2.a, &seg2.b) - na::center(&seg1.a, &seg1.b);
+
+    if normal.dot(&dcenters) < N::zero() {
+        -Unit::new_normalize(normal)
+    } else {
+        Unit::new_normalize(normal)
+    }
+}
@@ -1,12 +1,14 @@
 use na::RealField;
 
 use crate::math::{Isometry, Point};
-use crate::query::{self, Contact};
-use crate::shape::{Ball, Plane, Shape};
+use crate::query::{self, Contact, QueryBuffers};
+use crate::shape::{Ball, Capsule, Cuboid, Plane, RoundedCuboid, Segment, Shape};
 
 /// Computes one contact point between two shapes.
 ///
-/// Returns `None` if the objects are separated by a distance greater than `prediction`.
+/// Returns `None` if the objects are separated by a distance greater than `prediction`. Only
+/// the built-in shapes are supported: use `pipeline::RegistryContactDispatcher::contact` for
+/// user-defined shapes.
 pub fn contact<N: RealField + Copy>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
@@ -22,6 +24,23 @@ pub fn contact<N: RealField + Copy>(
         let p2 = Point::from(m2.translation.vector);
 
         query::contact_ball_ball(&p1, b1, &p2, b2, prediction)
+    } else if let (Some(b1), Some(c2)) = (ball1, g2.as_shape::<Cuboid<N>>()) {
+        let p1 = Point::from(m1.translation.vector);
+        query::contact_ball_cuboid(&p1, b1, m2, c2, prediction)
+    } else if let (Some(c1), Some(b2)) = (g1.as_shape::<Cuboid<N>>(), ball2) {
+        let p2 = Point::from(m2.translation.vector);
+        query::contact_cuboid_ball(m1, c1, &p2, b2, prediction)
+    } else if let (Some(c1), Some(c2)) = (g1.as_shape::<Capsule<N>>(), g2.as_shape::<Capsule<N>>())
+    {
+        query::contact_capsule_capsule(m1, c1, m2, c2, prediction)
+    } else if let Some(c1) = g1.as_shape::<Capsule<N>>() {
+        query::contact_capsule_shape(m1, c1, m2, g2, prediction)
+    } else if let Some(c2) = g2.as_shape::<Capsule<N>>() {
+        query::contact_shape_capsule(m1, g1, m2, c2, prediction)
+    } else if let Some(c1) = g1.as_shape::<RoundedCuboid<N>>() {
+        query::contact_rounded_cuboid_shape(m1, c1, m2, g2, prediction)
+    } else if let Some(c2) = g2.as_shape::<RoundedCuboid<N>>() {
+        query::contact_shape_rounded_cuboid(m1, g1, m2, c2, prediction)
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<N>>(), g2.as_support_map()) {
         query::contact_plane_support_map(m1, p1, m2, s2, prediction)
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<N>>()) {
@@ -36,8 +55,13 @@ pub fn contact<N: RealField + Copy>(
     {
         let p2 = Point::from(m2.translation.vector);
         query::contact_convex_polyhedron_ball(m1, g1, &p2, b2, prediction)
+    } else if let (Some(s1), Some(s2)) = (g1.as_shape::<Segment<N>>(), g2.as_shape::<Segment<N>>())
+    {
+        query::contact_segment_segment(m1, s1, m2, s2, prediction)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         query::contact_support_map_support_map(m1, s1, m2, s2, prediction)
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        query::contact_composite_shape_composite_shape(m1, c1, m2, c2, prediction)
     } else if let Some(c1) = g1.as_composite_shape() {
         query::contact_composite_shape_shape(m1, c1, m2, g2, prediction)
     } else if let Some(c2) = g2.as_composite_shape() {
@@ -46,3 +70,70 @@ pub fn contact<N: RealField + Copy>(
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes one contact point between two shapes, expressed in the local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`, e.g. `m1.inverse() * m2`. The returned
+/// contact points and normal are expressed in the local space of `g1`.
+pub fn contact_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>> {
+    if is_capsule_or_rounded_cuboid(g1) || is_capsule_or_rounded_cuboid(g2) {
+        // Those are handled before composite shapes by `contact`.
+        contact(&Isometry::identity(), g1, pos12, g2, prediction)
+    } else if let (Some(_), Some(_)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        contact(&Isometry::identity(), g1, pos12, g2, prediction)
+    } else if let Some(c1) = g1.as_composite_shape() {
+        query::contact_composite_shape_shape_local(pos12, c1, g2, prediction)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::contact_shape_composite_shape_local(pos12, g1, c2, prediction)
+    } else {
+        contact(&Isometry::identity(), g1, pos12, g2, prediction)
+    }
+}
+
+/// Computes one contact point between two shapes, reusing the scratch memory of `buffers`.
+///
+/// This is equivalent to `contact` but does not allocate any temporary buffer when the
+/// shapes are support-mapped or when only one of them is a composite shape.
+pub fn contact_with_buffers<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+    buffers: &mut QueryBuffers<N>,
+) -> Option<Contact<N>> {
+    if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        if g1.is_shape::<Ball<N>>()
+            || g2.is_shape::<Ball<N>>()
+            || (is_segment_or_capsule(g1) && is_segment_or_capsule(g2))
+        {
+            // Those have closed-form solutions that do not need any buffer.
+            contact(m1, g1, m2, g2, prediction)
+        } else {
+            query::contact_support_map_support_map_with_buffers(m1, s1, m2, s2, prediction, buffers)
+        }
+    } else if g1.is_shape::<Plane<N>>() || g2.is_shape::<Plane<N>>() {
+        contact(m1, g1, m2, g2, prediction)
+    } else if let (Some(_), Some(_)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        contact(m1, g1, m2, g2, prediction)
+    } else if let Some(c1) = g1.as_composite_shape() {
+        query::contact_composite_shape_shape_with_buffers(m1, c1, m2, g2, prediction, buffers)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::contact_shape_composite_shape_with_buffers(m1, g1, m2, c2, prediction, buffers)
+    } else {
+        contact(m1, g1, m2, g2, prediction)
+    }
+}
+
+fn is_segment_or_capsule<N: RealField + Copy>(g: &dyn Shape<N>) -> bool {
+    g.is_shape::<Segment<N>>() || g.is_shape::<Capsule<N>>()
+}
+
+fn is_capsule_or_rounded_cuboid<N: RealField + Copy>(g: &dyn Shape<N>) -> bool {
+    g.is_shape::<Capsule<N>>() || g.is_shape::<RoundedCuboid<N>>()
+}
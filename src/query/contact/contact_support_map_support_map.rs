@@ -1,7 +1,7 @@
 use crate::math::{Isometry, Vector};
-use crate::query::algorithms::{gjk, gjk::GJKResult, CSOPoint};
-use crate::query::algorithms::{VoronoiSimplex, EPA};
-use crate::query::Contact;
+use crate::query::algorithms::gjk::{self, GJKOptions, GJKResult};
+use crate::query::algorithms::{CSOPoint, DebugTraceSink, EPAOptions, VoronoiSimplex, EPA};
+use crate::query::{Contact, QueryBuffers};
 use crate::shape::SupportMap;
 use na::{RealField, Unit};
 
@@ -29,6 +29,42 @@ where
     }
 }
 
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.), reusing the scratch
+/// memory of `buffers` for GJK and EPA.
+pub fn contact_support_map_support_map_with_buffers<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+    buffers: &mut QueryBuffers<N>,
+) -> Option<Contact<N>>
+where
+    N: RealField + Copy,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let QueryBuffers { simplex, epa, .. } = buffers;
+    reset_simplex(m1, g1, m2, g2, simplex, None);
+
+    match do_contact_support_map_support_map(
+        m1,
+        g1,
+        m2,
+        g2,
+        simplex,
+        epa,
+        GJKOptions::new(prediction, true, &mut ()),
+    ) {
+        GJKResult::ClosestPoints(world1, world2, normal) => {
+            Some(Contact::new_wo_depth(world1, world2, normal))
+        }
+        GJKResult::NoIntersection(_) => None,
+        GJKResult::Intersection => unreachable!(),
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
+
 /// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
 ///
 /// This allows a more fine grained control other the underlying GJK algorigtm.
@@ -48,6 +84,66 @@ where
     N: RealField + Copy,
     G1: SupportMap<N>,
     G2: SupportMap<N>,
+{
+    reset_simplex(m1, g1, m2, g2, simplex, init_dir);
+    do_contact_support_map_support_map(
+        m1,
+        g1,
+        m2,
+        g2,
+        simplex,
+        &mut EPA::new(),
+        GJKOptions::new(prediction, true, &mut ()),
+    )
+}
+
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.), reporting each
+/// iteration of the underlying GJK and EPA algorithms to `trace`.
+///
+/// This is meant to help diagnosing bad contacts. See `DebugTrace` for a sink recording all
+/// the iterations.
+pub fn contact_support_map_support_map_with_trace<N, G1: ?Sized, G2: ?Sized, T: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+    trace: &mut T,
+) -> Option<Contact<N>>
+where
+    N: RealField + Copy,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+    T: DebugTraceSink<N>,
+{
+    let simplex = &mut VoronoiSimplex::new();
+    let epa = &mut EPA::new();
+    reset_simplex(m1, g1, m2, g2, simplex, None);
+    let options = GJKOptions::new(prediction, true, trace);
+
+    match do_contact_support_map_support_map(m1, g1, m2, g2, simplex, epa, options) {
+        GJKResult::ClosestPoints(world1, world2, normal) => {
+            Some(Contact::new_wo_depth(world1, world2, normal))
+        }
+        GJKResult::NoIntersection(_) => None,
+        GJKResult::Intersection => unreachable!(),
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
+
+// Initializes `simplex` with the support point of the CSO of both shapes along `init_dir`, or
+// along the direction joining their centers if `init_dir` is `None`.
+fn reset_simplex<N, G1, G2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+    init_dir: Option<Unit<Vector<N>>>,
+) where
+    N: RealField + Copy,
+    G1: ?Sized + SupportMap<N>,
+    G2: ?Sized + SupportMap<N>,
 {
     let dir = if let Some(init_dir) = init_dir {
         init_dir
@@ -61,15 +157,38 @@ where
     };
 
     simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &dir));
+}
 
-    let cpts = gjk::closest_points(m1, g1, m2, g2, prediction, true, simplex);
+// The simplex must have been initialized with `reset_simplex`. The EPA fallback reports its
+// iterations to the same trace as the GJK.
+fn do_contact_support_map_support_map<N, G1: ?Sized, G2: ?Sized, T: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex<N>,
+    epa: &mut EPA<N>,
+    options: GJKOptions<N, T>,
+) -> GJKResult<N>
+where
+    N: RealField + Copy,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+    T: DebugTraceSink<N>,
+{
+    let trace = options.trace;
+    let gjk_options = GJKOptions {
+        trace: &mut *trace,
+        ..options
+    };
+    let cpts = gjk::closest_points_with_options(m1, g1, m2, g2, simplex, gjk_options);
     if cpts != GJKResult::Intersection {
         return cpts;
     }
 
     // The point is inside of the CSO: use the fallback algorithm
-    let mut epa = EPA::new();
-    if let Some((p1, p2, n)) = epa.closest_points(m1, g1, m2, g2, simplex) {
+    let options = EPAOptions::new(trace);
+    if let Some((p1, p2, n)) = epa.closest_points_with_options(m1, g1, m2, g2, simplex, options) {
         // FIXME: the n here,
         return GJKResult::ClosestPoints(p1, p2, n);
     }
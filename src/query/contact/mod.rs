@@ -11,21 +11,39 @@ pub use self::contact_ball_ball::contact_ball_ball;
 pub use self::contact_ball_convex_polyhedron::{
     contact_ball_convex_polyhedron, contact_convex_polyhedron_ball,
 };
+pub use self::contact_ball_cuboid::{contact_ball_cuboid, contact_cuboid_ball};
+pub use self::contact_capsule::{
+    contact_capsule_capsule, contact_capsule_segment, contact_capsule_shape,
+    contact_segment_capsule, contact_shape_capsule,
+};
 pub use self::contact_composite_shape_shape::{
-    contact_composite_shape_shape, contact_shape_composite_shape,
+    contact_composite_shape_composite_shape, contact_composite_shape_shape,
+    contact_composite_shape_shape_local, contact_composite_shape_shape_with_buffers,
+    contact_shape_composite_shape, contact_shape_composite_shape_local,
+    contact_shape_composite_shape_with_buffers,
 };
 pub use self::contact_plane_support_map::{contact_plane_support_map, contact_support_map_plane};
-pub use self::contact_shape_shape::contact;
+pub use self::contact_rounded_cuboid::{
+    contact_rounded_cuboid_shape, contact_shape_rounded_cuboid,
+};
+pub use self::contact_segment_segment::contact_segment_segment;
+pub use self::contact_shape_shape::{contact, contact_local, contact_with_buffers};
 pub use self::contact_support_map_support_map::contact_support_map_support_map;
+pub use self::contact_support_map_support_map::contact_support_map_support_map_with_buffers;
 pub use self::contact_support_map_support_map::contact_support_map_support_map_with_params;
+pub use self::contact_support_map_support_map::contact_support_map_support_map_with_trace;
 
 mod contact;
 mod contact_ball_ball;
 mod contact_ball_convex_polyhedron;
+mod contact_ball_cuboid;
+mod contact_capsule;
 mod contact_composite_shape_shape;
 mod contact_kinematic;
 mod contact_manifold;
 mod contact_plane_support_map;
 mod contact_preprocessor;
+mod contact_rounded_cuboid;
+mod contact_segment_segment;
 mod contact_shape_shape;
 mod contact_support_map_support_map;
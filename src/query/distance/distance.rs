@@ -31,3 +31,20 @@ pub fn distance<N: RealField + Copy>(
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes the minimum distance separating two shapes, in the local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`, e.g. `m1.inverse() * m2`.
+pub fn distance_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+) -> N {
+    if let Some(c1) = g1.as_composite_shape() {
+        query::distance_composite_shape_shape_local(pos12, c1, g2)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::distance_shape_composite_shape_local(pos12, g1, c2)
+    } else {
+        distance(&Isometry::identity(), g1, pos12, g2)
+    }
+}
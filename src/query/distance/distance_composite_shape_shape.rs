@@ -1,7 +1,7 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point, Vector};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{self, PointQuery};
+use crate::query::{self, statistics, PointQuery};
 use crate::shape::{CompositeShape, Shape};
 use na::{self, RealField};
 
@@ -16,16 +16,44 @@ where
     N: RealField + Copy,
     G1: CompositeShape<N>,
 {
-    let ls_m2 = m1.inverse() * m2.clone();
-    let ls_aabb2 = g2.aabb(&ls_m2);
+    distance_composite_shape_shape_local(&(m1.inverse() * m2), g1, g2)
+}
+
+/// Smallest distance between a shape and a composite shape.
+pub fn distance_shape_composite_shape<N, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> N
+where
+    N: RealField + Copy,
+    G2: CompositeShape<N>,
+{
+    distance_composite_shape_shape(m2, g2, m1, g1)
+}
+
+/// Smallest distance between a composite shape and any other shape, computed in the local space
+/// of the composite shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn distance_composite_shape_shape_local<N, G1>(
+    pos12: &Isometry<N>,
+    g1: &G1,
+    g2: &dyn Shape<N>,
+) -> N
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+{
+    let ls_aabb2 = g2.aabb(pos12);
 
     let mut visitor = CompositeShapeAgainstAnyDistanceVisitor {
         msum_shift: -ls_aabb2.center().coords,
         msum_margin: ls_aabb2.half_extents(),
-        m1: m1,
-        g1: g1,
-        m2: m2,
-        g2: g2,
+        g1,
+        pos12,
+        g2,
     };
 
     g1.bvh()
@@ -34,27 +62,28 @@ where
         .1
 }
 
-/// Smallest distance between a shape and a composite shape.
-pub fn distance_shape_composite_shape<N, G2: ?Sized>(
-    m1: &Isometry<N>,
+/// Smallest distance between a shape and a composite shape, computed in the local space of the
+/// first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn distance_shape_composite_shape_local<N, G2>(
+    pos12: &Isometry<N>,
     g1: &dyn Shape<N>,
-    m2: &Isometry<N>,
     g2: &G2,
 ) -> N
 where
     N: RealField + Copy,
-    G2: CompositeShape<N>,
+    G2: ?Sized + CompositeShape<N>,
 {
-    distance_composite_shape_shape(m2, g2, m1, g1)
+    distance_composite_shape_shape_local(&pos12.inverse(), g2, g1)
 }
 
 struct CompositeShapeAgainstAnyDistanceVisitor<'a, N: 'a + RealField + Copy, G1: ?Sized + 'a> {
     msum_shift: Vector<N>,
     msum_margin: Vector<N>,
 
-    m1: &'a Isometry<N>,
     g1: &'a G1,
-    m2: &'a Isometry<N>,
+    pos12: &'a Isometry<N>,
     g2: &'a dyn Shape<N>,
 }
 
@@ -87,13 +116,15 @@ where
 
         if let Some(b) = data {
             if dist < best {
-                self.g1.map_part_at(*b, self.m1, &mut |m1, g1| {
-                    let distance = query::distance(m1, g1, self.m2, self.g2);
-                    res = BestFirstVisitStatus::Continue {
-                        cost: distance,
-                        result: Some(distance),
-                    }
-                });
+                statistics::count_primitive_test();
+                self.g1
+                    .map_part_at(*b, &Isometry::identity(), &mut |m1, g1| {
+                        let distance = query::distance(m1, g1, self.pos12, self.g2);
+                        res = BestFirstVisitStatus::Continue {
+                            cost: distance,
+                            result: Some(distance),
+                        }
+                    });
             }
         }
 
@@ -1,9 +1,10 @@
 //! Implementation details of the `distance` function.
 
-pub use self::distance::distance;
+pub use self::distance::{distance, distance_local};
 pub use self::distance_ball_ball::distance_ball_ball;
 pub use self::distance_composite_shape_shape::{
-    distance_composite_shape_shape, distance_shape_composite_shape,
+    distance_composite_shape_shape, distance_composite_shape_shape_local,
+    distance_shape_composite_shape, distance_shape_composite_shape_local,
 };
 pub use self::distance_plane_support_map::{
     distance_plane_support_map, distance_support_map_plane,
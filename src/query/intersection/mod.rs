@@ -0,0 +1,7 @@
+//! Computation of the geometric intersection of two shapes.
+
+pub use self::triangle_triangle_intersection::{
+    triangle_triangle_intersection, TriangleTriangleIntersection,
+};
+
+mod triangle_triangle_intersection;
@@ -0,0 +1,240 @@
+use crate::math::{Point, Vector};
+use crate::shape::Triangle;
+use na::{self, RealField};
+
+/// The geometric intersection of two triangles.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TriangleTriangleIntersection<N: RealField + Copy> {
+    /// The triangles do not intersect.
+    Disjoint,
+    /// The triangles touch at a single point.
+    Touching(Point<N>),
+    /// The triangles intersect along a segment, given by its two endpoints.
+    ///
+    /// This is also the result for coplanar triangles sharing only part of an edge.
+    Segment(Point<N>, Point<N>),
+    /// The triangles are coplanar and their intersection is a convex polygon with a non-zero area.
+    ///
+    /// The vertices of this polygon are given in counter-clockwise order with regard to the
+    /// normal of the first triangle.
+    Coplanar(Vec<Point<N>>),
+}
+
+/// Computes the intersection of two triangles expressed in the same coordinate frame.
+///
+/// Vertices closer than a small tolerance from the plane of the other triangle are considered
+/// to lie on this plane. Degenerate triangles, i.e., with a zero area, are considered not to
+/// intersect anything.
+pub fn triangle_triangle_intersection<N: RealField + Copy>(
+    t1: &Triangle<N>,
+    t2: &Triangle<N>,
+) -> TriangleTriangleIntersection<N> {
+    let pts1 = [t1.a, t1.b, t1.c];
+    let pts2 = [t2.a, t2.b, t2.c];
+
+    let scale = pts1
+        .iter()
+        .chain(pts2.iter())
+        .fold(N::one(), |acc, pt| acc.max(pt.coords.amax()));
+    let eps = N::default_epsilon().sqrt() * scale;
+
+    let n1 = match t1.normal() {
+        Some(n) => n,
+        None => return TriangleTriangleIntersection::Disjoint,
+    };
+    let n2 = match t2.normal() {
+        Some(n) => n,
+        None => return TriangleTriangleIntersection::Disjoint,
+    };
+
+    let dists1 = signed_distances(&pts1, &pts2[0], &n2, eps);
+    if dists1.iter().all(|d| *d > N::zero()) || dists1.iter().all(|d| *d < N::zero()) {
+        return TriangleTriangleIntersection::Disjoint;
+    }
+
+    if dists1.iter().all(|d| d.is_zero()) {
+        return coplanar_intersection(&pts1, &pts2, &n1, &n2, eps);
+    }
+
+    let dists2 = signed_distances(&pts2, &pts1[0], &n1, eps);
+    if dists2.iter().all(|d| *d > N::zero()) || dists2.iter().all(|d| *d < N::zero()) {
+        return TriangleTriangleIntersection::Disjoint;
+    }
+
+    // Both triangles cross the plane of the other: their intersections with these planes
+    // are two segments lying on the intersection line of both planes.
+    let dir = n1.cross(&n2);
+    let (start1, end1) = plane_crossing(&pts1, &dists1, &dir);
+    let (start2, end2) = plane_crossing(&pts2, &dists2, &dir);
+
+    let start = if start1.0 > start2.0 { start1 } else { start2 };
+    let end = if end1.0 < end2.0 { end1 } else { end2 };
+
+    if na::distance(&start.1, &end.1) <= eps {
+        if start.0 <= end.0 + eps {
+            TriangleTriangleIntersection::Touching(na::center(&start.1, &end.1))
+        } else {
+            TriangleTriangleIntersection::Disjoint
+        }
+    } else if start.0 < end.0 {
+        TriangleTriangleIntersection::Segment(start.1, end.1)
+    } else {
+        TriangleTriangleIntersection::Disjoint
+    }
+}
+
+fn signed_distances<N: RealField + Copy>(
+    pts: &[Point<N>; 3],
+    origin: &Point<N>,
+    normal: &Vector<N>,
+    eps: N,
+) -> [N; 3] {
+    let mut res = [N::zero(); 3];
+
+    for i in 0..3 {
+        let d = normal.dot(&(pts[i] - origin));
+        res[i] = if d.abs() <= eps { N::zero() } else { d };
+    }
+
+    res
+}
+
+// The two endpoints of the intersection between a triangle and a plane, sorted by increasing
+// parameter along `dir`. Each endpoint is given with its parameter along `dir`.
+fn plane_crossing<N: RealField + Copy>(
+    pts: &[Point<N>; 3],
+    dists: &[N; 3],
+    dir: &Vector<N>,
+) -> ((N, Point<N>), (N, Point<N>)) {
+    let mut start = (N::max_value().unwrap(), Point::origin());
+    let mut end = (-N::max_value().unwrap(), Point::origin());
+    let mut push = |pt: Point<N>| {
+        let t = dir.dot(&pt.coords);
+
+        if t < start.0 {
+            start = (t, pt);
+        }
+
+        if t > end.0 {
+            end = (t, pt);
+        }
+    };
+
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+
+        if dists[i].is_zero() {
+            push(pts[i]);
+        }
+
+        if (dists[i] > N::zero() && dists[j] < N::zero())
+            || (dists[i] < N::zero() && dists[j] > N::zero())
+        {
+            let t = dists[i] / (dists[i] - dists[j]);
+            push(pts[i] + (pts[j] - pts[i]) * t);
+        }
+    }
+
+    (start, end)
+}
+
+fn coplanar_intersection<N: RealField + Copy>(
+    pts1: &[Point<N>; 3],
+    pts2: &[Point<N>; 3],
+    n1: &Vector<N>,
+    n2: &Vector<N>,
+    eps: N,
+) -> TriangleTriangleIntersection<N> {
+    // Clip the first triangle by the three edges of the second one (Sutherland-Hodgman).
+    let mut poly = pts1.to_vec();
+    let mut clipped = Vec::with_capacity(6);
+
+    for i in 0..3 {
+        let a = pts2[i];
+        let b = pts2[(i + 1) % 3];
+        // Normal of the edge pointing toward the interior of the second triangle.
+        let inward = n2.cross(&(b - a)).normalize();
+        let dist = |pt: &Point<N>| {
+            let d = inward.dot(&(pt - a));
+            if d.abs() <= eps {
+                N::zero()
+            } else {
+                d
+            }
+        };
+
+        clipped.clear();
+
+        for k in 0..poly.len() {
+            let p = poly[k];
+            let q = poly[(k + 1) % poly.len()];
+            let dp = dist(&p);
+            let dq = dist(&q);
+
+            if dp >= N::zero() {
+                clipped.push(p);
+            }
+
+            if (dp > N::zero() && dq < N::zero()) || (dp < N::zero() && dq > N::zero()) {
+                let t = dp / (dp - dq);
+                clipped.push(p + (q - p) * t);
+            }
+        }
+
+        std::mem::swap(&mut poly, &mut clipped);
+
+        if poly.is_empty() {
+            return TriangleTriangleIntersection::Disjoint;
+        }
+    }
+
+    // Remove duplicate consecutive vertices.
+    let mut res: Vec<Point<N>> = Vec::with_capacity(poly.len());
+    for pt in poly {
+        if !res.last().is_some_and(|last| na::distance(last, &pt) <= eps) {
+            res.push(pt);
+        }
+    }
+
+    while res.len() > 1 && na::distance(&res[0], res.last().unwrap()) <= eps {
+        let _ = res.pop();
+    }
+
+    match res.len() {
+        0 => TriangleTriangleIntersection::Disjoint,
+        1 => TriangleTriangleIntersection::Touching(res[0]),
+        2 => TriangleTriangleIntersection::Segment(res[0], res[1]),
+        _ => {
+            // The polygon may still be flat if the triangles share only an edge.
+            let mut area = Vector::zeros();
+            for k in 1..res.len() - 1 {
+                area += (res[k] - res[0]).cross(&(res[k + 1] - res[0]));
+            }
+
+            if area.dot(n1).abs() <= eps * eps {
+                let (start, end) = farthest_pair(&res);
+                TriangleTriangleIntersection::Segment(start, end)
+            } else {
+                TriangleTriangleIntersection::Coplanar(res)
+            }
+        }
+    }
+}
+
+fn farthest_pair<N: RealField + Copy>(pts: &[Point<N>]) -> (Point<N>, Point<N>) {
+    let mut best = (pts[0], pts[0]);
+    let mut best_dist = -N::one();
+
+    for i in 0..pts.len() {
+        for j in i + 1..pts.len() {
+            let dist = na::distance_squared(&pts[i], &pts[j]);
+
+            if dist > best_dist {
+                best_dist = dist;
+                best = (pts[i], pts[j]);
+            }
+        }
+    }
+
+    best
+}
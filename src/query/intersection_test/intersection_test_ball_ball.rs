@@ -0,0 +1,15 @@
+use crate::math::Point;
+use crate::shape::Ball;
+use na::RealField;
+
+/// Intersection test between balls.
+#[inline]
+pub fn intersection_test_ball_ball<N: RealField + Copy>(
+    center1: &Point<N>,
+    b1: &Ball<N>,
+    center2: &Point<N>,
+    b2: &Ball<N>,
+) -> bool {
+    let sum_radius = b1.radius + b2.radius;
+    na::distance_squared(center1, center2) <= sum_radius * sum_radius
+}
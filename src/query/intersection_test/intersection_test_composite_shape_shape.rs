@@ -0,0 +1,82 @@
+use crate::bounding_volume::{BoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::partitioning::{VisitStatus, Visitor};
+use crate::query::{self, statistics};
+use crate::shape::{CompositeShape, Shape};
+use na::RealField;
+
+/// Intersection test between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// The BVH of the composite shape is traversed until the first part intersecting `g2` is found.
+pub fn intersection_test_composite_shape_shape<N: RealField + Copy, G1>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> bool
+where
+    G1: ?Sized + CompositeShape<N>,
+{
+    let ls_m2 = m1.inverse() * m2;
+    let mut visitor = CompositeShapeAgainstAnyIntersectionVisitor {
+        ls_aabb2: g2.aabb(&ls_m2),
+        m1,
+        g1,
+        m2,
+        g2,
+        found: false,
+    };
+
+    g1.bvh().visit(&mut visitor);
+    visitor.found
+}
+
+/// Intersection test between a shape and a composite (`Mesh`, `Compound`) shape.
+pub fn intersection_test_shape_composite_shape<N: RealField + Copy, G2>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> bool
+where
+    G2: ?Sized + CompositeShape<N>,
+{
+    intersection_test_composite_shape_shape(m2, g2, m1, g1)
+}
+
+struct CompositeShapeAgainstAnyIntersectionVisitor<'a, N: 'a + RealField + Copy, G1: ?Sized + 'a> {
+    ls_aabb2: AABB<N>,
+
+    m1: &'a Isometry<N>,
+    g1: &'a G1,
+    m2: &'a Isometry<N>,
+    g2: &'a dyn Shape<N>,
+    found: bool,
+}
+
+impl<'a, N: RealField + Copy, G1: ?Sized> Visitor<usize, AABB<N>>
+    for CompositeShapeAgainstAnyIntersectionVisitor<'a, N, G1>
+where
+    G1: CompositeShape<N>,
+{
+    fn visit(&mut self, bv: &AABB<N>, data: Option<&usize>) -> VisitStatus {
+        if !bv.intersects(&self.ls_aabb2) {
+            return VisitStatus::Stop;
+        }
+
+        if let Some(b) = data {
+            statistics::count_primitive_test();
+            self.g1.map_part_at(*b, self.m1, &mut |m1, g1| {
+                if query::intersection_test(m1, g1, self.m2, self.g2) {
+                    self.found = true;
+                }
+            });
+        }
+
+        if self.found {
+            VisitStatus::ExitEarly
+        } else {
+            VisitStatus::Continue
+        }
+    }
+}
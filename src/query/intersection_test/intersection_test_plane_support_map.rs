@@ -0,0 +1,29 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point};
+use crate::shape::Plane;
+use crate::shape::SupportMap;
+
+/// Intersection test between a plane and a support-mapped shape (Cuboid, ConvexHull, etc.)
+pub fn intersection_test_plane_support_map<N: RealField + Copy, G: ?Sized + SupportMap<N>>(
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+    mother: &Isometry<N>,
+    other: &G,
+) -> bool {
+    let plane_normal = mplane * plane.normal;
+    let plane_center = Point::from(mplane.translation.vector);
+    let deepest = other.support_point_toward(mother, &-plane_normal);
+
+    plane_normal.dot(&(plane_center - deepest)) >= na::zero()
+}
+
+/// Intersection test between a support-mapped shape (Cuboid, ConvexHull, etc.) and a plane.
+pub fn intersection_test_support_map_plane<N: RealField + Copy, G: ?Sized + SupportMap<N>>(
+    mother: &Isometry<N>,
+    other: &G,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> bool {
+    intersection_test_plane_support_map(mplane, plane, mother, other)
+}
@@ -0,0 +1,48 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point};
+use crate::query;
+use crate::shape::{Ball, Plane, Shape};
+
+/// Tests whether two shapes are intersecting.
+///
+/// Unlike `proximity` or `contact`, this only computes a yes/no answer: support-mapped shapes use
+/// a boolean-only GJK without any penetration depth computation, and composite shapes stop
+/// traversing their BVH at the first intersecting part.
+pub fn intersection_test<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> bool {
+    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
+        let p1 = Point::from(m1.translation.vector);
+        let p2 = Point::from(m2.translation.vector);
+
+        query::intersection_test_ball_ball(&p1, b1, &p2, b2)
+    } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<N>>(), g2.as_support_map()) {
+        query::intersection_test_plane_support_map(m1, p1, m2, s2)
+    } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<N>>()) {
+        query::intersection_test_support_map_plane(m1, s1, m2, p2)
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        query::intersection_test_support_map_support_map(m1, s1, m2, s2)
+    } else if let Some(c1) = g1.as_composite_shape() {
+        query::intersection_test_composite_shape_shape(m1, c1, m2, g2)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::intersection_test_shape_composite_shape(m1, g1, m2, c2)
+    } else {
+        panic!("No algorithm known to test the intersection between the given pair of shapes.")
+    }
+}
+
+/// Tests whether two shapes expressed in the local space of the first one are intersecting.
+///
+/// This is equivalent to `intersection_test(&Isometry::identity(), g1, pos12, g2)` where `pos12`
+/// is the position of `g2` relative to `g1`.
+pub fn intersection_test_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+) -> bool {
+    intersection_test(&Isometry::identity(), g1, pos12, g2)
+}
@@ -0,0 +1,32 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Vector};
+use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
+use crate::shape::SupportMap;
+
+/// Intersection test between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This runs a boolean-only GJK, so it is cheaper than `proximity_support_map_support_map` or
+/// `contact_support_map_support_map` when only the intersection status is needed.
+pub fn intersection_test_support_map_support_map<N, G1, G2>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> bool
+where
+    N: RealField + Copy,
+    G1: ?Sized + SupportMap<N>,
+    G2: ?Sized + SupportMap<N>,
+{
+    let dir = Unit::try_new(
+        m2.translation.vector - m1.translation.vector,
+        N::default_epsilon(),
+    )
+    .unwrap_or_else(Vector::x_axis);
+
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &dir));
+
+    gjk::intersection_test(m1, g1, m2, g2, &mut simplex)
+}
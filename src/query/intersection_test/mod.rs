@@ -0,0 +1,17 @@
+//! Implementation details of the `intersection_test` function.
+
+pub use self::intersection_test_ball_ball::intersection_test_ball_ball;
+pub use self::intersection_test_composite_shape_shape::{
+    intersection_test_composite_shape_shape, intersection_test_shape_composite_shape,
+};
+pub use self::intersection_test_plane_support_map::{
+    intersection_test_plane_support_map, intersection_test_support_map_plane,
+};
+pub use self::intersection_test_shape_shape::{intersection_test, intersection_test_local};
+pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map;
+
+mod intersection_test_ball_ball;
+mod intersection_test_composite_shape_shape;
+mod intersection_test_plane_support_map;
+mod intersection_test_shape_shape;
+mod intersection_test_support_map_support_map;
@@ -7,9 +7,15 @@
 //! * [`query::distance()`] to compute the distance between two shapes.
 //! * [`query::contact()`] to compute one pair of contact points between two shapes, including penetrating contact.
 //! * [`query::proximity()`] to determine if two shapes are intersecting or not.
+//! * [`query::intersection_test()`] to determine if two shapes are intersecting, without computing any proximity margin or contact.
+//! * [`query::overlap_volume()`] to estimate the volume of the intersection of two shapes.
 //! * [`query::time_of_impact()`] to determine when two shapes undergoing translational motions hit for the first time.
 //! * [`query::nonlinear_time_of_impact()`] to determine when two shapes undergoing continuous rigid motions hit for the first time.
 //!
+//! Except for `nonlinear_time_of_impact`, each of those has a `_local` variant (e.g. [`query::contact_local()`])
+//! taking only the position of the second shape relative to the first one. The results are expressed in the local
+//! space of the first shape.
+//!
 //! Ray-casting and point-projection can be achieved by importing traits:
 //!
 //! * [`query::RayCast`] for ray-casting.
@@ -21,7 +27,7 @@
 //! They are less convenient to use than the most generic version but will be slightly faster due to the lack of dynamic dispatch.
 //! Generally, the specific functions have the form `[operation]_[shape1]_[shape2]()` where:
 //!
-//! * `[operation]` can be `closest_points`, `distance`, `contact`, `proximity` or `time_of_impact`.
+//! * `[operation]` can be `closest_points`, `distance`, `contact`, `proximity`, `intersection_test` or `time_of_impact`.
 //! * `[shape1]` is the type of the first shape passed to the function, e.g., `ball`, or `plane`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 //! * `[shape2]` is the type of the second shape passed to the function, e.g., `ball`, or `plane`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 
@@ -29,10 +35,18 @@ pub use self::closest_points::*;
 pub use self::contact::*;
 pub use self::distance::*;
 pub use self::error::*;
+#[cfg(feature = "dim3")]
+pub use self::intersection::*;
+pub use self::intersection_test::*;
 pub use self::nonlinear_time_of_impact::*;
+pub use self::overlap_volume::*;
 pub use self::point::*;
 pub use self::proximity::*;
+pub use self::query_buffers::QueryBuffers;
+pub use self::query_precision::QueryPrecision;
 pub use self::ray::*;
+#[cfg(feature = "query_statistics")]
+pub use self::statistics::QueryStatistics;
 pub use self::time_of_impact::*;
 pub use self::toi_dispatcher::*;
 
@@ -41,10 +55,17 @@ mod closest_points;
 mod contact;
 mod distance;
 mod error;
+#[cfg(feature = "dim3")]
+mod intersection;
+mod intersection_test;
 mod nonlinear_time_of_impact;
+mod overlap_volume;
 mod point;
 mod proximity;
-mod ray;
+mod query_buffers;
+mod query_precision;
+pub mod ray;
+pub(crate) mod statistics;
 mod time_of_impact;
 mod toi_dispatcher;
 pub mod visitors;
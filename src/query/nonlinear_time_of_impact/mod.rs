@@ -4,6 +4,8 @@ pub use self::nonlinear_time_of_impact_ball_ball::nonlinear_time_of_impact_ball_
 pub use self::nonlinear_time_of_impact_composite_shape_shape::{
     nonlinear_time_of_impact_composite_shape_shape, nonlinear_time_of_impact_shape_composite_shape,
 };
+#[cfg(feature = "dim2")]
+pub use self::nonlinear_time_of_impact_constant_velocity2::nonlinear_time_of_impact_constant_velocity;
 //pub use self::nonlinear_time_of_impact_plane_support_map::{nonlinear_time_of_impact_plane_support_map, nonlinear_time_of_impact_support_map_plane};
 pub use self::nonlinear_time_of_impact::nonlinear_time_of_impact;
 pub use self::nonlinear_time_of_impact_support_map_support_map::{
@@ -13,6 +15,8 @@ pub use self::nonlinear_time_of_impact_support_map_support_map::{
 
 mod nonlinear_time_of_impact_ball_ball;
 mod nonlinear_time_of_impact_composite_shape_shape;
+#[cfg(feature = "dim2")]
+mod nonlinear_time_of_impact_constant_velocity2;
 //mod nonlinear_time_of_impact_plane_support_map;
 mod nonlinear_time_of_impact;
 mod nonlinear_time_of_impact_support_map_support_map;
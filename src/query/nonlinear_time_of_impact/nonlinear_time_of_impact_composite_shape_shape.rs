@@ -2,7 +2,7 @@ use crate::bounding_volume::{BoundingSphere, AABB};
 use crate::interpolation::{RigidMotion, RigidMotionComposition};
 use crate::math::Isometry;
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{self, TOIDispatcher, TOI};
+use crate::query::{self, statistics, TOIDispatcher, TOI};
 use crate::shape::{Ball, CompositeShape, Shape};
 use na::{self, RealField};
 
@@ -135,6 +135,7 @@ where
 
             if let Some(b) = data {
                 if toi.toi < best {
+                    statistics::count_primitive_test();
                     self.g1
                         .map_part_at(*b, &Isometry::identity(), &mut |m1, g1| {
                             let motion1 = self.motion1.prepend_transformation(*m1);
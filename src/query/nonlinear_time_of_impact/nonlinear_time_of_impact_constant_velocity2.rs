@@ -0,0 +1,109 @@
+use na::{RealField, Unit};
+
+use crate::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
+use crate::math::{Point, Vector};
+use crate::query::{self, ClosestPoints, TOIStatus, TOI};
+use crate::shape::Shape;
+
+/// Time of impact of two 2D shapes moving with constant linear and angular velocities.
+///
+/// Each shape rotates around the `local_center` of its motion. The search starts at the latest
+/// of the starting times `t0` of both motions and ends at `max_toi`. This relies on conservative
+/// advancement: at each step, the shapes are moved forward in time by the largest amount ensuring
+/// they cannot have reached the target distance yet, so fast-spinning shapes never tunnel through
+/// each other.
+///
+/// Returns `None` if the shapes never reach the target distance before `max_toi`. The witness
+/// points and normals of the result are expressed in the local-space of each shape.
+pub fn nonlinear_time_of_impact_constant_velocity<N: RealField + Copy>(
+    motion1: &ConstantVelocityRigidMotion<N>,
+    g1: &dyn Shape<N>,
+    motion2: &ConstantVelocityRigidMotion<N>,
+    g2: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>> {
+    const MAX_ITERATIONS: usize = 1000;
+
+    let abs_tol: N = query::algorithms::gjk::eps_tol();
+    let rel_tol = abs_tol.sqrt();
+    let linvel12 = motion2.linvel - motion1.linvel;
+
+    // Upper bound of the speed of any point of the shapes due to their rotation.
+    let bs1 = g1.local_bounding_sphere();
+    let bs2 = g2.local_bounding_sphere();
+    let angular_bound = motion1.angvel.abs()
+        * ((bs1.center() - motion1.local_center).norm() + bs1.radius())
+        + motion2.angvel.abs() * ((bs2.center() - motion2.local_center).norm() + bs2.radius());
+
+    let start = motion1.t0.max(motion2.t0);
+    let mut result = TOI {
+        toi: start,
+        normal1: Vector::x_axis(),
+        normal2: Vector::x_axis(),
+        witness1: Point::origin(),
+        witness2: Point::origin(),
+        status: TOIStatus::OutOfIterations,
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let pos1 = motion1.position_at_time(result.toi);
+        let pos2 = motion2.position_at_time(result.toi);
+
+        let (p1, p2) = match query::closest_points(&pos1, g1, &pos2, g2, N::max_value().unwrap()) {
+            ClosestPoints::Intersecting => {
+                result.status = if result.toi == start {
+                    TOIStatus::Penetrating
+                } else {
+                    TOIStatus::Failed
+                };
+                return Some(result);
+            }
+            ClosestPoints::WithinMargin(p1, p2) => (p1, p2),
+            // Only happens if one of the shapes is an empty composite shape.
+            ClosestPoints::Disjoint => return None,
+        };
+
+        result.witness1 = pos1.inverse_transform_point(&p1);
+        result.witness2 = pos2.inverse_transform_point(&p2);
+
+        let (dir, dist) = match Unit::try_new_and_get(p2 - p1, N::default_epsilon()) {
+            Some(res) => res,
+            None => {
+                result.status = if result.toi == start {
+                    TOIStatus::Penetrating
+                } else {
+                    TOIStatus::Converged
+                };
+                return Some(result);
+            }
+        };
+
+        result.normal1 = pos1.inverse_transform_unit_vector(&dir);
+        result.normal2 = pos2.inverse_transform_unit_vector(&-dir);
+
+        if dist <= target_distance + rel_tol {
+            result.status = if result.toi == start && dist < target_distance {
+                TOIStatus::Penetrating
+            } else {
+                TOIStatus::Converged
+            };
+            return Some(result);
+        }
+
+        // Upper bound of the rate at which the distance between the shapes decreases.
+        let approach_speed = (-linvel12.dot(&dir)).max(N::zero()) + angular_bound;
+
+        if approach_speed <= N::default_epsilon() {
+            return None;
+        }
+
+        result.toi += (dist - target_distance) / approach_speed;
+
+        if result.toi > max_toi {
+            return None;
+        }
+    }
+
+    Some(result)
+}
@@ -0,0 +1,8 @@
+//! Implementation details of the `overlap_volume` function.
+
+pub use self::overlap_volume_shape_shape::{
+    overlap_volume, overlap_volume_local, overlap_volume_sampled,
+};
+
+mod overlap_volume_polytope_polytope;
+mod overlap_volume_shape_shape;
@@ -0,0 +1,178 @@
+use na::{self, RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector, DIM};
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+#[cfg(feature = "dim3")]
+use crate::shape::{ConvexHull, ConvexPolyhedron, FeatureId};
+use crate::shape::{Cuboid, Shape};
+use crate::volumetric::Volumetric;
+
+#[cfg(feature = "dim2")]
+type Hull<N> = ConvexPolygon<N>;
+#[cfg(feature = "dim3")]
+type Hull<N> = ConvexHull<N>;
+
+// A convex polytope expressed in world-space, described by both its edges and its half-spaces.
+pub(crate) struct Polytope<N: RealField + Copy> {
+    points: Vec<Point<N>>,
+    edges: Vec<(usize, usize)>,
+    // Each half-space `(n, d)` contains the points `x` such that `n.dot(x) <= d`.
+    half_spaces: Vec<(Unit<Vector<N>>, N)>,
+}
+
+impl<N: RealField + Copy> Polytope<N> {
+    // Returns `None` if `shape` is not a polytope.
+    pub fn from_shape(m: &Isometry<N>, shape: &dyn Shape<N>) -> Option<Self> {
+        if let Some(cuboid) = shape.as_shape::<Cuboid<N>>() {
+            Some(Self::from_cuboid(m, cuboid))
+        } else if let Some(hull) = shape.as_shape::<Hull<N>>() {
+            Some(Self::from_hull(m, hull))
+        } else {
+            None
+        }
+    }
+
+    fn from_cuboid(m: &Isometry<N>, cuboid: &Cuboid<N>) -> Self {
+        let half_extents = cuboid.half_extents;
+        let mut points = Vec::with_capacity(1 << DIM);
+        let mut edges = Vec::new();
+        let mut half_spaces = Vec::with_capacity(2 * DIM);
+
+        // The i-th bit of the index of a corner gives its position along the i-th axis.
+        for k in 0..1 << DIM {
+            let mut pt = Point::origin();
+
+            for i in 0..DIM {
+                if k & (1 << i) == 0 {
+                    pt[i] = -half_extents[i];
+                    edges.push((k, k | (1 << i)));
+                } else {
+                    pt[i] = half_extents[i];
+                }
+            }
+
+            points.push(m * pt);
+        }
+
+        for i in 0..DIM {
+            let axis = m * Vector::ith_axis(i);
+            let center = axis.dot(&m.translation.vector);
+            half_spaces.push((axis, center + half_extents[i]));
+            half_spaces.push((-axis, half_extents[i] - center));
+        }
+
+        Polytope {
+            points,
+            edges,
+            half_spaces,
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn from_hull(m: &Isometry<N>, hull: &ConvexPolygon<N>) -> Self {
+        let points: Vec<_> = hull.points().iter().map(|pt| m * pt).collect();
+        let edges = (0..points.len())
+            .map(|i| (i, (i + 1) % points.len()))
+            .collect();
+        let half_spaces = hull
+            .normals()
+            .iter()
+            .zip(points.iter())
+            .map(|(normal, pt)| {
+                let normal = m * normal;
+                (normal, normal.dot(&pt.coords))
+            })
+            .collect();
+
+        Polytope {
+            points,
+            edges,
+            half_spaces,
+        }
+    }
+
+    #[cfg(feature = "dim3")]
+    fn from_hull(m: &Isometry<N>, hull: &ConvexHull<N>) -> Self {
+        let points: Vec<_> = hull.points().iter().map(|pt| m * pt).collect();
+        let mut edges = Vec::new();
+        let mut half_spaces = Vec::with_capacity(hull.num_faces());
+
+        for i in 0..hull.num_faces() {
+            let vertices = hull.face_vertices(i);
+            let normal = m * hull.feature_normal(FeatureId::Face(i));
+            half_spaces.push((normal, normal.dot(&points[vertices[0]].coords)));
+
+            // Each edge is shared by two faces which list it in opposite orders.
+            for j in 0..vertices.len() {
+                let (a, b) = (vertices[j], vertices[(j + 1) % vertices.len()]);
+
+                if a < b {
+                    edges.push((a, b));
+                }
+            }
+        }
+
+        Polytope {
+            points,
+            edges,
+            half_spaces,
+        }
+    }
+
+    // Pushes to `out` the endpoints of the parts of the edges of `self` inside of `other`.
+    fn clip_edges(&self, other: &Self, out: &mut Vec<Point<N>>) {
+        for (a, b) in &self.edges {
+            let (a, b) = (self.points[*a], self.points[*b]);
+            let mut t0 = N::zero();
+            let mut t1 = N::one();
+
+            for (normal, offset) in &other.half_spaces {
+                let da = normal.dot(&a.coords) - *offset;
+                let db = normal.dot(&b.coords) - *offset;
+
+                if da > N::zero() && db > N::zero() {
+                    t1 = -N::one();
+                    break;
+                } else if da > N::zero() {
+                    t0 = t0.max(da / (da - db));
+                } else if db > N::zero() {
+                    t1 = t1.min(da / (da - db));
+                }
+            }
+
+            if t0 <= t1 {
+                out.push(a + (b - a) * t0);
+                out.push(a + (b - a) * t1);
+            }
+        }
+    }
+}
+
+// The vertices of the intersection of two convex polytopes are the points where the edges of
+// each polytope enter or leave the other, so the intersection is the convex hull of the clipped
+// edges.
+pub(crate) fn overlap_volume_polytope_polytope<N: RealField + Copy>(
+    p1: &Polytope<N>,
+    p2: &Polytope<N>,
+) -> N {
+    let mut points = Vec::new();
+    p1.clip_edges(p2, &mut points);
+    p2.clip_edges(p1, &mut points);
+
+    // Remove the duplicate endpoints of adjacent edges, which may confuse the hull computation.
+    let eps = N::default_epsilon().sqrt();
+    let mut unique: Vec<Point<N>> = Vec::with_capacity(points.len());
+
+    for pt in points {
+        if unique.iter().all(|other| na::distance(other, &pt) > eps) {
+            unique.push(pt);
+        }
+    }
+
+    if unique.len() < DIM + 1 {
+        return N::zero();
+    }
+
+    Hull::try_from_points(&unique).map_or(N::zero(), |hull| hull.volume())
+}
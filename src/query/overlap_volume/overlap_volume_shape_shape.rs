@@ -0,0 +1,104 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, DIM};
+use crate::query::overlap_volume::overlap_volume_polytope_polytope::{
+    overlap_volume_polytope_polytope, Polytope,
+};
+use crate::query::PointQuery;
+use crate::shape::Shape;
+
+/// Estimates the volume of the intersection of two shapes.
+///
+/// In 2D, this is the area of the intersection. The result is exact for pairs of cuboids and
+/// convex polygons (or convex hulls in 3D), computed by clipping one polytope with the other.
+/// For any other pair, it is estimated by sampling `resolution` points along each axis of the
+/// intersection of the shapes' AABBs, see `overlap_volume_sampled`.
+pub fn overlap_volume<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    resolution: usize,
+) -> N {
+    if let (Some(p1), Some(p2)) = (Polytope::from_shape(m1, g1), Polytope::from_shape(m2, g2)) {
+        overlap_volume_polytope_polytope(&p1, &p2)
+    } else {
+        overlap_volume_sampled(m1, g1, m2, g2, resolution)
+    }
+}
+
+/// Estimates the volume of the intersection of two shapes expressed in the local space of the
+/// first one.
+///
+/// This is equivalent to `overlap_volume(&Isometry::identity(), g1, pos12, g2, resolution)` where
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn overlap_volume_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+    resolution: usize,
+) -> N {
+    overlap_volume(&Isometry::identity(), g1, pos12, g2, resolution)
+}
+
+/// Estimates the volume of the intersection of two shapes by sampling.
+///
+/// The intersection of the AABBs of both shapes is divided into `resolution` cells along each
+/// axis, and every cell whose center is contained by both shapes contributes its volume to the
+/// result. The error thus decreases linearly with `resolution` while the cost grows with its
+/// `DIM`-th power. At least one of the shapes must be bounded.
+pub fn overlap_volume_sampled<N: RealField + Copy>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    resolution: usize,
+) -> N {
+    assert!(
+        resolution > 0,
+        "The sampling resolution must be strictly positive."
+    );
+
+    let aabb1 = g1.aabb(m1);
+    let aabb2 = g2.aabb(m2);
+    let mins = aabb1.mins.sup(&aabb2.mins);
+    let maxs = aabb1.maxs.inf(&aabb2.maxs);
+
+    if (0..DIM).any(|i| mins[i] >= maxs[i]) {
+        return N::zero();
+    }
+
+    let nsamples: N = na::convert(resolution as f64);
+    let cell = (maxs - mins) / nsamples;
+    let mut index = [0; DIM];
+    let mut count = 0usize;
+
+    loop {
+        let mut pt = mins;
+
+        for i in 0..DIM {
+            pt[i] += cell[i] * (na::convert::<_, N>(index[i] as f64) + na::convert(0.5));
+        }
+
+        if g1.contains_point(m1, &pt) && g2.contains_point(m2, &pt) {
+            count += 1;
+        }
+
+        // Move to the next cell.
+        let mut axis = 0;
+
+        while axis < DIM && index[axis] == resolution - 1 {
+            index[axis] = 0;
+            axis += 1;
+        }
+
+        if axis == DIM {
+            break;
+        }
+
+        index[axis] += 1;
+    }
+
+    let cell_volume = cell.iter().fold(N::one(), |vol, e| vol * *e);
+    cell_volume * na::convert(count as f64)
+}
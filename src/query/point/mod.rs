@@ -11,15 +11,23 @@ mod point_capsule;
 mod point_compound;
 mod point_cuboid;
 mod point_heightfield;
+#[cfg(feature = "dim3")]
+mod point_oriented;
 mod point_plane;
+mod point_point_cloud;
 mod point_polyline;
+#[cfg(feature = "dim3")]
+mod point_quad;
 #[doc(hidden)]
 pub mod point_query;
+mod point_rounded_cuboid;
 mod point_segment;
 mod point_shape;
 mod point_support_map;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
+#[cfg(feature = "dim2")]
+mod point_tile_grid;
 mod point_triangle;
 #[cfg(feature = "dim3")]
 mod point_trimesh;
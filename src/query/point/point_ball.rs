@@ -1,7 +1,7 @@
 use na;
 use simba::scalar::RealField;
 
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{Ball, FeatureId};
 
@@ -15,6 +15,9 @@ impl<N: RealField + Copy> PointQuery<N> for Ball<N> {
 
         if inside && solid {
             PointProjection::new(true, *pt)
+        } else if distance_squared.is_zero() {
+            // Any boundary point is a valid projection of the center.
+            PointProjection::new(true, m * Point::from(Vector::x() * self.radius))
         } else {
             let ls_proj = Point::from(ls_pt.coords * (self.radius / distance_squared.sqrt()));
             PointProjection::new(inside, m * ls_proj)
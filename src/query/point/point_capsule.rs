@@ -1,14 +1,12 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointProjection, PointQuery};
-use crate::shape::{Capsule, FeatureId, Segment};
+use crate::shape::{Capsule, FeatureId};
 use na::{self, RealField, Unit};
 
 impl<N: RealField + Copy> PointQuery<N> for Capsule<N> {
     #[inline]
     fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
-        let mut y = Point::origin();
-        y[1] = self.half_height;
-        let seg = Segment::new(-y, y);
+        let seg = self.segment();
         let proj = seg.project_point(m, pt, solid);
         let dproj = *pt - proj.point;
 
@@ -25,6 +23,11 @@ impl<N: RealField + Copy> PointQuery<N> for Capsule<N> {
             } else {
                 let mut dir: Vector<N> = na::zero();
                 dir[1] = na::one();
+
+                if let Some(frame) = self.local_frame() {
+                    dir = frame * dir;
+                }
+
                 dir = m * dir;
                 PointProjection::new(true, proj.point + dir * self.radius)
             }
@@ -1,7 +1,9 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
-use crate::query::{visitors::CompositePointContainmentTest, PointProjection, PointQuery};
+use crate::query::{
+    statistics, visitors::CompositePointContainmentTest, PointProjection, PointQuery,
+};
 use crate::shape::{CompositeShape, Compound, FeatureId};
 use na::{self, RealField};
 
@@ -9,28 +11,67 @@ impl<N: RealField + Copy> PointQuery<N> for Compound<N> {
     // XXX: if solid == false, this might return internal projection.
     #[inline]
     fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
-        let ls_pt = m.inverse_transform_point(point);
-        let mut visitor = CompoundPointProjVisitor {
-            compound: self,
-            point: &ls_pt,
-            solid: solid,
-        };
-
-        let mut proj = self.bvt().best_first_search(&mut visitor).unwrap().1;
-        proj.point = m * proj.point;
-
-        proj
+        // Masks only apply to the filtered queries, so every part is considered here.
+        self.project_point_with_filter(m, point, solid, None)
+            .unwrap()
     }
 
+    /// Projects a point on the boundary of `self` transformed by `m`.
+    ///
+    /// The returned feature id is the feature of the closest part of this compound, as
+    /// identified by this part itself. Use `project_point_with_part_and_feature` to retrieve
+    /// the index of this part too.
     #[inline]
     fn project_point_with_feature(
         &self,
-        _: &Isometry<N>,
-        _: &Point<N>,
+        m: &Isometry<N>,
+        point: &Point<N>,
     ) -> (PointProjection<N>, FeatureId) {
-        // XXX Properly propagate the feature id.
-        unimplemented!()
-        // (self.project_point(m, point), FeatureId::Unknown)
+        let (proj, (_, feature)) = self.project_point_with_part_and_feature(m, point);
+        (proj, feature)
+    }
+
+    /// Projects a point on the boundary of the union of the parts of `self` transformed by `m`.
+    ///
+    /// If the point is inside of some parts, it is projected on the boundary of each part and the
+    /// closest projection that is not covered by another part is returned. This is exact for
+    /// polyhedral parts, but may miss the closest boundary point when it lies on the intersection
+    /// of the boundaries of several parts.
+    fn project_point_on_boundary(&self, m: &Isometry<N>, point: &Point<N>) -> PointProjection<N> {
+        if !self.contains_point(m, point) {
+            return self.project_point(m, point, false);
+        }
+
+        let ls_pt = m.inverse_transform_point(point);
+        let shapes = self.shapes();
+        // Points on the boundary of several parts are not covered.
+        let tolerance = N::default_epsilon().sqrt();
+        let mut best = (N::max_value().unwrap(), ls_pt);
+        let mut best_uncovered = best;
+
+        for (i, (part_pos, part)) in shapes.iter().enumerate() {
+            let proj = part.project_point_on_boundary(part_pos, &ls_pt);
+            let dist = na::distance(&ls_pt, &proj.point);
+            let covered = shapes.iter().enumerate().any(|(j, (other_pos, other))| {
+                j != i
+                    && self.aabb_at(j).contains_local_point(&proj.point)
+                    && other.distance_to_point(other_pos, &proj.point, false) < -tolerance
+            });
+
+            if dist < best.0 {
+                best = (dist, proj.point);
+            }
+
+            if !covered && dist < best_uncovered.0 {
+                best_uncovered = (dist, proj.point);
+            }
+        }
+
+        if best_uncovered.0 < N::max_value().unwrap() {
+            best = best_uncovered;
+        }
+
+        PointProjection::new(true, m * best.1)
     }
 
     #[inline]
@@ -48,6 +89,64 @@ impl<N: RealField + Copy> PointQuery<N> for Compound<N> {
     }
 }
 
+impl<N: RealField + Copy> Compound<N> {
+    /// Projects a point on `self` transformed by `m`, ignoring the parts whose mask does not
+    /// share any bit with `filter`.
+    ///
+    /// Returns `None` if no part matches the filter.
+    pub fn project_point_filtered(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+        solid: bool,
+        filter: u32,
+    ) -> Option<PointProjection<N>> {
+        self.project_point_with_filter(m, point, solid, Some(filter))
+    }
+
+    fn project_point_with_filter(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+        solid: bool,
+        filter: Option<u32>,
+    ) -> Option<PointProjection<N>> {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut visitor = CompoundPointProjVisitor {
+            compound: self,
+            point: &ls_pt,
+            solid: solid,
+            filter,
+        };
+
+        let mut proj = self.bvt().best_first_search(&mut visitor)?.1;
+        proj.point = m * proj.point;
+
+        Some(proj)
+    }
+
+    /// Projects a point on the boundary of `self` transformed by `m` and returns the index of the
+    /// part the point was projected on, together with the id of the closest feature of this part.
+    ///
+    /// The feature id is expressed with the convention of the part it belongs to.
+    pub fn project_point_with_part_and_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, (usize, FeatureId)) {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut visitor = CompoundPointProjWithFeatureVisitor {
+            compound: self,
+            point: &ls_pt,
+        };
+
+        let (mut proj, location) = self.bvt().best_first_search(&mut visitor).unwrap().1;
+        proj.point = m * proj.point;
+
+        (proj, location)
+    }
+}
+
 /*
  * Visitors
  */
@@ -55,6 +154,7 @@ struct CompoundPointProjVisitor<'a, N: 'a + RealField + Copy> {
     compound: &'a Compound<N>,
     point: &'a Point<N>,
     solid: bool,
+    filter: Option<u32>,
 }
 
 impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
@@ -77,7 +177,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
         };
 
         if let Some(b) = data {
-            if dist < best {
+            if dist < best && self.compound.child_matches_optional_filter(*b, self.filter) {
+                statistics::count_primitive_test();
                 self.compound
                     .map_part_at(*b, &Isometry::identity(), &mut |objm, obj| {
                         let proj = obj.project_point(objm, self.point, self.solid);
@@ -93,3 +194,46 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
         res
     }
 }
+
+struct CompoundPointProjWithFeatureVisitor<'a, N: 'a + RealField + Copy> {
+    compound: &'a Compound<N>,
+    point: &'a Point<N>,
+}
+
+impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
+    for CompoundPointProjWithFeatureVisitor<'a, N>
+{
+    type Result = (PointProjection<N>, (usize, FeatureId));
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &AABB<N>,
+        data: Option<&usize>,
+    ) -> BestFirstVisitStatus<N, Self::Result> {
+        let dist = aabb.distance_to_point(&Isometry::identity(), self.point, true);
+
+        let mut res = BestFirstVisitStatus::Continue {
+            cost: dist,
+            result: None,
+        };
+
+        if let Some(b) = data {
+            if dist < best {
+                statistics::count_primitive_test();
+                self.compound
+                    .map_part_at(*b, &Isometry::identity(), &mut |objm, obj| {
+                        let (proj, feature) = obj.project_point_with_feature(objm, self.point);
+
+                        res = BestFirstVisitStatus::Continue {
+                            cost: na::distance(self.point, &proj.point),
+                            result: Some((proj, (*b, feature))),
+                        };
+                    });
+            }
+        }
+
+        res
+    }
+}
@@ -0,0 +1,27 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Oriented};
+use na::RealField;
+
+impl<N: RealField + Copy, G: PointQuery<N>> PointQuery<N> for Oriented<N, G> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        self.shape
+            .project_point(&(m * self.local_isometry()), pt, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        self.shape
+            .project_point_with_feature(&(m * self.local_isometry()), pt)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        self.shape.contains_point(&(m * self.local_isometry()), pt)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery, PointQueryWithLocation};
+use crate::shape::{FeatureId, PointCloud};
+use na::RealField;
+
+impl<N: RealField + Copy> PointQuery<N> for PointCloud<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        let (projection, _) = self.project_point_with_location(m, point, solid);
+        projection
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &Isometry<N>, point: &Point<N>, _: bool) -> N {
+        let ls_pt = m.inverse_transform_point(point);
+        self.nearest_point(&ls_pt).1
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let (projection, id) = self.project_point_with_location(m, point, false);
+        (projection, FeatureId::Vertex(id))
+    }
+}
+
+impl<N: RealField + Copy> PointQueryWithLocation<N> for PointCloud<N> {
+    /// The index of the closest point of the point cloud.
+    type Location = usize;
+
+    /// Projects a point on the closest point of this point cloud.
+    ///
+    /// A point cloud has no interior, so the projection is inside only if `point` is one of its
+    /// points.
+    #[inline]
+    fn project_point_with_location(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+        _: bool,
+    ) -> (PointProjection<N>, Self::Location) {
+        let ls_pt = m.inverse_transform_point(point);
+        let (id, dist) = self.nearest_point(&ls_pt);
+        let proj = PointProjection::new(dist.is_zero(), m * self.points()[id]);
+
+        (proj, id)
+    }
+}
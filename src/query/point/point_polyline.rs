@@ -2,7 +2,8 @@ use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
 use crate::query::{
-    visitors::CompositePointContainmentTest, PointProjection, PointQuery, PointQueryWithLocation,
+    statistics, visitors::CompositePointContainmentTest, PointProjection, PointQuery,
+    PointQueryWithLocation,
 };
 use crate::shape::{FeatureId, Polyline, SegmentPointLocation};
 use na::{self, RealField};
@@ -20,18 +21,8 @@ impl<N: RealField + Copy> PointQuery<N> for Polyline<N> {
         m: &Isometry<N>,
         point: &Point<N>,
     ) -> (PointProjection<N>, FeatureId) {
-        let ls_pt = m.inverse_transform_point(point);
-        let mut visitor = PolylinePointProjWithFeatureVisitor {
-            polyline: self,
-            point: &ls_pt,
-        };
-
-        let (mut proj, (id, feature)) = self.bvt().best_first_search(&mut visitor).unwrap().1;
-        proj.point = m * proj.point;
-
-        let polyline_feature = self.segment_feature_to_polyline_feature(id, feature);
-
-        (proj, polyline_feature)
+        let (projection, (_, feature_id)) = self.project_point_with_part_and_feature(m, point);
+        (projection, feature_id)
     }
 
     // FIXME: implement distance_to_point too?
@@ -74,6 +65,29 @@ impl<N: RealField + Copy> PointQueryWithLocation<N> for Polyline<N> {
     }
 }
 
+impl<N: RealField + Copy> Polyline<N> {
+    /// Projects a point on `self` transformed by `m` and returns the index of the segment the
+    /// point was projected on, together with the id of the closest feature of this polyline.
+    pub fn project_point_with_part_and_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, (usize, FeatureId)) {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut visitor = PolylinePointProjWithFeatureVisitor {
+            polyline: self,
+            point: &ls_pt,
+        };
+
+        let (mut proj, (id, feature)) = self.bvt().best_first_search(&mut visitor).unwrap().1;
+        proj.point = m * proj.point;
+
+        let polyline_feature = self.segment_feature_to_polyline_feature(id, feature);
+
+        (proj, (id, polyline_feature))
+    }
+}
+
 /*
  * Visitors
  */
@@ -103,6 +117,7 @@ macro_rules! gen_visitor(
 
                 if let Some(b) = data {
                     if dist < best {
+                        statistics::count_primitive_test();
                         let (proj, extra_info) = self.polyline.segment_at(*b).$project(
                             &Isometry::identity(),
                             self.point
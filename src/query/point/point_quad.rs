@@ -0,0 +1,55 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Quad};
+use na::RealField;
+
+impl<N: RealField + Copy> PointQuery<N> for Quad<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let (proj, _) = self.project_point_with_feature(m, pt);
+
+        if proj.is_inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            proj
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let ls_pt = m.inverse_transform_point(pt);
+        let (hx, hz) = (self.half_extents.x, self.half_extents.y);
+        let clamp_x = ls_pt.x.abs() > hx;
+        let clamp_z = ls_pt.z.abs() > hz;
+        let positive_x = ls_pt.x >= N::zero();
+        let positive_z = ls_pt.z >= N::zero();
+
+        let proj = Point::new(
+            na::clamp(ls_pt.x, -hx, hx),
+            N::zero(),
+            na::clamp(ls_pt.z, -hz, hz),
+        );
+
+        let feature = match (clamp_x, clamp_z) {
+            (true, true) => FeatureId::Vertex(Quad::<N>::vertex_id(positive_x, positive_z)),
+            (true, false) => FeatureId::Edge(if positive_x { 2 } else { 0 }),
+            (false, true) => FeatureId::Edge(if positive_z { 1 } else { 3 }),
+            (false, false) => {
+                if ls_pt.y >= N::zero() {
+                    FeatureId::Face(0)
+                } else {
+                    FeatureId::Face(1)
+                }
+            }
+        };
+
+        // A quad has no interior: only the points lying exactly on it are considered inside.
+        let inside = proj == ls_pt;
+
+        (PointProjection::new(inside, m * proj), feature)
+    }
+}
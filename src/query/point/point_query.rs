@@ -10,12 +10,36 @@ pub struct PointProjection<N: RealField + Copy> {
     pub is_inside: bool,
     /// The projection result.
     pub point: Point<N>,
+    #[cfg(feature = "dim3")]
+    face_data: Option<u32>,
 }
 
 impl<N: RealField + Copy> PointProjection<N> {
     /// Initializes a new `PointProjection`.
     pub fn new(is_inside: bool, point: Point<N>) -> PointProjection<N> {
-        PointProjection { is_inside, point }
+        PointProjection {
+            is_inside,
+            point,
+            #[cfg(feature = "dim3")]
+            face_data: None,
+        }
+    }
+
+    /// Sets the user data attached to the face of the triangle mesh the point was projected on.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn with_face_data(mut self, face_data: Option<u32>) -> Self {
+        self.face_data = face_data;
+        self
+    }
+
+    /// The user data attached to the face of the triangle mesh the point was projected on.
+    ///
+    /// This is `None` for other shapes, and for meshes without per-face data.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn face_data(&self) -> Option<u32> {
+        self.face_data
     }
 }
 
@@ -45,11 +69,43 @@ pub trait PointQuery<N: RealField + Copy> {
         pt: &Point<N>,
     ) -> (PointProjection<N>, FeatureId);
 
+    /// Projects a point on the boundary of `self` transformed by `m`, even if it is inside of
+    /// `self`.
+    ///
+    /// The `is_inside` flag of the result tells whether the point was inside of `self`. For
+    /// composite shapes, the boundary is the one of the union of their parts.
+    #[inline]
+    fn project_point_on_boundary(&self, m: &Isometry<N>, pt: &Point<N>) -> PointProjection<N> {
+        self.project_point(m, pt, false)
+    }
+
     /// Tests if the given point is inside of `self` transformed by `m`.
     #[inline]
     fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
         self.project_point(m, pt, false).is_inside
     }
+
+    /// Projects each point of `pts` on `self` transformed by `m`, appending the projections to
+    /// `out` in the same order.
+    ///
+    /// The points are brought to the local-space of `self` with a single inverse transformation.
+    fn project_points(
+        &self,
+        m: &Isometry<N>,
+        pts: &[Point<N>],
+        solid: bool,
+        out: &mut Vec<PointProjection<N>>,
+    ) {
+        let inv_m = m.inverse();
+        let identity = Isometry::identity();
+        out.reserve(pts.len());
+
+        for pt in pts {
+            let mut proj = self.project_point(&identity, &(inv_m * pt), solid);
+            proj.point = m * proj.point;
+            out.push(proj);
+        }
+    }
 }
 
 /// Returns shape-specific info in addition to generic projection information
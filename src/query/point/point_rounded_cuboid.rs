@@ -0,0 +1,54 @@
+use crate::math::{Isometry, Point, DIM};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, RoundedCuboid};
+use na::RealField;
+
+impl<N: RealField + Copy> PointQuery<N> for RoundedCuboid<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let he = self.half_extents;
+        let clamped = Point::from(ls_pt.coords.sup(&-he).inf(&he));
+        let dproj = ls_pt - clamped;
+
+        if let Some(dir) = dproj.try_normalize(N::zero()) {
+            // The point is outside of the inner cuboid.
+            let inside = dproj.norm() <= self.border_radius;
+
+            if solid && inside {
+                PointProjection::new(true, *pt)
+            } else {
+                PointProjection::new(inside, m * (clamped + dir * self.border_radius))
+            }
+        } else if solid {
+            PointProjection::new(true, *pt)
+        } else {
+            // The point is inside of the inner cuboid: the closest boundary point lies on the
+            // flat part of the closest face.
+            let mut best = 0;
+            let mut best_dist = N::max_value().unwrap();
+
+            for i in 0..DIM {
+                let dist = he[i] - ls_pt[i].abs();
+
+                if dist < best_dist {
+                    best = i;
+                    best_dist = dist;
+                }
+            }
+
+            let mut proj = ls_pt;
+            proj[best] = (he[best] + self.border_radius).copysign(ls_pt[best]);
+            PointProjection::new(true, m * proj)
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, pt, false), FeatureId::Unknown)
+    }
+}
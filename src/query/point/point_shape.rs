@@ -1,6 +1,6 @@
 use crate::math::{Isometry, Point};
 use crate::query::{PointProjection, PointQuery};
-use crate::shape::{FeatureId, Shape};
+use crate::shape::{FeatureId, Shape, ShapeHandle};
 use na::RealField;
 
 impl<N: RealField + Copy> PointQuery<N> for dyn Shape<N> {
@@ -29,10 +29,72 @@ impl<N: RealField + Copy> PointQuery<N> for dyn Shape<N> {
             .distance_to_point(m, pt, solid)
     }
 
+    #[inline]
+    fn project_point_on_boundary(&self, m: &Isometry<N>, pt: &Point<N>) -> PointProjection<N> {
+        self.as_point_query()
+            .expect("No PointQuery implementation for the underlying shape.")
+            .project_point_on_boundary(m, pt)
+    }
+
     #[inline]
     fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
         self.as_point_query()
             .expect("No PointQuery implementation for the underlying shape.")
             .contains_point(m, pt)
     }
+
+    #[inline]
+    fn project_points(
+        &self,
+        m: &Isometry<N>,
+        pts: &[Point<N>],
+        solid: bool,
+        out: &mut Vec<PointProjection<N>>,
+    ) {
+        self.as_point_query()
+            .expect("No PointQuery implementation for the underlying shape.")
+            .project_points(m, pts, solid, out)
+    }
+}
+
+impl<N: RealField + Copy> PointQuery<N> for ShapeHandle<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        self.as_ref().project_point(m, pt, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        self.as_ref().project_point_with_feature(m, pt)
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
+        self.as_ref().distance_to_point(m, pt, solid)
+    }
+
+    #[inline]
+    fn project_point_on_boundary(&self, m: &Isometry<N>, pt: &Point<N>) -> PointProjection<N> {
+        self.as_ref().project_point_on_boundary(m, pt)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, pt: &Point<N>) -> bool {
+        self.as_ref().contains_point(m, pt)
+    }
+
+    #[inline]
+    fn project_points(
+        &self,
+        m: &Isometry<N>,
+        pts: &[Point<N>],
+        solid: bool,
+        out: &mut Vec<PointProjection<N>>,
+    ) {
+        self.as_ref().project_points(m, pts, solid, out)
+    }
 }
@@ -8,8 +8,8 @@ use crate::query::{PointProjection, PointQuery};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexHull, Cylinder};
-use crate::shape::{ConvexPolyhedron, FeatureId, SupportMap};
+use crate::shape::{Cone, ConvexHull, Cylinder, Superellipsoid};
+use crate::shape::{ConvexPolyhedron, FeatureId, SupportMap, Swept};
 
 /// Projects a point on a shape using the GJK algorithm.
 pub fn point_projection_on_support_map<N, G>(
@@ -86,6 +86,23 @@ impl<N: RealField + Copy> PointQuery<N> for Cone<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> PointQuery<N> for Superellipsoid<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField + Copy> PointQuery<N> for ConvexHull<N> {
     #[inline]
@@ -145,3 +162,19 @@ impl<N: RealField + Copy> PointQuery<N> for ConvexPolygon<N> {
         }
     }
 }
+
+impl<N: RealField + Copy, G: SupportMap<N>> PointQuery<N> for Swept<N, G> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        point_projection_on_support_map(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point};
+use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
+use crate::query::{statistics, PointProjection, PointQuery};
+use crate::shape::{FeatureId, TileGrid};
+use na::{self, RealField};
+
+impl<N: RealField + Copy> PointQuery<N> for TileGrid<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        if solid && self.contains_point(m, point) {
+            return PointProjection::new(true, *point);
+        }
+
+        self.project_point_with_feature(m, point).0
+    }
+
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut visitor = TileGridPointProjVisitor {
+            grid: self,
+            point: &ls_pt,
+        };
+
+        match self.bvt().best_first_search(&mut visitor) {
+            Some((_, (mut proj, (id, feature)))) => {
+                proj.point = m * proj.point;
+                proj.is_inside = self.contains_local_point(&ls_pt);
+
+                (proj, self.segment_feature_to_tile_grid_feature(id, feature))
+            }
+            None => {
+                // Without any solid cell, fall back to the boundary of the grid itself.
+                let proj = self.grid_aabb().project_point(m, point, false);
+                (PointProjection::new(false, proj.point), FeatureId::Unknown)
+            }
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(point))
+    }
+}
+
+impl<N: RealField + Copy> TileGrid<N> {
+    fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        match self.cell_at_point(pt) {
+            Some((i, j)) => self.is_solid(i, j),
+            None => false,
+        }
+    }
+}
+
+struct TileGridPointProjVisitor<'a, N: 'a + RealField + Copy> {
+    grid: &'a TileGrid<N>,
+    point: &'a Point<N>,
+}
+
+impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
+    for TileGridPointProjVisitor<'a, N>
+{
+    type Result = (PointProjection<N>, (usize, FeatureId));
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &AABB<N>,
+        data: Option<&usize>,
+    ) -> BestFirstVisitStatus<N, Self::Result> {
+        let dist = aabb.distance_to_point(&Isometry::identity(), self.point, true);
+
+        let mut res = BestFirstVisitStatus::Continue {
+            cost: dist,
+            result: None,
+        };
+
+        if let Some(b) = data {
+            if dist < best {
+                statistics::count_primitive_test();
+                let (proj, feature) = self
+                    .grid
+                    .edge_segment(*b)
+                    .project_point_with_feature(&Isometry::identity(), self.point);
+
+                res = BestFirstVisitStatus::Continue {
+                    cost: na::distance(self.point, &proj.point),
+                    result: Some((proj, (*b, feature))),
+                };
+            }
+        }
+
+        res
+    }
+}
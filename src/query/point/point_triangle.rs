@@ -268,8 +268,8 @@ impl<N: RealField + Copy> PointQueryWithLocation<N> for Triangle<N> {
 
             let bc = c - b;
             let d_ab = ap.norm_squared() - (ab.norm_squared() * v * v);
-            let d_ac = ap.norm_squared() - (ac.norm_squared() * u * u);
-            let d_bc = bp.norm_squared() - (bc.norm_squared() * w * w);
+            let d_ac = ap.norm_squared() - (ac.norm_squared() * w * w);
+            let d_bc = bp.norm_squared() - (bc.norm_squared() * u * u);
 
             let mut proj;
             let loc;
@@ -2,9 +2,11 @@ use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
 use crate::query::{
-    visitors::CompositePointContainmentTest, PointProjection, PointQuery, PointQueryWithLocation,
+    statistics, visitors::CompositePointContainmentTest, PointProjection, PointQuery,
+    PointQueryWithLocation,
 };
 use crate::shape::{CompositeShape, FeatureId, TriMesh, TrianglePointLocation};
+use crate::utils;
 use na::{self, RealField};
 
 impl<N: RealField + Copy> PointQuery<N> for TriMesh<N> {
@@ -20,19 +22,7 @@ impl<N: RealField + Copy> PointQuery<N> for TriMesh<N> {
         m: &Isometry<N>,
         point: &Point<N>,
     ) -> (PointProjection<N>, FeatureId) {
-        let (projection, (triangle_id, location)) =
-            self.project_point_with_location(m, point, false);
-        let face = &self.faces()[triangle_id];
-        let feature_id = match location {
-            TrianglePointLocation::OnVertex(triangle_local_id) => {
-                FeatureId::Vertex(face.indices[triangle_local_id])
-            }
-            TrianglePointLocation::OnEdge(triangle_local_id, _) => {
-                FeatureId::Edge(face.edges[triangle_local_id])
-            }
-            TrianglePointLocation::OnFace(_, _) => FeatureId::Face(triangle_id),
-            TrianglePointLocation::OnSolid => FeatureId::Unknown,
-        };
+        let (projection, (_, feature_id)) = self.project_point_with_part_and_feature(m, point);
         (projection, feature_id)
     }
 
@@ -41,6 +31,11 @@ impl<N: RealField + Copy> PointQuery<N> for TriMesh<N> {
     #[inline]
     fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
         let ls_pt = m.inverse_transform_point(point);
+
+        if self.closed() && self.contains_local_point_by_winding_number(&ls_pt) {
+            return true;
+        }
+
         let mut visitor = CompositePointContainmentTest {
             shape: self,
             point: &ls_pt,
@@ -56,12 +51,19 @@ impl<N: RealField + Copy> PointQuery<N> for TriMesh<N> {
 impl<N: RealField + Copy> PointQueryWithLocation<N> for TriMesh<N> {
     type Location = (usize, TrianglePointLocation<N>);
 
+    /// Projects a point on `self` transformed by `m`.
+    ///
+    /// If this mesh is closed, it is assumed to be watertight with outward normals, and the
+    /// inside of the mesh is determined using its winding number. Then a point inside of this
+    /// mesh is its own projection if `solid` is `true`. Otherwise it is projected on the mesh
+    /// boundary with `is_inside` set to `true`, so that `distance_to_point` returns a negative
+    /// distance. Open meshes are treated as infinitely thin sets of triangles.
     #[inline]
     fn project_point_with_location(
         &self,
         m: &Isometry<N>,
         point: &Point<N>,
-        _: bool,
+        solid: bool,
     ) -> (PointProjection<N>, Self::Location) {
         let ls_pt = m.inverse_transform_point(point);
         let mut visitor = TriMeshPointProjVisitor {
@@ -69,10 +71,79 @@ impl<N: RealField + Copy> PointQueryWithLocation<N> for TriMesh<N> {
             point: &ls_pt,
         };
 
-        let (mut proj, extra_info) = self.bvh().best_first_search(&mut visitor).unwrap().1;
+        let (mut proj, mut extra_info) = self.bvh().best_first_search(&mut visitor).unwrap().1;
+
+        if self.closed() && !proj.is_inside && self.contains_local_point_by_winding_number(&ls_pt)
+        {
+            if solid {
+                proj.point = ls_pt;
+                extra_info.1 = TrianglePointLocation::OnSolid;
+            }
+
+            proj.is_inside = true;
+        }
+
         proj.point = m * proj.point;
 
-        (proj, extra_info)
+        (proj.with_face_data(self.face_data_at(extra_info.0)), extra_info)
+    }
+}
+
+impl<N: RealField + Copy> TriMesh<N> {
+    /// Computes the generalized winding number of this mesh with regard to the given point.
+    ///
+    /// The point is expressed in the local-space of the mesh. The winding number is the sum of
+    /// the signed solid angles subtended by every triangle, divided by `4π`. For a closed mesh with
+    /// outward normals, it is equal to 1 for points inside of the mesh, and 0 for points outside.
+    /// For a mesh with holes, it varies smoothly and can be thresholded at 0.5.
+    pub fn winding_number(&self, point: &Point<N>) -> N {
+        let mut total = N::zero();
+
+        for face in self.faces() {
+            let a = self.points()[face.indices.x] - point;
+            let b = self.points()[face.indices.y] - point;
+            let c = self.points()[face.indices.z] - point;
+            let la = a.norm();
+            let lb = b.norm();
+            let lc = c.norm();
+
+            // Solid angle from Van Oosterom and Strackee.
+            let num = a.dot(&b.cross(&c));
+            let den = la * lb * lc + a.dot(&b) * lc + b.dot(&c) * la + c.dot(&a) * lb;
+            total += utils::atan2(num, den);
+        }
+
+        // Each atan2 is half of the solid angle.
+        total / (N::two_pi())
+    }
+
+    fn contains_local_point_by_winding_number(&self, point: &Point<N>) -> bool {
+        let aabb = self.aabb();
+
+        aabb.contains_local_point(point) && self.winding_number(point) > na::convert(0.5)
+    }
+
+    /// Projects a point on the boundary of `self` transformed by `m` and returns the index of the
+    /// triangle the point was projected on, together with the id of the closest feature of this mesh.
+    pub fn project_point_with_part_and_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, (usize, FeatureId)) {
+        let (projection, (triangle_id, location)) =
+            self.project_point_with_location(m, point, false);
+        let face = &self.faces()[triangle_id];
+        let feature_id = match location {
+            TrianglePointLocation::OnVertex(triangle_local_id) => {
+                FeatureId::Vertex(face.indices[triangle_local_id])
+            }
+            TrianglePointLocation::OnEdge(triangle_local_id, _) => {
+                FeatureId::Edge(face.edges[triangle_local_id])
+            }
+            TrianglePointLocation::OnFace(_, _) => FeatureId::Face(triangle_id),
+            TrianglePointLocation::OnSolid => FeatureId::Unknown,
+        };
+        (projection, (triangle_id, feature_id))
     }
 }
 
@@ -105,6 +176,7 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
 
         if let Some(b) = data {
             if dist < best {
+                statistics::count_primitive_test();
                 let (proj, extra_info) = self.polyline.triangle_at(*b).project_point_with_location(
                     &Isometry::identity(),
                     self.point,
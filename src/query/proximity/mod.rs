@@ -3,12 +3,13 @@
 pub use self::proximity::Proximity;
 pub use self::proximity_ball_ball::proximity_ball_ball;
 pub use self::proximity_composite_shape_shape::{
-    proximity_composite_shape_shape, proximity_shape_composite_shape,
+    proximity_composite_shape_shape, proximity_composite_shape_shape_local,
+    proximity_shape_composite_shape, proximity_shape_composite_shape_local,
 };
 pub use self::proximity_plane_support_map::{
     proximity_plane_support_map, proximity_support_map_plane,
 };
-pub use self::proximity_shape_shape::proximity;
+pub use self::proximity_shape_shape::{proximity, proximity_local};
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map;
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map_with_params;
 
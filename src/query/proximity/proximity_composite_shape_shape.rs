@@ -1,7 +1,7 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point, Vector};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{self, PointQuery, Proximity};
+use crate::query::{self, statistics, PointQuery, Proximity};
 use crate::shape::{CompositeShape, Shape};
 use na::{self, RealField};
 
@@ -15,13 +15,43 @@ pub fn proximity_composite_shape_shape<N: RealField + Copy, G1: ?Sized>(
 ) -> Proximity
 where
     G1: CompositeShape<N>,
+{
+    proximity_composite_shape_shape_local(&(m1.inverse() * m2), g1, g2, margin)
+}
+
+/// Proximity between a shape and a composite (`Mesh`, `Compound`) shape.
+pub fn proximity_shape_composite_shape<N: RealField + Copy, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &G2,
+    margin: N,
+) -> Proximity
+where
+    G2: CompositeShape<N>,
+{
+    proximity_composite_shape_shape(m2, g2, m1, g1, margin)
+}
+
+/// Proximity between a composite shape (`Mesh`, `Compound`) and any other shape, computed in the
+/// local space of the composite shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn proximity_composite_shape_shape_local<N: RealField + Copy, G1>(
+    pos12: &Isometry<N>,
+    g1: &G1,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> Proximity
+where
+    G1: ?Sized + CompositeShape<N>,
 {
     assert!(
         margin >= na::zero(),
         "The proximity margin must be positive or null."
     );
 
-    let mut visitor = CompositeShapeAgainstAnyInterfVisitor::new(m1, g1, m2, g2, margin);
+    let mut visitor = CompositeShapeAgainstAnyInterfVisitor::new(pos12, g1, g2, margin);
 
     match g1.bvh().best_first_search(&mut visitor) {
         None => Proximity::Disjoint,
@@ -29,27 +59,28 @@ where
     }
 }
 
-/// Proximity between a shape and a composite (`Mesh`, `Compound`) shape.
-pub fn proximity_shape_composite_shape<N: RealField + Copy, G2: ?Sized>(
-    m1: &Isometry<N>,
+/// Proximity between a shape and a composite (`Mesh`, `Compound`) shape, computed in the local
+/// space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`.
+pub fn proximity_shape_composite_shape_local<N: RealField + Copy, G2>(
+    pos12: &Isometry<N>,
     g1: &dyn Shape<N>,
-    m2: &Isometry<N>,
     g2: &G2,
     margin: N,
 ) -> Proximity
 where
-    G2: CompositeShape<N>,
+    G2: ?Sized + CompositeShape<N>,
 {
-    proximity_composite_shape_shape(m2, g2, m1, g1, margin)
+    proximity_composite_shape_shape_local(&pos12.inverse(), g2, g1, margin)
 }
 
 struct CompositeShapeAgainstAnyInterfVisitor<'a, N: 'a + RealField + Copy, G1: ?Sized + 'a> {
     msum_shift: Vector<N>,
     msum_margin: Vector<N>,
 
-    m1: &'a Isometry<N>,
     g1: &'a G1,
-    m2: &'a Isometry<N>,
+    pos12: &'a Isometry<N>,
     g2: &'a dyn Shape<N>,
     margin: N,
 }
@@ -59,21 +90,18 @@ where
     G1: CompositeShape<N>,
 {
     pub fn new(
-        m1: &'a Isometry<N>,
+        pos12: &'a Isometry<N>,
         g1: &'a G1,
-        m2: &'a Isometry<N>,
         g2: &'a dyn Shape<N>,
         margin: N,
     ) -> CompositeShapeAgainstAnyInterfVisitor<'a, N, G1> {
-        let ls_m2 = m1.inverse() * m2.clone();
-        let ls_aabb2 = g2.aabb(&ls_m2);
+        let ls_aabb2 = g2.aabb(pos12);
 
         CompositeShapeAgainstAnyInterfVisitor {
             msum_shift: -ls_aabb2.center().coords,
             msum_margin: ls_aabb2.half_extents(),
-            m1,
             g1,
-            m2,
+            pos12,
             g2,
             margin,
         }
@@ -108,14 +136,11 @@ where
 
         if let Some(b) = data {
             if dist < best {
-                self.g1
-                    .map_part_at(*b, self.m1, &mut |m1, g1| match query::proximity(
-                        m1,
-                        g1,
-                        self.m2,
-                        self.g2,
-                        self.margin,
-                    ) {
+                statistics::count_primitive_test();
+                self.g1.map_part_at(
+                    *b,
+                    &Isometry::identity(),
+                    &mut |m1, g1| match query::proximity(m1, g1, self.pos12, self.g2, self.margin) {
                         Proximity::WithinMargin => {
                             res = BestFirstVisitStatus::Continue {
                                 cost: self.margin,
@@ -126,7 +151,8 @@ where
                             res = BestFirstVisitStatus::ExitEarly(Some(Proximity::Intersecting))
                         }
                         Proximity::Disjoint => {}
-                    });
+                    },
+                );
             }
         }
 
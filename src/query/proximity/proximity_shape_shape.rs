@@ -31,3 +31,22 @@ pub fn proximity<N: RealField + Copy>(
         panic!("No algorithm known to compute proximity between the given pair of shapes.")
     }
 }
+
+/// Tests whether two shapes are intersecting or separated by a distance smaller than `margin`,
+/// in the local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`, e.g. `m1.inverse() * m2`.
+pub fn proximity_local<N: RealField + Copy>(
+    pos12: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+    margin: N,
+) -> Proximity {
+    if let Some(c1) = g1.as_composite_shape() {
+        query::proximity_composite_shape_shape_local(pos12, c1, g2, margin)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        query::proximity_shape_composite_shape_local(pos12, g1, c2, margin)
+    } else {
+        proximity(&Isometry::identity(), g1, pos12, g2, margin)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::partitioning::{BVTNodeId, BestFirstSearchQueue, DBVTNodeId};
+use crate::query::algorithms::{VoronoiSimplex, EPA};
+use na::RealField;
+
+/// Scratch memory reused by the `_with_buffers` variants of the geometric queries.
+///
+/// Keeping one instance of this structure alive across calls lets hot loops run without
+/// allocating temporary buffers for the GJK, EPA, and bounding volume hierarchy traversals.
+/// The content of the buffers is meaningless between two queries.
+pub struct QueryBuffers<N: RealField + Copy> {
+    pub(crate) simplex: VoronoiSimplex<N>,
+    pub(crate) epa: EPA<N>,
+    pub(crate) bvt_queue: BestFirstSearchQueue<N, BVTNodeId>,
+    pub(crate) dbvt_queue: BestFirstSearchQueue<N, DBVTNodeId>,
+    pub(crate) interferences: Vec<usize>,
+    pub(crate) hull: ConvexHullBuffers,
+}
+
+/// Scratch memory used by the convex hull computation.
+#[derive(Default)]
+pub(crate) struct ConvexHullBuffers {
+    pub undecidable_points: Vec<usize>,
+    #[cfg(feature = "dim3")]
+    pub horizon_loop_facets: Vec<usize>,
+    #[cfg(feature = "dim3")]
+    pub horizon_loop_ids: Vec<usize>,
+    #[cfg(feature = "dim3")]
+    pub removed_facets: Vec<usize>,
+}
+
+impl<N: RealField + Copy> QueryBuffers<N> {
+    /// Creates a new set of empty buffers.
+    pub fn new() -> Self {
+        QueryBuffers {
+            simplex: VoronoiSimplex::new(),
+            epa: EPA::new(),
+            bvt_queue: BestFirstSearchQueue::new(),
+            dbvt_queue: BestFirstSearchQueue::new(),
+            interferences: Vec::new(),
+            hull: ConvexHullBuffers::default(),
+        }
+    }
+}
+
+impl<N: RealField + Copy> Default for QueryBuffers<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
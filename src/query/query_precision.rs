@@ -0,0 +1,49 @@
+use crate::query::algorithms::gjk;
+use na::RealField;
+
+/// Tolerances and iteration limits of the iterative geometric algorithms (GJK, EPA, and the
+/// GJK-based ray-casting, which takes them as `gjk::RaycastOptions`).
+///
+/// The defaults are tuned for floating-point numbers. Scalar types with a much coarser
+/// resolution, e.g., fixed-point numbers used for deterministic simulations, usually need a
+/// larger `epsilon` than `N::default_epsilon()`, and may want to set the `relative_epsilon`
+/// directly instead of relying on a square root.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QueryPrecision<N: RealField + Copy> {
+    /// The absolute tolerance used to detect convergence and degenerate directions.
+    pub epsilon: N,
+    /// The relative tolerance on the gap between the lower and upper distance bounds.
+    pub relative_epsilon: N,
+    /// The maximum number of iterations before giving up.
+    pub max_iterations: usize,
+}
+
+impl<N: RealField + Copy> QueryPrecision<N> {
+    /// Initializes the precision parameters, with a relative tolerance set to the square root of
+    /// `epsilon`.
+    pub fn new(epsilon: N, max_iterations: usize) -> Self {
+        QueryPrecision {
+            epsilon,
+            relative_epsilon: epsilon.sqrt(),
+            max_iterations,
+        }
+    }
+
+    /// Replaces the relative tolerance of these parameters.
+    pub fn with_relative_epsilon(mut self, relative_epsilon: N) -> Self {
+        self.relative_epsilon = relative_epsilon;
+        self
+    }
+
+    /// The default parameters of the EPA algorithm, which is less accurate than the GJK.
+    pub fn epa() -> Self {
+        QueryPrecision::new(N::default_epsilon() * na::convert(100.0f64), 10000)
+    }
+}
+
+impl<N: RealField + Copy> Default for QueryPrecision<N> {
+    /// The default parameters of the GJK algorithm.
+    fn default() -> Self {
+        QueryPrecision::new(gjk::eps_tol(), 10000)
+    }
+}
@@ -2,24 +2,51 @@
 
 #[doc(inline)]
 pub use self::ray::{Ray, RayCast, RayIntersection};
+pub use self::ray_aabb::ray_toi_with_aabb;
 pub use self::ray_ball::ray_toi_with_ball;
+pub use self::ray_capsule::ray_intersection_with_capsule;
+#[cfg(feature = "dim3")]
+pub use self::ray_cone::ray_intersection_with_cone;
+#[cfg(feature = "dim3")]
+pub use self::ray_cylinder::ray_intersection_with_cylinder;
+pub use self::ray_moving_shape::ray_toi_with_moving_shape;
+pub use self::ray_packet::RayPacket;
 pub use self::ray_plane::{line_toi_with_plane, ray_toi_with_plane};
+pub use self::ray_segment::{clip_segment_with_shape, segment_intersects_shape};
 pub use self::ray_support_map::ray_intersection_with_support_map_with_params;
+pub use self::ray_thick::{thick_ray_cast, thick_ray_cast_with_support_map};
 #[cfg(feature = "dim3")]
 pub use self::ray_triangle::ray_intersection_with_triangle;
 
+pub mod primitives;
 #[doc(hidden)]
 pub mod ray;
 mod ray_aabb;
 mod ray_ball;
 mod ray_bounding_sphere;
+mod ray_capsule;
 mod ray_compound;
+#[cfg(feature = "dim3")]
+mod ray_cone;
 mod ray_cuboid;
+#[cfg(feature = "dim3")]
+mod ray_cylinder;
 mod ray_heightfield;
+mod ray_moving_shape;
+#[cfg(feature = "dim3")]
+mod ray_oriented;
+mod ray_packet;
 mod ray_plane;
 mod ray_polyline;
+#[cfg(feature = "dim3")]
+mod ray_quad;
+mod ray_scaled;
+mod ray_segment;
 mod ray_shape;
 mod ray_support_map;
+mod ray_thick;
+#[cfg(feature = "dim2")]
+mod ray_tile_grid;
 #[cfg(feature = "dim3")]
 mod ray_triangle;
 #[cfg(feature = "dim3")]
@@ -0,0 +1,17 @@
+//! Low-level ray-casting functions on geometric primitives.
+//!
+//! These functions do not require building any shape: the primitives are described directly
+//! by their points and dimensions, in the same space as the ray. They all follow the same
+//! conventions:
+//!
+//! * The ray direction does not need to be normalized. A time of impact `t` identifies the
+//!   point `ray.origin + ray.dir * t`, so it is only a distance if `ray.dir` has a unit length.
+//! * Only non-negative times of impact are reported, i.e., nothing behind the ray origin.
+//! * When the ray starts inside of a `solid` primitive, the time of impact is zero. If the
+//!   primitive is not solid, the ray hits its boundary from the inside instead.
+
+pub use super::ray_aabb::ray_toi_with_aabb;
+pub use super::ray_ball::{ray_toi_and_normal_with_ball, ray_toi_with_ball};
+pub use super::ray_plane::{line_toi_with_plane, ray_toi_with_plane};
+#[cfg(feature = "dim3")]
+pub use super::ray_triangle::ray_intersection_with_triangle;
@@ -77,6 +77,9 @@ pub struct RayIntersection<N: RealField + Copy> {
     /// do not support texture coordinates.
     #[cfg(feature = "dim3")]
     pub uvs: Option<Point2<N>>,
+
+    #[cfg(feature = "dim3")]
+    face_data: Option<u32>,
 }
 
 impl<N: RealField + Copy> RayIntersection<N> {
@@ -94,6 +97,7 @@ impl<N: RealField + Copy> RayIntersection<N> {
             normal,
             feature,
             uvs,
+            face_data: None,
         }
     }
 
@@ -106,6 +110,7 @@ impl<N: RealField + Copy> RayIntersection<N> {
             normal,
             feature,
             uvs: None,
+            face_data: None,
         }
     }
 
@@ -119,6 +124,23 @@ impl<N: RealField + Copy> RayIntersection<N> {
             feature,
         }
     }
+
+    /// Sets the user data attached to the face of the triangle mesh hit by the ray.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn with_face_data(mut self, face_data: Option<u32>) -> Self {
+        self.face_data = face_data;
+        self
+    }
+
+    /// The user data attached to the face of the triangle mesh hit by the ray.
+    ///
+    /// This is `None` for other shapes, and for meshes without per-face data.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn face_data(&self) -> Option<u32> {
+        self.face_data
+    }
 }
 
 /// Traits of objects which can be transformed and tested for intersection with a ray.
@@ -12,41 +12,7 @@ use crate::shape::{FeatureId, Segment};
 impl<N: RealField + Copy> RayCast<N> for AABB<N> {
     fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
         let ls_ray = ray.inverse_transform_by(m);
-
-        let mut tmin: N = na::zero();
-        let mut tmax: N = max_toi;
-
-        for i in 0usize..DIM {
-            if ls_ray.dir[i].is_zero() {
-                if ls_ray.origin[i] < self.mins[i] || ls_ray.origin[i] > self.maxs[i] {
-                    return None;
-                }
-            } else {
-                let _1: N = na::one();
-                let denom = _1 / ls_ray.dir[i];
-                let mut inter_with_near_plane = (self.mins[i] - ls_ray.origin[i]) * denom;
-                let mut inter_with_far_plane = (self.maxs[i] - ls_ray.origin[i]) * denom;
-
-                if inter_with_near_plane > inter_with_far_plane {
-                    mem::swap(&mut inter_with_near_plane, &mut inter_with_far_plane)
-                }
-
-                tmin = tmin.max(inter_with_near_plane);
-                tmax = tmax.min(inter_with_far_plane);
-
-                if tmin > tmax {
-                    // This covers the case where tmax is negative because tmin is
-                    // initialized at zero.
-                    return None;
-                }
-            }
-        }
-
-        if tmin.is_zero() && !solid {
-            Some(tmax)
-        } else {
-            Some(tmin)
-        }
+        ray_toi_with_aabb(&self.mins, &self.maxs, &ls_ray, max_toi, solid)
     }
 
     #[inline]
@@ -130,6 +96,55 @@ impl<N: RealField + Copy> AABB<N> {
     }
 }
 
+/// Computes the time of impact of a ray on an axis-aligned box, using the slab test.
+///
+/// The box is given by its smallest and largest corners `mins` and `maxs`. Returns `None` if
+/// the ray misses the box or only hits it after `max_toi`. If the ray starts inside of the box,
+/// the result is zero when `solid` is `true`, and the time of impact with the box boundary
+/// otherwise.
+pub fn ray_toi_with_aabb<N: RealField + Copy>(
+    mins: &Point<N>,
+    maxs: &Point<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<N> {
+    let mut tmin: N = na::zero();
+    let mut tmax: N = max_toi;
+
+    for i in 0usize..DIM {
+        if ray.dir[i].is_zero() {
+            if ray.origin[i] < mins[i] || ray.origin[i] > maxs[i] {
+                return None;
+            }
+        } else {
+            let _1: N = na::one();
+            let denom = _1 / ray.dir[i];
+            let mut inter_with_near_plane = (mins[i] - ray.origin[i]) * denom;
+            let mut inter_with_far_plane = (maxs[i] - ray.origin[i]) * denom;
+
+            if inter_with_near_plane > inter_with_far_plane {
+                mem::swap(&mut inter_with_near_plane, &mut inter_with_far_plane)
+            }
+
+            tmin = tmin.max(inter_with_near_plane);
+            tmax = tmax.min(inter_with_far_plane);
+
+            if tmin > tmax {
+                // This covers the case where tmax is negative because tmin is
+                // initialized at zero.
+                return None;
+            }
+        }
+    }
+
+    if tmin.is_zero() && !solid {
+        Some(tmax)
+    } else {
+        Some(tmin)
+    }
+}
+
 #[cfg(feature = "dim3")]
 fn do_toi_and_normal_and_uv_with_ray<N: RealField + Copy>(
     m: &Isometry<N>,
@@ -8,6 +8,8 @@ use crate::math::Vector;
 use crate::math::{Isometry, Point};
 use crate::query::{Ray, RayCast, RayIntersection};
 use crate::shape::{Ball, FeatureId};
+#[cfg(feature = "dim3")]
+use crate::utils;
 
 #[cfg(feature = "dim3")]
 #[inline]
@@ -15,8 +17,8 @@ fn ball_uv<N: RealField + Copy>(normal: &Vector<N>) -> Point2<N> {
     let two_pi: N = RealField::two_pi();
     let pi: N = RealField::pi();
     let _0_5: N = na::convert(0.5f64);
-    let uvx = _0_5 + normal[2].atan2(normal[0]) / two_pi;
-    let uvy = _0_5 - normal[1].asin() / pi;
+    let uvx = _0_5 + utils::atan2(normal[2], normal[0]) / two_pi;
+    let uvy = _0_5 - utils::asin(normal[1]) / pi;
 
     Point2::new(uvx, uvy)
 }
@@ -72,7 +74,10 @@ impl<N: RealField + Copy> RayCast<N> for Ball<N> {
 
 /// Computes the time of impact of a ray on a ball.
 ///
-/// The first result element is `true` if the ray started inside of the ball.
+/// The first result element is `true` if the ray started inside of the ball. If it did, the
+/// time of impact is zero when `solid` is `true`, and the time of impact with the ball boundary
+/// otherwise. The time of impact is not bounded, so it may be larger than any `max_toi` the
+/// caller is interested in.
 #[inline]
 pub fn ray_toi_with_ball<N: RealField + Copy>(
     center: &Point<N>,
@@ -121,6 +126,9 @@ pub fn ray_toi_with_ball<N: RealField + Copy>(
 }
 
 /// Computes the time of impact and contact normal of a ray on a ball.
+///
+/// Same as `ray_toi_with_ball`, except that the unit normal of the ball at the intersection
+/// point is returned too. It points toward the ray origin when it started inside of the ball.
 #[inline]
 pub fn ray_toi_and_normal_with_ball<N: RealField + Copy>(
     center: &Point<N>,
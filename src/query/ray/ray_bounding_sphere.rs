@@ -1,7 +1,7 @@
 use crate::bounding_volume::BoundingSphere;
-use crate::math::Isometry;
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{Ray, RayCast, RayIntersection};
-use crate::shape::Ball;
+use crate::shape::{Ball, Segment};
 use na::RealField;
 
 impl<N: RealField + Copy> RayCast<N> for BoundingSphere<N> {
@@ -56,3 +56,69 @@ impl<N: RealField + Copy> RayCast<N> for BoundingSphere<N> {
         Ball::new(self.radius()).intersects_ray(&Isometry::identity(), &centered_ray, max_toi)
     }
 }
+
+impl<N: RealField + Copy> BoundingSphere<N> {
+    /// Computes the parameters of the two intersection points between a line and this bounding sphere.
+    ///
+    /// The parameters are such that the point are given by `orig + dir * parameter`.
+    /// Returns `None` if there is no intersection.
+    #[inline]
+    pub fn clip_line_parameters(&self, orig: &Point<N>, dir: &Vector<N>) -> Option<(N, N)> {
+        let dcenter = orig - self.center();
+        let a = dir.norm_squared();
+        let c = dcenter.norm_squared() - self.radius() * self.radius();
+
+        if a.is_zero() {
+            return if c <= N::zero() {
+                let max = N::max_value().unwrap();
+                Some((-max, max))
+            } else {
+                None
+            };
+        }
+
+        let b = dcenter.dot(dir);
+        let delta = b * b - a * c;
+
+        if delta < N::zero() {
+            None
+        } else {
+            let sqrt_delta = delta.sqrt();
+            Some(((-b - sqrt_delta) / a, (-b + sqrt_delta) / a))
+        }
+    }
+
+    /// Computes the intersection segment between a line and this bounding sphere.
+    ///
+    /// Returns `None` if there is no intersection.
+    #[inline]
+    pub fn clip_line(&self, orig: &Point<N>, dir: &Vector<N>) -> Option<Segment<N>> {
+        self.clip_line_parameters(orig, dir)
+            .map(|clip| Segment::new(orig + dir * clip.0, orig + dir * clip.1))
+    }
+
+    /// Computes the parameters of the two intersection points between a ray and this bounding sphere.
+    ///
+    /// The parameters are such that the point are given by `ray.orig + ray.dir * parameter`.
+    /// Returns `None` if there is no intersection.
+    #[inline]
+    pub fn clip_ray_parameters(&self, ray: &Ray<N>) -> Option<(N, N)> {
+        self.clip_line_parameters(&ray.origin, &ray.dir)
+            .and_then(|clip| {
+                if clip.1 < N::zero() {
+                    None
+                } else {
+                    Some((clip.0.max(N::zero()), clip.1))
+                }
+            })
+    }
+
+    /// Computes the intersection segment between a ray and this bounding sphere.
+    ///
+    /// Returns `None` if there is no intersection.
+    #[inline]
+    pub fn clip_ray(&self, ray: &Ray<N>) -> Option<Segment<N>> {
+        self.clip_ray_parameters(ray)
+            .map(|clip| Segment::new(ray.point_at(clip.0), ray.point_at(clip.1)))
+    }
+}
@@ -0,0 +1,216 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Capsule, FeatureId};
+
+/// Computes the parameters where a ray enters and exits a ball centered at `center`.
+fn clip_ray_with_ball<N: RealField + Copy>(
+    center: &Point<N>,
+    radius: N,
+    ray: &Ray<N>,
+) -> Option<(N, N)> {
+    let dcenter = ray.origin - *center;
+    let a = ray.dir.norm_squared();
+    let b = dcenter.dot(&ray.dir);
+    let c = dcenter.norm_squared() - radius * radius;
+
+    if a.is_zero() {
+        return if c > N::zero() {
+            None
+        } else {
+            Some((-N::max_value().unwrap(), N::max_value().unwrap()))
+        };
+    }
+
+    let delta = b * b - a * c;
+
+    if delta < N::zero() {
+        None
+    } else {
+        let sqrt_delta = delta.sqrt();
+        Some(((-b - sqrt_delta) / a, (-b + sqrt_delta) / a))
+    }
+}
+
+/// Computes the parameters where a ray enters and exits the cylindrical part of a capsule.
+fn clip_ray_with_capsule_body<N: RealField + Copy>(
+    capsule: &Capsule<N>,
+    ray: &Ray<N>,
+) -> Option<(N, N)> {
+    // Intersection with the infinite slab along the capsule axis.
+    let (mut tmin, mut tmax) = if ray.dir.y.is_zero() {
+        if ray.origin.y.abs() > capsule.half_height {
+            return None;
+        }
+
+        (-N::max_value().unwrap(), N::max_value().unwrap())
+    } else {
+        let t0 = (-capsule.half_height - ray.origin.y) / ray.dir.y;
+        let t1 = (capsule.half_height - ray.origin.y) / ray.dir.y;
+        (t0.min(t1), t0.max(t1))
+    };
+
+    // Intersection with the infinite cylinder (or the infinite band in 2D) around the axis.
+    let mut perp_origin = ray.origin.coords;
+    let mut perp_dir = ray.dir;
+    perp_origin.y = N::zero();
+    perp_dir.y = N::zero();
+
+    let a = perp_dir.norm_squared();
+    let b = perp_origin.dot(&perp_dir);
+    let c = perp_origin.norm_squared() - capsule.radius * capsule.radius;
+
+    if a.is_zero() {
+        if c > N::zero() {
+            return None;
+        }
+    } else {
+        let delta = b * b - a * c;
+
+        if delta < N::zero() {
+            return None;
+        }
+
+        let sqrt_delta = delta.sqrt();
+        tmin = tmin.max((-b - sqrt_delta) / a);
+        tmax = tmax.min((-b + sqrt_delta) / a);
+    }
+
+    if tmin <= tmax {
+        Some((tmin, tmax))
+    } else {
+        None
+    }
+}
+
+/// The outward normal and feature of a capsule at a point of its surface.
+fn capsule_normal_and_feature<N: RealField + Copy>(
+    capsule: &Capsule<N>,
+    pt: &Point<N>,
+) -> (Vector<N>, FeatureId) {
+    let mut proj = Point::origin();
+    proj.y = pt.y.max(-capsule.half_height).min(capsule.half_height);
+
+    let normal = (pt - proj).try_normalize(N::zero()).unwrap_or_else(|| {
+        let mut normal = Vector::zeros();
+        normal.y = N::one().copysign(pt.y);
+        normal
+    });
+
+    let feature = if pt.y <= -capsule.half_height {
+        FeatureId::Face(0)
+    } else if pt.y >= capsule.half_height {
+        FeatureId::Face(1)
+    } else {
+        #[cfg(feature = "dim2")]
+        {
+            if pt.x >= N::zero() {
+                FeatureId::Face(2)
+            } else {
+                FeatureId::Face(3)
+            }
+        }
+        #[cfg(feature = "dim3")]
+        {
+            FeatureId::Face(2)
+        }
+    };
+
+    (normal, feature)
+}
+
+/// Computes the intersection between a ray and a capsule in its local-space.
+///
+/// The capsule is the union of a cylindrical body and two balls at the extremities of its
+/// axis. Being convex, the ray enters it at the smallest parameter where it enters any of those
+/// three parts, and exits it at the greatest parameter where it exits any of them.
+///
+/// The bottom and top caps are identified by `FeatureId::Face(0)` and `FeatureId::Face(1)`, and
+/// the cylindrical body by `FeatureId::Face(2)` (and `FeatureId::Face(3)` for the side with
+/// negative `x` coordinates in 2D). The axis of a capsule created with
+/// `Capsule::new_with_endpoints` goes from its first endpoint to its second one.
+pub fn ray_intersection_with_capsule<N: RealField + Copy>(
+    capsule: &Capsule<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    match capsule.local_frame() {
+        Some(frame) => {
+            let aligned_ray = ray.inverse_transform_by(frame);
+
+            ray_intersection_with_aligned_capsule(&capsule.aligned(), &aligned_ray, max_toi, solid)
+                .map(|mut res| {
+                    res.normal = frame * res.normal;
+                    res
+                })
+        }
+        None => ray_intersection_with_aligned_capsule(capsule, ray, max_toi, solid),
+    }
+}
+
+// Same as `ray_intersection_with_capsule` for a capsule centered and aligned with the `y` axis.
+fn ray_intersection_with_aligned_capsule<N: RealField + Copy>(
+    capsule: &Capsule<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    let segment = capsule.segment();
+    let parts = [
+        clip_ray_with_ball(&segment.a, capsule.radius, ray),
+        clip_ray_with_ball(&segment.b, capsule.radius, ray),
+        clip_ray_with_capsule_body(capsule, ray),
+    ];
+
+    let mut clip: Option<(N, N)> = None;
+
+    for part in parts.iter().flatten() {
+        clip = Some(match clip {
+            Some(clip) => (clip.0.min(part.0), clip.1.max(part.1)),
+            None => *part,
+        });
+    }
+
+    let (enter, exit) = clip?;
+
+    if exit < N::zero() {
+        return None;
+    }
+
+    if enter < N::zero() {
+        // The ray origin is inside of the capsule.
+        if solid {
+            let (_, feature) = capsule_normal_and_feature(capsule, &ray.point_at(exit));
+            Some(RayIntersection::new(N::zero(), na::zero(), feature))
+        } else if exit <= max_toi {
+            let (normal, feature) = capsule_normal_and_feature(capsule, &ray.point_at(exit));
+            Some(RayIntersection::new(exit, -normal, feature))
+        } else {
+            None
+        }
+    } else if enter <= max_toi {
+        let (normal, feature) = capsule_normal_and_feature(capsule, &ray.point_at(enter));
+        Some(RayIntersection::new(enter, normal, feature))
+    } else {
+        None
+    }
+}
+
+impl<N: RealField + Copy> RayCast<N> for Capsule<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_capsule(self, &ls_ray, max_toi, solid).map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
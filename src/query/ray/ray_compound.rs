@@ -1,13 +1,91 @@
 use crate::bounding_volume::AABB;
 use crate::math::Isometry;
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
-use crate::query::{Ray, RayCast, RayIntersection};
-use crate::shape::Compound;
+use crate::query::visitors::RayInterferencesCollector;
+use crate::query::{statistics, Ray, RayCast, RayIntersection};
+use crate::shape::{Compound, Shape};
 use na::RealField;
 
 // XXX: if solid == false, this might return internal intersection.
 impl<N: RealField + Copy> RayCast<N> for Compound<N> {
     fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
+        // Masks only apply to the filtered queries, so every part is considered here.
+        self.toi_with_ray_with_filter(m, ray, max_toi, solid, None)
+    }
+
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.toi_and_normal_with_ray_with_filter(m, ray, max_toi, solid, None)
+    }
+
+    #[cfg(feature = "dim3")]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut visitor = CompoundRayToiAndNormalVisitor {
+            compound: self,
+            ray: &ls_ray,
+            max_toi,
+            solid,
+            filter: None,
+            with_uvs: true,
+        };
+
+        self.bvt()
+            .best_first_search(&mut visitor)
+            .map(|(_, mut res)| {
+                res.normal = m * res.normal;
+                res
+            })
+    }
+}
+
+impl<N: RealField + Copy> Compound<N> {
+    /// Computes the time of impact between this transformed compound and a ray, ignoring the
+    /// parts whose mask does not share any bit with `filter`.
+    pub fn toi_with_ray_filtered(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        filter: u32,
+    ) -> Option<N> {
+        self.toi_with_ray_with_filter(m, ray, max_toi, solid, Some(filter))
+    }
+
+    /// Computes the time of impact and normal between this transformed compound and a ray,
+    /// ignoring the parts whose mask does not share any bit with `filter`.
+    pub fn toi_and_normal_with_ray_filtered(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        filter: u32,
+    ) -> Option<RayIntersection<N>> {
+        self.toi_and_normal_with_ray_with_filter(m, ray, max_toi, solid, Some(filter))
+    }
+
+    fn toi_with_ray_with_filter(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        filter: Option<u32>,
+    ) -> Option<N> {
         let ls_ray = ray.inverse_transform_by(m);
 
         let mut visitor = CompoundRayToiVisitor {
@@ -15,17 +93,19 @@ impl<N: RealField + Copy> RayCast<N> for Compound<N> {
             ray: &ls_ray,
             max_toi,
             solid: solid,
+            filter,
         };
 
         self.bvt().best_first_search(&mut visitor).map(|res| res.1)
     }
 
-    fn toi_and_normal_with_ray(
+    fn toi_and_normal_with_ray_with_filter(
         &self,
         m: &Isometry<N>,
         ray: &Ray<N>,
         max_toi: N,
         solid: bool,
+        filter: Option<u32>,
     ) -> Option<RayIntersection<N>> {
         let ls_ray = ray.inverse_transform_by(m);
 
@@ -34,6 +114,9 @@ impl<N: RealField + Copy> RayCast<N> for Compound<N> {
             ray: &ls_ray,
             max_toi,
             solid,
+            filter,
+            #[cfg(feature = "dim3")]
+            with_uvs: false,
         };
 
         self.bvt()
@@ -44,8 +127,65 @@ impl<N: RealField + Copy> RayCast<N> for Compound<N> {
             })
     }
 
-    // XXX: We have to implement toi_and_normal_and_uv_with_ray! Otherwise, no uv will be computed
-    // for any of the sub-shapes.
+    /// Computes the time of impact between this transformed compound and a ray, for each part
+    /// hit by the ray.
+    ///
+    /// Returns the index of each intersected part together with its time of impact, sorted by
+    /// increasing time of impact.
+    pub fn toi_with_ray_per_child(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Vec<(usize, N)> {
+        self.toi_with_ray_per_child_with_filter(m, ray, max_toi, solid, None)
+    }
+
+    /// Computes the time of impact between this transformed compound and a ray, for each part
+    /// hit by the ray whose mask shares at least one bit with `filter`.
+    pub fn toi_with_ray_per_child_filtered(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        filter: u32,
+    ) -> Vec<(usize, N)> {
+        self.toi_with_ray_per_child_with_filter(m, ray, max_toi, solid, Some(filter))
+    }
+
+    fn toi_with_ray_per_child_with_filter(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+        filter: Option<u32>,
+    ) -> Vec<(usize, N)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        let mut candidates = Vec::new();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(&ls_ray, max_toi, &mut candidates);
+            self.bvt().visit(&mut visitor);
+        }
+
+        let mut res: Vec<_> = candidates
+            .into_iter()
+            .filter(|i| self.child_matches_optional_filter(*i, filter))
+            .filter_map(|i| {
+                statistics::count_primitive_test();
+                let elt = &self.shapes()[i];
+                elt.1
+                    .toi_with_ray(&elt.0, &ls_ray, max_toi, solid)
+                    .map(|toi| (i, toi))
+            })
+            .collect();
+
+        res.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+        res
+    }
 }
 
 /*
@@ -56,6 +196,7 @@ struct CompoundRayToiVisitor<'a, N: 'a + RealField + Copy> {
     ray: &'a Ray<N>,
     max_toi: N,
     solid: bool,
+    filter: Option<u32>,
 }
 
 impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for CompoundRayToiVisitor<'a, N> {
@@ -75,7 +216,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for CompoundRa
             };
 
             if let Some(b) = data {
-                if toi < best {
+                if toi < best && self.compound.child_matches_optional_filter(*b, self.filter) {
+                    statistics::count_primitive_test();
                     let elt = &self.compound.shapes()[*b];
                     if let Some(toi) =
                         elt.1
@@ -101,6 +243,28 @@ struct CompoundRayToiAndNormalVisitor<'a, N: 'a + RealField + Copy> {
     ray: &'a Ray<N>,
     max_toi: N,
     solid: bool,
+    filter: Option<u32>,
+    // Whether the texture coordinates of the parts should be computed too.
+    #[cfg(feature = "dim3")]
+    with_uvs: bool,
+}
+
+impl<'a, N: RealField + Copy> CompoundRayToiAndNormalVisitor<'a, N> {
+    #[cfg(feature = "dim2")]
+    #[inline]
+    fn cast_on_part(&self, m: &Isometry<N>, part: &dyn Shape<N>) -> Option<RayIntersection<N>> {
+        part.toi_and_normal_with_ray(m, self.ray, self.max_toi, self.solid)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn cast_on_part(&self, m: &Isometry<N>, part: &dyn Shape<N>) -> Option<RayIntersection<N>> {
+        if self.with_uvs {
+            part.toi_and_normal_and_uv_with_ray(m, self.ray, self.max_toi, self.solid)
+        } else {
+            part.toi_and_normal_with_ray(m, self.ray, self.max_toi, self.solid)
+        }
+    }
 }
 
 impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
@@ -122,12 +286,10 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
             };
 
             if let Some(b) = data {
-                if toi < best {
+                if toi < best && self.compound.child_matches_optional_filter(*b, self.filter) {
+                    statistics::count_primitive_test();
                     let elt = &self.compound.shapes()[*b];
-                    if let Some(toi) =
-                        elt.1
-                            .toi_and_normal_with_ray(&elt.0, self.ray, self.max_toi, self.solid)
-                    {
+                    if let Some(toi) = self.cast_on_part(&elt.0, &*elt.1) {
                         res = BestFirstVisitStatus::Continue {
                             cost: toi.toi,
                             result: Some(toi),
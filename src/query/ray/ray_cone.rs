@@ -0,0 +1,121 @@
+use na::RealField;
+
+use crate::math::{Isometry, Vector};
+use crate::query::ray::ray_cylinder::{
+    clip_ray_bounds, clip_ray_with_slab, ray_intersection_from_bounds, RayBound,
+};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Cone, FeatureId};
+
+/// Computes the intersection between a ray and a cone in its local-space.
+///
+/// The lateral surface of the cone is identified by the feature `FeatureId::Face(0)`, its base
+/// by `FeatureId::Face(1)`, and its apex by `FeatureId::Vertex(0)`.
+pub fn ray_intersection_with_cone<N: RealField + Copy>(
+    cone: &Cone<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    let mut enter = (-N::max_value().unwrap(), None, FeatureId::Unknown);
+    let mut exit = (N::max_value().unwrap(), None, FeatureId::Unknown);
+
+    if !clip_ray_with_slab(
+        ray,
+        cone.half_height,
+        FeatureId::Vertex(0),
+        FeatureId::Face(1),
+        &mut enter,
+        &mut exit,
+    ) {
+        return None;
+    }
+
+    // Intersection with the infinite double cone `x² + z² <= (slope * (h - y))²` where the apex
+    // is located at `y = h`. Its points are such that `qa * t² + 2 * qb * t + qc >= 0`.
+    let _2: N = na::convert(2.0f64);
+    let slope = cone.radius / (cone.half_height * _2);
+    let sq_slope = slope * slope;
+    let apex_height = cone.half_height - ray.origin.y;
+    let dir_down = -ray.dir.y;
+
+    let qa = sq_slope * dir_down * dir_down - ray.dir.x * ray.dir.x - ray.dir.z * ray.dir.z;
+    let qb =
+        sq_slope * apex_height * dir_down - ray.origin.x * ray.dir.x - ray.origin.z * ray.dir.z;
+    let qc = sq_slope * apex_height * apex_height
+        - ray.origin.x * ray.origin.x
+        - ray.origin.z * ray.origin.z;
+
+    let lateral = |toi: N| -> RayBound<N> { (toi, None, FeatureId::Face(0)) };
+    let unbounded_enter = (-N::max_value().unwrap(), None, FeatureId::Unknown);
+    let unbounded_exit = (N::max_value().unwrap(), None, FeatureId::Unknown);
+
+    if qa.is_zero() {
+        // The ray is parallel to a generatrix of the cone.
+        if qb.is_zero() {
+            if qc < N::zero() {
+                return None;
+            }
+        } else {
+            let toi = -qc / (qb * _2);
+
+            if qb > N::zero() {
+                clip_ray_bounds(&mut enter, &mut exit, lateral(toi), unbounded_exit);
+            } else {
+                clip_ray_bounds(&mut enter, &mut exit, unbounded_enter, lateral(toi));
+            }
+        }
+    } else {
+        let delta = qb * qb - qa * qc;
+
+        if delta < N::zero() {
+            // The sign of the quadratic is the sign of `qa` everywhere.
+            if qa < N::zero() {
+                return None;
+            }
+        } else {
+            let sqrt_delta = delta.sqrt();
+            let r0 = (-qb - sqrt_delta) / qa;
+            let r1 = (-qb + sqrt_delta) / qa;
+            let (t0, t1) = if r0 <= r1 { (r0, r1) } else { (r1, r0) };
+
+            if qa < N::zero() {
+                clip_ray_bounds(&mut enter, &mut exit, lateral(t0), lateral(t1));
+            } else {
+                // The ray crosses both nappes of the double cone. Only one of the two parts
+                // of the ray inside of it are within the slab, except for the apex.
+                let (mut enter0, mut exit0) = (enter, exit);
+                clip_ray_bounds(&mut enter0, &mut exit0, unbounded_enter, lateral(t0));
+                clip_ray_bounds(&mut enter, &mut exit, lateral(t1), unbounded_exit);
+
+                if exit0.0 - enter0.0 > exit.0 - enter.0 {
+                    enter = enter0;
+                    exit = exit0;
+                }
+            }
+        }
+    }
+
+    ray_intersection_from_bounds(ray, enter, exit, max_toi, solid, |pt| {
+        Vector::new(pt.x, sq_slope * (cone.half_height - pt.y), pt.z)
+            .try_normalize(N::zero())
+            .unwrap_or_else(Vector::y)
+    })
+}
+
+impl<N: RealField + Copy> RayCast<N> for Cone<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_cone(self, &ls_ray, max_toi, solid).map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
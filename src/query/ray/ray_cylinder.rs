@@ -0,0 +1,171 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Cylinder, FeatureId};
+
+/// A point where a ray enters or exits a shape.
+///
+/// The normal is `None` if it has to be computed from the impact point.
+pub(crate) type RayBound<N> = (N, Option<Vector<N>>, FeatureId);
+
+/// Intersects the parameter interval `[enter, exit]` of a ray with the interval `[t0, t1]`.
+pub(crate) fn clip_ray_bounds<N: RealField + Copy>(
+    enter: &mut RayBound<N>,
+    exit: &mut RayBound<N>,
+    t0: RayBound<N>,
+    t1: RayBound<N>,
+) {
+    if t0.0 > enter.0 {
+        *enter = t0;
+    }
+
+    if t1.0 < exit.0 {
+        *exit = t1;
+    }
+}
+
+/// Intersects the parameter interval of a ray with the slab `-half_height <= y <= half_height`.
+///
+/// Returns `false` if the ray does not intersect the slab. The top and bottom planes yield the
+/// features `top` and `bottom`.
+pub(crate) fn clip_ray_with_slab<N: RealField + Copy>(
+    ray: &Ray<N>,
+    half_height: N,
+    top: FeatureId,
+    bottom: FeatureId,
+    enter: &mut RayBound<N>,
+    exit: &mut RayBound<N>,
+) -> bool {
+    if ray.dir.y.is_zero() {
+        return ray.origin.y.abs() <= half_height;
+    }
+
+    let t_top = (half_height - ray.origin.y) / ray.dir.y;
+    let t_bottom = (-half_height - ray.origin.y) / ray.dir.y;
+    let top = (t_top, Some(Vector::y()), top);
+    let bottom = (t_bottom, Some(-Vector::y()), bottom);
+
+    if ray.dir.y > N::zero() {
+        clip_ray_bounds(enter, exit, bottom, top);
+    } else {
+        clip_ray_bounds(enter, exit, top, bottom);
+    }
+
+    enter.0 <= exit.0
+}
+
+/// Selects the impact of a ray from the parameters where it enters and exits a convex shape.
+///
+/// The outward normal of the lateral surface at the impact point is computed by `normal`.
+pub(crate) fn ray_intersection_from_bounds<N: RealField + Copy>(
+    ray: &Ray<N>,
+    enter: RayBound<N>,
+    exit: RayBound<N>,
+    max_toi: N,
+    solid: bool,
+    normal: impl Fn(&Point<N>) -> Vector<N>,
+) -> Option<RayIntersection<N>> {
+    if enter.0 > exit.0 || exit.0 < N::zero() {
+        return None;
+    }
+
+    let compute_normal =
+        |bound: &RayBound<N>| bound.1.unwrap_or_else(|| normal(&ray.point_at(bound.0)));
+
+    if enter.0 < N::zero() {
+        // The ray origin is inside of the shape.
+        if solid {
+            Some(RayIntersection::new(N::zero(), na::zero(), exit.2))
+        } else if exit.0 <= max_toi {
+            Some(RayIntersection::new(exit.0, -compute_normal(&exit), exit.2))
+        } else {
+            None
+        }
+    } else if enter.0 <= max_toi {
+        Some(RayIntersection::new(
+            enter.0,
+            compute_normal(&enter),
+            enter.2,
+        ))
+    } else {
+        None
+    }
+}
+
+/// Computes the intersection between a ray and a cylinder in its local-space.
+///
+/// The lateral surface of the cylinder is identified by the feature `FeatureId::Face(0)`, and its
+/// top and bottom caps by `FeatureId::Face(1)` and `FeatureId::Face(2)`.
+pub fn ray_intersection_with_cylinder<N: RealField + Copy>(
+    cylinder: &Cylinder<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    let mut enter = (-N::max_value().unwrap(), None, FeatureId::Unknown);
+    let mut exit = (N::max_value().unwrap(), None, FeatureId::Unknown);
+
+    if !clip_ray_with_slab(
+        ray,
+        cylinder.half_height,
+        FeatureId::Face(1),
+        FeatureId::Face(2),
+        &mut enter,
+        &mut exit,
+    ) {
+        return None;
+    }
+
+    // Intersection with the infinite cylinder.
+    let a = ray.dir.x * ray.dir.x + ray.dir.z * ray.dir.z;
+    let b = ray.origin.x * ray.dir.x + ray.origin.z * ray.dir.z;
+    let c = ray.origin.x * ray.origin.x + ray.origin.z * ray.origin.z
+        - cylinder.radius * cylinder.radius;
+
+    if a.is_zero() {
+        if c > N::zero() {
+            return None;
+        }
+    } else {
+        let delta = b * b - a * c;
+
+        if delta < N::zero() {
+            return None;
+        }
+
+        let sqrt_delta = delta.sqrt();
+        let t0 = (-b - sqrt_delta) / a;
+        let t1 = (-b + sqrt_delta) / a;
+
+        clip_ray_bounds(
+            &mut enter,
+            &mut exit,
+            (t0, None, FeatureId::Face(0)),
+            (t1, None, FeatureId::Face(0)),
+        );
+    }
+
+    ray_intersection_from_bounds(ray, enter, exit, max_toi, solid, |pt| {
+        Vector::new(pt.x, N::zero(), pt.z)
+            .try_normalize(N::zero())
+            .unwrap_or_else(Vector::x)
+    })
+}
+
+impl<N: RealField + Copy> RayCast<N> for Cylinder<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_cylinder(self, &ls_ray, max_toi, solid).map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
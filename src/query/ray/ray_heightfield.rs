@@ -43,12 +43,12 @@ impl<N: RealField + Copy> RayCast<N> for HeightField<N> {
          */
         if let Some(seg) = self.segment_at(curr) {
             let (s, t) = query::closest_points_line_line_parameters(
-                &ray.origin,
-                &ray.dir,
+                &ls_ray.origin,
+                &ls_ray.dir,
                 &seg.a,
                 &seg.scaled_direction(),
             );
-            if s >= N::zero() && t >= N::zero() && t <= N::one() {
+            if s >= N::zero() && s <= max_toi && t >= N::zero() && t <= N::one() {
                 // Cast succeeded on the first element!
                 let n = seg.normal().unwrap().into_inner();
                 let fid = if n.dot(&ls_ray.dir) > N::zero() {
@@ -95,8 +95,8 @@ impl<N: RealField + Copy> RayCast<N> for HeightField<N> {
             if let Some(seg) = self.segment_at(curr) {
                 // TODO: test the y-coordinates (equivalent to an AABB test) before actually computing the intersection.
                 let (s, t) = query::closest_points_line_line_parameters(
-                    &ray.origin,
-                    &ray.dir,
+                    &ls_ray.origin,
+                    &ls_ray.dir,
                     &seg.a,
                     &seg.scaled_direction(),
                 );
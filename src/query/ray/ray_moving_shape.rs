@@ -0,0 +1,44 @@
+use crate::math::{Isometry, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::FeatureId;
+use na::RealField;
+
+/// Computes the time of impact of a ray with a shape translating at the constant velocity `linvel`.
+///
+/// The ray is seen as a point starting at `ray.origin` at the time 0 and moving at the velocity
+/// `ray.dir`. The shape is located at `m` at the time 0. Thus, the returned time of impact `t`
+/// is such that the hit point is `ray.point_at(t)`, and the shape is then located at
+/// `linvel * t` translated by `m`. The returned normal is expressed in world-space and is the same
+/// at the initial and impact positions of the shape because its motion is purely translational.
+///
+/// Times of impact greater than `max_toi` are ignored. The shape is assumed to be solid so a
+/// ray starting inside of it has a time of impact equal to zero.
+pub fn ray_toi_with_moving_shape<N, G>(
+    ray: &Ray<N>,
+    shape: &G,
+    m: &Isometry<N>,
+    linvel: &Vector<N>,
+    max_toi: N,
+) -> Option<RayIntersection<N>>
+where
+    N: RealField + Copy,
+    G: ?Sized + RayCast<N> + PointQuery<N>,
+{
+    // Cast the ray on the shape's frame of reference, where the shape is motionless.
+    let relative_ray = Ray::new(ray.origin, ray.dir - *linvel);
+
+    if relative_ray.dir.norm_squared() <= N::default_epsilon() {
+        // The ray does not move relative to the shape.
+        if shape.contains_point(m, &ray.origin) {
+            Some(RayIntersection::new(
+                N::zero(),
+                Vector::zeros(),
+                FeatureId::Unknown,
+            ))
+        } else {
+            None
+        }
+    } else {
+        shape.toi_and_normal_with_ray(m, &relative_ray, max_toi, true)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::Oriented;
+use na::RealField;
+
+impl<N: RealField + Copy, G: RayCast<N>> RayCast<N> for Oriented<N, G> {
+    #[inline]
+    fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
+        self.shape
+            .toi_with_ray(&(m * self.local_isometry()), ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.shape
+            .toi_and_normal_with_ray(&(m * self.local_isometry()), ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.shape
+            .toi_and_normal_and_uv_with_ray(&(m * self.local_isometry()), ray, max_toi, solid)
+    }
+}
@@ -0,0 +1,171 @@
+//! Traversal of bounding volume hierarchies by packets of coherent rays.
+
+use crate::math::Isometry;
+use crate::partitioning::BVH;
+use crate::query::{statistics, Ray, RayCast, RayIntersection};
+#[cfg(feature = "dim3")]
+use crate::shape::{FeatureId, TriMesh};
+use na::RealField;
+
+/// A set of rays cast together against the same bounding volume hierarchy.
+///
+/// Coherent rays, i.e., rays with close origins and directions like the primary rays of a
+/// camera or a grid of line-of-sight tests, tend to traverse the same nodes of a hierarchy. Casting
+/// them as a single packet lets them share the traversal of the hierarchy: each node is visited at
+/// most once for the whole packet, and only the rays that hit its bounding volume are tested against
+/// its children.
+#[derive(Debug, Clone)]
+pub struct RayPacket<N: RealField + Copy> {
+    /// The rays of this packet.
+    pub rays: Vec<Ray<N>>,
+}
+
+impl<N: RealField + Copy> RayPacket<N> {
+    /// Creates a new packet from a set of rays.
+    pub fn new(rays: Vec<Ray<N>>) -> Self {
+        RayPacket { rays }
+    }
+
+    /// The number of rays in this packet.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rays.len()
+    }
+
+    /// Returns `true` if this packet does not contain any ray.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rays.is_empty()
+    }
+
+    /// Transforms all the rays of this packet by the given isometry.
+    #[inline]
+    pub fn transform_by(&self, m: &Isometry<N>) -> Self {
+        RayPacket::new(self.rays.iter().map(|ray| ray.transform_by(m)).collect())
+    }
+
+    /// Transforms all the rays of this packet by the inverse of the given isometry.
+    #[inline]
+    pub fn inverse_transform_by(&self, m: &Isometry<N>) -> Self {
+        RayPacket::new(
+            self.rays
+                .iter()
+                .map(|ray| ray.inverse_transform_by(m))
+                .collect(),
+        )
+    }
+
+    /// Casts all the rays of this packet on the given bounding volume hierarchy.
+    ///
+    /// The rays and the bounding volumes of `bvh` must be expressed in the same coordinate
+    /// frame. The closure `leaf_cast(data, ray, max_toi)` is called to cast a ray on the content of a
+    /// leaf which bounding volume is hit by this ray. It must return the intersection of the ray
+    /// with this leaf content if its time of impact is smaller than `max_toi`.
+    ///
+    /// Returns, for each ray of this packet, the data of the leaf with the smallest time of impact
+    /// together with the corresponding ray intersection. The i-th element of the result is `None`
+    /// if the i-th ray does not hit anything before `max_toi`.
+    pub fn cast_on_bvh<'a, T, BV>(
+        &self,
+        bvh: &'a impl BVH<T, BV>,
+        max_toi: N,
+        mut leaf_cast: impl FnMut(&'a T, &Ray<N>, N) -> Option<RayIntersection<N>>,
+    ) -> Vec<Option<(&'a T, RayIntersection<N>)>>
+    where
+        BV: 'a + RayCast<N>,
+    {
+        let mut result: Vec<Option<(&'a T, RayIntersection<N>)>> =
+            self.rays.iter().map(|_| None).collect();
+        let mut best_tois: Vec<N> = self.rays.iter().map(|_| max_toi).collect();
+
+        let root = match bvh.root() {
+            Some(root) => root,
+            None => return result,
+        };
+
+        // The indices of the rays still active for each node on the stack are stored
+        // contiguously on a single buffer to avoid allocations. Each stack entry references
+        // a range of this buffer, and these ranges are increasing from the bottom to the top
+        // of the stack.
+        let mut active: Vec<usize> = (0..self.rays.len()).collect();
+        let mut stack = vec![(root, 0, active.len())];
+        let id = Isometry::identity();
+
+        while let Some((node, start, end)) = stack.pop() {
+            // Everything after `end` belongs to nodes that have already been visited.
+            active.truncate(end);
+            let (bv, data) = bvh.content(node);
+            let new_start = active.len();
+
+            for k in start..end {
+                let i = active[k];
+                let ray = &self.rays[i];
+
+                if bv.intersects_ray(&id, ray, best_tois[i]) {
+                    active.push(i);
+                }
+            }
+
+            if active.len() == new_start {
+                continue;
+            }
+
+            if let Some(data) = data {
+                statistics::count_primitive_test();
+                for &i in &active[new_start..] {
+                    if let Some(inter) = leaf_cast(data, &self.rays[i], best_tois[i]) {
+                        if inter.toi <= best_tois[i] {
+                            best_tois[i] = inter.toi;
+                            result[i] = Some((data, inter));
+                        }
+                    }
+                }
+            }
+
+            let new_end = active.len();
+            for c in 0..bvh.num_children(node) {
+                stack.push((bvh.child(c, node), new_start, new_end));
+            }
+        }
+
+        result
+    }
+
+    /// Casts all the rays of this packet on the given triangle mesh.
+    ///
+    /// The rays are expressed in world-space, and the triangle mesh is located at `m`. Returns, for
+    /// each ray, its first intersection with the mesh if its time of impact is smaller than `max_toi`.
+    /// The features and normals of the intersections are the same as the ones computed by
+    /// `mesh.toi_and_normal_with_ray(...)`.
+    #[cfg(feature = "dim3")]
+    pub fn toi_and_normal_with_trimesh(
+        &self,
+        m: &Isometry<N>,
+        mesh: &TriMesh<N>,
+        max_toi: N,
+    ) -> Vec<Option<RayIntersection<N>>> {
+        let ls_packet = self.inverse_transform_by(m);
+        let id = Isometry::identity();
+        let nfaces = mesh.faces().len();
+
+        ls_packet
+            .cast_on_bvh(mesh.bvt(), max_toi, |face, ray, max_toi| {
+                mesh.triangle_at(*face)
+                    .toi_and_normal_with_ray(&id, ray, max_toi, true)
+            })
+            .into_iter()
+            .map(|hit| {
+                hit.map(|(face, mut inter)| {
+                    if let FeatureId::Face(1) = inter.feature {
+                        inter.feature = FeatureId::Face(*face + nfaces);
+                    } else {
+                        inter.feature = FeatureId::Face(*face);
+                    }
+
+                    inter.normal = m * inter.normal;
+                    inter
+                })
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,8 @@
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::Isometry;
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor, BVH};
-use crate::query::{Ray, RayCast, RayIntersection};
+use crate::query::ray::ray_support_map::ray_intersection_with_dilated_support_map;
+use crate::query::{statistics, Ray, RayCast, RayIntersection};
 use crate::shape::{FeatureId, Polyline};
 use na::RealField;
 
@@ -50,6 +51,30 @@ impl<N: RealField + Copy> RayCast<N> for Polyline<N> {
     }
 }
 
+// Casts a ray on the i-th edge of `polyline`, as a capsule if the polyline is thick.
+fn ray_intersection_with_edge<N: RealField + Copy>(
+    polyline: &Polyline<N>,
+    i: usize,
+    ray: &Ray<N>,
+    max_toi: N,
+) -> Option<RayIntersection<N>> {
+    let segment = polyline.segment_at(i);
+    let thickness = polyline.thickness();
+
+    if thickness.is_zero() {
+        // FIXME: optimize this by not using Isometry identity.
+        return segment.toi_and_normal_with_ray(&Isometry::identity(), ray, max_toi, true);
+    }
+
+    let mut inter = ray_intersection_with_dilated_support_map(&segment, thickness, ray, max_toi)?;
+    inter.feature = match polyline.edges()[i].normal {
+        Some(n) if n.dot(&inter.normal) < N::zero() => FeatureId::Face(1),
+        _ => FeatureId::Face(0),
+    };
+
+    Some(inter)
+}
+
 /*
  * Costs functions.
  */
@@ -69,6 +94,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for PolylineRa
         aabb: &AABB<N>,
         data: Option<&usize>,
     ) -> BestFirstVisitStatus<N, Self::Result> {
+        let aabb = aabb.loosened(self.polyline.thickness());
+
         if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true) {
             let mut res = BestFirstVisitStatus::Continue {
                 cost: toi,
@@ -77,14 +104,13 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for PolylineRa
 
             if let Some(b) = data {
                 if toi < best {
-                    // FIXME: optimize this by not using Isometry identity.
-                    let segment = self.polyline.segment_at(*b);
-                    if let Some(toi) =
-                        segment.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true)
+                    statistics::count_primitive_test();
+                    if let Some(inter) =
+                        ray_intersection_with_edge(self.polyline, *b, self.ray, self.max_toi)
                     {
                         res = BestFirstVisitStatus::Continue {
-                            cost: toi,
-                            result: Some(toi),
+                            cost: inter.toi,
+                            result: Some(inter.toi),
                         }
                     }
                 }
@@ -115,6 +141,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
         aabb: &AABB<N>,
         data: Option<&usize>,
     ) -> BestFirstVisitStatus<N, Self::Result> {
+        let aabb = aabb.loosened(self.polyline.thickness());
+
         if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true) {
             let mut res = BestFirstVisitStatus::Continue {
                 cost: toi,
@@ -123,14 +151,10 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
 
             if let Some(b) = data {
                 if toi < best {
-                    // FIXME: optimize this by not using Isometry identity.
-                    let segment = self.polyline.segment_at(*b);
-                    if let Some(toi) = segment.toi_and_normal_with_ray(
-                        &Isometry::identity(),
-                        self.ray,
-                        self.max_toi,
-                        true,
-                    ) {
+                    statistics::count_primitive_test();
+                    if let Some(toi) =
+                        ray_intersection_with_edge(self.polyline, *b, self.ray, self.max_toi)
+                    {
                         res = BestFirstVisitStatus::Continue {
                             cost: toi.toi,
                             result: Some((*b, toi)),
@@ -0,0 +1,77 @@
+use na::{Point2, RealField};
+
+use crate::math::{Isometry, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Quad};
+
+impl<N: RealField + Copy> Quad<N> {
+    fn local_ray_intersection(
+        &self,
+        ls_ray: &Ray<N>,
+        max_toi: N,
+    ) -> Option<(RayIntersection<N>, Point2<N>)> {
+        // The ray is parallel to the quad.
+        if ls_ray.dir.y.is_zero() {
+            return None;
+        }
+
+        let toi = -ls_ray.origin.y / ls_ray.dir.y;
+
+        if toi < N::zero() || toi > max_toi {
+            return None;
+        }
+
+        let hit = ls_ray.point_at(toi);
+        let (half_x, half_z) = (self.half_extents.x, self.half_extents.y);
+
+        if hit.x.abs() > half_x || hit.z.abs() > half_z {
+            return None;
+        }
+
+        let (normal, feature) = if ls_ray.dir.y < N::zero() {
+            (Vector::y(), FeatureId::Face(0))
+        } else {
+            (-Vector::y(), FeatureId::Face(1))
+        };
+
+        let _0_5: N = na::convert(0.5);
+        let uvs = Point2::new(
+            (hit.x / half_x + N::one()) * _0_5,
+            (hit.z / half_z + N::one()) * _0_5,
+        );
+
+        Some((RayIntersection::new(toi, normal, feature), uvs))
+    }
+}
+
+impl<N: RealField + Copy> RayCast<N> for Quad<N> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        _: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        self.local_ray_intersection(&ls_ray, max_toi)
+            .map(|(inter, _)| RayIntersection::new(inter.toi, m * inter.normal, inter.feature))
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        _: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        self.local_ray_intersection(&ls_ray, max_toi)
+            .map(|(inter, uvs)| {
+                RayIntersection::new_with_uvs(inter.toi, m * inter.normal, inter.feature, Some(uvs))
+            })
+    }
+}
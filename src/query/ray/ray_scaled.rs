@@ -0,0 +1,65 @@
+use crate::math::Isometry;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{Scaled, Shape};
+use na::RealField;
+
+impl<N: RealField + Copy, G: Shape<N>> Scaled<N, G> {
+    fn unscaled_ray(&self, m: &Isometry<N>, ray: &Ray<N>) -> Ray<N> {
+        let ls_ray = ray.inverse_transform_by(m);
+        // The time of impact is preserved because both the origin and direction are unscaled.
+        Ray::new(
+            self.unscale_point(&ls_ray.origin),
+            ls_ray.dir.component_div(&self.scale),
+        )
+    }
+
+    fn ray_cast(&self) -> &dyn RayCast<N> {
+        self.shape
+            .as_ray_cast()
+            .expect("No RayCast implementation for the scaled shape.")
+    }
+}
+
+impl<N: RealField + Copy, G: Shape<N>> RayCast<N> for Scaled<N, G> {
+    #[inline]
+    fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
+        let ray = self.unscaled_ray(m, ray);
+        self.ray_cast()
+            .toi_with_ray(&Isometry::identity(), &ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ray = self.unscaled_ray(m, ray);
+        self.ray_cast()
+            .toi_and_normal_with_ray(&Isometry::identity(), &ray, max_toi, solid)
+            .map(|mut inter| {
+                inter.normal = m * self.scale_normal(&inter.normal);
+                inter
+            })
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ray = self.unscaled_ray(m, ray);
+        self.ray_cast()
+            .toi_and_normal_and_uv_with_ray(&Isometry::identity(), &ray, max_toi, solid)
+            .map(|mut inter| {
+                inter.normal = m * self.scale_normal(&inter.normal);
+                inter
+            })
+    }
+}
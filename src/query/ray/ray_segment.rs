@@ -0,0 +1,65 @@
+use crate::math::Isometry;
+use crate::query::Ray;
+use crate::shape::{Segment, Shape};
+use na::RealField;
+
+/// Tests if any part of the segment `seg` intersects the shape `shape` transformed by `m`.
+///
+/// Solid shapes block the segment even if it is entirely contained inside of them. This is
+/// typically what line-of-sight checks need. Shapes that do not support ray casting never
+/// intersect any segment.
+pub fn segment_intersects_shape<N: RealField + Copy>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    seg: &Segment<N>,
+) -> bool {
+    if seg.a == seg.b {
+        return shape
+            .as_point_query()
+            .map(|q| q.contains_point(m, &seg.a))
+            .unwrap_or(false);
+    }
+
+    let ray = Ray::new(seg.a, seg.scaled_direction());
+
+    shape
+        .as_ray_cast()
+        .map(|r| r.intersects_ray(m, &ray, N::one()))
+        .unwrap_or(false)
+}
+
+/// Computes the part of the segment `seg` lying inside of the shape `shape` transformed by `m`.
+///
+/// Returns `None` if the segment does not intersect the shape. For non-convex shapes, or shapes
+/// without interior like triangle meshes, the result goes from the first to the last
+/// intersection between the segment and the shape boundary, so it may contain parts located
+/// outside of the shape.
+pub fn clip_segment_with_shape<N: RealField + Copy>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    seg: &Segment<N>,
+) -> Option<Segment<N>> {
+    if seg.a == seg.b {
+        return if segment_intersects_shape(m, shape, seg) {
+            Some(*seg)
+        } else {
+            None
+        };
+    }
+
+    let ray_cast = shape.as_ray_cast()?;
+    let dir = seg.scaled_direction();
+    let forward = Ray::new(seg.a, dir);
+    let backward = Ray::new(seg.b, -dir);
+
+    let t_enter = ray_cast.toi_with_ray(m, &forward, N::one(), true)?;
+    let t_exit = ray_cast
+        .toi_with_ray(m, &backward, N::one(), true)
+        .map(|t| N::one() - t)
+        .unwrap_or(t_enter);
+
+    Some(Segment::new(
+        forward.point_at(t_enter),
+        forward.point_at(t_exit.max(t_enter)),
+    ))
+}
@@ -1,6 +1,6 @@
 use crate::math::Isometry;
 use crate::query::{Ray, RayCast, RayIntersection};
-use crate::shape::Shape;
+use crate::shape::{Shape, ShapeHandle};
 use na::RealField;
 
 impl<N: RealField + Copy> RayCast<N> for dyn Shape<N> {
@@ -45,3 +45,40 @@ impl<N: RealField + Copy> RayCast<N> for dyn Shape<N> {
             .intersects_ray(m, ray, max_toi)
     }
 }
+
+impl<N: RealField + Copy> RayCast<N> for ShapeHandle<N> {
+    #[inline]
+    fn toi_with_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N, solid: bool) -> Option<N> {
+        self.as_ref().toi_with_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.as_ref()
+            .toi_and_normal_with_ray(m, ray, max_toi, solid)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.as_ref()
+            .toi_and_normal_and_uv_with_ray(m, ray, max_toi, solid)
+    }
+
+    #[inline]
+    fn intersects_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N) -> bool {
+        self.as_ref().intersects_ray(m, ray, max_toi)
+    }
+}
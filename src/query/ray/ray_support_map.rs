@@ -3,13 +3,13 @@ use na::{self, RealField};
 use crate::math::Isometry;
 #[cfg(feature = "dim2")]
 use crate::query;
-use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
+use crate::query::algorithms::{gjk, special_support_maps::DilatedShape, CSOPoint, VoronoiSimplex};
 use crate::query::{Ray, RayCast, RayIntersection};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexHull, Cylinder};
+use crate::shape::{ConvexHull, Superellipsoid};
+use crate::shape::{FeatureId, RoundedCuboid, Segment, SupportMap, Swept};
 
 /// Cast a ray on a shape using the GJK algorithm.
 pub fn ray_intersection_with_support_map_with_params<N, G: ?Sized>(
@@ -59,34 +59,31 @@ where
     }
 }
 
-#[cfg(feature = "dim3")]
-impl<N: RealField + Copy> RayCast<N> for Cylinder<N> {
-    fn toi_and_normal_with_ray(
-        &self,
-        m: &Isometry<N>,
-        ray: &Ray<N>,
-        max_toi: N,
-        solid: bool,
-    ) -> Option<RayIntersection<N>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        ray_intersection_with_support_map_with_params(
-            &Isometry::identity(),
-            self,
-            &mut VoronoiSimplex::new(),
-            &ls_ray,
-            max_toi,
-            solid,
-        )
-        .map(|mut res| {
-            res.normal = m * res.normal;
-            res
-        })
-    }
+/// Casts a solid ray on the Minkowski sum of a shape and a ball of radius `radius`.
+///
+/// Both the shape and the ray are expressed in the same frame.
+pub(crate) fn ray_intersection_with_dilated_support_map<N, G>(
+    shape: &G,
+    radius: N,
+    ray: &Ray<N>,
+    max_toi: N,
+) -> Option<RayIntersection<N>>
+where
+    N: RealField + Copy,
+    G: ?Sized + SupportMap<N>,
+{
+    ray_intersection_with_support_map_with_params(
+        &Isometry::identity(),
+        &DilatedShape { shape, radius },
+        &mut VoronoiSimplex::new(),
+        ray,
+        max_toi,
+        true,
+    )
 }
 
 #[cfg(feature = "dim3")]
-impl<N: RealField + Copy> RayCast<N> for Cone<N> {
+impl<N: RealField + Copy> RayCast<N> for Superellipsoid<N> {
     fn toi_and_normal_with_ray(
         &self,
         m: &Isometry<N>,
@@ -111,7 +108,7 @@ impl<N: RealField + Copy> RayCast<N> for Cone<N> {
     }
 }
 
-impl<N: RealField + Copy> RayCast<N> for Capsule<N> {
+impl<N: RealField + Copy> RayCast<N> for RoundedCuboid<N> {
     fn toi_and_normal_with_ray(
         &self,
         m: &Isometry<N>,
@@ -311,3 +308,28 @@ impl<N: RealField + Copy> RayCast<N> for Segment<N> {
         }
     }
 }
+
+impl<N: RealField + Copy, G: SupportMap<N>> RayCast<N> for Swept<N, G> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_support_map_with_params(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            max_toi,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
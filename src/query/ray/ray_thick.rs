@@ -0,0 +1,76 @@
+use crate::math::{Isometry, Vector};
+use crate::query::{self, DefaultTOIDispatcher, Ray, RayIntersection, TOI};
+use crate::shape::{Ball, FeatureId, Shape, SupportMap};
+use na::RealField;
+
+/// Casts a ray with a thickness against a shape.
+///
+/// The thick ray is the volume swept by a ball of radius `radius` centered at the origin of the
+/// ray and moving along the ray direction, i.e., a capsule of infinite length. The resulting
+/// intersection is the first contact between this ball and the shape: its time of impact is
+/// expressed in units of the ray direction and its normal is the normal of `shape` at the contact
+/// point, in world-space.
+///
+/// Returns `None` if the thick ray does not hit the shape before `max_toi`, or if no
+/// time-of-impact algorithm is known for the shape.
+pub fn thick_ray_cast<N: RealField + Copy>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    ray: &Ray<N>,
+    radius: N,
+    max_toi: N,
+) -> Option<RayIntersection<N>> {
+    let ball = Ball::new(radius);
+    let ball_pos = Isometry::from_parts(ray.origin.coords.into(), na::one());
+
+    query::time_of_impact(
+        &DefaultTOIDispatcher,
+        &ball_pos,
+        &ray.dir,
+        &ball,
+        m,
+        &Vector::zeros(),
+        shape,
+        max_toi,
+        N::zero(),
+    )
+    .ok()?
+    .map(|toi| thick_ray_intersection(m, &toi))
+}
+
+/// Casts a ray with a thickness against a support-mapped shape.
+///
+/// See `thick_ray_cast` for details.
+pub fn thick_ray_cast_with_support_map<N, G>(
+    m: &Isometry<N>,
+    shape: &G,
+    ray: &Ray<N>,
+    radius: N,
+    max_toi: N,
+) -> Option<RayIntersection<N>>
+where
+    N: RealField + Copy,
+    G: ?Sized + SupportMap<N>,
+{
+    let ball = Ball::new(radius);
+    let ball_pos = Isometry::from_parts(ray.origin.coords.into(), na::one());
+
+    query::time_of_impact_support_map_support_map(
+        &ball_pos,
+        &ray.dir,
+        &ball,
+        m,
+        &Vector::zeros(),
+        shape,
+        max_toi,
+        N::zero(),
+    )
+    .map(|toi| thick_ray_intersection(m, &toi))
+}
+
+fn thick_ray_intersection<N: RealField + Copy>(
+    m: &Isometry<N>,
+    toi: &TOI<N>,
+) -> RayIntersection<N> {
+    RayIntersection::new(toi.toi, m * toi.normal2.into_inner(), FeatureId::Unknown)
+}
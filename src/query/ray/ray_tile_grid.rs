@@ -0,0 +1,178 @@
+use crate::math::{Isometry, Vector};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, TileGrid};
+use na::{self, RealField};
+
+impl<N: RealField + Copy> RayCast<N> for TileGrid<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        if self.nrows() == 0 || self.ncols() == 0 || self.edges().is_empty() {
+            return None;
+        }
+
+        let ls_ray = ray.inverse_transform_by(m);
+        let grid_aabb = self.grid_aabb();
+        let (tmin, tmax) = grid_aabb.clip_ray_parameters(&ls_ray)?;
+
+        if tmin > max_toi {
+            return None;
+        }
+
+        let tmax = tmax.min(max_toi);
+        let size = *self.cell_size();
+        let dims = [self.ncols(), self.nrows()];
+        let start = ls_ray.point_at(tmin);
+        let mut cell = [0; 2];
+
+        for k in 0..2 {
+            let coord = start[k] / size[k];
+            let mut c = coord.floor();
+
+            // On a grid line, the ray belongs to the cell it is heading toward.
+            if c == coord && ls_ray.dir[k] < N::zero() {
+                c -= N::one();
+            }
+
+            let c = na::convert_unchecked::<N, f64>(c.max(N::zero())) as usize;
+            cell[k] = c.min(dims[k] - 1);
+        }
+
+        let inside = self.is_solid(cell[1], cell[0]);
+
+        if inside {
+            if tmin.is_zero() {
+                if solid {
+                    return Some(RayIntersection::new(
+                        N::zero(),
+                        Vector::zeros(),
+                        FeatureId::Unknown,
+                    ));
+                }
+            } else {
+                // The ray enters the grid through a solid cell.
+                let mut axis = 0;
+                let mut best = -N::max_value().unwrap();
+
+                for k in 0..2 {
+                    if !ls_ray.dir[k].is_zero() {
+                        let side = if ls_ray.dir[k] > N::zero() {
+                            N::zero()
+                        } else {
+                            grid_aabb.maxs[k]
+                        };
+                        let t = (side - ls_ray.origin[k]) / ls_ray.dir[k];
+
+                        if t > best {
+                            best = t;
+                            axis = k;
+                        }
+                    }
+                }
+
+                let step = ls_ray.dir[axis] > N::zero();
+                let edge = self.face_edge(cell, axis, !step)?;
+                return Some(self.intersection(m, tmin, axis, !step, edge));
+            }
+        }
+
+        // Walk through the cells traversed by the ray (DDA) until it crosses the boundary of the
+        // solid cells.
+        loop {
+            let mut axis = 0;
+            let mut t = N::max_value().unwrap();
+
+            for k in 0..2 {
+                if !ls_ray.dir[k].is_zero() {
+                    let next = if ls_ray.dir[k] > N::zero() {
+                        cell[k] + 1
+                    } else {
+                        cell[k]
+                    };
+                    let tk =
+                        (size[k] * na::convert(next as f64) - ls_ray.origin[k]) / ls_ray.dir[k];
+
+                    if tk < t {
+                        t = tk;
+                        axis = k;
+                    }
+                }
+            }
+
+            if t > tmax {
+                return None;
+            }
+
+            let positive = ls_ray.dir[axis] > N::zero();
+            let edge = self.face_edge(cell, axis, positive);
+
+            if positive {
+                cell[axis] += 1;
+            } else if cell[axis] == 0 {
+                return edge.map(|e| self.back_intersection(m, t, axis, positive, e));
+            } else {
+                cell[axis] -= 1;
+            }
+
+            if cell[axis] >= dims[axis] {
+                return edge.map(|e| self.back_intersection(m, t, axis, positive, e));
+            }
+
+            if self.is_solid(cell[1], cell[0]) != inside {
+                let e = edge?;
+
+                if inside {
+                    return Some(self.back_intersection(m, t, axis, positive, e));
+                } else {
+                    return Some(self.intersection(m, t, axis, !positive, e));
+                }
+            }
+        }
+    }
+}
+
+impl<N: RealField + Copy> TileGrid<N> {
+    // The edge containing the face of `cell` orthogonal to the `axis`-th axis, on its positive or
+    // negative side.
+    fn face_edge(&self, cell: [usize; 2], axis: usize, positive: bool) -> Option<usize> {
+        let shift = if positive { 1 } else { 0 };
+
+        if axis == 0 {
+            self.vertical_face_edge(cell[1], cell[0] + shift)
+        } else {
+            self.horizontal_face_edge(cell[1] + shift, cell[0])
+        }
+    }
+
+    // The intersection with the front side of an edge, with a normal oriented toward the
+    // positive or negative side of the `axis`-th axis.
+    fn intersection(
+        &self,
+        m: &Isometry<N>,
+        toi: N,
+        axis: usize,
+        positive: bool,
+        edge: usize,
+    ) -> RayIntersection<N> {
+        let mut normal = Vector::zeros();
+        normal[axis] = if positive { N::one() } else { -N::one() };
+        RayIntersection::new(toi, m * normal, FeatureId::Face(edge))
+    }
+
+    fn back_intersection(
+        &self,
+        m: &Isometry<N>,
+        toi: N,
+        axis: usize,
+        positive: bool,
+        edge: usize,
+    ) -> RayIntersection<N> {
+        let mut res = self.intersection(m, toi, axis, positive, edge);
+        res.feature = FeatureId::Face(edge + self.edges().len());
+        res
+    }
+}
@@ -28,7 +28,11 @@ impl<N: RealField + Copy> RayCast<N> for Triangle<N> {
 /// Computes the intersection between a triangle and a ray.
 ///
 /// If an intersection is found, the time of impact, the normal and the barycentric coordinates of
-/// the intersection point are returned.
+/// the intersection point are returned. The barycentric coordinates `bcoords` are such that the
+/// intersection point is `a * bcoords.x + b * bcoords.y + c * bcoords.z`. The normal is
+/// oriented toward the side of the triangle containing the ray origin, and the feature is
+/// `FeatureId::Face(0)` if this is the side where `a`, `b`, `c` are counterclockwise, and
+/// `FeatureId::Face(1)` otherwise. A ray parallel to the triangle plane never hits it.
 pub fn ray_intersection_with_triangle<N: RealField + Copy>(
     a: &Point<N>,
     b: &Point<N>,
@@ -1,7 +1,8 @@
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::Isometry;
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{self, Ray, RayCast, RayIntersection};
+use crate::query::ray::ray_support_map::ray_intersection_with_dilated_support_map;
+use crate::query::{self, statistics, Ray, RayCast, RayIntersection};
 use crate::shape::{CompositeShape, FeatureId, TriMesh};
 use na::{Point2, RealField, Vector3};
 
@@ -45,7 +46,7 @@ impl<N: RealField + Copy> RayCast<N> for TriMesh<N> {
                 }
 
                 res.normal = m * res.normal;
-                res
+                res.with_face_data(self.face_data_at(best))
             })
     }
 
@@ -56,7 +57,7 @@ impl<N: RealField + Copy> RayCast<N> for TriMesh<N> {
         max_toi: N,
         solid: bool,
     ) -> Option<RayIntersection<N>> {
-        if self.uvs().is_none() {
+        if self.uvs().is_none() || self.thickness() > N::zero() {
             return self.toi_and_normal_with_ray(m, ray, max_toi, solid);
         }
 
@@ -90,10 +91,36 @@ impl<N: RealField + Copy> RayCast<N> for TriMesh<N> {
             };
 
             RayIntersection::new_with_uvs(toi, m * n, feature, Some(Point2::new(uvx, uvy)))
+                .with_face_data(self.face_data_at(best))
         })
     }
 }
 
+// Casts a ray on the i-th face of `mesh`, dilated by the mesh thickness.
+fn ray_intersection_with_face<N: RealField + Copy>(
+    mesh: &TriMesh<N>,
+    i: usize,
+    ray: &Ray<N>,
+    max_toi: N,
+) -> Option<RayIntersection<N>> {
+    let triangle = mesh.triangle_at(i);
+    let thickness = mesh.thickness();
+
+    if thickness.is_zero() {
+        // FIXME: optimize this by not using Isometry identity.
+        return triangle.toi_and_normal_with_ray(&Isometry::identity(), ray, max_toi, true);
+    }
+
+    let mut inter = ray_intersection_with_dilated_support_map(&triangle, thickness, ray, max_toi)?;
+    // Hits on the side opposite to the triangle normal are reported on its back face.
+    inter.feature = match triangle.normal() {
+        Some(n) if n.dot(&inter.normal) < N::zero() => FeatureId::Face(1),
+        _ => FeatureId::Face(0),
+    };
+
+    Some(inter)
+}
+
 /*
  * Costs functions.
  */
@@ -113,6 +140,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for TriMeshRay
         aabb: &AABB<N>,
         data: Option<&usize>,
     ) -> BestFirstVisitStatus<N, Self::Result> {
+        let aabb = aabb.loosened(self.mesh.thickness());
+
         if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true) {
             let mut res = BestFirstVisitStatus::Continue {
                 cost: toi,
@@ -121,14 +150,13 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>> for TriMeshRay
 
             if let Some(b) = data {
                 if toi < best {
-                    // FIXME: optimize this by not using Isometry identity.
-                    let triangle = self.mesh.triangle_at(*b);
-                    if let Some(toi) =
-                        triangle.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true)
+                    statistics::count_primitive_test();
+                    if let Some(inter) =
+                        ray_intersection_with_face(self.mesh, *b, self.ray, self.max_toi)
                     {
                         res = BestFirstVisitStatus::Continue {
-                            cost: toi,
-                            result: Some(toi),
+                            cost: inter.toi,
+                            result: Some(inter.toi),
                         }
                     }
                 }
@@ -159,6 +187,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
         aabb: &AABB<N>,
         data: Option<&usize>,
     ) -> BestFirstVisitStatus<N, Self::Result> {
+        let aabb = aabb.loosened(self.mesh.thickness());
+
         if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true) {
             let mut res = BestFirstVisitStatus::Continue {
                 cost: toi,
@@ -167,14 +197,10 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
 
             if let Some(b) = data {
                 if toi < best {
-                    // FIXME: optimize this by not using Isometry identity.
-                    let triangle = self.mesh.triangle_at(*b);
-                    if let Some(toi) = triangle.toi_and_normal_with_ray(
-                        &Isometry::identity(),
-                        self.ray,
-                        self.max_toi,
-                        true,
-                    ) {
+                    statistics::count_primitive_test();
+                    if let Some(toi) =
+                        ray_intersection_with_face(self.mesh, *b, self.ray, self.max_toi)
+                    {
                         res = BestFirstVisitStatus::Continue {
                             cost: toi.toi,
                             result: Some((*b, toi)),
@@ -208,6 +234,8 @@ impl<'a, N: RealField + Copy> BestFirstVisitor<N, usize, AABB<N>>
         aabb: &AABB<N>,
         data: Option<&usize>,
     ) -> BestFirstVisitStatus<N, Self::Result> {
+        let aabb = aabb.loosened(self.mesh.thickness());
+
         if let Some(toi) = aabb.toi_with_ray(&Isometry::identity(), self.ray, self.max_toi, true) {
             let mut res = BestFirstVisitStatus::Continue {
                 cost: toi,
@@ -0,0 +1,98 @@
+//! Counters of the elementary operations performed by the geometric queries.
+//!
+//! The counters are only maintained when the `query_statistics` feature is enabled. Otherwise,
+//! the counting functions do nothing and are optimized out.
+
+#[cfg(feature = "query_statistics")]
+use std::cell::Cell;
+
+/// The number of elementary operations performed by the geometric queries of the current
+/// thread.
+#[cfg(feature = "query_statistics")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryStatistics {
+    /// The number of bounding volumes tested during the traversal of bounding volume
+    /// hierarchies.
+    pub bv_tests: usize,
+    /// The number of sub-shapes of composite shapes actually tested after their bounding volume
+    /// test succeeded.
+    pub primitive_tests: usize,
+    /// The number of iterations of the GJK algorithm, including its ray-casting variant.
+    pub gjk_iterations: usize,
+}
+
+#[cfg(feature = "query_statistics")]
+thread_local! {
+    static STATISTICS: Cell<QueryStatistics> = const {
+        Cell::new(QueryStatistics {
+            bv_tests: 0,
+            primitive_tests: 0,
+            gjk_iterations: 0,
+        })
+    };
+}
+
+#[cfg(feature = "query_statistics")]
+impl QueryStatistics {
+    /// The counters accumulated on the current thread since the last reset.
+    pub fn current() -> Self {
+        STATISTICS.with(|s| s.get())
+    }
+
+    /// Resets the counters of the current thread to zero.
+    pub fn reset() {
+        STATISTICS.with(|s| s.set(QueryStatistics::default()))
+    }
+
+    /// Runs `f` and returns its result together with the operations it performed on the
+    /// current thread.
+    ///
+    /// The counters of the current thread are left unchanged by this call, so measurements can
+    /// be nested.
+    pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Self) {
+        let before = Self::current();
+        Self::reset();
+        let result = f();
+        let stats = Self::current();
+        STATISTICS.with(|s| {
+            s.set(QueryStatistics {
+                bv_tests: before.bv_tests + stats.bv_tests,
+                primitive_tests: before.primitive_tests + stats.primitive_tests,
+                gjk_iterations: before.gjk_iterations + stats.gjk_iterations,
+            })
+        });
+
+        (result, stats)
+    }
+}
+
+#[cfg(feature = "query_statistics")]
+#[inline]
+fn increment(f: impl FnOnce(&mut QueryStatistics)) {
+    STATISTICS.with(|s| {
+        let mut stats = s.get();
+        f(&mut stats);
+        s.set(stats)
+    })
+}
+
+/// Records one bounding volume test.
+#[inline]
+pub(crate) fn count_bv_test() {
+    #[cfg(feature = "query_statistics")]
+    increment(|s| s.bv_tests += 1)
+}
+
+/// Records one test of a sub-shape of a composite shape.
+#[inline]
+pub(crate) fn count_primitive_test() {
+    #[cfg(feature = "query_statistics")]
+    increment(|s| s.primitive_tests += 1)
+}
+
+/// Records one GJK iteration.
+#[inline]
+pub(crate) fn count_gjk_iteration() {
+    #[cfg(feature = "query_statistics")]
+    increment(|s| s.gjk_iterations += 1)
+}
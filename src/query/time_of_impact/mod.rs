@@ -1,9 +1,12 @@
 //! Implementation details of the `time_of_impact` function.
 
-pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
+#[cfg(feature = "dim2")]
+pub use self::time_of_impact::time_of_impact_with_angular_velocity;
+pub use self::time_of_impact::{time_of_impact, time_of_impact_local, TOIStatus, TOI};
 pub use self::time_of_impact_ball_ball::time_of_impact_ball_ball;
 pub use self::time_of_impact_composite_shape_shape::{
-    time_of_impact_composite_shape_shape, time_of_impact_shape_composite_shape,
+    time_of_impact_composite_shape_shape, time_of_impact_composite_shape_shape_local,
+    time_of_impact_shape_composite_shape, time_of_impact_shape_composite_shape_local,
 };
 pub use self::time_of_impact_plane_support_map::{
     time_of_impact_plane_support_map, time_of_impact_support_map_plane,
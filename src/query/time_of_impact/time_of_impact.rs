@@ -1,5 +1,7 @@
 use na::{RealField, Unit};
 
+#[cfg(feature = "dim2")]
+use crate::interpolation::{ConstantVelocityRigidMotion, RigidMotion};
 use crate::math::{Isometry, Point, Vector};
 use crate::query::{self, TOIDispatcher, Unsupported};
 use crate::shape::{Ball, Plane, Shape};
@@ -153,3 +155,101 @@ pub fn time_of_impact<N: RealField + Copy>(
         Err(Unsupported)
     }
 }
+
+/// Computes the smallest time at with two 2D shapes moving with constant linear and angular
+/// velocities are separated by a distance smaller or equal to `distance`.
+///
+/// If none of the shapes rotate, this is the same as `time_of_impact` with the positions of the
+/// shapes at the latest of the starting times `t0` of both motions. Otherwise, the time of
+/// impact is computed by `nonlinear_time_of_impact_constant_velocity` so spinning shapes do not
+/// tunnel through each other. In both cases, the returned time of impact is measured the same
+/// way as the times of the motions.
+#[cfg(feature = "dim2")]
+pub fn time_of_impact_with_angular_velocity<N: RealField + Copy>(
+    dispatcher: &dyn TOIDispatcher<N>,
+    motion1: &ConstantVelocityRigidMotion<N>,
+    g1: &dyn Shape<N>,
+    motion2: &ConstantVelocityRigidMotion<N>,
+    g2: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> Result<Option<TOI<N>>, Unsupported> {
+    if motion1.angvel.is_zero() && motion2.angvel.is_zero() {
+        let start = motion1.t0.max(motion2.t0);
+        let toi = time_of_impact(
+            dispatcher,
+            &motion1.position_at_time(start),
+            &motion1.linvel,
+            g1,
+            &motion2.position_at_time(start),
+            &motion2.linvel,
+            g2,
+            max_toi - start,
+            target_distance,
+        )?;
+
+        Ok(toi.map(|mut toi| {
+            toi.toi += start;
+            toi
+        }))
+    } else {
+        Ok(query::nonlinear_time_of_impact_constant_velocity(
+            motion1,
+            g1,
+            motion2,
+            g2,
+            max_toi,
+            target_distance,
+        ))
+    }
+}
+
+/// Computes the smallest time at with two shapes under translational movement are separated by a
+/// distance smaller or equal to `distance`, in the local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1`, e.g. `m1.inverse() * m2`, and `vel12` the
+/// velocity of `g2` relative to `g1` expressed in the local space of `g1`, e.g.
+/// `m1.inverse_transform_vector(&(vel2 - vel1))`.
+pub fn time_of_impact_local<N: RealField + Copy>(
+    dispatcher: &dyn TOIDispatcher<N>,
+    pos12: &Isometry<N>,
+    vel12: &Vector<N>,
+    g1: &dyn Shape<N>,
+    g2: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> Result<Option<TOI<N>>, Unsupported> {
+    if let Some(c1) = g1.as_composite_shape() {
+        Ok(query::time_of_impact_composite_shape_shape_local(
+            dispatcher,
+            pos12,
+            vel12,
+            c1,
+            g2,
+            max_toi,
+            target_distance,
+        ))
+    } else if let Some(c2) = g2.as_composite_shape() {
+        Ok(query::time_of_impact_shape_composite_shape_local(
+            dispatcher,
+            pos12,
+            vel12,
+            g1,
+            c2,
+            max_toi,
+            target_distance,
+        ))
+    } else {
+        time_of_impact(
+            dispatcher,
+            &Isometry::identity(),
+            &Vector::zeros(),
+            g1,
+            pos12,
+            vel12,
+            g2,
+            max_toi,
+            target_distance,
+        )
+    }
+}
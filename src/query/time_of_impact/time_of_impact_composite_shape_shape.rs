@@ -2,7 +2,7 @@ use crate::bounding_volume::bounding_volume::BoundingVolume;
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point, Vector};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{Ray, RayCast, TOIDispatcher, TOI};
+use crate::query::{statistics, Ray, RayCast, TOIDispatcher, TOI};
 use crate::shape::{CompositeShape, Shape};
 use na::{self, RealField};
 
@@ -22,18 +22,15 @@ where
     N: RealField + Copy,
     G1: CompositeShape<N>,
 {
-    let mut visitor = CompositeShapeAgainstAnyTOIVisitor::new(
+    time_of_impact_composite_shape_shape_local(
         dispatcher,
-        m1,
-        vel1,
+        &(m1.inverse() * m2),
+        &m1.inverse_transform_vector(&(*vel2 - *vel1)),
         g1,
-        m2,
-        vel2,
         g2,
         max_toi,
         target_distance,
-    );
-    g1.bvh().best_first_search(&mut visitor).map(|res| res.1)
+    )
 }
 
 /// Time Of Impact of any shape with a composite shape, under translational movement.
@@ -66,17 +63,75 @@ where
     .map(|toi| toi.swapped())
 }
 
+/// Time Of Impact of a composite shape with any other shape, under translational movement,
+/// computed in the local space of the composite shape.
+///
+/// `pos12` is the position of `g2` relative to `g1` and `vel12` the velocity of `g2` relative to
+/// `g1`, expressed in the local space of `g1`.
+pub fn time_of_impact_composite_shape_shape_local<N, G1>(
+    dispatcher: &dyn TOIDispatcher<N>,
+    pos12: &Isometry<N>,
+    vel12: &Vector<N>,
+    g1: &G1,
+    g2: &dyn Shape<N>,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>>
+where
+    N: RealField + Copy,
+    G1: ?Sized + CompositeShape<N>,
+{
+    let mut visitor = CompositeShapeAgainstAnyTOIVisitor::new(
+        dispatcher,
+        pos12,
+        vel12,
+        g1,
+        g2,
+        max_toi,
+        target_distance,
+    );
+    g1.bvh().best_first_search(&mut visitor).map(|res| res.1)
+}
+
+/// Time Of Impact of any shape with a composite shape, under translational movement, computed
+/// in the local space of the first shape.
+///
+/// `pos12` is the position of `g2` relative to `g1` and `vel12` the velocity of `g2` relative to
+/// `g1`, expressed in the local space of `g1`.
+pub fn time_of_impact_shape_composite_shape_local<N, G2>(
+    dispatcher: &dyn TOIDispatcher<N>,
+    pos12: &Isometry<N>,
+    vel12: &Vector<N>,
+    g1: &dyn Shape<N>,
+    g2: &G2,
+    max_toi: N,
+    target_distance: N,
+) -> Option<TOI<N>>
+where
+    N: RealField + Copy,
+    G2: ?Sized + CompositeShape<N>,
+{
+    time_of_impact_composite_shape_shape_local(
+        dispatcher,
+        &pos12.inverse(),
+        &pos12.inverse_transform_vector(&-*vel12),
+        g2,
+        g1,
+        max_toi,
+        target_distance,
+    )
+    .map(|toi| toi.swapped())
+}
+
 struct CompositeShapeAgainstAnyTOIVisitor<'a, N: 'a + RealField + Copy, G1: ?Sized + 'a> {
     dispatcher: &'a dyn TOIDispatcher<N>,
     msum_shift: Vector<N>,
     msum_margin: Vector<N>,
     ray: Ray<N>,
 
-    m1: &'a Isometry<N>,
-    vel1: &'a Vector<N>,
     g1: &'a G1,
-    m2: &'a Isometry<N>,
-    vel2: &'a Vector<N>,
+    pos12: &'a Isometry<N>,
+    vel12: &'a Vector<N>,
     g2: &'a dyn Shape<N>,
     max_toi: N,
     target_distance: N,
@@ -89,31 +144,23 @@ where
 {
     pub fn new(
         dispatcher: &'a dyn TOIDispatcher<N>,
-        m1: &'a Isometry<N>,
-        vel1: &'a Vector<N>,
+        pos12: &'a Isometry<N>,
+        vel12: &'a Vector<N>,
         g1: &'a G1,
-        m2: &'a Isometry<N>,
-        vel2: &'a Vector<N>,
         g2: &'a dyn Shape<N>,
         max_toi: N,
         target_distance: N,
     ) -> CompositeShapeAgainstAnyTOIVisitor<'a, N, G1> {
-        let ls_m2 = m1.inverse() * m2.clone();
-        let ls_aabb2 = g2.aabb(&ls_m2).loosened(target_distance);
+        let ls_aabb2 = g2.aabb(pos12).loosened(target_distance);
 
         CompositeShapeAgainstAnyTOIVisitor {
             dispatcher,
             msum_shift: -ls_aabb2.center().coords,
             msum_margin: ls_aabb2.half_extents(),
-            ray: Ray::new(
-                Point::origin(),
-                m1.inverse_transform_vector(&(*vel2 - *vel1)),
-            ),
-            m1,
-            vel1,
+            ray: Ray::new(Point::origin(), *vel12),
             g1,
-            m2,
-            vel2,
+            pos12,
+            vel12,
             g2,
             max_toi,
             target_distance,
@@ -155,32 +202,34 @@ where
 
             if let Some(b) = data {
                 if toi < best {
-                    self.g1.map_part_at(*b, self.m1, &mut |m1, g1| {
-                        if let Some(toi) = self
-                            .dispatcher
-                            .time_of_impact(
-                                self.dispatcher,
-                                m1,
-                                self.vel1,
-                                g1,
-                                self.m2,
-                                self.vel2,
-                                self.g2,
-                                self.max_toi,
-                                self.target_distance,
-                            )
-                            .unwrap_or(None)
-                        {
-                            if toi.toi > self.max_toi {
-                                res = BestFirstVisitStatus::Stop;
-                            } else {
-                                res = BestFirstVisitStatus::Continue {
-                                    cost: toi.toi,
-                                    result: Some(toi),
+                    statistics::count_primitive_test();
+                    self.g1
+                        .map_part_at(*b, &Isometry::identity(), &mut |m1, g1| {
+                            if let Some(toi) = self
+                                .dispatcher
+                                .time_of_impact(
+                                    self.dispatcher,
+                                    m1,
+                                    &Vector::zeros(),
+                                    g1,
+                                    self.pos12,
+                                    self.vel12,
+                                    self.g2,
+                                    self.max_toi,
+                                    self.target_distance,
+                                )
+                                .unwrap_or(None)
+                            {
+                                if toi.toi > self.max_toi {
+                                    res = BestFirstVisitStatus::Stop;
+                                } else {
+                                    res = BestFirstVisitStatus::Continue {
+                                        cost: toi.toi,
+                                        result: Some(toi),
+                                    }
                                 }
                             }
-                        }
-                    });
+                        });
                 }
             }
 
@@ -0,0 +1,54 @@
+use crate::bounding_volume::BoundingVolumePair;
+use crate::partitioning::{VisitStatus, Visitor};
+use na::RealField;
+use std::marker::PhantomData;
+
+/// Spatial partitioning data structure visitor collecting interferences with a bounding volume
+/// that may have a different type than the bounding volumes of the structure.
+///
+/// For example, this can collect all the leaves of an AABB tree intersecting a bounding sphere.
+pub struct BoundingVolumePairInterferencesCollector<'a, N: 'a, T: 'a, QBV: 'a> {
+    /// The bounding volume used for interference tests.
+    pub bv: &'a QBV,
+    /// The data contained by the nodes with bounding volumes intersecting `self.bv`.
+    pub collector: &'a mut Vec<T>,
+    _point: PhantomData<N>,
+}
+
+impl<'a, N, T, QBV> BoundingVolumePairInterferencesCollector<'a, N, T, QBV>
+where
+    N: RealField + Copy,
+{
+    /// Creates a new `BoundingVolumePairInterferencesCollector`.
+    #[inline]
+    pub fn new(
+        bv: &'a QBV,
+        buffer: &'a mut Vec<T>,
+    ) -> BoundingVolumePairInterferencesCollector<'a, N, T, QBV> {
+        BoundingVolumePairInterferencesCollector {
+            bv,
+            collector: buffer,
+            _point: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, T, QBV, BV> Visitor<T, BV> for BoundingVolumePairInterferencesCollector<'a, N, T, QBV>
+where
+    N: RealField + Copy,
+    T: Clone,
+    QBV: BoundingVolumePair<N, BV>,
+{
+    #[inline]
+    fn visit(&mut self, bv: &BV, t: Option<&T>) -> VisitStatus {
+        if self.bv.intersects_bv(bv) {
+            if let Some(t) = t {
+                self.collector.push(t.clone())
+            }
+
+            VisitStatus::Continue
+        } else {
+            VisitStatus::Stop
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point};
 use crate::partitioning::{BestFirstVisitStatus, BestFirstVisitor};
-use crate::query::{PointProjection, PointQuery};
+use crate::query::{statistics, PointProjection, PointQuery};
 use crate::shape::CompositeShape;
 use na::{self, RealField};
 
@@ -44,6 +44,7 @@ impl<'a, N: RealField + Copy, S: CompositeShape<N> + PointQuery<N>>
 
         if let Some(b) = data {
             if dist < best {
+                statistics::count_primitive_test();
                 self.shape
                     .map_part_at(*b, &Isometry::identity(), &mut |objm, obj| {
                         let proj = obj.project_point(objm, self.point, self.solid);
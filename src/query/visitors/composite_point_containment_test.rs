@@ -1,7 +1,7 @@
 use crate::bounding_volume::BoundingVolume;
 use crate::math::{Isometry, Point};
 use crate::partitioning::{VisitStatus, Visitor};
-use crate::query::PointQuery;
+use crate::query::{statistics, PointQuery};
 use crate::shape::CompositeShape;
 use na::RealField;
 
@@ -22,6 +22,7 @@ impl<'a, N: RealField + Copy, BV: BoundingVolume<N> + PointQuery<N>, S: Composit
     fn visit(&mut self, bv: &BV, b: Option<&usize>) -> VisitStatus {
         if bv.contains_point(&Isometry::identity(), self.point) {
             if let Some(b) = b {
+                statistics::count_primitive_test();
                 self.shape
                     .map_part_at(*b, &Isometry::identity(), &mut |objm, obj| {
                         if obj.contains_point(objm, self.point) {
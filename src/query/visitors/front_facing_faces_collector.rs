@@ -0,0 +1,113 @@
+use crate::bounding_volume::SpatializedNormalCone;
+use crate::math::{Isometry, Vector};
+use crate::partitioning::{VisitStatus, Visitor};
+use crate::query::{Ray, RayCast};
+use na::{RealField, Unit};
+
+/// Bounding Volume Tree visitor collecting the leaves which normals may face toward a
+/// given view direction.
+///
+/// A face is front-facing if its normal forms an angle greater than `π / 2` with the view
+/// direction. Any node which normal cone is entirely contained by the half-space of
+/// back-facing directions is pruned, together with all its descendants.
+pub struct FrontFacingFacesCollector<'a, N: 'a + RealField + Copy, T: 'a> {
+    /// The view direction.
+    pub view_dir: Unit<Vector<N>>,
+    /// The data contained by the leaves which normal cone is not back-facing.
+    pub collector: &'a mut Vec<T>,
+}
+
+impl<'a, N: RealField + Copy, T> FrontFacingFacesCollector<'a, N, T> {
+    /// Creates a new `FrontFacingFacesCollector`.
+    #[inline]
+    pub fn new(
+        view_dir: Unit<Vector<N>>,
+        buffer: &'a mut Vec<T>,
+    ) -> FrontFacingFacesCollector<'a, N, T> {
+        FrontFacingFacesCollector {
+            view_dir,
+            collector: buffer,
+        }
+    }
+}
+
+impl<'a, N, T> Visitor<T, SpatializedNormalCone<N>> for FrontFacingFacesCollector<'a, N, T>
+where
+    N: RealField + Copy,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SpatializedNormalCone<N>, t: Option<&T>) -> VisitStatus {
+        if bv.normals.is_within_half_space(&self.view_dir) {
+            VisitStatus::Stop
+        } else {
+            if let Some(t) = t {
+                self.collector.push(t.clone())
+            }
+
+            VisitStatus::Continue
+        }
+    }
+}
+
+/// Bounding Volume Tree visitor collecting interferences with a given ray, ignoring the
+/// leaves facing away from the ray direction.
+///
+/// A node is pruned if its AABB is not hit by the ray, or if all the normals bounded by
+/// its normal cone face the same direction as the ray.
+pub struct FrontFacingRayInterferencesCollector<'a, N: 'a + RealField + Copy, T: 'a> {
+    /// Ray to be tested.
+    pub ray: &'a Ray<N>,
+    /// The maximum allowed time of impact.
+    pub max_toi: N,
+    /// The data contained by the leaves which AABB intersects `self.ray` and which normal
+    /// cone is not back-facing.
+    pub collector: &'a mut Vec<T>,
+    ray_dir: Option<Unit<Vector<N>>>,
+}
+
+impl<'a, N: RealField + Copy, T> FrontFacingRayInterferencesCollector<'a, N, T> {
+    /// Creates a new `FrontFacingRayInterferencesCollector`.
+    #[inline]
+    pub fn new(
+        ray: &'a Ray<N>,
+        max_toi: N,
+        buffer: &'a mut Vec<T>,
+    ) -> FrontFacingRayInterferencesCollector<'a, N, T> {
+        FrontFacingRayInterferencesCollector {
+            ray,
+            max_toi,
+            collector: buffer,
+            ray_dir: Unit::try_new(ray.dir, N::default_epsilon()),
+        }
+    }
+}
+
+impl<'a, N, T> Visitor<T, SpatializedNormalCone<N>>
+    for FrontFacingRayInterferencesCollector<'a, N, T>
+where
+    N: RealField + Copy,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SpatializedNormalCone<N>, t: Option<&T>) -> VisitStatus {
+        if let Some(ray_dir) = self.ray_dir {
+            if bv.normals.is_within_half_space(&ray_dir) {
+                return VisitStatus::Stop;
+            }
+        }
+
+        if bv
+            .aabb
+            .intersects_ray(&Isometry::identity(), self.ray, self.max_toi)
+        {
+            if let Some(t) = t {
+                self.collector.push(t.clone())
+            }
+
+            VisitStatus::Continue
+        } else {
+            VisitStatus::Stop
+        }
+    }
+}
@@ -2,16 +2,24 @@
 
 pub use self::aabb_sets_interferences_collector::AABBSetsInterferencesCollector;
 pub use self::bounding_volume_interferences_collector::BoundingVolumeInterferencesCollector;
+pub use self::bounding_volume_pair_interferences_collector::BoundingVolumePairInterferencesCollector;
 pub use self::composite_closest_point_visitor::CompositeClosestPointVisitor;
 pub use self::composite_point_containment_test::CompositePointContainmentTest;
+pub use self::front_facing_faces_collector::{
+    FrontFacingFacesCollector, FrontFacingRayInterferencesCollector,
+};
 pub use self::point_interferences_collector::PointInterferencesCollector;
 pub use self::ray_interferences_collector::RayInterferencesCollector;
 pub use self::ray_intersection_cost_fn_visitor::RayIntersectionCostFnVisitor;
+pub use self::thick_ray_interferences_collector::ThickRayInterferencesCollector;
 
 mod aabb_sets_interferences_collector;
 mod bounding_volume_interferences_collector;
+mod bounding_volume_pair_interferences_collector;
 mod composite_closest_point_visitor;
 mod composite_point_containment_test;
+mod front_facing_faces_collector;
 mod point_interferences_collector;
 mod ray_interferences_collector;
 mod ray_intersection_cost_fn_visitor;
+mod thick_ray_interferences_collector;
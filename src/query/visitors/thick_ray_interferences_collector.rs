@@ -0,0 +1,62 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::Isometry;
+use crate::partitioning::{VisitStatus, Visitor};
+use crate::query::{Ray, RayCast};
+use na::RealField;
+
+/// Bounding Volume Tree visitor collecting interferences with a ray of non-zero thickness.
+///
+/// The thick ray is the volume swept by a ball of radius `radius` moving along the ray. The
+/// collected objects are only potentially hit by the thick ray: use `query::thick_ray_cast` to
+/// compute the actual intersections.
+pub struct ThickRayInterferencesCollector<'a, N: 'a + RealField + Copy, T: 'a> {
+    /// Ray to be tested.
+    pub ray: &'a Ray<N>,
+    /// The thickness of the ray.
+    pub radius: N,
+    /// The maximum allowed time of impact.
+    pub max_toi: N,
+    /// The data contained by the nodes which bounding volume intersects the thick ray.
+    pub collector: &'a mut Vec<T>,
+}
+
+impl<'a, N: RealField + Copy, T> ThickRayInterferencesCollector<'a, N, T> {
+    /// Creates a new `ThickRayInterferencesCollector`.
+    #[inline]
+    pub fn new(
+        ray: &'a Ray<N>,
+        radius: N,
+        max_toi: N,
+        buffer: &'a mut Vec<T>,
+    ) -> ThickRayInterferencesCollector<'a, N, T> {
+        ThickRayInterferencesCollector {
+            ray,
+            radius,
+            max_toi,
+            collector: buffer,
+        }
+    }
+}
+
+impl<'a, N, T, BV> Visitor<T, BV> for ThickRayInterferencesCollector<'a, N, T>
+where
+    N: RealField + Copy,
+    T: Clone,
+    BV: BoundingVolume<N> + RayCast<N>,
+{
+    #[inline]
+    fn visit(&mut self, bv: &BV, t: Option<&T>) -> VisitStatus {
+        if bv
+            .loosened(self.radius)
+            .intersects_ray(&Isometry::identity(), self.ray, self.max_toi)
+        {
+            if let Some(t) = t {
+                self.collector.push(t.clone())
+            }
+
+            VisitStatus::Continue
+        } else {
+            VisitStatus::Stop
+        }
+    }
+}
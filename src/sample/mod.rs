@@ -0,0 +1,10 @@
+//! Uniform random sampling of points inside and on the boundary of shapes.
+//!
+//! This module requires the `rand` feature.
+
+pub use self::sample_boundary::sample_boundary;
+pub use self::sample_interior::{sample_interior, MAX_REJECTION_ATTEMPTS};
+
+mod sample_boundary;
+mod sample_interior;
+mod sample_utils;
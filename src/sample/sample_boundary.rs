@@ -0,0 +1,227 @@
+use crate::math::{Point, Vector, DIM};
+use crate::sample::sample_utils;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{Ball, Compound, Cuboid, Polyline, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::{ConvexHull, TriMesh};
+use na::{self, RealField};
+use rand::Rng;
+
+/// Samples `n` points uniformly distributed on the boundary of `shape`.
+///
+/// The points are expressed in the local-space of `shape`. Supported shapes are balls,
+/// cuboids, polylines, convex polygons (2D), convex hulls and triangle meshes (3D), and
+/// compounds of those. Polygonal boundaries are sampled proportionally to the length (2D) or
+/// area (3D) of their elements. The boundaries of the parts of a compound are all sampled,
+/// including where they lie inside of another part.
+///
+/// Panics if `shape` is not supported.
+pub fn sample_boundary<N: RealField + Copy, R: Rng + ?Sized>(
+    shape: &dyn Shape<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    if let Some(b) = shape.as_shape::<Ball<N>>() {
+        (0..n)
+            .map(|_| Point::from(sample_utils::uniform_on_unit_sphere(rng) * b.radius))
+            .collect()
+    } else if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+        sample_cuboid_boundary(c, rng, n)
+    } else if let Some(polyline) = shape.as_shape::<Polyline<N>>() {
+        let segments: Vec<_> = (0..polyline.edges().len())
+            .map(|i| polyline.edge_segment(i))
+            .collect();
+        let lengths: Vec<N> = segments.iter().map(|s| s.length()).collect();
+        let counts = sample_utils::distribute_samples(&lengths, rng, n);
+        let mut res = Vec::with_capacity(n);
+
+        for (seg, count) in segments.iter().zip(counts) {
+            for _ in 0..count {
+                res.push(sample_utils::uniform_on_segment(&seg.a, &seg.b, rng))
+            }
+        }
+
+        res
+    } else if let Some(c) = shape.as_shape::<Compound<N>>() {
+        let measures: Vec<N> = c
+            .shapes()
+            .iter()
+            .map(|(_, part)| boundary_measure(&**part))
+            .collect();
+        let counts = sample_utils::distribute_samples(&measures, rng, n);
+        let mut res = Vec::with_capacity(n);
+
+        for ((pos, part), count) in c.shapes().iter().zip(counts) {
+            if count != 0 {
+                res.extend(
+                    sample_boundary(&**part, rng, count)
+                        .into_iter()
+                        .map(|pt| pos * pt),
+                );
+            }
+        }
+
+        res
+    } else {
+        sample_polyhedral_boundary(shape, rng, n)
+    }
+}
+
+fn sample_cuboid_boundary<N: RealField + Copy, R: Rng + ?Sized>(
+    cuboid: &Cuboid<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    let he = cuboid.half_extents;
+    // The measure of the faces orthogonal to each axis.
+    let measures: Vec<N> = (0..DIM)
+        .map(|i| {
+            (0..DIM)
+                .filter(|j| *j != i)
+                .fold(N::one(), |acc, j| acc * he[j])
+        })
+        .collect();
+    let counts = sample_utils::distribute_samples(&measures, rng, n);
+    let mut res = Vec::with_capacity(n);
+
+    for (axis, count) in counts.into_iter().enumerate() {
+        for _ in 0..count {
+            let mut v: Vector<N> = sample_utils::uniform_in_unit_cube(rng);
+            v[axis] = if rng.gen::<bool>() {
+                N::one()
+            } else {
+                -N::one()
+            };
+            res.push(Point::from(v.component_mul(&he)));
+        }
+    }
+
+    res
+}
+
+/// The length (2D) or area (3D) of the boundary of `shape`.
+fn boundary_measure<N: RealField + Copy>(shape: &dyn Shape<N>) -> N {
+    if let Some(b) = shape.as_shape::<Ball<N>>() {
+        ball_boundary_measure(b)
+    } else if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+        cuboid_boundary_measure(c)
+    } else if let Some(polyline) = shape.as_shape::<Polyline<N>>() {
+        polyline.perimeter()
+    } else {
+        polyhedral_elements(shape)
+            .map(|elts| elts.iter().fold(N::zero(), |acc, e| acc + e.measure()))
+            .unwrap_or(N::zero())
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn ball_boundary_measure<N: RealField + Copy>(ball: &Ball<N>) -> N {
+    N::two_pi() * ball.radius
+}
+
+#[cfg(feature = "dim3")]
+fn ball_boundary_measure<N: RealField + Copy>(ball: &Ball<N>) -> N {
+    N::pi() * ball.radius * ball.radius * na::convert(4.0)
+}
+
+#[cfg(feature = "dim2")]
+fn cuboid_boundary_measure<N: RealField + Copy>(cuboid: &Cuboid<N>) -> N {
+    let he = cuboid.half_extents;
+    (he.x + he.y) * na::convert(4.0)
+}
+
+#[cfg(feature = "dim3")]
+fn cuboid_boundary_measure<N: RealField + Copy>(cuboid: &Cuboid<N>) -> N {
+    let he = cuboid.half_extents;
+    (he.x * he.y + he.y * he.z + he.z * he.x) * na::convert(8.0)
+}
+
+#[cfg(feature = "dim2")]
+struct Element<N: RealField + Copy>(Point<N>, Point<N>);
+
+#[cfg(feature = "dim3")]
+struct Element<N: RealField + Copy>(Point<N>, Point<N>, Point<N>);
+
+impl<N: RealField + Copy> Element<N> {
+    #[cfg(feature = "dim2")]
+    fn measure(&self) -> N {
+        na::distance(&self.0, &self.1)
+    }
+
+    #[cfg(feature = "dim3")]
+    fn measure(&self) -> N {
+        (self.1 - self.0).cross(&(self.2 - self.0)).norm() * na::convert(0.5)
+    }
+
+    #[cfg(feature = "dim2")]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<N> {
+        sample_utils::uniform_on_segment(&self.0, &self.1, rng)
+    }
+
+    #[cfg(feature = "dim3")]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<N> {
+        sample_utils::uniform_on_triangle(&self.0, &self.1, &self.2, rng)
+    }
+}
+
+/// The segments (2D) or triangles (3D) forming the boundary of `shape`.
+#[cfg(feature = "dim2")]
+fn polyhedral_elements<N: RealField + Copy>(shape: &dyn Shape<N>) -> Option<Vec<Element<N>>> {
+    let poly = shape.as_shape::<ConvexPolygon<N>>()?;
+    let pts = poly.points();
+
+    Some(
+        (0..pts.len())
+            .map(|i| Element(pts[i], pts[(i + 1) % pts.len()]))
+            .collect(),
+    )
+}
+
+/// The segments (2D) or triangles (3D) forming the boundary of `shape`.
+#[cfg(feature = "dim3")]
+fn polyhedral_elements<N: RealField + Copy>(shape: &dyn Shape<N>) -> Option<Vec<Element<N>>> {
+    if let Some(hull) = shape.as_shape::<ConvexHull<N>>() {
+        let pts = hull.points();
+        let mut res = Vec::new();
+
+        for vids in hull.faces_vertices() {
+            for i in 1..vids.len() - 1 {
+                res.push(Element(pts[vids[0]], pts[vids[i]], pts[vids[i + 1]]));
+            }
+        }
+
+        Some(res)
+    } else if let Some(mesh) = shape.as_shape::<TriMesh<N>>() {
+        let pts = mesh.points();
+
+        Some(
+            mesh.faces()
+                .iter()
+                .map(|f| Element(pts[f.indices.x], pts[f.indices.y], pts[f.indices.z]))
+                .collect(),
+        )
+    } else {
+        None
+    }
+}
+
+fn sample_polyhedral_boundary<N: RealField + Copy, R: Rng + ?Sized>(
+    shape: &dyn Shape<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    let elements = polyhedral_elements(shape)
+        .expect("No algorithm known to sample the boundary of the given shape.");
+    let measures: Vec<N> = elements.iter().map(|e| e.measure()).collect();
+    let counts = sample_utils::distribute_samples(&measures, rng, n);
+    let mut res = Vec::with_capacity(n);
+
+    for (elt, count) in elements.iter().zip(counts) {
+        for _ in 0..count {
+            res.push(elt.sample(rng))
+        }
+    }
+
+    res
+}
@@ -0,0 +1,175 @@
+use crate::math::Point;
+use crate::sample::sample_utils;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{Ball, Compound, Cuboid, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::{ConvexHull, TriMesh};
+#[cfg(feature = "dim3")]
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+use rand::Rng;
+
+/// The maximum number of rejection sampling attempts per point requested from `sample_interior`.
+pub const MAX_REJECTION_ATTEMPTS: usize = 1000;
+
+/// Samples `n` points uniformly distributed inside of `shape`.
+///
+/// The points are expressed in the local-space of `shape`. Supported shapes are balls,
+/// cuboids, convex polygons (2D), convex hulls and closed triangle meshes (3D), and compounds
+/// of those. The parts of a compound are sampled proportionally to their volume, so regions
+/// where parts overlap are sampled more densely.
+///
+/// Triangle meshes are sampled by rejection, with at most `MAX_REJECTION_ATTEMPTS` attempts per
+/// requested point. Fewer than `n` points are returned if the mesh is open or degenerate, or if
+/// its interior fills only a tiny part of its AABB.
+///
+/// Panics if `shape` is not supported.
+pub fn sample_interior<N: RealField + Copy, R: Rng + ?Sized>(
+    shape: &dyn Shape<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    if let Some(b) = shape.as_shape::<Ball<N>>() {
+        (0..n)
+            .map(|_| Point::from(sample_utils::uniform_in_unit_ball(rng) * b.radius))
+            .collect()
+    } else if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+        (0..n)
+            .map(|_| {
+                let v = sample_utils::uniform_in_unit_cube(rng);
+                Point::from(v.component_mul(&c.half_extents))
+            })
+            .collect()
+    } else if let Some(c) = shape.as_shape::<Compound<N>>() {
+        sample_compound_interior(c, rng, n)
+    } else {
+        sample_polyhedral_interior(shape, rng, n)
+    }
+}
+
+fn sample_compound_interior<N: RealField + Copy, R: Rng + ?Sized>(
+    compound: &Compound<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    let volumes: Vec<N> = compound
+        .shapes()
+        .iter()
+        .map(|(_, part)| {
+            part.as_volumetric()
+                .map(|v| v.volume())
+                .unwrap_or(N::zero())
+        })
+        .collect();
+    let counts = sample_utils::distribute_samples(&volumes, rng, n);
+    let mut res = Vec::with_capacity(n);
+
+    for ((pos, part), count) in compound.shapes().iter().zip(counts) {
+        if count != 0 {
+            res.extend(
+                sample_interior(&**part, rng, count)
+                    .into_iter()
+                    .map(|pt| pos * pt),
+            );
+        }
+    }
+
+    res
+}
+
+#[cfg(feature = "dim2")]
+fn sample_polyhedral_interior<N: RealField + Copy, R: Rng + ?Sized>(
+    shape: &dyn Shape<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    if let Some(poly) = shape.as_shape::<ConvexPolygon<N>>() {
+        let pts = poly.points();
+        let areas: Vec<N> = (1..pts.len() - 1)
+            .map(|i| (pts[i] - pts[0]).perp(&(pts[i + 1] - pts[0])).abs())
+            .collect();
+        let counts = sample_utils::distribute_samples(&areas, rng, n);
+        let mut res = Vec::with_capacity(n);
+
+        for (i, count) in counts.into_iter().enumerate() {
+            for _ in 0..count {
+                res.push(sample_utils::uniform_on_triangle(
+                    &pts[0],
+                    &pts[i + 1],
+                    &pts[i + 2],
+                    rng,
+                ))
+            }
+        }
+
+        res
+    } else {
+        panic!("No algorithm known to sample the interior of the given shape.")
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn sample_polyhedral_interior<N: RealField + Copy, R: Rng + ?Sized>(
+    shape: &dyn Shape<N>,
+    rng: &mut R,
+    n: usize,
+) -> Vec<Point<N>> {
+    if let Some(hull) = shape.as_shape::<ConvexHull<N>>() {
+        let pts = hull.points();
+        let center = pts.iter().fold(Point::origin(), |acc, pt| acc + pt.coords)
+            / na::convert::<_, N>(pts.len() as f64);
+        let mut tetras = Vec::new();
+        let mut volumes = Vec::new();
+
+        for vids in hull.faces_vertices() {
+            for i in 1..vids.len() - 1 {
+                let (a, b, c) = (pts[vids[0]], pts[vids[i]], pts[vids[i + 1]]);
+                volumes.push((a - center).dot(&(b - center).cross(&(c - center))).abs());
+                tetras.push((a, b, c));
+            }
+        }
+
+        let counts = sample_utils::distribute_samples(&volumes, rng, n);
+        let mut res = Vec::with_capacity(n);
+
+        for ((a, b, c), count) in tetras.into_iter().zip(counts) {
+            for _ in 0..count {
+                res.push(sample_utils::uniform_in_tetrahedron(
+                    &center, &a, &b, &c, rng,
+                ))
+            }
+        }
+
+        res
+    } else if let Some(mesh) = shape.as_shape::<TriMesh<N>>() {
+        // Rejection sampling on the AABB of the mesh.
+        let mut res = Vec::with_capacity(n);
+
+        if mesh.volume().is_zero() {
+            return res;
+        }
+
+        let aabb = mesh.aabb();
+        let (center, half_extents) = (aabb.center(), aabb.half_extents());
+        let _1_2: N = na::convert(0.5);
+        let max_attempts = n.saturating_mul(MAX_REJECTION_ATTEMPTS);
+
+        for _ in 0..max_attempts {
+            if res.len() == n {
+                break;
+            }
+
+            let v = sample_utils::uniform_in_unit_cube(rng);
+            let pt = center + v.component_mul(&half_extents);
+
+            if mesh.winding_number(&pt).abs() > _1_2 {
+                res.push(pt);
+            }
+        }
+
+        res
+    } else {
+        panic!("No algorithm known to sample the interior of the given shape.")
+    }
+}
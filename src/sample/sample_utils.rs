@@ -0,0 +1,134 @@
+use crate::math::{Point, Vector};
+use na::{self, RealField};
+use rand::Rng;
+
+/// A random number uniformly distributed in `[0, 1)`.
+#[inline]
+pub fn uniform<N: RealField + Copy, R: Rng + ?Sized>(rng: &mut R) -> N {
+    na::convert(rng.gen::<f64>())
+}
+
+/// A random vector uniformly distributed in the cube `[-1, 1)^DIM`.
+#[inline]
+pub fn uniform_in_unit_cube<N: RealField + Copy, R: Rng + ?Sized>(rng: &mut R) -> Vector<N> {
+    let _2: N = na::convert(2.0);
+    Vector::from_fn(|_, _| uniform::<N, R>(rng) * _2 - N::one())
+}
+
+/// A random vector uniformly distributed inside of the unit ball.
+pub fn uniform_in_unit_ball<N: RealField + Copy, R: Rng + ?Sized>(rng: &mut R) -> Vector<N> {
+    loop {
+        let v: Vector<N> = uniform_in_unit_cube(rng);
+
+        if v.norm_squared() <= N::one() {
+            return v;
+        }
+    }
+}
+
+/// A random unit vector uniformly distributed on the unit sphere.
+pub fn uniform_on_unit_sphere<N: RealField + Copy, R: Rng + ?Sized>(rng: &mut R) -> Vector<N> {
+    let eps: N = na::convert(1.0e-4);
+
+    loop {
+        let v: Vector<N> = uniform_in_unit_ball(rng);
+        let norm = v.norm();
+
+        if norm > eps {
+            return v / norm;
+        }
+    }
+}
+
+/// A random point uniformly distributed on the segment `[a, b]`.
+#[inline]
+pub fn uniform_on_segment<N: RealField + Copy, R: Rng + ?Sized>(
+    a: &Point<N>,
+    b: &Point<N>,
+    rng: &mut R,
+) -> Point<N> {
+    a + (b - a) * uniform::<N, R>(rng)
+}
+
+/// A random point uniformly distributed on the triangle `abc`.
+pub fn uniform_on_triangle<N: RealField + Copy, R: Rng + ?Sized>(
+    a: &Point<N>,
+    b: &Point<N>,
+    c: &Point<N>,
+    rng: &mut R,
+) -> Point<N> {
+    let mut s: N = uniform(rng);
+    let mut t: N = uniform(rng);
+
+    if s + t > N::one() {
+        s = N::one() - s;
+        t = N::one() - t;
+    }
+
+    a + (b - a) * s + (c - a) * t
+}
+
+/// A random point uniformly distributed inside of the tetrahedron `abcd`.
+#[cfg(feature = "dim3")]
+pub fn uniform_in_tetrahedron<N: RealField + Copy, R: Rng + ?Sized>(
+    a: &Point<N>,
+    b: &Point<N>,
+    c: &Point<N>,
+    d: &Point<N>,
+    rng: &mut R,
+) -> Point<N> {
+    // Fold the unit cube into the unit tetrahedron (Rocchini & Cignoni).
+    let mut s: N = uniform(rng);
+    let mut t: N = uniform(rng);
+    let mut u: N = uniform(rng);
+
+    if s + t > N::one() {
+        s = N::one() - s;
+        t = N::one() - t;
+    }
+
+    if t + u > N::one() {
+        let tmp = u;
+        u = N::one() - s - t;
+        t = N::one() - tmp;
+    } else if s + t + u > N::one() {
+        let tmp = u;
+        u = s + t + u - N::one();
+        s = N::one() - t - tmp;
+    }
+
+    a + (b - a) * s + (c - a) * t + (d - a) * u
+}
+
+/// Distributes `n` samples among several elements, proportionally to their weights.
+///
+/// Returns the number of samples attributed to each element.
+pub fn distribute_samples<N: RealField + Copy, R: Rng + ?Sized>(
+    weights: &[N],
+    rng: &mut R,
+    n: usize,
+) -> Vec<usize> {
+    let mut counts = vec![0; weights.len()];
+    let mut cumulative = Vec::with_capacity(weights.len());
+    let mut total = N::zero();
+
+    for w in weights {
+        total += *w;
+        cumulative.push(total);
+    }
+
+    if weights.is_empty() || total <= N::zero() {
+        return counts;
+    }
+
+    for _ in 0..n {
+        let x = uniform::<N, R>(rng) * total;
+        let i = match cumulative.binary_search_by(|c| c.partial_cmp(&x).unwrap()) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        };
+        counts[i.min(weights.len() - 1)] += 1;
+    }
+
+    counts
+}
@@ -2,6 +2,8 @@ use na::{RealField, Unit};
 
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::SupportMap;
+#[cfg(feature = "dim2")]
+use crate::utils;
 
 /// A Ball shape.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +28,37 @@ impl<N: RealField + Copy> Ball<N> {
     }
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField + Copy> Ball<N> {
+    /// The area of this disk.
+    #[inline]
+    pub fn area(&self) -> N {
+        N::pi() * self.radius * self.radius
+    }
+
+    /// The length of the boundary of this disk.
+    #[inline]
+    pub fn perimeter(&self) -> N {
+        N::two_pi() * self.radius
+    }
+
+    /// The curvature of the boundary of this disk, i.e., the inverse of its radius.
+    #[inline]
+    pub fn curvature(&self) -> N {
+        N::one() / self.radius
+    }
+
+    /// The point and outward normal located at the curvilinear abscissa `s` of the boundary of this disk.
+    ///
+    /// The boundary is traversed counter-clockwise, starting at the point `(radius, 0)`.
+    #[inline]
+    pub fn boundary_point_at(&self, s: N) -> (Point<N>, Unit<Vector<N>>) {
+        let (sin, cos) = utils::sin_cos(s / self.radius);
+        let normal = Unit::new_unchecked(Vector::new(cos, sin));
+        (Point::from(*normal * self.radius), normal)
+    }
+}
+
 impl<N: RealField + Copy> SupportMap<N> for Ball<N> {
     #[inline]
     fn support_point(&self, m: &Isometry<N>, dir: &Vector<N>) -> Point<N> {
@@ -2,18 +2,26 @@
 
 use na::{self, RealField, Unit};
 
-use crate::math::{Point, Vector};
+use crate::math::{Isometry, Point, Rotation, Translation, Vector};
 use crate::query::{Contact, ContactKinematic, ContactPreprocessor};
 use crate::shape::{FeatureId, Segment, SupportMap};
+use crate::utils::rotation_from_y_axis;
 
-/// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
+/// SupportMap description of a capsule shape.
+///
+/// A capsule created with `Capsule::new` is centered at the origin with its principal axis
+/// aligned with the `y` axis. A capsule created with `Capsule::new_with_endpoints` is the
+/// dilation of an arbitrary segment of its local space.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
-pub struct Capsule<N> {
+pub struct Capsule<N: RealField + Copy> {
     /// The half-height of the capsule's cylindrical part.
     pub half_height: N,
     /// The radius of the capsule.
     pub radius: N,
+    // The position of the capsule centered and aligned with the `y` axis that coincides with
+    // this one, if they differ.
+    pub(crate) frame: Option<Isometry<N>>,
 }
 
 impl<N: RealField + Copy> Capsule<N> {
@@ -26,9 +34,51 @@ impl<N: RealField + Copy> Capsule<N> {
         Capsule {
             half_height,
             radius,
+            frame: None,
         }
     }
 
+    /// Creates a new capsule whose cylindrical part joins the points `a` and `b`.
+    ///
+    /// # Arguments:
+    /// * `a` - the center of the first rounded extremity of the capsule.
+    /// * `b` - the center of the second rounded extremity of the capsule.
+    /// * `radius` - radius of the rounded part of the capsule.
+    pub fn new_with_endpoints(a: Point<N>, b: Point<N>, radius: N) -> Capsule<N> {
+        let center = na::center(&a, &b);
+        let half_height = na::distance(&a, &b) * na::convert(0.5);
+        let rotation = Unit::try_new(b - a, N::default_epsilon())
+            .and_then(|axis| rotation_from_y_axis(&axis))
+            .unwrap_or_else(Rotation::identity);
+
+        let mut res = Capsule::new(half_height, radius);
+
+        if center != Point::origin() || rotation != Rotation::identity() {
+            res.frame = Some(Isometry::from_parts(
+                Translation::from(center.coords),
+                rotation,
+            ));
+        }
+
+        res
+    }
+
+    /// The position of this capsule relative to the capsule with the same dimensions centered
+    /// at the origin and aligned with the `y` axis.
+    ///
+    /// This is `None` for capsules created with `Capsule::new`, and for capsules created with
+    /// `Capsule::new_with_endpoints` whose endpoints are symmetric wrt. the origin along `y`.
+    #[inline]
+    pub fn local_frame(&self) -> Option<&Isometry<N>> {
+        self.frame.as_ref()
+    }
+
+    /// The capsule with the same dimensions centered at the origin and aligned with the `y` axis.
+    #[inline]
+    pub fn aligned(&self) -> Capsule<N> {
+        Capsule::new(self.half_height, self.radius)
+    }
+
     /// The capsule half length along its local `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` public field directly.")]
@@ -57,7 +107,10 @@ impl<N: RealField + Copy> Capsule<N> {
         a.y = -self.half_height;
         b.y = self.half_height;
 
-        Segment::new(a, b)
+        match &self.frame {
+            Some(frame) => Segment::new(frame * a, frame * b),
+            None => Segment::new(a, b),
+        }
     }
 
     /// The contact preprocessor to be used for contact determination with this capsule.
@@ -77,9 +130,17 @@ impl<N: RealField + Copy> SupportMap<N> for Capsule<N> {
 
     #[inline]
     fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
-        let mut res: Vector<N> = na::zero();
-        res[1] = self.half_height.copysign(dir[1]);
-        Point::from(res + **dir * self.radius)
+        match &self.frame {
+            Some(frame) => {
+                let local_dir = frame.inverse_transform_unit_vector(dir);
+                frame * self.aligned().local_support_point_toward(&local_dir)
+            }
+            None => {
+                let mut res: Vector<N> = na::zero();
+                res[1] = self.half_height.copysign(dir[1]);
+                Point::from(res + **dir * self.radius)
+            }
+        }
     }
 }
 
@@ -38,4 +38,12 @@ pub trait CompositeShape<N: RealField + Copy> {
 
     /// Gets the acceleration structure of the concave shape.
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>>;
+
+    /// The radius by which each sub-shape is dilated by contact and ray queries.
+    ///
+    /// The AABBs returned by `self.aabb_at` and stored in `self.bvh()` do not include it.
+    #[inline]
+    fn thickness(&self) -> N {
+        N::zero()
+    }
 }
@@ -14,12 +14,22 @@ use std::mem;
 ///
 /// A compound shape is a shape composed of the union of several simpler shape. This is
 /// the main way of creating a concave shape from convex parts. Each parts can have its own
-/// delta transformation to shift or rotate it with regard to the other shapes.
+/// delta transformation to shift or rotate it with regard to the other shapes. Parts may
+/// themselves be compounds; use `flatten` to get an equivalent single-level compound.
+///
+/// Each part also has a 32-bits mask, with all its bits set by default. The `_filtered` variants
+/// of the ray casts and point projections on this compound only consider the parts whose mask
+/// shares at least one bit with the given filter, while the unfiltered queries ignore the masks.
+/// Similarly, contacts are only generated for the parts whose mask shares at least one bit with
+/// `self.contact_filter()`. Filters apply to the parts of this compound only, not to the parts of
+/// nested compounds.
 #[derive(Clone)]
 pub struct Compound<N: RealField + Copy> {
     shapes: Vec<(Isometry<N>, ShapeHandle<N>)>,
     bvt: BVT<usize, AABB<N>>,
     bvs: Vec<AABB<N>>,
+    masks: Vec<u32>,
+    contact_filter: u32,
     nbits: usize,
 }
 
@@ -36,18 +46,21 @@ impl<N: RealField + Copy> Compound<N> {
             bvs.push(bv.clone());
             leaves.push((i, bv));
 
-            if let Some(_comp) = shape.as_composite_shape() {
-                panic!("Nested composite shapes are not allowed.");
+            if shape.as_composite_shape().is_some() && !shape.is_shape::<Compound<N>>() {
+                panic!("Nested composite shapes other than compounds are not allowed.");
             }
         }
 
         let nbits = mem::size_of::<usize>() * 8 - leaves.len().leading_zeros() as usize;
         let bvt = BVT::new_balanced(leaves);
+        let masks = vec![u32::MAX; shapes.len()];
 
         Compound {
             shapes: shapes,
             bvt: bvt,
             bvs: bvs,
+            masks,
+            contact_filter: u32::MAX,
             nbits,
         }
     }
@@ -60,6 +73,99 @@ impl<N: RealField + Copy> Compound<N> {
         &self.shapes[..]
     }
 
+    /// The masks of the shapes of this compound shape.
+    #[inline]
+    pub fn child_masks(&self) -> &[u32] {
+        &self.masks[..]
+    }
+
+    /// Sets the mask of the `i`-th shape of this compound shape.
+    #[inline]
+    pub fn set_child_mask(&mut self, i: usize, mask: u32) {
+        self.masks[i] = mask
+    }
+
+    /// Sets the masks of all the shapes of this compound shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `masks` does not contain exactly one mask per shape.
+    pub fn with_child_masks(mut self, masks: Vec<u32>) -> Self {
+        assert_eq!(
+            masks.len(),
+            self.shapes.len(),
+            "A compound must have exactly one mask per shape."
+        );
+        self.masks = masks;
+        self
+    }
+
+    /// Whether the mask of the `i`-th shape of this compound shares at least one bit with `filter`.
+    #[inline]
+    pub fn child_matches_filter(&self, i: usize, filter: u32) -> bool {
+        self.masks[i] & filter != 0
+    }
+
+    // Unfiltered queries consider every shape, whatever its mask.
+    #[inline]
+    pub(crate) fn child_matches_optional_filter(&self, i: usize, filter: Option<u32>) -> bool {
+        match filter {
+            Some(filter) => self.child_matches_filter(i, filter),
+            None => true,
+        }
+    }
+
+    /// The filter applied to the masks of the shapes of this compound to select the shapes that
+    /// generate contacts.
+    #[inline]
+    pub fn contact_filter(&self) -> u32 {
+        self.contact_filter
+    }
+
+    /// Sets the filter applied to the masks of the shapes of this compound to select the shapes
+    /// that generate contacts.
+    ///
+    /// Contact manifolds with the shapes that no longer match the filter are removed at the next
+    /// contact update.
+    #[inline]
+    pub fn set_contact_filter(&mut self, filter: u32) {
+        self.contact_filter = filter
+    }
+
+    /// Builds a single-level compound equivalent to this one.
+    ///
+    /// Each child `Compound` is recursively replaced by its own children, with their positions
+    /// composed with the position of the nested compound. The mask of each resulting shape is the
+    /// intersection of its own mask with the masks of the compounds it was nested in.
+    pub fn flatten(&self) -> Compound<N> {
+        let mut shapes = Vec::new();
+        let mut masks = Vec::new();
+        self.collect_leaves(&Isometry::identity(), u32::MAX, &mut shapes, &mut masks);
+
+        let mut res = Compound::new(shapes).with_child_masks(masks);
+        res.contact_filter = self.contact_filter;
+        res
+    }
+
+    fn collect_leaves(
+        &self,
+        m: &Isometry<N>,
+        mask: u32,
+        out: &mut Vec<(Isometry<N>, ShapeHandle<N>)>,
+        out_masks: &mut Vec<u32>,
+    ) {
+        for ((delta, shape), child_mask) in self.shapes.iter().zip(self.masks.iter()) {
+            let pos = m * delta;
+
+            if let Some(compound) = shape.as_shape::<Compound<N>>() {
+                compound.collect_leaves(&pos, mask & child_mask, out, out_masks)
+            } else {
+                out.push((pos, shape.clone()));
+                out_masks.push(mask & child_mask);
+            }
+        }
+    }
+
     /// The optimization structure used by this compound shape.
     #[inline]
     pub fn bvt(&self) -> &BVT<usize, AABB<N>> {
@@ -134,6 +240,10 @@ impl<N: RealField + Copy> CompositeShape<N> for Compound<N> {
         _prediction: &ContactPrediction<N>,
         f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>, &dyn ContactPreprocessor<N>),
     ) {
+        if !self.child_matches_filter(i, self.contact_filter) {
+            return;
+        }
+
         let elt = &self.shapes()[i];
         let pos = m * elt.0;
         let proc = CompoundContactProcessor::new(&elt.0, i, self.nbits);
@@ -1,8 +1,8 @@
 //! Support mapping based Cone shape.
 
 use crate::math::{Point, Vector};
-use crate::shape::SupportMap;
-use na::{self, RealField};
+use crate::shape::{Oriented, SupportMap};
+use na::{self, RealField, Unit};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -27,6 +27,14 @@ impl<N: RealField + Copy> Cone<N> {
         }
     }
 
+    /// Rotates this cone so that its principal axis is `axis` instead of the `y` axis.
+    ///
+    /// The principal axis of a cone points from the center of its base to its apex.
+    #[inline]
+    pub fn with_axis(self, axis: Unit<Vector<N>>) -> Oriented<N, Cone<N>> {
+        Oriented::with_axis(self, axis)
+    }
+
     /// The cone half length along the `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` public field directly.")]
@@ -352,6 +352,76 @@ impl<N: RealField + Copy> ConvexHull<N> {
         &self.points[..]
     }
 
+    /// The number of polygonal faces of this convex polyhedron.
+    #[inline]
+    pub fn num_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// The indices, into `self.points()`, of the vertices of the `i`-th face of this convex
+    /// polyhedron, in the order of its contour.
+    #[inline]
+    pub fn face_vertices(&self, i: usize) -> &[usize] {
+        let face = &self.faces[i];
+        &self.vertices_adj_to_face
+            [face.first_vertex_or_edge..face.first_vertex_or_edge + face.num_vertices_or_edges]
+    }
+
+    /// Applies in-place a transformation to this convex polyhedron.
+    pub fn transform_by(&mut self, transform: &Isometry<N>) {
+        for pt in &mut self.points {
+            *pt = transform * *pt
+        }
+
+        for face in &mut self.faces {
+            face.normal = transform * face.normal
+        }
+
+        for edge in &mut self.edges {
+            edge.dir = transform * edge.dir
+        }
+    }
+
+    /// Applies a transformation to this convex polyhedron.
+    pub fn transformed(mut self, transform: &Isometry<N>) -> Self {
+        self.transform_by(transform);
+        self
+    }
+
+    /// Applies in-place a non-uniform scale to this convex polyhedron.
+    ///
+    /// All the components of `scale` must be strictly positive.
+    pub fn scale_by(&mut self, scale: &Vector<N>) {
+        for pt in &mut self.points {
+            pt.coords.component_mul_assign(scale)
+        }
+
+        for face in &mut self.faces {
+            // Normals are transformed by the inverse transpose of the scaling.
+            face.normal = Unit::new_normalize(face.normal.component_div(scale))
+        }
+
+        for edge in &mut self.edges {
+            edge.dir = Unit::new_normalize(edge.dir.component_mul(scale))
+        }
+    }
+
+    /// Applies a non-uniform scale to this convex polyhedron.
+    ///
+    /// All the components of `scale` must be strictly positive.
+    pub fn scaled(mut self, scale: &Vector<N>) -> Self {
+        self.scale_by(scale);
+        self
+    }
+
+    /// The ordered indices of the vertices of each face of this convex polyhedron.
+    pub(crate) fn faces_vertices(&self) -> impl Iterator<Item = &[usize]> {
+        self.faces.iter().map(move |face| {
+            let first = face.first_vertex_or_edge;
+            &self.vertices_adj_to_face[first..first + face.num_vertices_or_edges]
+        })
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
@@ -386,7 +456,7 @@ impl<N: RealField + Copy> ConvexHull<N> {
     }
 
     fn support_feature_id_toward_eps(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
-        let (seps, ceps) = eps.sin_cos();
+        let (seps, ceps) = utils::sin_cos(eps);
         let support_pt_id = utils::point_cloud_support_point_id(local_dir.as_ref(), &self.points);
         let vertex = &self.vertices[support_pt_id];
 
@@ -82,6 +82,45 @@ impl<N: RealField + Copy> ConvexPolygon<N> {
         &self.normals
     }
 
+    /// Applies in-place a transformation to this convex polygon.
+    pub fn transform_by(&mut self, transform: &Isometry<N>) {
+        for pt in &mut self.points {
+            *pt = transform * *pt
+        }
+
+        for normal in &mut self.normals {
+            *normal = transform * *normal
+        }
+    }
+
+    /// Applies a transformation to this convex polygon.
+    pub fn transformed(mut self, transform: &Isometry<N>) -> Self {
+        self.transform_by(transform);
+        self
+    }
+
+    /// Applies in-place a non-uniform scale to this convex polygon.
+    ///
+    /// All the components of `scale` must be strictly positive.
+    pub fn scale_by(&mut self, scale: &Vector<N>) {
+        for pt in &mut self.points {
+            pt.coords.component_mul_assign(scale)
+        }
+
+        for normal in &mut self.normals {
+            // Normals are transformed by the inverse transpose of the scaling.
+            *normal = Unit::new_normalize(normal.component_div(scale))
+        }
+    }
+
+    /// Applies a non-uniform scale to this convex polygon.
+    ///
+    /// All the components of `scale` must be strictly positive.
+    pub fn scaled(mut self, scale: &Vector<N>) -> Self {
+        self.scale_by(scale);
+        self
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
@@ -108,6 +147,98 @@ impl<N: RealField + Copy> ConvexPolygon<N> {
     }
 }
 
+impl<N: RealField + Copy> ConvexPolygon<N> {
+    /// The area of this convex polygon.
+    pub fn area(&self) -> N {
+        polygon_area(&self.points)
+    }
+
+    /// The length of the boundary of this convex polygon.
+    pub fn perimeter(&self) -> N {
+        polygon_perimeter(&self.points)
+    }
+
+    /// The point and outward normal located at the curvilinear abscissa `s` of the boundary of this polygon.
+    ///
+    /// The boundary is traversed counter-clockwise, starting at the first vertex. `s` is taken
+    /// modulo the perimeter of the polygon. The normal on a vertex is the normal of the edge
+    /// starting at this vertex.
+    pub fn boundary_point_at(&self, s: N) -> (Point<N>, Unit<Vector<N>>) {
+        polygon_boundary_point_at(&self.points, &self.normals, s)
+    }
+
+    /// The exterior angle at the `i`-th vertex of this polygon.
+    ///
+    /// This is the discrete curvature of the boundary at this vertex, i.e., the angle the
+    /// boundary turns by when going through it. The exterior angles of a convex polygon add up to `2 * pi`.
+    pub fn vertex_turning_angle(&self, i: usize) -> N {
+        let prev = if i == 0 {
+            self.normals.len() - 1
+        } else {
+            i - 1
+        };
+        self.normals[prev].angle(&self.normals[i])
+    }
+}
+
+/// The absolute area of the polygon with the given vertices.
+pub(crate) fn polygon_area<N: RealField + Copy>(points: &[Point<N>]) -> N {
+    let mut res = N::zero();
+
+    for i in 0..points.len() {
+        let j = (i + 1) % points.len();
+        res += points[i].coords.perp(&points[j].coords);
+    }
+
+    res.abs() * na::convert(0.5)
+}
+
+/// The length of the closed loop passing through the given vertices.
+pub(crate) fn polygon_perimeter<N: RealField + Copy>(points: &[Point<N>]) -> N {
+    let mut res = N::zero();
+
+    for i in 0..points.len() {
+        let j = (i + 1) % points.len();
+        res += na::distance(&points[i], &points[j]);
+    }
+
+    res
+}
+
+/// The point and normal at the curvilinear abscissa `s` of a closed loop.
+///
+/// The normal `normals[i]` is the normal of the edge starting at `points[i]`.
+pub(crate) fn polygon_boundary_point_at<N: RealField + Copy>(
+    points: &[Point<N>],
+    normals: &[Unit<Vector<N>>],
+    s: N,
+) -> (Point<N>, Unit<Vector<N>>) {
+    let perimeter = polygon_perimeter(points);
+    let mut s = s % perimeter;
+
+    if s < N::zero() {
+        s += perimeter;
+    }
+
+    for i in 0..points.len() {
+        let j = (i + 1) % points.len();
+        let length = na::distance(&points[i], &points[j]);
+
+        if s <= length || j == 0 {
+            let t = if length.is_zero() {
+                N::zero()
+            } else {
+                (s / length).min(N::one())
+            };
+            return (points[i] + (points[j] - points[i]) * t, normals[i]);
+        }
+
+        s -= length;
+    }
+
+    (points[0], normals[0])
+}
+
 impl<N: RealField + Copy> SupportMap<N> for ConvexPolygon<N> {
     #[inline]
     fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
@@ -184,7 +315,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for ConvexPolygon<N> {
 
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
         let eps: N = na::convert(f64::consts::PI / 180.0);
-        let ceps = eps.cos();
+        let ceps = utils::cos(eps);
 
         // Check faces.
         for i in 0..self.normals.len() {
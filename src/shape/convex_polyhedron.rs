@@ -81,4 +81,43 @@ pub trait ConvexPolyhedron<N: RealField + Copy>: SupportMap<N> {
 
     /// Retrieve the identifier of the feature which normal cone contains `dir`.
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId;
+
+    /// Checks that the given direction in world-space is on the normal cone of the given `feature`.
+    ///
+    /// The normal cone of a feature is the set of directions toward which this feature is a
+    /// support feature of `self`. A contact normal which is not on the normal cone of the
+    /// feature it lies on is typically a ghost contact, e.g., due to an internal edge of a mesh.
+    fn normal_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let local_dir = m.inverse_transform_unit_vector(dir);
+        let max_dot = self
+            .local_support_point_toward(&local_dir)
+            .coords
+            .dot(&local_dir);
+        let tolerance = N::default_epsilon().sqrt() * (N::one() + max_dot.abs());
+        let on_support = |pt: &Point<N>| pt.coords.dot(&local_dir) >= max_dot - tolerance;
+
+        match feature {
+            FeatureId::Vertex(_) => on_support(&self.vertex(feature)),
+            #[cfg(feature = "dim3")]
+            FeatureId::Edge(_) => {
+                let (a, b, _, _) = self.edge(feature);
+                on_support(&a) && on_support(&b)
+            }
+            FeatureId::Face(_) => {
+                let mut face = ConvexPolygonalFeature::new();
+                self.face(feature, &mut face);
+                let front = face
+                    .normal
+                    .map_or(true, |n| n.dot(&local_dir) >= -tolerance);
+
+                front && face.vertices().iter().all(on_support)
+            }
+            FeatureId::Unknown => false,
+        }
+    }
 }
@@ -1,7 +1,10 @@
 //! Support mapping based Cuboid shape.
 
 use crate::math::{Isometry, Point, Vector, DIM};
+#[cfg(feature = "dim2")]
+use crate::shape::convex_polygon;
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
+use crate::utils;
 use na::{self, RealField, Unit};
 use std::f64;
 
@@ -29,6 +32,44 @@ impl<N: RealField + Copy> Cuboid<N> {
     }
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField + Copy> Cuboid<N> {
+    /// The area of this rectangle.
+    #[inline]
+    pub fn area(&self) -> N {
+        self.half_extents.x * self.half_extents.y * na::convert(4.0)
+    }
+
+    /// The length of the boundary of this rectangle.
+    #[inline]
+    pub fn perimeter(&self) -> N {
+        (self.half_extents.x + self.half_extents.y) * na::convert(4.0)
+    }
+
+    /// The point and outward normal located at the curvilinear abscissa `s` of the boundary of this rectangle.
+    ///
+    /// The boundary is traversed counter-clockwise, starting at the vertex `(hx, -hy)` where
+    /// `(hx, hy)` are the half-extents of the rectangle. `s` is taken modulo the perimeter of
+    /// the rectangle.
+    pub fn boundary_point_at(&self, s: N) -> (Point<N>, Unit<Vector<N>>) {
+        let he = self.half_extents;
+        let points = [
+            Point::new(he.x, -he.y),
+            Point::new(he.x, he.y),
+            Point::new(-he.x, he.y),
+            Point::new(-he.x, -he.y),
+        ];
+        let normals = [
+            Vector::x_axis(),
+            Vector::y_axis(),
+            -Vector::x_axis(),
+            -Vector::y_axis(),
+        ];
+
+        convex_polygon::polygon_boundary_point_at(&points, &normals, s)
+    }
+}
+
 impl<N: RealField + Copy> Cuboid<N> {
     /// The half-extents of this box. Half-extents are the box half-width along each axis.
     #[inline]
@@ -314,7 +355,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Cuboid<N> {
         out: &mut ConvexPolygonalFeature<N>,
     ) {
         let local_dir = m.inverse_transform_vector(dir);
-        let cang = angle.cos();
+        let cang = utils::cos(angle);
         let mut support_point = self.half_extents;
 
         out.clear();
@@ -351,7 +392,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Cuboid<N> {
 
         #[cfg(feature = "dim3")]
         {
-            let sang = angle.sin();
+            let sang = utils::sin(angle);
             let mut support_point_id = 0;
 
             // Check faces.
@@ -406,7 +447,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Cuboid<N> {
 
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
         let one_degree: N = na::convert(f64::consts::PI / 180.0);
-        let cang = one_degree.cos();
+        let cang = utils::cos(one_degree);
 
         #[cfg(feature = "dim2")]
         {
@@ -432,7 +473,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Cuboid<N> {
 
         #[cfg(feature = "dim3")]
         {
-            let sang = one_degree.sin();
+            let sang = utils::sin(one_degree);
             let mut support_point_id = 0;
 
             // Check faces.
@@ -1,8 +1,8 @@
 //! Support mapping based Cylinder shape.
 
 use crate::math::{Point, Vector};
-use crate::shape::SupportMap;
-use na::{self, RealField};
+use crate::shape::{Oriented, SupportMap};
+use na::{self, RealField, Unit};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -29,6 +29,12 @@ impl<N: RealField + Copy> Cylinder<N> {
         }
     }
 
+    /// Rotates this cylinder so that its principal axis is `axis` instead of the `y` axis.
+    #[inline]
+    pub fn with_axis(self, axis: Unit<Vector<N>>) -> Oriented<N, Cylinder<N>> {
+        Oriented::with_axis(self, axis)
+    }
+
     /// The cylinder half length along the `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` field directly.")]
@@ -26,18 +26,32 @@ pub use self::deformable_shape::{DeformableShape, DeformationsType};
 pub use self::heightfield2::HeightField;
 #[cfg(feature = "dim3")]
 pub use self::heightfield3::{HeightField, HeightFieldCellStatus};
-pub use self::plane::Plane;
+#[cfg(feature = "dim3")]
+pub use self::oriented::Oriented;
+pub use self::plane::{HalfSpace, Plane};
+pub use self::point_cloud::PointCloud;
 pub use self::polyline::Polyline;
+#[cfg(feature = "dim3")]
+pub use self::quad::Quad;
+pub use self::rounded_cuboid::RoundedCuboid;
+pub use self::scaled::Scaled;
 pub use self::segment::{Segment, SegmentPointLocation};
+#[cfg(feature = "dim3")]
+pub use self::superellipsoid::Superellipsoid;
 #[doc(inline)]
-pub use self::shape::{Shape, ShapeHandle};
+pub use self::shape::{ConvexShape, Shape, ShapeHandle};
 #[doc(inline)]
 pub use self::support_map::SupportMap;
+pub use self::swept::Swept;
+#[cfg(feature = "dim2")]
+pub use self::tile_grid::{TileGrid, TileGridEdge};
 #[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
 pub use self::triangle::{Triangle, TrianglePointLocation};
 #[cfg(feature = "dim3")]
 pub use self::trimesh::{FaceAdjacentToEdge, TriMesh, TriMeshEdge, TriMeshFace, TriMeshVertex};
+#[doc(inline)]
+pub use crate::volumetric::Volumetric;
 
 mod ball;
 mod capsule;
@@ -63,14 +77,26 @@ mod deformable_shape;
 mod heightfield2;
 #[cfg(feature = "dim3")]
 mod heightfield3;
+#[cfg(feature = "dim3")]
+mod oriented;
 mod plane;
+mod point_cloud;
 mod polyline;
+#[cfg(feature = "dim3")]
+mod quad;
+mod rounded_cuboid;
+mod scaled;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
 mod shape_impl;
+#[cfg(feature = "dim3")]
+mod superellipsoid;
 #[doc(hidden)]
 pub mod support_map;
+mod swept;
+#[cfg(feature = "dim2")]
+mod tile_grid;
 #[cfg(feature = "dim3")]
 mod tetrahedron;
 mod triangle;
@@ -0,0 +1,64 @@
+//! Shape wrapper changing the orientation of a shape in its local space.
+
+use crate::math::{Isometry, Point, Rotation, Translation, Vector};
+use crate::shape::SupportMap;
+use crate::utils::rotation_from_y_axis;
+use na::{RealField, Unit};
+
+/// A shape rotated in its local space.
+///
+/// Shapes like `Cylinder` and `Cone` have their principal axis aligned with the `y` axis of
+/// their local space. Wrapping them into an `Oriented` shape, e.g., with `Cylinder::with_axis`,
+/// maps this axis to an arbitrary direction instead, without having to carry a corrective
+/// isometry around.
+///
+/// The support mapping, bounding volumes, ray casting, point projection, mass properties and
+/// triangle mesh of an oriented shape are those of the wrapped shape, rotated.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Oriented<N: RealField + Copy, G> {
+    /// The shape being rotated.
+    pub shape: G,
+    /// The rotation applied to the wrapped shape.
+    pub rotation: Rotation<N>,
+}
+
+impl<N: RealField + Copy, G> Oriented<N, G> {
+    /// Rotates `shape` by `rotation` in its local space.
+    #[inline]
+    pub fn new(shape: G, rotation: Rotation<N>) -> Oriented<N, G> {
+        Oriented { shape, rotation }
+    }
+
+    /// Rotates `shape` so that the `y` axis of its local space is mapped to `axis`.
+    pub fn with_axis(shape: G, axis: Unit<Vector<N>>) -> Oriented<N, G> {
+        let rotation = rotation_from_y_axis(&axis).unwrap_or_else(Rotation::identity);
+        Oriented { shape, rotation }
+    }
+
+    /// The image of the `y` axis of the wrapped shape, e.g., the principal axis of a cylinder.
+    #[inline]
+    pub fn axis(&self) -> Unit<Vector<N>> {
+        self.rotation * Vector::y_axis()
+    }
+
+    /// The position of the wrapped shape relative to `self`.
+    #[inline]
+    pub fn local_isometry(&self) -> Isometry<N> {
+        Isometry::from_parts(Translation::identity(), self.rotation)
+    }
+}
+
+impl<N: RealField + Copy, G: SupportMap<N>> SupportMap<N> for Oriented<N, G> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let local_dir = self.rotation.inverse_transform_vector(dir);
+        self.rotation * self.shape.local_support_point(&local_dir)
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        let local_dir = self.rotation.inverse_transform_unit_vector(dir);
+        self.rotation * self.shape.local_support_point_toward(&local_dir)
+    }
+}
@@ -1,8 +1,13 @@
 //! Support mapping based Plane shape.
 use crate::math::Vector;
+use crate::shape::FeatureId;
 use na::{RealField, Unit};
 
-/// SupportMap description of a plane.
+/// A plane, treated as the solid half-space lying behind it.
+///
+/// Every point `p` such that `p.coords.dot(&normal) <= 0` in the local-space of the plane is
+/// inside of this shape, so objects sunk below the plane are in contact with it, no matter how
+/// deep. The single feature of this shape is its boundary, identified by `Plane::FACE_ID`.
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Plane<N: RealField + Copy> {
@@ -10,7 +15,14 @@ pub struct Plane<N: RealField + Copy> {
     pub normal: Unit<Vector<N>>,
 }
 
+/// Alias of `Plane` emphasizing that its interior is the whole half-space behind it.
+pub type HalfSpace<N> = Plane<N>;
+
 impl<N: RealField + Copy> Plane<N> {
+    /// The feature identifier of the boundary of the half-space, reported by all the contacts
+    /// involving a plane.
+    pub const FACE_ID: FeatureId = FeatureId::Face(0);
+
     /// Builds a new plane from its center and its normal.
     #[inline]
     pub fn new(normal: Unit<Vector<N>>) -> Plane<N> {
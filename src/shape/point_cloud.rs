@@ -0,0 +1,237 @@
+//! A set of points indexed by a k-d tree.
+
+use crate::bounding_volume::{self, BoundingSphere, BoundingVolume, AABB};
+use crate::math::{Isometry, Point, DIM};
+use crate::shape::Shape;
+use na::{self, RealField};
+use std::cmp::Ordering;
+
+/// A set of points, e.g., a LiDAR scan, indexed by a k-d tree for nearest-point queries.
+///
+/// The k-d tree is implicit: it is a permutation of the point indices such that, for each range
+/// of this permutation, the point in the middle of the range splits the rest of the range along
+/// the axis selected by the depth of the range. The points themselves are kept in their original
+/// order.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointCloud<N: RealField + Copy> {
+    points: Vec<Point<N>>,
+    tree: Vec<usize>,
+    aabb: AABB<N>,
+    bounding_sphere: BoundingSphere<N>,
+}
+
+impl<N: RealField + Copy> PointCloud<N> {
+    /// Builds a new point cloud and its k-d tree from the given points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn new(points: Vec<Point<N>>) -> PointCloud<N> {
+        assert!(
+            !points.is_empty(),
+            "A point cloud must contain at least one point."
+        );
+
+        let aabb = bounding_volume::local_point_cloud_aabb(&points);
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&points);
+        let bounding_sphere = BoundingSphere::new(center, radius);
+        let mut tree: Vec<usize> = (0..points.len()).collect();
+        build_tree(&points, &mut tree, 0);
+
+        PointCloud {
+            points,
+            tree,
+            aabb,
+            bounding_sphere,
+        }
+    }
+
+    /// The points of this point cloud, in the order they were given to `PointCloud::new`.
+    #[inline]
+    pub fn points(&self) -> &[Point<N>] {
+        &self.points
+    }
+
+    /// The AABB of this point cloud.
+    #[inline]
+    pub fn aabb(&self) -> &AABB<N> {
+        &self.aabb
+    }
+
+    /// A bounding sphere of this point cloud, centered at the barycenter of its points.
+    #[inline]
+    pub fn bounding_sphere(&self) -> &BoundingSphere<N> {
+        &self.bounding_sphere
+    }
+
+    /// The index of the point of this point cloud closest to `point`, and their distance.
+    ///
+    /// The point is expressed in the local-space of the point cloud.
+    pub fn nearest_point(&self, point: &Point<N>) -> (usize, N) {
+        let mut best = (self.tree[0], N::max_value().unwrap());
+        self.nearest_point_in(0, self.tree.len(), 0, point, &mut best);
+        (best.0, best.1.sqrt())
+    }
+
+    /// Appends to `out` the index of every point at a distance smaller than or equal to `radius`
+    /// from `center`.
+    ///
+    /// The center is expressed in the local-space of the point cloud. The indices are not sorted.
+    pub fn points_within_radius(&self, center: &Point<N>, radius: N, out: &mut Vec<usize>) {
+        self.points_within_radius_in(0, self.tree.len(), 0, center, radius, out)
+    }
+
+    /// Appends to `out` the index of every point contained by `aabb`.
+    ///
+    /// The AABB is expressed in the local-space of the point cloud. The indices are not sorted.
+    pub fn points_in_aabb(&self, aabb: &AABB<N>, out: &mut Vec<usize>) {
+        if self.aabb.intersects(aabb) {
+            self.points_in_aabb_in(0, self.tree.len(), 0, aabb, out)
+        }
+    }
+
+    /// Appends to `out` the index of every point of this point cloud, transformed by `m`, that is
+    /// inside of `shape` transformed by `m_shape`.
+    ///
+    /// Only the points inside of the AABB of `shape` are tested with a point query. Shapes not
+    /// supporting point queries do not contain any point. The indices are not sorted.
+    pub fn points_inside_shape(
+        &self,
+        m: &Isometry<N>,
+        m_shape: &Isometry<N>,
+        shape: &dyn Shape<N>,
+        out: &mut Vec<usize>,
+    ) {
+        if let Some(query) = shape.as_point_query() {
+            let ls_m_shape = m.inv_mul(m_shape);
+            let first = out.len();
+            self.points_in_aabb(&shape.aabb(&ls_m_shape), out);
+
+            let mut i = first;
+            while i < out.len() {
+                if query.contains_point(&ls_m_shape, &self.points[out[i]]) {
+                    i += 1;
+                } else {
+                    let _ = out.swap_remove(i);
+                }
+            }
+        }
+    }
+
+    fn nearest_point_in(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        point: &Point<N>,
+        best: &mut (usize, N),
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let id = self.tree[mid];
+        let sq_dist = na::distance_squared(point, &self.points[id]);
+
+        if sq_dist < best.1 {
+            *best = (id, sq_dist);
+        }
+
+        let axis = depth % DIM;
+        let diff = point[axis] - self.points[id][axis];
+        let (near, far) = if diff < N::zero() {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+
+        self.nearest_point_in(near.0, near.1, depth + 1, point, best);
+
+        if diff * diff < best.1 {
+            self.nearest_point_in(far.0, far.1, depth + 1, point, best);
+        }
+    }
+
+    fn points_within_radius_in(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        center: &Point<N>,
+        radius: N,
+        out: &mut Vec<usize>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let id = self.tree[mid];
+
+        if na::distance_squared(center, &self.points[id]) <= radius * radius {
+            out.push(id);
+        }
+
+        let axis = depth % DIM;
+        let diff = center[axis] - self.points[id][axis];
+
+        if diff <= radius {
+            self.points_within_radius_in(start, mid, depth + 1, center, radius, out);
+        }
+
+        if diff >= -radius {
+            self.points_within_radius_in(mid + 1, end, depth + 1, center, radius, out);
+        }
+    }
+
+    fn points_in_aabb_in(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        aabb: &AABB<N>,
+        out: &mut Vec<usize>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let id = self.tree[mid];
+        let pt = &self.points[id];
+
+        if aabb.contains_local_point(pt) {
+            out.push(id);
+        }
+
+        let axis = depth % DIM;
+
+        if aabb.mins[axis] <= pt[axis] {
+            self.points_in_aabb_in(start, mid, depth + 1, aabb, out);
+        }
+
+        if aabb.maxs[axis] >= pt[axis] {
+            self.points_in_aabb_in(mid + 1, end, depth + 1, aabb, out);
+        }
+    }
+}
+
+fn build_tree<N: RealField + Copy>(points: &[Point<N>], ids: &mut [usize], depth: usize) {
+    if ids.len() <= 1 {
+        return;
+    }
+
+    let mid = ids.len() / 2;
+    let axis = depth % DIM;
+    let _ = ids.select_nth_unstable_by(mid, |a, b| {
+        points[*a][axis]
+            .partial_cmp(&points[*b][axis])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let (left, right) = ids.split_at_mut(mid);
+    build_tree(points, left, depth + 1);
+    build_tree(points, &mut right[1..], depth + 1);
+}
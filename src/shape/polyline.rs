@@ -1,8 +1,10 @@
 //! 2d line strip, 3d polyline.
 
-use crate::bounding_volume::{self, BoundingVolume, AABB};
+use crate::bounding_volume::{self, BoundingSphere, BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::partitioning::{BVHImpl, BVT};
+#[cfg(feature = "dim2")]
+use crate::procedural;
 use crate::query::{
     Contact, ContactKinematic, ContactPrediction, ContactPreprocessor, LocalShapeApproximation,
     NeighborhoodGeometry,
@@ -43,6 +45,7 @@ pub struct PolylineVertex {
 #[derive(Clone)]
 pub struct Polyline<N: RealField + Copy> {
     bvt: BVT<usize, AABB<N>>,
+    bounding_sphere: BoundingSphere<N>,
     points: Vec<Point<N>>,
     vertices: Vec<PolylineVertex>,
     edges: Vec<PolylineEdge<N>>,
@@ -51,6 +54,7 @@ pub struct Polyline<N: RealField + Copy> {
     adj_vertex_list: Vec<usize>,
     deformations: DeformationInfos<N>,
     oriented: bool, // FIXME: useless in 3D
+    thickness: N,
 }
 
 impl<N: RealField + Copy> Polyline<N> {
@@ -106,8 +110,11 @@ impl<N: RealField + Copy> Polyline<N> {
             seg_to_update: Vec::new(),
         };
 
+        let bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&points);
+
         Polyline {
             bvt,
+            bounding_sphere,
             points,
             deformations,
             vertices,
@@ -115,6 +122,7 @@ impl<N: RealField + Copy> Polyline<N> {
             adj_edge_list,
             adj_vertex_list,
             oriented: false,
+            thickness: N::zero(),
         }
     }
 
@@ -221,6 +229,12 @@ impl<N: RealField + Copy> Polyline<N> {
             .expect("An empty Polyline has no AABB.")
     }
 
+    /// The smallest sphere enclosing the points of this polyline, ignoring its thickness.
+    #[inline]
+    pub(crate) fn cached_bounding_sphere(&self) -> &BoundingSphere<N> {
+        &self.bounding_sphere
+    }
+
     /// The points of this polyline.
     #[inline]
     pub fn points(&self) -> &[Point<N>] {
@@ -249,6 +263,39 @@ impl<N: RealField + Copy> Polyline<N> {
         self.oriented = oriented
     }
 
+    /// The collision thickness of this polyline.
+    ///
+    /// See `self.set_thickness` for details.
+    #[inline]
+    pub fn thickness(&self) -> N {
+        self.thickness
+    }
+
+    /// Sets the collision thickness of this polyline.
+    ///
+    /// Contact and ray queries then treat each segment as a capsule of radius `thickness`, which
+    /// gives some depth to walls modeled as polylines. Other geometric queries ignore it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `thickness` is negative.
+    #[inline]
+    pub fn set_thickness(&mut self, thickness: N) {
+        assert!(
+            thickness >= N::zero(),
+            "The thickness of a polyline must be positive or zero."
+        );
+        self.thickness = thickness
+    }
+
+    /// Builds this polyline with the given collision thickness.
+    ///
+    /// See `self.set_thickness` for details.
+    pub fn with_thickness(mut self, thickness: N) -> Self {
+        self.set_thickness(thickness);
+        self
+    }
+
     /// Face containing feature.
     #[inline]
     pub fn edge_containing_feature(&self, id: FeatureId) -> usize {
@@ -363,6 +410,7 @@ impl<N: RealField + Copy> Polyline<N> {
         for pt in &mut self.points {
             *pt = transform * *pt
         }
+        self.bounding_sphere = self.bounding_sphere.transform_by(transform);
     }
 
     /// Applies a transformation to this polyline.
@@ -377,6 +425,7 @@ impl<N: RealField + Copy> Polyline<N> {
         for pt in &mut self.points {
             pt.coords.component_mul_assign(scale)
         }
+        self.bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&self.points);
     }
 
     /// Applies a non-uniform scale to this polyline.
@@ -386,6 +435,72 @@ impl<N: RealField + Copy> Polyline<N> {
         self
     }
 
+    /// The area enclosed by this polyline.
+    ///
+    /// This assumes the edges of this polyline form one or several closed non-intersecting loops
+    /// with consistent orientations.
+    #[cfg(feature = "dim2")]
+    pub fn area(&self) -> N {
+        let mut res = N::zero();
+
+        for edge in &self.edges {
+            let a = self.points[edge.indices.x];
+            let b = self.points[edge.indices.y];
+            res += a.coords.perp(&b.coords);
+        }
+
+        res.abs() * na::convert(0.5)
+    }
+
+    /// The total length of the edges of this polyline.
+    pub fn perimeter(&self) -> N {
+        self.edges.iter().fold(N::zero(), |acc, edge| {
+            acc + na::distance(
+                &self.points[edge.indices.x],
+                &self.points[edge.indices.y],
+            )
+        })
+    }
+
+    /// The point and normal located at the curvilinear abscissa `s` of this polyline.
+    ///
+    /// The edges are traversed in order, from their first to their second vertex. `s` is taken
+    /// modulo the perimeter of this polyline. The normal is the one of the edge containing the
+    /// returned point. Returns `None` if all the edges of this polyline are degenerate.
+    #[cfg(feature = "dim2")]
+    pub fn boundary_point_at(&self, s: N) -> Option<(Point<N>, Unit<Vector<N>>)> {
+        let perimeter = self.perimeter();
+
+        if perimeter.is_zero() {
+            return None;
+        }
+
+        let mut s = s % perimeter;
+
+        if s < N::zero() {
+            s += perimeter;
+        }
+
+        let mut last = None;
+
+        for i in 0..self.edges.len() {
+            let seg = self.edge_segment(i);
+            let length = seg.length();
+
+            if let Some(normal) = seg.normal() {
+                if s <= length {
+                    return Some((seg.a + seg.scaled_direction() * (s / length), normal));
+                }
+
+                last = Some((seg.b, normal));
+            }
+
+            s -= length;
+        }
+
+        last
+    }
+
     /// Returns `true` if the given feature is a FeatureId::Face and
     /// identifies a backface of this polyline.
     #[inline]
@@ -530,6 +645,20 @@ impl<N: RealField + Copy> Polyline<N> {
     }
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField + Copy> From<procedural::Polyline<N>> for Polyline<N> {
+    /// Converts a procedural polyline into a polyline with the same edges.
+    ///
+    /// A procedural polyline without explicit edges is converted into a chain joining its
+    /// consecutive points, which is closed only if the procedural polyline is flagged as closed
+    /// or if its first and last points coincide.
+    fn from(polyline: procedural::Polyline<N>) -> Self {
+        let indices = polyline.edges();
+        let (points, _) = polyline.unwrap();
+        Polyline::new(points, Some(indices))
+    }
+}
+
 impl<N: RealField + Copy> CompositeShape<N> for Polyline<N> {
     #[inline]
     fn nparts(&self) -> usize {
@@ -571,6 +700,11 @@ impl<N: RealField + Copy> CompositeShape<N> for Polyline<N> {
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
         BVHImpl::BVT(&self.bvt)
     }
+
+    #[inline]
+    fn thickness(&self) -> N {
+        self.thickness
+    }
 }
 
 impl<N: RealField + Copy> DeformableShape<N> for Polyline<N> {
@@ -637,7 +771,8 @@ impl<N: RealField + Copy> DeformableShape<N> for Polyline<N> {
         }
 
         // FIXME: measure efficiency with a non-zero margin.
-        self.bvt.refit(N::zero())
+        self.bvt.refit(N::zero());
+        self.bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&self.points);
     }
 
     fn update_local_approximation(&self, coords: &[N], approx: &mut LocalShapeApproximation<N>) {
@@ -724,8 +859,9 @@ impl<'a, N: RealField + Copy> PolylineContactProcessor<'a, N> {
     }
 }
 
-impl<'a, N: RealField + Copy> ContactPreprocessor<N> for PolylineContactProcessor<'a, N> {
-    fn process_contact(
+impl<'a, N: RealField + Copy> PolylineContactProcessor<'a, N> {
+    // Maps the features of the contact to the polyline features.
+    fn process_surface_contact(
         &self,
         _c: &mut Contact<N>,
         kinematic: &mut ContactKinematic<N>,
@@ -776,6 +912,35 @@ impl<'a, N: RealField + Copy> ContactPreprocessor<N> for PolylineContactProcesso
     }
 }
 
+impl<'a, N: RealField + Copy> ContactPreprocessor<N> for PolylineContactProcessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        if !self.process_surface_contact(c, kinematic, is_first) {
+            return false;
+        }
+
+        let thickness = self.polyline.thickness();
+
+        if thickness > N::zero() {
+            if is_first {
+                kinematic.set_dilation1(thickness);
+                c.world1 += *c.normal * thickness;
+            } else {
+                kinematic.set_dilation2(thickness);
+                c.world2 -= *c.normal * thickness;
+            }
+
+            c.depth += thickness;
+        }
+
+        true
+    }
+}
+
 #[cfg(feature = "dim2")]
 fn xy_point<N: RealField + Copy>(x: N, y: N) -> Point<N> {
     Point::new(x, y)
@@ -0,0 +1,238 @@
+//! Definition of the quad shape.
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
+use crate::utils;
+use na::{self, RealField, Unit, Vector2};
+use std::f64;
+
+/// A flat rectangle with finite extents, i.e., a bounded plane.
+///
+/// The quad lies on the local `xz` plane and is centered at the origin. Its front face normal is
+/// the local `+y` axis. Unlike a `Plane`, a quad is not a half-space: it has no interior and
+/// rays or shapes may pass beyond its edges.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Quad<N: RealField + Copy> {
+    /// The half-extents of the quad along the local `x` and `z` axes.
+    pub half_extents: Vector2<N>,
+}
+
+impl<N: RealField + Copy> Quad<N> {
+    /// Creates a new quad from its half-extents along the local `x` and `z` axes.
+    ///
+    /// Both half-extents must be positive.
+    #[inline]
+    pub fn new(half_extents: Vector2<N>) -> Quad<N> {
+        assert!(
+            half_extents.x > N::zero() && half_extents.y > N::zero(),
+            "A quad must have positive half-extents."
+        );
+
+        Quad { half_extents }
+    }
+
+    /// The normal of the front face of this quad.
+    #[inline]
+    pub fn normal(&self) -> Unit<Vector<N>> {
+        Vector::y_axis()
+    }
+
+    /// The four vertices of this quad, counterclockwise when seen from its front face.
+    #[inline]
+    pub fn vertices(&self) -> [Point<N>; 4] {
+        let (hx, hz) = (self.half_extents.x, self.half_extents.y);
+
+        [
+            Point::new(-hx, N::zero(), -hz),
+            Point::new(-hx, N::zero(), hz),
+            Point::new(hx, N::zero(), hz),
+            Point::new(hx, N::zero(), -hz),
+        ]
+    }
+
+    /// The identifier of the vertex located on the given side of each axis.
+    #[inline]
+    pub(crate) fn vertex_id(positive_x: bool, positive_z: bool) -> usize {
+        match (positive_x, positive_z) {
+            (false, false) => 0,
+            (false, true) => 1,
+            (true, true) => 2,
+            (true, false) => 3,
+        }
+    }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_vector(dir);
+
+        match feature {
+            FeatureId::Face(0) => ls_dir.y <= N::zero(),
+            FeatureId::Face(1) => ls_dir.y >= N::zero(),
+            _ => false,
+        }
+    }
+
+    /// The feature of this quad with a normal closest to `local_dir`.
+    ///
+    /// A face is returned if `local_dir` is at most `eps` radians away from a face normal, and an
+    /// edge if it is at most `eps` radians away from the plane orthogonal to that edge.
+    pub fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
+        let (seps, ceps) = utils::sin_cos(eps);
+
+        if local_dir.y >= ceps {
+            return FeatureId::Face(0);
+        }
+
+        if local_dir.y <= -ceps {
+            return FeatureId::Face(1);
+        }
+
+        let positive_x = local_dir.x >= N::zero();
+        let positive_z = local_dir.z >= N::zero();
+
+        if local_dir.x.abs() <= seps {
+            // Edge along the `x` axis.
+            if positive_z {
+                FeatureId::Edge(1)
+            } else {
+                FeatureId::Edge(3)
+            }
+        } else if local_dir.z.abs() <= seps {
+            // Edge along the `z` axis.
+            if positive_x {
+                FeatureId::Edge(2)
+            } else {
+                FeatureId::Edge(0)
+            }
+        } else {
+            FeatureId::Vertex(Self::vertex_id(positive_x, positive_z))
+        }
+    }
+}
+
+impl<N: RealField + Copy> SupportMap<N> for Quad<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let support_x = if dir.x >= N::zero() {
+            self.half_extents.x
+        } else {
+            -self.half_extents.x
+        };
+        let support_z = if dir.z >= N::zero() {
+            self.half_extents.y
+        } else {
+            -self.half_extents.y
+        };
+
+        Point::new(support_x, N::zero(), support_z)
+    }
+}
+
+impl<N: RealField + Copy> ConvexPolyhedron<N> for Quad<N> {
+    fn vertex(&self, id: FeatureId) -> Point<N> {
+        let i = id.unwrap_vertex();
+        assert!(i < 4, "Quad vertex index out of bounds.");
+        self.vertices()[i]
+    }
+
+    fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
+        let i = id.unwrap_edge();
+        assert!(i < 4, "Quad edge index out of bounds.");
+        let j = (i + 1) % 4;
+        let vtx = self.vertices();
+
+        (vtx[i], vtx[j], FeatureId::Vertex(i), FeatureId::Vertex(j))
+    }
+
+    fn face(&self, id: FeatureId, face: &mut ConvexPolygonalFeature<N>) {
+        face.clear();
+        face.set_feature_id(id);
+
+        let vtx = self.vertices();
+
+        match id.unwrap_face() {
+            0 => {
+                for (i, pt) in vtx.iter().enumerate() {
+                    face.push(*pt, FeatureId::Vertex(i));
+                    face.push_edge_feature_id(FeatureId::Edge(i));
+                }
+                face.set_normal(self.normal());
+            }
+            1 => {
+                for (i, pt) in vtx.iter().enumerate().rev() {
+                    face.push(*pt, FeatureId::Vertex(i));
+                    face.push_edge_feature_id(FeatureId::Edge((i + 3) % 4));
+                }
+                face.set_normal(-self.normal());
+            }
+            _ => panic!("Quad face index out of bounds."),
+        }
+
+        face.recompute_edge_normals();
+    }
+
+    fn feature_normal(&self, feature: FeatureId) -> Unit<Vector<N>> {
+        match feature {
+            FeatureId::Face(1) => -self.normal(),
+            _ => self.normal(),
+        }
+    }
+
+    fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        face: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let ls_dir = m.inverse_transform_vector(dir);
+
+        if ls_dir.y >= N::zero() {
+            ConvexPolyhedron::<N>::face(self, FeatureId::Face(0), face);
+        } else {
+            ConvexPolyhedron::<N>::face(self, FeatureId::Face(1), face);
+        }
+        face.transform_by(m)
+    }
+
+    fn support_feature_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        eps: N,
+        out: &mut ConvexPolygonalFeature<N>,
+    ) {
+        out.clear();
+        let ls_dir = m.inverse_transform_unit_vector(dir);
+        let feature = self.support_feature_id_toward(&ls_dir, eps);
+
+        match feature {
+            FeatureId::Vertex(_) => {
+                let vertex = self.vertex(feature);
+                out.push(m * vertex, feature);
+                out.set_feature_id(feature);
+            }
+            FeatureId::Edge(_) => {
+                let (vertex1, vertex2, vertex_id1, vertex_id2) = self.edge(feature);
+                out.push(m * vertex1, vertex_id1);
+                out.push(m * vertex2, vertex_id2);
+                out.push_edge_feature_id(feature);
+                out.set_feature_id(feature);
+            }
+            FeatureId::Face(_) => {
+                self.face(feature, out);
+                out.transform_by(m);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
+        self.support_feature_id_toward(local_dir, na::convert(f64::consts::PI / 180.0))
+    }
+}
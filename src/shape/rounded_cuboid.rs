@@ -0,0 +1,59 @@
+//! Support mapping based rounded cuboid shape.
+
+use crate::math::{Point, Vector};
+use crate::shape::{Cuboid, SupportMap};
+use na::{RealField, Unit};
+
+/// A cuboid with rounded edges and corners.
+///
+/// This is the set of points located at a distance smaller than `border_radius` from a cuboid
+/// with the half-extents `half_extents`. In 2D, this is a rounded rectangle, which is a common
+/// choice for character controllers because its rounded corners slide over the junctions of
+/// adjacent tiles.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct RoundedCuboid<N: RealField + Copy> {
+    /// The half-extents of the cuboid before it gets rounded.
+    pub half_extents: Vector<N>,
+    /// The radius of the rounded edges and corners.
+    pub border_radius: N,
+}
+
+impl<N: RealField + Copy> RoundedCuboid<N> {
+    /// Creates a new rounded cuboid.
+    ///
+    /// # Arguments:
+    /// * `half_extents` - the half-extents of the cuboid before it gets rounded. Each of them
+    ///   must be non-negative.
+    /// * `border_radius` - the radius of the rounded edges and corners. It must be positive.
+    #[inline]
+    pub fn new(half_extents: Vector<N>, border_radius: N) -> RoundedCuboid<N> {
+        assert!(
+            half_extents.iter().all(|e| *e >= N::zero()) && border_radius > N::zero(),
+            "A rounded cuboid must have non-negative half-extents and a positive border radius."
+        );
+
+        RoundedCuboid {
+            half_extents,
+            border_radius,
+        }
+    }
+
+    /// The cuboid that, once dilated by `self.border_radius`, yields this rounded cuboid.
+    #[inline]
+    pub fn inner_cuboid(&self) -> Cuboid<N> {
+        Cuboid::new(self.half_extents)
+    }
+}
+
+impl<N: RealField + Copy> SupportMap<N> for RoundedCuboid<N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.local_support_point_toward(&Unit::new_normalize(*dir))
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.inner_cuboid().local_support_point(dir) + **dir * self.border_radius
+    }
+}
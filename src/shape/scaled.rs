@@ -0,0 +1,89 @@
+//! Shape wrapper applying a non-uniform scale.
+
+use crate::bounding_volume::{self, AABB};
+use crate::math::{Point, Vector};
+use crate::shape::{Shape, SupportMap};
+use na::{RealField, Unit};
+
+/// A shape scaled non-uniformly along its local axes.
+///
+/// This allows a single canonical shape, e.g., a triangle mesh, to be reused at several scales
+/// without duplicating its data. The scale is applied in the local-space of the wrapped shape,
+/// before its position. Each component of the scale must be non-zero; negative components
+/// mirror the shape along the corresponding axis.
+///
+/// The support mapping, AABB, and ray casting of a scaled shape are exact.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Scaled<N: RealField + Copy, G> {
+    /// The shape being scaled.
+    pub shape: G,
+    /// The scale factor along each local axis of the shape.
+    pub scale: Vector<N>,
+}
+
+impl<N: RealField + Copy, G> Scaled<N, G> {
+    /// Scales `shape` by `scale` along its local axes.
+    ///
+    /// Panics if a component of `scale` is zero.
+    #[inline]
+    pub fn new(shape: G, scale: Vector<N>) -> Scaled<N, G> {
+        assert!(
+            scale.iter().all(|s| !s.is_zero()),
+            "The scale of a shape must not have zero components."
+        );
+
+        Scaled { shape, scale }
+    }
+
+    /// Transforms a point from the local-space of the wrapped shape to the local-space of `self`.
+    #[inline]
+    pub fn scale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_mul(&self.scale))
+    }
+
+    /// Transforms a point from the local-space of `self` to the local-space of the wrapped shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<N>) -> Point<N> {
+        Point::from(pt.coords.component_div(&self.scale))
+    }
+
+    /// Transforms a normal from the local-space of the wrapped shape to the local-space of `self`.
+    #[inline]
+    pub fn scale_normal(&self, normal: &Vector<N>) -> Vector<N> {
+        // Normals are transformed by the inverse transpose of the scaling matrix.
+        normal.component_div(&self.scale).normalize()
+    }
+}
+
+impl<N: RealField + Copy, G: Shape<N>> Scaled<N, G> {
+    /// The exact AABB of this scaled shape, in its local-space.
+    pub fn local_scaled_aabb(&self) -> AABB<N> {
+        if self.shape.is_support_map() {
+            bounding_volume::local_support_map_aabb(self)
+        } else {
+            let aabb = self.shape.local_aabb();
+            let a = self.scale_point(&aabb.mins);
+            let b = self.scale_point(&aabb.maxs);
+            AABB::new(a.inf(&b), a.sup(&b))
+        }
+    }
+}
+
+impl<N: RealField + Copy, G: Shape<N>> SupportMap<N> for Scaled<N, G> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let support_map = self
+            .shape
+            .as_support_map()
+            .expect("The scaled shape does not implement SupportMap.");
+        // The support point of S * G toward `dir` is S times the support point of G toward S * dir.
+        let pt = support_map.local_support_point(&dir.component_mul(&self.scale));
+        self.scale_point(&pt)
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.local_support_point(dir.as_ref())
+    }
+}
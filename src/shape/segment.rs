@@ -2,7 +2,6 @@
 
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
-#[cfg(feature = "dim2")]
 use crate::utils;
 use na::{self, RealField, Unit};
 use std::f64;
@@ -240,9 +239,9 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Segment<N> {
             match feature {
                 FeatureId::Vertex(id) => {
                     if id == 0 {
-                        direction
-                    } else {
                         -direction
+                    } else {
+                        direction
                     }
                 }
                 #[cfg(feature = "dim3")]
@@ -312,7 +311,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Segment<N> {
     ) {
         face.clear();
         let seg = self.transformed(transform);
-        let ceps = eps.sin();
+        let ceps = utils::sin(eps);
 
         if let Some(seg_dir) = seg.direction() {
             let cang = dir.dot(&seg_dir);
@@ -346,7 +345,7 @@ impl<N: RealField + Copy> ConvexPolyhedron<N> for Segment<N> {
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
         if let Some(seg_dir) = self.direction() {
             let eps: N = na::convert(f64::consts::PI / 180.0);
-            let seps = eps.sin();
+            let seps = utils::sin(eps);
             let dot = seg_dir.dot(local_dir.as_ref());
 
             if dot <= seps {
@@ -3,6 +3,7 @@ use crate::bounding_volume::{BoundingSphere, AABB};
 use crate::math::{Isometry, Vector};
 use crate::query::{PointQuery, RayCast};
 use crate::shape::{CompositeShape, ConvexPolyhedron, DeformableShape, FeatureId, SupportMap};
+use crate::volumetric::Volumetric;
 use downcast_rs::Downcast;
 use na::{self, RealField, Unit};
 use std::ops::Deref;
@@ -22,6 +23,14 @@ impl<N: RealField + Copy, T: 'static + Shape<N> + Clone> ShapeClone<N> for T {
 /// Trait implemented by all shapes supported by ncollide.
 ///
 /// This allows dynamic inspection of the shape capabilities.
+///
+/// # Thread safety
+///
+/// Shapes are `Send + Sync` and may be queried from several threads at once, e.g., by sharing a
+/// `&TriMesh` or a `ShapeHandle` with a thread pool. None of the shapes of this crate rely on
+/// interior mutability: acceleration structures like the BVT of a `TriMesh` or a `Compound` are
+/// built by their constructor, and updated only by methods taking `&mut self`. Custom shapes
+/// caching data lazily from `&self` must keep that cache behind a lock.
 pub trait Shape<N: RealField + Copy>: Send + Sync + Downcast + ShapeClone<N> {
     /// The AABB of `self` transformed by `m`.
     fn aabb(&self, m: &Isometry<N>) -> AABB<N>;
@@ -88,6 +97,12 @@ pub trait Shape<N: RealField + Copy>: Send + Sync + Downcast + ShapeClone<N> {
         None
     }
 
+    /// The convex shape representation of `self` if applicable.
+    #[inline]
+    fn as_convex(&self) -> Option<&dyn ConvexShape<N>> {
+        None
+    }
+
     /// The composite shape representation of `self` if applicable.
     #[inline]
     fn as_composite_shape(&self) -> Option<&dyn CompositeShape<N>> {
@@ -106,6 +121,12 @@ pub trait Shape<N: RealField + Copy>: Send + Sync + Downcast + ShapeClone<N> {
         None
     }
 
+    /// The mass properties of `self` if applicable.
+    #[inline]
+    fn as_volumetric(&self) -> Option<&dyn Volumetric<N>> {
+        None
+    }
+
     /// Whether `self` uses a convex polyhedron representation.
     #[inline]
     fn is_convex_polyhedron(&self) -> bool {
@@ -118,6 +139,15 @@ pub trait Shape<N: RealField + Copy>: Send + Sync + Downcast + ShapeClone<N> {
         self.as_support_map().is_some()
     }
 
+    /// Whether `self` is convex, i.e., can be handled by the GJK and EPA algorithms.
+    ///
+    /// Half-spaces like `Plane` are not considered convex here because they do not have a
+    /// support mapping.
+    #[inline]
+    fn is_convex(&self) -> bool {
+        self.as_convex().is_some()
+    }
+
     /// Whether `self` uses a composite shape-based representation.
     #[inline]
     fn is_composite_shape(&self) -> bool {
@@ -133,6 +163,14 @@ pub trait Shape<N: RealField + Copy>: Send + Sync + Downcast + ShapeClone<N> {
 
 impl_downcast!(Shape<N> where N: RealField + Copy);
 
+/// Trait implemented by all convex shapes, i.e., shapes with a support mapping.
+///
+/// This is automatically implemented for every shape implementing `SupportMap`. Use
+/// `Shape::as_convex` to retrieve it from a shape trait-object.
+pub trait ConvexShape<N: RealField + Copy>: Shape<N> + SupportMap<N> {}
+
+impl<N: RealField + Copy, T: Shape<N> + SupportMap<N>> ConvexShape<N> for T {}
+
 /// Trait for casting shapes to its exact represetation.
 impl<N: RealField + Copy> dyn Shape<N> {
     /// Tests if this shape has a specific type `T`.
@@ -150,7 +188,8 @@ impl<N: RealField + Copy> dyn Shape<N> {
 
 /// A shared handle to an abstract shape.
 ///
-/// This can be mutated using COW.
+/// This can be mutated using COW. Cloning a handle is cheap, and the clones can be moved to other
+/// threads.
 #[derive(Clone)]
 pub struct ShapeHandle<N: RealField + Copy>(Arc<dyn Shape<N>>);
 
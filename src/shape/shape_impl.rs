@@ -1,14 +1,16 @@
-use crate::bounding_volume::{self, BoundingSphere, AABB};
+use crate::bounding_volume::{self, BoundingSphere, HasBoundingVolume, AABB};
 use crate::math::{Isometry, Vector};
 use crate::query::{PointQuery, RayCast};
 #[cfg(feature = "dim2")]
-use crate::shape::ConvexPolygon;
+use crate::shape::{ConvexPolygon, TileGrid};
 use crate::shape::{
-    Ball, Capsule, CompositeShape, Compound, ConvexPolyhedron, Cuboid, DeformableShape, FeatureId,
-    HeightField, Plane, Polyline, Segment, Shape, SupportMap,
+    Ball, Capsule, CompositeShape, Compound, ConvexPolyhedron, ConvexShape, Cuboid,
+    DeformableShape, FeatureId, HeightField, Plane, PointCloud, Polyline, RoundedCuboid, Scaled,
+    Segment, Shape, SupportMap, Swept, Triangle,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{ConvexHull, TriMesh, Triangle};
+use crate::shape::{Cone, ConvexHull, Cylinder, Oriented, Quad, Superellipsoid, TriMesh};
+use crate::volumetric::Volumetric;
 use na::{RealField, Unit};
 
 macro_rules! impl_as_convex_polyhedron (
@@ -41,6 +43,11 @@ macro_rules! impl_as_support_map (
         fn is_support_map(&self) -> bool {
             true
         }
+
+        #[inline]
+        fn as_convex(&self) -> Option<&dyn ConvexShape<N>> {
+            Some(self)
+        }
     }
 );
 
@@ -77,6 +84,15 @@ macro_rules! impl_as_deformable_shape (
     }
 );
 
+macro_rules! impl_as_volumetric (
+    () => {
+        #[inline]
+        fn as_volumetric(&self) -> Option<&dyn Volumetric<N>> {
+            Some(self)
+        }
+    }
+);
+
 macro_rules! impl_shape_common (
     () => {
         #[inline]
@@ -106,13 +122,19 @@ macro_rules! impl_shape_common (
     }
 );
 
-#[cfg(feature = "dim3")]
 impl<N: RealField + Copy> Shape<N> for Triangle<N> {
     impl_shape_common!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> Shape<N> for Quad<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+    impl_as_convex_polyhedron!();
+}
+
 impl<N: RealField + Copy> Shape<N> for Segment<N> {
     impl_shape_common!();
     impl_as_support_map!();
@@ -121,6 +143,7 @@ impl<N: RealField + Copy> Shape<N> for Segment<N> {
 
 impl<N: RealField + Copy> Shape<N> for Ball<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_support_map!();
 
     // FIXME: this is wrong in theory but keep it this
@@ -139,11 +162,31 @@ impl<N: RealField + Copy> Shape<N> for Ball<N> {
 
 impl<N: RealField + Copy> Shape<N> for Cuboid<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
 }
 
 impl<N: RealField + Copy> Shape<N> for Capsule<N> {
+    impl_shape_common!();
+    impl_as_volumetric!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N: RealField + Copy> Shape<N> for RoundedCuboid<N> {
     impl_shape_common!();
     impl_as_support_map!();
 
@@ -164,19 +207,107 @@ impl<N: RealField + Copy> Shape<N> for Capsule<N> {
 #[cfg(feature = "dim3")]
 impl<N: RealField + Copy> Shape<N> for ConvexHull<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> Shape<N> for Cone<N> {
+    impl_shape_common!();
+    impl_as_volumetric!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> Shape<N> for Cylinder<N> {
+    impl_shape_common!();
+    impl_as_volumetric!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N, G> Shape<N> for Oriented<N, G>
+where
+    N: RealField + Copy,
+    G: Shape<N>
+        + SupportMap<N>
+        + RayCast<N>
+        + PointQuery<N>
+        + Volumetric<N>
+        + HasBoundingVolume<N, BoundingSphere<N>>
+        + Clone,
+{
+    impl_shape_common!();
+    impl_as_volumetric!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        deformations: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        self.shape
+            .tangent_cone_contains_dir(feature, &(m * self.local_isometry()), deformations, dir)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField + Copy> Shape<N> for Superellipsoid<N> {
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // Same as for balls: there are no features on this smooth shape.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
 #[cfg(feature = "dim2")]
 impl<N: RealField + Copy> Shape<N> for ConvexPolygon<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_support_map!();
     impl_as_convex_polyhedron!();
 }
 
 impl<N: RealField + Copy> Shape<N> for Compound<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_composite_shape!();
 
     fn tangent_cone_contains_dir(
@@ -202,6 +333,7 @@ impl<N: RealField + Copy> Shape<N> for Compound<N> {
 #[cfg(feature = "dim3")]
 impl<N: RealField + Copy> Shape<N> for TriMesh<N> {
     impl_shape_common!();
+    impl_as_volumetric!();
     impl_as_composite_shape!();
     impl_as_deformable_shape!();
 
@@ -268,6 +400,66 @@ impl<N: RealField + Copy> Shape<N> for HeightField<N> {
     }
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField + Copy> Shape<N> for TileGrid<N> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        fid: FeatureId,
+        m: &Isometry<N>,
+        _: Option<&[N]>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        match fid {
+            FeatureId::Face(i) => {
+                let ls_dir = m.inverse_transform_unit_vector(dir);
+                self.face_tangent_cone_contains_dir(i, &ls_dir)
+            }
+            // FIXME: handle the concave corners of the grid.
+            _ => false,
+        }
+    }
+
+    fn subshape_containing_feature(&self, id: FeatureId) -> usize {
+        self.edge_containing_feature(id)
+    }
+}
+
+impl<N: RealField + Copy> Shape<N> for PointCloud<N> {
+    #[inline]
+    fn aabb(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn local_aabb(&self) -> AABB<N> {
+        bounding_volume::local_aabb(self)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&dyn PointQuery<N>> {
+        Some(self)
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        // The tangent cone of an isolated point is reduced to the zero vector.
+        false
+    }
+}
+
 impl<N: RealField + Copy> Shape<N> for Plane<N> {
     impl_shape_common!();
 
@@ -282,3 +474,74 @@ impl<N: RealField + Copy> Shape<N> for Plane<N> {
         dir.dot(&world_normal) <= N::zero()
     }
 }
+
+impl<N, G> Shape<N> for Swept<N, G>
+where
+    N: RealField + Copy,
+    G: SupportMap<N> + HasBoundingVolume<N, BoundingSphere<N>> + Clone + Send + Sync + 'static,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
+impl<N, G> Shape<N> for Scaled<N, G>
+where
+    N: RealField + Copy,
+    G: Shape<N> + Clone,
+{
+    #[inline]
+    fn aabb(&self, m: &Isometry<N>) -> AABB<N> {
+        if self.shape.is_support_map() {
+            bounding_volume::support_map_aabb(m, self)
+        } else {
+            self.local_scaled_aabb().transform_by(m)
+        }
+    }
+
+    #[inline]
+    fn local_aabb(&self) -> AABB<N> {
+        self.local_scaled_aabb()
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&dyn RayCast<N>> {
+        self.shape.as_ray_cast().map(|_| self as &dyn RayCast<N>)
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&dyn SupportMap<N>> {
+        self.shape
+            .as_support_map()
+            .map(|_| self as &dyn SupportMap<N>)
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        self.shape.is_support_map()
+    }
+
+    #[inline]
+    fn as_convex(&self) -> Option<&dyn ConvexShape<N>> {
+        self.shape.as_convex().map(|_| self as &dyn ConvexShape<N>)
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
@@ -0,0 +1,135 @@
+//! Support mapping based superellipsoid shape.
+
+use crate::math::{Point, Vector};
+use crate::shape::SupportMap;
+use na::{self, RealField};
+
+/// SupportMap description of a superellipsoid with its principal axis aligned with the `y` axis.
+///
+/// The surface of a superellipsoid is the set of points verifying:
+///
+/// ```text
+/// (|x / rx|^(2 / e2) + |z / rz|^(2 / e2))^(e2 / e1) + |y / ry|^(2 / e1) = 1
+/// ```
+///
+/// where `e1` controls the squareness of the shape along the `y` axis, and `e2` its squareness on
+/// the `xz` plane. Exponents close to zero give a box-like shape, `e1 = e2 = 1` gives an
+/// ellipsoid, and exponents close to 2 give a shape with sharp edges. The shape is convex as long
+/// as both exponents lie in `]0, 2[`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Superellipsoid<N: RealField + Copy> {
+    /// The radii of the superellipsoid along each local axis.
+    pub radii: Vector<N>,
+    /// The exponent controlling the squareness of the superellipsoid along the `y` axis.
+    pub e1: N,
+    /// The exponent controlling the squareness of the superellipsoid on the `xz` plane.
+    pub e2: N,
+}
+
+impl<N: RealField + Copy> Superellipsoid<N> {
+    /// Creates a new superellipsoid.
+    ///
+    /// # Arguments:
+    /// * `radii` - the radii of the superellipsoid along each axis. They must be positive.
+    /// * `e1` - the squareness exponent along the `y` axis. It must lie in `]0, 2[`.
+    /// * `e2` - the squareness exponent on the `xz` plane. It must lie in `]0, 2[`.
+    pub fn new(radii: Vector<N>, e1: N, e2: N) -> Superellipsoid<N> {
+        let two: N = na::convert(2.0f64);
+
+        assert!(
+            radii.iter().all(|r| *r > N::zero()),
+            "A superellipsoid must have positive radii."
+        );
+        assert!(
+            e1 > N::zero() && e1 < two && e2 > N::zero() && e2 < two,
+            "The exponents of a convex superellipsoid must lie in ]0, 2[."
+        );
+
+        Superellipsoid { radii, e1, e2 }
+    }
+
+    /// Evaluates the inside-outside function of this superellipsoid at the local point `pt`.
+    ///
+    /// The result is smaller than 1 if `pt` is inside of the superellipsoid, equal to 1 if it lies
+    /// on its surface, and greater than 1 otherwise.
+    pub fn inside_outside(&self, pt: &Point<N>) -> N {
+        let two: N = na::convert(2.0f64);
+        let x = (pt.x / self.radii.x).abs();
+        let y = (pt.y / self.radii.y).abs();
+        let z = (pt.z / self.radii.z).abs();
+        let xz = x.powf(two / self.e2) + z.powf(two / self.e2);
+
+        xz.powf(self.e2 / self.e1) + y.powf(two / self.e1)
+    }
+
+    /// The point at the given spherical coordinates of this superellipsoid's parametrization.
+    ///
+    /// The latitude `eta` lies in `[-pi / 2, pi / 2]` and the longitude `omega` in `[-pi, pi]`,
+    /// both given as their sine and cosine.
+    pub(crate) fn parametric_point(
+        &self,
+        (seta, ceta): (N, N),
+        (somega, comega): (N, N),
+    ) -> Point<N> {
+        let ceta = signed_pow(ceta, self.e1);
+
+        Point::new(
+            self.radii.x * ceta * signed_pow(comega, self.e2),
+            self.radii.y * signed_pow(seta, self.e1),
+            self.radii.z * ceta * signed_pow(somega, self.e2),
+        )
+    }
+}
+
+/// Computes `sign(x) * |x|^e`.
+#[inline]
+fn signed_pow<N: RealField + Copy>(x: N, e: N) -> N {
+    if x.is_zero() {
+        N::zero()
+    } else {
+        x.abs().powf(e).copysign(x)
+    }
+}
+
+impl<N: RealField + Copy> SupportMap<N> for Superellipsoid<N> {
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        // The superellipsoid is the unit ball of a nested norm, once scaled by its radii. Its
+        // support point is thus the gradient of the dual norm, which is also a nested norm with
+        // the exponents `2 / (2 - e)`, evaluated at the direction scaled by the radii.
+        let two: N = na::convert(2.0f64);
+        let mut w = dir.component_mul(&self.radii);
+        let max = w.amax();
+
+        if max.is_zero() {
+            return Point::new(N::zero(), self.radii.y, N::zero());
+        }
+
+        // Normalize to avoid overflows with the large exponents of nearly-sharp superellipsoids.
+        w /= max;
+
+        let p1 = two / (two - self.e1);
+        let p2 = two / (two - self.e2);
+        let (x, y, z) = (w.x.abs(), w.y.abs(), w.z.abs());
+
+        let xz = (x.powf(p2) + z.powf(p2)).powf(N::one() / p2);
+        let norm = (xz.powf(p1) + y.powf(p1)).powf(N::one() / p1);
+
+        let gy = (y / norm).powf(p1 - N::one());
+        let (gx, gz) = if xz.is_zero() {
+            (N::zero(), N::zero())
+        } else {
+            let gxz = (xz / norm).powf(p1 - N::one());
+            (
+                gxz * (x / xz).powf(p2 - N::one()),
+                gxz * (z / xz).powf(p2 - N::one()),
+            )
+        };
+
+        Point::new(
+            (gx * self.radii.x).copysign(w.x),
+            (gy * self.radii.y).copysign(w.y),
+            (gz * self.radii.z).copysign(w.z),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+//! Support mapping based swept shape.
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+use na::{RealField, Unit};
+
+/// The volume swept by a convex shape translated along a displacement vector.
+///
+/// This is the Minkowski sum of the shape with the segment joining the origin to
+/// `self.displacement`. Its support point is thus the support point of the shape at either
+/// end of the motion, whichever is the furthest along the support direction.
+///
+/// This is useful to conservatively cover the whole linear motion of a shape during a
+/// single timestep.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Swept<N: RealField + Copy, G> {
+    /// The shape being swept.
+    pub shape: G,
+    /// The displacement of the shape, expressed in the local-space of the shape.
+    pub displacement: Vector<N>,
+}
+
+impl<N: RealField + Copy, G> Swept<N, G> {
+    /// Creates the volume swept by `shape` when translated by `displacement`.
+    ///
+    /// The displacement must be expressed in the local-space of `shape`.
+    #[inline]
+    pub fn new(shape: G, displacement: Vector<N>) -> Swept<N, G> {
+        Swept {
+            shape,
+            displacement,
+        }
+    }
+
+    /// Creates the volume swept by `shape`, initially positioned at `m`, when translated by
+    /// the world-space displacement `displacement`.
+    #[inline]
+    pub fn from_world_displacement(
+        shape: G,
+        m: &Isometry<N>,
+        displacement: &Vector<N>,
+    ) -> Swept<N, G> {
+        Swept::new(shape, m.inverse_transform_vector(displacement))
+    }
+}
+
+impl<N: RealField + Copy, G: SupportMap<N>> SupportMap<N> for Swept<N, G> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        let pt = self.shape.local_support_point(dir);
+
+        if self.displacement.dot(dir) > N::zero() {
+            pt + self.displacement
+        } else {
+            pt
+        }
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        let pt = self.shape.local_support_point_toward(dir);
+
+        if self.displacement.dot(dir) > N::zero() {
+            pt + self.displacement
+        } else {
+            pt
+        }
+    }
+}
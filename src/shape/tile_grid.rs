@@ -0,0 +1,471 @@
+//! 2D grid of solid and empty cells.
+
+use crate::bounding_volume::{self, AABB};
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::{BVHImpl, BVT};
+use crate::query::{Contact, ContactKinematic, ContactPrediction, ContactPreprocessor};
+use crate::shape::{CompositeShape, FeatureId, Segment, Shape};
+use na::{self, Point2, RealField, Unit};
+
+const NO_EDGE: usize = usize::MAX;
+
+/// A boundary edge of a tile grid.
+///
+/// Each edge separates a maximal run of solid cells from empty cells along a single grid
+/// line.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TileGridEdge<N: RealField + Copy> {
+    /// The identifiers of the grid vertices at the extremities of this edge.
+    ///
+    /// They are ordered such that the counterclockwise normal of the edge is `self.normal`.
+    pub indices: Point2<usize>,
+    /// The normal of this edge, pointing toward the empty cells.
+    pub normal: Unit<Vector<N>>,
+    bvt_leaf: usize,
+}
+
+/// A 2D grid of square tiles, each of which is either solid or empty.
+///
+/// The cell on the `i`-th row and `j`-th column covers the rectangle
+/// `[j * cell_size.x, (j + 1) * cell_size.x] x [i * cell_size.y, (i + 1) * cell_size.y]` of the
+/// grid local space. Cells outside of the grid are considered empty.
+///
+/// The shape of a tile grid is described by the boundary between its solid and empty cells.
+/// Adjacent cell faces along the same grid line are merged into a single edge so that shapes
+/// sliding along a flat run of tiles never catch on the internal corners between cells. This
+/// shape is a composite shape whose parts are those edges. Contacts involving the back side of
+/// an edge are discarded by the narrow phase.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct TileGrid<N: RealField + Copy> {
+    nrows: usize,
+    ncols: usize,
+    cell_size: Vector<N>,
+    cells: Vec<u64>,
+    edges: Vec<TileGridEdge<N>>,
+    // The edge containing each cell face along the horizontal, then the vertical, grid lines.
+    horizontal_faces: Vec<usize>,
+    vertical_faces: Vec<usize>,
+    // An edge adjacent to each grid vertex.
+    vertex_edges: Vec<usize>,
+    bvt: BVT<usize, AABB<N>>,
+}
+
+impl<N: RealField + Copy> TileGrid<N> {
+    /// Creates a tile grid with `nrows` rows and `ncols` columns of empty cells.
+    pub fn new(nrows: usize, ncols: usize, cell_size: Vector<N>) -> Self {
+        Self::from_fn(nrows, ncols, cell_size, |_, _| false)
+    }
+
+    /// Creates a tile grid where the cell on the `i`-th row and `j`-th column is solid iff.
+    /// `is_solid(i, j)` returns `true`.
+    pub fn from_fn(
+        nrows: usize,
+        ncols: usize,
+        cell_size: Vector<N>,
+        mut is_solid: impl FnMut(usize, usize) -> bool,
+    ) -> Self {
+        assert!(
+            cell_size.x > N::zero() && cell_size.y > N::zero(),
+            "The cells of a tile grid must have a strictly positive size."
+        );
+
+        let mut cells = vec![0; (nrows * ncols).div_ceil(64)];
+
+        for i in 0..nrows {
+            for j in 0..ncols {
+                if is_solid(i, j) {
+                    let id = i * ncols + j;
+                    cells[id / 64] |= 1 << (id % 64);
+                }
+            }
+        }
+
+        let mut res = TileGrid {
+            nrows,
+            ncols,
+            cell_size,
+            cells,
+            edges: Vec::new(),
+            horizontal_faces: Vec::new(),
+            vertical_faces: Vec::new(),
+            vertex_edges: Vec::new(),
+            bvt: BVT::new_balanced(Vec::new()),
+        };
+
+        res.rebuild_edges();
+        res
+    }
+
+    /// The number of rows of this grid.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// The number of columns of this grid.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The size of each cell of this grid.
+    #[inline]
+    pub fn cell_size(&self) -> &Vector<N> {
+        &self.cell_size
+    }
+
+    /// Whether the cell on the `i`-th row and `j`-th column is solid.
+    ///
+    /// Returns `false` if this cell is outside of the grid.
+    #[inline]
+    pub fn is_solid(&self, i: usize, j: usize) -> bool {
+        if i >= self.nrows || j >= self.ncols {
+            return false;
+        }
+
+        let id = i * self.ncols + j;
+        self.cells[id / 64] & (1 << (id % 64)) != 0
+    }
+
+    /// Marks the cell on the `i`-th row and `j`-th column as solid or empty.
+    ///
+    /// The edges of this grid are recomputed, which takes a time linear wrt. the number of cells.
+    pub fn set_solid(&mut self, i: usize, j: usize, solid: bool) {
+        assert!(
+            i < self.nrows && j < self.ncols,
+            "Tile grid cell index out of bounds."
+        );
+
+        if self.is_solid(i, j) != solid {
+            let id = i * self.ncols + j;
+            self.cells[id / 64] ^= 1 << (id % 64);
+            self.rebuild_edges();
+        }
+    }
+
+    /// The row and column of the cell containing the given point, if it lies inside of the grid.
+    pub fn cell_at_point(&self, pt: &Point<N>) -> Option<(usize, usize)> {
+        let i = self.quantize(pt.y, self.cell_size.y, self.nrows)?;
+        let j = self.quantize(pt.x, self.cell_size.x, self.ncols)?;
+        Some((i, j))
+    }
+
+    fn quantize(&self, val: N, size: N, len: usize) -> Option<usize> {
+        let cell = (val / size).floor();
+
+        if cell < N::zero() || val > size * na::convert(len as f64) {
+            return None;
+        }
+
+        let cell = na::convert_unchecked::<N, f64>(cell) as usize;
+        Some(cell.min(len.max(1) - 1))
+    }
+
+    /// The AABB of the cell on the `i`-th row and `j`-th column.
+    pub fn cell_aabb(&self, i: usize, j: usize) -> AABB<N> {
+        let mins = self.vertex(i * (self.ncols + 1) + j);
+        AABB::new(mins, mins + self.cell_size)
+    }
+
+    /// The AABB of the whole grid, including its empty cells.
+    pub fn grid_aabb(&self) -> AABB<N> {
+        let extents = Vector::new(
+            self.cell_size.x * na::convert(self.ncols as f64),
+            self.cell_size.y * na::convert(self.nrows as f64),
+        );
+        AABB::new(Point::origin(), Point::from(extents))
+    }
+
+    /// The AABB of the solid cells of this grid.
+    ///
+    /// This is the AABB of the whole grid if it has no solid cell.
+    pub fn aabb(&self) -> AABB<N> {
+        self.bvt
+            .root_bounding_volume()
+            .cloned()
+            .unwrap_or_else(|| self.grid_aabb())
+    }
+
+    /// The position of the grid vertex with the given identifier.
+    ///
+    /// The vertex at the intersection of the `i`-th horizontal and the `j`-th vertical grid lines
+    /// has the identifier `i * (self.ncols() + 1) + j`.
+    #[inline]
+    pub fn vertex(&self, id: usize) -> Point<N> {
+        let i = id / (self.ncols + 1);
+        let j = id % (self.ncols + 1);
+
+        Point::new(
+            self.cell_size.x * na::convert(j as f64),
+            self.cell_size.y * na::convert(i as f64),
+        )
+    }
+
+    /// The boundary edges of this grid.
+    #[inline]
+    pub fn edges(&self) -> &[TileGridEdge<N>] {
+        &self.edges
+    }
+
+    /// The segment of the `i`-th edge of this grid.
+    #[inline]
+    pub fn edge_segment(&self, i: usize) -> Segment<N> {
+        let idx = self.edges[i].indices;
+        Segment::new(self.vertex(idx.x), self.vertex(idx.y))
+    }
+
+    /// The index of the edge containing the face on the `i`-th horizontal grid line, below the
+    /// cell of the `j`-th column.
+    pub fn horizontal_face_edge(&self, i: usize, j: usize) -> Option<usize> {
+        Some(self.horizontal_faces[i * self.ncols + j]).filter(|e| *e != NO_EDGE)
+    }
+
+    /// The index of the edge containing the face on the `j`-th vertical grid line, left of the
+    /// cell of the `i`-th row.
+    pub fn vertical_face_edge(&self, i: usize, j: usize) -> Option<usize> {
+        Some(self.vertical_faces[i * (self.ncols + 1) + j]).filter(|e| *e != NO_EDGE)
+    }
+
+    /// The optimization structure used by this grid.
+    #[inline]
+    pub fn bvt(&self) -> &BVT<usize, AABB<N>> {
+        &self.bvt
+    }
+
+    /// Returns `true` if the given feature is a `FeatureId::Face` and identifies the back side of
+    /// an edge of this grid.
+    #[inline]
+    pub fn is_backface(&self, feature: FeatureId) -> bool {
+        if let FeatureId::Face(i) = feature {
+            i >= self.edges.len()
+        } else {
+            false
+        }
+    }
+
+    /// The index of an edge containing the given feature.
+    pub fn edge_containing_feature(&self, id: FeatureId) -> usize {
+        match id {
+            FeatureId::Vertex(i) => Some(self.vertex_edges[i])
+                .filter(|e| *e != NO_EDGE)
+                .expect("Invalid tile grid vertex feature."),
+            FeatureId::Face(i) => i % self.edges.len(),
+            _ => panic!("Feature ID cannot be unknown."),
+        }
+    }
+
+    /// Converts a feature of the segment of the `edge_id`-th edge to a feature of this grid.
+    pub fn segment_feature_to_tile_grid_feature(
+        &self,
+        edge_id: usize,
+        feature: FeatureId,
+    ) -> FeatureId {
+        let edge = &self.edges[edge_id];
+        match feature {
+            FeatureId::Vertex(i) => FeatureId::Vertex(edge.indices[i]),
+            FeatureId::Face(0) => FeatureId::Face(edge_id),
+            FeatureId::Face(_) => FeatureId::Face(edge_id + self.edges.len()),
+            _ => FeatureId::Unknown,
+        }
+    }
+
+    /// Tests that the given `dir` is on the tangent cone of the given face of this grid.
+    pub fn face_tangent_cone_contains_dir(&self, i: usize, dir: &Unit<Vector<N>>) -> bool {
+        let nedges = self.edges.len();
+        let normal = self.edges[i % nedges].normal;
+
+        if i < nedges {
+            normal.dot(dir) <= N::zero()
+        } else {
+            normal.dot(dir) >= N::zero()
+        }
+    }
+
+    fn rebuild_edges(&mut self) {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        let mut edges = Vec::new();
+        let mut horizontal_faces = vec![NO_EDGE; (nrows + 1) * ncols];
+        let mut vertical_faces = vec![NO_EDGE; nrows * (ncols + 1)];
+        let mut vertex_edges = vec![NO_EDGE; (nrows + 1) * (ncols + 1)];
+        let vid = |i: usize, j: usize| i * (ncols + 1) + j;
+
+        // Horizontal grid lines, each face being between the cells (i - 1, j) and (i, j).
+        for i in 0..=nrows {
+            let mut j = 0;
+
+            while j < ncols {
+                let below = i > 0 && self.is_solid(i - 1, j);
+                let above = self.is_solid(i, j);
+
+                if below == above {
+                    j += 1;
+                    continue;
+                }
+
+                let start = j;
+
+                while j < ncols
+                    && (i > 0 && self.is_solid(i - 1, j)) == below
+                    && self.is_solid(i, j) == above
+                {
+                    horizontal_faces[i * ncols + j] = edges.len();
+                    j += 1;
+                }
+
+                // Orient the edge so that its counterclockwise normal points toward the empty cell.
+                let (indices, normal) = if below {
+                    (Point2::new(vid(i, j), vid(i, start)), Vector::y_axis())
+                } else {
+                    (Point2::new(vid(i, start), vid(i, j)), -Vector::y_axis())
+                };
+
+                vertex_edges[indices.x] = edges.len();
+                vertex_edges[indices.y] = edges.len();
+                edges.push(TileGridEdge {
+                    indices,
+                    normal,
+                    bvt_leaf: 0,
+                });
+            }
+        }
+
+        // Vertical grid lines, each face being between the cells (i, j - 1) and (i, j).
+        for j in 0..=ncols {
+            let mut i = 0;
+
+            while i < nrows {
+                let left = j > 0 && self.is_solid(i, j - 1);
+                let right = self.is_solid(i, j);
+
+                if left == right {
+                    i += 1;
+                    continue;
+                }
+
+                let start = i;
+
+                while i < nrows
+                    && (j > 0 && self.is_solid(i, j - 1)) == left
+                    && self.is_solid(i, j) == right
+                {
+                    vertical_faces[i * (ncols + 1) + j] = edges.len();
+                    i += 1;
+                }
+
+                let (indices, normal) = if left {
+                    (Point2::new(vid(start, j), vid(i, j)), Vector::x_axis())
+                } else {
+                    (Point2::new(vid(i, j), vid(start, j)), -Vector::x_axis())
+                };
+
+                vertex_edges[indices.x] = edges.len();
+                vertex_edges[indices.y] = edges.len();
+                edges.push(TileGridEdge {
+                    indices,
+                    normal,
+                    bvt_leaf: 0,
+                });
+            }
+        }
+
+        self.edges = edges;
+        self.horizontal_faces = horizontal_faces;
+        self.vertical_faces = vertical_faces;
+        self.vertex_edges = vertex_edges;
+
+        let leaves = (0..self.edges.len())
+            .map(|i| (i, bounding_volume::local_aabb(&self.edge_segment(i))))
+            .collect();
+        self.bvt = BVT::new_balanced(leaves);
+
+        for (i, leaf) in self.bvt.leaves().iter().enumerate() {
+            self.edges[*leaf.data()].bvt_leaf = i;
+        }
+    }
+}
+
+impl<N: RealField + Copy> CompositeShape<N> for TileGrid<N> {
+    #[inline]
+    fn nparts(&self) -> usize {
+        self.edges.len()
+    }
+
+    #[inline(always)]
+    fn map_part_at(
+        &self,
+        i: usize,
+        m: &Isometry<N>,
+        f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>),
+    ) {
+        let element = self.edge_segment(i);
+        f(m, &element)
+    }
+
+    fn map_part_and_preprocessor_at(
+        &self,
+        i: usize,
+        m: &Isometry<N>,
+        _prediction: &ContactPrediction<N>,
+        f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>, &dyn ContactPreprocessor<N>),
+    ) {
+        let element = self.edge_segment(i);
+        let proc = TileGridContactProcessor::new(self, m, i);
+        f(m, &element, &proc)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<N> {
+        *self.bvt.leaf(self.edges[i].bvt_leaf).bounding_volume()
+    }
+
+    #[inline]
+    fn bvh(&self) -> BVHImpl<'_, N, usize, AABB<N>> {
+        BVHImpl::BVT(&self.bvt)
+    }
+}
+
+/// The contact preprocessor dedicated to tile grids.
+///
+/// It maps the features of the edge segments to the features of the grid, and discards the
+/// contacts that would push the other shape toward the solid cells.
+struct TileGridContactProcessor<'a, N: RealField + Copy> {
+    grid: &'a TileGrid<N>,
+    pos: &'a Isometry<N>,
+    edge_id: usize,
+}
+
+impl<'a, N: RealField + Copy> TileGridContactProcessor<'a, N> {
+    pub fn new(grid: &'a TileGrid<N>, pos: &'a Isometry<N>, edge_id: usize) -> Self {
+        TileGridContactProcessor { grid, pos, edge_id }
+    }
+}
+
+impl<'a, N: RealField + Copy> ContactPreprocessor<N> for TileGridContactProcessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        let local_normal = self.pos.inverse_transform_unit_vector(&c.normal);
+        let outward = self.grid.edges[self.edge_id].normal;
+
+        if is_first {
+            let feature = kinematic.feature1();
+            kinematic.set_feature1(
+                self.grid
+                    .segment_feature_to_tile_grid_feature(self.edge_id, feature),
+            );
+            local_normal.dot(&outward) >= N::zero()
+        } else {
+            let feature = kinematic.feature2();
+            kinematic.set_feature2(
+                self.grid
+                    .segment_feature_to_tile_grid_feature(self.edge_id, feature),
+            );
+            local_normal.dot(&outward) <= N::zero()
+        }
+    }
+}
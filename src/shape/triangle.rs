@@ -3,11 +3,10 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::Segment;
 use crate::shape::SupportMap;
-#[cfg(feature = "dim3")]
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId};
+use crate::utils;
 use na::RealField;
 use na::{self, Unit};
-#[cfg(feature = "dim3")]
 use std::f64;
 use std::mem;
 
@@ -232,10 +231,48 @@ impl<N: RealField + Copy> Triangle<N> {
         }
     }
 
+    /// The outward normal of the `i`-th edge of this triangle, whatever its orientation.
+    ///
+    /// The 0-th, 1-st and 2-nd edges are respectively AB, BC and CA.
+    #[cfg(feature = "dim2")]
+    fn edge_normal(&self, i: usize) -> Option<Unit<Vector<N>>> {
+        let vtx = self.vertices();
+        let normal = utils::ccw_face_normal([&vtx[i], &vtx[(i + 1) % 3]])?;
+
+        if (self.b - self.a).perp(&(self.c - self.a)) >= N::zero() {
+            Some(normal)
+        } else {
+            Some(-normal)
+        }
+    }
+
+    /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn tangent_cone_contains_dir(
+        &self,
+        feature: FeatureId,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+    ) -> bool {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let inward = |i: usize| {
+            self.edge_normal(i)
+                .map(|n| ls_dir.dot(&n) <= N::zero())
+                .unwrap_or(false)
+        };
+
+        match feature {
+            FeatureId::Face(i) => inward(i),
+            FeatureId::Vertex(i) => inward((i + 2) % 3) && inward(i),
+            _ => panic!("Invalid feature ID."),
+        }
+    }
+
     #[cfg(feature = "dim3")]
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>, eps: N) -> FeatureId {
         if let Some(normal) = self.normal() {
-            let (seps, ceps) = eps.sin_cos();
+            let (seps, ceps) = utils::sin_cos(eps);
 
             let normal_dot = local_dir.dot(&*normal);
             if normal_dot >= ceps {
@@ -310,6 +347,103 @@ impl<N: RealField + Copy> SupportMap<N> for Triangle<N> {
     }
 }
 
+#[cfg(feature = "dim2")]
+impl<N: RealField + Copy> ConvexPolyhedron<N> for Triangle<N> {
+    fn vertex(&self, id: FeatureId) -> Point<N> {
+        match id.unwrap_vertex() {
+            0 => self.a,
+            1 => self.b,
+            2 => self.c,
+            _ => panic!("Triangle vertex index out of bounds."),
+        }
+    }
+
+    fn face(&self, id: FeatureId, face: &mut ConvexPolygonalFeature<N>) {
+        face.clear();
+
+        let ia = id.unwrap_face();
+        let ib = (ia + 1) % 3;
+
+        if let Some(normal) = self.edge_normal(ia) {
+            let vtx = self.vertices();
+            face.push(vtx[ia], FeatureId::Vertex(ia));
+            face.push(vtx[ib], FeatureId::Vertex(ib));
+            face.set_normal(normal);
+            face.set_feature_id(id);
+        } else {
+            face.push(self.vertex(FeatureId::Vertex(ia)), FeatureId::Vertex(ia));
+            face.set_feature_id(FeatureId::Vertex(ia));
+        }
+    }
+
+    fn feature_normal(&self, feature: FeatureId) -> Unit<Vector<N>> {
+        let normal = match feature {
+            FeatureId::Face(id) => self.edge_normal(id),
+            FeatureId::Vertex(id) => self
+                .edge_normal((id + 2) % 3)
+                .and_then(|n1| Unit::try_new(*n1 + *self.edge_normal(id)?, N::default_epsilon())),
+            _ => panic!("Invalid feature ID: {:?}", feature),
+        };
+
+        normal.unwrap_or_else(Vector::y_axis)
+    }
+
+    fn support_face_toward(
+        &self,
+        m: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        face: &mut ConvexPolygonalFeature<N>,
+    ) {
+        let ls_dir = m.inverse_transform_vector(dir);
+        let mut best_face = 0;
+        let mut max_dot = None;
+
+        for i in 0..3 {
+            if let Some(normal) = self.edge_normal(i) {
+                let dot = normal.dot(&ls_dir);
+
+                if max_dot.map_or(true, |max| dot > max) {
+                    max_dot = Some(dot);
+                    best_face = i;
+                }
+            }
+        }
+
+        self.face(FeatureId::Face(best_face), face);
+        face.transform_by(m)
+    }
+
+    fn support_feature_toward(
+        &self,
+        transform: &Isometry<N>,
+        dir: &Unit<Vector<N>>,
+        _angle: N,
+        face: &mut ConvexPolygonalFeature<N>,
+    ) {
+        face.clear();
+        // FIXME: actualy find the support feature.
+        self.support_face_toward(transform, dir, face)
+    }
+
+    fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId {
+        let eps: N = na::convert(f64::consts::PI / 180.0);
+        let ceps = utils::cos(eps);
+
+        for i in 0..3 {
+            if let Some(normal) = self.edge_normal(i) {
+                if normal.dot(local_dir.as_ref()) >= ceps {
+                    return FeatureId::Face(i);
+                }
+            }
+        }
+
+        FeatureId::Vertex(utils::point_cloud_support_point_id(
+            local_dir.as_ref(),
+            self.vertices(),
+        ))
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField + Copy> ConvexPolyhedron<N> for Triangle<N> {
     fn vertex(&self, id: FeatureId) -> Point<N> {
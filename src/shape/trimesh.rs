@@ -1,6 +1,8 @@
 //! 2d line strip, 3d triangle mesh, and nd subsimplex mesh.
 
-use crate::bounding_volume::{self, BoundingVolume, AABB};
+use crate::bounding_volume::{
+    self, BoundingSphere, BoundingVolume, CircularCone, SpatializedNormalCone, AABB,
+};
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::partitioning::{BVHImpl, BVT};
 use crate::procedural;
@@ -84,7 +86,10 @@ pub struct TriMeshVertex {
 #[derive(Clone)]
 pub struct TriMesh<N: RealField + Copy> {
     bvt: BVT<usize, AABB<N>>,
+    normal_cone_bvt: Option<BVT<usize, SpatializedNormalCone<N>>>,
+    bounding_sphere: BoundingSphere<N>,
     uvs: Option<Vec<Point2<N>>>,
+    face_data: Option<Vec<u32>>,
     points: Vec<Point<N>>,
     vertices: Vec<TriMeshVertex>,
     edges: Vec<TriMeshEdge>,
@@ -93,6 +98,8 @@ pub struct TriMesh<N: RealField + Copy> {
     adj_vertex_list: Vec<usize>,
     deformations: DeformationInfos<N>,
     oriented: bool,
+    closed: bool,
+    thickness: N,
 }
 
 impl<N: RealField + Copy> TriMesh<N> {
@@ -182,10 +189,15 @@ impl<N: RealField + Copy> TriMesh<N> {
             tri_to_update: Vec::new(),
         };
 
+        let bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&points);
+
         TriMesh {
             bvt,
+            normal_cone_bvt: None,
+            bounding_sphere,
             points,
             uvs,
+            face_data: None,
             deformations,
             vertices,
             edges,
@@ -193,6 +205,8 @@ impl<N: RealField + Copy> TriMesh<N> {
             adj_face_list,
             adj_vertex_list,
             oriented: false,
+            closed: false,
+            thickness: N::zero(),
         }
     }
 
@@ -331,6 +345,25 @@ impl<N: RealField + Copy> TriMesh<N> {
         adj_face_list
     }
 
+    // Recomputes the face normals and side normals after the vertices moved.
+    fn update_face_normals(&mut self) {
+        for face in &mut self.faces {
+            let triangle = Triangle::new(
+                self.points[face.indices.x],
+                self.points[face.indices.y],
+                self.points[face.indices.z],
+            );
+            face.normal = triangle.normal();
+            face.side_normals = face.normal.map(|n| {
+                [
+                    Unit::new_normalize((triangle.b - triangle.a).cross(&n)),
+                    Unit::new_normalize((triangle.c - triangle.b).cross(&n)),
+                    Unit::new_normalize((triangle.a - triangle.c).cross(&n)),
+                ]
+            });
+        }
+    }
+
     fn refit_bvt(&mut self) {
         let mut leaves = Vec::with_capacity(self.faces.len());
         for (i, face) in self.faces.iter().enumerate() {
@@ -348,6 +381,68 @@ impl<N: RealField + Copy> TriMesh<N> {
         for (i, leaf) in self.bvt.leaves().iter().enumerate() {
             self.faces[*leaf.data()].bvt_leaf = i;
         }
+
+        self.update_normal_cone_bvt();
+    }
+
+    fn build_normal_cone_bvt(&self) -> BVT<usize, SpatializedNormalCone<N>> {
+        let mut leaves = Vec::with_capacity(self.faces.len());
+        for (i, face) in self.faces.iter().enumerate() {
+            let triangle = Triangle::new(
+                self.points[face.indices[0]],
+                self.points[face.indices[1]],
+                self.points[face.indices[2]],
+            );
+            // Degenerate faces have no normal, so they are never front-facing.
+            let normals = triangle
+                .normal()
+                .map(|n| CircularCone::Spread {
+                    axis: n,
+                    angle: N::zero(),
+                })
+                .unwrap_or(CircularCone::Empty);
+            let bv = SpatializedNormalCone {
+                aabb: triangle.local_aabb(),
+                normals,
+            };
+            leaves.push((i, bv));
+        }
+
+        BVT::new_balanced(leaves)
+    }
+
+    fn update_normal_cone_bvt(&mut self) {
+        if self.normal_cone_bvt.is_some() {
+            self.normal_cone_bvt = Some(self.build_normal_cone_bvt());
+        }
+    }
+
+    /// Computes a bounding volume tree storing, for each node, a cone bounding the normals of the faces it contains.
+    ///
+    /// Once computed, this tree is kept up-to-date each time this mesh is transformed, scaled, or deformed.
+    /// It can be traversed with the `FrontFacingFacesCollector` or the `FrontFacingRayInterferencesCollector`
+    /// visitors to quickly discard groups of faces facing away from a given direction.
+    pub fn compute_normal_cones(&mut self) {
+        self.normal_cone_bvt = Some(self.build_normal_cone_bvt());
+    }
+
+    /// Builds this mesh with per-node normal cones.
+    ///
+    /// See `compute_normal_cones` for details.
+    pub fn with_normal_cones(mut self) -> Self {
+        self.compute_normal_cones();
+        self
+    }
+
+    /// Deletes the normal cones computed by `compute_normal_cones`.
+    pub fn clear_normal_cones(&mut self) {
+        self.normal_cone_bvt = None;
+    }
+
+    /// The bounding volume tree storing the normal cones of this mesh, if they have been computed.
+    #[inline]
+    pub fn normal_cone_bvt(&self) -> Option<&BVT<usize, SpatializedNormalCone<N>>> {
+        self.normal_cone_bvt.as_ref()
     }
 
     /// The triangle mesh's AABB.
@@ -358,6 +453,12 @@ impl<N: RealField + Copy> TriMesh<N> {
             .expect("An empty TriMesh has no AABB.")
     }
 
+    /// The smallest sphere enclosing the points of this mesh, ignoring its thickness.
+    #[inline]
+    pub(crate) fn cached_bounding_sphere(&self) -> &BoundingSphere<N> {
+        &self.bounding_sphere
+    }
+
     /// The points of this mesh.
     #[inline]
     pub fn points(&self) -> &[Point<N>] {
@@ -387,6 +488,8 @@ impl<N: RealField + Copy> TriMesh<N> {
         for pt in &mut self.points {
             *pt = transform * *pt
         }
+        self.bounding_sphere = self.bounding_sphere.transform_by(transform);
+        self.update_face_normals();
         self.refit_bvt();
     }
 
@@ -401,6 +504,8 @@ impl<N: RealField + Copy> TriMesh<N> {
         for pt in &mut self.points {
             pt.coords.component_mul_assign(scale)
         }
+        self.bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&self.points);
+        self.update_face_normals();
         self.refit_bvt();
     }
 
@@ -426,6 +531,59 @@ impl<N: RealField + Copy> TriMesh<N> {
         self.oriented = oriented
     }
 
+    /// Whether this trimesh is considered as the closed boundary of a solid.
+    ///
+    /// By default a trimesh is not closed.
+    #[inline]
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Whether this trimesh is considered as the closed boundary of a solid.
+    ///
+    /// A closed trimesh is assumed to be watertight with outward face normals. Point queries will
+    /// then detect points located inside of this mesh, and report negative distances for them.
+    #[inline]
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed
+    }
+
+    /// The collision thickness of this mesh.
+    ///
+    /// See `self.set_thickness` for details.
+    #[inline]
+    pub fn thickness(&self) -> N {
+        self.thickness
+    }
+
+    /// Sets the collision thickness of this mesh.
+    ///
+    /// Contact and ray queries then treat each triangle as if it was dilated by `thickness` on both
+    /// sides, i.e., replaced by its Minkowski sum with a ball of radius `thickness`. This prevents
+    /// small fast objects from tunneling through thin walls between two frames. It is ignored by
+    /// the other geometric queries, and UV coordinates are not computed by ray casts on a thick
+    /// mesh. The contacts between two meshes are dilated by the thicknesses of both meshes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `thickness` is negative.
+    #[inline]
+    pub fn set_thickness(&mut self, thickness: N) {
+        assert!(
+            thickness >= N::zero(),
+            "The thickness of a mesh must be positive or zero."
+        );
+        self.thickness = thickness
+    }
+
+    /// Builds this mesh with the given collision thickness.
+    ///
+    /// See `self.set_thickness` for details.
+    pub fn with_thickness(mut self, thickness: N) -> Self {
+        self.set_thickness(thickness);
+        self
+    }
+
     /// Face containing feature.
     #[inline]
     pub fn face_containing_feature(&self, id: FeatureId) -> usize {
@@ -450,6 +608,57 @@ impl<N: RealField + Copy> TriMesh<N> {
         self.uvs.as_ref().map(|uvs| &uvs[..])
     }
 
+    /// The user data attached to each face of this mesh, e.g., material identifiers.
+    #[inline]
+    pub fn face_data(&self) -> Option<&[u32]> {
+        self.face_data.as_ref().map(|data| &data[..])
+    }
+
+    /// Attaches one user-defined value to each face of this mesh, or removes them.
+    ///
+    /// These values are reported by the ray casts and point projections on this mesh, and can be
+    /// retrieved from the features of contacts with `self.face_data_at_feature(feature)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not contain exactly one value per face.
+    pub fn set_face_data(&mut self, data: Option<Vec<u32>>) {
+        if let Some(data) = &data {
+            assert_eq!(
+                data.len(),
+                self.faces.len(),
+                "The per-face data must contain exactly one value per face."
+            );
+        }
+
+        self.face_data = data;
+    }
+
+    /// Attaches one user-defined value to each face of this mesh.
+    ///
+    /// See `self.set_face_data` for details.
+    pub fn with_face_data(mut self, data: Vec<u32>) -> Self {
+        self.set_face_data(Some(data));
+        self
+    }
+
+    /// The user data attached to the face containing the given feature.
+    ///
+    /// Vertices and edges are attached to the data of one of their adjacent faces. Returns `None`
+    /// if this mesh does not have per-face data or if the feature is unknown.
+    pub fn face_data_at_feature(&self, id: FeatureId) -> Option<u32> {
+        match (&self.face_data, id) {
+            (_, FeatureId::Unknown) | (None, _) => None,
+            (Some(data), id) => Some(data[self.face_containing_feature(id)]),
+        }
+    }
+
+    /// The user data attached to the `i`-th face of this mesh.
+    #[inline]
+    pub(crate) fn face_data_at(&self, i: usize) -> Option<u32> {
+        self.face_data.as_ref().map(|data| data[i])
+    }
+
     /// The adjacent vertices list of this mesh.
     ///
     /// Use `TriMeshVertex.adj_vertices` to index this. Elements are indexes into the `vertices` list.
@@ -474,6 +683,111 @@ impl<N: RealField + Copy> TriMesh<N> {
         Triangle::new(self.points[idx.x], self.points[idx.y], self.points[idx.z])
     }
 
+    /// Labels each face of this mesh with the index of the connected component it belongs to.
+    ///
+    /// Two faces belong to the same component if they are linked by a sequence of faces sharing
+    /// a vertex. Vertices are identified by their index, so duplicated points are not welded.
+    /// Components are numbered in increasing order of their first face; the number of components
+    /// is thus the largest label plus one.
+    pub fn connected_components(&self) -> Vec<usize> {
+        let mut labels = vec![usize::max_value(); self.faces.len()];
+        let mut stack = Vec::new();
+        let mut num_components = 0;
+
+        for first in 0..self.faces.len() {
+            if labels[first] != usize::max_value() {
+                continue;
+            }
+
+            labels[first] = num_components;
+            stack.push(first);
+
+            while let Some(face) = stack.pop() {
+                for vid in self.faces[face].indices.iter() {
+                    let adj_faces = self.vertices[*vid].adj_faces.clone();
+
+                    for adj_face in &self.adj_face_list[adj_faces] {
+                        if labels[*adj_face] == usize::max_value() {
+                            labels[*adj_face] = num_components;
+                            stack.push(*adj_face);
+                        }
+                    }
+                }
+            }
+
+            num_components += 1;
+        }
+
+        labels
+    }
+
+    /// Splits this mesh into one mesh per connected component.
+    ///
+    /// The meshes are given in the order of the labels returned by `self.connected_components()`.
+    /// Each mesh only keeps the points used by its faces, in the same relative order, together
+    /// with their texture coordinates. The per-face data, the orientation flag and the normal cones,
+    /// if they were computed, are kept as well.
+    pub fn split_components(&self) -> Vec<TriMesh<N>> {
+        let labels = self.connected_components();
+        let num_components = labels.iter().map(|l| l + 1).max().unwrap_or(0);
+        let mut indices = vec![Vec::new(); num_components];
+        let mut face_data = vec![Vec::new(); num_components];
+        let mut used = vec![usize::max_value(); self.points.len()];
+
+        for (i, (face, label)) in self.faces.iter().zip(labels.iter()).enumerate() {
+            indices[*label].push(face.indices);
+
+            if let Some(data) = self.face_data_at(i) {
+                face_data[*label].push(data);
+            }
+
+            for vid in face.indices.iter() {
+                used[*vid] = *label;
+            }
+        }
+
+        let mut remap = vec![0; self.points.len()];
+
+        indices
+            .into_iter()
+            .zip(face_data)
+            .enumerate()
+            .map(|(label, (mut indices, face_data))| {
+                let mut points = Vec::new();
+                let mut uvs = self.uvs.as_ref().map(|_| Vec::new());
+
+                for (vid, vlabel) in used.iter().enumerate() {
+                    if *vlabel == label {
+                        remap[vid] = points.len();
+                        points.push(self.points[vid]);
+
+                        if let (Some(uvs), Some(self_uvs)) = (&mut uvs, &self.uvs) {
+                            uvs.push(self_uvs[vid]);
+                        }
+                    }
+                }
+
+                for idx in &mut indices {
+                    *idx = idx.map(|vid| remap[vid]);
+                }
+
+                let mut mesh = TriMesh::new(points, indices, uvs);
+                mesh.oriented = self.oriented;
+                mesh.closed = self.closed;
+
+                if self.face_data.is_some() {
+                    mesh.face_data = Some(face_data);
+                }
+
+                if self.normal_cone_bvt.is_some() {
+                    mesh.compute_normal_cones();
+                }
+
+                mesh
+            })
+            .collect()
+    }
+
     /// Returns `true` if the given feature is a FeatureId::Face and
     /// identifies a backface of this trimesh.
     #[inline]
@@ -738,6 +1052,69 @@ impl<N: RealField + Copy> TriMesh<N> {
         }
     }
 
+    /// The normal of the face adjacent to `feature` which is the closest to `local_dir`.
+    ///
+    /// Returns `None` if `feature` lies on the boundary of this mesh, or if all its adjacent
+    /// faces are degenerate. Both orientations of each face are considered if this mesh is not
+    /// oriented.
+    fn closest_adjacent_face_normal(
+        &self,
+        feature: FeatureId,
+        local_dir: &Unit<Vector<N>>,
+    ) -> Option<Unit<Vector<N>>> {
+        let oriented = self.oriented;
+        let mut best: Option<(N, Unit<Vector<N>>)> = None;
+        let mut push_candidate = |normal: Option<Unit<Vector<N>>>| {
+            if let Some(mut normal) = normal {
+                let mut dot = normal.dot(local_dir);
+
+                if !oriented && dot < N::zero() {
+                    normal = -normal;
+                    dot = -dot;
+                }
+
+                if best.map(|b| dot > b.0).unwrap_or(true) {
+                    best = Some((dot, normal));
+                }
+            }
+        };
+
+        match feature {
+            FeatureId::Face(i) => {
+                if i >= self.faces.len() {
+                    push_candidate(self.faces[i - self.faces.len()].normal.map(|n| -n))
+                } else {
+                    push_candidate(self.faces[i].normal)
+                }
+            }
+            FeatureId::Edge(i) => {
+                let e = &self.edges[i];
+
+                if e.adj_faces.0.face_id == e.adj_faces.1.face_id {
+                    return None;
+                }
+
+                push_candidate(self.faces[e.adj_faces.0.face_id].normal);
+                push_candidate(self.faces[e.adj_faces.1.face_id].normal);
+            }
+            FeatureId::Vertex(i) => {
+                let v = &self.vertices[i];
+
+                // The fan of faces around a vertex on the boundary is not closed.
+                if v.adj_faces.len() != v.adj_vertices.len() {
+                    return None;
+                }
+
+                for adj_face in &self.adj_face_list[v.adj_faces.clone()] {
+                    push_candidate(self.faces[*adj_face].normal)
+                }
+            }
+            FeatureId::Unknown => {}
+        }
+
+        best.map(|b| b.1)
+    }
+
     fn init_deformation_infos(&mut self) -> bool {
         if self.deformations.ref_vertices.is_empty() {
             self.deformations.timestamps = iter::repeat(0).take(self.faces.len()).collect();
@@ -790,6 +1167,11 @@ impl<N: RealField + Copy> CompositeShape<N> for TriMesh<N> {
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
         BVHImpl::BVT(&self.bvt)
     }
+
+    #[inline]
+    fn thickness(&self) -> N {
+        self.thickness
+    }
 }
 
 impl<N: RealField + Copy> DeformableShape<N> for TriMesh<N> {
@@ -871,7 +1253,9 @@ impl<N: RealField + Copy> DeformableShape<N> for TriMesh<N> {
         }
 
         // FIXME: measure efficiency with a non-zero margin.
-        self.bvt.refit(N::zero())
+        self.bvt.refit(N::zero());
+        self.update_normal_cone_bvt();
+        self.bounding_sphere = bounding_volume::bounding_sphere_exact_or_empty(&self.points);
     }
 
     fn update_local_approximation(&self, coords: &[N], approx: &mut LocalShapeApproximation<N>) {
@@ -966,8 +1350,10 @@ impl<'a, N: RealField + Copy> TriMeshContactProcessor<'a, N> {
     }
 }
 
-impl<'a, N: RealField + Copy> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N> {
-    fn process_contact(
+impl<'a, N: RealField + Copy> TriMeshContactProcessor<'a, N> {
+    // Maps the features of the contact to the mesh features, and checks that the contact normal
+    // matches the neighborhood of the mesh feature.
+    fn process_surface_contact(
         &self,
         c: &mut Contact<N>,
         kinematic: &mut ContactKinematic<N>,
@@ -1002,6 +1388,58 @@ impl<'a, N: RealField + Copy> ContactPreprocessor<N> for TriMeshContactProcessor
 
         // Test the validity of the LMD.
         if c.depth > N::zero() {
+            // Penetrating contacts with internal features of the mesh may have a normal
+            // which is not in the normal cone of the mesh, e.g., pointing along a flat surface
+            // because of an internal edge. Replace it by the closest adjacent face normal so
+            // objects slide smoothly across the mesh.
+            let local_dir = self.pos.inverse_transform_unit_vector(&c.normal);
+            let (outward, sin_ang_tol, cos_ang_tol) = if is_first {
+                (
+                    local_dir,
+                    self.prediction.sin_angular1(),
+                    self.prediction.cos_angular1(),
+                )
+            } else {
+                (
+                    -local_dir,
+                    self.prediction.sin_angular2(),
+                    self.prediction.cos_angular2(),
+                )
+            };
+
+            if !self.mesh.tangent_cone_polar_contains_dir(
+                actual_feature,
+                &outward,
+                sin_ang_tol,
+                cos_ang_tol,
+            ) {
+                if let Some(corrected) = self
+                    .mesh
+                    .closest_adjacent_face_normal(actual_feature, &outward)
+                {
+                    // Keep the point on the other shape and move the mesh point to the plane
+                    // of the face, along the corrected normal.
+                    let corrected = self.pos * corrected;
+                    let (world_mesh, world_other) = if is_first {
+                        (c.world1, c.world2)
+                    } else {
+                        (c.world2, c.world1)
+                    };
+                    let depth = (world_mesh - world_other).dot(&corrected);
+                    let world_mesh = world_other + *corrected * depth;
+
+                    if depth < -self.prediction.linear() {
+                        return false;
+                    }
+
+                    *c = if is_first {
+                        Contact::new(world_mesh, world_other, corrected, depth)
+                    } else {
+                        Contact::new(world_other, world_mesh, -corrected, depth)
+                    };
+                }
+            }
+
             true
         } else {
             let local_dir = self.pos.inverse_transform_unit_vector(&c.normal);
@@ -1024,3 +1462,32 @@ impl<'a, N: RealField + Copy> ContactPreprocessor<N> for TriMeshContactProcessor
         }
     }
 }
+
+impl<'a, N: RealField + Copy> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        if !self.process_surface_contact(c, kinematic, is_first) {
+            return false;
+        }
+
+        let thickness = self.mesh.thickness();
+
+        if thickness > N::zero() {
+            if is_first {
+                kinematic.set_dilation1(thickness);
+                c.world1 += *c.normal * thickness;
+            } else {
+                kinematic.set_dilation2(thickness);
+                c.world2 -= *c.normal * thickness;
+            }
+
+            c.depth += thickness;
+        }
+
+        true
+    }
+}
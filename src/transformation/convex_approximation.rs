@@ -0,0 +1,77 @@
+use crate::math::{Point, Vector};
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexHull;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::SupportMap;
+use na::{self, RealField, Unit};
+
+/// Approximates a support-mapped shape by the convex polygon of `nsamples` of its support points.
+///
+/// The support directions are uniformly distributed on the unit circle. The result is inscribed
+/// into `shape`, and is exact for polygonal shapes if `nsamples` is large enough for each vertex to
+/// be the support point of at least one direction. Returns `None` if less than three distinct
+/// support points were found.
+#[cfg(feature = "dim2")]
+pub fn convex_approximation<N: RealField + Copy, G: SupportMap<N> + ?Sized>(
+    shape: &G,
+    nsamples: usize,
+) -> Option<ConvexPolygon<N>> {
+    let points = sample_support_points(shape, nsamples);
+    ConvexPolygon::try_from_points(&points)
+}
+
+/// Approximates a support-mapped shape by the convex hull of `nsamples` of its support points.
+///
+/// The support directions are distributed on the unit sphere along a Fibonacci spiral, which is
+/// close to uniform for any number of samples. The result is inscribed into `shape`, and is exact
+/// for polyhedral shapes if `nsamples` is large enough for each vertex to be the support point of
+/// at least one direction. This allows, e.g., cones, cylinders or Minkowski sums to be exported
+/// or clipped as explicit polyhedra. Returns `None` if the support points are degenerate.
+#[cfg(feature = "dim3")]
+pub fn convex_approximation<N: RealField + Copy, G: SupportMap<N> + ?Sized>(
+    shape: &G,
+    nsamples: usize,
+) -> Option<ConvexHull<N>> {
+    let points = sample_support_points(shape, nsamples);
+    ConvexHull::try_from_points(&points)
+}
+
+fn sample_support_points<N: RealField + Copy, G: SupportMap<N> + ?Sized>(
+    shape: &G,
+    nsamples: usize,
+) -> Vec<Point<N>> {
+    let eps = N::default_epsilon().sqrt();
+    let mut points: Vec<Point<N>> = Vec::new();
+
+    for i in 0..nsamples {
+        let pt = shape.local_support_point_toward(&sample_direction(i, nsamples));
+
+        // Many directions share the same support point on polyhedral shapes.
+        if points
+            .iter()
+            .all(|p| na::distance_squared(p, &pt) > eps * eps)
+        {
+            points.push(pt);
+        }
+    }
+
+    points
+}
+
+#[cfg(feature = "dim2")]
+fn sample_direction<N: RealField + Copy>(i: usize, n: usize) -> Unit<Vector<N>> {
+    let angle = N::two_pi() * na::convert(i as f64) / na::convert(n as f64);
+    Unit::new_unchecked(Vector::new(angle.cos(), angle.sin()))
+}
+
+#[cfg(feature = "dim3")]
+fn sample_direction<N: RealField + Copy>(i: usize, n: usize) -> Unit<Vector<N>> {
+    // The golden angle.
+    let golden_angle: N = na::convert(std::f64::consts::PI * (3.0 - 5.0f64.sqrt()));
+    let y = N::one() - na::convert::<f64, N>((2 * i + 1) as f64) / na::convert(n as f64);
+    let r = (N::one() - y * y).max(N::zero()).sqrt();
+    let theta = golden_angle * na::convert(i as f64);
+
+    Unit::new_normalize(Vector::new(r * theta.cos(), y, r * theta.sin()))
+}
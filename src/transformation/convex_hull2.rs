@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 #[cfg(feature = "dim2")]
 use crate::procedural::Polyline;
+use crate::query::QueryBuffers;
 use crate::transformation::convex_hull_utils::{indexed_support_point_id, support_point_id};
 use na::{self, Point2, Vector2};
 use simba::scalar::RealField;
@@ -9,21 +10,40 @@ use simba::scalar::RealField;
 /// Computes the convex hull of a set of 2d points.
 #[cfg(feature = "dim2")]
 pub fn convex_hull2<N: RealField + Copy>(points: &[Point2<N>]) -> Polyline<N> {
-    let idx = convex_hull2_idx(points);
+    convex_hull2_with_buffers(points, &mut QueryBuffers::new())
+}
+
+/// Computes the convex hull of a set of 2d points, reusing the scratch memory of `buffers`.
+#[cfg(feature = "dim2")]
+pub fn convex_hull2_with_buffers<N: RealField + Copy>(
+    points: &[Point2<N>],
+    buffers: &mut QueryBuffers<N>,
+) -> Polyline<N> {
+    let idx = convex_hull2_idx_with_buffers(points, buffers);
     let mut pts = Vec::new();
 
     for id in idx.into_iter() {
         pts.push(points[id].clone());
     }
 
-    Polyline::new(pts, None)
+    Polyline::new(pts, None).with_closed(true)
 }
 
 /// Computes the convex hull of a set of 2d points and returns only the indices of the hull
 /// vertices.
 pub fn convex_hull2_idx<N: RealField + Copy>(points: &[Point2<N>]) -> Vec<usize> {
-    let mut undecidable_points = Vec::new();
-    let mut segments = get_initial_polyline(points, &mut undecidable_points);
+    convex_hull2_idx_with_buffers(points, &mut QueryBuffers::new())
+}
+
+/// Computes the convex hull of a set of 2d points and returns only the indices of the hull
+/// vertices, reusing the scratch memory of `buffers`.
+pub fn convex_hull2_idx_with_buffers<N: RealField + Copy>(
+    points: &[Point2<N>],
+    buffers: &mut QueryBuffers<N>,
+) -> Vec<usize> {
+    let undecidable_points = &mut buffers.hull.undecidable_points;
+    undecidable_points.clear();
+    let mut segments = get_initial_polyline(points, undecidable_points);
 
     let mut i = 0;
     while i != segments.len() {
@@ -45,7 +65,7 @@ pub fn convex_hull2_idx<N: RealField + Copy>(points: &[Point2<N>]) -> Vec<usize>
                 &points[..],
                 &mut segments,
                 i,
-                &mut undecidable_points,
+                undecidable_points,
             );
         }
 
@@ -2,6 +2,7 @@ use num::Bounded;
 use std::cmp::Ordering;
 
 use crate::procedural::{IndexBuffer, TriMesh};
+use crate::query::QueryBuffers;
 use crate::transformation::{
     self,
     convex_hull_utils::{denormalize, indexed_support_point_id, normalize, support_point_id},
@@ -26,6 +27,14 @@ fn cov<N: RealField + Copy>(pts: &[Point3<N>]) -> Matrix3<N> {
 
 /// Computes the convex hull of a set of 3d points.
 pub fn convex_hull3<N: RealField + Copy>(points: &[Point3<N>]) -> TriMesh<N> {
+    convex_hull3_with_buffers(points, &mut QueryBuffers::new())
+}
+
+/// Computes the convex hull of a set of 3d points, reusing the scratch memory of `buffers`.
+pub fn convex_hull3_with_buffers<N: RealField + Copy>(
+    points: &[Point3<N>],
+    buffers: &mut QueryBuffers<N>,
+) -> TriMesh<N> {
     assert!(
         points.len() != 0,
         "Cannot compute the convex hull of an empty set of point."
@@ -35,15 +44,20 @@ pub fn convex_hull3<N: RealField + Copy>(points: &[Point3<N>]) -> TriMesh<N> {
 
     let (norm_center, norm_diag) = normalize(&mut points[..]);
 
-    let mut undecidable_points = Vec::new();
-    let mut horizon_loop_facets = Vec::new();
-    let mut horizon_loop_ids = Vec::new();
-    let mut removed_facets = Vec::new();
+    let hull = &mut buffers.hull;
+    let undecidable_points = &mut hull.undecidable_points;
+    let horizon_loop_facets = &mut hull.horizon_loop_facets;
+    let horizon_loop_ids = &mut hull.horizon_loop_ids;
+    let removed_facets = &mut hull.removed_facets;
+    undecidable_points.clear();
+    horizon_loop_facets.clear();
+    horizon_loop_ids.clear();
+    removed_facets.clear();
 
     let mut triangles;
     let denormalizer;
 
-    match get_initial_mesh(&mut points[..], &mut undecidable_points) {
+    match get_initial_mesh(&mut points[..], undecidable_points) {
         InitialMesh::Facets(facets, denorm) => {
             triangles = facets;
             denormalizer = denorm;
@@ -82,10 +96,10 @@ pub fn convex_hull3<N: RealField + Copy>(points: &[Point3<N>]) -> TriMesh<N> {
                     triangles[i].adj[j],
                     triangles[i].indirect_adj_id[j],
                     point,
-                    &mut horizon_loop_facets,
-                    &mut horizon_loop_ids,
+                    horizon_loop_facets,
+                    horizon_loop_ids,
                     &points[..],
-                    &mut removed_facets,
+                    removed_facets,
                     &mut triangles[..],
                 );
             }
@@ -116,7 +130,7 @@ pub fn convex_hull3<N: RealField + Copy>(points: &[Point3<N>]) -> TriMesh<N> {
                 &points[..],
                 &mut triangles,
                 &removed_facets[..],
-                &mut undecidable_points,
+                undecidable_points,
             );
         }
 
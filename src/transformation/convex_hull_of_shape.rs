@@ -0,0 +1,128 @@
+use crate::math::{Isometry, Point, DIM};
+use crate::query::PointQuery;
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexHull;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{PointCloud, Polyline, Shape};
+#[cfg(feature = "dim3")]
+use crate::transformation::ToTriMeshWithTolerance;
+#[cfg(feature = "dim2")]
+use crate::transformation::{discretization::circle_subdivisions, ToPolyline};
+use na::{self, RealField};
+
+#[cfg(feature = "dim2")]
+type Hull<N> = ConvexPolygon<N>;
+#[cfg(feature = "dim3")]
+type Hull<N> = ConvexHull<N>;
+
+/// Computes the convex polygon enclosing a shape.
+///
+/// See the 3D version of this function for details.
+#[cfg(feature = "dim2")]
+pub fn convex_hull_of_shape<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    tolerance: Option<N>,
+) -> Option<ConvexPolygon<N>> {
+    let points = shape_points(shape, tolerance);
+    if points.len() < DIM + 1 {
+        return None;
+    }
+
+    let hull = ConvexPolygon::try_from_points(&points)?;
+    Some(simplify(hull, tolerance))
+}
+
+/// Computes the convex hull enclosing a shape.
+///
+/// The hull is computed from the vertices of polyhedral shapes, e.g., cuboids, triangle meshes,
+/// convex hulls, or point clouds. Curved shapes are discretized first so the result is inscribed
+/// into them. If `tolerance` is given, the hull is then simplified by removing the vertices
+/// located at a distance smaller than `tolerance` from the hull of the remaining ones, which
+/// collapses nearly coplanar faces. The discretization of curved shapes uses the same tolerance,
+/// or one hundredth of the radius of their bounding sphere if `tolerance` is `None`. The
+/// simplification computes one convex hull per vertex, so it is meant to be used once, when
+/// setting up the collision objects.
+///
+/// Returns `None` if the shape is unbounded, like planes, or if its hull is degenerate.
+#[cfg(feature = "dim3")]
+pub fn convex_hull_of_shape<N: RealField + Copy>(
+    shape: &dyn Shape<N>,
+    tolerance: Option<N>,
+) -> Option<ConvexHull<N>> {
+    let points = shape_points(shape, tolerance);
+    if points.len() < DIM + 1 {
+        return None;
+    }
+
+    let hull = ConvexHull::try_from_points(&points)?;
+    Some(simplify(hull, tolerance))
+}
+
+fn shape_points<N: RealField + Copy>(shape: &dyn Shape<N>, tolerance: Option<N>) -> Vec<Point<N>> {
+    if let Some(s) = shape.as_shape::<PointCloud<N>>() {
+        return s.points().to_vec();
+    } else if let Some(s) = shape.as_shape::<Polyline<N>>() {
+        return s.points().to_vec();
+    }
+
+    let radius = shape.local_bounding_sphere().radius();
+    let tolerance = tolerance.unwrap_or(radius * na::convert(0.01));
+
+    if radius <= N::zero() || tolerance <= N::zero() {
+        return Vec::new();
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        shape
+            .to_polyline(circle_subdivisions(radius, tolerance))
+            .coords()
+            .to_vec()
+    }
+    #[cfg(feature = "dim3")]
+    {
+        shape.to_trimesh_with_tolerance(tolerance).coords
+    }
+}
+
+// Greedily removes the vertices of `hull` as long as every removed vertex stays close enough to
+// the simplified hull. Because the simplified hull is contained by the original one, this bounds
+// the distance between both hulls. Removing a vertex can only move the others further away from
+// the hull, so each vertex needs to be tested only once.
+fn simplify<N: RealField + Copy>(hull: Hull<N>, tolerance: Option<N>) -> Hull<N> {
+    let tolerance = match tolerance {
+        Some(tolerance) if tolerance > N::zero() => tolerance,
+        _ => return hull,
+    };
+
+    let identity = Isometry::identity();
+    let vertices = hull.points().to_vec();
+    let mut hull = hull;
+    let mut removed: Vec<Point<N>> = Vec::new();
+
+    for vertex in vertices {
+        let candidate_points: Vec<_> = hull
+            .points()
+            .iter()
+            .filter(|pt| **pt != vertex)
+            .cloned()
+            .collect();
+
+        if candidate_points.len() == hull.points().len() || candidate_points.len() < DIM + 1 {
+            continue;
+        }
+
+        if let Some(candidate) = Hull::try_from_points(&candidate_points) {
+            let is_close =
+                |pt: &Point<N>| candidate.distance_to_point(&identity, pt, true) <= tolerance;
+
+            if is_close(&vertex) && removed.iter().all(is_close) {
+                removed.push(vertex);
+                hull = candidate;
+            }
+        }
+    }
+
+    hull
+}
@@ -0,0 +1,177 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
+use crate::partitioning::{VisitStatus, Visitor, BVH};
+use crate::shape::{Plane, Polyline, TriMesh};
+use crate::utils::SortedPair;
+use na::{Point2, RealField};
+use std::collections::HashMap;
+
+/// Computes the intersection curves between a triangle mesh and a plane.
+///
+/// The plane is positioned by `m`, expressed in the local-space of `mesh`. The mesh BVT is used
+/// to find the triangles crossed by the plane, and adjacent intersection segments are chained
+/// together into polylines. If the mesh is a closed, consistently oriented manifold, each
+/// returned polyline is closed (its last vertex is linked to its first one). Otherwise, open
+/// polylines may be returned for the parts of the curve reaching the mesh boundary.
+///
+/// Triangles lying exactly on the plane do not contribute to the cross section.
+pub fn cross_section<N: RealField + Copy>(
+    mesh: &TriMesh<N>,
+    m: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Vec<Polyline<N>> {
+    let normal = m * plane.normal.into_inner();
+    let origin = Point::from(m.translation.vector);
+    let mut crossed = Vec::new();
+
+    {
+        let mut visitor = PlaneInterferencesCollector {
+            normal: &normal,
+            origin: &origin,
+            collector: &mut crossed,
+        };
+        mesh.bvt().visit(&mut visitor);
+    }
+
+    let pts = mesh.points();
+    let dists: Vec<N> = pts.iter().map(|pt| (pt - origin).dot(&normal)).collect();
+
+    let mut section_pts = Vec::new();
+    let mut point_ids = HashMap::new();
+    // Ids of the start and end points of each intersection segment.
+    let mut segments = Vec::new();
+
+    for i in crossed {
+        let idx = mesh.faces()[i].indices;
+        let mut start = None;
+        let mut end = None;
+
+        for k in 0..3 {
+            let (ia, ib) = (idx[k], idx[(k + 1) % 3]);
+            let (da, db) = (dists[ia], dists[ib]);
+            let above_a = da >= N::zero();
+            let above_b = db >= N::zero();
+
+            if above_a == above_b {
+                continue;
+            }
+
+            // Vertices lying exactly on the plane are shared by all the edges touching them.
+            let key = if da.is_zero() {
+                CrossingKey::Vertex(ia)
+            } else if db.is_zero() {
+                CrossingKey::Vertex(ib)
+            } else {
+                CrossingKey::Edge(SortedPair::new(ia, ib))
+            };
+
+            let id = *point_ids.entry(key).or_insert_with(|| {
+                let t = da / (da - db);
+                section_pts.push(pts[ia] + (pts[ib] - pts[ia]) * t);
+                section_pts.len() - 1
+            });
+
+            if above_a {
+                end = Some(id);
+            } else {
+                start = Some(id);
+            }
+        }
+
+        if let (Some(start), Some(end)) = (start, end) {
+            if start != end {
+                segments.push((start, end));
+            }
+        }
+    }
+
+    chain_segments(&section_pts, &segments)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum CrossingKey {
+    Vertex(usize),
+    Edge(SortedPair<usize>),
+}
+
+fn chain_segments<N: RealField + Copy>(
+    pts: &[Point<N>],
+    segments: &[(usize, usize)],
+) -> Vec<Polyline<N>> {
+    let mut next = HashMap::new();
+    let mut has_predecessor = vec![false; pts.len()];
+
+    for (i, seg) in segments.iter().enumerate() {
+        let _ = next.entry(seg.0).or_insert(i);
+        has_predecessor[seg.1] = true;
+    }
+
+    let mut visited = vec![false; segments.len()];
+    let mut result = Vec::new();
+
+    // Open chains first, starting from the segments without predecessor,
+    // then the remaining closed loops.
+    let heads = (0..segments.len())
+        .filter(|i| !has_predecessor[segments[*i].0])
+        .chain(0..segments.len());
+
+    for head in heads {
+        if visited[head] {
+            continue;
+        }
+
+        let first = segments[head].0;
+        let mut chain = vec![pts[first]];
+        let mut curr = head;
+        let mut closed = false;
+
+        loop {
+            visited[curr] = true;
+            let end = segments[curr].1;
+
+            if end == first {
+                closed = true;
+                break;
+            }
+
+            chain.push(pts[end]);
+
+            match next.get(&end) {
+                Some(&n) if !visited[n] => curr = n,
+                _ => break,
+            }
+        }
+
+        let nedges = if closed { chain.len() } else { chain.len() - 1 };
+        let indices = (0..nedges)
+            .map(|i| Point2::new(i, (i + 1) % chain.len()))
+            .collect();
+        result.push(Polyline::new(chain, Some(indices)));
+    }
+
+    result
+}
+
+struct PlaneInterferencesCollector<'a, N: RealField + Copy> {
+    normal: &'a Vector<N>,
+    origin: &'a Point<N>,
+    collector: &'a mut Vec<usize>,
+}
+
+impl<'a, N: RealField + Copy> Visitor<usize, AABB<N>> for PlaneInterferencesCollector<'a, N> {
+    #[inline]
+    fn visit(&mut self, bv: &AABB<N>, data: Option<&usize>) -> VisitStatus {
+        let dist = (bv.center() - self.origin).dot(self.normal);
+        let radius = bv.half_extents().dot(&self.normal.abs());
+
+        if dist.abs() > radius {
+            return VisitStatus::Stop;
+        }
+
+        if let Some(data) = data {
+            self.collector.push(*data);
+        }
+
+        VisitStatus::Continue
+    }
+}
@@ -0,0 +1,25 @@
+use na::RealField;
+
+/// The smallest number of segments of a regular polygon inscribed into a circle of radius
+/// `radius` such that each segment is at most at a distance `tolerance` from the circle.
+///
+/// The result is at least 3 and at most 1024.
+pub(crate) fn circle_subdivisions<N: RealField + Copy>(radius: N, tolerance: N) -> u32 {
+    assert!(
+        tolerance > N::zero(),
+        "The discretization tolerance must be strictly positive."
+    );
+
+    if tolerance >= radius {
+        return 3;
+    }
+
+    // A segment spanning the angle `a` is at the distance `radius * (1 - cos(a / 2))` from the
+    // circle.
+    let max_angle = (N::one() - tolerance / radius).acos() * na::convert(2.0);
+    let nsubdivs = (N::two_pi() / max_angle).ceil();
+
+    na::try_convert::<N, f64>(nsubdivs)
+        .map(|n| n.clamp(3.0, 1024.0) as u32)
+        .unwrap_or(1024)
+}
@@ -0,0 +1,295 @@
+use crate::bounding_volume;
+use crate::math::{Point, Vector};
+use crate::shape::Polyline;
+use crate::utils;
+use na::{self, Point2, RealField};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+struct Sample<N: RealField + Copy> {
+    point: Point<N>,
+    contour: usize,
+    id: usize,
+}
+
+struct Triangle<N: RealField + Copy> {
+    vertices: [usize; 3],
+    center: Point<N>,
+    sq_radius: N,
+}
+
+impl<N: RealField + Copy> Triangle<N> {
+    fn new(vertices: [usize; 3], points: &[Point<N>]) -> Self {
+        let (center, radius) = utils::circumcircle(
+            &points[vertices[0]],
+            &points[vertices[1]],
+            &points[vertices[2]],
+        );
+
+        Triangle {
+            vertices,
+            center,
+            sq_radius: radius * radius,
+        }
+    }
+}
+
+/// Computes an approximation of the medial axis of the region enclosed by a polyline.
+///
+/// The medial axis is the set of centers of the disks that fit into the region and touch its
+/// boundary at least twice. The edges of `polyline` must form closed non-intersecting loops, e.g.,
+/// an outer boundary and its holes. The boundary is sampled with points at most `spacing` apart,
+/// and the medial axis is approximated by the part of the Voronoi diagram of these samples that
+/// lies inside of the region and separates samples that are not neighbors along the boundary.
+/// The result is a graph whose vertices are located at a distance of the order of `spacing` from
+/// the exact medial axis. Branches that reach convex corners of the boundary stop slightly before
+/// them.
+///
+/// The computation is quadratic wrt. the number of samples. Returns `None` if `polyline` does not
+/// enclose any region.
+pub fn medial_axis<N: RealField + Copy>(polyline: &Polyline<N>, spacing: N) -> Option<Polyline<N>> {
+    assert!(
+        spacing > N::zero(),
+        "The sampling spacing must be strictly positive."
+    );
+
+    let samples = sample_contours(polyline, spacing);
+    let points: Vec<_> = samples.iter().map(|s| s.point).collect();
+
+    if points.len() < 3 {
+        return None;
+    }
+
+    let mut contour_lens = vec![0; samples[samples.len() - 1].contour + 1];
+
+    for s in &samples {
+        contour_lens[s.contour] += 1;
+    }
+
+    let triangles = delaunay(&points);
+
+    // Map each Delaunay edge to the triangles sharing it.
+    let mut adjacency: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for (i, tri) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let (a, b) = (tri.vertices[k], tri.vertices[(k + 1) % 3]);
+            adjacency.entry((a.min(b), a.max(b))).or_default().push(i);
+        }
+    }
+
+    let inside: Vec<_> = triangles
+        .iter()
+        .map(|t| t.vertices.iter().all(|v| *v < points.len()) && contains(polyline, &t.center))
+        .collect();
+
+    let mut edges: Vec<_> = adjacency
+        .into_iter()
+        .filter(|((a, b), tris)| {
+            if tris.len() != 2 || !inside[tris[0]] || !inside[tris[1]] {
+                return false;
+            }
+
+            // The bisector of samples close to each other along the boundary is a branch of the
+            // Voronoi diagram that goes straight to the boundary.
+            let (sa, sb) = (&samples[*a], &samples[*b]);
+            let len = contour_lens[sa.contour];
+            let dist = sa.id.abs_diff(sb.id);
+
+            sa.contour != sb.contour || dist.min(len - dist) > 2
+        })
+        .map(|(_, tris)| (tris[0], tris[1]))
+        .collect();
+    edges.sort_unstable();
+
+    // Merge the circumcenters of cocircular samples.
+    let tolerance = spacing * N::default_epsilon().sqrt();
+    let mut ids = vec![usize::MAX; triangles.len()];
+    let mut medial_points: Vec<Point<N>> = Vec::new();
+    let mut order: Vec<_> = (0..triangles.len()).filter(|i| inside[*i]).collect();
+    order.sort_by(|i, j| {
+        triangles[*i]
+            .center
+            .x
+            .partial_cmp(&triangles[*j].center.x)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    for (k, i) in order.iter().enumerate() {
+        if ids[*i] != usize::MAX {
+            continue;
+        }
+
+        let center = triangles[*i].center;
+        ids[*i] = medial_points.len();
+
+        for j in &order[k + 1..] {
+            let other = triangles[*j].center;
+
+            if other.x - center.x > tolerance {
+                break;
+            }
+
+            if ids[*j] == usize::MAX && na::distance(&center, &other) <= tolerance {
+                ids[*j] = medial_points.len();
+            }
+        }
+
+        medial_points.push(center);
+    }
+
+    let mut indices: Vec<_> = edges
+        .into_iter()
+        .map(|(a, b)| (ids[a].min(ids[b]), ids[a].max(ids[b])))
+        .filter(|(a, b)| a != b)
+        .collect();
+    indices.sort_unstable();
+    indices.dedup();
+
+    if indices.is_empty() {
+        return None;
+    }
+
+    // Only keep the points used by the medial edges.
+    let mut new_ids = vec![usize::MAX; medial_points.len()];
+    let mut new_points = Vec::new();
+    let indices = indices
+        .into_iter()
+        .map(|(a, b)| {
+            let mut id = |i: usize| {
+                if new_ids[i] == usize::MAX {
+                    new_ids[i] = new_points.len();
+                    new_points.push(medial_points[i]);
+                }
+
+                new_ids[i]
+            };
+
+            Point2::new(id(a), id(b))
+        })
+        .collect();
+
+    Some(Polyline::new(new_points, Some(indices)))
+}
+
+// Samples each closed loop of `polyline`. The samples of each loop are numbered in order.
+fn sample_contours<N: RealField + Copy>(polyline: &Polyline<N>, spacing: N) -> Vec<Sample<N>> {
+    let vertices = polyline.points();
+    let edges = polyline.edges();
+    let mut next_edge = vec![None; vertices.len()];
+
+    for (i, e) in edges.iter().enumerate() {
+        next_edge[e.indices.x] = Some(i);
+    }
+
+    let mut visited = vec![false; edges.len()];
+    let mut samples = Vec::new();
+    let mut contour = 0;
+
+    for first in 0..edges.len() {
+        let mut curr = Some(first);
+        let mut id = 0;
+
+        while let Some(i) = curr {
+            if visited[i] {
+                break;
+            }
+
+            visited[i] = true;
+            let a = vertices[edges[i].indices.x];
+            let b = vertices[edges[i].indices.y];
+            let nsegments = na::try_convert::<N, f64>((na::distance(&a, &b) / spacing).ceil())
+                .map(|n| n.max(1.0) as usize)
+                .unwrap_or(1);
+
+            for k in 0..nsegments {
+                let t: N = na::convert(k as f64 / nsegments as f64);
+                samples.push(Sample {
+                    point: a + (b - a) * t,
+                    contour,
+                    id,
+                });
+                id += 1;
+            }
+
+            curr = next_edge[edges[i].indices.y];
+        }
+
+        if id != 0 {
+            contour += 1;
+        }
+    }
+
+    samples
+}
+
+// Bowyer-Watson triangulation of `points`, enclosed by three additional points with indices
+// greater than or equal to `points.len()`.
+fn delaunay<N: RealField + Copy>(points: &[Point<N>]) -> Vec<Triangle<N>> {
+    let mut all_points = points.to_vec();
+    let aabb = bounding_volume::local_point_cloud_aabb(points);
+    let center = aabb.center();
+    let extent = aabb.half_extents().norm() * na::convert(10.0) + N::one();
+    let far = extent * na::convert(3.0);
+
+    all_points.push(center + Vector::new(-far, -extent));
+    all_points.push(center + Vector::new(far, -extent));
+    all_points.push(center + Vector::new(N::zero(), far));
+
+    let n = points.len();
+    let mut triangles = vec![Triangle::new([n, n + 1, n + 2], &all_points)];
+    let mut boundary: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for i in 0..n {
+        let pt = all_points[i];
+        boundary.clear();
+
+        let mut k = 0;
+        while k < triangles.len() {
+            if na::distance_squared(&pt, &triangles[k].center) < triangles[k].sq_radius {
+                let tri = triangles.swap_remove(k);
+
+                for j in 0..3 {
+                    let (a, b) = (tri.vertices[j], tri.vertices[(j + 1) % 3]);
+                    *boundary.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+                }
+            } else {
+                k += 1;
+            }
+        }
+
+        let mut cavity: Vec<_> = boundary
+            .iter()
+            .filter(|(_, count)| **count == 1)
+            .map(|(edge, _)| *edge)
+            .collect();
+        cavity.sort_unstable();
+
+        for (a, b) in cavity {
+            triangles.push(Triangle::new([a, b, i], &all_points));
+        }
+    }
+
+    triangles
+}
+
+// Even-odd point inclusion test wrt. the edges of `polyline`.
+fn contains<N: RealField + Copy>(polyline: &Polyline<N>, pt: &Point<N>) -> bool {
+    let vertices = polyline.points();
+    let mut inside = false;
+
+    for e in polyline.edges() {
+        let a = vertices[e.indices.x];
+        let b = vertices[e.indices.y];
+
+        if (a.y > pt.y) != (b.y > pt.y) {
+            let x = a.x + (pt.y - a.y) / (b.y - a.y) * (b.x - a.x);
+
+            if pt.x < x {
+                inside = !inside;
+            }
+        }
+    }
+
+    inside
+}
@@ -3,25 +3,50 @@
 #[cfg(feature = "dim3")]
 pub(crate) use self::convex_hull2::convex_hull2_idx;
 #[cfg(feature = "dim2")]
-pub use self::convex_hull2::{convex_hull2 as convex_hull, convex_hull2_idx as convex_hull_idx};
+pub use self::convex_hull2::{
+    convex_hull2 as convex_hull, convex_hull2_idx as convex_hull_idx,
+    convex_hull2_idx_with_buffers as convex_hull_idx_with_buffers,
+    convex_hull2_with_buffers as convex_hull_with_buffers,
+};
 #[cfg(feature = "dim3")]
-pub use self::convex_hull3::convex_hull3 as convex_hull;
+pub use self::convex_hull3::{
+    convex_hull3 as convex_hull, convex_hull3_with_buffers as convex_hull_with_buffers,
+};
+pub use self::convex_approximation::convex_approximation;
+pub use self::convex_hull_of_shape::convex_hull_of_shape;
+#[cfg(feature = "dim3")]
+pub use self::cross_section::cross_section;
 #[cfg(feature = "dim3")]
 pub use self::hacd::hacd;
 #[cfg(feature = "dim2")]
+pub use self::medial_axis::medial_axis;
+#[cfg(feature = "dim2")]
+pub use self::offset_polyline::{offset_polyline, OffsetCorner};
+pub use self::simplify_polyline::simplify_polyline;
+#[cfg(feature = "dim2")]
 pub use self::to_polyline::ToPolyline;
 #[cfg(feature = "dim3")]
-pub use self::to_trimesh::ToTriMesh;
+pub use self::to_trimesh::{ToTriMesh, ToTriMeshWithTolerance, TriMeshLOD};
 // pub use self::triangulate::triangulate;
 
+mod convex_approximation;
 mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;
+mod convex_hull_of_shape;
 #[doc(hidden)]
 pub mod convex_hull_utils; // Internal implementation details.
 #[cfg(feature = "dim3")]
+mod cross_section;
+mod discretization;
+#[cfg(feature = "dim3")]
 mod hacd;
 #[cfg(feature = "dim2")]
+mod medial_axis;
+#[cfg(feature = "dim2")]
+mod offset_polyline;
+mod simplify_polyline;
+#[cfg(feature = "dim2")]
 mod to_polyline;
 #[cfg(feature = "dim3")]
 mod to_trimesh;
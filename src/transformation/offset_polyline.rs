@@ -0,0 +1,376 @@
+use crate::math::{Point, Vector};
+use crate::shape::Polyline;
+use crate::transformation::discretization::circle_subdivisions;
+use na::{self, Point2, RealField, UnitComplex};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// The shape of the corners generated by `offset_polyline` where the offset edges separate.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OffsetCorner<N: RealField + Copy> {
+    /// Circular arcs centered on the original vertices, discretized such that each of their
+    /// segments is at most at the given distance from the exact arc.
+    Round(N),
+    /// Sharp corners obtained by extending the offset edges until they meet.
+    ///
+    /// Corners for which the distance between the original vertex and the corner exceeds the
+    /// given multiple of the offset radius are beveled instead.
+    Miter(N),
+}
+
+/// Computes the outline of a polyline offset by `radius`.
+///
+/// The edges of `polyline` are expected to form closed loops with counterclockwise outer
+/// boundaries and clockwise holes. A positive `radius` inflates the enclosed region, i.e.,
+/// computes its Minkowski sum with a disk, and a negative `radius` deflates it. Polylines that
+/// contain open chains, e.g., a single segment, are offset as if each chain enclosed a region of
+/// zero area, so inflating them yields their rounded or mitered outline. In that case, each
+/// vertex must be shared by at most two edges.
+///
+/// The raw offset curves are completed by corners, split at their intersections, and only the
+/// parts separating the offset region from its exterior are kept. The result follows the same
+/// orientation conventions as the input. Returns `None` if the offset region is empty, e.g., if
+/// the polyline is deflated by more than half its width.
+pub fn offset_polyline<N: RealField + Copy>(
+    polyline: &Polyline<N>,
+    radius: N,
+    corner: OffsetCorner<N>,
+) -> Option<Polyline<N>> {
+    if polyline.edges().is_empty() {
+        return None;
+    }
+
+    if radius.is_zero() {
+        return Some(polyline.clone());
+    }
+
+    let (points, segments) = raw_offset_curve(polyline, radius, corner);
+    let tolerance = radius.abs() * N::default_epsilon().sqrt();
+    let (points, segments) = split_at_intersections(points, segments, tolerance);
+
+    let mut indices = Vec::new();
+    let mut kept = HashSet::new();
+
+    for seg in &segments {
+        for sub in seg.windows(2) {
+            let (a, b) = (points[sub[0]], points[sub[1]]);
+            let length = na::distance(&a, &b);
+
+            if length <= N::default_epsilon() {
+                continue;
+            }
+
+            let side = left_normal(&(b - a)) * (length.min(radius.abs()) * na::convert(1.0e-3));
+            let mid = na::center(&a, &b);
+            let filled_left = winding_number(&points, &segments, &(mid + side)) > 0;
+            let filled_right = winding_number(&points, &segments, &(mid - side)) > 0;
+
+            let edge = if filled_left && !filled_right {
+                (sub[0], sub[1])
+            } else if filled_right && !filled_left {
+                (sub[1], sub[0])
+            } else {
+                continue;
+            };
+
+            // Overlapping collinear segments yield the same edge several times.
+            if kept.insert(edge) {
+                indices.push(Point2::new(edge.0, edge.1));
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return None;
+    }
+
+    // Only keep the points used by the kept edges.
+    let mut new_ids = vec![usize::MAX; points.len()];
+    let mut new_points = Vec::new();
+
+    for idx in &mut indices {
+        for i in idx.iter_mut() {
+            if new_ids[*i] == usize::MAX {
+                new_ids[*i] = new_points.len();
+                new_points.push(points[*i]);
+            }
+
+            *i = new_ids[*i];
+        }
+    }
+
+    Some(Polyline::new(new_points, Some(indices)))
+}
+
+// Builds the closed curves obtained by moving each edge of `polyline` by `radius` along its
+// outward normal, and by joining consecutive edges either with a corner, where they separate, or
+// through the original vertex, where they overlap. The region of the offset polyline is the set
+// of points with a positive winding number wrt. these curves.
+//
+// Each segment is returned as the list of its point indices, so it can be split later.
+fn raw_offset_curve<N: RealField + Copy>(
+    polyline: &Polyline<N>,
+    radius: N,
+    corner: OffsetCorner<N>,
+) -> (Vec<Point<N>>, Vec<Vec<usize>>) {
+    let vertices = polyline.points();
+    let mut edges: Vec<(usize, usize)> = polyline
+        .edges()
+        .iter()
+        .map(|e| (e.indices.x, e.indices.y))
+        .filter(|e| vertices[e.0] != vertices[e.1])
+        .collect();
+    let mut out_edges = vec![Vec::new(); vertices.len()];
+
+    for (i, e) in edges.iter().enumerate() {
+        out_edges[e.0].push(i);
+    }
+
+    // Open chains are traversed in both directions.
+    if edges.iter().any(|e| out_edges[e.1].is_empty()) {
+        for i in 0..edges.len() {
+            let e = edges[i];
+            out_edges[e.1].push(edges.len());
+            edges.push((e.1, e.0));
+        }
+    }
+
+    let mut points = Vec::with_capacity(edges.len() * 2);
+    let mut segments = Vec::with_capacity(edges.len() * 2);
+    let mut dirs = Vec::with_capacity(edges.len());
+
+    for e in &edges {
+        let dir = (vertices[e.1] - vertices[e.0]).normalize();
+        let shift = -left_normal(&dir) * radius;
+        segments.push(vec![points.len(), points.len() + 1]);
+        points.push(vertices[e.0] + shift);
+        points.push(vertices[e.1] + shift);
+        dirs.push(dir);
+    }
+
+    for (i, e) in edges.iter().enumerate() {
+        // Prefer the successor that does not go back along the same edge. A vertex without any
+        // other successor is the end of an open chain.
+        let next = out_edges[e.1]
+            .iter()
+            .cloned()
+            .find(|j| edges[*j].1 != e.0)
+            .or_else(|| out_edges[e.1].first().cloned());
+        let next = match next {
+            Some(next) => next,
+            None => continue,
+        };
+
+        let center = vertices[e.1];
+        let (start, end) = (2 * i + 1, 2 * next);
+        let (u1, u2) = (dirs[i], dirs[next]);
+        let cross = u1.perp(&u2);
+        let dot = u1.dot(&u2);
+        let half_turn = cross.is_zero() && dot < N::zero();
+        let mut join = vec![start];
+
+        if cross * radius > N::zero() || (half_turn && radius > N::zero()) {
+            let v1 = points[start] - center;
+            let v2 = points[end] - center;
+
+            match corner {
+                OffsetCorner::Round(tolerance) => {
+                    let angle = cross.abs().atan2(dot);
+                    let nsubdivs = circle_subdivisions(radius.abs(), tolerance);
+                    let fraction = angle / N::two_pi() * na::convert(nsubdivs as f64);
+                    let nsegments = na::try_convert::<N, f64>(fraction.ceil())
+                        .map(|n| n.max(1.0) as usize)
+                        .unwrap_or(1);
+                    let step = angle * radius.signum() / na::convert(nsegments as f64);
+
+                    for k in 1..nsegments {
+                        let rot = UnitComplex::new(step * na::convert(k as f64));
+                        join.push(points.len());
+                        points.push(center + rot * v1);
+                    }
+                }
+                OffsetCorner::Miter(limit) => {
+                    // The miter point is at the distance `radius / cos(angle / 2)` from the
+                    // vertex, and `cos(angle / 2)^2 = (1 + cos(angle)) / 2`.
+                    let cos_half_sq = (N::one() + dot) * na::convert(0.5);
+
+                    if cos_half_sq * limit * limit >= N::one() {
+                        join.push(points.len());
+                        points.push(center + (v1 + v2) / (N::one() + dot));
+                    }
+                }
+            }
+        } else if cross * radius < N::zero() || half_turn {
+            join.push(points.len());
+            points.push(center);
+        }
+
+        join.push(end);
+        segments.extend(join.windows(2).map(|w| w.to_vec()));
+    }
+
+    (points, segments)
+}
+
+// Merges the points closer than `tolerance` to each other, so that the segments meeting at the
+// same location share their endpoints. Then inserts into each segment, sorted along it, the
+// indices of the points where other segments intersect it, or where collinear segments overlapping
+// it end. Points and segments are swept along the `x` axis to avoid testing all their pairs.
+fn split_at_intersections<N: RealField + Copy>(
+    mut points: Vec<Point<N>>,
+    segments: Vec<Vec<usize>>,
+    tolerance: N,
+) -> (Vec<Point<N>>, Vec<Vec<usize>>) {
+    let eps = N::default_epsilon().sqrt();
+    let mut welded: Vec<usize> = (0..points.len()).collect();
+    let mut order = welded.clone();
+    order.sort_by(|i, j| {
+        points[*i]
+            .x
+            .partial_cmp(&points[*j].x)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    for (k, i) in order.iter().enumerate() {
+        for j in &order[k + 1..] {
+            if points[*j].x - points[*i].x > tolerance {
+                break;
+            }
+
+            if welded[*j] == *j && na::distance(&points[*i], &points[*j]) <= tolerance {
+                welded[*j] = welded[*i];
+            }
+        }
+    }
+
+    let segments: Vec<Vec<usize>> = segments
+        .into_iter()
+        .map(|s| vec![welded[s[0]], welded[s[1]]])
+        .filter(|s| s[0] != s[1])
+        .collect();
+    let ranges: Vec<_> = segments
+        .iter()
+        .map(|s| {
+            let (a, b) = (points[s[0]].x, points[s[1]].x);
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|i, j| {
+        ranges[*i]
+            .0
+            .partial_cmp(&ranges[*j].0)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut splits: Vec<Vec<(N, usize)>> = vec![Vec::new(); segments.len()];
+
+    for (k, i) in order.iter().enumerate() {
+        let si = &segments[*i];
+        let (a, b) = (points[si[0]], points[si[1]]);
+
+        for j in &order[k + 1..] {
+            let sj = &segments[*j];
+
+            if ranges[*j].0 > ranges[*i].1 {
+                break;
+            }
+
+            if si.iter().any(|id| sj.contains(id)) {
+                continue;
+            }
+
+            let (c, d) = (points[sj[0]], points[sj[1]]);
+            let (r, s) = (b - a, d - c);
+            let denom = r.perp(&s);
+
+            if denom.abs() <= N::default_epsilon() * r.norm() * s.norm() {
+                if (c - a).perp(&r).abs() <= tolerance * r.norm() {
+                    for &(seg, others, origin, dir) in &[(*i, sj, a, r), (*j, si, c, s)] {
+                        for id in others {
+                            let t = (points[*id] - origin).dot(&dir) / dir.norm_squared();
+
+                            if t > eps && t < N::one() - eps {
+                                splits[seg].push((t, *id));
+                            }
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            let t = (c - a).perp(&s) / denom;
+            let u = (c - a).perp(&r) / denom;
+
+            if t < -eps || t > N::one() + eps || u < -eps || u > N::one() + eps {
+                continue;
+            }
+
+            let endpoint = |param: N, seg: &Vec<usize>| {
+                if param <= eps {
+                    Some(seg[0])
+                } else if param >= N::one() - eps {
+                    Some(seg[1])
+                } else {
+                    None
+                }
+            };
+
+            match (endpoint(t, si), endpoint(u, sj)) {
+                (Some(_), Some(_)) => {}
+                (Some(id), None) => splits[*j].push((u, id)),
+                (None, Some(id)) => splits[*i].push((t, id)),
+                (None, None) => {
+                    splits[*i].push((t, points.len()));
+                    splits[*j].push((u, points.len()));
+                    points.push(a + r * t);
+                }
+            }
+        }
+    }
+
+    let segments = segments
+        .into_iter()
+        .zip(splits)
+        .map(|(seg, mut split)| {
+            split.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+            let mut res = vec![seg[0]];
+            res.extend(split.into_iter().map(|s| s.1));
+            res.push(seg[1]);
+            res.dedup();
+            res
+        })
+        .collect();
+
+    (points, segments)
+}
+
+fn winding_number<N: RealField + Copy>(
+    points: &[Point<N>],
+    segments: &[Vec<usize>],
+    pt: &Point<N>,
+) -> i32 {
+    let mut winding = 0;
+
+    for seg in segments {
+        let a = points[seg[0]];
+        let b = points[seg[seg.len() - 1]];
+        let side = (b - a).perp(&(pt - a));
+
+        if a.y <= pt.y {
+            if b.y > pt.y && side > N::zero() {
+                winding += 1;
+            }
+        } else if b.y <= pt.y && side < N::zero() {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+fn left_normal<N: RealField + Copy>(v: &Vector<N>) -> Vector<N> {
+    let length = v.norm();
+    Vector::new(-v.y / length, v.x / length)
+}
@@ -0,0 +1,211 @@
+use crate::math::{Isometry, Point};
+use crate::query::PointQuery;
+use crate::shape::{Polyline, Segment};
+use na::{self, Point2, RealField};
+use std::cmp::Ordering;
+
+/// Simplifies a polyline with the Ramer–Douglas–Peucker algorithm.
+///
+/// The edges of `polyline` are split into chains joined at vertices that are not shared by
+/// exactly two edges, and each chain is simplified independently by removing the vertices that
+/// are within a distance `epsilon` of the simplified chain. The vertices joining several
+/// chains and the endpoints of open chains are always kept, and closed loops keep at least three
+/// vertices. The Hausdorff distance between each chain and its simplification is at most
+/// `epsilon`, so the result deviates from the original polyline by at most `epsilon`.
+///
+/// The points of the result are a subset of the points of `polyline`. Chains are oriented like
+/// their first edge, and the result inherits the thickness and orientation flag of `polyline`.
+pub fn simplify_polyline<N: RealField + Copy>(polyline: &Polyline<N>, epsilon: N) -> Polyline<N> {
+    assert!(
+        epsilon >= N::zero(),
+        "The simplification tolerance must be positive or zero."
+    );
+
+    let vertices = polyline.points();
+    let edges: Vec<_> = polyline
+        .edges()
+        .iter()
+        .map(|e| (e.indices.x, e.indices.y))
+        .collect();
+
+    if edges.is_empty() {
+        return polyline.clone();
+    }
+
+    let mut adj_edges = vec![Vec::new(); vertices.len()];
+
+    for (i, e) in edges.iter().enumerate() {
+        adj_edges[e.0].push(i);
+        adj_edges[e.1].push(i);
+    }
+
+    let is_junction = |v: usize| adj_edges[v].len() != 2;
+    let mut visited = vec![false; edges.len()];
+    let mut chains = Vec::new();
+
+    // Open chains, and loops going through a junction, start and end at junctions. The
+    // remaining edges form loops made only of vertices shared by two edges.
+    let junctions = (0..vertices.len()).filter(|v| is_junction(*v));
+    let loops = edges.iter().map(|e| e.0).filter(|v| !is_junction(*v));
+
+    for start in junctions.chain(loops) {
+        for k in 0..adj_edges[start].len() {
+            let first = adj_edges[start][k];
+
+            if visited[first] {
+                continue;
+            }
+
+            let mut chain = vec![start];
+            let mut curr_edge = first;
+            let mut curr = start;
+
+            loop {
+                visited[curr_edge] = true;
+                let e = edges[curr_edge];
+                curr = if e.0 == curr { e.1 } else { e.0 };
+                chain.push(curr);
+
+                if curr == start || is_junction(curr) {
+                    break;
+                }
+
+                match adj_edges[curr].iter().find(|i| !visited[**i]) {
+                    Some(next) => curr_edge = *next,
+                    None => break,
+                }
+            }
+
+            if edges[first].0 != start {
+                chain.reverse();
+            }
+
+            chains.push(chain);
+        }
+    }
+
+    let mut new_ids = vec![usize::MAX; vertices.len()];
+    let mut new_points = Vec::new();
+    let mut indices = Vec::new();
+
+    for chain in chains {
+        let kept = simplify_chain(vertices, &chain, epsilon);
+
+        for w in kept.windows(2) {
+            let mut id = |i: usize| {
+                if new_ids[i] == usize::MAX {
+                    new_ids[i] = new_points.len();
+                    new_points.push(vertices[i]);
+                }
+
+                new_ids[i]
+            };
+
+            let a = id(w[0]);
+            let b = id(w[1]);
+            indices.push(Point2::new(a, b));
+        }
+    }
+
+    let mut result = Polyline::new(new_points, Some(indices)).with_thickness(polyline.thickness());
+    result.set_oriented(polyline.oriented());
+    result
+}
+
+// Returns the vertices of `chain` kept by the simplification, in order.
+fn simplify_chain<N: RealField + Copy>(
+    vertices: &[Point<N>],
+    chain: &[usize],
+    epsilon: N,
+) -> Vec<usize> {
+    let last = chain.len() - 1;
+    let mut keep = vec![false; chain.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    if chain[0] == chain[last] {
+        // Split closed loops at the vertex farthest from their first vertex.
+        let origin = vertices[chain[0]];
+        let far = (1..last)
+            .max_by(|i, j| {
+                let di = na::distance_squared(&origin, &vertices[chain[*i]]);
+                let dj = na::distance_squared(&origin, &vertices[chain[*j]]);
+                di.partial_cmp(&dj).unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(0);
+        keep[far] = true;
+
+        rdp(vertices, chain, 0, far, epsilon, &mut keep);
+        rdp(vertices, chain, far, last, epsilon, &mut keep);
+
+        // A loop reduced to two vertices would be flattened into a back-and-forth segment.
+        if keep.iter().filter(|k| **k).count() < 4 {
+            let extra = (1..last).filter(|i| *i != far).max_by(|i, j| {
+                let (lo_i, hi_i) = if *i < far { (0, far) } else { (far, last) };
+                let (lo_j, hi_j) = if *j < far { (0, far) } else { (far, last) };
+                let di = chord_distance(vertices, chain, lo_i, hi_i, *i);
+                let dj = chord_distance(vertices, chain, lo_j, hi_j, *j);
+                di.partial_cmp(&dj).unwrap_or(Ordering::Equal)
+            });
+
+            if let Some(extra) = extra {
+                keep[extra] = true;
+            }
+        }
+    } else {
+        rdp(vertices, chain, 0, last, epsilon, &mut keep);
+    }
+
+    chain
+        .iter()
+        .zip(keep.iter())
+        .filter(|(_, k)| **k)
+        .map(|(v, _)| *v)
+        .collect()
+}
+
+// Marks the vertices of `chain[start..=end]` that must be kept for the simplified chain to stay
+// within `epsilon` of the original one.
+fn rdp<N: RealField + Copy>(
+    vertices: &[Point<N>],
+    chain: &[usize],
+    start: usize,
+    end: usize,
+    epsilon: N,
+    keep: &mut [bool],
+) {
+    let mut stack = vec![(start, end)];
+
+    while let Some((lo, hi)) = stack.pop() {
+        let mut farthest = None;
+        let mut max_dist = epsilon;
+
+        for i in lo + 1..hi {
+            let dist = chord_distance(vertices, chain, lo, hi, i);
+
+            if dist > max_dist {
+                max_dist = dist;
+                farthest = Some(i);
+            }
+        }
+
+        if let Some(i) = farthest {
+            keep[i] = true;
+            stack.push((lo, i));
+            stack.push((i, hi));
+        }
+    }
+}
+
+// The distance between the `i`-th vertex of `chain` and the segment joining its `lo`-th and
+// `hi`-th vertices.
+fn chord_distance<N: RealField + Copy>(
+    vertices: &[Point<N>],
+    chain: &[usize],
+    lo: usize,
+    hi: usize,
+    i: usize,
+) -> N {
+    let chord = Segment::new(vertices[chain[lo]], vertices[chain[hi]]);
+    chord.distance_to_point(&Isometry::identity(), &vertices[chain[i]], true)
+}
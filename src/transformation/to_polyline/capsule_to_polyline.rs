@@ -26,6 +26,11 @@ impl<N: RealField + Copy> ToPolyline<N> for Capsule<N> {
             points[i] = new_point;
         }
 
-        Polyline::new(points, None)
+        let polyline = Polyline::new(points, None).with_closed(true);
+
+        match self.local_frame() {
+            Some(frame) => polyline.transformed(frame),
+            None => polyline,
+        }
     }
 }
@@ -0,0 +1,22 @@
+use super::ToPolyline;
+use crate::procedural::Polyline;
+use crate::shape::Compound;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToPolyline<N> for Compound<N> {
+    /// The number of subdivisions of the curved parts.
+    type DiscretizationParameter = u32;
+
+    /// Builds the union of the polylines of all the parts of this compound.
+    ///
+    /// The result has explicit edges so that the outlines of distinct parts are not joined.
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline<N> {
+        let mut res = Polyline::new(Vec::new(), None);
+
+        for (m, part) in self.shapes() {
+            res.append(part.as_ref().to_polyline(nsubdiv).transformed(m));
+        }
+
+        res
+    }
+}
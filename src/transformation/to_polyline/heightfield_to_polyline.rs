@@ -0,0 +1,23 @@
+use super::ToPolyline;
+use crate::procedural::Polyline;
+use crate::shape::HeightField;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToPolyline<N> for HeightField<N> {
+    type DiscretizationParameter = ();
+
+    /// Builds the polyline joining the endpoints of the non-removed segments of this heightfield.
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        let mut points = Vec::new();
+
+        for seg in self.segments() {
+            if points.last() != Some(&seg.a) {
+                points.push(seg.a);
+            }
+
+            points.push(seg.b);
+        }
+
+        Polyline::new(points, None)
+    }
+}
@@ -5,6 +5,12 @@ pub mod to_polyline;
 
 mod ball_to_polyline;
 mod capsule_to_polyline;
+mod compound_to_polyline;
+mod convex_polygon_to_polyline;
 mod cuboid_to_polyline;
+mod heightfield_to_polyline;
+mod plane_to_polyline;
+mod polyline_to_polyline;
 mod segment_to_polyline;
+mod shape_to_polyline;
 mod triangle_to_polyline;
@@ -0,0 +1,15 @@
+use super::ToPolyline;
+use crate::procedural::Polyline;
+use crate::shape::Plane;
+use na::{Point2, Vector2};
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToPolyline<N> for Plane<N> {
+    /// The half-length of the segment used to represent the plane.
+    type DiscretizationParameter = N;
+
+    fn to_polyline(&self, half_length: N) -> Polyline<N> {
+        let tangent = Vector2::new(-self.normal.y, self.normal.x) * half_length;
+        Polyline::new(vec![Point2::from(-tangent), Point2::from(tangent)], None)
+    }
+}
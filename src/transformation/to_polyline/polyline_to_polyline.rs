@@ -0,0 +1,14 @@
+use super::ToPolyline;
+use crate::procedural::Polyline;
+use crate::shape;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToPolyline<N> for shape::Polyline<N> {
+    type DiscretizationParameter = ();
+
+    /// Builds a polyline with the same vertices and edges as this polyline shape.
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        let indices = self.edges().iter().map(|e| e.indices).collect();
+        Polyline::new(self.points().to_vec(), None).with_indices(indices)
+    }
+}
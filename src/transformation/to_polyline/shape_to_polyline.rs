@@ -0,0 +1,45 @@
+use super::ToPolyline;
+use crate::procedural::Polyline;
+use crate::shape::{
+    Ball, Capsule, Compound, ConvexPolygon, Cuboid, HeightField, Segment, Shape, Triangle,
+};
+use crate::{shape, transformation};
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToPolyline<N> for dyn Shape<N> {
+    /// The number of subdivisions of the curved shapes.
+    type DiscretizationParameter = u32;
+
+    /// Builds a polyline from any shape.
+    ///
+    /// Any support-mapped shape without a dedicated discretization is approximated by
+    /// `transformation::convex_approximation` with `nsubdiv` samples. Planes, which are
+    /// unbounded, and unsupported shapes result in an empty polyline.
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline<N> {
+        if let Some(s) = self.as_shape::<Ball<N>>() {
+            s.to_polyline(nsubdiv)
+        } else if let Some(s) = self.as_shape::<Cuboid<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<Capsule<N>>() {
+            s.to_polyline(nsubdiv)
+        } else if let Some(s) = self.as_shape::<Segment<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<Triangle<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<ConvexPolygon<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<shape::Polyline<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<HeightField<N>>() {
+            s.to_polyline(())
+        } else if let Some(s) = self.as_shape::<Compound<N>>() {
+            s.to_polyline(nsubdiv)
+        } else if let Some(s) = self.as_support_map() {
+            transformation::convex_approximation(s, nsubdiv as usize)
+                .map(|polygon| polygon.to_polyline(()))
+                .unwrap_or_else(|| Polyline::new(Vec::new(), None))
+        } else {
+            Polyline::new(Vec::new(), None)
+        }
+    }
+}
@@ -7,6 +7,6 @@ impl<N: RealField + Copy> ToPolyline<N> for Triangle<N> {
     type DiscretizationParameter = ();
 
     fn to_polyline(&self, _: ()) -> Polyline<N> {
-        Polyline::new(vec![self.a, self.b, self.c], None)
+        Polyline::new(vec![self.a, self.b, self.c], None).with_closed(true)
     }
 }
@@ -1,7 +1,8 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural;
 use crate::procedural::TriMesh;
 use crate::shape::Ball;
+use crate::transformation::discretization::circle_subdivisions;
 use na;
 use simba::scalar::RealField;
 
@@ -17,3 +18,11 @@ impl<N: RealField + Copy> ToTriMesh<N> for Ball<N> {
         )
     }
 }
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Ball<N> {
+    fn discretization_parameter(&self, tolerance: N) -> (u32, u32) {
+        // The triangles span two directions, which doubles the chordal error of the segments.
+        let ntheta_subdiv = circle_subdivisions(self.radius, tolerance * na::convert(0.5));
+        (ntheta_subdiv, ntheta_subdiv.div_ceil(2).max(2))
+    }
+}
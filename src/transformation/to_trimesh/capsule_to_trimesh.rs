@@ -1,7 +1,8 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural;
 use crate::procedural::TriMesh;
 use crate::shape::Capsule;
+use crate::transformation::discretization::circle_subdivisions;
 use na;
 use simba::scalar::RealField;
 
@@ -11,8 +12,25 @@ impl<N: RealField + Copy> ToTriMesh<N> for Capsule<N> {
     fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh<N> {
         let diameter = self.radius * na::convert(2.0f64);
         let height = self.half_height * na::convert(2.0f64);
+        // The caps must have at least one ring besides their poles to be attached to each other.
+        let ntheta_subdiv = ntheta_subdiv.max(3);
+        let nphi_subdiv = nphi_subdiv.max(2);
         // FIXME: the fact `capsule` does not take directly the half_height and the radius feels
         // inconsistant.
-        procedural::capsule(&diameter, &height, ntheta_subdiv, nphi_subdiv)
+        let mesh = procedural::capsule(&diameter, &height, ntheta_subdiv, nphi_subdiv);
+
+        match self.local_frame() {
+            Some(frame) => mesh.transformed(frame),
+            None => mesh,
+        }
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Capsule<N> {
+    fn discretization_parameter(&self, tolerance: N) -> (u32, u32) {
+        // As for balls, the error of the caps adds up along both directions.
+        let ntheta_subdiv = circle_subdivisions(self.radius, tolerance * na::convert(0.5));
+        // The `nphi_subdiv - 1` segments of each cap span a quarter of a circle.
+        (ntheta_subdiv, ntheta_subdiv.div_ceil(4) + 1)
     }
 }
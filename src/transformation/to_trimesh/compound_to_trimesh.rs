@@ -0,0 +1,45 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural::TriMesh;
+use crate::shape::Compound;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToTriMesh<N> for Compound<N> {
+    /// The discretization parameters of the curved parts, see the implementation of this trait
+    /// for `dyn Shape`.
+    type DiscretizationParameter = (u32, u32);
+
+    fn to_trimesh(&self, nsubdivs: (u32, u32)) -> TriMesh<N> {
+        let mut res = TriMesh::new(Vec::new(), None, None, None);
+
+        for (m, part) in self.shapes() {
+            res.append(part.as_ref().to_trimesh(nsubdivs).transformed(m));
+        }
+
+        res
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Compound<N> {
+    /// The componentwise maximum of the discretization parameters of the parts.
+    fn discretization_parameter(&self, tolerance: N) -> (u32, u32) {
+        self.shapes()
+            .iter()
+            .map(|(_, part)| part.as_ref().discretization_parameter(tolerance))
+            .fold((3, 2), |(t1, p1), (t2, p2)| (t1.max(t2), p1.max(p2)))
+    }
+
+    /// Discretizes each part with its own discretization parameters.
+    fn to_trimesh_with_tolerance(&self, tolerance: N) -> TriMesh<N> {
+        let mut res = TriMesh::new(Vec::new(), None, None, None);
+
+        for (m, part) in self.shapes() {
+            res.append(
+                part.as_ref()
+                    .to_trimesh_with_tolerance(tolerance)
+                    .transformed(m),
+            );
+        }
+
+        res
+    }
+}
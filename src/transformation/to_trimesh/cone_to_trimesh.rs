@@ -1,7 +1,8 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural;
 use crate::procedural::TriMesh;
 use crate::shape::Cone;
+use crate::transformation::discretization::circle_subdivisions;
 use na;
 use simba::scalar::RealField;
 
@@ -17,3 +18,9 @@ impl<N: RealField + Copy> ToTriMesh<N> for Cone<N> {
         procedural::cone(diameter, height, nsubdiv)
     }
 }
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Cone<N> {
+    fn discretization_parameter(&self, tolerance: N) -> u32 {
+        circle_subdivisions(self.radius, tolerance)
+    }
+}
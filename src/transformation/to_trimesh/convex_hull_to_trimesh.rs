@@ -0,0 +1,41 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::{ConvexHull, ConvexPolyhedron, FeatureId};
+use na::{Point3, RealField};
+
+impl<N: RealField + Copy> ToTriMesh<N> for ConvexHull<N> {
+    type DiscretizationParameter = ();
+
+    fn to_trimesh(&self, _: ()) -> TriMesh<N> {
+        let points = self.points();
+        let mut indices = Vec::new();
+
+        for i in 0..self.num_faces() {
+            let face = self.face_vertices(i);
+            let normal = self.feature_normal(FeatureId::Face(i));
+
+            // Fan-triangulate each face, making sure triangles are oriented toward its normal.
+            for j in 1..face.len() - 1 {
+                let (a, b, c) = (face[0], face[j], face[j + 1]);
+                let n = (points[b] - points[a]).cross(&(points[c] - points[a]));
+
+                if n.dot(&normal) >= N::zero() {
+                    indices.push(Point3::new(a as u32, b as u32, c as u32));
+                } else {
+                    indices.push(Point3::new(a as u32, c as u32, b as u32));
+                }
+            }
+        }
+
+        TriMesh::new(
+            points.to_vec(),
+            None,
+            None,
+            Some(IndexBuffer::Unified(indices)),
+        )
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for ConvexHull<N> {
+    fn discretization_parameter(&self, _: N) {}
+}
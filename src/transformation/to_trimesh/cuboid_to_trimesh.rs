@@ -1,4 +1,4 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural;
 use crate::procedural::TriMesh;
 use crate::shape::Cuboid;
@@ -15,4 +15,8 @@ impl<N: RealField + Copy> ToTriMesh<N> for Cuboid<N> {
     }
 }
 
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Cuboid<N> {
+    fn discretization_parameter(&self, _: N) {}
+}
+
 // FIXME: in 2d, generate a filled rectangle.
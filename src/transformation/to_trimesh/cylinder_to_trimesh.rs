@@ -1,7 +1,8 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural;
 use crate::procedural::TriMesh;
 use crate::shape::Cylinder;
+use crate::transformation::discretization::circle_subdivisions;
 use na;
 use simba::scalar::RealField;
 
@@ -15,3 +16,9 @@ impl<N: RealField + Copy> ToTriMesh<N> for Cylinder<N> {
         procedural::cylinder(diameter, height, nsubdiv)
     }
 }
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Cylinder<N> {
+    fn discretization_parameter(&self, tolerance: N) -> u32 {
+        circle_subdivisions(self.radius, tolerance)
+    }
+}
@@ -1,4 +1,4 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural::TriMesh;
 use crate::shape;
 use simba::scalar::RealField;
@@ -18,3 +18,7 @@ impl<N: RealField + Copy> ToTriMesh<N> for shape::HeightField<N> {
         TriMesh::new(vertices, None, None, None)
     }
 }
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for shape::HeightField<N> {
+    fn discretization_parameter(&self, _: N) {}
+}
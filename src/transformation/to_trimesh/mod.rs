@@ -1,12 +1,20 @@
-pub use self::to_trimesh::ToTriMesh;
+pub use self::to_trimesh::{ToTriMesh, ToTriMeshWithTolerance};
+pub use self::trimesh_lod::TriMeshLOD;
 
 mod ball_to_trimesh;
 mod capsule_to_trimesh;
+mod compound_to_trimesh;
 mod cone_to_trimesh;
+mod convex_hull_to_trimesh;
 mod cuboid_to_trimesh;
 mod cylinder_to_trimesh;
+mod heightfield_to_trimesh;
+mod mesh_to_trimesh;
+mod oriented_to_trimesh;
+mod plane_to_trimesh;
+mod shape_to_trimesh;
+mod superellipsoid_to_trimesh;
 #[doc(hidden)]
 pub mod to_trimesh;
-// mod mesh_to_trimesh;
-mod heightfield_to_trimesh;
 mod triangle_to_trimesh;
+mod trimesh_lod;
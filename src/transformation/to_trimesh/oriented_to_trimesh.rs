@@ -0,0 +1,29 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural::TriMesh;
+use crate::shape::Oriented;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy, G: ToTriMesh<N>> ToTriMesh<N> for Oriented<N, G> {
+    /// The discretization parameter of the wrapped shape.
+    type DiscretizationParameter = G::DiscretizationParameter;
+
+    fn to_trimesh(&self, param: G::DiscretizationParameter) -> TriMesh<N> {
+        self.shape
+            .to_trimesh(param)
+            .transformed(&self.local_isometry())
+    }
+}
+
+impl<N: RealField + Copy, G: ToTriMeshWithTolerance<N>> ToTriMeshWithTolerance<N>
+    for Oriented<N, G>
+{
+    fn discretization_parameter(&self, tolerance: N) -> G::DiscretizationParameter {
+        self.shape.discretization_parameter(tolerance)
+    }
+
+    fn to_trimesh_with_tolerance(&self, tolerance: N) -> TriMesh<N> {
+        self.shape
+            .to_trimesh_with_tolerance(tolerance)
+            .transformed(&self.local_isometry())
+    }
+}
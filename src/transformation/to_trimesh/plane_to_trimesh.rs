@@ -0,0 +1,28 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural::{self, TriMesh};
+use crate::shape::Plane;
+use na::{self, Isometry3, Translation3, UnitQuaternion, Vector3};
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToTriMesh<N> for Plane<N> {
+    /// The half-width of the square used to represent the plane.
+    type DiscretizationParameter = N;
+
+    fn to_trimesh(&self, half_width: N) -> TriMesh<N> {
+        let width = half_width * na::convert(2.0f64);
+        let quad = procedural::quad(width, width, 1, 1);
+        // The quad normal is the `z` axis.
+        let rotation = UnitQuaternion::rotation_between(&Vector3::z(), &self.normal)
+            .unwrap_or_else(|| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), N::pi()));
+
+        quad.transformed(&Isometry3::from_parts(Translation3::identity(), rotation))
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Plane<N> {
+    /// A unit half-width: the plane is flat so the tolerance is irrelevant, and it is unbounded so
+    /// any size is as accurate as any other.
+    fn discretization_parameter(&self, _: N) -> N {
+        N::one()
+    }
+}
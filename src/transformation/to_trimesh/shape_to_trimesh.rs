@@ -0,0 +1,98 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural::TriMesh;
+use crate::shape::{
+    Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, HeightField, Oriented, Shape,
+    Superellipsoid, Triangle,
+};
+use crate::{shape, transformation};
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToTriMesh<N> for dyn Shape<N> {
+    /// The numbers of subdivisions `(ntheta_subdiv, nphi_subdiv)` of the curved shapes.
+    ///
+    /// Cones and cylinders, oriented or not, only use `ntheta_subdiv`.
+    type DiscretizationParameter = (u32, u32);
+
+    /// Builds a triangle mesh from any shape.
+    ///
+    /// Any support-mapped shape without a dedicated discretization is approximated by
+    /// `transformation::convex_approximation` with `ntheta_subdiv * nphi_subdiv` samples. Planes,
+    /// which are unbounded, and unsupported shapes result in an empty mesh.
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh<N> {
+        if let Some(s) = self.as_shape::<Ball<N>>() {
+            s.to_trimesh((ntheta_subdiv, nphi_subdiv))
+        } else if let Some(s) = self.as_shape::<Cuboid<N>>() {
+            s.to_trimesh(())
+        } else if let Some(s) = self.as_shape::<Capsule<N>>() {
+            s.to_trimesh((ntheta_subdiv, nphi_subdiv))
+        } else if let Some(s) = self.as_shape::<Cone<N>>() {
+            s.to_trimesh(ntheta_subdiv)
+        } else if let Some(s) = self.as_shape::<Cylinder<N>>() {
+            s.to_trimesh(ntheta_subdiv)
+        } else if let Some(s) = self.as_shape::<Oriented<N, Cone<N>>>() {
+            s.to_trimesh(ntheta_subdiv)
+        } else if let Some(s) = self.as_shape::<Oriented<N, Cylinder<N>>>() {
+            s.to_trimesh(ntheta_subdiv)
+        } else if let Some(s) = self.as_shape::<Superellipsoid<N>>() {
+            s.to_trimesh((ntheta_subdiv, nphi_subdiv))
+        } else if let Some(s) = self.as_shape::<Triangle<N>>() {
+            s.to_trimesh(())
+        } else if let Some(s) = self.as_shape::<ConvexHull<N>>() {
+            s.to_trimesh(())
+        } else if let Some(s) = self.as_shape::<shape::TriMesh<N>>() {
+            s.to_trimesh(())
+        } else if let Some(s) = self.as_shape::<HeightField<N>>() {
+            s.to_trimesh(())
+        } else if let Some(s) = self.as_shape::<Compound<N>>() {
+            s.to_trimesh((ntheta_subdiv, nphi_subdiv))
+        } else if let Some(s) = self.as_support_map() {
+            let nsamples = (ntheta_subdiv * nphi_subdiv) as usize;
+            transformation::convex_approximation(s, nsamples)
+                .map(|hull| hull.to_trimesh(()))
+                .unwrap_or_else(empty_trimesh)
+        } else {
+            empty_trimesh()
+        }
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for dyn Shape<N> {
+    /// The discretization parameters of the curved shape, of all the parts of a compound, or of
+    /// the bounding sphere of any other support-mapped shape.
+    fn discretization_parameter(&self, tolerance: N) -> (u32, u32) {
+        if let Some(s) = self.as_shape::<Ball<N>>() {
+            s.discretization_parameter(tolerance)
+        } else if let Some(s) = self.as_shape::<Capsule<N>>() {
+            s.discretization_parameter(tolerance)
+        } else if let Some(s) = self.as_shape::<Cone<N>>() {
+            (s.discretization_parameter(tolerance), 2)
+        } else if let Some(s) = self.as_shape::<Cylinder<N>>() {
+            (s.discretization_parameter(tolerance), 2)
+        } else if let Some(s) = self.as_shape::<Oriented<N, Cone<N>>>() {
+            (s.discretization_parameter(tolerance), 2)
+        } else if let Some(s) = self.as_shape::<Oriented<N, Cylinder<N>>>() {
+            (s.discretization_parameter(tolerance), 2)
+        } else if let Some(s) = self.as_shape::<Superellipsoid<N>>() {
+            s.discretization_parameter(tolerance)
+        } else if let Some(s) = self.as_shape::<Compound<N>>() {
+            s.discretization_parameter(tolerance)
+        } else if self.is_support_map() {
+            let radius = self.local_bounding_sphere().radius();
+            Ball::new(radius).discretization_parameter(tolerance)
+        } else {
+            (3, 2)
+        }
+    }
+
+    fn to_trimesh_with_tolerance(&self, tolerance: N) -> TriMesh<N> {
+        if let Some(s) = self.as_shape::<Compound<N>>() {
+            s.to_trimesh_with_tolerance(tolerance)
+        } else {
+            self.to_trimesh(self.discretization_parameter(tolerance))
+        }
+    }
+}
+
+fn empty_trimesh<N: RealField + Copy>() -> TriMesh<N> {
+    TriMesh::new(Vec::new(), None, None, None)
+}
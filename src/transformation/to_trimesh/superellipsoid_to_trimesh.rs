@@ -0,0 +1,41 @@
+use super::{ToTriMesh, ToTriMeshWithTolerance};
+use crate::procedural;
+use crate::procedural::TriMesh;
+use crate::shape::{Ball, Superellipsoid};
+use na;
+use simba::scalar::RealField;
+
+impl<N: RealField + Copy> ToTriMesh<N> for Superellipsoid<N> {
+    type DiscretizationParameter = (u32, u32);
+
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh<N> {
+        // Map each vertex of a unit sphere to the point of the superellipsoid with the same
+        // spherical coordinates.
+        let mut mesh: TriMesh<N> =
+            procedural::sphere(na::convert(2.0f64), ntheta_subdiv, nphi_subdiv, false);
+
+        for pt in &mut mesh.coords {
+            let ceta = (pt.x * pt.x + pt.z * pt.z).sqrt();
+            let (somega, comega) = if ceta.is_zero() {
+                (N::zero(), N::one())
+            } else {
+                (pt.z / ceta, pt.x / ceta)
+            };
+
+            *pt = self.parametric_point((pt.y, ceta), (somega, comega));
+        }
+
+        mesh.recompute_normals();
+        mesh
+    }
+}
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Superellipsoid<N> {
+    /// The discretization of the circumscribed ball.
+    ///
+    /// This bounds the chordal error of ellipsoids only: it may be larger close to the edges of
+    /// nearly boxy superellipsoids.
+    fn discretization_parameter(&self, tolerance: N) -> (u32, u32) {
+        Ball::new(self.radii.max()).discretization_parameter(tolerance)
+    }
+}
@@ -12,3 +12,19 @@ pub trait ToTriMesh<N: RealField + Copy> {
     /// * `i` - the discretization parameters.
     fn to_trimesh(&self, i: Self::DiscretizationParameter) -> TriMesh<N>;
 }
+
+/// Trait implemented by shapes that can be approximated by a triangle mesh with a bounded error.
+pub trait ToTriMeshWithTolerance<N: RealField + Copy>: ToTriMesh<N> {
+    /// The coarsest discretization parameters such that the distance between the shape boundary
+    /// and the mesh does not exceed `tolerance`.
+    ///
+    /// This is the chordal error of the curved parts: a ball of radius `r` needs more
+    /// subdivisions than a ball of radius `r / 10` for the same tolerance. Shapes that are
+    /// represented exactly ignore the tolerance, others panic if it is not strictly positive.
+    fn discretization_parameter(&self, tolerance: N) -> Self::DiscretizationParameter;
+
+    /// Builds a triangle mesh from this shape, with a chordal error not exceeding `tolerance`.
+    fn to_trimesh_with_tolerance(&self, tolerance: N) -> TriMesh<N> {
+        self.to_trimesh(self.discretization_parameter(tolerance))
+    }
+}
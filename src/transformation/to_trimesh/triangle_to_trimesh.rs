@@ -1,4 +1,4 @@
-use super::ToTriMesh;
+use super::{ToTriMesh, ToTriMeshWithTolerance};
 use crate::procedural::TriMesh;
 use crate::shape::Triangle;
 use simba::scalar::RealField;
@@ -10,3 +10,7 @@ impl<N: RealField + Copy> ToTriMesh<N> for Triangle<N> {
         TriMesh::new(vec![self.a, self.b, self.c], None, None, None)
     }
 }
+
+impl<N: RealField + Copy> ToTriMeshWithTolerance<N> for Triangle<N> {
+    fn discretization_parameter(&self, _: N) {}
+}
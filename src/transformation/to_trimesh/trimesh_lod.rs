@@ -0,0 +1,69 @@
+use super::ToTriMeshWithTolerance;
+use crate::procedural::TriMesh;
+use simba::scalar::RealField;
+
+/// A chain of triangle meshes approximating the same shape at increasing chordal errors.
+#[derive(Clone, Debug)]
+pub struct TriMeshLOD<N: RealField + Copy> {
+    levels: Vec<(N, TriMesh<N>)>,
+}
+
+impl<N: RealField + Copy> TriMeshLOD<N> {
+    /// Discretizes `shape` once for each tolerance of `tolerances`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerances` is empty or not sorted in increasing order.
+    pub fn new<S: ToTriMeshWithTolerance<N> + ?Sized>(shape: &S, tolerances: &[N]) -> Self {
+        assert!(
+            !tolerances.is_empty(),
+            "A level of detail chain must have at least one level."
+        );
+        assert!(
+            tolerances.windows(2).all(|w| w[0] <= w[1]),
+            "The level of detail tolerances must be sorted in increasing order."
+        );
+
+        let levels = tolerances
+            .iter()
+            .map(|tolerance| (*tolerance, shape.to_trimesh_with_tolerance(*tolerance)))
+            .collect();
+
+        TriMeshLOD { levels }
+    }
+
+    /// Discretizes `shape` into `nlevels` levels, starting with the tolerance `finest_tolerance`
+    /// and doubling it at each level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nlevels` is zero.
+    pub fn with_doubling_tolerances<S: ToTriMeshWithTolerance<N> + ?Sized>(
+        shape: &S,
+        finest_tolerance: N,
+        nlevels: usize,
+    ) -> Self {
+        let two: N = na::convert(2.0);
+        let tolerances: Vec<N> = std::iter::successors(Some(finest_tolerance), |t| Some(*t * two))
+            .take(nlevels)
+            .collect();
+
+        Self::new(shape, &tolerances)
+    }
+
+    /// The tolerance and mesh of each level, from the finest to the coarsest.
+    #[inline]
+    pub fn levels(&self) -> &[(N, TriMesh<N>)] {
+        &self.levels
+    }
+
+    /// The coarsest mesh with a tolerance smaller than or equal to `max_error`.
+    ///
+    /// If all the levels are too coarse, this returns the finest one.
+    pub fn level(&self, max_error: N) -> &TriMesh<N> {
+        let i = self
+            .levels
+            .partition_point(|(tolerance, _)| *tolerance <= max_error);
+        &self.levels[i.max(1) - 1].1
+    }
+}
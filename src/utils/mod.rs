@@ -15,6 +15,7 @@ pub use self::point_cloud_support_point::{
 };
 pub use self::point_in_poly2d::point_in_poly2d;
 pub use self::ref_with_cost::RefWithCost;
+pub(crate) use self::rotation_from_y_axis::rotation_from_y_axis;
 pub use self::sort::{sort2, sort3};
 pub use self::sorted_pair::SortedPair;
 #[cfg(feature = "dim3")]
@@ -24,6 +25,7 @@ pub use self::triangle::is_affinely_dependent_triangle;
 pub use self::triangle::{
     circumcircle, is_point_in_triangle, triangle_area, triangle_center, triangle_perimeter,
 };
+pub use self::trigonometry::{acos, asin, atan2, cos, sin, sin_cos};
 
 #[doc(hidden)]
 pub mod as_bytes;
@@ -38,8 +40,10 @@ mod median;
 mod point_cloud_support_point;
 mod point_in_poly2d;
 mod ref_with_cost;
+mod rotation_from_y_axis;
 mod sort;
 mod sorted_pair;
 #[cfg(feature = "dim3")]
 mod tetrahedron;
 mod triangle;
+mod trigonometry;
@@ -0,0 +1,22 @@
+use crate::math::{Rotation, Vector};
+use na::{RealField, Unit};
+
+/// The rotation mapping the `y` axis to `axis`, or `None` if they are equal.
+pub(crate) fn rotation_from_y_axis<N: RealField + Copy>(
+    axis: &Unit<Vector<N>>,
+) -> Option<Rotation<N>> {
+    if *axis == Vector::y_axis() {
+        return None;
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        Some(Rotation::rotation_between_axis(&Vector::y_axis(), axis))
+    }
+    #[cfg(feature = "dim3")]
+    {
+        // The rotation is not unique if the axis is `-y`.
+        Rotation::rotation_between_axis(&Vector::y_axis(), axis)
+            .or_else(|| Some(Rotation::from_axis_angle(&Vector::x_axis(), N::pi())))
+    }
+}
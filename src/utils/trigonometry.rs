@@ -0,0 +1,153 @@
+use na::{self, RealField};
+
+/// Computes the sine and cosine of `x`.
+///
+/// With the `deterministic` feature enabled, this does not rely on the platform's math library
+/// and only uses additions, multiplications and divisions, so the results are bit-identical on
+/// every IEEE 754 compliant target.
+#[cfg(not(feature = "deterministic"))]
+#[inline]
+pub fn sin_cos<N: RealField + Copy>(x: N) -> (N, N) {
+    x.sin_cos()
+}
+
+/// Computes the sine and cosine of `x`.
+///
+/// With the `deterministic` feature enabled, this does not rely on the platform's math library
+/// and only uses additions, multiplications and divisions, so the results are bit-identical on
+/// every IEEE 754 compliant target.
+#[cfg(feature = "deterministic")]
+pub fn sin_cos<N: RealField + Copy>(x: N) -> (N, N) {
+    // Reduce the angle to [-pi / 4, pi / 4].
+    let frac_pi_2 = N::frac_pi_2();
+    let quadrant = (x / frac_pi_2).round();
+    let r = x - quadrant * frac_pi_2;
+    let r2 = r * r;
+
+    // Taylor expansions evaluated with Horner's scheme. Nine terms exceed the
+    // precision of a `f64` on the reduced range.
+    let mut s = N::one();
+    let mut c = N::one();
+
+    for n in (1..10).rev() {
+        let sden: N = na::convert(((2 * n) * (2 * n + 1)) as f64);
+        let cden: N = na::convert(((2 * n - 1) * (2 * n)) as f64);
+        s = N::one() - r2 * s / sden;
+        c = N::one() - r2 * c / cden;
+    }
+
+    let s = r * s;
+    let quadrant: f64 = na::try_convert(quadrant).unwrap_or(0.0);
+
+    match (quadrant as i64).rem_euclid(4) {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Computes the sine of `x`.
+///
+/// See `sin_cos` for details about the `deterministic` feature.
+#[inline]
+pub fn sin<N: RealField + Copy>(x: N) -> N {
+    sin_cos(x).0
+}
+
+/// Computes the cosine of `x`.
+///
+/// See `sin_cos` for details about the `deterministic` feature.
+#[inline]
+pub fn cos<N: RealField + Copy>(x: N) -> N {
+    sin_cos(x).1
+}
+
+/// Computes the four-quadrant arctangent of `y` and `x`.
+///
+/// With the `deterministic` feature enabled, this only uses additions, multiplications,
+/// divisions and square roots, which are all correctly rounded on every IEEE 754 compliant
+/// target.
+#[cfg(not(feature = "deterministic"))]
+#[inline]
+pub fn atan2<N: RealField + Copy>(y: N, x: N) -> N {
+    y.atan2(x)
+}
+
+/// Computes the four-quadrant arctangent of `y` and `x`.
+///
+/// With the `deterministic` feature enabled, this only uses additions, multiplications,
+/// divisions and square roots, which are all correctly rounded on every IEEE 754 compliant
+/// target.
+#[cfg(feature = "deterministic")]
+pub fn atan2<N: RealField + Copy>(y: N, x: N) -> N {
+    let (ax, ay) = (x.abs(), y.abs());
+
+    if ax == N::zero() && ay == N::zero() {
+        return N::zero();
+    }
+
+    // Arctangent of a ratio in [0, 1].
+    let mut res = if ay <= ax {
+        atan_unit(ay / ax)
+    } else {
+        N::frac_pi_2() - atan_unit(ax / ay)
+    };
+
+    if x < N::zero() {
+        res = N::pi() - res;
+    }
+
+    if y < N::zero() {
+        -res
+    } else {
+        res
+    }
+}
+
+#[cfg(feature = "deterministic")]
+fn atan_unit<N: RealField + Copy>(t: N) -> N {
+    // Two applications of atan(t) = 2 * atan(t / (1 + sqrt(1 + t²))) reduce the
+    // argument below tan(pi / 16) ~= 0.2.
+    let mut t = t;
+
+    for _ in 0..2 {
+        t = t / (N::one() + (N::one() + t * t).sqrt());
+    }
+
+    // Taylor expansion evaluated with Horner's scheme. Twelve terms exceed the
+    // precision of a `f64` on the reduced range.
+    let t2 = t * t;
+    let mut res = N::zero();
+
+    for n in (0..12).rev() {
+        let den: N = na::convert((2 * n + 1) as f64);
+        res = N::one() / den - t2 * res;
+    }
+
+    t * res * na::convert(4.0)
+}
+
+/// Computes the arcsine of `x`.
+///
+/// See `atan2` for details about the `deterministic` feature.
+#[inline]
+pub fn asin<N: RealField + Copy>(x: N) -> N {
+    if cfg!(feature = "deterministic") {
+        atan2(x, ((N::one() - x) * (N::one() + x)).sqrt())
+    } else {
+        x.asin()
+    }
+}
+
+/// Computes the arccosine of `x`.
+///
+/// See `atan2` for details about the `deterministic` feature.
+#[inline]
+pub fn acos<N: RealField + Copy>(x: N) -> N {
+    if cfg!(feature = "deterministic") {
+        atan2(((N::one() - x) * (N::one() + x)).sqrt(), x)
+    } else {
+        x.acos()
+    }
+}
@@ -0,0 +1,23 @@
+//! Volume, center of mass, and angular inertia of shapes.
+
+#[doc(inline)]
+pub use self::volumetric_trait::Volumetric;
+
+mod volumetric_ball;
+mod volumetric_capsule;
+mod volumetric_compound;
+#[cfg(feature = "dim3")]
+mod volumetric_cone;
+#[cfg(feature = "dim3")]
+mod volumetric_convex;
+#[cfg(feature = "dim2")]
+mod volumetric_convex_polygon;
+mod volumetric_cuboid;
+#[cfg(feature = "dim3")]
+mod volumetric_cylinder;
+#[cfg(feature = "dim3")]
+mod volumetric_oriented;
+#[doc(hidden)]
+pub mod volumetric_trait;
+#[cfg(feature = "dim3")]
+mod volumetric_trimesh;
@@ -0,0 +1,39 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Ball;
+#[cfg(feature = "dim3")]
+use crate::volumetric::volumetric_trait::principal_inertia;
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Volumetric<N> for Ball<N> {
+    #[cfg(feature = "dim2")]
+    #[inline]
+    fn volume(&self) -> N {
+        N::pi() * self.radius * self.radius
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn volume(&self) -> N {
+        let _4_3: N = na::convert(4.0 / 3.0);
+        _4_3 * N::pi() * self.radius * self.radius * self.radius
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        Point::origin()
+    }
+
+    #[cfg(feature = "dim2")]
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        self.radius * self.radius * na::convert(0.5)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let inertia = self.radius * self.radius * na::convert(2.0 / 5.0);
+        principal_inertia(inertia, inertia, inertia)
+    }
+}
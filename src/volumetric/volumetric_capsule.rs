@@ -0,0 +1,83 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Capsule;
+#[cfg(feature = "dim3")]
+use crate::volumetric::volumetric_trait::{principal_inertia, rotate_inertia};
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Volumetric<N> for Capsule<N> {
+    #[cfg(feature = "dim2")]
+    #[inline]
+    fn volume(&self) -> N {
+        let radius = self.radius;
+        self.height() * radius * na::convert(2.0) + N::pi() * radius * radius
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn volume(&self) -> N {
+        let radius = self.radius;
+        let _4_3: N = na::convert(4.0 / 3.0);
+        N::pi() * radius * radius * (self.height() + _4_3 * radius)
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.local_frame()
+            .map_or_else(Point::origin, |frame| Point::from(frame.translation.vector))
+    }
+
+    #[cfg(feature = "dim2")]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let radius = self.radius;
+        let half_height = self.half_height;
+        let _2: N = na::convert(2.0);
+        let _3: N = na::convert(3.0);
+
+        // The rectangular part.
+        let rect_mass = radius * half_height * na::convert(4.0);
+        let rect_inertia = rect_mass * (radius * radius + half_height * half_height) / _3;
+
+        // The two half-disks, with their centers of mass shifted away from the origin.
+        let disk_mass = N::pi() * radius * radius;
+        let disk_inertia = disk_mass
+            * (radius * radius / _2
+                + half_height * half_height
+                + half_height * radius * na::convert(8.0) / (_3 * N::pi()));
+
+        (rect_inertia + disk_inertia) / (rect_mass + disk_mass)
+    }
+
+    #[cfg(feature = "dim3")]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let radius = self.radius;
+        let half_height = self.half_height;
+        let sq_radius = radius * radius;
+        let _2_5: N = na::convert(2.0 / 5.0);
+
+        // The cylindrical part.
+        let cyl_mass = N::pi() * sq_radius * half_height * na::convert(2.0);
+        let cyl_axial = cyl_mass * sq_radius * na::convert(0.5);
+        let cyl_lateral = cyl_mass
+            * (sq_radius / na::convert(4.0) + half_height * half_height / na::convert(3.0));
+
+        // The two hemispheres, with their centers of mass shifted away from the origin.
+        let ball_mass = N::pi() * sq_radius * radius * na::convert(4.0 / 3.0);
+        let ball_axial = ball_mass * sq_radius * _2_5;
+        let ball_lateral = ball_mass
+            * (sq_radius * _2_5
+                + half_height * half_height
+                + half_height * radius * na::convert(3.0 / 4.0));
+
+        let total_mass = cyl_mass + ball_mass;
+        let axial = (cyl_axial + ball_axial) / total_mass;
+        let lateral = (cyl_lateral + ball_lateral) / total_mass;
+
+        let inertia = principal_inertia(lateral, axial, lateral);
+
+        match self.local_frame() {
+            Some(frame) => rotate_inertia(&inertia, &frame.rotation),
+            None => inertia,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Compound;
+#[cfg(feature = "dim3")]
+use crate::volumetric::volumetric_trait::{rotate_inertia, shift_inertia};
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Compound<N> {
+    fn volume_properties(&self) -> (N, Point<N>, AngularInertia<N>) {
+        let mut volume = N::zero();
+        let mut com = Point::origin();
+        let mut parts = Vec::with_capacity(self.shapes().len());
+
+        for (pos, shape) in self.shapes() {
+            if let Some(part) = shape.as_volumetric() {
+                let part_volume = part.volume();
+                let part_com = pos * part.center_of_mass();
+
+                volume += part_volume;
+                com += part_com.coords * part_volume;
+                parts.push((pos, part, part_volume, part_com));
+            }
+        }
+
+        if volume.is_zero() {
+            return (N::zero(), Point::origin(), na::zero());
+        }
+
+        com /= volume;
+
+        let mut inertia: AngularInertia<N> = na::zero();
+
+        for (pos, part, part_volume, part_com) in parts {
+            let shift = part_com - com;
+
+            #[cfg(feature = "dim2")]
+            {
+                let _ = pos;
+                inertia += (part.unit_angular_inertia() + shift.norm_squared()) * part_volume;
+            }
+
+            #[cfg(feature = "dim3")]
+            {
+                inertia += rotate_inertia(&part.unit_angular_inertia(), &pos.rotation)
+                    * part_volume
+                    + shift_inertia(part_volume, &shift);
+            }
+        }
+
+        (volume, com, inertia / volume)
+    }
+}
+
+/// The mass properties of a `Compound`.
+///
+/// Only the parts implementing `Volumetric` contribute to the result. Overlapping parts are
+/// accounted for as many times as they overlap.
+impl<N: RealField + Copy> Volumetric<N> for Compound<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        self.shapes()
+            .iter()
+            .filter_map(|s| s.1.as_volumetric())
+            .fold(N::zero(), |acc, part| acc + part.volume())
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.volume_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        self.volume_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> (N, Point<N>, AngularInertia<N>) {
+        let (volume, com, inertia) = self.volume_properties();
+        let mass = volume * density;
+        (mass, com, inertia * mass)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cone;
+use crate::volumetric::volumetric_trait::principal_inertia;
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Volumetric<N> for Cone<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        N::pi() * self.radius * self.radius * self.half_height * na::convert(2.0 / 3.0)
+    }
+
+    /// The center of mass of the cone, located at a quarter of its height from its base.
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        let mut res = Point::origin();
+        res.y = -self.half_height * na::convert(0.5);
+        res
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let sq_radius = self.radius * self.radius;
+        let sq_half_height = self.half_height * self.half_height;
+        let _3_20: N = na::convert(3.0 / 20.0);
+        let axial = sq_radius * na::convert(3.0 / 10.0);
+        let lateral = (sq_radius + sq_half_height) * _3_20;
+
+        principal_inertia(lateral, axial, lateral)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::ConvexHull;
+use crate::volumetric::volumetric_trimesh::trimesh_volume_properties;
+use crate::volumetric::Volumetric;
+use na::{Point3, RealField};
+
+impl<N: RealField + Copy> ConvexHull<N> {
+    fn volume_properties(&self) -> (N, Point<N>, AngularInertia<N>) {
+        let triangles = self.faces_vertices().flat_map(|vids| {
+            (1..vids.len() - 1).map(move |i| Point3::new(vids[0], vids[i], vids[i + 1]))
+        });
+
+        trimesh_volume_properties(self.points(), triangles)
+    }
+}
+
+impl<N: RealField + Copy> Volumetric<N> for ConvexHull<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        self.volume_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.volume_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        self.volume_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> (N, Point<N>, AngularInertia<N>) {
+        let (volume, com, inertia) = self.volume_properties();
+        let mass = volume * density;
+        (mass, com, inertia * mass)
+    }
+}
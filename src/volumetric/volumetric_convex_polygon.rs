@@ -0,0 +1,64 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::ConvexPolygon;
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+/// Computes the area, the center of mass, and the unit angular inertia of a simple polygon.
+///
+/// The vertices of the polygon may be given in clockwise or counter-clockwise order.
+pub(crate) fn polygon_volume_properties<N: RealField + Copy>(
+    points: &[Point<N>],
+) -> (N, Point<N>, AngularInertia<N>) {
+    if points.len() < 3 {
+        return (N::zero(), Point::origin(), N::zero());
+    }
+
+    let reference = points[0];
+    let mut area = N::zero();
+    let mut com = Point::origin();
+    let mut inertia = N::zero();
+
+    for i in 1..points.len() - 1 {
+        let a = points[i] - reference;
+        let b = points[i + 1] - reference;
+        let tri_area = a.perp(&b) * na::convert(0.5);
+
+        area += tri_area;
+        com += (a + b) * (tri_area / na::convert(3.0));
+        inertia += (a.dot(&a) + a.dot(&b) + b.dot(&b)) * (tri_area / na::convert(6.0));
+    }
+
+    if area.is_zero() {
+        return (N::zero(), reference, N::zero());
+    }
+
+    com /= area;
+    // Move the inertia to the center of mass.
+    inertia -= com.coords.norm_squared() * area;
+
+    (area.abs(), reference + com.coords, inertia / area)
+}
+
+impl<N: RealField + Copy> Volumetric<N> for ConvexPolygon<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        polygon_volume_properties(self.points()).0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        polygon_volume_properties(self.points()).1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        polygon_volume_properties(self.points()).2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> (N, Point<N>, AngularInertia<N>) {
+        let (area, com, inertia) = polygon_volume_properties(self.points());
+        let mass = area * density;
+        (mass, com, inertia * mass)
+    }
+}
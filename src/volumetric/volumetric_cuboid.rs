@@ -0,0 +1,38 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cuboid;
+#[cfg(feature = "dim3")]
+use crate::volumetric::volumetric_trait::principal_inertia;
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Volumetric<N> for Cuboid<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        let extents = self.half_extents * na::convert::<_, N>(2.0);
+        extents.iter().fold(N::one(), |acc, e| acc * *e)
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        Point::origin()
+    }
+
+    #[cfg(feature = "dim2")]
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let half_extents = self.half_extents;
+        (half_extents.x * half_extents.x + half_extents.y * half_extents.y) / na::convert(3.0)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let _3: N = na::convert(3.0);
+        let sq_extents = self.half_extents.component_mul(&self.half_extents);
+        principal_inertia(
+            (sq_extents.y + sq_extents.z) / _3,
+            (sq_extents.x + sq_extents.z) / _3,
+            (sq_extents.x + sq_extents.y) / _3,
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cylinder;
+use crate::volumetric::volumetric_trait::principal_inertia;
+use crate::volumetric::Volumetric;
+use na::{self, RealField};
+
+impl<N: RealField + Copy> Volumetric<N> for Cylinder<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        N::pi() * self.radius * self.radius * self.half_height * na::convert(2.0)
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        Point::origin()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        let r2 = self.radius * self.radius;
+        let h2 = self.half_height * self.half_height;
+        let axial = r2 * na::convert(0.5);
+        let lateral = r2 / na::convert(4.0) + h2 / na::convert(3.0);
+
+        principal_inertia(lateral, axial, lateral)
+    }
+}
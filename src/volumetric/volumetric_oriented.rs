@@ -0,0 +1,22 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::Oriented;
+use crate::volumetric::volumetric_trait::rotate_inertia;
+use crate::volumetric::Volumetric;
+use na::RealField;
+
+impl<N: RealField + Copy, G: Volumetric<N>> Volumetric<N> for Oriented<N, G> {
+    #[inline]
+    fn volume(&self) -> N {
+        self.shape.volume()
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.rotation * self.shape.center_of_mass()
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        rotate_inertia(&self.shape.unit_angular_inertia(), &self.rotation)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::math::{AngularInertia, Point};
+#[cfg(feature = "dim3")]
+use crate::math::{Rotation, Vector};
+use na::RealField;
+#[cfg(feature = "dim3")]
+use na::{Matrix3, Vector3};
+
+/// Trait implemented by shapes with a well-defined volume and mass distribution.
+///
+/// All quantities are computed assuming a uniform density. In 2D, the "volume" of a shape is
+/// its area.
+pub trait Volumetric<N: RealField + Copy> {
+    /// The volume of this shape.
+    fn volume(&self) -> N;
+
+    /// The center of mass of this shape, expressed in its local-space.
+    fn center_of_mass(&self) -> Point<N>;
+
+    /// The angular inertia of this shape for a unit mass.
+    ///
+    /// It is expressed in the local-space of the shape, relative to its center of mass.
+    fn unit_angular_inertia(&self) -> AngularInertia<N>;
+
+    /// The mass of this shape given its density.
+    #[inline]
+    fn mass(&self, density: N) -> N {
+        self.volume() * density
+    }
+
+    /// The angular inertia of this shape given its mass.
+    ///
+    /// It is expressed in the local-space of the shape, relative to its center of mass.
+    #[inline]
+    fn angular_inertia(&self, mass: N) -> AngularInertia<N> {
+        self.unit_angular_inertia() * mass
+    }
+
+    /// The mass, the center of mass, and the angular inertia of this shape given its density.
+    #[inline]
+    fn mass_properties(&self, density: N) -> (N, Point<N>, AngularInertia<N>) {
+        let mass = self.mass(density);
+        (mass, self.center_of_mass(), self.angular_inertia(mass))
+    }
+}
+
+/// The angular inertia of a shape with the given principal moments along the local axes.
+#[cfg(feature = "dim3")]
+#[inline]
+pub(crate) fn principal_inertia<N: RealField + Copy>(x: N, y: N, z: N) -> AngularInertia<N> {
+    Matrix3::from_diagonal(&Vector3::new(x, y, z))
+}
+
+/// Rotates the angular inertia `inertia` by `rot`.
+#[cfg(feature = "dim3")]
+#[inline]
+pub(crate) fn rotate_inertia<N: RealField + Copy>(
+    inertia: &AngularInertia<N>,
+    rot: &Rotation<N>,
+) -> AngularInertia<N> {
+    let rot = rot.to_rotation_matrix();
+    rot * inertia * rot.inverse()
+}
+
+/// The inertia added to a body of mass `mass` whose center of mass is moved by `shift` (parallel axis theorem).
+#[cfg(feature = "dim3")]
+#[inline]
+pub(crate) fn shift_inertia<N: RealField + Copy>(mass: N, shift: &Vector<N>) -> AngularInertia<N> {
+    let diag = shift.norm_squared();
+    let mut res = shift * (-shift.transpose());
+    res.m11 += diag;
+    res.m22 += diag;
+    res.m33 += diag;
+    res * mass
+}
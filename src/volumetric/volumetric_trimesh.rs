@@ -0,0 +1,98 @@
+use crate::math::{AngularInertia, Point};
+use crate::shape::TriMesh;
+use crate::volumetric::Volumetric;
+use na::{self, Matrix3, Point3, RealField};
+
+/// Computes the volume, the center of mass, and the unit angular inertia of a closed triangle mesh.
+///
+/// This sums the signed contributions of the tetrahedra formed by each triangle and an
+/// arbitrary reference point, so the mesh does not have to be convex. If the triangles are
+/// oriented inward instead of outward, all the signs cancel out and the same result is obtained.
+pub(crate) fn trimesh_volume_properties<N: RealField + Copy>(
+    points: &[Point<N>],
+    indices: impl IntoIterator<Item = Point3<usize>>,
+) -> (N, Point<N>, AngularInertia<N>) {
+    if points.is_empty() {
+        return (N::zero(), Point::origin(), AngularInertia::zeros());
+    }
+
+    // Use a point close to the mesh as the reference to limit the numerical errors.
+    let reference = points[0];
+    let canonical = Matrix3::new(
+        na::convert(2.0),
+        N::one(),
+        N::one(),
+        N::one(),
+        na::convert(2.0),
+        N::one(),
+        N::one(),
+        N::one(),
+        na::convert(2.0),
+    ) / na::convert::<_, N>(120.0);
+
+    let mut volume = N::zero();
+    let mut com = Point::origin();
+    let mut covariance = Matrix3::zeros();
+
+    for idx in indices {
+        let a = points[idx.x] - reference;
+        let b = points[idx.y] - reference;
+        let c = points[idx.z] - reference;
+        let basis = Matrix3::from_columns(&[a, b, c]);
+        let det = basis.determinant();
+        let tetra_volume = det / na::convert(6.0);
+
+        volume += tetra_volume;
+        com += (a + b + c) * (tetra_volume / na::convert(4.0));
+        covariance += basis * canonical * basis.transpose() * det;
+    }
+
+    if volume.is_zero() {
+        return (N::zero(), reference, AngularInertia::zeros());
+    }
+
+    com /= volume;
+    // Move the covariance to the center of mass.
+    covariance -= com.coords * com.coords.transpose() * volume;
+
+    let mut inertia = -covariance;
+    let trace = covariance.trace();
+    inertia.m11 += trace;
+    inertia.m22 += trace;
+    inertia.m33 += trace;
+
+    (volume.abs(), reference + com.coords, inertia / volume)
+}
+
+/// The mass properties of a `TriMesh`.
+///
+/// The mesh is assumed to be closed. Results are meaningless otherwise.
+impl<N: RealField + Copy> Volumetric<N> for TriMesh<N> {
+    #[inline]
+    fn volume(&self) -> N {
+        self.volume_properties().0
+    }
+
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.volume_properties().1
+    }
+
+    #[inline]
+    fn unit_angular_inertia(&self) -> AngularInertia<N> {
+        self.volume_properties().2
+    }
+
+    #[inline]
+    fn mass_properties(&self, density: N) -> (N, Point<N>, AngularInertia<N>) {
+        let (volume, com, inertia) = self.volume_properties();
+        let mass = volume * density;
+        (mass, com, inertia * mass)
+    }
+}
+
+impl<N: RealField + Copy> TriMesh<N> {
+    fn volume_properties(&self) -> (N, Point<N>, AngularInertia<N>) {
+        trimesh_volume_properties(self.points(), self.faces().iter().map(|f| f.indices))
+    }
+}